@@ -0,0 +1,328 @@
+/**
+ * Generates `src/providers/provider-types.generated.ts` from provider
+ * schemas output by `zebar schema`.
+ *
+ * Some providers are only compiled in on certain platforms, so a schema
+ * generated on a single platform is incomplete. Schemas from each
+ * platform are merged, and generation fails if any provider type is
+ * still missing.
+ *
+ * Usage: `node scripts/generate-provider-types.mjs <schema.json>...`
+ */
+import { readFileSync, writeFileSync } from 'node:fs';
+import { dirname, resolve } from 'node:path';
+import { fileURLToPath } from 'node:url';
+
+const OUTPUT_PATH = resolve(
+  dirname(fileURLToPath(import.meta.url)),
+  '../src/providers/provider-types.generated.ts',
+);
+
+const HEADER = `// This file is generated from the desktop app's provider schemas
+// by \`scripts/generate-provider-types.mjs\`. Do not edit it by hand.
+`;
+
+const schemaPaths = process.argv.slice(2);
+
+if (schemaPaths.length === 0) {
+  console.error(
+    'Usage: node scripts/generate-provider-types.mjs <schema.json>...',
+  );
+  process.exit(1);
+}
+
+const schemas = schemaPaths.map(path =>
+  JSON.parse(readFileSync(path, 'utf8')),
+);
+
+const definitions = mergeDefinitions(schemas);
+checkProviderTypes(schemas, definitions);
+
+// Outputs are serialized with every field (`None` becomes `null`), even
+// though the schema marks optional fields as not required.
+const configNames = referencedDefinitions('ProviderConfig', definitions);
+const outputNames = referencedDefinitions('ProviderOutput', definitions);
+
+const output = Object.keys(definitions)
+  .sort()
+  .map(name =>
+    definitionToTs(
+      name,
+      definitions[name],
+      outputNames.has(name) && !configNames.has(name),
+    ),
+  )
+  .join('\n');
+
+writeFileSync(OUTPUT_PATH, `${HEADER}\n${output}`);
+console.log(`Wrote ${OUTPUT_PATH}.`);
+
+/**
+ * Merges the definitions of each schema. Variants of the top-level
+ * `ProviderConfig` and `ProviderOutput` unions are combined, whereas any
+ * other definition must be identical across schemas.
+ */
+function mergeDefinitions(schemas) {
+  const merged = {};
+  const configVariants = [];
+  const outputVariants = [];
+
+  for (const { definitions } of schemas) {
+    for (const [name, definition] of Object.entries(definitions)) {
+      if (name === 'ProviderConfig') {
+        configVariants.push(...definition.oneOf);
+      } else if (name === 'ProviderOutput') {
+        outputVariants.push(...definition.anyOf);
+      } else if (!merged[name]) {
+        merged[name] = definition;
+      } else if (
+        JSON.stringify(merged[name]) !== JSON.stringify(definition)
+      ) {
+        throw new Error(
+          `Definition '${name}' differs between the given schemas.`,
+        );
+      }
+    }
+  }
+
+  merged.ProviderConfig = {
+    oneOf: uniqueVariants(
+      configVariants,
+      variant => variant.properties.type.enum[0],
+    ),
+  };
+
+  merged.ProviderOutput = {
+    anyOf: uniqueVariants(
+      outputVariants,
+      variant => variant.$ref ?? JSON.stringify(variant),
+    ),
+  };
+
+  return merged;
+}
+
+/**
+ * Removes duplicate union variants and sorts them by the given key, so
+ * that the output doesn't depend on the platform or order of schemas.
+ */
+function uniqueVariants(variants, getKey) {
+  const unique = new Map(
+    variants.map(variant => [getKey(variant), variant]),
+  );
+
+  return [...unique.keys()].sort().map(key => unique.get(key));
+}
+
+/**
+ * Checks that every provider type listed in the schemas has a config
+ * variant, i.e. that a schema was given for each of its platforms.
+ */
+function checkProviderTypes(schemas, definitions) {
+  const platforms = Object.assign(
+    {},
+    ...schemas.map(schema => schema.providerPlatforms),
+  );
+
+  const configTypes = new Set(
+    definitions.ProviderConfig.oneOf.map(
+      variant => variant.properties.type.enum[0],
+    ),
+  );
+
+  const missing = Object.keys(platforms).filter(
+    type => !configTypes.has(type),
+  );
+
+  if (missing.length > 0) {
+    throw new Error(
+      'Schemas are missing the following providers: ' +
+        missing
+          .map(type => `${type} (${platforms[type].join(', ')})`)
+          .join(', ') +
+        '. Pass a schema generated on each of their platforms.',
+    );
+  }
+}
+
+/**
+ * Gets the names of all definitions that are referenced (directly or
+ * transitively) by the given definition, including itself.
+ */
+function referencedDefinitions(name, definitions) {
+  const names = new Set();
+  const pending = [name];
+
+  while (pending.length > 0) {
+    const next = pending.pop();
+
+    if (!names.has(next)) {
+      names.add(next);
+
+      const refs = JSON.stringify(definitions[next]).matchAll(
+        /"#\/definitions\/(\w+)"/g,
+      );
+
+      pending.push(...[...refs].map(([, ref]) => ref));
+    }
+  }
+
+  return names;
+}
+
+/**
+ * Converts a definition to an exported interface or type alias. With
+ * `allRequired`, properties of objects are never optional.
+ */
+function definitionToTs(name, schema, allRequired) {
+  const doc = docComment(schema.description, '');
+  const context = { indent: '', allRequired };
+  const isInterface =
+    schema.properties && !schema.anyOf && !schema.oneOf && !schema.enum;
+
+  return isInterface
+    ? `${doc}export interface ${name} ${objectToTs(schema, context)}\n`
+    : `${doc}export type ${name} = ${schemaToTs(schema, context)};\n`;
+}
+
+/**
+ * Converts a JSON schema to a TypeScript type. Only covers the keywords
+ * that `schemars` outputs for provider types.
+ */
+function schemaToTs(schema, context) {
+  if (schema === true || Object.keys(schema).length === 0) {
+    return 'unknown';
+  }
+
+  if (schema.$ref) {
+    return schema.$ref.replace('#/definitions/', '');
+  }
+
+  const variants = schema.anyOf ?? schema.oneOf;
+
+  if (variants) {
+    return union(variants.map(variant => schemaToTs(variant, context)));
+  }
+
+  const types = [schema.type ?? []].flat();
+
+  if (schema.enum) {
+    const literals = schema.enum.map(literal);
+
+    if (types.includes('null') && !schema.enum.includes(null)) {
+      literals.push('null');
+    }
+
+    return union(literals);
+  }
+
+  return union(
+    types.map(type => {
+      switch (type) {
+        case 'string':
+          return 'string';
+        case 'integer':
+        case 'number':
+          return 'number';
+        case 'boolean':
+          return 'boolean';
+        case 'null':
+          return 'null';
+        case 'array':
+          return arrayToTs(schema, context);
+        case 'object':
+          return objectToTs(schema, context);
+        default:
+          throw new Error(`Unsupported schema type '${type}'.`);
+      }
+    }),
+  );
+}
+
+function arrayToTs(schema, context) {
+  if (Array.isArray(schema.items)) {
+    const items = schema.items.map(item => schemaToTs(item, context));
+    return `[${items.join(', ')}]`;
+  }
+
+  const item = schemaToTs(schema.items ?? {}, context);
+  return /[|&]/.test(item) ? `(${item})[]` : `${item}[]`;
+}
+
+function objectToTs(schema, context) {
+  if (!schema.properties) {
+    const value =
+      schema.additionalProperties && schema.additionalProperties !== true
+        ? schemaToTs(schema.additionalProperties, context)
+        : 'unknown';
+
+    return `Record<string, ${value}>`;
+  }
+
+  const required = new Set(schema.required ?? []);
+  const indent = `${context.indent}  `;
+
+  const properties = Object.keys(schema.properties)
+    .sort()
+    .map(key => {
+      const property = schema.properties[key];
+      const doc = docComment(property.description, indent);
+      const optional = context.allRequired || required.has(key) ? '' : '?';
+      const type = schemaToTs(property, { ...context, indent });
+
+      return `${doc}${indent}${propertyKey(key)}${optional}: ${type};`;
+    });
+
+  return `{\n${properties.join('\n')}\n${context.indent}}`;
+}
+
+function union(types) {
+  const unique = [...new Set(types)];
+  return unique.length === 0 ? 'never' : unique.join(' | ');
+}
+
+function literal(value) {
+  return typeof value === 'string'
+    ? `'${value.replace(/[\\']/g, '\\$&')}'`
+    : JSON.stringify(value);
+}
+
+function propertyKey(key) {
+  return /^[A-Za-z_$][\w$]*$/.test(key) ? key : literal(key);
+}
+
+/**
+ * Formats a description as a JSDoc comment, wrapped at 75 columns.
+ */
+function docComment(description, indent) {
+  if (!description) {
+    return '';
+  }
+
+  const lines = description
+    .replaceAll('*/', '*\\/')
+    .split('\n')
+    .flatMap(paragraph => wrap(paragraph, 75 - indent.length - 3));
+
+  const body = lines
+    .map(line => (line ? `${indent} * ${line}` : `${indent} *`))
+    .join('\n');
+
+  return `${indent}/**\n${body}\n${indent} */\n`;
+}
+
+function wrap(paragraph, maxWidth) {
+  const lines = [];
+  let line = '';
+
+  for (const word of paragraph.split(' ').filter(Boolean)) {
+    if (line && line.length + word.length + 1 > maxWidth) {
+      lines.push(line);
+      line = word;
+    } else {
+      line = line ? `${line} ${word}` : word;
+    }
+  }
+
+  return [...lines, line];
+}
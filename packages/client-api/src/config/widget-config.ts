@@ -1,13 +1,84 @@
+import type { ProviderConfig } from '~/providers';
 import type { WidgetCaching } from './widget-caching';
 import type { WidgetPreset } from './widget-preset';
 
 export type WidgetConfig = {
+  extends?: string | string[];
   htmlPath: string;
+  name?: string;
   zOrder: 'normal' | 'top_most' | 'bottom_most';
+  layer?: 'bottom' | 'normal' | 'top' | 'overlay';
   shownInTaskbar: boolean;
   focused: boolean;
   resizable: boolean;
   transparent: boolean;
+  x11WindowType?:
+    | 'dock'
+    | 'desktop'
+    | 'toolbar'
+    | 'utility'
+    | 'notification';
+  touch?: WidgetTouchOptions;
   caching: WidgetCaching;
   presets: WidgetPreset[];
+  opensWith?: string[];
+  segments?: WidgetSegment[];
+  singleWebview?: boolean;
+  devMode?: boolean;
+  launchOptions?: WidgetLaunchOptions;
+  permissions?: WidgetPermissions;
+  security?: WidgetSecurity;
+  windowTitle?: WidgetWindowTitle;
+  env?: Record<string, string>;
 };
+
+export type WidgetLaunchOptions = {
+  deferUntilShown: boolean;
+};
+
+export type WidgetTouchOptions = {
+  edgeSwipeReveal?: boolean;
+  revealDuration?: number;
+  holdToRightClick?: boolean;
+  holdDuration?: number;
+};
+
+export type WidgetSegment = {
+  name: string;
+  region: 'left' | 'center' | 'right';
+  width: string;
+};
+
+export type WidgetWindowTitle = {
+  template: string;
+  providers: Record<string, ProviderConfig>;
+};
+
+export type WidgetSecurity = {
+  allowRemoteContent: boolean;
+  connectHosts: string[];
+};
+
+export type WidgetPermissions = {
+  providers: string[];
+  commands: WidgetCommandPermission[];
+  shellCommands?: WidgetShellCommandRule[];
+  secrets?: string[];
+};
+
+export type WidgetShellCommandRule = {
+  program: string;
+  args?: string[];
+};
+
+export type WidgetCommandPermission =
+  | 'shell_exec'
+  | 'screenshot'
+  | 'manage_widgets'
+  | 'display_profiles'
+  | 'secrets'
+  | 'power'
+  | 'launcher'
+  | 'git_sync'
+  | 'tray_badge'
+  | 'history';
@@ -2,4 +2,14 @@ import type { WidgetPlacement } from './widget-placement';
 
 export type WidgetPreset = {
   name: string;
+  schedule?: WidgetSchedule;
 } & WidgetPlacement;
+
+export type WidgetSchedule = {
+  activeHours?: {
+    start: string;
+    end: string;
+    days?: ('mon' | 'tue' | 'wed' | 'thu' | 'fri' | 'sat' | 'sun')[];
+  }[];
+  hideOnFullscreen?: boolean;
+};
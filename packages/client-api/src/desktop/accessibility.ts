@@ -0,0 +1,41 @@
+import { listen, type UnlistenFn } from '@tauri-apps/api/event';
+
+export interface AccessibilityPrefs {
+  /**
+   * Whether the user prefers reduced motion (i.e. animations are
+   * disabled in the OS settings).
+   */
+  reducedMotion: boolean;
+
+  /**
+   * Whether a high-contrast theme is enabled.
+   */
+  highContrast: boolean;
+}
+
+/**
+ * Gets the accessibility preferences from the OS settings at the time
+ * the widget was opened. Use `onAccessibilityChange` to get updates.
+ */
+export function accessibilityPrefs(): AccessibilityPrefs {
+  return (
+    window.__ZEBAR_ACCESSIBILITY ?? {
+      reducedMotion: false,
+      highContrast: false,
+    }
+  );
+}
+
+/**
+ * Listens for changes to the accessibility preferences in the OS
+ * settings.
+ *
+ * Returns a function to stop listening.
+ */
+export function onAccessibilityChange(
+  callback: (prefs: AccessibilityPrefs) => void,
+): Promise<UnlistenFn> {
+  return listen<AccessibilityPrefs>('accessibility-changed', event =>
+    callback(event.payload),
+  );
+}
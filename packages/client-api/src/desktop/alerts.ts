@@ -0,0 +1,25 @@
+import { listen, type UnlistenFn } from '@tauri-apps/api/event';
+
+export interface AlertEvent {
+  /**
+   * Name of the alert rule that fired.
+   */
+  name: string;
+
+  /**
+   * Latest outputs of the rule's providers, keyed by name.
+   */
+  outputs: Record<string, unknown>;
+}
+
+/**
+ * Listens for alert rules with an `emit_event` action firing. Rules are
+ * defined under `alerts` in the settings.
+ *
+ * Returns a function to stop listening.
+ */
+export function onAlert(
+  callback: (alert: AlertEvent) => void,
+): Promise<UnlistenFn> {
+  return listen<AlertEvent>('alert', event => callback(event.payload));
+}
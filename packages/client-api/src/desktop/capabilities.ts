@@ -0,0 +1,54 @@
+import { desktopCommands } from './desktop-commands';
+
+export interface Capabilities {
+  /**
+   * Version of the widget API. Same as `getApiVersion()`.
+   */
+  apiVersion: number;
+
+  /**
+   * Version of Zebar (e.g. `2.4.0`).
+   */
+  version: string;
+
+  /**
+   * Current platform.
+   */
+  platform: 'windows' | 'macos' | 'linux';
+
+  /**
+   * Names of the commands that widgets can invoke (e.g.
+   * `set_taskbar_progress`).
+   */
+  commands: string[];
+
+  /**
+   * Provider types available on the current platform (e.g. `audio`).
+   */
+  providers: string[];
+}
+
+/**
+ * Gets the version of the widget API that the running Zebar build
+ * supports. The version is incremented whenever the API changes.
+ *
+ * Returns `0` for Zebar builds from before the API was versioned.
+ */
+export function getApiVersion(): number {
+  return window.__ZEBAR_API_VERSION ?? 0;
+}
+
+/**
+ * Gets the commands and provider types supported by the running Zebar
+ * build, so that widgets can degrade gracefully when a feature is
+ * unavailable.
+ *
+ * Returns `null` for Zebar builds from before the API was versioned.
+ */
+export async function getCapabilities(): Promise<Capabilities | null> {
+  if (getApiVersion() === 0) {
+    return null;
+  }
+
+  return desktopCommands.getCapabilities();
+}
@@ -6,6 +6,10 @@ import {
 import { createLogger } from '../utils';
 import type { ProviderConfig } from '~/providers';
 import type { WidgetPlacement } from '~/config';
+import type { AccessibilityPrefs } from './accessibility';
+import type { Capabilities } from './capabilities';
+import type { GitSyncStatus } from './git-sync';
+import type { ScreenPresence } from './screen-presence';
 
 const logger = createLogger('desktop-commands');
 
@@ -15,23 +19,197 @@ export const desktopCommands = {
   listenProvider,
   unlistenProvider,
   callProviderFunction,
+  getProviderAttachment,
+  resolveAsset,
+  getProviderSchemas,
+  getCapabilities,
   setAlwaysOnTop,
   setSkipTaskbar,
+  locale,
+  widgetEnv,
+  setTrayBadge,
+  setTaskbarOverlay,
+  setTaskbarProgress,
+  setKeepAwake,
+  powerAction,
+  launcherSearch,
+  launcherActivate,
+  launcherIcon,
+  queryHistory,
+  exportHistory,
+  captureWidget,
+  showContextMenu,
+  showTooltip,
+  hideTooltip,
+  accessibilityPrefs,
+  screenPresence,
+  gitSyncStatus,
+  gitSyncPull,
+  gitSyncCommitAndPush,
+  runCommand,
+  setSecret,
+  deleteSecret,
 };
 
-export type ProviderFunction = AudioFunction | MediaFunction;
+/**
+ * Reference to binary data emitted by a provider (e.g. album art).
+ */
+export interface ProviderAttachment {
+  id: string;
+  mimeType: string;
+  size: number;
+}
+
+/**
+ * Supported platforms and JSON schemas of a provider type.
+ */
+export interface ProviderSchema {
+  name: string;
+  platforms: ('windows' | 'macos' | 'linux')[];
+  configSchema: Record<string, unknown>;
+  outputSchema: Record<string, unknown>;
+}
+
+export type TrayBadge =
+  | { type: 'dot'; color?: string }
+  | { type: 'count'; count: number; color?: string };
+
+/**
+ * Item of a native context menu shown via `showContextMenu`. Items with
+ * `checked` set are shown with a checkmark.
+ */
+export type ContextMenuItem =
+  | {
+      type: 'item';
+      id: string;
+      label: string;
+      disabled?: boolean;
+      checked?: boolean;
+    }
+  | { type: 'separator' }
+  | {
+      type: 'submenu';
+      label: string;
+      disabled?: boolean;
+      items: ContextMenuItem[];
+    };
+
+export type ProviderFunction =
+  | AudioFunction
+  | CiStatusFunction
+  | DiscordFunction
+  | HyprlandFunction
+  | I3Function
+  | KomorebiFunction
+  | MediaFunction
+  | PrinterFunction
+  | SpotifyFunction
+  | TasksFunction
+  | TimerFunction
+  | VpnFunction;
 
 export interface AudioFunction {
   type: 'audio';
+  function:
+    | {
+        name: 'set_volume';
+        args: {
+          volume: number;
+          deviceId?: string;
+        };
+      }
+    | {
+        name: 'toggle_mic_mute';
+        args: {
+          deviceId?: string;
+        };
+      }
+    | {
+        name: 'set_app_volume';
+        args: {
+          sessionId: string;
+          volume: number;
+        };
+      }
+    | {
+        name: 'set_app_mute';
+        args: {
+          sessionId: string;
+          isMuted: boolean;
+        };
+      };
+}
+
+export interface CiStatusFunction {
+  type: 'ci_status';
+  function: {
+    name: 'open_pipeline';
+    args: {
+      repo: string;
+      branch?: string;
+    };
+  };
+}
+
+export interface DiscordFunction {
+  type: 'discord';
+  function:
+    | {
+        name: 'authorize' | 'logout' | 'toggle_mute' | 'toggle_deafen';
+      }
+    | {
+        name: 'set_mute';
+        args: {
+          isMuted: boolean;
+        };
+      }
+    | {
+        name: 'set_deafen';
+        args: {
+          isDeafened: boolean;
+        };
+      };
+}
+
+export interface HyprlandFunction {
+  type: 'hyprland';
   function: {
-    name: 'set_volume';
+    name: 'dispatch';
     args: {
-      volume: number;
-      deviceId?: string;
+      dispatcher: string;
+      args?: string;
     };
   };
 }
 
+export interface I3Function {
+  type: 'i3';
+  function: {
+    name: 'command';
+    args: {
+      command: string;
+    };
+  };
+}
+
+export interface KomorebiFunction {
+  type: 'komorebi';
+  function:
+    | {
+        name: 'focus_workspace';
+        args: {
+          workspaceIndex: number;
+          monitorIndex?: number;
+        };
+      }
+    | {
+        name: 'focus_monitor';
+        args: {
+          monitorIndex: number;
+        };
+      };
+}
+
 export interface MediaFunction {
   type: 'media';
   function: {
@@ -42,6 +220,97 @@ export interface MediaFunction {
   };
 }
 
+export interface PrinterFunction {
+  type: 'printer';
+  function: {
+    name: 'cancel_job';
+    args: {
+      printerName: string;
+      jobId: number;
+    };
+  };
+}
+
+export interface SpotifyFunction {
+  type: 'spotify';
+  function:
+    | {
+        name:
+          | 'authorize'
+          | 'logout'
+          | 'play'
+          | 'pause'
+          | 'toggle_play_pause'
+          | 'next'
+          | 'previous';
+      }
+    | {
+        name: 'seek';
+        args: {
+          position: number;
+        };
+      }
+    | {
+        name: 'set_volume';
+        args: {
+          volume: number;
+        };
+      }
+    | {
+        name: 'transfer_playback';
+        args: {
+          deviceId: string;
+          play?: boolean;
+        };
+      }
+    | {
+        name: 'set_liked';
+        args: {
+          isLiked: boolean;
+        };
+      };
+}
+
+export interface TasksFunction {
+  type: 'tasks';
+  function:
+    | {
+        name: 'authorize' | 'logout';
+      }
+    | {
+        name: 'complete_task';
+        args: {
+          taskId: string;
+        };
+      };
+}
+
+export interface TimerFunction {
+  type: 'timer';
+  function:
+    | {
+        name: 'create_timer';
+        args: {
+          name: string;
+          duration?: number;
+          start?: boolean;
+        };
+      }
+    | {
+        name: 'start' | 'pause' | 'lap' | 'reset' | 'cancel';
+        args: {
+          name: string;
+        };
+      };
+}
+
+export interface VpnFunction {
+  type: 'vpn';
+  function: {
+    name: 'connect' | 'disconnect';
+  };
+}
+
 function startWidget(
   configPath: string,
   placement: WidgetPlacement,
@@ -67,16 +336,51 @@ function unlistenProvider(configHash: string): Promise<void> {
   return invoke<void>('unlisten_provider', { configHash });
 }
 
-function callProviderFunction(
+function callProviderFunction<T = void>(
   configHash: string,
   fn: ProviderFunction,
-): Promise<void> {
-  return invoke<void>('call_provider_function', {
+): Promise<T> {
+  return invoke<T>('call_provider_function', {
     configHash,
     function: fn,
   });
 }
 
+/**
+ * Gets the binary data of a provider attachment. The widget needs to be
+ * permitted to use the provider that emitted it.
+ */
+function getProviderAttachment(id: string): Promise<ArrayBuffer> {
+  return invoke<ArrayBuffer>('get_provider_attachment', { id });
+}
+
+/**
+ * Resolves a file outside of the widget's own directory (e.g. a shared
+ * font or image) to a URL that the widget can load.
+ *
+ * Relative paths are resolved from the widget's directory. The file
+ * must be within the Zebar config directory.
+ */
+function resolveAsset(path: string): Promise<string> {
+  return invoke<string>('resolve_asset', { path });
+}
+
+/**
+ * Gets the schemas of all provider types available on the current
+ * platform.
+ */
+function getProviderSchemas(): Promise<ProviderSchema[]> {
+  return invoke<ProviderSchema[]>('get_provider_schemas');
+}
+
+/**
+ * Gets the API version, commands, and provider types supported by the
+ * running Zebar build.
+ */
+function getCapabilities(): Promise<Capabilities> {
+  return invoke<Capabilities>('get_capabilities');
+}
+
 function setAlwaysOnTop(): Promise<void> {
   return invoke<void>('set_always_on_top');
 }
@@ -85,6 +389,373 @@ function setSkipTaskbar(skip: boolean): Promise<void> {
   return invoke<void>('set_skip_taskbar', { skip });
 }
 
+/**
+ * Active locale for backend-generated text (e.g. `en` or `zh-CN`).
+ */
+function locale(): Promise<string> {
+  return invoke<string>('locale');
+}
+
+/**
+ * Variables from the `env` of the calling widget's config.
+ */
+function widgetEnv(): Promise<Record<string, string>> {
+  return invoke<Record<string, string>>('widget_env');
+}
+
+export type RunCommandOptions = {
+  /**
+   * Working directory to run the program in. Relative paths are resolved
+   * from the widget's directory. Needs to be within the widget's
+   * directory, unless `shell_exec` is in `permissions.commands` of the
+   * widget config.
+   */
+  cwd?: string;
+
+  /**
+   * Max time in milliseconds before the program is killed. Defaults to
+   * 10 seconds, and is capped at 60 seconds.
+   */
+  timeoutMs?: number;
+};
+
+export type RunCommandOutput = {
+  exitCode: number | null;
+  stdout: string;
+  stderr: string;
+  truncated: boolean;
+};
+
+/**
+ * Runs a program (without a shell) and returns its output.
+ *
+ * The program and arguments need to be allowed via
+ * `permissions.shellCommands` in the widget config.
+ */
+function runCommand(
+  program: string,
+  args: string[] = [],
+  options: RunCommandOptions = {},
+): Promise<RunCommandOutput> {
+  return invoke<RunCommandOutput>('run_command', {
+    program,
+    args,
+    cwd: options.cwd ?? null,
+    timeoutMs: options.timeoutMs ?? null,
+  });
+}
+
+/**
+ * Stores a secret in the OS credential store. Provider configs can then
+ * reference it by name, e.g. `apiKey: 'secret:owm'`, if it's listed in
+ * `permissions.secrets` of the widget config.
+ *
+ * Requires `secrets` in `permissions.commands` of the widget config.
+ */
+function setSecret(name: string, value: string): Promise<void> {
+  return invoke<void>('set_secret', { name, value });
+}
+
+/**
+ * Removes a secret from the OS credential store.
+ *
+ * Requires `secrets` in `permissions.commands` of the widget config.
+ */
+function deleteSecret(name: string): Promise<void> {
+  return invoke<void>('delete_secret', { name });
+}
+
+/**
+ * Sets or clears (with `null`) the badge shown on the system tray icon.
+ *
+ * Requires `tray_badge` in `permissions.commands` of the widget config.
+ */
+function setTrayBadge(badge: TrayBadge | null): Promise<void> {
+  return invoke<void>('set_tray_badge', { badge });
+}
+
+/**
+ * Sets or clears the badge overlaid on the widget's taskbar icon.
+ *
+ * Requires `shownInTaskbar` in the widget config. Only supported on
+ * Windows.
+ */
+function setTaskbarOverlay(badge: TrayBadge | null): Promise<void> {
+  return invoke<void>('set_taskbar_overlay', { badge });
+}
+
+export type TaskbarProgress = {
+  /** Progress from 0 to 100. Ignored for indeterminate progress. */
+  progress?: number;
+  status?: 'normal' | 'indeterminate' | 'paused' | 'error';
+};
+
+/**
+ * Sets or clears the progress shown on the widget's taskbar button.
+ *
+ * Requires `shownInTaskbar` in the widget config.
+ */
+function setTaskbarProgress(
+  progress: TaskbarProgress | null,
+): Promise<void> {
+  return invoke<void>('set_taskbar_progress', { progress });
+}
+
+/**
+ * Starts or stops preventing the system from sleeping.
+ *
+ * Requires `power` in `permissions.commands` of the widget config.
+ */
+function setKeepAwake(isActive: boolean): Promise<void> {
+  return invoke<void>('set_keep_awake', { isActive });
+}
+
+export type PowerAction =
+  | 'lock'
+  | 'logout'
+  | 'sleep'
+  | 'hibernate'
+  | 'restart'
+  | 'shutdown';
+
+export type PowerActionOptions = {
+  /**
+   * Whether to ask the user to confirm via a dialog first. Defaults to
+   * `false`.
+   */
+  confirm?: boolean;
+};
+
+/**
+ * Locks, logs out, sleeps, hibernates, restarts, or shuts down the
+ * system. Resolves to `false` if the user cancelled the confirmation.
+ *
+ * Requires `power` in `permissions.commands` of the widget config.
+ * Hibernate is not supported on MacOS.
+ */
+function powerAction(
+  action: PowerAction,
+  options: PowerActionOptions = {},
+): Promise<boolean> {
+  return invoke<boolean>('power_action', {
+    action,
+    confirm: options.confirm ?? null,
+  });
+}
+
+export type LauncherResult = {
+  /**
+   * ID to pass to `launcherActivate` (e.g. `window:1234`).
+   */
+  id: string;
+  kind: 'app' | 'window' | 'command';
+  title: string;
+
+  /**
+   * Secondary text (e.g. the path of an app).
+   */
+  subtitle: string | null;
+
+  /**
+   * Match score. Higher is better.
+   */
+  score: number;
+};
+
+/**
+ * Fuzzy searches installed apps, open windows, and commands registered
+ * in the `launcher` settings. Results are sorted best match first.
+ *
+ * Requires `launcher` in `permissions.commands` of the widget config.
+ */
+function launcherSearch(
+  query: string,
+  limit?: number,
+): Promise<LauncherResult[]> {
+  return invoke<LauncherResult[]>('launcher_search', {
+    query,
+    limit: limit ?? null,
+  });
+}
+
+/**
+ * Launches an app, focuses a window, or runs a command from the search
+ * results, then closes the launcher.
+ *
+ * Requires `launcher` in `permissions.commands` of the widget config.
+ */
+function launcherActivate(id: string): Promise<void> {
+  return invoke<void>('launcher_activate', { id });
+}
+
+/**
+ * Gets the icon of a search result as a PNG. Only available for window
+ * results on Windows.
+ *
+ * Requires `launcher` in `permissions.commands` of the widget config.
+ */
+function launcherIcon(id: string): Promise<ArrayBuffer> {
+  return invoke<ArrayBuffer>('launcher_icon', { id });
+}
+
+export type HistoryPoint = {
+  /**
+   * Unix timestamp in milliseconds. Start of the bucket when queried
+   * with a resolution.
+   */
+  timestamp: number;
+
+  /**
+   * Average of the values within the bucket.
+   */
+  value: number;
+  min: number;
+  max: number;
+};
+
+/**
+ * Gets the recorded values of a metric from the `history` settings
+ * between two Unix timestamps in milliseconds. `end` defaults to now.
+ *
+ * If a `resolution` in milliseconds is given, values are grouped into
+ * buckets of that size (e.g. `3600000` for hourly averages).
+ *
+ * Requires `history` in `permissions.commands` of the widget config.
+ */
+function queryHistory(
+  metric: string,
+  range: { start: number; end?: number },
+  resolution?: number,
+): Promise<HistoryPoint[]> {
+  return invoke<HistoryPoint[]>('query_history', {
+    metric,
+    start: range.start,
+    end: range.end ?? null,
+    resolution: resolution ?? null,
+  });
+}
+
+/**
+ * Gets the recorded values of a metric from the `history` settings as
+ * CSV or JSON, e.g. for saving to a file. Takes the same arguments as
+ * `queryHistory`.
+ *
+ * Requires `history` in `permissions.commands` of the widget config.
+ */
+function exportHistory(
+  metric: string,
+  range: { start: number; end?: number },
+  format: 'csv' | 'json',
+  resolution?: number,
+): Promise<string> {
+  return invoke<string>('export_history', {
+    metric,
+    start: range.start,
+    end: range.end ?? null,
+    resolution: resolution ?? null,
+    format,
+  });
+}
+
+/**
+ * Renders a widget to a PNG file, keeping its transparent background.
+ * Captures the current widget if no widget ID is given. Not yet
+ * supported on MacOS.
+ *
+ * The output path is resolved from the widget's directory and needs to
+ * be within it. If no output path is given, the user picks one via a
+ * save dialog.
+ *
+ * Requires `screenshot` in `permissions.commands` of the widget config.
+ * Capturing other widgets additionally requires `manage_widgets`.
+ */
+function captureWidget(
+  outputPath?: string,
+  widgetId?: string,
+): Promise<void> {
+  return invoke<void>('capture_widget', {
+    outputPath: outputPath ?? null,
+    widgetId: widgetId ?? null,
+  });
+}
+
+/**
+ * Shows a native context menu at the given position (in pixels relative
+ * to the widget's window), or at the cursor if no position is given.
+ * Unlike HTML popups, native menus aren't clipped by the window bounds.
+ *
+ * Resolves to the ID of the selected item, or `null` if the menu was
+ * closed without a selection.
+ */
+function showContextMenu(
+  items: ContextMenuItem[],
+  position?: { x: number; y: number },
+): Promise<string | null> {
+  return invoke<string | null>('show_context_menu', {
+    items,
+    x: position?.x ?? null,
+    y: position?.y ?? null,
+  });
+}
+
+/**
+ * Shows a native tooltip at the given position (in pixels relative to
+ * the widget's window), replacing any open tooltip of the widget. Not
+ * yet supported on MacOS.
+ */
+function showTooltip(
+  text: string,
+  position: { x: number; y: number },
+): Promise<void> {
+  return invoke<void>('show_tooltip', { text, ...position });
+}
+
+/**
+ * Hides the open tooltip of the widget (if any).
+ */
+function hideTooltip(): Promise<void> {
+  return invoke<void>('hide_tooltip');
+}
+
+/**
+ * Gets the accessibility preferences from the OS settings.
+ */
+function accessibilityPrefs(): Promise<AccessibilityPrefs> {
+  return invoke<AccessibilityPrefs>('accessibility_prefs');
+}
+
+/**
+ * Gets whether the display is on and the session is in use.
+ */
+function screenPresence(): Promise<ScreenPresence> {
+  return invoke<ScreenPresence>('screen_presence');
+}
+
+/**
+ * Gets the git status of the config directory.
+ */
+function gitSyncStatus(): Promise<GitSyncStatus | null> {
+  return invoke<GitSyncStatus | null>('git_sync_status');
+}
+
+/**
+ * Pulls upstream changes to the config directory.
+ *
+ * Requires `git_sync` in `permissions.commands` of the widget config.
+ */
+function gitSyncPull(): Promise<void> {
+  return invoke<void>('git_sync_pull');
+}
+
+/**
+ * Commits and pushes changes within the config directory.
+ *
+ * Requires `git_sync` in `permissions.commands` of the widget config.
+ */
+function gitSyncCommitAndPush(): Promise<void> {
+  return invoke<void>('git_sync_commit_and_push');
+}
+
 /**
  * Invoke a Tauri command with logging and error handling.
  */
@@ -0,0 +1,60 @@
+import { desktopCommands } from './desktop-commands';
+
+export interface GitSyncStatus {
+  /**
+   * Name of the checked out branch. `null` if no branch is checked out
+   * (i.e. a detached `HEAD`).
+   */
+  branch: string | null;
+
+  /**
+   * Paths of files within the config directory with uncommitted
+   * changes, including untracked files.
+   */
+  changedFiles: string[];
+
+  /**
+   * Whether the branch has an upstream branch to pull from and push to.
+   */
+  hasUpstream: boolean;
+
+  /**
+   * Number of local commits that haven't been pushed.
+   */
+  ahead: number;
+
+  /**
+   * Number of upstream commits that haven't been pulled, as of the last
+   * fetch.
+   */
+  behind: number;
+}
+
+/**
+ * Gets the git status of the config directory. `null` if `gitSync` is
+ * disabled in settings or the config directory isn't in a git
+ * repository.
+ */
+export function getGitSyncStatus(): Promise<GitSyncStatus | null> {
+  return desktopCommands.gitSyncStatus();
+}
+
+/**
+ * Pulls upstream changes to the config directory and reloads all
+ * configs. Only fast-forwards.
+ *
+ * Requires `git_sync` in `permissions.commands` of the widget config.
+ */
+export function gitSyncPull(): Promise<void> {
+  return desktopCommands.gitSyncPull();
+}
+
+/**
+ * Commits all changes within the config directory with a generated
+ * message, then pushes them.
+ *
+ * Requires `git_sync` in `permissions.commands` of the widget config.
+ */
+export function gitSyncCommitAndPush(): Promise<void> {
+  return desktopCommands.gitSyncCommitAndPush();
+}
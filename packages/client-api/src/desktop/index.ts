@@ -1,6 +1,11 @@
+export * from './accessibility';
+export * from './alerts';
+export * from './capabilities';
 export * from './desktop-commands';
 export * from './dialogs';
+export * from './git-sync';
 export * from './monitors';
 export * from './provider-emit';
+export * from './screen-presence';
 export * from './widgets';
 export * from './windows';
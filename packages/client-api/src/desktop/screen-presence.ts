@@ -0,0 +1,43 @@
+import { listen, type UnlistenFn } from '@tauri-apps/api/event';
+
+import { desktopCommands } from './desktop-commands';
+
+export interface ScreenPresence {
+  /**
+   * Whether the display is on (i.e. not turned off by power saving).
+   */
+  isDisplayOn: boolean;
+
+  /**
+   * Whether the session that Zebar is running in is locked.
+   */
+  isSessionLocked: boolean;
+
+  /**
+   * Whether the session that Zebar is running in is in the foreground
+   * (i.e. not switched away from or disconnected via RDP).
+   */
+  isSessionActive: boolean;
+}
+
+/**
+ * Gets whether the display is on and the session is in use. Widgets can
+ * use this to pause animations while nobody is looking at the screen.
+ */
+export function getScreenPresence(): Promise<ScreenPresence> {
+  return desktopCommands.screenPresence();
+}
+
+/**
+ * Listens for the display turning on or off, and the session being
+ * locked, unlocked, or disconnected.
+ *
+ * Returns a function to stop listening.
+ */
+export function onScreenPresenceChange(
+  callback: (presence: ScreenPresence) => void,
+): Promise<UnlistenFn> {
+  return listen<ScreenPresence>('screen-presence-changed', event =>
+    callback(event.payload),
+  );
+}
@@ -1,7 +1,12 @@
+import { listen, type UnlistenFn } from '@tauri-apps/api/event';
 import { join } from '@tauri-apps/api/path';
 
 import { desktopCommands } from './desktop-commands';
-import type { WidgetPlacement } from '~/config';
+import type {
+  WidgetConfig,
+  WidgetPlacement,
+  WidgetSegment,
+} from '~/config';
 import { currentWindow, type WidgetWindow } from './windows';
 
 export interface Widget {
@@ -20,12 +25,41 @@ export interface Widget {
    */
   htmlPath: string;
 
+  /**
+   * Name of the segment that the window renders. `null` if the config
+   * has no segments, or if they're all rendered in the same window (via
+   * `singleWebview`).
+   */
+  segment: string | null;
+
   /**
    * The window of the widget.
    */
   window: WidgetWindow;
 }
 
+/**
+ * State of an open widget, as broadcast when any widget is opened or
+ * closed.
+ */
+export interface WidgetState {
+  id: string;
+  configPath: string;
+  htmlPath: string;
+  openOptions: { standalone: WidgetPlacement } | { preset: string };
+
+  /**
+   * Config path of the widget that this widget was opened as a companion
+   * of (via `opensWith`).
+   */
+  openedBy: string | null;
+
+  /**
+   * Name of the segment that the window renders.
+   */
+  segment: string | null;
+}
+
 function getWidgetState(): Widget {
   if (window.__ZEBAR_STATE) {
     return window.__ZEBAR_STATE;
@@ -47,10 +81,99 @@ export function currentWidget(): Widget {
     id: state.id,
     configPath: state.configPath,
     htmlPath: state.htmlPath,
+    segment: state.segment ?? null,
     window: currentWindow(),
   };
 }
 
+/**
+ * Gets the variables from the widget config's `env`, with references to
+ * OS environment variables (e.g. `${USERNAME}`) resolved.
+ *
+ * Unlike the rest of the widget's state, these are only available to
+ * the widget itself.
+ */
+export function getWidgetEnv(): Promise<Record<string, string>> {
+  return desktopCommands.widgetEnv();
+}
+
+/**
+ * Gets the elements to render the widget's segments into, keyed by
+ * segment name.
+ *
+ * If the segments are rendered in a single window (via `singleWebview`),
+ * the body is split into a left, center, and right region, with an
+ * element per segment. Otherwise, only the segment of the current window
+ * is included, with the body as its element.
+ */
+export function getSegmentElements(): Record<string, HTMLElement> {
+  // The raw widget state also includes the widget's config.
+  const state = getWidgetState() as Widget & { config: WidgetConfig };
+  const segments = state.config.segments ?? [];
+
+  if (state.segment) {
+    return { [state.segment]: document.body };
+  }
+
+  // Regions are only created once, in case this is called repeatedly.
+  const container =
+    document.querySelector<HTMLElement>('[data-zebar-segments]') ??
+    createSegmentRegions(segments);
+
+  return Object.fromEntries(
+    segments.map(segment => [
+      segment.name,
+      container.querySelector<HTMLElement>(
+        `[data-zebar-segment="${CSS.escape(segment.name)}"]`,
+      )!,
+    ]),
+  );
+}
+
+/**
+ * Appends a left, center, and right region to the body, containing an
+ * element for each segment.
+ */
+function createSegmentRegions(segments: WidgetSegment[]): HTMLElement {
+  const container = document.createElement('div');
+  container.setAttribute('data-zebar-segments', '');
+  container.style.cssText =
+    'display:grid;grid-template-columns:1fr auto 1fr;width:100%;height:100%;';
+
+  const justify = {
+    left: 'flex-start',
+    center: 'center',
+    right: 'flex-end',
+  };
+
+  for (const [region, justifyContent] of Object.entries(justify)) {
+    const regionElement = document.createElement('div');
+    regionElement.setAttribute('data-zebar-region', region);
+    regionElement.style.cssText = `display:flex;height:100%;justify-content:${justifyContent};`;
+    container.appendChild(regionElement);
+  }
+
+  for (const segment of segments) {
+    // Percentages are relative to the width of the window, same as for
+    // segments that are rendered in separate windows.
+    const width = segment.width.endsWith('%')
+      ? `${parseFloat(segment.width)}vw`
+      : segment.width;
+
+    const element = document.createElement('div');
+    element.setAttribute('data-zebar-segment', segment.name);
+    element.style.cssText = `flex:none;height:100%;width:${width};`;
+
+    container
+      .querySelector(`[data-zebar-region="${segment.region}"]`)!
+      .appendChild(element);
+  }
+
+  document.body.appendChild(container);
+
+  return container;
+}
+
 /**
  * Opens a widget by its config path and chosen placement.
  *
@@ -96,3 +219,72 @@ export async function startWidgetPreset(
 
   return desktopCommands.startPreset(absolutePath, presetName);
 }
+
+/**
+ * Gets a URL for loading a file outside of the widget's own directory
+ * (e.g. a font shared between widgets).
+ *
+ * Path is relative to the widget's directory and must be within the
+ * Zebar config directory.
+ */
+export function resolveAsset(path: string): Promise<string> {
+  return desktopCommands.resolveAsset(path);
+}
+
+/**
+ * Listens for any widget being opened, including the current one.
+ *
+ * Returns a function to stop listening.
+ */
+export function onWidgetOpened(
+  callback: (widget: WidgetState) => void,
+): Promise<UnlistenFn> {
+  return listen<WidgetState>('widget-opened', event =>
+    callback(event.payload),
+  );
+}
+
+/**
+ * Emitted when a widget stops sending heartbeats (e.g. due to hung JS or
+ * a crashed renderer).
+ */
+export interface WidgetUnresponsiveEvent {
+  id: string;
+
+  /**
+   * Time in milliseconds since the last heartbeat.
+   */
+  elapsed: number;
+
+  /**
+   * Whether the widget is being relaunched (via `heartbeat.autoReload`
+   * in the settings).
+   */
+  isReloading: boolean;
+}
+
+/**
+ * Listens for any widget becoming unresponsive.
+ *
+ * Returns a function to stop listening.
+ */
+export function onWidgetUnresponsive(
+  callback: (event: WidgetUnresponsiveEvent) => void,
+): Promise<UnlistenFn> {
+  return listen<WidgetUnresponsiveEvent>('widget-unresponsive', event =>
+    callback(event.payload),
+  );
+}
+
+/**
+ * Listens for any widget being closed.
+ *
+ * Returns a function to stop listening.
+ */
+export function onWidgetClosed(
+  callback: (widget: WidgetState) => void,
+): Promise<UnlistenFn> {
+  return listen<WidgetState>('widget-closed', event =>
+    callback(event.payload),
+  );
+}
@@ -1,9 +1,22 @@
+import type { UnlistenFn } from '@tauri-apps/api/event';
 import { getCurrentWindow } from '@tauri-apps/api/window';
 
 import { desktopCommands } from './desktop-commands';
 
 export type ZOrder = 'bottom_most' | 'top_most' | 'normal';
 
+/**
+ * Lifecycle event of a widget's window. Positions and sizes are in
+ * physical pixels.
+ */
+export type WidgetWindowEvent =
+  | { type: 'focused' }
+  | { type: 'blurred' }
+  | { type: 'moved'; x: number; y: number }
+  | { type: 'resized'; width: number; height: number }
+  | { type: 'monitor_changed'; monitor: string | null }
+  | { type: 'visibility_changed'; visible: boolean };
+
 export interface WidgetWindow {
   /**
    * The underlying Tauri window.
@@ -14,6 +27,16 @@ export interface WidgetWindow {
    * Sets the z-order of the Tauri window.
    */
   setZOrder(zOrder: ZOrder): Promise<void>;
+
+  /**
+   * Listens for lifecycle events of the window (e.g. to pause animations
+   * on blur or re-layout on resize).
+   *
+   * Returns a function to stop listening.
+   */
+  onEvent(
+    callback: (event: WidgetWindowEvent) => void,
+  ): Promise<UnlistenFn>;
 }
 
 /**
@@ -25,6 +48,7 @@ export function currentWindow(): WidgetWindow {
       return getCurrentWindow();
     },
     setZOrder,
+    onEvent,
   };
 }
 
@@ -37,3 +61,12 @@ async function setZOrder(zOrder: ZOrder) {
     await getCurrentWindow().setAlwaysOnTop(false);
   }
 }
+
+function onEvent(
+  callback: (event: WidgetWindowEvent) => void,
+): Promise<UnlistenFn> {
+  return getCurrentWindow().listen<WidgetWindowEvent>(
+    'widget-window-event',
+    event => callback(event.payload),
+  );
+}
@@ -1,6 +1,8 @@
 export * from './config';
 export {
   currentWidget,
+  getWidgetEnv,
+  resolveAsset,
   startWidget,
   startWidgetPreset,
   type Widget,
@@ -1,30 +1,55 @@
 import type { Provider } from '../create-base-provider';
+import type {
+  AudioOutput as GeneratedAudioOutput,
+  DeviceType,
+} from '../provider-types.generated';
+
+export type {
+  AudioDevice,
+  AudioSession,
+} from '../provider-types.generated';
 
 export interface AudioProviderConfig {
   type: 'audio';
+
+  /**
+   * How often to sample the input level of the default recording device
+   * in milliseconds. Input level metering is disabled if not set.
+   *
+   * Only supported on Windows. Note that metering keeps a capture stream
+   * open, so Windows will show the microphone as being in use.
+   */
+  inputLevelInterval?: number;
 }
 
 export type AudioProvider = Provider<AudioProviderConfig, AudioOutput>;
 
-export interface AudioOutput {
-  defaultPlaybackDevice: AudioDevice | null;
-  defaultRecordingDevice: AudioDevice | null;
-  playbackDevices: AudioDevice[];
-  recordingDevices: AudioDevice[];
+export interface AudioOutput extends GeneratedAudioOutput {
   setVolume(volume: number, options?: SetVolumeOptions): Promise<void>;
+
+  /**
+   * Toggles mute on a recording device. Defaults to the default
+   * recording device.
+   */
+  toggleMicMute(options?: ToggleMicMuteOptions): Promise<void>;
+
+  /**
+   * Sets the volume of an app's audio session.
+   */
+  setAppVolume(sessionId: string, volume: number): Promise<void>;
+
+  /**
+   * Mutes or unmutes an app's audio session.
+   */
+  setAppMute(sessionId: string, isMuted: boolean): Promise<void>;
 }
 
 export interface SetVolumeOptions {
   deviceId?: string;
 }
 
-export interface AudioDevice {
-  deviceId: string;
-  name: string;
-  volume: number;
-  type: AudioDeviceType;
-  isDefaultPlayback: boolean;
-  isDefaultRecording: boolean;
+export interface ToggleMicMuteOptions {
+  deviceId?: string;
 }
 
-export type AudioDeviceType = 'playback' | 'recording';
+export type AudioDeviceType = DeviceType;
@@ -7,10 +7,12 @@ import type {
   AudioProvider,
   AudioProviderConfig,
   SetVolumeOptions,
+  ToggleMicMuteOptions,
 } from './audio-provider-types';
 
 const audioProviderConfigSchema = z.object({
   type: z.literal('audio'),
+  inputLevelInterval: z.coerce.number().optional(),
 });
 
 export function createAudioProvider(
@@ -36,6 +38,33 @@ export function createAudioProvider(
                 },
               });
             },
+            toggleMicMute: (options?: ToggleMicMuteOptions) => {
+              return desktopCommands.callProviderFunction(configHash, {
+                type: 'audio',
+                function: {
+                  name: 'toggle_mic_mute',
+                  args: { deviceId: options?.deviceId },
+                },
+              });
+            },
+            setAppVolume: (sessionId: string, volume: number) => {
+              return desktopCommands.callProviderFunction(configHash, {
+                type: 'audio',
+                function: {
+                  name: 'set_app_volume',
+                  args: { sessionId, volume },
+                },
+              });
+            },
+            setAppMute: (sessionId: string, isMuted: boolean) => {
+              return desktopCommands.callProviderFunction(configHash, {
+                type: 'audio',
+                function: {
+                  name: 'set_app_mute',
+                  args: { sessionId, isMuted },
+                },
+              });
+            },
           });
         }
       },
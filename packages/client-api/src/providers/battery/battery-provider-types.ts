@@ -1,12 +1,23 @@
+import type { Duration } from '~/utils';
 import type { Provider } from '../create-base-provider';
+import type { RefreshOptions } from '../refresh-options';
+import type {
+  BatteryOutput as GeneratedBatteryOutput,
+} from '../provider-types.generated';
 
 export interface BatteryProviderConfig {
   type: 'battery';
 
   /**
-   * How often this provider refreshes in milliseconds.
+   * How often this provider refreshes, either in milliseconds or as a
+   * string with a unit (e.g. `'5s'`, `'2m'`, or `'1h'`).
    */
-  refreshInterval?: number;
+  refreshInterval?: Duration;
+
+  /**
+   * Alignment and jitter of refreshes.
+   */
+  refreshOptions?: RefreshOptions;
 }
 
 export type BatteryProvider = Provider<
@@ -14,14 +25,6 @@ export type BatteryProvider = Provider<
   BatteryOutput
 >;
 
-export interface BatteryOutput {
-  chargePercent: number;
-  cycleCount: number;
-  healthPercent: number;
-  powerConsumption: number;
+export interface BatteryOutput extends GeneratedBatteryOutput {
   state: 'discharging' | 'charging' | 'full' | 'empty' | 'unknown';
-  isCharging: boolean;
-  timeTillEmpty: number | null;
-  timeTillFull: number | null;
-  voltage: number | null;
 }
@@ -0,0 +1,26 @@
+import type { Duration } from '~/utils';
+import type { Provider } from '../create-base-provider';
+import type { RefreshOptions } from '../refresh-options';
+import type { CameraOutput } from '../provider-types.generated';
+
+export type {
+  CameraOutput,
+  CameraDevice,
+} from '../provider-types.generated';
+
+export interface CameraProviderConfig {
+  type: 'camera';
+
+  /**
+   * How often this provider refreshes, either in milliseconds or as a
+   * string with a unit (e.g. `'5s'`, `'2m'`, or `'1h'`).
+   */
+  refreshInterval?: Duration;
+
+  /**
+   * Alignment and jitter of refreshes.
+   */
+  refreshOptions?: RefreshOptions;
+}
+
+export type CameraProvider = Provider<CameraProviderConfig, CameraOutput>;
@@ -0,0 +1,33 @@
+import { z } from 'zod';
+
+import { createBaseProvider } from '../create-base-provider';
+import { refreshOptionsSchema } from '../refresh-options';
+import { onProviderEmit } from '~/desktop';
+import { durationSchema } from '~/utils';
+import type {
+  CameraOutput,
+  CameraProvider,
+  CameraProviderConfig,
+} from './camera-provider-types';
+
+const cameraProviderConfigSchema = z.object({
+  type: z.literal('camera'),
+  refreshInterval: durationSchema.default(2 * 1000),
+  refreshOptions: refreshOptionsSchema.optional(),
+});
+
+export function createCameraProvider(
+  config: CameraProviderConfig,
+): CameraProvider {
+  const mergedConfig = cameraProviderConfigSchema.parse(config);
+
+  return createBaseProvider(mergedConfig, async queue => {
+    return onProviderEmit<CameraOutput>(mergedConfig, ({ result }) => {
+      if ('error' in result) {
+        queue.error(result.error);
+      } else {
+        queue.output(result.output);
+      }
+    });
+  });
+}
@@ -0,0 +1,84 @@
+import type { Duration } from '~/utils';
+import type { Provider } from '../create-base-provider';
+import type { RefreshOptions } from '../refresh-options';
+import type { TlsConfig } from '../tls-config';
+import type {
+  CiStatusOutput as GeneratedCiStatusOutput,
+} from '../provider-types.generated';
+
+export type { CiStatus, CiPipeline } from '../provider-types.generated';
+
+export interface CiStatusProviderConfig {
+  type: 'ci_status';
+
+  /**
+   * How often this provider refreshes, either in milliseconds or as a
+   * string with a unit (e.g. `'5s'`, `'2m'`, or `'1h'`).
+   */
+  refreshInterval?: Duration;
+
+  /**
+   * Alignment and jitter of refreshes.
+   */
+  refreshOptions?: RefreshOptions;
+
+  /**
+   * CI service to poll.
+   */
+  backend: CiBackend;
+
+  /**
+   * Base URL of the service, for self-hosted instances. Required for the
+   * `jenkins` backend.
+   */
+  baseUrl?: string;
+
+  /**
+   * Username for Jenkins. Used together with `token` as an API token.
+   */
+  username?: string;
+
+  /**
+   * Access token. Should be a secret reference (e.g.
+   * `secret:github_token`). Widgets can't use a secret reference
+   * together with `baseUrl`.
+   */
+  token?: string;
+
+  /**
+   * Repos (or Jenkins jobs) to get the latest pipeline of.
+   */
+  targets: CiTarget[];
+
+  /**
+   * TLS options for HTTP requests made by the provider.
+   */
+  tls?: TlsConfig;
+}
+
+export type CiStatusProvider = Provider<
+  CiStatusProviderConfig,
+  CiStatusOutput
+>;
+
+export type CiBackend = 'github_actions' | 'gitlab' | 'jenkins';
+
+export interface CiTarget {
+  /**
+   * Repo (e.g. `glzr-io/zebar`), GitLab project path, or Jenkins job
+   * path (e.g. `folder/job`).
+   */
+  repo: string;
+
+  /**
+   * Branch to limit pipelines to. Defaults to any branch.
+   */
+  branch?: string;
+}
+
+export interface CiStatusOutput extends GeneratedCiStatusOutput {
+  /**
+   * Opens the latest pipeline of a target in the browser.
+   */
+  openPipeline(repo: string, branch?: string): Promise<void>;
+}
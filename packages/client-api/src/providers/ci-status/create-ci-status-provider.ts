@@ -0,0 +1,56 @@
+import { z } from 'zod';
+
+import { createBaseProvider } from '../create-base-provider';
+import { refreshOptionsSchema } from '../refresh-options';
+import { tlsConfigSchema } from '../tls-config';
+import { desktopCommands, onProviderEmit } from '~/desktop';
+import { durationSchema } from '~/utils';
+import type {
+  CiStatusOutput,
+  CiStatusProvider,
+  CiStatusProviderConfig,
+} from './ci-status-provider-types';
+
+const ciStatusProviderConfigSchema = z.object({
+  type: z.literal('ci_status'),
+  refreshInterval: durationSchema.default(60 * 1000),
+  refreshOptions: refreshOptionsSchema.optional(),
+  backend: z.enum(['github_actions', 'gitlab', 'jenkins']),
+  baseUrl: z.string().optional(),
+  username: z.string().optional(),
+  token: z.string().optional(),
+  targets: z.array(
+    z.object({
+      repo: z.string(),
+      branch: z.string().optional(),
+    }),
+  ),
+  tls: tlsConfigSchema.optional(),
+});
+
+export function createCiStatusProvider(
+  config: CiStatusProviderConfig,
+): CiStatusProvider {
+  const mergedConfig = ciStatusProviderConfigSchema.parse(config);
+
+  return createBaseProvider(mergedConfig, async queue => {
+    return onProviderEmit<CiStatusOutput>(
+      mergedConfig,
+      ({ configHash, result }) => {
+        if ('error' in result) {
+          queue.error(result.error);
+        } else {
+          queue.output({
+            ...result.output,
+            openPipeline: (repo: string, branch?: string) => {
+              return desktopCommands.callProviderFunction(configHash, {
+                type: 'ci_status',
+                function: { name: 'open_pipeline', args: { repo, branch } },
+              });
+            },
+          });
+        }
+      },
+    );
+  });
+}
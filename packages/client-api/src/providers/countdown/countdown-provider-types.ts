@@ -0,0 +1,52 @@
+import type { Duration } from '~/utils';
+import type { Provider } from '../create-base-provider';
+import type { RefreshOptions } from '../refresh-options';
+import type { CountdownOutput } from '../provider-types.generated';
+
+export type {
+  CountdownOutput,
+  Countdown,
+} from '../provider-types.generated';
+
+export interface CountdownProviderConfig {
+  type: 'countdown';
+
+  /**
+   * How often this provider refreshes, either in milliseconds or as a
+   * string with a unit (e.g. `'5s'`, `'2m'`, or `'1h'`).
+   */
+  refreshInterval?: Duration;
+
+  /**
+   * Alignment and jitter of refreshes.
+   */
+  refreshOptions?: RefreshOptions;
+
+  /**
+   * Events to count down to.
+   */
+  events: CountdownEventConfig[];
+}
+
+export interface CountdownEventConfig {
+  name: string;
+
+  /**
+   * Target date in local time. Supports full dates (`2026-12-25 18:30`,
+   * RFC 3339), relative days (`tomorrow 9am`), month and day
+   * (`Dec 25`), weekdays (`friday 17:00`), and times (`17:30`).
+   */
+  date: string;
+
+  /**
+   * How often the event repeats. Defaults to the recurrence implied by
+   * the date (e.g. `Dec 25` repeats yearly, `friday` weekly, and
+   * `17:30` daily).
+   */
+  recurrence?: 'none' | 'daily' | 'weekly' | 'monthly' | 'yearly';
+}
+
+export type CountdownProvider = Provider<
+  CountdownProviderConfig,
+  CountdownOutput
+>;
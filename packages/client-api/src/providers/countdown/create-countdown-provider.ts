@@ -0,0 +1,42 @@
+import { z } from 'zod';
+
+import { createBaseProvider } from '../create-base-provider';
+import { refreshOptionsSchema } from '../refresh-options';
+import { onProviderEmit } from '~/desktop';
+import { durationSchema } from '~/utils';
+import type {
+  CountdownOutput,
+  CountdownProvider,
+  CountdownProviderConfig,
+} from './countdown-provider-types';
+
+const countdownProviderConfigSchema = z.object({
+  type: z.literal('countdown'),
+  refreshInterval: durationSchema.default(1000),
+  refreshOptions: refreshOptionsSchema.optional(),
+  events: z.array(
+    z.object({
+      name: z.string(),
+      date: z.string(),
+      recurrence: z
+        .enum(['none', 'daily', 'weekly', 'monthly', 'yearly'])
+        .optional(),
+    }),
+  ),
+});
+
+export function createCountdownProvider(
+  config: CountdownProviderConfig,
+): CountdownProvider {
+  const mergedConfig = countdownProviderConfigSchema.parse(config);
+
+  return createBaseProvider(mergedConfig, async queue => {
+    return onProviderEmit<CountdownOutput>(mergedConfig, ({ result }) => {
+      if ('error' in result) {
+        queue.error(result.error);
+      } else {
+        queue.output(result.output);
+      }
+    });
+  });
+}
@@ -1,20 +1,23 @@
+import type { Duration } from '~/utils';
 import type { Provider } from '../create-base-provider';
+import type { RefreshOptions } from '../refresh-options';
+import type { CpuOutput } from '../provider-types.generated';
+
+export type { CpuOutput } from '../provider-types.generated';
 
 export interface CpuProviderConfig {
   type: 'cpu';
 
   /**
-   * How often this provider refreshes in milliseconds.
+   * How often this provider refreshes, either in milliseconds or as a
+   * string with a unit (e.g. `'5s'`, `'2m'`, or `'1h'`).
+   */
+  refreshInterval?: Duration;
+
+  /**
+   * Alignment and jitter of refreshes.
    */
-  refreshInterval?: number;
+  refreshOptions?: RefreshOptions;
 }
 
 export type CpuProvider = Provider<CpuProviderConfig, CpuOutput>;
-
-export interface CpuOutput {
-  frequency: number;
-  usage: number;
-  logicalCoreCount: number;
-  physicalCoreCount: number;
-  vendor: string;
-}
@@ -65,6 +65,131 @@ import type {
   DiskProvider,
   DiskProviderConfig,
 } from './disk/disk-provider-types';
+import { createPrinterProvider } from './printer/create-printer-provider';
+import type {
+  PrinterProviderConfig,
+  PrinterProvider,
+} from './printer/printer-provider-types';
+import { createMeetingProvider } from './meeting/create-meeting-provider';
+import type {
+  MeetingProviderConfig,
+  MeetingProvider,
+} from './meeting/meeting-provider-types';
+import { createVpnProvider } from './vpn/create-vpn-provider';
+import type {
+  VpnProviderConfig,
+  VpnProvider,
+} from './vpn/vpn-provider-types';
+import { createHyprlandProvider } from './hyprland/create-hyprland-provider';
+import type {
+  HyprlandProviderConfig,
+  HyprlandProvider,
+} from './hyprland/hyprland-provider-types';
+import { createI3Provider } from './i3/create-i3-provider';
+import type {
+  I3ProviderConfig,
+  I3Provider,
+} from './i3/i3-provider-types';
+import { createVirtualDesktopProvider } from './virtual-desktop/create-virtual-desktop-provider';
+import type {
+  VirtualDesktopProviderConfig,
+  VirtualDesktopProvider,
+} from './virtual-desktop/virtual-desktop-provider-types';
+import { createWorkspacesProvider } from './workspaces/create-workspaces-provider';
+import type {
+  WorkspacesProviderConfig,
+  WorkspacesProvider,
+} from './workspaces/workspaces-provider-types';
+import { createKeepAwakeProvider } from './keep-awake/create-keep-awake-provider';
+import type {
+  KeepAwakeProviderConfig,
+  KeepAwakeProvider,
+} from './keep-awake/keep-awake-provider-types';
+import { createSessionProvider } from './session/create-session-provider';
+import type {
+  SessionProviderConfig,
+  SessionProvider,
+} from './session/session-provider-types';
+import { createCountdownProvider } from './countdown/create-countdown-provider';
+import type {
+  CountdownProviderConfig,
+  CountdownProvider,
+} from './countdown/countdown-provider-types';
+import { createTimerProvider } from './timer/create-timer-provider';
+import type {
+  TimerProviderConfig,
+  TimerProvider,
+} from './timer/timer-provider-types';
+import { createPerfCountersProvider } from './perf-counters/create-perf-counters-provider';
+import type {
+  PerfCountersProviderConfig,
+  PerfCountersProvider,
+} from './perf-counters/perf-counters-provider-types';
+import { createEventLogProvider } from './event-log/create-event-log-provider';
+import type {
+  EventLogProviderConfig,
+  EventLogProvider,
+} from './event-log/event-log-provider-types';
+import { createInputStatsProvider } from './input-stats/create-input-stats-provider';
+import type {
+  InputStatsProviderConfig,
+  InputStatsProvider,
+} from './input-stats/input-stats-provider-types';
+import { createFpsProvider } from './fps/create-fps-provider';
+import type {
+  FpsProviderConfig,
+  FpsProvider,
+} from './fps/fps-provider-types';
+import { createFancyZonesProvider } from './fancy-zones/create-fancy-zones-provider';
+import type {
+  FancyZonesProviderConfig,
+  FancyZonesProvider,
+} from './fancy-zones/fancy-zones-provider-types';
+import { createSpotifyProvider } from './spotify/create-spotify-provider';
+import type {
+  SpotifyProviderConfig,
+  SpotifyProvider,
+} from './spotify/spotify-provider-types';
+import { createDiscordProvider } from './discord/create-discord-provider';
+import type {
+  DiscordProviderConfig,
+  DiscordProvider,
+} from './discord/discord-provider-types';
+import { createSteamProvider } from './steam/create-steam-provider';
+import type {
+  SteamProviderConfig,
+  SteamProvider,
+} from './steam/steam-provider-types';
+import { createTasksProvider } from './tasks/create-tasks-provider';
+import type {
+  TasksProviderConfig,
+  TasksProvider,
+} from './tasks/tasks-provider-types';
+import { createCiStatusProvider } from './ci-status/create-ci-status-provider';
+import type {
+  CiStatusProviderConfig,
+  CiStatusProvider,
+} from './ci-status/ci-status-provider-types';
+import { createSshGpgProvider } from './ssh-gpg/create-ssh-gpg-provider';
+import type {
+  SshGpgProviderConfig,
+  SshGpgProvider,
+} from './ssh-gpg/ssh-gpg-provider-types';
+import { createPeripheralsProvider } from './peripherals/create-peripherals-provider';
+import type {
+  PeripheralsProviderConfig,
+  PeripheralsProvider,
+} from './peripherals/peripherals-provider-types';
+import { createCameraProvider } from './camera/create-camera-provider';
+import type {
+  CameraProviderConfig,
+  CameraProvider,
+} from './camera/camera-provider-types';
+import { createDerivedProvider } from './derived/create-derived-provider';
+import type {
+  DerivedProviderConfig,
+  DerivedProvider,
+} from './derived/derived-provider-types';
 
 export interface ProviderConfigMap {
   audio: AudioProviderConfig;
@@ -81,6 +206,31 @@ export interface ProviderConfigMap {
   weather: WeatherProviderConfig;
   keyboard: KeyboardProviderConfig;
   disk: DiskProviderConfig;
+  printer: PrinterProviderConfig;
+  meeting: MeetingProviderConfig;
+  vpn: VpnProviderConfig;
+  hyprland: HyprlandProviderConfig;
+  i3: I3ProviderConfig;
+  virtual_desktop: VirtualDesktopProviderConfig;
+  workspaces: WorkspacesProviderConfig;
+  keep_awake: KeepAwakeProviderConfig;
+  session: SessionProviderConfig;
+  countdown: CountdownProviderConfig;
+  timer: TimerProviderConfig;
+  perf_counters: PerfCountersProviderConfig;
+  event_log: EventLogProviderConfig;
+  input_stats: InputStatsProviderConfig;
+  fps: FpsProviderConfig;
+  fancy_zones: FancyZonesProviderConfig;
+  spotify: SpotifyProviderConfig;
+  discord: DiscordProviderConfig;
+  steam: SteamProviderConfig;
+  tasks: TasksProviderConfig;
+  ci_status: CiStatusProviderConfig;
+  ssh_gpg: SshGpgProviderConfig;
+  peripherals: PeripheralsProviderConfig;
+  camera: CameraProviderConfig;
+  derived: DerivedProviderConfig;
 }
 
 export interface ProviderMap {
@@ -98,6 +248,31 @@ export interface ProviderMap {
   weather: WeatherProvider;
   keyboard: KeyboardProvider;
   disk: DiskProvider;
+  printer: PrinterProvider;
+  meeting: MeetingProvider;
+  vpn: VpnProvider;
+  hyprland: HyprlandProvider;
+  i3: I3Provider;
+  virtual_desktop: VirtualDesktopProvider;
+  workspaces: WorkspacesProvider;
+  keep_awake: KeepAwakeProvider;
+  session: SessionProvider;
+  countdown: CountdownProvider;
+  timer: TimerProvider;
+  perf_counters: PerfCountersProvider;
+  event_log: EventLogProvider;
+  input_stats: InputStatsProvider;
+  fps: FpsProvider;
+  fancy_zones: FancyZonesProvider;
+  spotify: SpotifyProvider;
+  discord: DiscordProvider;
+  steam: SteamProvider;
+  tasks: TasksProvider;
+  ci_status: CiStatusProvider;
+  ssh_gpg: SshGpgProvider;
+  peripherals: PeripheralsProvider;
+  camera: CameraProvider;
+  derived: DerivedProvider;
 }
 
 export type ProviderType = keyof ProviderConfigMap;
@@ -149,6 +324,56 @@ export function createProvider<T extends ProviderConfig>(
       return createKeyboardProvider(config) as any;
     case 'disk':
       return createDiskProvider(config) as any;
+    case 'printer':
+      return createPrinterProvider(config) as any;
+    case 'meeting':
+      return createMeetingProvider(config) as any;
+    case 'vpn':
+      return createVpnProvider(config) as any;
+    case 'hyprland':
+      return createHyprlandProvider(config) as any;
+    case 'i3':
+      return createI3Provider(config) as any;
+    case 'virtual_desktop':
+      return createVirtualDesktopProvider(config) as any;
+    case 'workspaces':
+      return createWorkspacesProvider(config) as any;
+    case 'keep_awake':
+      return createKeepAwakeProvider(config) as any;
+    case 'session':
+      return createSessionProvider(config) as any;
+    case 'countdown':
+      return createCountdownProvider(config) as any;
+    case 'timer':
+      return createTimerProvider(config) as any;
+    case 'perf_counters':
+      return createPerfCountersProvider(config) as any;
+    case 'event_log':
+      return createEventLogProvider(config) as any;
+    case 'input_stats':
+      return createInputStatsProvider(config) as any;
+    case 'fps':
+      return createFpsProvider(config) as any;
+    case 'fancy_zones':
+      return createFancyZonesProvider(config) as any;
+    case 'spotify':
+      return createSpotifyProvider(config) as any;
+    case 'discord':
+      return createDiscordProvider(config) as any;
+    case 'steam':
+      return createSteamProvider(config) as any;
+    case 'tasks':
+      return createTasksProvider(config) as any;
+    case 'ci_status':
+      return createCiStatusProvider(config) as any;
+    case 'ssh_gpg':
+      return createSshGpgProvider(config) as any;
+    case 'peripherals':
+      return createPeripheralsProvider(config) as any;
+    case 'camera':
+      return createCameraProvider(config) as any;
+    case 'derived':
+      return createDerivedProvider(config) as any;
     default:
       throw new Error('Not a supported provider type.');
   }
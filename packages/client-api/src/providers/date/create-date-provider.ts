@@ -1,7 +1,9 @@
 import { DateTime } from 'luxon';
 import { z } from 'zod';
 
+import { durationSchema } from '~/utils';
 import { createBaseProvider } from '../create-base-provider';
+import { refreshOptionsSchema } from '../refresh-options';
 import type {
   DateProvider,
   DateProviderConfig,
@@ -9,7 +11,8 @@ import type {
 
 const dateProviderConfigSchema = z.object({
   type: z.literal('date'),
-  refreshInterval: z.coerce.number().default(1000),
+  refreshInterval: durationSchema.default(1000),
+  refreshOptions: refreshOptionsSchema.optional(),
   timezone: z.string().default('local'),
   locale: z.string().optional(),
   formatting: z.string().default('EEE	d MMM t'),
@@ -23,10 +26,24 @@ export function createDateProvider(
   return createBaseProvider(mergedConfig, async queue => {
     queue.output(getDateValue());
 
-    const interval = setInterval(
-      () => queue.output(getDateValue()),
-      mergedConfig.refreshInterval,
-    );
+    let timeout = scheduleRefresh();
+
+    function scheduleRefresh() {
+      const { refreshInterval, refreshOptions } = mergedConfig;
+
+      // When aligned, refresh on the next multiple of the interval (e.g.
+      // exactly on the minute).
+      const delay = refreshOptions?.align
+        ? refreshInterval - (Date.now() % refreshInterval)
+        : refreshInterval;
+
+      const jitter = Math.random() * (refreshOptions?.jitter ?? 0);
+
+      return setTimeout(() => {
+        queue.output(getDateValue());
+        timeout = scheduleRefresh();
+      }, delay + jitter);
+    }
 
     function getDateValue() {
       const dateTime = DateTime.now().setZone(mergedConfig.timezone);
@@ -42,7 +59,7 @@ export function createDateProvider(
     }
 
     return () => {
-      clearInterval(interval);
+      clearTimeout(timeout);
     };
   });
 }
@@ -1,12 +1,20 @@
+import type { Duration } from '~/utils';
 import type { Provider } from '../create-base-provider';
+import type { RefreshOptions } from '../refresh-options';
 
 export interface DateProviderConfig {
   type: 'date';
 
   /**
-   * How often this provider refreshes in milliseconds.
+   * How often this provider refreshes, either in milliseconds or as a
+   * string with a unit (e.g. `'5s'`, `'2m'`, or `'1h'`).
    */
-  refreshInterval?: number;
+  refreshInterval?: Duration;
+
+  /**
+   * Alignment and jitter of refreshes.
+   */
+  refreshOptions?: RefreshOptions;
 
   /**
    * Either a UTC offset (eg. `UTC+8`) or an IANA timezone (eg.
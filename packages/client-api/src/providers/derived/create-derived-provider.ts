@@ -0,0 +1,31 @@
+import { z } from 'zod';
+
+import { createBaseProvider } from '../create-base-provider';
+import { onProviderEmit } from '~/desktop';
+import type {
+  DerivedOutput,
+  DerivedProvider,
+  DerivedProviderConfig,
+} from './derived-provider-types';
+
+const derivedProviderConfigSchema = z.object({
+  type: z.literal('derived'),
+  providers: z.record(z.object({ type: z.string() }).passthrough()),
+  values: z.record(z.string()),
+});
+
+export function createDerivedProvider(
+  config: DerivedProviderConfig,
+): DerivedProvider {
+  const mergedConfig = derivedProviderConfigSchema.parse(config);
+
+  return createBaseProvider(mergedConfig, async queue => {
+    return onProviderEmit<DerivedOutput>(mergedConfig, ({ result }) => {
+      if ('error' in result) {
+        queue.error(result.error);
+      } else {
+        queue.output(result.output);
+      }
+    });
+  });
+}
@@ -0,0 +1,44 @@
+import type { Provider } from '../create-base-provider';
+import type { ProviderConfig } from '../create-provider';
+
+export interface DerivedProviderConfig {
+  type: 'derived';
+
+  /**
+   * Providers to derive values from, keyed by the name used to reference
+   * their output in expressions.
+   *
+   * Source configs are sent to the backend as-is, so options that
+   * otherwise have defaults (e.g. `refreshInterval`) must be set.
+   * Providers that only run in the frontend (e.g. `date`) can't be used.
+   */
+  providers: Record<string, ProviderConfig>;
+
+  /**
+   * Expressions to evaluate, keyed by the name of the output field.
+   *
+   * Expressions can reference source outputs by path (e.g.
+   * `cpu.usage`), where `[]` selects every item of an array (e.g.
+   * `network.interfaces[].receivedRate`). Supports arithmetic,
+   * comparisons, `&&`, `||`, `!`, ternaries (`a ? b : c`), and the
+   * functions `sum`, `avg`, `min`, `max`, `count`, `round`, `floor`,
+   * `ceil`, and `abs`.
+   *
+   * @example
+   * ```ts
+   * {
+   *   networkTotal: 'sum(network.interfaces[].receivedRate)',
+   *   cpuState: "cpu.usage > 80 ? 'high' : 'normal'",
+   * }
+   * ```
+   */
+  values: Record<string, string>;
+}
+
+export type DerivedProvider = Provider<DerivedProviderConfig, DerivedOutput>;
+
+/**
+ * Evaluated values, keyed by their name in `values`. Values are `null`
+ * until the sources they reference have emitted.
+ */
+export type DerivedOutput = Record<string, unknown>;
@@ -0,0 +1,64 @@
+import { z } from 'zod';
+
+import { createBaseProvider } from '../create-base-provider';
+import { refreshOptionsSchema } from '../refresh-options';
+import { tlsConfigSchema } from '../tls-config';
+import {
+  desktopCommands,
+  onProviderEmit,
+  type DiscordFunction,
+} from '~/desktop';
+import { durationSchema } from '~/utils';
+import type {
+  DiscordOutput,
+  DiscordProvider,
+  DiscordProviderConfig,
+} from './discord-provider-types';
+
+const discordProviderConfigSchema = z.object({
+  type: z.literal('discord'),
+  refreshInterval: durationSchema.default(2 * 1000),
+  refreshOptions: refreshOptionsSchema.optional(),
+  clientId: z.string(),
+  clientSecret: z.string(),
+  tls: tlsConfigSchema.optional(),
+});
+
+export function createDiscordProvider(
+  config: DiscordProviderConfig,
+): DiscordProvider {
+  const mergedConfig = discordProviderConfigSchema.parse(config);
+
+  return createBaseProvider(mergedConfig, async queue => {
+    return onProviderEmit<DiscordOutput>(
+      mergedConfig,
+      ({ configHash, result }) => {
+        if ('error' in result) {
+          queue.error(result.error);
+          return;
+        }
+
+        const call = (fn: DiscordFunction['function']) => {
+          return desktopCommands.callProviderFunction(configHash, {
+            type: 'discord',
+            function: fn,
+          });
+        };
+
+        queue.output({
+          ...result.output,
+          authorize: () => call({ name: 'authorize' }),
+          logout: () => call({ name: 'logout' }),
+          toggleMute: () => call({ name: 'toggle_mute' }),
+          toggleDeafen: () => call({ name: 'toggle_deafen' }),
+          setMute: (isMuted: boolean) => {
+            return call({ name: 'set_mute', args: { isMuted } });
+          },
+          setDeafen: (isDeafened: boolean) => {
+            return call({ name: 'set_deafen', args: { isDeafened } });
+          },
+        });
+      },
+    );
+  });
+}
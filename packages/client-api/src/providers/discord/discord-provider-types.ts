@@ -0,0 +1,62 @@
+import type { Duration } from '~/utils';
+import type { Provider } from '../create-base-provider';
+import type { RefreshOptions } from '../refresh-options';
+import type { TlsConfig } from '../tls-config';
+import type {
+  DiscordOutput as GeneratedDiscordOutput,
+} from '../provider-types.generated';
+
+export type {
+  DiscordUser,
+  DiscordVoiceChannel,
+  DiscordVoiceMember,
+} from '../provider-types.generated';
+
+export interface DiscordProviderConfig {
+  type: 'discord';
+
+  /**
+   * How often this provider refreshes, either in milliseconds or as a
+   * string with a unit (e.g. `'5s'`, `'2m'`, or `'1h'`).
+   */
+  refreshInterval?: Duration;
+
+  /**
+   * Alignment and jitter of refreshes.
+   */
+  refreshOptions?: RefreshOptions;
+
+  /**
+   * Client ID of an app created in the Discord developer portal.
+   */
+  clientId: string;
+
+  /**
+   * Client secret of the app. Should be a secret reference (e.g.
+   * `secret:discord_client_secret`).
+   */
+  clientSecret: string;
+
+  /**
+   * TLS options for HTTP requests made by the provider.
+   */
+  tls?: TlsConfig;
+}
+
+export type DiscordProvider = Provider<
+  DiscordProviderConfig,
+  DiscordOutput
+>;
+
+export interface DiscordOutput extends GeneratedDiscordOutput {
+  /**
+   * Shows an authorization prompt in the Discord client. Resolves once
+   * access has been granted.
+   */
+  authorize(): Promise<void>;
+  logout(): Promise<void>;
+  toggleMute(): Promise<void>;
+  toggleDeafen(): Promise<void>;
+  setMute(isMuted: boolean): Promise<void>;
+  setDeafen(isDeafened: boolean): Promise<void>;
+}
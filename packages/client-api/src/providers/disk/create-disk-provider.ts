@@ -1,7 +1,9 @@
 import { z } from 'zod';
 
 import { createBaseProvider } from '../create-base-provider';
+import { refreshOptionsSchema } from '../refresh-options';
 import { onProviderEmit } from '~/desktop';
+import { durationSchema } from '~/utils';
 import type {
   DiskOutput,
   DiskProvider,
@@ -10,7 +12,14 @@ import type {
 
 const diskProviderConfigSchema = z.object({
   type: z.literal('disk'),
-  refreshInterval: z.coerce.number().default(60 * 1000),
+  refreshInterval: durationSchema.default(60 * 1000),
+  refreshOptions: refreshOptionsSchema.optional(),
+  includeMountPoints: z.array(z.string()).default([]),
+  excludeMountPoints: z.array(z.string()).default([]),
+  includeFileSystems: z.array(z.string()).default([]),
+  excludeFileSystems: z.array(z.string()).default([]),
+  excludeRemovable: z.boolean().default(false),
+  includeSmart: z.boolean().default(false),
 });
 
 export function createDiskProvider(
@@ -1,27 +1,61 @@
-import type { DataSizeMeasure } from '~/utils';
+import type { Duration } from '~/utils';
 import type { Provider } from '../create-base-provider';
+import type { RefreshOptions } from '../refresh-options';
+import type { DiskOutput } from '../provider-types.generated';
+
+export type {
+  Disk,
+  PhysicalDiskHealth,
+  DiskOutput,
+} from '../provider-types.generated';
 
 export interface DiskProviderConfig {
   type: 'disk';
 
   /**
-   * How often this provider refreshes in milliseconds.
+   * How often this provider refreshes, either in milliseconds or as a
+   * string with a unit (e.g. `'5s'`, `'2m'`, or `'1h'`).
    */
-  refreshInterval?: number;
-}
+  refreshInterval?: Duration;
 
-export type DiskProvider = Provider<DiskProviderConfig, DiskOutput>;
+  /**
+   * Alignment and jitter of refreshes.
+   */
+  refreshOptions?: RefreshOptions;
 
-export interface Disk {
-  name: string | null;
-  fileSystem: string;
-  mountPoint: string;
-  totalSpace: DataSizeMeasure;
-  availableSpace: DataSizeMeasure;
-  isRemovable: boolean;
-  driveType: string;
-}
+  /**
+   * Glob patterns of mount points to include. All mount points are
+   * included if empty.
+   */
+  includeMountPoints?: string[];
+
+  /**
+   * Glob patterns of mount points to exclude.
+   */
+  excludeMountPoints?: string[];
+
+  /**
+   * Glob patterns of file system types to include (e.g. `NTFS`, `ext4`).
+   * All file systems are included if empty.
+   */
+  includeFileSystems?: string[];
+
+  /**
+   * Glob patterns of file system types to exclude (e.g. `overlay`, `9p`).
+   */
+  excludeFileSystems?: string[];
+
+  /**
+   * Whether to exclude removable disks. Defaults to `false`.
+   */
+  excludeRemovable?: boolean;
 
-export interface DiskOutput {
-  disks: Disk[];
+  /**
+   * Whether to include S.M.A.R.T. health data for physical disks.
+   * Requires `smartctl` to be installed and usually needs elevated
+   * permissions. Defaults to `false`.
+   */
+  includeSmart?: boolean;
 }
+
+export type DiskProvider = Provider<DiskProviderConfig, DiskOutput>;
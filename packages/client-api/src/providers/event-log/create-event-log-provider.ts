@@ -0,0 +1,40 @@
+import { z } from 'zod';
+
+import { createBaseProvider } from '../create-base-provider';
+import { refreshOptionsSchema } from '../refresh-options';
+import { onProviderEmit } from '~/desktop';
+import { durationSchema } from '~/utils';
+import type {
+  EventLogOutput,
+  EventLogProvider,
+  EventLogProviderConfig,
+} from './event-log-provider-types';
+
+const eventLogProviderConfigSchema = z.object({
+  type: z.literal('event_log'),
+  refreshInterval: durationSchema.default(5 * 1000),
+  refreshOptions: refreshOptionsSchema.optional(),
+  channels: z.array(z.string()).default(['System', 'Application']),
+  minLevel: z
+    .enum(['critical', 'error', 'warning', 'info', 'debug'])
+    .optional(),
+  filter: z.string().optional(),
+  maxAge: z.coerce.number().default(60 * 60 * 1000),
+  maxEntries: z.coerce.number().default(50),
+});
+
+export function createEventLogProvider(
+  config: EventLogProviderConfig,
+): EventLogProvider {
+  const mergedConfig = eventLogProviderConfigSchema.parse(config);
+
+  return createBaseProvider(mergedConfig, async queue => {
+    return onProviderEmit<EventLogOutput>(mergedConfig, ({ result }) => {
+      if ('error' in result) {
+        queue.error(result.error);
+      } else {
+        queue.output(result.output);
+      }
+    });
+  });
+}
@@ -0,0 +1,64 @@
+import type { Duration } from '~/utils';
+import type { Provider } from '../create-base-provider';
+import type { RefreshOptions } from '../refresh-options';
+import type {
+  EventLevel,
+  EventLogOutput,
+} from '../provider-types.generated';
+
+export type {
+  EventLevel,
+  EventLogOutput,
+  EventLogEntry,
+} from '../provider-types.generated';
+
+export interface EventLogProviderConfig {
+  type: 'event_log';
+
+  /**
+   * How often to check for new entries, either in milliseconds or as a
+   * string with a unit (e.g. `'5s'`).
+   */
+  refreshInterval?: Duration;
+
+  /**
+   * Alignment and jitter of refreshes.
+   */
+  refreshOptions?: RefreshOptions;
+
+  /**
+   * Windows Event Log channels to watch. Ignored on Linux, where the
+   * systemd journal is watched. Defaults to `['System', 'Application']`.
+   */
+  channels?: string[];
+
+  /**
+   * Least severe level of entries to include. All levels are included
+   * by default.
+   */
+  minLevel?: EventLevel;
+
+  /**
+   * Additional platform-specific filter. On Windows, an XPath query
+   * (e.g. `*[System[Provider[@Name='disk']]]`), which replaces the
+   * default query for `minLevel` and `maxAge`. On Linux, `journalctl`
+   * matches (e.g. `_SYSTEMD_UNIT=sshd.service`).
+   */
+  filter?: string;
+
+  /**
+   * How far back to include entries in milliseconds. Defaults to 1
+   * hour.
+   */
+  maxAge?: number;
+
+  /**
+   * Max number of entries to output. Defaults to 50.
+   */
+  maxEntries?: number;
+}
+
+export type EventLogProvider = Provider<
+  EventLogProviderConfig,
+  EventLogOutput
+>;
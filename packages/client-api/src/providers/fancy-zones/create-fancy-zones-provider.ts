@@ -0,0 +1,36 @@
+import { z } from 'zod';
+
+import { createBaseProvider } from '../create-base-provider';
+import { refreshOptionsSchema } from '../refresh-options';
+import { onProviderEmit } from '~/desktop';
+import { durationSchema } from '~/utils';
+import type {
+  FancyZonesOutput,
+  FancyZonesProvider,
+  FancyZonesProviderConfig,
+} from './fancy-zones-provider-types';
+
+const fancyZonesProviderConfigSchema = z.object({
+  type: z.literal('fancy_zones'),
+  refreshInterval: durationSchema.default(1000),
+  refreshOptions: refreshOptionsSchema.optional(),
+});
+
+export function createFancyZonesProvider(
+  config: FancyZonesProviderConfig,
+): FancyZonesProvider {
+  const mergedConfig = fancyZonesProviderConfigSchema.parse(config);
+
+  return createBaseProvider(mergedConfig, async queue => {
+    return onProviderEmit<FancyZonesOutput>(
+      mergedConfig,
+      ({ result }) => {
+        if ('error' in result) {
+          queue.error(result.error);
+        } else {
+          queue.output(result.output);
+        }
+      },
+    );
+  });
+}
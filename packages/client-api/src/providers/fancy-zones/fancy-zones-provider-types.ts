@@ -0,0 +1,59 @@
+import type { Duration } from '~/utils';
+import type { Provider } from '../create-base-provider';
+import type { RefreshOptions } from '../refresh-options';
+import type {
+  FancyZonesOutput as GeneratedFancyZonesOutput,
+  FancyZonesLayout as GeneratedFancyZonesLayout,
+  ZonedWindow as GeneratedZonedWindow,
+} from '../provider-types.generated';
+
+export interface FancyZonesProviderConfig {
+  type: 'fancy_zones';
+
+  /**
+   * How often this provider refreshes, either in milliseconds or as a
+   * string with a unit (e.g. `'5s'`, `'2m'`, or `'1h'`).
+   */
+  refreshInterval?: Duration;
+
+  /**
+   * Alignment and jitter of refreshes.
+   */
+  refreshOptions?: RefreshOptions;
+}
+
+export type FancyZonesProvider = Provider<
+  FancyZonesProviderConfig,
+  FancyZonesOutput
+>;
+
+export interface FancyZonesOutput extends GeneratedFancyZonesOutput {
+  /**
+   * Layouts applied to each monitor and virtual desktop.
+   */
+  layouts: FancyZonesLayout[];
+
+  /**
+   * The window that currently has focus, excluding Zebar's own windows
+   * and the desktop.
+   */
+  focusedWindow: ZonedWindow | null;
+}
+
+export interface FancyZonesLayout extends GeneratedFancyZonesLayout {
+  layoutType:
+    | 'blank'
+    | 'focus'
+    | 'columns'
+    | 'rows'
+    | 'grid'
+    | 'priority-grid'
+    | 'custom';
+}
+
+export interface ZonedWindow extends GeneratedZonedWindow {
+  /**
+   * FancyZones layout that `zones` belong to.
+   */
+  layout: FancyZonesLayout | null;
+}
@@ -0,0 +1,34 @@
+import { z } from 'zod';
+
+import { createBaseProvider } from '../create-base-provider';
+import { refreshOptionsSchema } from '../refresh-options';
+import { onProviderEmit } from '~/desktop';
+import { durationSchema } from '~/utils';
+import type {
+  FpsOutput,
+  FpsProvider,
+  FpsProviderConfig,
+} from './fps-provider-types';
+
+const fpsProviderConfigSchema = z.object({
+  type: z.literal('fps'),
+  refreshInterval: durationSchema.default(1000),
+  refreshOptions: refreshOptionsSchema.optional(),
+  presentMonPath: z.string().default('PresentMon'),
+  fullscreenOnly: z.boolean().default(true),
+  sampleWindow: z.coerce.number().default(5 * 1000),
+});
+
+export function createFpsProvider(config: FpsProviderConfig): FpsProvider {
+  const mergedConfig = fpsProviderConfigSchema.parse(config);
+
+  return createBaseProvider(mergedConfig, async queue => {
+    return onProviderEmit<FpsOutput>(mergedConfig, ({ result }) => {
+      if ('error' in result) {
+        queue.error(result.error);
+      } else {
+        queue.output(result.output);
+      }
+    });
+  });
+}
@@ -0,0 +1,49 @@
+import type { Duration } from '~/utils';
+import type { Provider } from '../create-base-provider';
+import type { RefreshOptions } from '../refresh-options';
+import type { FpsOutput } from '../provider-types.generated';
+
+export type { FpsOutput } from '../provider-types.generated';
+
+/**
+ * Frame statistics of the focused app via PresentMon. Only available on
+ * Windows.
+ *
+ * PresentMon must be installed separately, and requires administrator
+ * rights or membership in the Performance Log Users group.
+ */
+export interface FpsProviderConfig {
+  type: 'fps';
+
+  /**
+   * How often this provider refreshes, either in milliseconds or as a
+   * string with a unit (e.g. `'5s'`, `'2m'`, or `'1h'`).
+   */
+  refreshInterval?: Duration;
+
+  /**
+   * Alignment and jitter of refreshes.
+   */
+  refreshOptions?: RefreshOptions;
+
+  /**
+   * Path to the PresentMon executable (e.g.
+   * `C:\Tools\PresentMon-2.3.0-x64.exe`). Defaults to `PresentMon` on
+   * the `PATH`.
+   */
+  presentMonPath?: string;
+
+  /**
+   * Whether to only measure the focused app if it's fullscreen. Defaults
+   * to `true`.
+   */
+  fullscreenOnly?: boolean;
+
+  /**
+   * Duration in milliseconds of recent frames to calculate stats over.
+   * Defaults to 5 seconds.
+   */
+  sampleWindow?: number;
+}
+
+export type FpsProvider = Provider<FpsProviderConfig, FpsOutput>;
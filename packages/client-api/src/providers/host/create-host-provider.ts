@@ -1,7 +1,9 @@
 import { z } from 'zod';
 
 import { createBaseProvider } from '../create-base-provider';
+import { refreshOptionsSchema } from '../refresh-options';
 import { onProviderEmit } from '~/desktop';
+import { durationSchema } from '~/utils';
 import type {
   HostOutput,
   HostProvider,
@@ -10,7 +12,8 @@ import type {
 
 const hostProviderConfigSchema = z.object({
   type: z.literal('host'),
-  refreshInterval: z.coerce.number().default(60 * 1000),
+  refreshInterval: durationSchema.default(60 * 1000),
+  refreshOptions: refreshOptionsSchema.optional(),
 });
 
 export function createHostProvider(
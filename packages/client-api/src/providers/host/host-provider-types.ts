@@ -1,21 +1,23 @@
+import type { Duration } from '~/utils';
 import type { Provider } from '../create-base-provider';
+import type { RefreshOptions } from '../refresh-options';
+import type { HostOutput } from '../provider-types.generated';
+
+export type { HostOutput } from '../provider-types.generated';
 
 export interface HostProviderConfig {
   type: 'host';
 
   /**
-   * How often this provider refreshes in milliseconds.
+   * How often this provider refreshes, either in milliseconds or as a
+   * string with a unit (e.g. `'5s'`, `'2m'`, or `'1h'`).
+   */
+  refreshInterval?: Duration;
+
+  /**
+   * Alignment and jitter of refreshes.
    */
-  refreshInterval?: number;
+  refreshOptions?: RefreshOptions;
 }
 
 export type HostProvider = Provider<HostProviderConfig, HostOutput>;
-
-export interface HostOutput {
-  hostname: string | null;
-  osName: string | null;
-  osVersion: string | null;
-  friendlyOsVersion: string | null;
-  bootTime: number;
-  uptime: number;
-}
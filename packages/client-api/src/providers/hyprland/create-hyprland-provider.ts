@@ -0,0 +1,43 @@
+import { z } from 'zod';
+
+import { createBaseProvider } from '../create-base-provider';
+import { desktopCommands, onProviderEmit } from '~/desktop';
+import type {
+  HyprlandOutput,
+  HyprlandProvider,
+  HyprlandProviderConfig,
+} from './hyprland-provider-types';
+
+const hyprlandProviderConfigSchema = z.object({
+  type: z.literal('hyprland'),
+});
+
+export function createHyprlandProvider(
+  config: HyprlandProviderConfig,
+): HyprlandProvider {
+  const mergedConfig = hyprlandProviderConfigSchema.parse(config);
+
+  return createBaseProvider(mergedConfig, async queue => {
+    return onProviderEmit<HyprlandOutput>(
+      mergedConfig,
+      ({ configHash, result }) => {
+        if ('error' in result) {
+          queue.error(result.error);
+        } else {
+          queue.output({
+            ...result.output,
+            dispatch: (dispatcher: string, args?: string) => {
+              return desktopCommands.callProviderFunction(configHash, {
+                type: 'hyprland',
+                function: {
+                  name: 'dispatch',
+                  args: { dispatcher, args },
+                },
+              });
+            },
+          });
+        }
+      },
+    );
+  });
+}
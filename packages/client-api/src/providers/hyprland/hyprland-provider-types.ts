@@ -0,0 +1,26 @@
+import type { Provider } from '../create-base-provider';
+import type {
+  HyprlandOutput as GeneratedHyprlandOutput,
+} from '../provider-types.generated';
+
+export type {
+  HyprlandMonitor,
+  HyprlandWorkspace,
+  HyprlandWindow,
+} from '../provider-types.generated';
+
+export interface HyprlandProviderConfig {
+  type: 'hyprland';
+}
+
+export type HyprlandProvider = Provider<
+  HyprlandProviderConfig,
+  HyprlandOutput
+>;
+
+export interface HyprlandOutput extends GeneratedHyprlandOutput {
+  /**
+   * Runs a Hyprland dispatcher, e.g. `dispatch('workspace', '3')`.
+   */
+  dispatch(dispatcher: string, args?: string): Promise<void>;
+}
@@ -0,0 +1,41 @@
+import { z } from 'zod';
+
+import { createBaseProvider } from '../create-base-provider';
+import { desktopCommands, onProviderEmit } from '~/desktop';
+import type {
+  I3Output,
+  I3Provider,
+  I3ProviderConfig,
+} from './i3-provider-types';
+
+const i3ProviderConfigSchema = z.object({
+  type: z.literal('i3'),
+});
+
+export function createI3Provider(config: I3ProviderConfig): I3Provider {
+  const mergedConfig = i3ProviderConfigSchema.parse(config);
+
+  return createBaseProvider(mergedConfig, async queue => {
+    return onProviderEmit<I3Output>(
+      mergedConfig,
+      ({ configHash, result }) => {
+        if ('error' in result) {
+          queue.error(result.error);
+        } else {
+          queue.output({
+            ...result.output,
+            command: (command: string) => {
+              return desktopCommands.callProviderFunction(configHash, {
+                type: 'i3',
+                function: {
+                  name: 'command',
+                  args: { command },
+                },
+              });
+            },
+          });
+        }
+      },
+    );
+  });
+}
@@ -0,0 +1,23 @@
+import type { Provider } from '../create-base-provider';
+import type {
+  I3Output as GeneratedI3Output,
+} from '../provider-types.generated';
+
+export type {
+  I3Workspace,
+  I3Rect,
+  I3Window,
+} from '../provider-types.generated';
+
+export interface I3ProviderConfig {
+  type: 'i3';
+}
+
+export type I3Provider = Provider<I3ProviderConfig, I3Output>;
+
+export interface I3Output extends GeneratedI3Output {
+  /**
+   * Runs an i3/Sway command, e.g. `command('workspace 3')`.
+   */
+  command(command: string): Promise<void>;
+}
@@ -12,5 +12,33 @@ export * from './media/media-provider-types';
 export * from './memory/memory-provider-types';
 export * from './network/network-provider-types';
 export * from './weather/weather-provider-types';
+export * from './printer/printer-provider-types';
+export * from './meeting/meeting-provider-types';
+export * from './vpn/vpn-provider-types';
+export * from './hyprland/hyprland-provider-types';
+export * from './i3/i3-provider-types';
+export * from './virtual-desktop/virtual-desktop-provider-types';
+export * from './fancy-zones/fancy-zones-provider-types';
+export * from './workspaces/workspaces-provider-types';
+export * from './keep-awake/keep-awake-provider-types';
+export * from './session/session-provider-types';
+export * from './countdown/countdown-provider-types';
+export * from './timer/timer-provider-types';
+export * from './perf-counters/perf-counters-provider-types';
+export * from './event-log/event-log-provider-types';
+export * from './input-stats/input-stats-provider-types';
+export * from './fps/fps-provider-types';
+export * from './spotify/spotify-provider-types';
+export * from './discord/discord-provider-types';
+export * from './steam/steam-provider-types';
+export * from './tasks/tasks-provider-types';
+export * from './ci-status/ci-status-provider-types';
+export * from './ssh-gpg/ssh-gpg-provider-types';
+export * from './peripherals/peripherals-provider-types';
+export * from './camera/camera-provider-types';
+export * from './derived/derived-provider-types';
+export type { TlsConfig } from './tls-config';
+export type { RefreshOptions } from './refresh-options';
+export type { Duration } from '~/utils';
 export * from './create-provider';
 export * from './create-provider-group';
@@ -0,0 +1,33 @@
+import { z } from 'zod';
+
+import { createBaseProvider } from '../create-base-provider';
+import { refreshOptionsSchema } from '../refresh-options';
+import { onProviderEmit } from '~/desktop';
+import { durationSchema } from '~/utils';
+import type {
+  InputStatsOutput,
+  InputStatsProvider,
+  InputStatsProviderConfig,
+} from './input-stats-provider-types';
+
+const inputStatsProviderConfigSchema = z.object({
+  type: z.literal('input_stats'),
+  refreshInterval: durationSchema.default(5 * 1000),
+  refreshOptions: refreshOptionsSchema.optional(),
+});
+
+export function createInputStatsProvider(
+  config: InputStatsProviderConfig,
+): InputStatsProvider {
+  const mergedConfig = inputStatsProviderConfigSchema.parse(config);
+
+  return createBaseProvider(mergedConfig, async queue => {
+    return onProviderEmit<InputStatsOutput>(mergedConfig, ({ result }) => {
+      if ('error' in result) {
+        queue.error(result.error);
+      } else {
+        queue.output(result.output);
+      }
+    });
+  });
+}
@@ -0,0 +1,33 @@
+import type { Duration } from '~/utils';
+import type { Provider } from '../create-base-provider';
+import type { RefreshOptions } from '../refresh-options';
+import type { InputStatsOutput } from '../provider-types.generated';
+
+export type { InputStatsOutput } from '../provider-types.generated';
+
+/**
+ * Counts keystrokes and mouse clicks. Which keys are pressed is never
+ * recorded.
+ *
+ * Must be explicitly allowed via `permissions.providers` in the widget
+ * config. On Linux, the user must be in the `input` group.
+ */
+export interface InputStatsProviderConfig {
+  type: 'input_stats';
+
+  /**
+   * How often this provider refreshes, either in milliseconds or as a
+   * string with a unit (e.g. `'5s'`, `'2m'`, or `'1h'`).
+   */
+  refreshInterval?: Duration;
+
+  /**
+   * Alignment and jitter of refreshes.
+   */
+  refreshOptions?: RefreshOptions;
+}
+
+export type InputStatsProvider = Provider<
+  InputStatsProviderConfig,
+  InputStatsOutput
+>;
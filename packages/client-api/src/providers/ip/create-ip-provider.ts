@@ -1,7 +1,10 @@
 import { z } from 'zod';
 
 import { createBaseProvider } from '../create-base-provider';
+import { refreshOptionsSchema } from '../refresh-options';
+import { tlsConfigSchema } from '../tls-config';
 import { onProviderEmit } from '~/desktop';
+import { durationSchema } from '~/utils';
 import type {
   IpOutput,
   IpProvider,
@@ -10,7 +13,9 @@ import type {
 
 const ipProviderConfigSchema = z.object({
   type: z.literal('ip'),
-  refreshInterval: z.coerce.number().default(60 * 60 * 1000),
+  refreshInterval: durationSchema.default(60 * 60 * 1000),
+  refreshOptions: refreshOptionsSchema.optional(),
+  tls: tlsConfigSchema.optional(),
 });
 
 export function createIpProvider(config: IpProviderConfig): IpProvider {
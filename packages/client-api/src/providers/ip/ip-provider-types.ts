@@ -1,20 +1,29 @@
+import type { Duration } from '~/utils';
 import type { Provider } from '../create-base-provider';
+import type { RefreshOptions } from '../refresh-options';
+import type { TlsConfig } from '../tls-config';
+import type { IpOutput } from '../provider-types.generated';
+
+export type { IpOutput } from '../provider-types.generated';
 
 export interface IpProviderConfig {
   type: 'ip';
 
   /**
-   * How often this provider refreshes in milliseconds.
+   * How often this provider refreshes, either in milliseconds or as a
+   * string with a unit (e.g. `'5s'`, `'2m'`, or `'1h'`).
    */
-  refreshInterval?: number;
-}
+  refreshInterval?: Duration;
 
-export type IpProvider = Provider<IpProviderConfig, IpOutput>;
+  /**
+   * Alignment and jitter of refreshes.
+   */
+  refreshOptions?: RefreshOptions;
 
-export interface IpOutput {
-  address: string;
-  approxCity: string;
-  approxCountry: string;
-  approxLatitude: number;
-  approxLongitude: number;
+  /**
+   * TLS options for HTTP requests made by the provider.
+   */
+  tls?: TlsConfig;
 }
+
+export type IpProvider = Provider<IpProviderConfig, IpOutput>;
@@ -0,0 +1,37 @@
+import { z } from 'zod';
+
+import { createBaseProvider } from '../create-base-provider';
+import { desktopCommands, onProviderEmit } from '~/desktop';
+import type {
+  KeepAwakeOutput,
+  KeepAwakeProvider,
+  KeepAwakeProviderConfig,
+} from './keep-awake-provider-types';
+
+const keepAwakeProviderConfigSchema = z.object({
+  type: z.literal('keep_awake'),
+});
+
+export function createKeepAwakeProvider(
+  config: KeepAwakeProviderConfig,
+): KeepAwakeProvider {
+  const mergedConfig = keepAwakeProviderConfigSchema.parse(config);
+
+  return createBaseProvider(mergedConfig, async queue => {
+    return onProviderEmit<KeepAwakeOutput>(mergedConfig, ({ result }) => {
+      if ('error' in result) {
+        queue.error(result.error);
+      } else {
+        queue.output({
+          ...result.output,
+          setActive: (isActive: boolean) => {
+            return desktopCommands.setKeepAwake(isActive);
+          },
+          toggle: () => {
+            return desktopCommands.setKeepAwake(!result.output.isActive);
+          },
+        });
+      }
+    });
+  });
+}
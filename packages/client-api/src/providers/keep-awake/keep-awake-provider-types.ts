@@ -0,0 +1,30 @@
+import type { Provider } from '../create-base-provider';
+import type {
+  KeepAwakeOutput as GeneratedKeepAwakeOutput,
+} from '../provider-types.generated';
+
+export interface KeepAwakeProviderConfig {
+  type: 'keep_awake';
+}
+
+export type KeepAwakeProvider = Provider<
+  KeepAwakeProviderConfig,
+  KeepAwakeOutput
+>;
+
+export interface KeepAwakeOutput extends GeneratedKeepAwakeOutput {
+  /**
+   * Starts or stops preventing the system from sleeping. The state is
+   * shared across all widgets.
+   *
+   * Requires `power` in `permissions.commands` of the widget config.
+   */
+  setActive(isActive: boolean): Promise<void>;
+
+  /**
+   * Toggles whether the system is prevented from sleeping.
+   *
+   * Requires `power` in `permissions.commands` of the widget config.
+   */
+  toggle(): Promise<void>;
+}
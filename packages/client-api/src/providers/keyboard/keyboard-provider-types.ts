@@ -1,19 +1,26 @@
+import type { Duration } from '~/utils';
 import type { Provider } from '../create-base-provider';
+import type { RefreshOptions } from '../refresh-options';
+import type { KeyboardOutput } from '../provider-types.generated';
+
+export type { KeyboardOutput } from '../provider-types.generated';
 
 export interface KeyboardProviderConfig {
   type: 'keyboard';
 
   /**
-   * How often this provider refreshes in milliseconds.
+   * How often this provider refreshes, either in milliseconds or as a
+   * string with a unit (e.g. `'5s'`, `'2m'`, or `'1h'`).
+   */
+  refreshInterval?: Duration;
+
+  /**
+   * Alignment and jitter of refreshes.
    */
-  refreshInterval?: number;
+  refreshOptions?: RefreshOptions;
 }
 
 export type KeyboardProvider = Provider<
   KeyboardProviderConfig,
   KeyboardOutput
 >;
-
-export interface KeyboardOutput {
-  layout: string;
-}
@@ -1,6 +1,10 @@
 import { z } from 'zod';
 
-import { getMonitors, onProviderEmit } from '~/desktop';
+import {
+  desktopCommands,
+  getMonitors,
+  onProviderEmit,
+} from '~/desktop';
 import { getCoordinateDistance } from '~/utils';
 import { createBaseProvider } from '../create-base-provider';
 import type {
@@ -72,12 +76,36 @@ export function createKomorebiProvider(
 
     return onProviderEmit<KomorebiResponse>(
       mergedConfig,
-      async ({ result }) => {
+      async ({ configHash, result }) => {
         if ('error' in result) {
           queue.error(result.error);
         } else {
           const updatedState = await getUpdatedState(result.output);
-          queue.output(updatedState);
+
+          queue.output({
+            ...updatedState,
+            focusWorkspace: (
+              workspaceIndex: number,
+              monitorIndex?: number,
+            ) => {
+              return desktopCommands.callProviderFunction(configHash, {
+                type: 'komorebi',
+                function: {
+                  name: 'focus_workspace',
+                  args: { workspaceIndex, monitorIndex },
+                },
+              });
+            },
+            focusMonitor: (monitorIndex: number) => {
+              return desktopCommands.callProviderFunction(configHash, {
+                type: 'komorebi',
+                function: {
+                  name: 'focus_monitor',
+                  args: { monitorIndex },
+                },
+              });
+            },
+          });
         }
       },
     );
@@ -1,4 +1,19 @@
 import type { Provider } from '../create-base-provider';
+import type {
+  KomorebiMonitor,
+  KomorebiWorkspace,
+} from '../provider-types.generated';
+
+export type {
+  KomorebiMonitor,
+  KomorebiWorkspace,
+  KomorebiContainer,
+  KomorebiWindow,
+  KomorebiRect,
+  KomorebiLayout,
+  KomorebiLayoutFlip,
+  KomorebiOutput as KomorebiResponse,
+} from '../provider-types.generated';
 
 export interface KomorebiProviderConfig {
   type: 'komorebi';
@@ -44,68 +59,18 @@ export interface KomorebiOutput {
    * Monitor that is nearest to this Zebar widget.
    */
   currentMonitor: KomorebiMonitor;
-}
 
-export interface KomorebiResponse {
-  allMonitors: KomorebiMonitor[];
-  focusedMonitorIndex: number;
-}
-
-export interface KomorebiMonitor {
-  id: number;
-  deviceId: string;
-  focusedWorkspaceIndex: number;
-  name: string;
-  size: KomorebiRect;
-  workAreaOffset: number | null;
-  workAreaSize: KomorebiRect;
-  workspaces: KomorebiWorkspace[];
-}
-
-export interface KomorebiWorkspace {
-  containerPadding: number | null;
-  floatingWindows: KomorebiWindow[];
-  focusedContainerIndex: number;
-  latestLayout: KomorebiRect[];
-  layout: KomorebiLayout;
-  layoutFlip: KomorebiLayoutFlip | null;
-  maximizedWindow: KomorebiWindow | null;
-  monocleContainer: KomorebiContainer | null;
-  name: string | null;
-  tilingContainers: KomorebiContainer[];
-  workspacePadding: number | null;
-}
-
-export interface KomorebiContainer {
-  id: string;
-  windows: KomorebiWindow[];
-}
-
-export interface KomorebiWindow {
-  class: string | null;
-  exe: string | null;
-  hwnd: number;
-  title: string | null;
-}
+  /**
+   * Focuses the workspace at the given index. Defaults to the focused
+   * monitor if no monitor index is given.
+   */
+  focusWorkspace(
+    workspaceIndex: number,
+    monitorIndex?: number,
+  ): Promise<void>;
 
-export interface KomorebiRect {
-  left: number;
-  top: number;
-  right: number;
-  bottom: number;
+  /**
+   * Focuses the monitor at the given index.
+   */
+  focusMonitor(monitorIndex: number): Promise<void>;
 }
-
-export type KomorebiLayout =
-  | 'bsp'
-  | 'vertical_stack'
-  | 'horizontal_stack'
-  | 'ultrawide_vertical_stack'
-  | 'rows'
-  | 'grid'
-  | 'right_main_vertical_stack'
-  | 'custom';
-
-export type KomorebiLayoutFlip =
-  | 'horizontal'
-  | 'vertical'
-  | 'horizontal_and_vertical';
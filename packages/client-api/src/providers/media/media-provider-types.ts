@@ -1,14 +1,18 @@
 import type { Provider } from '../create-base-provider';
+import type {
+  MediaSession,
+  MediaOutput as GeneratedMediaOutput,
+} from '../provider-types.generated';
+
+export type { MediaSession } from '../provider-types.generated';
 
 export interface MediaProviderConfig {
   type: 'media';
 }
 
-export interface MediaOutput {
+export interface MediaOutput extends GeneratedMediaOutput {
   /** @deprecated Use {@link currentSession} instead */
   session: MediaSession | null;
-  currentSession: MediaSession | null;
-  allSessions: MediaSession[];
   play(options?: MediaControlOptions): void;
   pause(options?: MediaControlOptions): void;
   togglePlayPause(options?: MediaControlOptions): void;
@@ -20,18 +24,4 @@ export interface MediaControlOptions {
   sessionId?: string;
 }
 
-export interface MediaSession {
-  sessionId: string;
-  title: string | null;
-  artist: string | null;
-  albumTitle: string | null;
-  albumArtist: string | null;
-  trackNumber: number;
-  startTime: number;
-  endTime: number;
-  position: number;
-  isPlaying: boolean;
-  isCurrentSession: boolean;
-}
-
 export type MediaProvider = Provider<MediaProviderConfig, MediaOutput>;
@@ -0,0 +1,33 @@
+import { z } from 'zod';
+
+import { createBaseProvider } from '../create-base-provider';
+import { refreshOptionsSchema } from '../refresh-options';
+import { onProviderEmit } from '~/desktop';
+import { durationSchema } from '~/utils';
+import type {
+  MeetingOutput,
+  MeetingProvider,
+  MeetingProviderConfig,
+} from './meeting-provider-types';
+
+const meetingProviderConfigSchema = z.object({
+  type: z.literal('meeting'),
+  refreshInterval: durationSchema.default(2 * 1000),
+  refreshOptions: refreshOptionsSchema.optional(),
+});
+
+export function createMeetingProvider(
+  config: MeetingProviderConfig,
+): MeetingProvider {
+  const mergedConfig = meetingProviderConfigSchema.parse(config);
+
+  return createBaseProvider(mergedConfig, async queue => {
+    return onProviderEmit<MeetingOutput>(mergedConfig, ({ result }) => {
+      if ('error' in result) {
+        queue.error(result.error);
+      } else {
+        queue.output(result.output);
+      }
+    });
+  });
+}
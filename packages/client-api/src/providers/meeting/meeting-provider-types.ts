@@ -0,0 +1,26 @@
+import type { Duration } from '~/utils';
+import type { Provider } from '../create-base-provider';
+import type { RefreshOptions } from '../refresh-options';
+import type { MeetingOutput } from '../provider-types.generated';
+
+export type { MeetingOutput } from '../provider-types.generated';
+
+export interface MeetingProviderConfig {
+  type: 'meeting';
+
+  /**
+   * How often this provider refreshes, either in milliseconds or as a
+   * string with a unit (e.g. `'5s'`, `'2m'`, or `'1h'`).
+   */
+  refreshInterval?: Duration;
+
+  /**
+   * Alignment and jitter of refreshes.
+   */
+  refreshOptions?: RefreshOptions;
+}
+
+export type MeetingProvider = Provider<
+  MeetingProviderConfig,
+  MeetingOutput
+>;
@@ -1,7 +1,9 @@
 import { z } from 'zod';
 
 import { createBaseProvider } from '../create-base-provider';
+import { refreshOptionsSchema } from '../refresh-options';
 import { onProviderEmit } from '~/desktop';
+import { durationSchema } from '~/utils';
 import type {
   MemoryOutput,
   MemoryProvider,
@@ -10,7 +12,8 @@ import type {
 
 const memoryProviderConfigSchema = z.object({
   type: z.literal('memory'),
-  refreshInterval: z.coerce.number().default(5 * 1000),
+  refreshInterval: durationSchema.default(5 * 1000),
+  refreshOptions: refreshOptionsSchema.optional(),
 });
 
 export function createMemoryProvider(
@@ -1,22 +1,23 @@
+import type { Duration } from '~/utils';
 import type { Provider } from '../create-base-provider';
+import type { RefreshOptions } from '../refresh-options';
+import type { MemoryOutput } from '../provider-types.generated';
+
+export type { MemoryOutput } from '../provider-types.generated';
 
 export interface MemoryProviderConfig {
   type: 'memory';
 
   /**
-   * How often this provider refreshes in milliseconds.
+   * How often this provider refreshes, either in milliseconds or as a
+   * string with a unit (e.g. `'5s'`, `'2m'`, or `'1h'`).
+   */
+  refreshInterval?: Duration;
+
+  /**
+   * Alignment and jitter of refreshes.
    */
-  refreshInterval?: number;
+  refreshOptions?: RefreshOptions;
 }
 
 export type MemoryProvider = Provider<MemoryProviderConfig, MemoryOutput>;
-
-export interface MemoryOutput {
-  usage: number;
-  freeMemory: number;
-  usedMemory: number;
-  totalMemory: number;
-  freeSwap: number;
-  usedSwap: number;
-  totalSwap: number;
-}
@@ -0,0 +1,43 @@
+import { z } from 'zod';
+
+import { createBaseProvider } from '../create-base-provider';
+import { refreshOptionsSchema } from '../refresh-options';
+import { onProviderEmit } from '~/desktop';
+import { durationSchema } from '~/utils';
+import type {
+  PerfCountersOutput,
+  PerfCountersProvider,
+  PerfCountersProviderConfig,
+} from './perf-counters-provider-types';
+
+const perfCountersProviderConfigSchema = z.object({
+  type: z.literal('perf_counters'),
+  refreshInterval: durationSchema.default(5 * 1000),
+  refreshOptions: refreshOptionsSchema.optional(),
+  counters: z.array(
+    z.object({
+      name: z.string(),
+      path: z.string(),
+      rate: z.boolean().default(false),
+    }),
+  ),
+});
+
+export function createPerfCountersProvider(
+  config: PerfCountersProviderConfig,
+): PerfCountersProvider {
+  const mergedConfig = perfCountersProviderConfigSchema.parse(config);
+
+  return createBaseProvider(mergedConfig, async queue => {
+    return onProviderEmit<PerfCountersOutput>(
+      mergedConfig,
+      ({ result }) => {
+        if ('error' in result) {
+          queue.error(result.error);
+        } else {
+          queue.output(result.output);
+        }
+      },
+    );
+  });
+}
@@ -0,0 +1,55 @@
+import type { Duration } from '~/utils';
+import type { Provider } from '../create-base-provider';
+import type { RefreshOptions } from '../refresh-options';
+import type { PerfCountersOutput } from '../provider-types.generated';
+
+export type { PerfCountersOutput } from '../provider-types.generated';
+
+export interface PerfCountersProviderConfig {
+  type: 'perf_counters';
+
+  /**
+   * How often this provider refreshes, either in milliseconds or as a
+   * string with a unit (e.g. `'5s'`, `'2m'`, or `'1h'`).
+   */
+  refreshInterval?: Duration;
+
+  /**
+   * Alignment and jitter of refreshes.
+   */
+  refreshOptions?: RefreshOptions;
+
+  /**
+   * Counters to sample.
+   */
+  counters: PerfCounterConfig[];
+}
+
+export interface PerfCounterConfig {
+  /**
+   * Name to output the counter's value under.
+   */
+  name: string;
+
+  /**
+   * On Windows, an English PDH counter path (e.g.
+   * `\Processor(_Total)\% Interrupt Time`).
+   *
+   * On Linux, a file and the key of a line within it (e.g.
+   * `/proc/stat:ctxt` or `/proc/meminfo:Dirty`), or a file that contains
+   * a single number (e.g. `/sys/class/thermal/thermal_zone0/temp`).
+   */
+  path: string;
+
+  /**
+   * Whether to output the per-second rate of change instead of the value
+   * itself. Useful for cumulative counters (e.g. context switches in
+   * `/proc/stat:ctxt`). Defaults to `false`.
+   */
+  rate?: boolean;
+}
+
+export type PerfCountersProvider = Provider<
+  PerfCountersProviderConfig,
+  PerfCountersOutput
+>;
@@ -0,0 +1,45 @@
+import { z } from 'zod';
+
+import { createBaseProvider } from '../create-base-provider';
+import { refreshOptionsSchema } from '../refresh-options';
+import { onProviderEmit } from '~/desktop';
+import { durationSchema } from '~/utils';
+import type {
+  PeripheralsOutput,
+  PeripheralsProvider,
+  PeripheralsProviderConfig,
+} from './peripherals-provider-types';
+
+const peripheralsProviderConfigSchema = z.object({
+  type: z.literal('peripherals'),
+  refreshInterval: durationSchema.default(60 * 1000),
+  refreshOptions: refreshOptionsSchema.optional(),
+  kinds: z
+    .array(
+      z.enum([
+        'stylus',
+        'game_controller',
+        'mouse',
+        'keyboard',
+        'headset',
+        'other',
+      ]),
+    )
+    .optional(),
+});
+
+export function createPeripheralsProvider(
+  config: PeripheralsProviderConfig,
+): PeripheralsProvider {
+  const mergedConfig = peripheralsProviderConfigSchema.parse(config);
+
+  return createBaseProvider(mergedConfig, async queue => {
+    return onProviderEmit<PeripheralsOutput>(mergedConfig, ({ result }) => {
+      if ('error' in result) {
+        queue.error(result.error);
+      } else {
+        queue.output(result.output);
+      }
+    });
+  });
+}
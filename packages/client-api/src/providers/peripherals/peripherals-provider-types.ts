@@ -0,0 +1,38 @@
+import type { Duration } from '~/utils';
+import type { Provider } from '../create-base-provider';
+import type { RefreshOptions } from '../refresh-options';
+import type {
+  PeripheralsOutput,
+  PeripheralKind,
+} from '../provider-types.generated';
+
+export type {
+  PeripheralsOutput,
+  Peripheral,
+  PeripheralKind,
+} from '../provider-types.generated';
+
+export interface PeripheralsProviderConfig {
+  type: 'peripherals';
+
+  /**
+   * How often this provider refreshes, either in milliseconds or as a
+   * string with a unit (e.g. `'5s'`, `'2m'`, or `'1h'`).
+   */
+  refreshInterval?: Duration;
+
+  /**
+   * Alignment and jitter of refreshes.
+   */
+  refreshOptions?: RefreshOptions;
+
+  /**
+   * Kinds of devices to include. Includes all kinds if not set.
+   */
+  kinds?: PeripheralKind[];
+}
+
+export type PeripheralsProvider = Provider<
+  PeripheralsProviderConfig,
+  PeripheralsOutput
+>;
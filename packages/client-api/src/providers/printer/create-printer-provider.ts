@@ -0,0 +1,47 @@
+import { z } from 'zod';
+
+import { createBaseProvider } from '../create-base-provider';
+import { refreshOptionsSchema } from '../refresh-options';
+import { desktopCommands, onProviderEmit } from '~/desktop';
+import { durationSchema } from '~/utils';
+import type {
+  PrinterOutput,
+  PrinterProvider,
+  PrinterProviderConfig,
+} from './printer-provider-types';
+
+const printerProviderConfigSchema = z.object({
+  type: z.literal('printer'),
+  refreshInterval: durationSchema.default(10 * 1000),
+  refreshOptions: refreshOptionsSchema.optional(),
+});
+
+export function createPrinterProvider(
+  config: PrinterProviderConfig,
+): PrinterProvider {
+  const mergedConfig = printerProviderConfigSchema.parse(config);
+
+  return createBaseProvider(mergedConfig, async queue => {
+    return onProviderEmit<PrinterOutput>(
+      mergedConfig,
+      ({ configHash, result }) => {
+        if ('error' in result) {
+          queue.error(result.error);
+        } else {
+          queue.output({
+            ...result.output,
+            cancelJob: (printerName: string, jobId: number) => {
+              return desktopCommands.callProviderFunction(configHash, {
+                type: 'printer',
+                function: {
+                  name: 'cancel_job',
+                  args: { printerName, jobId },
+                },
+              });
+            },
+          });
+        }
+      },
+    );
+  });
+}
@@ -0,0 +1,36 @@
+import type { Duration } from '~/utils';
+import type { Provider } from '../create-base-provider';
+import type { RefreshOptions } from '../refresh-options';
+import type {
+  PrinterOutput as GeneratedPrinterOutput,
+} from '../provider-types.generated';
+
+export type {
+  Printer,
+  PrintJob,
+  PrinterStatus,
+} from '../provider-types.generated';
+
+export interface PrinterProviderConfig {
+  type: 'printer';
+
+  /**
+   * How often this provider refreshes, either in milliseconds or as a
+   * string with a unit (e.g. `'5s'`, `'2m'`, or `'1h'`).
+   */
+  refreshInterval?: Duration;
+
+  /**
+   * Alignment and jitter of refreshes.
+   */
+  refreshOptions?: RefreshOptions;
+}
+
+export type PrinterProvider = Provider<
+  PrinterProviderConfig,
+  PrinterOutput
+>;
+
+export interface PrinterOutput extends GeneratedPrinterOutput {
+  cancelJob(printerName: string, jobId: number): Promise<void>;
+}
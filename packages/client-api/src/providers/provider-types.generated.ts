@@ -0,0 +1,1648 @@
+// This file is generated from the desktop app's provider schemas
+// by `scripts/generate-provider-types.mjs`. Do not edit it by hand.
+
+export interface AirQuality {
+  europeanAqi: number | null;
+  pm10: number | null;
+  pm25: number | null;
+  usAqi: number | null;
+}
+
+export interface AudioDevice {
+  deviceId: string;
+  deviceType: DeviceType;
+  isDefaultPlayback: boolean;
+  isDefaultRecording: boolean;
+  isMuted: boolean;
+  name: string;
+  volume: number;
+}
+
+export interface AudioOutput {
+  allDevices: AudioDevice[];
+  defaultPlaybackDevice: AudioDevice | null;
+  defaultRecordingDevice: AudioDevice | null;
+  /**
+   * Peak input level (0-100) of the default recording device. Only set
+   * when `input_level_interval` is configured.
+   */
+  inputLevel: number | null;
+  playbackDevices: AudioDevice[];
+  recordingDevices: AudioDevice[];
+  /**
+   * Apps playing audio on the default playback device.
+   */
+  sessions: AudioSession[];
+}
+
+/**
+ * Audio stream of an app (e.g. a browser playing a video).
+ */
+export interface AudioSession {
+  /**
+   * Icon of the app, if one could be found.
+   */
+  icon: ProviderAttachment | null;
+  /**
+   * Whether the app is currently playing audio.
+   */
+  isActive: boolean;
+  isMuted: boolean;
+  /**
+   * Display name of the app (e.g. `Firefox`).
+   */
+  name: string;
+  processId: number | null;
+  sessionId: string;
+  volume: number;
+}
+
+export interface BatteryOutput {
+  chargePercent: number;
+  cycleCount: number | null;
+  healthPercent: number;
+  isCharging: boolean;
+  powerConsumption: number;
+  state: string;
+  timeTillEmpty: number | null;
+  timeTillFull: number | null;
+  voltage: number;
+}
+
+export interface CameraDevice {
+  /**
+   * Identifier of the device, which is stable across reconnects to the
+   * same port.
+   */
+  id: string;
+  /**
+   * Whether the camera is currently capturing. `None` if unknown.
+   *
+   * Windows only tracks camera usage per app rather than per device, so
+   * this is only known when a single camera is connected. The `meeting`
+   * provider reports usage across all cameras instead. Not supported on
+   * MacOS.
+   */
+  isActive: boolean | null;
+  name: string;
+}
+
+export interface CameraOutput {
+  /**
+   * Connected video capture devices. Changes as cameras are plugged in or
+   * removed.
+   */
+  devices: CameraDevice[];
+}
+
+export type CiBackend = 'github_actions' | 'gitlab' | 'jenkins';
+
+export interface CiPipeline {
+  branch: string | null;
+  commitSha: string | null;
+  /**
+   * Error from fetching the pipeline, in which case the status is
+   * `unknown`.
+   */
+  error: string | null;
+  /**
+   * Name of the workflow or build.
+   */
+  name: string | null;
+  repo: string;
+  /**
+   * Unix timestamp in milliseconds of when the pipeline started.
+   */
+  startedAt: number | null;
+  status: CiStatus;
+  /**
+   * Link to the pipeline in the browser.
+   */
+  url: string | null;
+}
+
+export type CiStatus = 'success' | 'failure' | 'running' | 'pending' | 'cancelled' | 'skipped' | 'unknown';
+
+export interface CiStatusOutput {
+  failingCount: number;
+  /**
+   * Latest pipeline of each target, in the order of `targets`.
+   */
+  pipelines: CiPipeline[];
+  runningCount: number;
+  /**
+   * Worst status across all pipelines, in the order `failure`, `running`,
+   * `pending`, and `success`. `unknown` if there are no pipelines.
+   */
+  status: CiStatus;
+}
+
+export interface CiTarget {
+  /**
+   * Branch to limit pipelines to. Defaults to any branch.
+   */
+  branch?: string | null;
+  /**
+   * Repo (e.g. `glzr-io/zebar`), GitLab project path, or Jenkins job path
+   * (e.g. `folder/job`).
+   */
+  repo: string;
+}
+
+export interface Countdown {
+  /**
+   * Remaining whole days.
+   */
+  days: number;
+  /**
+   * Remaining hours after whole days (0-23).
+   */
+  hours: number;
+  /**
+   * Whether the event has passed. Only possible for events that don't
+   * repeat.
+   */
+  isPast: boolean;
+  /**
+   * Remaining minutes after whole hours (0-59).
+   */
+  minutes: number;
+  name: string;
+  /**
+   * Remaining time in milliseconds. 0 if the event has passed.
+   */
+  remaining: number;
+  /**
+   * Remaining seconds after whole minutes (0-59).
+   */
+  seconds: number;
+  /**
+   * Next occurrence of the event as a Unix timestamp in milliseconds.
+   */
+  targetTime: number;
+}
+
+export interface CountdownEventConfig {
+  /**
+   * Target date in local time. Supports full dates (`2026-12-25 18:30`,
+   * RFC 3339), relative days (`tomorrow 9am`), month and day (`Dec 25`),
+   * weekdays (`friday 17:00`), and times (`17:30`).
+   */
+  date: string;
+  name: string;
+  /**
+   * How often the event repeats. Defaults to the recurrence implied by the
+   * date (e.g. `Dec 25` repeats yearly, `friday` weekly, and `17:30`
+   * daily).
+   */
+  recurrence?: Recurrence | null;
+}
+
+export interface CountdownOutput {
+  /**
+   * Countdowns in the same order as the configured events.
+   */
+  events: Countdown[];
+}
+
+export interface CpuOutput {
+  frequency: number;
+  logicalCoreCount: number;
+  physicalCoreCount: number;
+  usage: number;
+  vendor: string;
+}
+
+export type DeviceType = 'playback' | 'recording';
+
+export interface DiscordOutput {
+  /**
+   * Whether the user has authorized access to the Discord client. Call the
+   * `authorize` function if `false`.
+   */
+  isAuthorized: boolean;
+  isDeafened: boolean;
+  isMuted: boolean;
+  /**
+   * Whether the Discord client is running.
+   */
+  isRunning: boolean;
+  /**
+   * Logged in user. Discord's RPC doesn't expose the user's online status,
+   * so only their profile is available.
+   */
+  user: DiscordUser | null;
+  /**
+   * Voice channel the user is connected to.
+   */
+  voiceChannel: DiscordVoiceChannel | null;
+}
+
+export interface DiscordUser {
+  avatarUrl: string | null;
+  /**
+   * Server nickname or global display name, falling back to the username.
+   */
+  displayName: string;
+  id: string;
+  username: string;
+}
+
+export interface DiscordVoiceChannel {
+  /**
+   * Server the channel belongs to. `None` for calls in DMs.
+   */
+  guildId: string | null;
+  guildName: string | null;
+  id: string;
+  members: DiscordVoiceMember[];
+  name: string;
+}
+
+export interface DiscordVoiceMember {
+  isDeafened: boolean;
+  isMuted: boolean;
+  user: DiscordUser;
+}
+
+export interface Disk {
+  availableSpace: DiskSizeMeasure;
+  driveType: string;
+  fileSystem: string;
+  isRemovable: boolean;
+  mountPoint: string;
+  name: string | null;
+  totalSpace: DiskSizeMeasure;
+}
+
+export interface DiskOutput {
+  disks: Disk[];
+  /**
+   * S.M.A.R.T. health data per physical disk. `None` if `include_smart` is
+   * disabled.
+   */
+  physicalDisks: PhysicalDiskHealth[] | null;
+}
+
+export interface DiskSizeMeasure {
+  bytes: number;
+  iecUnit: string;
+  iecValue: number;
+  siUnit: string;
+  siValue: number;
+}
+
+export type EventLevel = 'critical' | 'error' | 'warning' | 'info' | 'debug';
+
+export interface EventLogEntry {
+  /**
+   * Channel on Windows (e.g. `System`), or systemd unit on Linux.
+   */
+  channel: string | null;
+  /**
+   * Event ID. Only available on Windows.
+   */
+  eventId: number | null;
+  level: EventLevel;
+  message: string;
+  /**
+   * Event provider on Windows (e.g. `Service Control Manager`), or syslog
+   * identifier on Linux (e.g. `sshd`).
+   */
+  source: string;
+  /**
+   * Time of the entry as a Unix timestamp in milliseconds.
+   */
+  timestamp: number;
+}
+
+export interface EventLogOutput {
+  /**
+   * Matching entries within `maxAge`, newest first.
+   */
+  entries: EventLogEntry[];
+  /**
+   * Number of critical and error entries within `maxAge`.
+   */
+  errorCount: number;
+  /**
+   * Number of warning entries within `maxAge`.
+   */
+  warningCount: number;
+}
+
+export interface FancyZonesLayout {
+  /**
+   * GUID of the layout.
+   */
+  id: string;
+  /**
+   * One of `blank`, `focus`, `columns`, `rows`, `grid`, `priority-grid`,
+   * or `custom`.
+   */
+  layoutType: string;
+  /**
+   * Hardware ID of the monitor the layout is applied to (e.g. `DELA0F2`).
+   */
+  monitor: string;
+  /**
+   * 1-based number of the monitor, as shown in the FancyZones editor.
+   */
+  monitorNumber: number | null;
+  /**
+   * Name of the layout. For built-in layouts, this is its type (e.g.
+   * `priority-grid`).
+   */
+  name: string;
+  /**
+   * GUID of the virtual desktop the layout is applied to.
+   */
+  virtualDesktopId: string | null;
+  zoneCount: number;
+}
+
+export interface FancyZonesOutput {
+  /**
+   * The window that currently has focus, excluding Zebar's own windows and
+   * the desktop.
+   */
+  focusedWindow: ZonedWindow | null;
+  /**
+   * Whether the FancyZones state of PowerToys was found. Layouts and zones
+   * are empty otherwise.
+   */
+  isAvailable: boolean;
+  /**
+   * Layouts applied to each monitor and virtual desktop.
+   */
+  layouts: FancyZonesLayout[];
+}
+
+export interface FpsOutput {
+  /**
+   * Average frames per second.
+   */
+  fps: number | null;
+  /**
+   * Average frame time in milliseconds.
+   */
+  frameTime: number | null;
+  /**
+   * 99th percentile frame time in milliseconds.
+   */
+  frameTimeP99: number | null;
+  /**
+   * Frames per second of the slowest 1% of frames.
+   */
+  low1PercentFps: number | null;
+  /**
+   * ID of the process being measured. `None` if no app is focused (or the
+   * focused app isn't fullscreen with `fullscreenOnly`).
+   */
+  processId: number | null;
+  /**
+   * Name of the process being measured (e.g. `game.exe`). `None` until the
+   * process presents a frame.
+   */
+  processName: string | null;
+}
+
+export interface HostOutput {
+  bootTime: number;
+  friendlyOsVersion: string | null;
+  hostname: string | null;
+  osName: string | null;
+  osVersion: string | null;
+  uptime: number;
+}
+
+export interface HyprlandMonitor {
+  activeWorkspaceId: number;
+  description: string;
+  height: number;
+  id: number;
+  isFocused: boolean;
+  name: string;
+  scale: number;
+  width: number;
+  x: number;
+  y: number;
+}
+
+export interface HyprlandOutput {
+  activeWindow: HyprlandWindow | null;
+  focusedMonitor: HyprlandMonitor | null;
+  focusedWorkspace: HyprlandWorkspace | null;
+  monitors: HyprlandMonitor[];
+  /**
+   * Name of the active submap (keybind mode). `None` when in the default
+   * submap.
+   */
+  submap: string | null;
+  workspaces: HyprlandWorkspace[];
+}
+
+export interface HyprlandWindow {
+  address: string;
+  class: string;
+  isFloating: boolean;
+  title: string;
+  workspaceId: number | null;
+}
+
+export interface HyprlandWorkspace {
+  hasFullscreen: boolean;
+  id: number;
+  /**
+   * Whether the workspace is shown on its monitor.
+   */
+  isDisplayed: boolean;
+  /**
+   * Whether the workspace is shown on the focused monitor.
+   */
+  isFocused: boolean;
+  monitor: string;
+  name: string;
+  windowCount: number;
+}
+
+export interface I3Output {
+  /**
+   * Name of the active binding mode (e.g. `resize`). `default` when no
+   * mode is active.
+   */
+  bindingMode: string;
+  focusedWindow: I3Window | null;
+  focusedWorkspace: I3Workspace | null;
+  workspaces: I3Workspace[];
+}
+
+export interface I3Rect {
+  height: number;
+  width: number;
+  x: number;
+  y: number;
+}
+
+export interface I3Window {
+  /**
+   * App ID of Wayland-native windows. Only set on Sway.
+   */
+  appId: string | null;
+  /**
+   * `WM_CLASS` of X11 windows.
+   */
+  class: string | null;
+  id: number;
+  title: string | null;
+}
+
+export interface I3Workspace {
+  isFocused: boolean;
+  isUrgent: boolean;
+  isVisible: boolean;
+  name: string;
+  /**
+   * Workspace number, or -1 for workspaces without a number.
+   */
+  num: number;
+  /**
+   * Name of the output (monitor) the workspace is on.
+   */
+  output: string;
+  /**
+   * Area of the workspace in layout coordinates.
+   */
+  rect: I3Rect;
+}
+
+export interface InputStatsOutput {
+  /**
+   * Mouse clicks since the last refresh.
+   */
+  clicks: number;
+  /**
+   * Mouse clicks within the last minute.
+   */
+  clicksPerMinute: number;
+  /**
+   * Keystrokes since the last refresh. Held keys are counted once.
+   */
+  keystrokes: number;
+  /**
+   * Keystrokes within the last minute.
+   */
+  keystrokesPerMinute: number;
+  /**
+   * Mouse clicks since the provider started.
+   */
+  totalClicks: number;
+  /**
+   * Keystrokes since the provider started.
+   */
+  totalKeystrokes: number;
+}
+
+export type InterfaceType = 'unknown' | 'ethernet' | 'token_ring' | 'fddi' | 'ppp' | 'loopback' | 'slip' | 'atm' | 'generic_modem' | 'isdn' | 'wifi' | 'dsl' | 'tunnel' | 'high_performance_serial_bus' | 'mobile_broadband' | 'bridge';
+
+/**
+ * Options for when the ticks of an interval occur.
+ */
+export interface IntervalOptions {
+  /**
+   * Whether to tick on multiples of the interval since the Unix epoch
+   * (e.g. exactly on the minute for an interval of `1m`). The first tick
+   * still occurs immediately.
+   */
+  align?: boolean;
+  /**
+   * Max random delay in milliseconds to add to each tick. Avoids providers
+   * hitting an external API at the same time (e.g. on startup).
+   */
+  jitter?: number | string;
+}
+
+export interface IpOutput {
+  address: string;
+  approxCity: string;
+  approxCountry: string;
+  approxLatitude: number;
+  approxLongitude: number;
+}
+
+export interface KeepAwakeOutput {
+  /**
+   * Whether Zebar is currently preventing the system from sleeping.
+   */
+  isActive: boolean;
+}
+
+export interface KeyboardOutput {
+  layout: string;
+}
+
+export interface KomorebiContainer {
+  id: string;
+  windows: KomorebiWindow[];
+}
+
+export type KomorebiLayout = 'bsp' | 'vertical_stack' | 'horizontal_stack' | 'ultrawide_vertical_stack' | 'rows' | 'grid' | 'right_main_vertical_stack' | 'custom';
+
+export type KomorebiLayoutFlip = 'horizontal' | 'vertical' | 'horizontal_and_vertical';
+
+export interface KomorebiMonitor {
+  deviceId: string;
+  focusedWorkspaceIndex: number;
+  id: number;
+  name: string;
+  size: KomorebiRect;
+  workAreaOffset: KomorebiRect | null;
+  workAreaSize: KomorebiRect;
+  workspaces: KomorebiWorkspace[];
+}
+
+export interface KomorebiOutput {
+  allMonitors: KomorebiMonitor[];
+  focusedMonitorIndex: number;
+}
+
+/**
+ * Schema of komorebi's `Rect`, which doesn't implement `JsonSchema`.
+ */
+export interface KomorebiRect {
+  bottom: number;
+  left: number;
+  right: number;
+  top: number;
+}
+
+export interface KomorebiWindow {
+  class: string | null;
+  exe: string | null;
+  hwnd: number;
+  title: string | null;
+}
+
+export interface KomorebiWorkspace {
+  containerPadding: number | null;
+  floatingWindows: KomorebiWindow[];
+  focusedContainerIndex: number;
+  latestLayout: KomorebiRect[];
+  layout: KomorebiLayout;
+  layoutFlip: KomorebiLayoutFlip | null;
+  maximizedWindow: KomorebiWindow | null;
+  monocleContainer: KomorebiContainer | null;
+  name: string | null;
+  tilingContainers: KomorebiContainer[];
+  workspacePadding: number | null;
+}
+
+export interface MediaOutput {
+  allSessions: MediaSession[];
+  currentSession: MediaSession | null;
+}
+
+export interface MediaSession {
+  albumArtist: string | null;
+  albumTitle: string | null;
+  artist: string | null;
+  endTime: number;
+  isCurrentSession: boolean;
+  isPlaying: boolean;
+  position: number;
+  sessionId: string;
+  startTime: number;
+  /**
+   * Album art of the current track.
+   */
+  thumbnail: ProviderAttachment | null;
+  title: string | null;
+  trackNumber: number;
+}
+
+export interface MeetingOutput {
+  cameraApps: string[];
+  isCameraInUse: boolean;
+  isMicrophoneInUse: boolean;
+  microphoneApps: string[];
+}
+
+export interface MemoryOutput {
+  freeMemory: number;
+  freeSwap: number;
+  totalMemory: number;
+  totalSwap: number;
+  usage: number;
+  usedMemory: number;
+  usedSwap: number;
+}
+
+export interface NetworkGateway {
+  ipv4Addresses: string[];
+  ipv6Addresses: string[];
+  macAddress: string;
+  signalStrength: number | null;
+  ssid: string | null;
+}
+
+export interface NetworkInterface {
+  description: string | null;
+  dnsServers: string[];
+  friendlyName: string | null;
+  ipv4Addresses: string[];
+  ipv6Addresses: string[];
+  isDefault: boolean;
+  macAddress: string | null;
+  name: string;
+  receiveSpeed: number | null;
+  transmitSpeed: number | null;
+  type: InterfaceType;
+}
+
+export interface NetworkOutput {
+  defaultGateway: NetworkGateway | null;
+  defaultInterface: NetworkInterface | null;
+  interfaces: NetworkInterface[];
+  traffic: NetworkTraffic;
+}
+
+export interface NetworkTraffic {
+  received: NetworkTrafficMeasure;
+  totalReceived: NetworkTrafficMeasure;
+  totalTransmitted: NetworkTrafficMeasure;
+  transmitted: NetworkTrafficMeasure;
+}
+
+export interface NetworkTrafficMeasure {
+  bytes: number;
+  iecUnit: string;
+  iecValue: number;
+  siUnit: string;
+  siValue: number;
+}
+
+export interface PerfCounterConfig {
+  /**
+   * Name to output the counter's value under.
+   */
+  name: string;
+  /**
+   * On Windows, an English PDH counter path (e.g. `\Processor(_Total)\%
+   * Interrupt Time`).
+   *
+   * On Linux, a file and the key of a line within it (e.g.
+   * `/proc/stat:ctxt` or `/proc/meminfo:Dirty`), or a file that contains a
+   * single number (e.g. `/sys/class/thermal/thermal_zone0/temp`).
+   */
+  path: string;
+  /**
+   * Whether to output the per-second rate of change instead of the value
+   * itself. Useful for cumulative counters (e.g. context switches in
+   * `/proc/stat:ctxt`).
+   */
+  rate?: boolean;
+}
+
+export interface PerfCountersOutput {
+  /**
+   * Latest value of each counter, keyed by name. `null` if the counter
+   * couldn't be read, or on the first sample of a rate.
+   */
+  values: Record<string, number | null>;
+}
+
+export interface Peripheral {
+  /**
+   * Battery charge as a percentage. `None` if the device doesn't report
+   * its battery level.
+   *
+   * Game controllers on Windows only report a coarse level (empty, low,
+   * medium, or full), which is mapped to 0, 25, 60, and 100.
+   */
+  batteryPercent: number | null;
+  /**
+   * Identifier of the device, which is stable across reconnects.
+   */
+  id: string;
+  /**
+   * Whether the device is charging. `None` if unknown.
+   */
+  isCharging: boolean | null;
+  kind: PeripheralKind;
+  name: string;
+}
+
+export type PeripheralKind = 'stylus' | 'game_controller' | 'mouse' | 'keyboard' | 'headset' | 'other';
+
+export interface PeripheralsOutput {
+  devices: Peripheral[];
+}
+
+export interface PhysicalDiskHealth {
+  celsiusTemp: number | null;
+  device: string;
+  isHealthy: boolean | null;
+  model: string | null;
+  powerOnHours: number | null;
+  serialNumber: string | null;
+}
+
+export interface PrintJob {
+  document: string | null;
+  id: number;
+}
+
+export interface Printer {
+  isDefault: boolean;
+  jobCount: number;
+  jobs: PrintJob[];
+  name: string;
+  status: PrinterStatus;
+}
+
+export interface PrinterOutput {
+  defaultPrinter: Printer | null;
+  printers: Printer[];
+}
+
+export type PrinterStatus = 'idle' | 'printing' | 'paused' | 'offline' | 'error';
+
+/**
+ * Reference to binary data emitted by a provider (e.g. album art).
+ *
+ * Only the reference is included in provider outputs. The data itself is
+ * fetched as a raw binary payload via the `get_provider_attachment`
+ * command, which avoids inflating emissions with base64.
+ */
+export interface ProviderAttachment {
+  /**
+   * Unique ID of the attachment. Identical data results in the same ID.
+   */
+  id: string;
+  /**
+   * MIME type of the data (e.g. `image/png`).
+   */
+  mimeType: string;
+  /**
+   * Size of the data in bytes.
+   */
+  size: number;
+}
+
+export type ProviderConfig = {
+  /**
+   * How often to sample the input level of the default recording device in
+   * milliseconds. Input level metering is disabled if unset. Only
+   * supported on Windows.
+   *
+   * Note that metering keeps a capture stream open, so Windows will show
+   * the microphone as being in use.
+   */
+  inputLevelInterval?: number | null;
+  type: 'audio';
+} | {
+  refreshInterval: number | string;
+  /**
+   * Alignment and jitter of refreshes.
+   */
+  refreshOptions?: IntervalOptions;
+  type: 'battery';
+} | {
+  refreshInterval: number | string;
+  /**
+   * Alignment and jitter of refreshes.
+   */
+  refreshOptions?: IntervalOptions;
+  type: 'camera';
+} | {
+  /**
+   * CI service to poll.
+   */
+  backend: CiBackend;
+  /**
+   * Base URL of the service, for self-hosted instances. Required for the
+   * Jenkins backend.
+   */
+  baseUrl?: string | null;
+  refreshInterval: number | string;
+  /**
+   * Alignment and jitter of refreshes.
+   */
+  refreshOptions?: IntervalOptions;
+  /**
+   * Repos (or Jenkins jobs) to get the latest pipeline of.
+   */
+  targets: CiTarget[];
+  /**
+   * TLS options for requests to the service.
+   */
+  tls?: TlsConfig | null;
+  /**
+   * Access token. Should be a secret reference (e.g.
+   * `secret:github_token`). Widgets can't use a secret reference together
+   * with `base_url`.
+   */
+  token?: string | null;
+  type: 'ci_status';
+  /**
+   * Username for Jenkins. Used together with `token` as an API token.
+   */
+  username?: string | null;
+} | {
+  /**
+   * Events to count down to.
+   */
+  events: CountdownEventConfig[];
+  refreshInterval: number | string;
+  /**
+   * Alignment and jitter of refreshes.
+   */
+  refreshOptions?: IntervalOptions;
+  type: 'countdown';
+} | {
+  refreshInterval: number | string;
+  /**
+   * Alignment and jitter of refreshes.
+   */
+  refreshOptions?: IntervalOptions;
+  type: 'cpu';
+} | {
+  /**
+   * Providers to derive values from, keyed by the name used to reference
+   * their output in expressions.
+   */
+  providers: Record<string, ProviderConfig>;
+  type: 'derived';
+  /**
+   * Expressions to evaluate, keyed by the name of the output field. For
+   * example, `sum(network.interfaces[].receivedRate)` or `cpu.usage > 80 ?
+   * 'high' : 'normal'`.
+   */
+  values: Record<string, string>;
+} | {
+  /**
+   * Client ID of an app created in the Discord developer portal.
+   */
+  clientId: string;
+  /**
+   * Client secret of the app. Should be a secret reference (e.g.
+   * `secret:discord_client_secret`).
+   */
+  clientSecret: string;
+  refreshInterval: number | string;
+  /**
+   * Alignment and jitter of refreshes.
+   */
+  refreshOptions?: IntervalOptions;
+  /**
+   * TLS options for requests to Discord.
+   */
+  tls?: TlsConfig | null;
+  type: 'discord';
+} | {
+  /**
+   * Glob patterns of file system types to exclude (e.g. `overlay`, `9p`).
+   */
+  excludeFileSystems?: string[];
+  /**
+   * Glob patterns of mount points to exclude.
+   */
+  excludeMountPoints?: string[];
+  /**
+   * Whether to exclude removable disks.
+   */
+  excludeRemovable?: boolean;
+  /**
+   * Glob patterns of file system types to include (e.g. `NTFS`, `ext4`).
+   * All file systems are included if empty.
+   */
+  includeFileSystems?: string[];
+  /**
+   * Glob patterns of mount points to include. All mount points are
+   * included if empty.
+   */
+  includeMountPoints?: string[];
+  /**
+   * Whether to include S.M.A.R.T. health data for physical disks.
+   *
+   * Requires `smartctl` (smartmontools) to be installed, and usually needs
+   * elevated permissions.
+   */
+  includeSmart?: boolean;
+  refreshInterval: number | string;
+  /**
+   * Alignment and jitter of refreshes.
+   */
+  refreshOptions?: IntervalOptions;
+  type: 'disk';
+} | {
+  /**
+   * Windows Event Log channels to watch (e.g. `System`). Ignored on Linux,
+   * where the systemd journal is watched.
+   */
+  channels?: string[];
+  /**
+   * Additional platform-specific filter. On Windows, an XPath query (e.g.
+   * `*[System[Provider[@Name='disk']]]`), which replaces the default query
+   * for `minLevel` and `maxAge`. On Linux, `journalctl` matches (e.g.
+   * `_SYSTEMD_UNIT=sshd.service`).
+   */
+  filter?: string | null;
+  /**
+   * How far back to include entries, in milliseconds.
+   */
+  maxAge: number;
+  /**
+   * Max number of entries to output.
+   */
+  maxEntries: number;
+  /**
+   * Least severe level of entries to include. All levels are included if
+   * `None`.
+   */
+  minLevel?: EventLevel | null;
+  /**
+   * How often to check for new entries.
+   */
+  refreshInterval: number | string;
+  /**
+   * Alignment and jitter of refreshes.
+   */
+  refreshOptions?: IntervalOptions;
+  type: 'event_log';
+} | {
+  refreshInterval: number | string;
+  /**
+   * Alignment and jitter of refreshes.
+   */
+  refreshOptions?: IntervalOptions;
+  type: 'fancy_zones';
+} | {
+  /**
+   * Whether to only measure the focused app if it's fullscreen.
+   */
+  fullscreenOnly: boolean;
+  /**
+   * Path to the PresentMon executable (e.g.
+   * `C:\Tools\PresentMon-2.3.0-x64.exe`).
+   */
+  presentMonPath: string;
+  refreshInterval: number | string;
+  /**
+   * Alignment and jitter of refreshes.
+   */
+  refreshOptions?: IntervalOptions;
+  /**
+   * Duration in milliseconds of recent frames to calculate stats over.
+   */
+  sampleWindow: number;
+  type: 'fps';
+} | {
+  refreshInterval: number | string;
+  /**
+   * Alignment and jitter of refreshes.
+   */
+  refreshOptions?: IntervalOptions;
+  type: 'host';
+} | {
+  type: 'hyprland';
+} | {
+  type: 'i3';
+} | {
+  refreshInterval: number | string;
+  /**
+   * Alignment and jitter of refreshes.
+   */
+  refreshOptions?: IntervalOptions;
+  type: 'input_stats';
+} | {
+  refreshInterval: number | string;
+  /**
+   * Alignment and jitter of refreshes.
+   */
+  refreshOptions?: IntervalOptions;
+  /**
+   * TLS options for requests to IPinfo.
+   */
+  tls?: TlsConfig | null;
+  type: 'ip';
+} | {
+  type: 'keep_awake';
+} | {
+  refreshInterval: number | string;
+  /**
+   * Alignment and jitter of refreshes.
+   */
+  refreshOptions?: IntervalOptions;
+  type: 'keyboard';
+} | {
+  type: 'komorebi';
+} | {
+  type: 'media';
+} | {
+  refreshInterval: number | string;
+  /**
+   * Alignment and jitter of refreshes.
+   */
+  refreshOptions?: IntervalOptions;
+  type: 'meeting';
+} | {
+  refreshInterval: number | string;
+  /**
+   * Alignment and jitter of refreshes.
+   */
+  refreshOptions?: IntervalOptions;
+  type: 'memory';
+} | {
+  refreshInterval: number | string;
+  /**
+   * Alignment and jitter of refreshes.
+   */
+  refreshOptions?: IntervalOptions;
+  type: 'network';
+} | {
+  /**
+   * Counters to sample.
+   */
+  counters: PerfCounterConfig[];
+  refreshInterval: number | string;
+  /**
+   * Alignment and jitter of refreshes.
+   */
+  refreshOptions?: IntervalOptions;
+  type: 'perf_counters';
+} | {
+  /**
+   * Kinds of devices to include. Includes all kinds if empty.
+   */
+  kinds?: PeripheralKind[];
+  refreshInterval: number | string;
+  /**
+   * Alignment and jitter of refreshes.
+   */
+  refreshOptions?: IntervalOptions;
+  type: 'peripherals';
+} | {
+  refreshInterval: number | string;
+  /**
+   * Alignment and jitter of refreshes.
+   */
+  refreshOptions?: IntervalOptions;
+  type: 'printer';
+} | {
+  /**
+   * How often to check the session state. Lock and unlock events are
+   * emitted on the next check after they occur.
+   */
+  refreshInterval: number | string;
+  /**
+   * Alignment and jitter of refreshes.
+   */
+  refreshOptions?: IntervalOptions;
+  type: 'session';
+} | {
+  /**
+   * Client ID of a Spotify app created in the Spotify developer dashboard.
+   */
+  clientId: string;
+  /**
+   * Port of the redirect URI. The app must have
+   * `http://127.0.0.1:<PORT>/callback` registered as a redirect URI.
+   */
+  redirectPort: number;
+  refreshInterval: number | string;
+  /**
+   * Alignment and jitter of refreshes.
+   */
+  refreshOptions?: IntervalOptions;
+  /**
+   * TLS options for requests to Spotify.
+   */
+  tls?: TlsConfig | null;
+  type: 'spotify';
+} | {
+  refreshInterval: number | string;
+  /**
+   * Alignment and jitter of refreshes.
+   */
+  refreshOptions?: IntervalOptions;
+  type: 'ssh_gpg';
+} | {
+  /**
+   * Steam Web API key, for getting the friends list. Should be a secret
+   * reference (e.g. `secret:steam_api_key`).
+   */
+  apiKey?: string | null;
+  refreshInterval: number | string;
+  /**
+   * Alignment and jitter of refreshes.
+   */
+  refreshOptions?: IntervalOptions;
+  /**
+   * SteamID64 of the user, for getting the friends list.
+   */
+  steamId?: string | null;
+  /**
+   * Path to the Steam installation. Detected automatically if not set.
+   */
+  steamPath?: string | null;
+  /**
+   * TLS options for requests to the Steam Web API.
+   */
+  tls?: TlsConfig | null;
+  type: 'steam';
+} | {
+  /**
+   * Todoist API token. Required for the Todoist backend. Should be a
+   * secret reference (e.g. `secret:todoist_token`).
+   */
+  apiToken?: string | null;
+  /**
+   * Service to sync tasks from.
+   */
+  backend: TasksBackend;
+  /**
+   * Client ID of an app registered in Microsoft Entra. Required for the
+   * Microsoft To Do backend.
+   */
+  clientId?: string | null;
+  /**
+   * Todoist filter query (e.g. `today | overdue`) to limit tasks to.
+   */
+  filter?: string | null;
+  /**
+   * ID of the Microsoft To Do list to limit tasks to.
+   */
+  listId?: string | null;
+  /**
+   * Port of the redirect URI for Microsoft To Do. The app must have
+   * `http://127.0.0.1:<PORT>/callback` registered as a redirect URI.
+   */
+  redirectPort: number;
+  refreshInterval: number | string;
+  /**
+   * Alignment and jitter of refreshes.
+   */
+  refreshOptions?: IntervalOptions;
+  /**
+   * TLS options for requests to the backend.
+   */
+  tls?: TlsConfig | null;
+  type: 'tasks';
+} | {
+  /**
+   * How often running timers tick in milliseconds.
+   */
+  refreshInterval: number | string;
+  /**
+   * Alignment and jitter of refreshes.
+   */
+  refreshOptions?: IntervalOptions;
+  type: 'timer';
+} | {
+  refreshInterval: number | string;
+  /**
+   * Alignment and jitter of refreshes.
+   */
+  refreshOptions?: IntervalOptions;
+  type: 'virtual_desktop';
+} | {
+  /**
+   * VPN backend to query.
+   */
+  backend: VpnBackend;
+  /**
+   * Name of the WireGuard interface (e.g. `wg0`). Required for the
+   * WireGuard backend.
+   */
+  interface?: string | null;
+  refreshInterval: number | string;
+  /**
+   * Alignment and jitter of refreshes.
+   */
+  refreshOptions?: IntervalOptions;
+  type: 'vpn';
+} | {
+  /**
+   * Whether to also fetch air quality data each interval.
+   */
+  includeAirQuality?: boolean;
+  latitude?: number | null;
+  longitude?: number | null;
+  refreshInterval: number | string;
+  /**
+   * Alignment and jitter of refreshes.
+   */
+  refreshOptions?: IntervalOptions;
+  /**
+   * TLS options for requests to Open-Meteo and IPinfo.
+   */
+  tls?: TlsConfig | null;
+  type: 'weather';
+};
+
+export type ProviderOutput = AudioOutput | BatteryOutput | CameraOutput | CiStatusOutput | CountdownOutput | CpuOutput | DiscordOutput | DiskOutput | EventLogOutput | FancyZonesOutput | FpsOutput | HostOutput | HyprlandOutput | I3Output | InputStatsOutput | IpOutput | KeepAwakeOutput | KeyboardOutput | KomorebiOutput | MediaOutput | MeetingOutput | MemoryOutput | NetworkOutput | PerfCountersOutput | PeripheralsOutput | PrinterOutput | SessionOutput | SpotifyOutput | SshGpgOutput | SteamOutput | TasksOutput | TimerOutput | VirtualDesktopOutput | VpnOutput | WeatherOutput | Record<string, unknown>;
+
+export type Recurrence = 'none' | 'daily' | 'weekly' | 'monthly' | 'yearly';
+
+export interface SessionOutput {
+  /**
+   * Whether the session that Zebar is running in is in the foreground
+   * (i.e. not switched away from via fast user switching).
+   */
+  isActive: boolean;
+  /**
+   * Whether the session that Zebar is running in is locked.
+   */
+  isLocked: boolean;
+  /**
+   * Login time of the current session as a Unix timestamp in milliseconds.
+   */
+  loginTime: number | null;
+  /**
+   * All user sessions on the machine, including those of other users.
+   */
+  sessions: UserSession[];
+  /**
+   * Name of the user that Zebar is running as.
+   */
+  username: string;
+}
+
+export interface SpotifyDevice {
+  /**
+   * Type of device (e.g. `Computer` or `Smartphone`).
+   */
+  deviceType: string;
+  id: string | null;
+  isActive: boolean;
+  name: string;
+  /**
+   * Volume from 0 to 100. `None` if the device doesn't support volume
+   * control.
+   */
+  volume: number | null;
+}
+
+export interface SpotifyOutput {
+  /**
+   * Device that's currently playing.
+   */
+  device: SpotifyDevice | null;
+  /**
+   * All available playback devices.
+   */
+  devices: SpotifyDevice[];
+  /**
+   * Whether the user has authorized access to their Spotify account. Call
+   * the `authorize` function if `false`.
+   */
+  isAuthorized: boolean;
+  isPlaying: boolean;
+  isShuffled: boolean;
+  /**
+   * Playback position in milliseconds.
+   */
+  position: number;
+  /**
+   * Upcoming tracks in the queue.
+   */
+  queue: SpotifyTrack[];
+  /**
+   * Repeat mode: `off`, `track`, or `context`.
+   */
+  repeat: string;
+  track: SpotifyTrack | null;
+}
+
+export interface SpotifyTrack {
+  album: string | null;
+  albumArtUrl: string | null;
+  artists: string[];
+  /**
+   * Duration in milliseconds.
+   */
+  duration: number;
+  id: string | null;
+  /**
+   * Whether the track is in the user's liked songs. Always `false` for
+   * tracks in the queue.
+   */
+  isLiked: boolean;
+  title: string;
+}
+
+export interface SshGpgOutput {
+  /**
+   * Serial number of the inserted smartcard. Whether its PIN is cached
+   * isn't reported, since checking can prompt for it.
+   */
+  cardSerial: string | null;
+  /**
+   * Number of secret keys known to the GPG agent.
+   */
+  gpgKeyCount: number;
+  /**
+   * Number of GPG keys whose passphrase is cached by the agent.
+   */
+  gpgUnlockedKeyCount: number;
+  /**
+   * Whether an OpenPGP smartcard (e.g. a YubiKey) is inserted.
+   */
+  isCardPresent: boolean;
+  /**
+   * Whether the GPG agent is reachable via `gpg-connect-agent`.
+   */
+  isGpgAgentRunning: boolean;
+  /**
+   * Whether an SSH agent is reachable via `ssh-add`.
+   */
+  isSshAgentRunning: boolean;
+  /**
+   * Keys loaded in the SSH agent.
+   */
+  sshKeys: SshKey[];
+}
+
+export interface SshKey {
+  bits: number;
+  comment: string;
+  fingerprint: string;
+  /**
+   * Key type (e.g. `ED25519` or `RSA`).
+   */
+  keyType: string;
+}
+
+export interface SteamCompletedDownload {
+  appId: number;
+  /**
+   * Unix timestamp in milliseconds of when the download finished.
+   */
+  completedAt: number;
+  name: string;
+}
+
+export interface SteamDownload {
+  appId: number;
+  bytesDownloaded: number;
+  bytesToDownload: number;
+  isPaused: boolean;
+  name: string;
+  /**
+   * Download progress from 0 to 100.
+   */
+  progress: number;
+}
+
+export interface SteamFriend {
+  /**
+   * Name of the game the friend is playing.
+   */
+  game: string | null;
+  name: string;
+  /**
+   * One of `online`, `busy`, `away`, `snooze`, `looking_to_trade`, or
+   * `looking_to_play`.
+   */
+  status: string;
+  steamId: string;
+}
+
+export interface SteamOutput {
+  /**
+   * Downloads that finished while the provider was running, most recent
+   * first.
+   */
+  completedDownloads: SteamCompletedDownload[];
+  /**
+   * Pending, active, and paused downloads.
+   */
+  downloads: SteamDownload[];
+  /**
+   * Number of friends that are online. `None` if `apiKey` and `steamId`
+   * aren't set.
+   */
+  onlineFriendCount: number | null;
+  /**
+   * Friends that are online.
+   */
+  onlineFriends: SteamFriend[];
+}
+
+export interface Task {
+  /**
+   * Due date (e.g. `2025-01-31`), or date and time in RFC 3339 format for
+   * tasks that are due at a specific time.
+   */
+  due: string | null;
+  id: string;
+  isDueToday: boolean;
+  isOverdue: boolean;
+  title: string;
+}
+
+export type TasksBackend = 'todoist' | 'microsoft_todo';
+
+export interface TasksOutput {
+  backend: TasksBackend;
+  dueTodayCount: number;
+  /**
+   * Whether the user has authorized access. Always `true` for the Todoist
+   * backend. Call the `authorize` function if `false`.
+   */
+  isAuthorized: boolean;
+  /**
+   * Open task with the earliest due date.
+   */
+  nextTask: Task | null;
+  openCount: number;
+  overdueCount: number;
+  /**
+   * Open tasks sorted by due date, with undated tasks last.
+   */
+  tasks: Task[];
+}
+
+export type TimerKind = 'stopwatch' | 'timer';
+
+export interface TimerOutput {
+  /**
+   * All timers and stopwatches in creation order.
+   */
+  timers: TimerState[];
+}
+
+export interface TimerState {
+  /**
+   * Total duration in milliseconds. Only set for timers.
+   */
+  duration: number | null;
+  /**
+   * Elapsed time in milliseconds.
+   */
+  elapsed: number;
+  /**
+   * Whether a timer has counted down to zero. Always `false` for
+   * stopwatches.
+   */
+  isFinished: boolean;
+  /**
+   * Whether the timer is counting. `false` once a timer has finished.
+   */
+  isRunning: boolean;
+  kind: TimerKind;
+  /**
+   * Elapsed time in milliseconds at each recorded lap.
+   */
+  laps: number[];
+  name: string;
+  /**
+   * Remaining time in milliseconds. Only set for timers.
+   */
+  remaining: number | null;
+}
+
+/**
+ * TLS options for HTTP providers, e.g. for use behind TLS-inspecting
+ * corporate proxies.
+ */
+export interface TlsConfig {
+  /**
+   * Whether to skip certificate validation entirely. Dangerous, and should
+   * only be used as a last resort.
+   */
+  acceptInvalidCerts?: boolean;
+  /**
+   * Absolute path to a PEM or DER-encoded CA certificate to trust in
+   * addition to the system's root certificates. PEM files can contain
+   * multiple certificates.
+   */
+  caCertPath?: string | null;
+  /**
+   * Whether to only trust the certificates in `ca_cert_path`, which
+   * effectively pins connections to that CA.
+   */
+  pinCaCert?: boolean;
+}
+
+export interface UserSession {
+  id: string;
+  isActive: boolean;
+  /**
+   * Whether this is the session that Zebar is running in.
+   */
+  isCurrent: boolean;
+  isLocked: boolean;
+  /**
+   * Login time as a Unix timestamp in milliseconds.
+   */
+  loginTime: number | null;
+  username: string;
+}
+
+export interface VirtualDesktop {
+  /**
+   * GUID of the desktop (e.g. `{0F6B4C2A-...}`).
+   */
+  id: string;
+  index: number;
+  /**
+   * User-assigned name of the desktop. Defaults to `Desktop <n>`, as shown
+   * in the task view.
+   */
+  name: string;
+}
+
+export interface VirtualDesktopOutput {
+  currentDesktop: VirtualDesktop | null;
+  desktops: VirtualDesktop[];
+}
+
+export type VpnBackend = 'tailscale' | 'wireguard';
+
+export interface VpnOutput {
+  backend: VpnBackend;
+  exitNode: string | null;
+  isConnected: boolean;
+  onlinePeerCount: number;
+  peerCount: number;
+  relay: string | null;
+  state: string;
+}
+
+export interface WeatherOutput {
+  /**
+   * Air quality data. `None` if `include_air_quality` is disabled.
+   */
+  airQuality: AirQuality | null;
+  celsiusTemp: number;
+  fahrenheitTemp: number;
+  isDaytime: boolean;
+  status: WeatherStatus;
+  windSpeed: number;
+}
+
+export type WeatherStatus = 'clear_day' | 'clear_night' | 'cloudy_day' | 'cloudy_night' | 'light_rain_day' | 'light_rain_night' | 'heavy_rain_day' | 'heavy_rain_night' | 'snow_day' | 'snow_night' | 'thunder_day' | 'thunder_night';
+
+export interface ZonedWindow {
+  /**
+   * Whether the window is snapped via Windows' own snapping (e.g. snap
+   * layouts on Windows 11).
+   */
+  isSnapped: boolean;
+  /**
+   * FancyZones layout that `zones` belong to.
+   */
+  layout: FancyZonesLayout | null;
+  /**
+   * Executable name of the window's process (e.g. `firefox.exe`).
+   */
+  processName: string;
+  /**
+   * Indices of the FancyZones zones that the app was last snapped to,
+   * within `layout`. Empty if it hasn't been snapped with the current
+   * layout.
+   */
+  zones: number[];
+}
@@ -0,0 +1,27 @@
+import { z } from 'zod';
+
+import { durationSchema, type Duration } from '~/utils';
+
+/**
+ * Alignment and jitter of a provider's refreshes.
+ */
+export interface RefreshOptions {
+  /**
+   * Whether to refresh on multiples of `refreshInterval` since midnight
+   * UTC (e.g. exactly on the minute for an interval of `'1m'`). The first
+   * refresh still occurs immediately. Defaults to `false`.
+   */
+  align?: boolean;
+
+  /**
+   * Max random delay to add to each refresh, either in milliseconds or as
+   * a string with a unit (e.g. `'30s'`). Avoids providers hitting an
+   * external API at the same time (e.g. on startup).
+   */
+  jitter?: Duration;
+}
+
+export const refreshOptionsSchema = z.object({
+  align: z.boolean().default(false),
+  jitter: durationSchema.optional(),
+});
@@ -0,0 +1,33 @@
+import { z } from 'zod';
+
+import { createBaseProvider } from '../create-base-provider';
+import { refreshOptionsSchema } from '../refresh-options';
+import { onProviderEmit } from '~/desktop';
+import { durationSchema } from '~/utils';
+import type {
+  SessionOutput,
+  SessionProvider,
+  SessionProviderConfig,
+} from './session-provider-types';
+
+const sessionProviderConfigSchema = z.object({
+  type: z.literal('session'),
+  refreshInterval: durationSchema.default(1000),
+  refreshOptions: refreshOptionsSchema.optional(),
+});
+
+export function createSessionProvider(
+  config: SessionProviderConfig,
+): SessionProvider {
+  const mergedConfig = sessionProviderConfigSchema.parse(config);
+
+  return createBaseProvider(mergedConfig, async queue => {
+    return onProviderEmit<SessionOutput>(mergedConfig, ({ result }) => {
+      if ('error' in result) {
+        queue.error(result.error);
+      } else {
+        queue.output(result.output);
+      }
+    });
+  });
+}
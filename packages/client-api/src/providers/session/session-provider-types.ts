@@ -0,0 +1,30 @@
+import type { Duration } from '~/utils';
+import type { Provider } from '../create-base-provider';
+import type { RefreshOptions } from '../refresh-options';
+import type { SessionOutput } from '../provider-types.generated';
+
+export type {
+  SessionOutput,
+  UserSession,
+} from '../provider-types.generated';
+
+export interface SessionProviderConfig {
+  type: 'session';
+
+  /**
+   * How often this provider refreshes, either in milliseconds or as a
+   * string with a unit (e.g. `'5s'`). Lock and unlock
+   * events are emitted on the next refresh after they occur.
+   */
+  refreshInterval?: Duration;
+
+  /**
+   * Alignment and jitter of refreshes.
+   */
+  refreshOptions?: RefreshOptions;
+}
+
+export type SessionProvider = Provider<
+  SessionProviderConfig,
+  SessionOutput
+>;
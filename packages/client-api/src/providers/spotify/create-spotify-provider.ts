@@ -0,0 +1,76 @@
+import { z } from 'zod';
+
+import { createBaseProvider } from '../create-base-provider';
+import { refreshOptionsSchema } from '../refresh-options';
+import { tlsConfigSchema } from '../tls-config';
+import {
+  desktopCommands,
+  onProviderEmit,
+  type SpotifyFunction,
+} from '~/desktop';
+import { durationSchema } from '~/utils';
+import type {
+  SpotifyOutput,
+  SpotifyProvider,
+  SpotifyProviderConfig,
+} from './spotify-provider-types';
+
+const spotifyProviderConfigSchema = z.object({
+  type: z.literal('spotify'),
+  refreshInterval: durationSchema.default(5 * 1000),
+  refreshOptions: refreshOptionsSchema.optional(),
+  clientId: z.string(),
+  redirectPort: z.coerce.number().default(8974),
+  tls: tlsConfigSchema.optional(),
+});
+
+export function createSpotifyProvider(
+  config: SpotifyProviderConfig,
+): SpotifyProvider {
+  const mergedConfig = spotifyProviderConfigSchema.parse(config);
+
+  return createBaseProvider(mergedConfig, async queue => {
+    return onProviderEmit<SpotifyOutput>(
+      mergedConfig,
+      ({ configHash, result }) => {
+        if ('error' in result) {
+          queue.error(result.error);
+          return;
+        }
+
+        const call = (fn: SpotifyFunction['function']) => {
+          return desktopCommands.callProviderFunction(configHash, {
+            type: 'spotify',
+            function: fn,
+          });
+        };
+
+        queue.output({
+          ...result.output,
+          authorize: () => call({ name: 'authorize' }),
+          logout: () => call({ name: 'logout' }),
+          play: () => call({ name: 'play' }),
+          pause: () => call({ name: 'pause' }),
+          togglePlayPause: () => call({ name: 'toggle_play_pause' }),
+          next: () => call({ name: 'next' }),
+          previous: () => call({ name: 'previous' }),
+          seek: (position: number) => {
+            return call({ name: 'seek', args: { position } });
+          },
+          setVolume: (volume: number) => {
+            return call({ name: 'set_volume', args: { volume } });
+          },
+          transferPlayback: (deviceId: string, play?: boolean) => {
+            return call({
+              name: 'transfer_playback',
+              args: { deviceId, play },
+            });
+          },
+          setLiked: (isLiked: boolean) => {
+            return call({ name: 'set_liked', args: { isLiked } });
+          },
+        });
+      },
+    );
+  });
+}
@@ -0,0 +1,81 @@
+import type { Duration } from '~/utils';
+import type { Provider } from '../create-base-provider';
+import type { RefreshOptions } from '../refresh-options';
+import type { TlsConfig } from '../tls-config';
+import type {
+  SpotifyOutput as GeneratedSpotifyOutput,
+} from '../provider-types.generated';
+
+export type {
+  SpotifyTrack,
+  SpotifyDevice,
+} from '../provider-types.generated';
+
+export interface SpotifyProviderConfig {
+  type: 'spotify';
+
+  /**
+   * How often this provider refreshes, either in milliseconds or as a
+   * string with a unit (e.g. `'5s'`, `'2m'`, or `'1h'`).
+   */
+  refreshInterval?: Duration;
+
+  /**
+   * Alignment and jitter of refreshes.
+   */
+  refreshOptions?: RefreshOptions;
+
+  /**
+   * Client ID of a Spotify app created in the Spotify developer
+   * dashboard.
+   */
+  clientId: string;
+
+  /**
+   * Port of the redirect URI. The Spotify app must have
+   * `http://127.0.0.1:<PORT>/callback` registered as a redirect URI.
+   */
+  redirectPort?: number;
+
+  /**
+   * TLS options for HTTP requests made by the provider.
+   */
+  tls?: TlsConfig;
+}
+
+export type SpotifyProvider = Provider<
+  SpotifyProviderConfig,
+  SpotifyOutput
+>;
+
+export interface SpotifyOutput extends GeneratedSpotifyOutput {
+  repeat: 'off' | 'track' | 'context';
+
+  /**
+   * Opens the Spotify login page in the browser. Resolves once access
+   * has been granted.
+   */
+  authorize(): Promise<void>;
+  logout(): Promise<void>;
+  play(): Promise<void>;
+  pause(): Promise<void>;
+  togglePlayPause(): Promise<void>;
+  next(): Promise<void>;
+  previous(): Promise<void>;
+
+  /**
+   * Seeks to a position in milliseconds.
+   */
+  seek(position: number): Promise<void>;
+
+  /**
+   * Sets the volume of the active device (0-100).
+   */
+  setVolume(volume: number): Promise<void>;
+  transferPlayback(deviceId: string, play?: boolean): Promise<void>;
+
+  /**
+   * Adds or removes the current track from the user's liked songs.
+   */
+  setLiked(isLiked: boolean): Promise<void>;
+}
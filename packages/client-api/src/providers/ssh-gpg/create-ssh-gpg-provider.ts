@@ -0,0 +1,33 @@
+import { z } from 'zod';
+
+import { createBaseProvider } from '../create-base-provider';
+import { refreshOptionsSchema } from '../refresh-options';
+import { onProviderEmit } from '~/desktop';
+import { durationSchema } from '~/utils';
+import type {
+  SshGpgOutput,
+  SshGpgProvider,
+  SshGpgProviderConfig,
+} from './ssh-gpg-provider-types';
+
+const sshGpgProviderConfigSchema = z.object({
+  type: z.literal('ssh_gpg'),
+  refreshInterval: durationSchema.default(10 * 1000),
+  refreshOptions: refreshOptionsSchema.optional(),
+});
+
+export function createSshGpgProvider(
+  config: SshGpgProviderConfig,
+): SshGpgProvider {
+  const mergedConfig = sshGpgProviderConfigSchema.parse(config);
+
+  return createBaseProvider(mergedConfig, async queue => {
+    return onProviderEmit<SshGpgOutput>(mergedConfig, ({ result }) => {
+      if ('error' in result) {
+        queue.error(result.error);
+      } else {
+        queue.output(result.output);
+      }
+    });
+  });
+}
@@ -0,0 +1,23 @@
+import type { Duration } from '~/utils';
+import type { Provider } from '../create-base-provider';
+import type { RefreshOptions } from '../refresh-options';
+import type { SshGpgOutput } from '../provider-types.generated';
+
+export type { SshGpgOutput, SshKey } from '../provider-types.generated';
+
+export interface SshGpgProviderConfig {
+  type: 'ssh_gpg';
+
+  /**
+   * How often this provider refreshes, either in milliseconds or as a
+   * string with a unit (e.g. `'5s'`, `'2m'`, or `'1h'`).
+   */
+  refreshInterval?: Duration;
+
+  /**
+   * Alignment and jitter of refreshes.
+   */
+  refreshOptions?: RefreshOptions;
+}
+
+export type SshGpgProvider = Provider<SshGpgProviderConfig, SshGpgOutput>;
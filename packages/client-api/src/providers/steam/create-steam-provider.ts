@@ -0,0 +1,38 @@
+import { z } from 'zod';
+
+import { createBaseProvider } from '../create-base-provider';
+import { refreshOptionsSchema } from '../refresh-options';
+import { tlsConfigSchema } from '../tls-config';
+import { onProviderEmit } from '~/desktop';
+import { durationSchema } from '~/utils';
+import type {
+  SteamOutput,
+  SteamProvider,
+  SteamProviderConfig,
+} from './steam-provider-types';
+
+const steamProviderConfigSchema = z.object({
+  type: z.literal('steam'),
+  refreshInterval: durationSchema.default(10 * 1000),
+  refreshOptions: refreshOptionsSchema.optional(),
+  steamPath: z.string().optional(),
+  apiKey: z.string().optional(),
+  steamId: z.string().optional(),
+  tls: tlsConfigSchema.optional(),
+});
+
+export function createSteamProvider(
+  config: SteamProviderConfig,
+): SteamProvider {
+  const mergedConfig = steamProviderConfigSchema.parse(config);
+
+  return createBaseProvider(mergedConfig, async queue => {
+    return onProviderEmit<SteamOutput>(mergedConfig, ({ result }) => {
+      if ('error' in result) {
+        queue.error(result.error);
+      } else {
+        queue.output(result.output);
+      }
+    });
+  });
+}
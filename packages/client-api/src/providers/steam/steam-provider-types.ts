@@ -0,0 +1,65 @@
+import type { Duration } from '~/utils';
+import type { Provider } from '../create-base-provider';
+import type { RefreshOptions } from '../refresh-options';
+import type { TlsConfig } from '../tls-config';
+import type {
+  SteamOutput as GeneratedSteamOutput,
+  SteamFriend as GeneratedSteamFriend,
+} from '../provider-types.generated';
+
+export type {
+  SteamDownload,
+  SteamCompletedDownload,
+} from '../provider-types.generated';
+
+export interface SteamProviderConfig {
+  type: 'steam';
+
+  /**
+   * How often this provider refreshes, either in milliseconds or as a
+   * string with a unit (e.g. `'5s'`, `'2m'`, or `'1h'`).
+   */
+  refreshInterval?: Duration;
+
+  /**
+   * Alignment and jitter of refreshes.
+   */
+  refreshOptions?: RefreshOptions;
+
+  /**
+   * Path to the Steam installation. Detected automatically if not set.
+   */
+  steamPath?: string;
+
+  /**
+   * Steam Web API key, for getting the friends list. Should be a secret
+   * reference (e.g. `secret:steam_api_key`).
+   */
+  apiKey?: string;
+
+  /**
+   * SteamID64 of the user, for getting the friends list.
+   */
+  steamId?: string;
+
+  /**
+   * TLS options for HTTP requests made by the provider.
+   */
+  tls?: TlsConfig;
+}
+
+export type SteamProvider = Provider<SteamProviderConfig, SteamOutput>;
+
+export interface SteamOutput extends GeneratedSteamOutput {
+  onlineFriends: SteamFriend[];
+}
+
+export interface SteamFriend extends GeneratedSteamFriend {
+  status:
+    | 'online'
+    | 'busy'
+    | 'away'
+    | 'snooze'
+    | 'looking_to_trade'
+    | 'looking_to_play';
+}
@@ -0,0 +1,64 @@
+import { z } from 'zod';
+
+import { createBaseProvider } from '../create-base-provider';
+import { refreshOptionsSchema } from '../refresh-options';
+import { tlsConfigSchema } from '../tls-config';
+import { desktopCommands, onProviderEmit } from '~/desktop';
+import { durationSchema } from '~/utils';
+import type {
+  TasksOutput,
+  TasksProvider,
+  TasksProviderConfig,
+} from './tasks-provider-types';
+
+const tasksProviderConfigSchema = z.object({
+  type: z.literal('tasks'),
+  refreshInterval: durationSchema.default(60 * 1000),
+  refreshOptions: refreshOptionsSchema.optional(),
+  backend: z.enum(['todoist', 'microsoft_todo']),
+  apiToken: z.string().optional(),
+  filter: z.string().optional(),
+  clientId: z.string().optional(),
+  redirectPort: z.coerce.number().default(8975),
+  listId: z.string().optional(),
+  tls: tlsConfigSchema.optional(),
+});
+
+export function createTasksProvider(
+  config: TasksProviderConfig,
+): TasksProvider {
+  const mergedConfig = tasksProviderConfigSchema.parse(config);
+
+  return createBaseProvider(mergedConfig, async queue => {
+    return onProviderEmit<TasksOutput>(
+      mergedConfig,
+      ({ configHash, result }) => {
+        if ('error' in result) {
+          queue.error(result.error);
+        } else {
+          queue.output({
+            ...result.output,
+            authorize: () => {
+              return desktopCommands.callProviderFunction(configHash, {
+                type: 'tasks',
+                function: { name: 'authorize' },
+              });
+            },
+            logout: () => {
+              return desktopCommands.callProviderFunction(configHash, {
+                type: 'tasks',
+                function: { name: 'logout' },
+              });
+            },
+            completeTask: (taskId: string) => {
+              return desktopCommands.callProviderFunction(configHash, {
+                type: 'tasks',
+                function: { name: 'complete_task', args: { taskId } },
+              });
+            },
+          });
+        }
+      },
+    );
+  });
+}
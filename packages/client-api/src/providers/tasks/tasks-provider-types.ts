@@ -0,0 +1,75 @@
+import type { Duration } from '~/utils';
+import type { Provider } from '../create-base-provider';
+import type { RefreshOptions } from '../refresh-options';
+import type { TlsConfig } from '../tls-config';
+import type {
+  TasksBackend,
+  TasksOutput as GeneratedTasksOutput,
+} from '../provider-types.generated';
+
+export type { TasksBackend, Task } from '../provider-types.generated';
+
+export interface TasksProviderConfig {
+  type: 'tasks';
+
+  /**
+   * How often this provider refreshes, either in milliseconds or as a
+   * string with a unit (e.g. `'5s'`, `'2m'`, or `'1h'`).
+   */
+  refreshInterval?: Duration;
+
+  /**
+   * Alignment and jitter of refreshes.
+   */
+  refreshOptions?: RefreshOptions;
+
+  /**
+   * Service to sync tasks from.
+   */
+  backend: TasksBackend;
+
+  /**
+   * Todoist API token. Required for the `todoist` backend. Should be a
+   * secret reference (e.g. `secret:todoist_token`).
+   */
+  apiToken?: string;
+
+  /**
+   * Todoist filter query (e.g. `today | overdue`) to limit tasks to.
+   */
+  filter?: string;
+
+  /**
+   * Client ID of an app registered in Microsoft Entra. Required for the
+   * `microsoft_todo` backend.
+   */
+  clientId?: string;
+
+  /**
+   * Port of the redirect URI for Microsoft To Do. The app must have
+   * `http://127.0.0.1:<PORT>/callback` registered as a redirect URI.
+   */
+  redirectPort?: number;
+
+  /**
+   * ID of the Microsoft To Do list to limit tasks to.
+   */
+  listId?: string;
+
+  /**
+   * TLS options for HTTP requests made by the provider.
+   */
+  tls?: TlsConfig;
+}
+
+export type TasksProvider = Provider<TasksProviderConfig, TasksOutput>;
+
+export interface TasksOutput extends GeneratedTasksOutput {
+  /**
+   * Opens the Microsoft login page in the browser. Resolves once access
+   * has been granted. Only supported by the `microsoft_todo` backend.
+   */
+  authorize(): Promise<void>;
+  logout(): Promise<void>;
+  completeTask(taskId: string): Promise<void>;
+}
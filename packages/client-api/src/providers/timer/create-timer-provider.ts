@@ -0,0 +1,63 @@
+import { z } from 'zod';
+
+import { createBaseProvider } from '../create-base-provider';
+import { refreshOptionsSchema } from '../refresh-options';
+import { desktopCommands, onProviderEmit } from '~/desktop';
+import { durationSchema } from '~/utils';
+import type {
+  CreateTimerOptions,
+  TimerOutput,
+  TimerProvider,
+  TimerProviderConfig,
+} from './timer-provider-types';
+
+const timerProviderConfigSchema = z.object({
+  type: z.literal('timer'),
+  refreshInterval: durationSchema.default(1000),
+  refreshOptions: refreshOptionsSchema.optional(),
+});
+
+export function createTimerProvider(
+  config: TimerProviderConfig,
+): TimerProvider {
+  const mergedConfig = timerProviderConfigSchema.parse(config);
+
+  return createBaseProvider(mergedConfig, async queue => {
+    return onProviderEmit<TimerOutput>(
+      mergedConfig,
+      ({ configHash, result }) => {
+        if ('error' in result) {
+          queue.error(result.error);
+        } else {
+          function callTimerFunction(
+            name: 'start' | 'pause' | 'lap' | 'reset' | 'cancel',
+            timerName: string,
+          ) {
+            return desktopCommands.callProviderFunction(configHash, {
+              type: 'timer',
+              function: { name, args: { name: timerName } },
+            });
+          }
+
+          queue.output({
+            ...result.output,
+            createTimer: (name: string, options?: CreateTimerOptions) => {
+              return desktopCommands.callProviderFunction(configHash, {
+                type: 'timer',
+                function: {
+                  name: 'create_timer',
+                  args: { name, ...options },
+                },
+              });
+            },
+            start: name => callTimerFunction('start', name),
+            pause: name => callTimerFunction('pause', name),
+            lap: name => callTimerFunction('lap', name),
+            reset: name => callTimerFunction('reset', name),
+            cancel: name => callTimerFunction('cancel', name),
+          });
+        }
+      },
+    );
+  });
+}
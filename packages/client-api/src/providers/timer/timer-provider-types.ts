@@ -0,0 +1,71 @@
+import type { Duration } from '~/utils';
+import type { Provider } from '../create-base-provider';
+import type { RefreshOptions } from '../refresh-options';
+import type {
+  TimerOutput as GeneratedTimerOutput,
+} from '../provider-types.generated';
+
+export type { TimerState } from '../provider-types.generated';
+
+export interface TimerProviderConfig {
+  type: 'timer';
+
+  /**
+   * How often running timers tick, either in milliseconds or as a string
+   * with a unit (e.g. `'1s'`).
+   */
+  refreshInterval?: Duration;
+
+  /**
+   * Alignment and jitter of refreshes.
+   */
+  refreshOptions?: RefreshOptions;
+}
+
+export type TimerProvider = Provider<TimerProviderConfig, TimerOutput>;
+
+export interface TimerOutput extends GeneratedTimerOutput {
+  /**
+   * Creates a timer (if `duration` is set) or a stopwatch. Replaces any
+   * existing timer with the same name.
+   */
+  createTimer(name: string, options?: CreateTimerOptions): Promise<void>;
+
+  /**
+   * Starts or resumes a timer.
+   */
+  start(name: string): Promise<void>;
+
+  /**
+   * Pauses a timer, keeping its elapsed time.
+   */
+  pause(name: string): Promise<void>;
+
+  /**
+   * Records the current elapsed time as a lap.
+   */
+  lap(name: string): Promise<void>;
+
+  /**
+   * Stops a timer and clears its elapsed time and laps.
+   */
+  reset(name: string): Promise<void>;
+
+  /**
+   * Removes a timer.
+   */
+  cancel(name: string): Promise<void>;
+}
+
+export interface CreateTimerOptions {
+  /**
+   * Duration in milliseconds to count down from. Creates a stopwatch if
+   * not set.
+   */
+  duration?: number;
+
+  /**
+   * Whether to start immediately. Defaults to `true`.
+   */
+  start?: boolean;
+}
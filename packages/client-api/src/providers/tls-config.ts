@@ -0,0 +1,31 @@
+import { z } from 'zod';
+
+/**
+ * TLS options for providers that make HTTP requests, e.g. for use behind
+ * TLS-inspecting corporate proxies.
+ */
+export interface TlsConfig {
+  /**
+   * Absolute path to a PEM or DER-encoded CA certificate to trust in
+   * addition to the system's root certificates.
+   */
+  caCertPath?: string;
+
+  /**
+   * Whether to only trust the certificates in `caCertPath`, which
+   * effectively pins connections to that CA. Defaults to `false`.
+   */
+  pinCaCert?: boolean;
+
+  /**
+   * Whether to skip certificate validation entirely. Dangerous, and
+   * should only be used as a last resort. Defaults to `false`.
+   */
+  acceptInvalidCerts?: boolean;
+}
+
+export const tlsConfigSchema = z.object({
+  caCertPath: z.string().optional(),
+  pinCaCert: z.boolean().default(false),
+  acceptInvalidCerts: z.boolean().default(false),
+});
@@ -0,0 +1,36 @@
+import { z } from 'zod';
+
+import { createBaseProvider } from '../create-base-provider';
+import { refreshOptionsSchema } from '../refresh-options';
+import { onProviderEmit } from '~/desktop';
+import { durationSchema } from '~/utils';
+import type {
+  VirtualDesktopOutput,
+  VirtualDesktopProvider,
+  VirtualDesktopProviderConfig,
+} from './virtual-desktop-provider-types';
+
+const virtualDesktopProviderConfigSchema = z.object({
+  type: z.literal('virtual_desktop'),
+  refreshInterval: durationSchema.default(1000),
+  refreshOptions: refreshOptionsSchema.optional(),
+});
+
+export function createVirtualDesktopProvider(
+  config: VirtualDesktopProviderConfig,
+): VirtualDesktopProvider {
+  const mergedConfig = virtualDesktopProviderConfigSchema.parse(config);
+
+  return createBaseProvider(mergedConfig, async queue => {
+    return onProviderEmit<VirtualDesktopOutput>(
+      mergedConfig,
+      ({ result }) => {
+        if ('error' in result) {
+          queue.error(result.error);
+        } else {
+          queue.output(result.output);
+        }
+      },
+    );
+  });
+}
@@ -0,0 +1,29 @@
+import type { Duration } from '~/utils';
+import type { Provider } from '../create-base-provider';
+import type { RefreshOptions } from '../refresh-options';
+import type { VirtualDesktopOutput } from '../provider-types.generated';
+
+export type {
+  VirtualDesktopOutput,
+  VirtualDesktop,
+} from '../provider-types.generated';
+
+export interface VirtualDesktopProviderConfig {
+  type: 'virtual_desktop';
+
+  /**
+   * How often this provider refreshes, either in milliseconds or as a
+   * string with a unit (e.g. `'5s'`, `'2m'`, or `'1h'`).
+   */
+  refreshInterval?: Duration;
+
+  /**
+   * Alignment and jitter of refreshes.
+   */
+  refreshOptions?: RefreshOptions;
+}
+
+export type VirtualDesktopProvider = Provider<
+  VirtualDesktopProviderConfig,
+  VirtualDesktopOutput
+>;
@@ -0,0 +1,50 @@
+import { z } from 'zod';
+
+import { createBaseProvider } from '../create-base-provider';
+import { refreshOptionsSchema } from '../refresh-options';
+import { desktopCommands, onProviderEmit } from '~/desktop';
+import { durationSchema } from '~/utils';
+import type {
+  VpnOutput,
+  VpnProvider,
+  VpnProviderConfig,
+} from './vpn-provider-types';
+
+const vpnProviderConfigSchema = z.object({
+  type: z.literal('vpn'),
+  refreshInterval: durationSchema.default(5 * 1000),
+  refreshOptions: refreshOptionsSchema.optional(),
+  backend: z.enum(['tailscale', 'wireguard']),
+  interface: z.string().optional(),
+});
+
+export function createVpnProvider(config: VpnProviderConfig): VpnProvider {
+  const mergedConfig = vpnProviderConfigSchema.parse(config);
+
+  return createBaseProvider(mergedConfig, async queue => {
+    return onProviderEmit<VpnOutput>(
+      mergedConfig,
+      ({ configHash, result }) => {
+        if ('error' in result) {
+          queue.error(result.error);
+        } else {
+          queue.output({
+            ...result.output,
+            connect: () => {
+              return desktopCommands.callProviderFunction(configHash, {
+                type: 'vpn',
+                function: { name: 'connect' },
+              });
+            },
+            disconnect: () => {
+              return desktopCommands.callProviderFunction(configHash, {
+                type: 'vpn',
+                function: { name: 'disconnect' },
+              });
+            },
+          });
+        }
+      },
+    );
+  });
+}
@@ -0,0 +1,42 @@
+import type { Duration } from '~/utils';
+import type { Provider } from '../create-base-provider';
+import type { RefreshOptions } from '../refresh-options';
+import type {
+  VpnBackend,
+  VpnOutput as GeneratedVpnOutput,
+} from '../provider-types.generated';
+
+export type { VpnBackend } from '../provider-types.generated';
+
+export interface VpnProviderConfig {
+  type: 'vpn';
+
+  /**
+   * How often this provider refreshes, either in milliseconds or as a
+   * string with a unit (e.g. `'5s'`, `'2m'`, or `'1h'`).
+   */
+  refreshInterval?: Duration;
+
+  /**
+   * Alignment and jitter of refreshes.
+   */
+  refreshOptions?: RefreshOptions;
+
+  /**
+   * VPN backend to query.
+   */
+  backend: VpnBackend;
+
+  /**
+   * Name of the WireGuard interface (e.g. `wg0`). Required for the
+   * `wireguard` backend.
+   */
+  interface?: string;
+}
+
+export type VpnProvider = Provider<VpnProviderConfig, VpnOutput>;
+
+export interface VpnOutput extends GeneratedVpnOutput {
+  connect(): Promise<void>;
+  disconnect(): Promise<void>;
+}
@@ -1,7 +1,10 @@
 import { z } from 'zod';
 
 import { createBaseProvider } from '../create-base-provider';
+import { refreshOptionsSchema } from '../refresh-options';
+import { tlsConfigSchema } from '../tls-config';
 import { onProviderEmit } from '~/desktop';
+import { durationSchema } from '~/utils';
 import type {
   WeatherOutput,
   WeatherProvider,
@@ -12,7 +15,10 @@ const weatherProviderConfigSchema = z.object({
   type: z.literal('weather'),
   latitude: z.coerce.number().optional(),
   longitude: z.coerce.number().optional(),
-  refreshInterval: z.coerce.number().default(60 * 60 * 1000),
+  refreshInterval: durationSchema.default(60 * 60 * 1000),
+  refreshOptions: refreshOptionsSchema.optional(),
+  includeAirQuality: z.boolean().default(false),
+  tls: tlsConfigSchema.optional(),
 });
 
 export function createWeatherProvider(
@@ -1,4 +1,14 @@
+import type { Duration } from '~/utils';
 import type { Provider } from '../create-base-provider';
+import type { RefreshOptions } from '../refresh-options';
+import type { TlsConfig } from '../tls-config';
+import type { WeatherOutput } from '../provider-types.generated';
+
+export type {
+  WeatherOutput,
+  AirQuality,
+  WeatherStatus,
+} from '../provider-types.generated';
 
 export interface WeatherProviderConfig {
   type: 'weather';
@@ -16,34 +26,29 @@ export interface WeatherProviderConfig {
   longitude?: number;
 
   /**
-   * How often this provider refreshes in milliseconds.
+   * How often this provider refreshes, either in milliseconds or as a
+   * string with a unit (e.g. `'5s'`, `'2m'`, or `'1h'`).
+   */
+  refreshInterval?: Duration;
+
+  /**
+   * Alignment and jitter of refreshes.
+   */
+  refreshOptions?: RefreshOptions;
+
+  /**
+   * Whether to also fetch air quality data (AQI, PM2.5, PM10). Defaults
+   * to `false`.
    */
-  refreshInterval?: number;
+  includeAirQuality?: boolean;
+
+  /**
+   * TLS options for HTTP requests made by the provider.
+   */
+  tls?: TlsConfig;
 }
 
 export type WeatherProvider = Provider<
   WeatherProviderConfig,
   WeatherOutput
 >;
-
-export interface WeatherOutput {
-  isDaytime: boolean;
-  status: WeatherStatus;
-  celsiusTemp: number;
-  fahrenheitTemp: number;
-  windSpeed: number;
-}
-
-export type WeatherStatus =
-  | 'clear_day'
-  | 'clear_night'
-  | 'cloudy_day'
-  | 'cloudy_night'
-  | 'light_rain_day'
-  | 'light_rain_night'
-  | 'heavy_rain_day'
-  | 'heavy_rain_night'
-  | 'snow_day'
-  | 'snow_night'
-  | 'thunder_day'
-  | 'thunder_night';
@@ -0,0 +1,352 @@
+import { z } from 'zod';
+
+import { getMonitors, type Monitor } from '~/desktop';
+import { getCoordinateDistance } from '~/utils';
+import { createBaseProvider, type Provider } from '../create-base-provider';
+import { createGlazeWmProvider } from '../glazewm/create-glazewm-provider';
+import type { GlazeWmOutput } from '../glazewm/glazewm-provider-types';
+import { createHyprlandProvider } from '../hyprland/create-hyprland-provider';
+import type { HyprlandOutput } from '../hyprland/hyprland-provider-types';
+import { createI3Provider } from '../i3/create-i3-provider';
+import type { I3Output } from '../i3/i3-provider-types';
+import { createKomorebiProvider } from '../komorebi/create-komorebi-provider';
+import type { KomorebiOutput } from '../komorebi/komorebi-provider-types';
+import { createVirtualDesktopProvider } from '../virtual-desktop/create-virtual-desktop-provider';
+import type { VirtualDesktopOutput } from '../virtual-desktop/virtual-desktop-provider-types';
+import type {
+  WmWorkspace,
+  WorkspacesBackend,
+  WorkspacesOutput,
+  WorkspacesProvider,
+  WorkspacesProviderConfig,
+} from './workspaces-provider-types';
+
+const workspacesProviderConfigSchema = z.object({
+  type: z.literal('workspaces'),
+  backend: z
+    .enum(['auto', 'glazewm', 'komorebi', 'hyprland', 'i3', 'native'])
+    .default('auto'),
+});
+
+/**
+ * Order in which backends are tried when the backend is `auto`. Native
+ * virtual desktops are tried last, since tiling WMs run on top of them.
+ */
+const AUTO_BACKENDS: WorkspacesBackend[] = [
+  'glazewm',
+  'komorebi',
+  'hyprland',
+  'i3',
+  'native',
+];
+
+/**
+ * Delay in milliseconds before re-running detection if no backend is
+ * running.
+ */
+const DETECTION_RETRY_DELAY = 5000;
+
+export function createWorkspacesProvider(
+  config: WorkspacesProviderConfig,
+): WorkspacesProvider {
+  const mergedConfig = workspacesProviderConfigSchema.parse(config);
+
+  return createBaseProvider(mergedConfig, async queue => {
+    const monitors = await getMonitors();
+
+    let backendProvider: Provider<unknown, unknown> | null = null;
+    let retryTimeout: ReturnType<typeof setTimeout> | null = null;
+
+    if (mergedConfig.backend === 'auto') {
+      detectBackend(0);
+    } else {
+      backendProvider = listenBackend(mergedConfig.backend, {
+        output: queue.output,
+        error: queue.error,
+      });
+    }
+
+    /**
+     * Tries each backend in order until one emits an output.
+     */
+    function detectBackend(index: number) {
+      const backend = AUTO_BACKENDS[index];
+
+      if (!backend) {
+        queue.error('No supported window manager is running.');
+        retryTimeout = setTimeout(
+          () => detectBackend(0),
+          DETECTION_RETRY_DELAY,
+        );
+        return;
+      }
+
+      let isDetected = false;
+      let isRejected = false;
+
+      backendProvider = listenBackend(backend, {
+        output: output => {
+          isDetected = true;
+          queue.output(output);
+        },
+        error: error => {
+          if (isDetected) {
+            queue.error(error);
+          } else if (!isRejected) {
+            // Backend isn't running, so move on to the next one.
+            isRejected = true;
+            backendProvider?.stop();
+            detectBackend(index + 1);
+          }
+        },
+      });
+    }
+
+    function listenBackend(
+      backend: WorkspacesBackend,
+      callbacks: {
+        output: (output: WorkspacesOutput) => void;
+        error: (error: string) => void;
+      },
+    ): Provider<unknown, unknown> {
+      const provider = createBackendProvider(backend);
+
+      provider.onOutput(output => {
+        const currentMonitor = monitors.currentMonitor;
+        callbacks.output(transformOutput(backend, output, currentMonitor));
+      });
+
+      provider.onError(callbacks.error);
+
+      return provider;
+    }
+
+    return async () => {
+      if (retryTimeout) {
+        clearTimeout(retryTimeout);
+      }
+
+      await backendProvider?.stop();
+    };
+  });
+}
+
+function createBackendProvider(
+  backend: WorkspacesBackend,
+): Provider<unknown, any> {
+  switch (backend) {
+    case 'glazewm':
+      return createGlazeWmProvider({ type: 'glazewm' });
+    case 'komorebi':
+      return createKomorebiProvider({ type: 'komorebi' });
+    case 'hyprland':
+      return createHyprlandProvider({ type: 'hyprland' });
+    case 'i3':
+      return createI3Provider({ type: 'i3' });
+    case 'native':
+      return createVirtualDesktopProvider({ type: 'virtual_desktop' });
+  }
+}
+
+function transformOutput(
+  backend: WorkspacesBackend,
+  output: any,
+  currentMonitor: Monitor | null,
+): WorkspacesOutput {
+  switch (backend) {
+    case 'glazewm':
+      return fromGlazeWm(output);
+    case 'komorebi':
+      return fromKomorebi(output);
+    case 'hyprland':
+      return fromHyprland(output, currentMonitor);
+    case 'i3':
+      return fromI3(output, currentMonitor);
+    case 'native':
+      return fromVirtualDesktop(output);
+  }
+}
+
+function fromGlazeWm(output: GlazeWmOutput): WorkspacesOutput {
+  const workspaces = output.allWorkspaces.map(
+    (workspace): WmWorkspace => ({
+      id: workspace.name,
+      name: workspace.displayName ?? workspace.name,
+      monitor:
+        output.allMonitors.find(
+          monitor => monitor.id === workspace.parentId,
+        )?.deviceName ?? null,
+      isDisplayed: workspace.isDisplayed,
+      isFocused: workspace.hasFocus,
+    }),
+  );
+
+  return createOutput('glazewm', workspaces, {
+    currentWorkspaceIds: output.currentWorkspaces.map(
+      workspace => workspace.name,
+    ),
+    focusWorkspace: async id => {
+      await output.runCommand(`focus --workspace ${id}`);
+    },
+  });
+}
+
+function fromKomorebi(output: KomorebiOutput): WorkspacesOutput {
+  // Komorebi workspaces have no ID, so one is created from the monitor
+  // and workspace indices.
+  const workspaces = output.allMonitors.flatMap((monitor, monitorIndex) =>
+    monitor.workspaces.map(
+      (workspace, index): WmWorkspace => ({
+        id: `${monitorIndex}:${index}`,
+        name: workspace.name ?? `${index + 1}`,
+        monitor: monitor.name,
+        isDisplayed: index === monitor.focusedWorkspaceIndex,
+        isFocused:
+          monitor.id === output.focusedMonitor.id &&
+          index === monitor.focusedWorkspaceIndex,
+      }),
+    ),
+  );
+
+  const currentMonitorIndex = output.allMonitors.findIndex(
+    monitor => monitor.id === output.currentMonitor.id,
+  );
+
+  return createOutput('komorebi', workspaces, {
+    currentWorkspaceIds: workspaces
+      .filter(workspace =>
+        workspace.id.startsWith(`${currentMonitorIndex}:`),
+      )
+      .map(workspace => workspace.id),
+    focusWorkspace: id => {
+      const [monitorIndex, workspaceIndex] = id.split(':').map(Number);
+      return output.focusWorkspace(workspaceIndex!, monitorIndex);
+    },
+  });
+}
+
+function fromHyprland(
+  output: HyprlandOutput,
+  currentMonitor: Monitor | null,
+): WorkspacesOutput {
+  const workspaces = output.workspaces.map(
+    (workspace): WmWorkspace => ({
+      id: `${workspace.id}`,
+      name: workspace.name,
+      monitor: workspace.monitor,
+      isDisplayed: workspace.isDisplayed,
+      isFocused: workspace.isFocused,
+    }),
+  );
+
+  const currentMonitorName = currentMonitor
+    ? getNearest(output.monitors, currentMonitor)?.name
+    : null;
+
+  return createOutput('hyprland', workspaces, {
+    currentWorkspaceIds: workspaces
+      .filter(workspace => workspace.monitor === currentMonitorName)
+      .map(workspace => workspace.id),
+    focusWorkspace: id => output.dispatch('workspace', id),
+  });
+}
+
+function fromI3(
+  output: I3Output,
+  currentMonitor: Monitor | null,
+): WorkspacesOutput {
+  const workspaces = output.workspaces.map(
+    (workspace): WmWorkspace => ({
+      id: workspace.name,
+      name: workspace.name,
+      monitor: workspace.output,
+      isDisplayed: workspace.isVisible,
+      isFocused: workspace.isFocused,
+    }),
+  );
+
+  const currentOutputName = currentMonitor
+    ? getNearest(
+        output.workspaces.map(workspace => ({
+          name: workspace.output,
+          ...workspace.rect,
+        })),
+        currentMonitor,
+      )?.name
+    : null;
+
+  return createOutput('i3', workspaces, {
+    currentWorkspaceIds: workspaces
+      .filter(workspace => workspace.monitor === currentOutputName)
+      .map(workspace => workspace.id),
+    focusWorkspace: id =>
+      output.command(`workspace "${id.replace(/"/g, '\\"')}"`),
+  });
+}
+
+function fromVirtualDesktop(
+  output: VirtualDesktopOutput,
+): WorkspacesOutput {
+  const workspaces = output.desktops.map(
+    (desktop): WmWorkspace => ({
+      id: desktop.id,
+      name: desktop.name,
+      monitor: null,
+      isDisplayed: desktop.id === output.currentDesktop?.id,
+      isFocused: desktop.id === output.currentDesktop?.id,
+    }),
+  );
+
+  return createOutput('native', workspaces, {
+    currentWorkspaceIds: workspaces.map(workspace => workspace.id),
+    focusWorkspace: async () => {
+      throw new Error(
+        'Switching virtual desktops is not supported by the native backend.',
+      );
+    },
+  });
+}
+
+function createOutput(
+  backend: WorkspacesBackend,
+  workspaces: WmWorkspace[],
+  options: {
+    currentWorkspaceIds: string[];
+    focusWorkspace: (id: string) => Promise<unknown>;
+  },
+): WorkspacesOutput {
+  const currentWorkspaces = workspaces.filter(workspace =>
+    options.currentWorkspaceIds.includes(workspace.id),
+  );
+
+  return {
+    backend,
+    displayedWorkspace:
+      currentWorkspaces.find(workspace => workspace.isDisplayed) ?? null,
+    focusedWorkspace:
+      workspaces.find(workspace => workspace.isFocused) ?? null,
+    currentWorkspaces,
+    allWorkspaces: workspaces,
+    focusWorkspace: async id => {
+      await options.focusWorkspace(id);
+    },
+  };
+}
+
+/**
+ * Gets the item whose position is nearest to the given monitor.
+ */
+function getNearest<T extends { x: number; y: number }>(
+  items: T[],
+  monitor: Monitor,
+): T | null {
+  return items.reduce<T | null>(
+    (nearest, item) =>
+      !nearest ||
+      getCoordinateDistance(monitor, item) <
+        getCoordinateDistance(monitor, nearest)
+        ? item
+        : nearest,
+    null,
+  );
+}
@@ -0,0 +1,90 @@
+import type { Provider } from '../create-base-provider';
+
+export interface WorkspacesProviderConfig {
+  type: 'workspaces';
+
+  /**
+   * Window manager to get workspaces from. Defaults to `auto`, which
+   * uses the first window manager that is detected as running.
+   */
+  backend?: WorkspacesBackend | 'auto';
+}
+
+export type WorkspacesProvider = Provider<
+  WorkspacesProviderConfig,
+  WorkspacesOutput
+>;
+
+/**
+ * Window manager that workspaces are read from. `native` refers to the
+ * OS's built-in virtual desktops (currently only Windows).
+ */
+export type WorkspacesBackend =
+  | 'glazewm'
+  | 'komorebi'
+  | 'hyprland'
+  | 'i3'
+  | 'native';
+
+export interface WorkspacesOutput {
+  /**
+   * Window manager that the workspaces are read from.
+   */
+  backend: WorkspacesBackend;
+
+  /**
+   * Workspace displayed on the current monitor.
+   */
+  displayedWorkspace: WmWorkspace | null;
+
+  /**
+   * Workspace that currently has focus (on any monitor).
+   */
+  focusedWorkspace: WmWorkspace | null;
+
+  /**
+   * Workspaces on the current monitor.
+   */
+  currentWorkspaces: WmWorkspace[];
+
+  /**
+   * Workspaces across all monitors.
+   */
+  allWorkspaces: WmWorkspace[];
+
+  /**
+   * Focuses the workspace with the given ID.
+   *
+   * @throws If the backend doesn't support switching workspaces (i.e.
+   * `native`).
+   */
+  focusWorkspace(id: string): Promise<void>;
+}
+
+export interface WmWorkspace {
+  /**
+   * ID of the workspace. Only unique within the backend.
+   */
+  id: string;
+
+  /**
+   * Name of the workspace to display.
+   */
+  name: string;
+
+  /**
+   * Name of the monitor the workspace is on. `null` if the workspace
+   * spans all monitors (i.e. native virtual desktops).
+   */
+  monitor: string | null;
+
+  /**
+   * Whether the workspace is shown on its monitor.
+   */
+  isDisplayed: boolean;
+
+  /**
+   * Whether the workspace currently has focus.
+   */
+  isFocused: boolean;
+}
@@ -1,4 +1,6 @@
 interface Window {
   // TODO: Add typing.
   __ZEBAR_STATE: any;
+  __ZEBAR_ACCESSIBILITY?: import('./desktop/accessibility').AccessibilityPrefs;
+  __ZEBAR_API_VERSION?: number;
 }
@@ -0,0 +1,54 @@
+import { z } from 'zod';
+
+/**
+ * A duration in milliseconds, or a string with a unit (e.g. `'500ms'`,
+ * `'5s'`, `'2m'`, or `'1h'`).
+ */
+export type Duration = number | `${number}${'ms' | 's' | 'm' | 'h'}`;
+
+const DURATION_PATTERN = /^\s*(\d+(?:\.\d+)?)\s*(ms|s|m|h)?\s*$/;
+
+const UNIT_MULTIPLIERS: Record<string, number> = {
+  ms: 1,
+  s: 1000,
+  m: 60 * 1000,
+  h: 60 * 60 * 1000,
+};
+
+/**
+ * Parses a {@link Duration} into milliseconds. Returns `null` if the
+ * string isn't a valid duration.
+ */
+export function parseDuration(duration: Duration | string): number | null {
+  if (typeof duration === 'number') {
+    return duration;
+  }
+
+  const match = DURATION_PATTERN.exec(duration);
+
+  if (!match) {
+    return null;
+  }
+
+  return Math.round(Number(match[1]) * UNIT_MULTIPLIERS[match[2] ?? 'ms']!);
+}
+
+/**
+ * Zod schema that parses a {@link Duration} into milliseconds.
+ */
+export const durationSchema = z
+  .union([z.number().nonnegative(), z.string()])
+  .transform((duration, ctx) => {
+    const ms = parseDuration(duration);
+
+    if (ms === null) {
+      ctx.addIssue({
+        code: z.ZodIssueCode.custom,
+        message: `Not a valid duration '${duration}'. Must be a number of milliseconds or of format '500ms', '5s', '2m' or '1h'.`,
+      });
+
+      return z.NEVER;
+    }
+
+    return ms;
+  });
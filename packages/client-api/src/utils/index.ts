@@ -1,5 +1,6 @@
 export * from './create-logger';
 export * from './data-size-measure';
 export * from './deferred';
+export * from './duration';
 export * from './get-coordinate-distance';
 export * from './simple-hash';
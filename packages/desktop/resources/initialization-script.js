@@ -25,6 +25,27 @@ if (window.location.host === '127.0.0.1:6124') {
   });
 }
 
+// Send heartbeats so that the backend can detect a hung webview. Pages
+// without IPC access are never tracked. The JS heap size is included for
+// `zebar stats`, but is only available in Chromium-based webviews.
+if (window.__TAURI_INTERNALS__ && window.__ZEBAR_HEARTBEAT_INTERVAL) {
+  setInterval(
+    () =>
+      window.__TAURI_INTERNALS__
+        .invoke('widget_heartbeat', {
+          memoryUsage: performance.memory?.usedJSHeapSize ?? null,
+        })
+        .catch(() => {}),
+    window.__ZEBAR_HEARTBEAT_INTERVAL,
+  );
+}
+
+// Translate press-and-hold with touch or a pen into a right-click, so
+// that context menus of tray-style buttons work on touchscreens.
+if (window.__ZEBAR_STATE?.config.touch?.holdToRightClick) {
+  listenHoldToRightClick(window.__ZEBAR_STATE.config.touch.holdDuration);
+}
+
 /**
  * Adds a CSS file with the given path to the head element.
  */
@@ -65,3 +86,83 @@ function insertIntoHead(element) {
     document.head.appendChild(element);
   }
 }
+
+/**
+ * Dispatches a `contextmenu` event when a touch or pen press is held for
+ * the given duration (in milliseconds) without moving.
+ */
+function listenHoldToRightClick(holdDuration) {
+  // Max distance in pixels a press can move while still being a hold.
+  const moveTolerance = 10;
+
+  let pendingHold = null;
+  let suppressUntil = 0;
+
+  const cancelHold = () => {
+    clearTimeout(pendingHold?.timeout);
+    pendingHold = null;
+  };
+
+  document.addEventListener('pointerdown', event => {
+    if (event.pointerType === 'mouse' || !event.isPrimary) {
+      return;
+    }
+
+    cancelHold();
+
+    const { target, clientX, clientY, screenX, screenY } = event;
+
+    pendingHold = {
+      clientX,
+      clientY,
+      timeout: setTimeout(() => {
+        pendingHold = null;
+        suppressUntil = Date.now() + 1000;
+
+        target.dispatchEvent(
+          new MouseEvent('contextmenu', {
+            bubbles: true,
+            cancelable: true,
+            button: 2,
+            buttons: 2,
+            clientX,
+            clientY,
+            screenX,
+            screenY,
+          }),
+        );
+      }, holdDuration),
+    };
+  });
+
+  document.addEventListener('pointermove', event => {
+    if (
+      pendingHold &&
+      Math.hypot(
+        event.clientX - pendingHold.clientX,
+        event.clientY - pendingHold.clientY,
+      ) > moveTolerance
+    ) {
+      cancelHold();
+    }
+  });
+
+  document.addEventListener('pointerup', cancelHold);
+  document.addEventListener('pointercancel', cancelHold);
+
+  // Swallow the click that ends the hold, as well as the webview's own
+  // long-press context menu.
+  const suppress = event => {
+    if (event.isTrusted && Date.now() < suppressUntil) {
+      event.preventDefault();
+      event.stopImmediatePropagation();
+
+      if (event.type === 'click') {
+        suppressUntil = 0;
+      }
+    }
+  };
+
+  window.addEventListener('click', suppress, true);
+  window.addEventListener('contextmenu', suppress, true);
+}
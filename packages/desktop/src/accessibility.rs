@@ -0,0 +1,164 @@
+use std::{sync::Arc, time::Duration};
+
+use serde::Serialize;
+use tauri::{AppHandle, Emitter};
+use tokio::{sync::RwLock, task};
+use tracing::info;
+
+/// Name of the Tauri event emitted to all widgets when the preferences
+/// change.
+const ACCESSIBILITY_CHANGED_EVENT: &str = "accessibility-changed";
+
+/// Accessibility preferences from the OS settings.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccessibilityPrefs {
+  /// Whether the user prefers reduced motion (i.e. animations are
+  /// disabled in the OS settings).
+  pub reduced_motion: bool,
+
+  /// Whether a high-contrast theme is enabled.
+  pub high_contrast: bool,
+}
+
+pub struct AccessibilityState {
+  prefs: Arc<RwLock<AccessibilityPrefs>>,
+}
+
+impl AccessibilityState {
+  /// Creates a new `AccessibilityState` instance.
+  pub fn new(app_handle: &AppHandle) -> Self {
+    let prefs = Arc::new(RwLock::new(Self::system_prefs()));
+    Self::listen_changes(app_handle.clone(), prefs.clone());
+
+    Self { prefs }
+  }
+
+  /// Gets the current accessibility preferences.
+  pub async fn prefs(&self) -> AccessibilityPrefs {
+    *self.prefs.read().await
+  }
+
+  /// Polls the OS settings for changes, and emits the new preferences
+  /// to all widgets when they change.
+  fn listen_changes(
+    app_handle: AppHandle,
+    prefs: Arc<RwLock<AccessibilityPrefs>>,
+  ) {
+    task::spawn(async move {
+      let mut interval = tokio::time::interval(Duration::from_secs(4));
+
+      interval
+        .set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
+
+      loop {
+        interval.tick().await;
+
+        let Ok(new_prefs) = task::spawn_blocking(Self::system_prefs).await
+        else {
+          continue;
+        };
+
+        if *prefs.read().await != new_prefs {
+          info!("Detected change in accessibility preferences.");
+          *prefs.write().await = new_prefs;
+          let _ = app_handle.emit(ACCESSIBILITY_CHANGED_EVENT, new_prefs);
+        }
+      }
+    });
+  }
+
+  #[cfg(target_os = "windows")]
+  fn system_prefs() -> AccessibilityPrefs {
+    use windows::Win32::{
+      Foundation::BOOL,
+      UI::{
+        Accessibility::{HCF_HIGHCONTRASTON, HIGHCONTRASTW},
+        WindowsAndMessaging::{
+          SystemParametersInfoW, SPI_GETCLIENTAREAANIMATION,
+          SPI_GETHIGHCONTRAST, SYSTEM_PARAMETERS_INFO_UPDATE_FLAGS,
+        },
+      },
+    };
+
+    let mut animations_enabled = BOOL(1);
+
+    let _ = unsafe {
+      SystemParametersInfoW(
+        SPI_GETCLIENTAREAANIMATION,
+        0,
+        Some(&mut animations_enabled as *mut _ as _),
+        SYSTEM_PARAMETERS_INFO_UPDATE_FLAGS(0),
+      )
+    };
+
+    let mut high_contrast = HIGHCONTRASTW {
+      cbSize: std::mem::size_of::<HIGHCONTRASTW>() as u32,
+      ..Default::default()
+    };
+
+    let _ = unsafe {
+      SystemParametersInfoW(
+        SPI_GETHIGHCONTRAST,
+        high_contrast.cbSize,
+        Some(&mut high_contrast as *mut _ as _),
+        SYSTEM_PARAMETERS_INFO_UPDATE_FLAGS(0),
+      )
+    };
+
+    AccessibilityPrefs {
+      reduced_motion: !animations_enabled.as_bool(),
+      high_contrast: high_contrast.dwFlags.contains(HCF_HIGHCONTRASTON),
+    }
+  }
+
+  #[cfg(target_os = "macos")]
+  fn system_prefs() -> AccessibilityPrefs {
+    use cocoa::base::{id, BOOL, YES};
+    use objc::{class, msg_send, sel, sel_impl};
+
+    unsafe {
+      let workspace: id = msg_send![class!(NSWorkspace), sharedWorkspace];
+
+      let reduced_motion: BOOL =
+        msg_send![workspace, accessibilityDisplayShouldReduceMotion];
+
+      let high_contrast: BOOL =
+        msg_send![workspace, accessibilityDisplayShouldIncreaseContrast];
+
+      AccessibilityPrefs {
+        reduced_motion: reduced_motion == YES,
+        high_contrast: high_contrast == YES,
+      }
+    }
+  }
+
+  /// Reads the GNOME settings, which most other desktops also set via
+  /// their settings daemon.
+  #[cfg(target_os = "linux")]
+  fn system_prefs() -> AccessibilityPrefs {
+    let gsetting = |schema: &str, key: &str| {
+      std::process::Command::new("gsettings")
+        .args(["get", schema, key])
+        .output()
+        .ok()
+        .filter(|output| output.status.success())
+        .map(|output| {
+          String::from_utf8_lossy(&output.stdout).trim() == "true"
+        })
+    };
+
+    AccessibilityPrefs {
+      reduced_motion: gsetting(
+        "org.gnome.desktop.interface",
+        "enable-animations",
+      )
+      .is_some_and(|enabled| !enabled),
+      high_contrast: gsetting(
+        "org.gnome.desktop.a11y.interface",
+        "high-contrast",
+      )
+      .unwrap_or(false),
+    }
+  }
+}
@@ -0,0 +1,349 @@
+use std::{collections::HashMap, sync::Arc, time::Duration};
+
+use anyhow::{bail, Context};
+use serde::Serialize;
+use tauri::{AppHandle, Emitter};
+use tauri_plugin_notification::NotificationExt;
+use tokio::{
+  sync::Mutex,
+  task,
+  time::{self, Instant},
+};
+use tracing::{error, info, warn};
+
+use crate::{
+  common::{is_truthy, Expression},
+  config::{AlertAction, AlertRule, Config},
+  providers::{parse_provider_configs, ProviderEmission, ProviderManager},
+  secrets::SecretAccess,
+  shell_command::run_shell_command,
+  window_title::render_template,
+};
+
+/// Payload of the `alert` event emitted to widgets.
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AlertEvent {
+  /// Name of the rule that fired.
+  pub name: String,
+
+  /// Latest outputs of the rule's providers, keyed by name.
+  pub outputs: HashMap<String, serde_json::Value>,
+}
+
+/// Evaluates the alert rules from the user's settings and runs their
+/// actions when they fire.
+///
+/// Providers used by a rule are created under their own config hashes,
+/// so that they're independent of any open widgets.
+pub struct Alerts {
+  app_handle: AppHandle,
+
+  /// Reference to `Config`.
+  config: Arc<Config>,
+
+  /// Reference to `ProviderManager`.
+  manager: Arc<ProviderManager>,
+
+  /// Rule configs that are currently active.
+  rule_configs: Mutex<Vec<AlertRule>>,
+
+  /// Active rules by name.
+  rules: Mutex<HashMap<String, ActiveRule>>,
+}
+
+struct ActiveRule {
+  config: AlertRule,
+  condition: Expression,
+
+  /// Names of the rule's providers, keyed by config hash.
+  provider_names: HashMap<String, String>,
+
+  /// Latest output of each provider, keyed by name.
+  outputs: HashMap<String, serde_json::Value>,
+
+  /// When the condition started holding. `None` if it doesn't hold.
+  since: Option<Instant>,
+
+  /// Whether the rule has fired since the condition started holding.
+  has_fired: bool,
+
+  /// Latest evaluation error, to avoid logging it repeatedly.
+  error: Option<String>,
+}
+
+impl Alerts {
+  pub fn new(
+    app_handle: &AppHandle,
+    config: Arc<Config>,
+    manager: Arc<ProviderManager>,
+  ) -> Arc<Self> {
+    Arc::new(Self {
+      app_handle: app_handle.clone(),
+      config,
+      manager,
+      rule_configs: Mutex::new(Vec::new()),
+      rules: Mutex::new(HashMap::new()),
+    })
+  }
+
+  /// Restarts the rules if they changed in the settings.
+  pub async fn reload(&self) -> anyhow::Result<()> {
+    let new_configs = self.config.settings.lock().await.alerts.clone();
+    let mut rule_configs = self.rule_configs.lock().await;
+
+    if *rule_configs == new_configs {
+      return Ok(());
+    }
+
+    self.stop_all().await;
+    *rule_configs = new_configs.clone();
+
+    // An invalid rule shouldn't prevent the others from starting.
+    for rule_config in new_configs {
+      let name = rule_config.name.clone();
+
+      if let Err(err) = self.start(rule_config).await {
+        error!("Failed to start alert '{}': {:?}", name, err);
+      }
+    }
+
+    Ok(())
+  }
+
+  async fn start(&self, rule_config: AlertRule) -> anyhow::Result<()> {
+    if self.rules.lock().await.contains_key(&rule_config.name) {
+      bail!("An alert with the same name already exists.");
+    }
+
+    let condition = Expression::parse(&rule_config.condition)
+      .context("Invalid alert condition.")?;
+
+    let provider_configs = parse_provider_configs(
+      &self.app_handle,
+      rule_config.providers.clone(),
+      &self.config.settings_path(),
+      SecretAccess::Any,
+    )
+    .await
+    .context("Invalid alert providers.")?;
+
+    let provider_configs = provider_configs
+      .into_iter()
+      .map(|(name, config)| {
+        let config_hash = format!("alert:{}:{}", rule_config.name, name);
+        (config_hash, name, config)
+      })
+      .collect::<Vec<_>>();
+
+    self.rules.lock().await.insert(
+      rule_config.name.clone(),
+      ActiveRule {
+        provider_names: provider_configs
+          .iter()
+          .map(|(config_hash, name, _)| {
+            (config_hash.clone(), name.clone())
+          })
+          .collect(),
+        config: rule_config.clone(),
+        condition,
+        outputs: HashMap::new(),
+        since: None,
+        has_fired: false,
+        error: None,
+      },
+    );
+
+    let subscriber = format!("alert:{}", rule_config.name);
+
+    for (config_hash, _, config) in provider_configs {
+      self
+        .manager
+        .create(config_hash, config, &subscriber)
+        .await?;
+    }
+
+    info!("Started alert '{}'.", rule_config.name);
+    Ok(())
+  }
+
+  /// Stops the providers of all rules.
+  async fn stop_all(&self) {
+    let rules = std::mem::take(&mut *self.rules.lock().await);
+
+    let config_hashes = rules.into_values().flat_map(|rule| {
+      let subscriber = format!("alert:{}", rule.config.name);
+
+      rule
+        .provider_names
+        .into_keys()
+        .map(move |config_hash| (config_hash, subscriber.clone()))
+    });
+
+    for (config_hash, subscriber) in config_hashes {
+      if let Err(err) = self.manager.stop(config_hash, &subscriber).await {
+        warn!("Failed to stop alert provider: {:?}", err);
+      }
+    }
+  }
+
+  /// Re-evaluates the rule bound to the emitting provider (if any).
+  pub async fn handle_emission(
+    self: &Arc<Self>,
+    emission: &ProviderEmission,
+  ) {
+    let Ok(output) = &emission.result else {
+      return;
+    };
+
+    let rule_name = {
+      let mut rules = self.rules.lock().await;
+
+      let Some((rule_name, rule)) = rules.iter_mut().find(|(_, rule)| {
+        rule.provider_names.contains_key(&emission.config_hash)
+      }) else {
+        return;
+      };
+
+      let name = rule.provider_names[&emission.config_hash].clone();
+      let output = serde_json::to_value(output).unwrap_or_default();
+      rule.outputs.insert(name, output);
+
+      rule_name.clone()
+    };
+
+    self.evaluate(&rule_name).await;
+  }
+
+  /// Evaluates a rule's condition, and fires the rule if the condition
+  /// has held for long enough.
+  async fn evaluate(self: &Arc<Self>, rule_name: &str) {
+    let mut rules = self.rules.lock().await;
+
+    let Some(rule) = rules.get_mut(rule_name) else {
+      return;
+    };
+
+    let is_met = match rule.condition.evaluate(&rule.outputs) {
+      Ok(value) => {
+        rule.error = None;
+        is_truthy(&value)
+      }
+      Err(err) => {
+        let err = format!("{:#}", err);
+
+        if rule.error.as_ref() != Some(&err) {
+          warn!("Failed to evaluate alert '{}': {}", rule_name, err);
+          rule.error = Some(err);
+        }
+
+        false
+      }
+    };
+
+    if !is_met {
+      rule.since = None;
+      rule.has_fired = false;
+      return;
+    }
+
+    let is_new = rule.since.is_none();
+    let since = *rule.since.get_or_insert_with(Instant::now);
+    let duration = Duration::from_millis(rule.config.duration);
+    drop(rules);
+
+    // Providers might not emit again while the condition holds, so check
+    // again once the duration has passed.
+    if is_new && !duration.is_zero() {
+      let alerts = self.clone();
+      let rule_name = rule_name.to_string();
+
+      task::spawn(async move {
+        time::sleep_until(since + duration).await;
+        alerts.fire_if_due(&rule_name, since).await;
+      });
+    }
+
+    self.fire_if_due(rule_name, since).await;
+  }
+
+  /// Fires the rule if its condition has held since the given instant
+  /// for at least its duration, and it hasn't fired yet.
+  async fn fire_if_due(&self, rule_name: &str, since: Instant) {
+    let mut rules = self.rules.lock().await;
+
+    let Some(rule) = rules.get_mut(rule_name) else {
+      return;
+    };
+
+    let duration = Duration::from_millis(rule.config.duration);
+
+    let is_due = rule.since == Some(since) && since.elapsed() >= duration;
+
+    if !is_due || rule.has_fired {
+      return;
+    }
+
+    rule.has_fired = true;
+    info!("Alert '{}' fired.", rule_name);
+
+    for action in &rule.config.actions {
+      if let Err(err) = self.run_action(action, rule_name, &rule.outputs) {
+        error!(
+          "Failed to run action for alert '{}': {:?}",
+          rule_name, err
+        );
+      }
+    }
+  }
+
+  fn run_action(
+    &self,
+    action: &AlertAction,
+    rule_name: &str,
+    outputs: &HashMap<String, serde_json::Value>,
+  ) -> anyhow::Result<()> {
+    match action {
+      AlertAction::Notification { title, body } => {
+        let title = title
+          .as_ref()
+          .map(|title| render_template(title, outputs))
+          .unwrap_or_else(|| rule_name.to_string());
+
+        let mut notification =
+          self.app_handle.notification().builder().title(title);
+
+        if let Some(body) = body {
+          notification = notification.body(render_template(body, outputs));
+        }
+
+        notification.show()?;
+      }
+      AlertAction::RunCommand { program, args } => {
+        let program = program.clone();
+        let args = args.clone();
+
+        task::spawn(async move {
+          match run_shell_command(&program, &args, None, None).await {
+            Ok(output) if output.exit_code != Some(0) => {
+              warn!("Alert command exited with {:?}.", output.exit_code);
+            }
+            Err(err) => error!("Failed to run alert command: {:?}", err),
+            _ => {}
+          }
+        });
+      }
+      AlertAction::EmitEvent => {
+        self.app_handle.emit(
+          "alert",
+          AlertEvent {
+            name: rule_name.to_string(),
+            outputs: outputs.clone(),
+          },
+        )?;
+      }
+    }
+
+    Ok(())
+  }
+}
@@ -1,81 +1,272 @@
 use std::{
   collections::HashMap,
-  io::Cursor,
+  fs::{self, File},
+  io::{Cursor, Write},
   path::{Path, PathBuf},
-  sync::LazyLock,
+  sync::{Arc, LazyLock, OnceLock},
 };
 
+use anyhow::Context;
 use rocket::{
+  fairing::AdHoc,
   fs::NamedFile,
   http::{ContentType, Cookie, CookieJar, Header, SameSite, Status},
   request::{FromRequest, Outcome},
-  response::{self, Redirect, Responder, Response},
-  Request,
+  response::{self, stream::TextStream, Redirect, Responder, Response},
+  serde::json::Json,
+  Request, State,
+};
+use serde::Serialize;
+use tauri::{AppHandle, Manager};
+use tokio::{
+  sync::{oneshot, Mutex},
+  task,
 };
-use tokio::{sync::Mutex, task};
 use uuid::Uuid;
 
-use crate::common::PathExt;
+use crate::{
+  common::PathExt,
+  portable,
+  usage_stats::{UsageReport, UsageStats},
+  widget_factory::{WidgetFactory, WidgetState},
+};
+
+/// Default port for the localhost asset server.
+pub const DEFAULT_ASSET_SERVER_PORT: u16 = 6124;
 
-/// Port for the localhost asset server.
-const ASSET_SERVER_PORT: u16 = 6124;
+/// Port that the asset server is running on.
+static ASSET_SERVER_PORT: OnceLock<u16> = OnceLock::new();
 
-/// Map of tokens to their corresponding path.
-static ASSET_SERVER_TOKENS: LazyLock<Mutex<HashMap<String, PathBuf>>> =
+/// Token that requests from the CLI have to present. Kept separate from
+/// widget tokens, since it grants access to the state of all widgets.
+static CLI_TOKEN: OnceLock<String> = OnceLock::new();
+
+/// Map of tokens to their corresponding directory.
+static ASSET_SERVER_TOKENS: LazyLock<Mutex<HashMap<String, AssetScope>>> =
   LazyLock::new(|| Mutex::new(HashMap::new()));
 
-pub fn setup_asset_server() {
-  task::spawn(async move {
-    let rocket = rocket::build()
-      .configure(
-        rocket::Config::figment().merge(("port", ASSET_SERVER_PORT)),
-      )
-      .mount("/", routes![sw_js, normalize_css, init, serve]);
+/// Map of tokens to individual files resolved via `resolve_asset`.
+static ASSET_FILE_TOKENS: LazyLock<Mutex<HashMap<String, PathBuf>>> =
+  LazyLock::new(|| Mutex::new(HashMap::new()));
+
+/// Directory that a token grants access to, along with the
+/// Content-Security-Policy to serve its files with.
+#[derive(Clone, Debug, PartialEq)]
+struct AssetScope {
+  directory: PathBuf,
+  csp: Option<String>,
+}
+
+/// Starts the asset server.
+///
+/// Uses a random free port if `use_free_port` is true (e.g. for
+/// additional instances of Zebar), otherwise the default port. Resolves
+/// once the server is listening.
+pub async fn setup_asset_server(
+  app_handle: &AppHandle,
+  use_free_port: bool,
+  widget_factory: Arc<WidgetFactory>,
+  usage_stats: Arc<UsageStats>,
+) -> anyhow::Result<()> {
+  // Rocket binds the port itself, so that a free port can't be taken by
+  // another process before the server starts. Port 0 lets the OS pick
+  // one, and the bound port is then read back on liftoff.
+  let port = match use_free_port {
+    true => 0,
+    false => DEFAULT_ASSET_SERVER_PORT,
+  };
+
+  let (port_tx, port_rx) = oneshot::channel();
+
+  let rocket = rocket::build()
+    .configure(rocket::Config::figment().merge(("port", port)))
+    .manage(widget_factory)
+    .manage(usage_stats)
+    .attach(AdHoc::on_liftoff("Asset server port", |rocket| {
+      Box::pin(async move {
+        let _ = port_tx.send(rocket.config().port);
+      })
+    }))
+    .mount(
+      "/",
+      routes![sw_js, normalize_css, init, events, stats, asset, serve],
+    );
 
+  task::spawn(async move {
     if let Err(err) = rocket.launch().await {
       error!("Asset server failed to start: {:?}", err);
     }
   });
+
+  let port = port_rx.await.context("Asset server failed to start.")?;
+
+  ASSET_SERVER_PORT.get_or_init(|| port);
+  write_cli_token(app_handle, port)?;
+
+  if port != DEFAULT_ASSET_SERVER_PORT {
+    add_widget_capability(app_handle, port)?;
+  }
+
+  Ok(())
+}
+
+/// Returns the path of the file that holds the CLI token of the
+/// instance running on the given port.
+pub fn cli_token_path(
+  app_handle: &AppHandle,
+  port: u16,
+) -> anyhow::Result<PathBuf> {
+  portable::data_dir()
+    .or_else(|| app_handle.path().app_local_data_dir().ok())
+    .map(|dir| dir.join(format!("cli-token-{}", port)))
+    .context("Unable to resolve data directory.")
+}
+
+/// Generates the CLI token and writes it to the user's data directory,
+/// where only the CLI of the same user can read it.
+fn write_cli_token(
+  app_handle: &AppHandle,
+  port: u16,
+) -> anyhow::Result<()> {
+  let token = Uuid::new_v4().to_string();
+  let path = cli_token_path(app_handle, port)?;
+
+  if let Some(parent) = path.parent() {
+    fs::create_dir_all(parent)?;
+  }
+
+  let mut options = File::options();
+  options.create(true).write(true).truncate(true);
+
+  #[cfg(unix)]
+  {
+    use std::os::unix::fs::OpenOptionsExt;
+    options.mode(0o600);
+  }
+
+  options.open(&path)?.write_all(token.as_bytes())?;
+  let _ = CLI_TOKEN.set(token);
+
+  Ok(())
+}
+
+/// Grants widgets served from a non-default port the same access as
+/// the bundled widget capability, which only allows the default port's
+/// origin.
+fn add_widget_capability(
+  app_handle: &AppHandle,
+  port: u16,
+) -> anyhow::Result<()> {
+  let mut capability: serde_json::Value =
+    serde_json::from_str(include_str!("../capabilities/widget.json"))?;
+
+  if let Some(capability) = capability.as_object_mut() {
+    capability.remove("$schema");
+  }
+
+  capability["identifier"] = format!("widget-{}", port).into();
+  capability["remote"]["urls"] =
+    serde_json::json!([format!("http://127.0.0.1:{}", port)]);
+
+  app_handle.add_capability(capability.to_string())?;
+
+  Ok(())
 }
 
+/// Returns the URL that a widget window is initially opened with.
+///
+/// The query string (if any) is preserved when redirecting to the HTML
+/// file. Widgets in the same directory share a token, unless their
+/// Content-Security-Policy differs.
 pub async fn create_init_url(
   parent_dir: &Path,
   html_path: &Path,
+  query: Option<String>,
+  csp: Option<String>,
 ) -> anyhow::Result<tauri::Url> {
   // Generate a unique token to identify requests from the widget to the
   // asset server.
-  let token = upsert_or_get_token(parent_dir).await;
+  let scope = AssetScope {
+    directory: parent_dir.to_path_buf(),
+    csp,
+  };
 
-  let redirect = format!(
-    "/{}",
-    html_path.strip_prefix(parent_dir)?.to_unicode_string()
-  );
+  let token = upsert_or_get_token(&ASSET_SERVER_TOKENS, scope).await;
+
+  // Each path segment is percent-encoded, so that spaces, `#`, and
+  // non-ASCII characters (e.g. in the user's name) survive the redirect.
+  let mut redirect_url = tauri::Url::parse("http://127.0.0.1")?;
+
+  redirect_url
+    .path_segments_mut()
+    .map_err(|_| anyhow::anyhow!("Invalid base URL for redirect."))?
+    .pop_if_empty()
+    .extend(
+      html_path
+        .strip_prefix(parent_dir)?
+        .components()
+        .map(|component| component.as_os_str().to_string_lossy()),
+    );
+
+  redirect_url.set_query(query.as_deref());
+
+  let redirect = match redirect_url.query() {
+    Some(query) => format!("{}?{}", redirect_url.path(), query),
+    None => redirect_url.path().to_string(),
+  };
 
   let url = tauri::Url::parse_with_params(
-    &format!("http://127.0.0.1:{}/__zebar/init", ASSET_SERVER_PORT),
+    &format!("http://127.0.0.1:{}/__zebar/init", asset_server_port()),
     &[("token", &token), ("redirect", &redirect)],
   )?;
 
   Ok(url)
 }
 
-/// Returns an asset server token for a given directory.
+/// Returns a URL for serving a single file from the asset server.
+///
+/// Used for files outside of a widget's own directory, which its
+/// webview otherwise can't access.
+pub async fn create_asset_url(path: &Path) -> anyhow::Result<tauri::Url> {
+  let token =
+    upsert_or_get_token(&ASSET_FILE_TOKENS, path.to_path_buf()).await;
+
+  let url = tauri::Url::parse(&format!(
+    "http://127.0.0.1:{}/__zebar/asset/{}",
+    asset_server_port(),
+    token
+  ))?;
+
+  Ok(url)
+}
+
+/// Returns the port that the asset server is running on.
+fn asset_server_port() -> u16 {
+  *ASSET_SERVER_PORT
+    .get()
+    .unwrap_or(&DEFAULT_ASSET_SERVER_PORT)
+}
+
+/// Returns an asset server token for a given target.
 ///
-/// If the directory does not have an existing token, a new one is
+/// If the target does not have an existing token, a new one is
 /// generated and inserted.
-async fn upsert_or_get_token(directory: &Path) -> String {
-  let mut asset_server_tokens = ASSET_SERVER_TOKENS.lock().await;
+async fn upsert_or_get_token<T: PartialEq>(
+  tokens: &Mutex<HashMap<String, T>>,
+  target: T,
+) -> String {
+  let mut tokens = tokens.lock().await;
 
-  // Find existing token for this path.
-  let found_token = asset_server_tokens
+  // Find existing token for this target.
+  let found_token = tokens
     .iter()
-    .find(|(_, path)| *path == directory)
+    .find(|(_, existing)| **existing == target)
     .map(|(token, _)| token.clone());
 
   found_token.unwrap_or_else(|| {
     let new_token = Uuid::new_v4().to_string();
 
-    asset_server_tokens.insert(new_token.clone(), directory.to_path_buf());
+    tokens.insert(new_token.clone(), target);
 
     new_token
   })
@@ -122,28 +313,107 @@ pub fn normalize_css() -> (ContentType, &'static str) {
   (ContentType::CSS, include_str!("../resources/normalize.css"))
 }
 
+/// Widget lifecycle event streamed via `/__zebar/events`.
+#[derive(Serialize)]
+#[serde(tag = "type", content = "widget", rename_all = "snake_case")]
+enum WidgetLifecycleEvent {
+  Opened(WidgetState),
+  Closed(WidgetState),
+}
+
+/// Streams widget open and close events as newline-delimited JSON. Used
+/// by `zebar watch`.
+#[get("/__zebar/events")]
+pub fn events(
+  _auth: CliAuth,
+  widget_factory: &State<Arc<WidgetFactory>>,
+) -> TextStream![String] {
+  let mut open_rx = widget_factory.open_tx.subscribe();
+  let mut close_rx = widget_factory.close_tx.subscribe();
+
+  TextStream! {
+    loop {
+      let event = tokio::select! {
+        Ok(state) = open_rx.recv() => WidgetLifecycleEvent::Opened(state),
+        Ok(state) = close_rx.recv() => WidgetLifecycleEvent::Closed(state),
+        else => break,
+      };
+
+      if let Ok(json) = serde_json::to_string(&event) {
+        yield json + "\n";
+      }
+    }
+  }
+}
+
+/// Returns a snapshot of the usage stats. Used by `zebar stats`.
+#[get("/__zebar/stats")]
+pub async fn stats(
+  _auth: CliAuth,
+  usage_stats: &State<Arc<UsageStats>>,
+) -> Json<UsageReport> {
+  Json(usage_stats.report().await)
+}
+
+/// Serves a single file resolved via `resolve_asset`. Unlike widget
+/// assets, these don't require the widget's cookie, since the token is
+/// only ever handed out for that specific file.
+#[get("/__zebar/asset/<token>")]
+pub async fn asset(token: String) -> Option<NamedFile> {
+  let path = { ASSET_FILE_TOKENS.lock().await.get(&token).cloned() }?;
+  NamedFile::open(path).await.ok()
+}
+
 #[rocket::get("/<path..>", rank = 100)]
 pub async fn serve(
   path: Option<PathBuf>,
   token: ServerToken,
-) -> Option<NamedFile> {
+) -> Option<AssetResponse> {
   // Retrieve base directory for the corresponding token.
-  let base_url =
-    { ASSET_SERVER_TOKENS.lock().await.get(&token.0).cloned() }?;
+  let scope = { ASSET_SERVER_TOKENS.lock().await.get(&token.0).cloned() }?;
 
-  let asset_path = base_url
+  let asset_path = scope
+    .directory
     .join(path.unwrap_or("index.html".into()))
     .to_absolute()
     .ok()?;
 
   // Prevent directory traversal outside of the base URL.
-  if !asset_path.starts_with(&base_url) {
+  if !asset_path.starts_with(&scope.directory) {
     return None;
   }
 
   // Attempt to open and serve the requested file. Currently returns HTML
   // `Content-Type` if not found.
-  NamedFile::open(asset_path).await.ok()
+  let file = NamedFile::open(asset_path).await.ok()?;
+
+  Some(AssetResponse {
+    file,
+    csp: scope.csp,
+  })
+}
+
+/// Widget asset with the widget's Content-Security-Policy applied.
+#[derive(Debug)]
+pub struct AssetResponse {
+  file: NamedFile,
+  csp: Option<String>,
+}
+
+#[rocket::async_trait]
+impl<'r> Responder<'r, 'static> for AssetResponse {
+  fn respond_to(
+    self,
+    request: &'r Request<'_>,
+  ) -> response::Result<'static> {
+    let mut response = self.file.respond_to(request)?;
+
+    if let Some(csp) = self.csp {
+      response.set_header(Header::new("Content-Security-Policy", csp));
+    }
+
+    Ok(response)
+  }
 }
 
 /// Token for identifying which directory is being accessed.
@@ -170,3 +440,45 @@ impl<'r> FromRequest<'r> for ServerToken {
     }
   }
 }
+
+/// Guard for endpoints that are used by the CLI.
+///
+/// Requires the instance's CLI token as a bearer token. Requests with a
+/// `Host` other than the server's own address are rejected, so that
+/// websites can't reach the endpoints via DNS rebinding.
+#[derive(Debug)]
+pub struct CliAuth;
+
+#[rocket::async_trait]
+impl<'r> FromRequest<'r> for CliAuth {
+  type Error = anyhow::Error;
+
+  async fn from_request(
+    request: &'r Request<'_>,
+  ) -> Outcome<Self, Self::Error> {
+    let expected_host = format!("127.0.0.1:{}", asset_server_port());
+
+    if request.headers().get_one("Host") != Some(expected_host.as_str()) {
+      return Outcome::Error((
+        Status::Forbidden,
+        anyhow::anyhow!("Invalid host for CLI request."),
+      ));
+    }
+
+    let expected_auth =
+      CLI_TOKEN.get().map(|token| format!("Bearer {}", token));
+
+    match expected_auth {
+      Some(expected_auth)
+        if request.headers().get_one("Authorization")
+          == Some(expected_auth.as_str()) =>
+      {
+        Outcome::Success(CliAuth)
+      }
+      _ => Outcome::Error((
+        Status::Unauthorized,
+        anyhow::anyhow!("Missing or invalid CLI token."),
+      )),
+    }
+  }
+}
@@ -0,0 +1,242 @@
+use std::{
+  fs,
+  path::{Path, PathBuf},
+  sync::Arc,
+  time::Duration,
+};
+
+use anyhow::{bail, Context};
+use chrono::{Local, NaiveDateTime, TimeZone};
+use tauri::{AppHandle, Manager};
+use tokio::{task, time};
+use tracing::{error, info, warn};
+
+use crate::{config::Config, portable};
+
+/// Format of backup directory names, which is also the timestamp passed
+/// to `zebar restore`.
+const TIMESTAMP_FORMAT: &str = "%Y%m%d-%H%M%S";
+
+/// Directories that are neither backed up nor touched on restore, since
+/// they can be large and are reproducible (e.g. via `npm install`).
+const EXCLUDED_DIRS: [&str; 2] = ["node_modules", ".git"];
+
+/// Max time between checks for whether a backup is due, so that changes
+/// to the backup settings apply without waiting a full interval.
+const MAX_CHECK_INTERVAL: Duration = Duration::from_secs(60 * 60);
+
+/// Directory that backups are stored in. Kept outside of the config
+/// directory, so that backups aren't picked up as widget configs.
+pub fn backups_dir(app_handle: &AppHandle) -> Option<PathBuf> {
+  portable::data_dir()
+    .or_else(|| app_handle.path().app_local_data_dir().ok())
+    .map(|dir| dir.join("backups"))
+}
+
+/// Periodically creates timestamped backups of the config directory, as
+/// set by the `backup` settings.
+pub struct ConfigBackups {
+  config: Arc<Config>,
+  backups_dir: PathBuf,
+}
+
+impl ConfigBackups {
+  /// Starts creating backups in a background task.
+  pub fn start(config: Arc<Config>, backups_dir: Option<PathBuf>) {
+    let Some(backups_dir) = backups_dir else {
+      warn!("No directory available for config backups.");
+      return;
+    };
+
+    let backups = Self {
+      config,
+      backups_dir,
+    };
+
+    task::spawn(async move {
+      loop {
+        let wait = match backups.backup_if_due().await {
+          Ok(wait) => wait,
+          Err(err) => {
+            error!("Failed to back up config: {:?}", err);
+            MAX_CHECK_INTERVAL
+          }
+        };
+
+        time::sleep(wait.min(MAX_CHECK_INTERVAL)).await;
+      }
+    });
+  }
+
+  /// Creates a backup if the latest one is older than the backup
+  /// interval. Returns how long until the next backup is due.
+  async fn backup_if_due(&self) -> anyhow::Result<Duration> {
+    let backup_config = self.config.settings.lock().await.backup.clone();
+
+    if !backup_config.enabled {
+      return Ok(MAX_CHECK_INTERVAL);
+    }
+
+    let interval = Duration::from_millis(backup_config.interval);
+
+    let elapsed = list_backups(&self.backups_dir)?
+      .last()
+      .and_then(|latest| parse_timestamp(latest))
+      .and_then(|created_at| (Local::now() - created_at).to_std().ok());
+
+    if let Some(elapsed) = elapsed.filter(|elapsed| *elapsed < interval) {
+      return Ok(interval - elapsed);
+    }
+
+    let config_dir = self.config.config_dir.clone();
+    let backups_dir = self.backups_dir.clone();
+    let retention = backup_config.retention;
+
+    let timestamp = task::spawn_blocking(move || {
+      let timestamp = create_backup(&config_dir, &backups_dir)?;
+      prune_backups(&backups_dir, retention)?;
+      anyhow::Ok(timestamp)
+    })
+    .await??;
+
+    info!("Backed up config directory as {}.", timestamp);
+
+    Ok(interval)
+  }
+}
+
+/// Copies the config directory into a new backup. Returns the timestamp
+/// of the backup.
+fn create_backup(
+  config_dir: &Path,
+  backups_dir: &Path,
+) -> anyhow::Result<String> {
+  let timestamp = Local::now().format(TIMESTAMP_FORMAT).to_string();
+  let backup_dir = backups_dir.join(&timestamp);
+
+  copy_dir(config_dir, &backup_dir).with_context(|| {
+    format!("Failed to back up config to {}.", backup_dir.display())
+  })?;
+
+  Ok(timestamp)
+}
+
+/// Gets the timestamps of all backups, from oldest to newest.
+pub fn list_backups(backups_dir: &Path) -> anyhow::Result<Vec<String>> {
+  if !backups_dir.exists() {
+    return Ok(Vec::new());
+  }
+
+  let mut timestamps = fs::read_dir(backups_dir)?
+    .flatten()
+    .filter(|entry| entry.path().is_dir())
+    .map(|entry| entry.file_name().to_string_lossy().to_string())
+    .filter(|name| parse_timestamp(name).is_some())
+    .collect::<Vec<_>>();
+
+  // Timestamps are zero-padded, so they sort chronologically.
+  timestamps.sort();
+
+  Ok(timestamps)
+}
+
+/// Replaces the contents of the config directory with the given
+/// backup.
+///
+/// The current config is backed up first, so that the restore can be
+/// undone.
+pub fn restore_backup(
+  config_dir: &Path,
+  backups_dir: &Path,
+  timestamp: &str,
+) -> anyhow::Result<String> {
+  let backup_dir = backups_dir.join(timestamp);
+
+  if parse_timestamp(timestamp).is_none() || !backup_dir.is_dir() {
+    bail!(
+      "No backup found for '{}'. Run `zebar restore` to list backups.",
+      timestamp
+    );
+  }
+
+  let current_timestamp = create_backup(config_dir, backups_dir)?;
+
+  clear_dir(config_dir).with_context(|| {
+    format!("Failed to clear {}.", config_dir.display())
+  })?;
+
+  copy_dir(&backup_dir, config_dir).with_context(|| {
+    format!("Failed to restore backup to {}.", config_dir.display())
+  })?;
+
+  Ok(current_timestamp)
+}
+
+/// Deletes the oldest backups, keeping the given number of backups.
+fn prune_backups(
+  backups_dir: &Path,
+  retention: usize,
+) -> anyhow::Result<()> {
+  let timestamps = list_backups(backups_dir)?;
+  let prune_count = timestamps.len().saturating_sub(retention.max(1));
+
+  for timestamp in &timestamps[..prune_count] {
+    fs::remove_dir_all(backups_dir.join(timestamp))?;
+  }
+
+  Ok(())
+}
+
+fn parse_timestamp(timestamp: &str) -> Option<chrono::DateTime<Local>> {
+  let naive =
+    NaiveDateTime::parse_from_str(timestamp, TIMESTAMP_FORMAT).ok()?;
+
+  Local.from_local_datetime(&naive).earliest()
+}
+
+fn is_excluded(path: &Path) -> bool {
+  path
+    .file_name()
+    .is_some_and(|name| EXCLUDED_DIRS.iter().any(|dir| name == *dir))
+}
+
+/// Recursively copies a directory, skipping excluded directories.
+fn copy_dir(src_dir: &Path, dest_dir: &Path) -> anyhow::Result<()> {
+  fs::create_dir_all(dest_dir)?;
+
+  for entry in fs::read_dir(src_dir)? {
+    let entry = entry?;
+    let dest_path = dest_dir.join(entry.file_name());
+
+    if entry.file_type()?.is_dir() {
+      if !is_excluded(&entry.path()) {
+        copy_dir(&entry.path(), &dest_path)?;
+      }
+    } else {
+      fs::copy(entry.path(), dest_path)?;
+    }
+  }
+
+  Ok(())
+}
+
+/// Recursively deletes the contents of a directory, leaving excluded
+/// directories (and the directories containing them) in place.
+fn clear_dir(dir: &Path) -> anyhow::Result<()> {
+  for entry in fs::read_dir(dir)? {
+    let entry = entry?;
+
+    if entry.file_type()?.is_dir() {
+      if !is_excluded(&entry.path()) {
+        clear_dir(&entry.path())?;
+
+        // Fails if an excluded directory was kept within it.
+        let _ = fs::remove_dir(entry.path());
+      }
+    } else {
+      fs::remove_file(entry.path())?;
+    }
+  }
+
+  Ok(())
+}
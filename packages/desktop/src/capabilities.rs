@@ -0,0 +1,97 @@
+use serde::Serialize;
+
+use crate::providers::provider_schemas;
+
+/// Version of the API exposed to widgets (i.e. the commands, events, and
+/// provider types). Passed to the widget's initialization script.
+///
+/// Incremented whenever the API changes, so that widgets can check for
+/// features newer than the Zebar version they're running on.
+pub const API_VERSION: u32 = 1;
+
+/// Names of all commands that widgets can invoke. Must be kept in sync
+/// with the invoke handler in `main.rs`.
+const COMMANDS: &[&str] = &[
+  "widget_configs",
+  "widget_states",
+  "start_widget",
+  "start_preset",
+  "stop_preset",
+  "update_widget_config",
+  "locale",
+  "widget_env",
+  "profiles",
+  "active_profile",
+  "switch_profile",
+  "display_profiles",
+  "save_display_profile",
+  "apply_display_profile_by_name",
+  "listen_provider",
+  "unlisten_provider",
+  "call_provider_function",
+  "get_provider_attachment",
+  "resolve_asset",
+  "get_provider_schema",
+  "get_provider_schemas",
+  "get_capabilities",
+  "run_command",
+  "set_secret",
+  "delete_secret",
+  "set_always_on_top",
+  "set_skip_taskbar",
+  "set_tray_badge",
+  "set_taskbar_overlay",
+  "set_taskbar_progress",
+  "set_keep_awake",
+  "power_action",
+  "launcher_search",
+  "launcher_activate",
+  "launcher_icon",
+  "query_history",
+  "export_history",
+  "capture_widget",
+  "show_context_menu",
+  "show_tooltip",
+  "hide_tooltip",
+  "accessibility_prefs",
+  "screen_presence",
+  "git_sync_status",
+  "git_sync_pull",
+  "git_sync_commit_and_push",
+  "widget_heartbeat",
+];
+
+/// What the running Zebar build supports, for widgets to degrade
+/// gracefully when a command or provider is unavailable.
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Capabilities {
+  /// Version of the widget API. See `API_VERSION`.
+  pub api_version: u32,
+
+  /// Version of Zebar (e.g. `2.4.0`).
+  pub version: &'static str,
+
+  /// Current platform (either `windows`, `macos`, or `linux`).
+  pub platform: &'static str,
+
+  /// Names of the commands that widgets can invoke.
+  pub commands: Vec<&'static str>,
+
+  /// Provider types available on the current platform.
+  pub providers: Vec<&'static str>,
+}
+
+/// Gets the capabilities of the running Zebar build.
+pub fn capabilities() -> Capabilities {
+  Capabilities {
+    api_version: API_VERSION,
+    version: env!("VERSION_NUMBER"),
+    platform: std::env::consts::OS,
+    commands: COMMANDS.to_vec(),
+    providers: provider_schemas()
+      .iter()
+      .map(|schema| schema.name)
+      .collect(),
+  }
+}
@@ -2,7 +2,9 @@ use std::{path::PathBuf, process};
 
 use clap::{Args, Parser, Subcommand, ValueEnum};
 
-use crate::{common::LengthValue, config::AnchorPoint};
+use crate::{
+  common::LengthValue, config::AnchorPoint, history::ExportFormat,
+};
 
 const VERSION: &'static str = env!("VERSION_NUMBER");
 
@@ -11,6 +13,26 @@ const VERSION: &'static str = env!("VERSION_NUMBER");
 pub struct Cli {
   #[command(subcommand)]
   command: Option<CliCommand>,
+
+  /// Absolute or relative path to the Zebar config directory. Can also
+  /// be set via the `ZEBAR_CONFIG_DIR` environment variable.
+  ///
+  /// The default path is `%userprofile%/.glzr/zebar/`, or `config/`
+  /// next to the executable in portable mode.
+  #[clap(
+    long,
+    global = true,
+    env = "ZEBAR_CONFIG_DIR",
+    value_hint = clap::ValueHint::DirPath
+  )]
+  pub config_dir: Option<PathBuf>,
+
+  /// Runs without a system tray icon.
+  ///
+  /// Zebar is then only controllable via the CLI (e.g. `zebar reload`
+  /// and `zebar exit`).
+  #[clap(long, global = true)]
+  pub no_tray: bool,
 }
 
 impl Cli {
@@ -42,12 +64,92 @@ pub enum CliCommand {
   /// Starts Zebar if it is not already running.
   Startup(StartupArgs),
 
+  /// Switches to a profile by its name, closing all open widgets and
+  /// opening the ones in the profile. Use `default` to switch to the
+  /// top-level startup configs.
+  ///
+  /// Starts Zebar if it is not already running.
+  Profile(ProfileArgs),
+
   /// Retrieves and outputs a specific part of the state.
   ///
   /// Requires an already running instance of Zebar.
   #[clap(subcommand)]
   Query(QueryArgs),
 
+  /// Stores a secret in the OS credential store, which can then be
+  /// referenced in provider configs as `secret:<NAME>`.
+  ///
+  /// Reads the value from stdin if `--value` is not provided.
+  SetSecret(SetSecretArgs),
+
+  /// Manages encryption of sensitive values in config files.
+  ///
+  /// Encrypted values can be used in place of any string in
+  /// `settings.json`, and in provider configs and `env` of widget
+  /// configs. They're decrypted using a key stored in the OS credential
+  /// store.
+  #[clap(subcommand)]
+  Encryption(EncryptionArgs),
+
+  /// Runs providers without a GUI, e.g.
+  /// `zebar provider get weather --config '{"latitude":52.5}'`.
+  ///
+  /// Zebar doesn't need to be running.
+  #[clap(subcommand)]
+  Provider(ProviderArgs),
+
+  /// Outputs a JSON schema of all provider configs and outputs.
+  ///
+  /// Used for generating the client API's TypeScript types.
+  Schema(SchemaArgs),
+
+  /// Outputs widget open and close events as newline-delimited JSON
+  /// until interrupted.
+  ///
+  /// Requires an already running instance of Zebar.
+  Watch(WatchArgs),
+
+  /// Outputs recorded history of a metric as CSV or JSON, e.g.
+  /// `zebar export --metric cpu --range 24h --format csv`.
+  ///
+  /// Reads directly from the history database, so Zebar doesn't need to
+  /// be running.
+  Export(ExportArgs),
+
+  /// Restores the config directory from a backup, e.g.
+  /// `zebar restore 20240131-090000`. Lists the available backups if no
+  /// timestamp is provided.
+  ///
+  /// The current config is backed up before restoring. Zebar doesn't
+  /// need to be running.
+  Restore(RestoreArgs),
+
+  /// Checks for common problems (e.g. a missing webview runtime, invalid
+  /// configs, or unreachable provider endpoints) and outputs a pass/fail
+  /// report.
+  ///
+  /// Exits with a non-zero code if any check fails. Zebar doesn't need
+  /// to be running.
+  Doctor,
+
+  /// Outputs how long Zebar has been running, the number of emissions
+  /// per provider, and the memory use of each open widget.
+  ///
+  /// Stats are only kept locally and are never sent anywhere. Requires
+  /// an already running instance of Zebar.
+  Stats(StatsArgs),
+
+  /// Empties the cache and reloads all widget configs.
+  ///
+  /// Requires an already running instance of Zebar.
+  Reload,
+
+  /// Exits Zebar.
+  ///
+  /// Requires an already running instance of Zebar.
+  Exit,
+
   /// Used when Zebar is launched with no arguments.
   ///
   /// If Zebar is already running, this command will no-op, otherwise it
@@ -111,11 +213,129 @@ pub struct StartWidgetPresetArgs {
 
 #[derive(Args, Clone, Debug, PartialEq)]
 pub struct StartupArgs {
-  /// Absolute or relative path to the Zebar config directory.
+  /// Starts an additional, independent instance of Zebar even if one is
+  /// already running. Requires `--config-dir`.
   ///
-  /// The default path is `%userprofile%/.glzr/zebar/`
+  /// CLI commands (e.g. `zebar reload`) are still sent to the original
+  /// instance.
+  #[clap(long)]
+  pub new_instance: bool,
+}
+
+#[derive(Args, Clone, Debug, PartialEq)]
+pub struct ProfileArgs {
+  /// Name of the profile in `settings.json`.
+  pub name: String,
+}
+
+#[derive(Args, Clone, Debug, PartialEq)]
+pub struct SetSecretArgs {
+  /// Name to reference the secret by.
+  #[clap(long)]
+  pub name: String,
+
+  /// Value of the secret.
+  #[clap(long)]
+  pub value: Option<String>,
+}
+
+#[derive(Args, Clone, Debug, PartialEq)]
+pub struct SchemaArgs {
+  /// File to write the schema to. Printed to stdout if not provided.
   #[clap(long, value_hint = clap::ValueHint::FilePath)]
-  pub config_dir: Option<PathBuf>,
+  pub output: Option<PathBuf>,
+}
+
+#[derive(Args, Clone, Debug, PartialEq)]
+pub struct WatchArgs {
+  /// Asset server port of the instance to watch. Only needed for
+  /// instances started with `--new-instance`.
+  #[clap(long)]
+  pub port: Option<u16>,
+}
+
+#[derive(Args, Clone, Debug, PartialEq)]
+pub struct StatsArgs {
+  /// Asset server port of the instance to query. Only needed for
+  /// instances started with `--new-instance`.
+  #[clap(long)]
+  pub port: Option<u16>,
+
+  /// Whether to output the stats as JSON.
+  #[clap(long)]
+  pub json: bool,
+}
+
+#[derive(Args, Clone, Debug, PartialEq)]
+pub struct ExportArgs {
+  /// Name of the metric in the `history` settings.
+  #[clap(long, visible_alias = "provider")]
+  pub metric: String,
+
+  /// How far back to export (e.g. `30m`, `24h`, or `7d`).
+  #[clap(long, default_value = "24h", value_parser = parse_range)]
+  pub range: u64,
+
+  /// Groups values into buckets of this size (e.g. `1h`). Exports the
+  /// raw samples if not provided.
+  #[clap(long, value_parser = parse_range)]
+  pub resolution: Option<u64>,
+
+  #[clap(long, default_value = "csv")]
+  pub format: ExportFormat,
+
+  /// File to write the export to. Printed to stdout if not provided.
+  #[clap(long, value_hint = clap::ValueHint::FilePath)]
+  pub output: Option<PathBuf>,
+}
+
+#[derive(Args, Clone, Debug, PartialEq)]
+pub struct RestoreArgs {
+  /// Timestamp of the backup to restore.
+  pub timestamp: Option<String>,
+}
+
+/// Parses a time range with a unit suffix (`s`, `m`, `h`, `d`, or `w`)
+/// into milliseconds.
+fn parse_range(range: &str) -> Result<u64, String> {
+  let unit_index = range
+    .find(|char: char| !char.is_ascii_digit())
+    .ok_or("Missing unit (e.g. `24h`).")?;
+
+  let (amount, unit) = range.split_at(unit_index);
+  let amount = amount.parse::<u64>().map_err(|err| err.to_string())?;
+
+  let unit_ms = match unit {
+    "s" => 1000,
+    "m" => 60 * 1000,
+    "h" => 60 * 60 * 1000,
+    "d" => 24 * 60 * 60 * 1000,
+    "w" => 7 * 24 * 60 * 60 * 1000,
+    _ => return Err(format!("Unknown unit '{}'.", unit)),
+  };
+
+  Ok(amount * unit_ms)
+}
+
+#[derive(Clone, Debug, Parser, PartialEq)]
+pub enum EncryptionArgs {
+  /// Outputs the encrypted form of a value to paste into a config file.
+  ///
+  /// Creates the encryption key if it doesn't exist yet. Reads the value
+  /// from stdin if `--value` is not provided.
+  Encrypt {
+    #[clap(long)]
+    value: Option<String>,
+  },
+
+  /// Outputs the encryption key, for importing on another machine.
+  ExportKey,
+
+  /// Stores an encryption key exported from another machine.
+  ImportKey {
+    #[clap(long)]
+    key: String,
+  },
 }
 
 #[derive(Clone, Debug, Parser, PartialEq)]
@@ -124,6 +344,23 @@ pub enum QueryArgs {
   Monitors,
 }
 
+#[derive(Clone, Debug, Parser, PartialEq)]
+pub enum ProviderArgs {
+  /// Outputs the first emission of a provider as JSON, then exits.
+  Get {
+    /// Provider type (e.g. `weather`).
+    provider_type: String,
+
+    /// Provider config as a JSON object, without the `type` field.
+    #[clap(long)]
+    config: Option<String>,
+
+    /// How long to wait for the provider to emit (e.g. `30s`).
+    #[clap(long, default_value = "30s", value_parser = parse_range)]
+    timeout: u64,
+  },
+}
+
 /// Prints to stdout/stderror and exits the process.
 pub fn print_and_exit(output: anyhow::Result<String>) {
   match output {
@@ -1,17 +1,46 @@
 use std::{collections::HashMap, path::PathBuf, sync::Arc};
 
-use tauri::{State, Window};
+use tauri::{ipc::Response, LogicalPosition, Manager, State, Window};
+use tauri_plugin_dialog::{
+  DialogExt, MessageDialogButtons, MessageDialogKind,
+};
+use tokio::task;
+use tracing::error;
 
 #[cfg(target_os = "macos")]
 use crate::common::macos::WindowExtMacOs;
 #[cfg(target_os = "windows")]
 use crate::common::windows::WindowExtWindows;
 use crate::{
-  config::{Config, WidgetConfig, WidgetPlacement},
+  accessibility::{AccessibilityPrefs, AccessibilityState},
+  asset_server::create_asset_url,
+  capabilities::{capabilities, Capabilities},
+  config::{
+    CommandPermission, Config, DisplayProfile, Profile, WidgetConfig,
+    WidgetPlacement,
+  },
+  display_profiles::{apply_display_profile, capture_display_profile},
+  git_sync::{GitSync, GitSyncStatus},
+  heartbeat::WidgetHeartbeats,
+  history::{ExportFormat, HistoryPoint, HistoryRecorder},
+  i18n::{self, t},
+  keep_awake::KEEP_AWAKE,
+  launcher::{LauncherManager, LauncherResult},
+  monitor_state::MonitorState,
+  native_popup::{self, ContextMenuItem, NativePopups},
+  power_action::PowerAction,
   providers::{
-    ProviderConfig, ProviderFunction, ProviderFunctionResponse,
-    ProviderManager,
+    parse_provider_config, provider_schema, provider_schemas,
+    ProviderConfigError, ProviderFunction, ProviderFunctionResponse,
+    ProviderManager, ProviderSchema,
   },
+  screen_presence::{ScreenPresence, ScreenPresenceState},
+  secrets,
+  shell_command::{run_shell_command, ShellCommandOutput},
+  sys_tray::SysTray,
+  taskbar_progress::{progress_bar_state, TaskbarProgress},
+  tray_badge::TrayBadge,
+  widget_capture::capture_widget_to_file,
   widget_factory::{WidgetFactory, WidgetOpenOptions, WidgetState},
 };
 
@@ -33,8 +62,17 @@ pub async fn widget_states(
 pub async fn start_widget(
   config_path: String,
   placement: WidgetPlacement,
+  window: Window,
   widget_factory: State<'_, Arc<WidgetFactory>>,
 ) -> anyhow::Result<(), String> {
+  widget_factory
+    .check_command_permission(
+      window.label(),
+      CommandPermission::ManageWidgets,
+    )
+    .await
+    .map_err(|err| err.to_string())?;
+
   widget_factory
     .start_widget(
       &PathBuf::from(config_path),
@@ -48,8 +86,17 @@ pub async fn start_widget(
 pub async fn start_preset(
   config_path: String,
   preset_name: String,
+  window: Window,
   widget_factory: State<'_, Arc<WidgetFactory>>,
 ) -> anyhow::Result<(), String> {
+  widget_factory
+    .check_command_permission(
+      window.label(),
+      CommandPermission::ManageWidgets,
+    )
+    .await
+    .map_err(|err| err.to_string())?;
+
   widget_factory
     .start_widget(
       &PathBuf::from(config_path),
@@ -63,8 +110,17 @@ pub async fn start_preset(
 pub async fn stop_preset(
   config_path: String,
   preset_name: String,
+  window: Window,
   widget_factory: State<'_, Arc<WidgetFactory>>,
 ) -> anyhow::Result<(), String> {
+  widget_factory
+    .check_command_permission(
+      window.label(),
+      CommandPermission::ManageWidgets,
+    )
+    .await
+    .map_err(|err| err.to_string())?;
+
   widget_factory
     .stop_by_preset(&PathBuf::from(config_path), &preset_name)
     .await
@@ -75,22 +131,189 @@ pub async fn stop_preset(
 pub async fn update_widget_config(
   config_path: String,
   new_config: WidgetConfig,
+  window: Window,
   config: State<'_, Arc<Config>>,
+  widget_factory: State<'_, Arc<WidgetFactory>>,
 ) -> Result<(), String> {
+  // Also prevents widgets from granting themselves permissions.
+  widget_factory
+    .check_command_permission(
+      window.label(),
+      CommandPermission::ManageWidgets,
+    )
+    .await
+    .map_err(|err| err.to_string())?;
+
   config
     .update_widget_config(&PathBuf::from(config_path), new_config)
     .await
     .map_err(|err| err.to_string())
 }
 
+/// Returns the `env` variables of the calling widget.
+#[tauri::command]
+pub async fn widget_env(
+  window: Window,
+  widget_factory: State<'_, Arc<WidgetFactory>>,
+) -> anyhow::Result<HashMap<String, String>, String> {
+  widget_factory
+    .env(window.label())
+    .await
+    .map_err(|err| err.to_string())
+}
+
+/// Returns the active locale for backend-generated text (e.g. `en` or
+/// `zh-CN`).
+#[tauri::command]
+pub fn locale() -> String {
+  i18n::locale()
+}
+
+#[tauri::command]
+pub async fn profiles(
+  config: State<'_, Arc<Config>>,
+) -> Result<Vec<Profile>, String> {
+  Ok(config.profiles().await)
+}
+
+#[tauri::command]
+pub async fn active_profile(
+  config: State<'_, Arc<Config>>,
+) -> Result<Option<String>, String> {
+  Ok(config.active_profile().await)
+}
+
+/// Switches to the given profile. Use `default` to switch to the
+/// top-level startup configs.
+#[tauri::command]
+pub async fn switch_profile(
+  name: String,
+  window: Window,
+  widget_factory: State<'_, Arc<WidgetFactory>>,
+) -> Result<(), String> {
+  widget_factory
+    .check_command_permission(
+      window.label(),
+      CommandPermission::ManageWidgets,
+    )
+    .await
+    .map_err(|err| err.to_string())?;
+
+  widget_factory
+    .switch_profile(&name)
+    .await
+    .map_err(|err| err.to_string())
+}
+
+#[tauri::command]
+pub async fn display_profiles(
+  config: State<'_, Arc<Config>>,
+) -> Result<Vec<DisplayProfile>, String> {
+  Ok(config.display_profiles().await)
+}
+
+#[tauri::command]
+pub async fn save_display_profile(
+  name: String,
+  window: Window,
+  config: State<'_, Arc<Config>>,
+  monitor_state: State<'_, Arc<MonitorState>>,
+  widget_factory: State<'_, Arc<WidgetFactory>>,
+) -> Result<DisplayProfile, String> {
+  widget_factory
+    .check_command_permission(
+      window.label(),
+      CommandPermission::DisplayProfiles,
+    )
+    .await
+    .map_err(|err| err.to_string())?;
+
+  let profile =
+    capture_display_profile(&name, &monitor_state.monitors().await);
+
+  config
+    .save_display_profile(profile.clone())
+    .await
+    .map_err(|err| err.to_string())?;
+
+  Ok(profile)
+}
+
+#[tauri::command]
+pub async fn apply_display_profile_by_name(
+  name: String,
+  window: Window,
+  config: State<'_, Arc<Config>>,
+  widget_factory: State<'_, Arc<WidgetFactory>>,
+) -> Result<(), String> {
+  widget_factory
+    .check_command_permission(
+      window.label(),
+      CommandPermission::DisplayProfiles,
+    )
+    .await
+    .map_err(|err| err.to_string())?;
+
+  let profile = config
+    .display_profile_by_name(&name)
+    .await
+    .ok_or_else(|| format!("No display profile named '{}'.", name))?;
+
+  apply_display_profile(&profile).map_err(|err| err.to_string())
+}
+
+/// Creates a provider from the given config.
+///
+/// String values of the form `secret:<NAME>` in the config are replaced
+/// with the corresponding secret from the OS credential store, if the
+/// secret is listed in the widget's `permissions.secrets`.
 #[tauri::command]
 pub async fn listen_provider(
   config_hash: String,
-  config: ProviderConfig,
+  config: serde_json::Value,
+  window: Window,
   provider_manager: State<'_, Arc<ProviderManager>>,
+  widget_factory: State<'_, Arc<WidgetFactory>>,
 ) -> anyhow::Result<(), String> {
+  let access = widget_factory
+    .secret_access(window.label())
+    .await
+    .map_err(|err| err.to_string())?;
+
+  // Credential store lookups can block (e.g. Secret Service on Linux).
+  let config =
+    task::spawn_blocking(move || parse_provider_config(config, &access))
+      .await
+      .map_err(|err| err.to_string())?;
+
+  let config = match config {
+    Ok(config) => config,
+    Err(err) => {
+      let Ok(err) = err.downcast::<ProviderConfigError>() else {
+        return Err(err.to_string());
+      };
+
+      // Add the widget's config path for context.
+      let err = match widget_factory.states().await.get(window.label()) {
+        Some(state) => err.with_config_path(&state.config_path),
+        None => err,
+      };
+
+      error!("{}", err);
+      err.show_dialog(window.app_handle());
+      return Err(err.to_string());
+    }
+  };
+
+  for provider_type in config.provider_types() {
+    widget_factory
+      .check_provider_permission(window.label(), provider_type)
+      .await
+      .map_err(|err| err.to_string())?;
+  }
+
   provider_manager
-    .create(config_hash, config)
+    .create(config_hash, config, window.label())
     .await
     .map_err(|err| err.to_string())
 }
@@ -98,10 +321,11 @@ pub async fn listen_provider(
 #[tauri::command]
 pub async fn unlisten_provider(
   config_hash: String,
+  window: Window,
   provider_manager: State<'_, Arc<ProviderManager>>,
 ) -> anyhow::Result<(), String> {
   provider_manager
-    .stop(config_hash)
+    .stop(config_hash, window.label())
     .await
     .map_err(|err| err.to_string())
 }
@@ -110,14 +334,557 @@ pub async fn unlisten_provider(
 pub async fn call_provider_function(
   config_hash: String,
   function: ProviderFunction,
+  window: Window,
   provider_manager: State<'_, Arc<ProviderManager>>,
+  widget_factory: State<'_, Arc<WidgetFactory>>,
 ) -> anyhow::Result<ProviderFunctionResponse, String> {
+  widget_factory
+    .check_provider_permission(window.label(), function.provider_type())
+    .await
+    .map_err(|err| err.to_string())?;
+
   provider_manager
     .call_function(config_hash, function)
     .await
     .map_err(|err| err.to_string())
 }
 
+/// Gets the binary data of a provider attachment (e.g. album art).
+///
+/// Returned as a raw binary payload, which is received as an
+/// `ArrayBuffer` on the frontend. The widget needs to be permitted to
+/// use the provider that emitted the attachment.
+#[tauri::command]
+pub async fn get_provider_attachment(
+  id: String,
+  window: Window,
+  provider_manager: State<'_, Arc<ProviderManager>>,
+  widget_factory: State<'_, Arc<WidgetFactory>>,
+) -> anyhow::Result<Response, String> {
+  let (data, provider_type) = provider_manager
+    .attachment(&id)
+    .await
+    .map_err(|err| err.to_string())?;
+
+  widget_factory
+    .check_provider_permission(window.label(), provider_type)
+    .await
+    .map_err(|err| err.to_string())?;
+
+  Ok(Response::new(data.to_vec()))
+}
+
+/// Gets a JSON schema of all provider configs and outputs.
+#[tauri::command]
+pub fn get_provider_schema() -> serde_json::Value {
+  provider_schema()
+}
+
+/// Gets the name, supported platforms, and config and output schemas of
+/// each provider type available on the current platform.
+#[tauri::command]
+pub fn get_provider_schemas() -> Vec<ProviderSchema> {
+  provider_schemas()
+}
+
+/// Gets the API version, commands, and provider types supported by the
+/// running Zebar build.
+#[tauri::command]
+pub fn get_capabilities() -> Capabilities {
+  capabilities()
+}
+
+/// Resolves an asset outside of the widget's own directory (e.g. a
+/// shared font or image) to a URL that the widget can load.
+///
+/// Relative paths are resolved from the widget's directory.
+#[tauri::command]
+pub async fn resolve_asset(
+  path: PathBuf,
+  window: Window,
+  config: State<'_, Arc<Config>>,
+  widget_factory: State<'_, Arc<WidgetFactory>>,
+) -> anyhow::Result<String, String> {
+  let base_dir = widget_factory
+    .states()
+    .await
+    .get(window.label())
+    .and_then(|state| state.config_path.parent().map(PathBuf::from))
+    .unwrap_or(config.config_dir.clone());
+
+  let asset_path = config
+    .resolve_asset_path(&base_dir, &path)
+    .map_err(|err| err.to_string())?;
+
+  create_asset_url(&asset_path)
+    .await
+    .map(|url| url.to_string())
+    .map_err(|err| err.to_string())
+}
+
+/// Stores a secret in the OS credential store, which can then be
+/// referenced in provider configs as `secret:<NAME>`.
+#[tauri::command]
+pub async fn set_secret(
+  name: String,
+  value: String,
+  window: Window,
+  widget_factory: State<'_, Arc<WidgetFactory>>,
+) -> anyhow::Result<(), String> {
+  widget_factory
+    .check_command_permission(window.label(), CommandPermission::Secrets)
+    .await
+    .map_err(|err| err.to_string())?;
+
+  task::spawn_blocking(move || secrets::set_secret(&name, &value))
+    .await
+    .map_err(|err| err.to_string())?
+    .map_err(|err| err.to_string())
+}
+
+#[tauri::command]
+pub async fn delete_secret(
+  name: String,
+  window: Window,
+  widget_factory: State<'_, Arc<WidgetFactory>>,
+) -> anyhow::Result<(), String> {
+  widget_factory
+    .check_command_permission(window.label(), CommandPermission::Secrets)
+    .await
+    .map_err(|err| err.to_string())?;
+
+  task::spawn_blocking(move || secrets::delete_secret(&name))
+    .await
+    .map_err(|err| err.to_string())?
+    .map_err(|err| err.to_string())
+}
+
+/// Runs a program on behalf of a widget, if allowed by the widget's
+/// permissions.
+#[tauri::command]
+pub async fn run_command(
+  program: String,
+  args: Vec<String>,
+  cwd: Option<String>,
+  timeout_ms: Option<u64>,
+  window: Window,
+  widget_factory: State<'_, Arc<WidgetFactory>>,
+) -> anyhow::Result<ShellCommandOutput, String> {
+  let cwd = widget_factory
+    .check_shell_command_permission(
+      window.label(),
+      &program,
+      &args,
+      cwd.as_deref(),
+    )
+    .await
+    .map_err(|err| err.to_string())?;
+
+  run_shell_command(&program, &args, cwd, timeout_ms)
+    .await
+    .map_err(|err| err.to_string())
+}
+
+/// Sets or clears the badge shown on the system tray icon.
+#[tauri::command]
+pub async fn set_tray_badge(
+  badge: Option<TrayBadge>,
+  window: Window,
+  widget_factory: State<'_, Arc<WidgetFactory>>,
+  tray: State<'_, Arc<SysTray>>,
+) -> anyhow::Result<(), String> {
+  widget_factory
+    .check_command_permission(window.label(), CommandPermission::TrayBadge)
+    .await
+    .map_err(|err| err.to_string())?;
+
+  tray.set_badge(badge).await.map_err(|err| err.to_string())
+}
+
+/// Sets or clears the badge overlaid on the widget's taskbar icon.
+///
+/// Only supported on Windows.
+#[tauri::command]
+pub async fn set_taskbar_overlay(
+  badge: Option<TrayBadge>,
+  window: Window,
+  widget_factory: State<'_, Arc<WidgetFactory>>,
+) -> anyhow::Result<(), String> {
+  widget_factory
+    .check_shown_in_taskbar(window.label())
+    .await
+    .map_err(|err| err.to_string())?;
+
+  window
+    .set_overlay_icon(badge.map(|badge| badge.render_standalone(32)))
+    .map_err(|err| err.to_string())
+}
+
+/// Sets or clears the progress shown on the widget's taskbar button.
+#[tauri::command]
+pub async fn set_taskbar_progress(
+  progress: Option<TaskbarProgress>,
+  window: Window,
+  widget_factory: State<'_, Arc<WidgetFactory>>,
+) -> anyhow::Result<(), String> {
+  widget_factory
+    .check_shown_in_taskbar(window.label())
+    .await
+    .map_err(|err| err.to_string())?;
+
+  window
+    .set_progress_bar(progress_bar_state(progress))
+    .map_err(|err| err.to_string())
+}
+
+/// Starts or stops preventing the system from sleeping.
+#[tauri::command]
+pub async fn set_keep_awake(
+  is_active: bool,
+  window: Window,
+  widget_factory: State<'_, Arc<WidgetFactory>>,
+) -> anyhow::Result<(), String> {
+  widget_factory
+    .check_command_permission(window.label(), CommandPermission::Power)
+    .await
+    .map_err(|err| err.to_string())?;
+
+  task::spawn_blocking(move || KEEP_AWAKE.set_active(is_active))
+    .await
+    .map_err(|err| err.to_string())?
+    .map_err(|err| err.to_string())
+}
+
+/// Locks, logs out, sleeps, hibernates, restarts, or shuts down the
+/// system. If `confirm` is set, the user is first asked to confirm via
+/// a dialog.
+///
+/// Returns `false` if the user cancelled the action.
+#[tauri::command]
+pub async fn power_action(
+  action: PowerAction,
+  confirm: Option<bool>,
+  window: Window,
+  widget_factory: State<'_, Arc<WidgetFactory>>,
+) -> anyhow::Result<bool, String> {
+  widget_factory
+    .check_command_permission(window.label(), CommandPermission::Power)
+    .await
+    .map_err(|err| err.to_string())?;
+
+  task::spawn_blocking(move || {
+    if confirm.unwrap_or(false) {
+      let is_confirmed = window
+        .dialog()
+        .message(action.confirm_message())
+        .title("Zebar")
+        .kind(MessageDialogKind::Warning)
+        .buttons(MessageDialogButtons::OkCancelCustom(
+          action.label(),
+          t("power.cancel"),
+        ))
+        .blocking_show();
+
+      if !is_confirmed {
+        return Ok(false);
+      }
+    }
+
+    action.run().map(|_| true)
+  })
+  .await
+  .map_err(|err| err.to_string())?
+  .map_err(|err| err.to_string())
+}
+
+/// Searches apps, open windows, and commands from the launcher.
+#[tauri::command]
+pub async fn launcher_search(
+  query: String,
+  limit: Option<usize>,
+  window: Window,
+  launcher: State<'_, Arc<LauncherManager>>,
+  widget_factory: State<'_, Arc<WidgetFactory>>,
+) -> anyhow::Result<Vec<LauncherResult>, String> {
+  widget_factory
+    .check_command_permission(window.label(), CommandPermission::Launcher)
+    .await
+    .map_err(|err| err.to_string())?;
+
+  launcher
+    .search(&query, limit)
+    .await
+    .map_err(|err| err.to_string())
+}
+
+/// Launches or focuses a launcher search result, then closes the
+/// launcher.
+#[tauri::command]
+pub async fn launcher_activate(
+  id: String,
+  window: Window,
+  launcher: State<'_, Arc<LauncherManager>>,
+  widget_factory: State<'_, Arc<WidgetFactory>>,
+) -> anyhow::Result<(), String> {
+  widget_factory
+    .check_command_permission(window.label(), CommandPermission::Launcher)
+    .await
+    .map_err(|err| err.to_string())?;
+
+  launcher.activate(&id).await.map_err(|err| err.to_string())
+}
+
+/// Gets the icon of a launcher search result as a PNG.
+///
+/// Returned as a raw binary payload, which is received as an
+/// `ArrayBuffer` on the frontend.
+#[tauri::command]
+pub async fn launcher_icon(
+  id: String,
+  window: Window,
+  launcher: State<'_, Arc<LauncherManager>>,
+  widget_factory: State<'_, Arc<WidgetFactory>>,
+) -> anyhow::Result<Response, String> {
+  widget_factory
+    .check_command_permission(window.label(), CommandPermission::Launcher)
+    .await
+    .map_err(|err| err.to_string())?;
+
+  launcher
+    .icon(&id)
+    .await
+    .map(Response::new)
+    .map_err(|err| err.to_string())
+}
+
+/// Gets the recorded values of a history metric between two Unix
+/// timestamps in milliseconds, optionally grouped by a resolution.
+#[tauri::command]
+pub async fn query_history(
+  metric: String,
+  start: i64,
+  end: Option<i64>,
+  resolution: Option<u64>,
+  window: Window,
+  widget_factory: State<'_, Arc<WidgetFactory>>,
+  history: State<'_, Arc<HistoryRecorder>>,
+) -> anyhow::Result<Vec<HistoryPoint>, String> {
+  widget_factory
+    .check_command_permission(window.label(), CommandPermission::History)
+    .await
+    .map_err(|err| err.to_string())?;
+
+  history
+    .query(metric, start, end, resolution)
+    .await
+    .map_err(|err| err.to_string())
+}
+
+/// Gets the recorded values of a history metric formatted as CSV or
+/// JSON.
+#[tauri::command]
+pub async fn export_history(
+  metric: String,
+  start: i64,
+  end: Option<i64>,
+  resolution: Option<u64>,
+  format: ExportFormat,
+  window: Window,
+  widget_factory: State<'_, Arc<WidgetFactory>>,
+  history: State<'_, Arc<HistoryRecorder>>,
+) -> anyhow::Result<String, String> {
+  widget_factory
+    .check_command_permission(window.label(), CommandPermission::History)
+    .await
+    .map_err(|err| err.to_string())?;
+
+  history
+    .export(metric, start, end, resolution, format)
+    .await
+    .map_err(|err| err.to_string())
+}
+
+/// Renders a widget to a PNG file. Captures the calling widget if no
+/// widget ID is given.
+///
+/// The output path is resolved from the widget's directory and must be
+/// within it. If no output path is given, it's picked by the user via a
+/// save dialog.
+#[tauri::command]
+pub async fn capture_widget(
+  output_path: Option<String>,
+  widget_id: Option<String>,
+  window: Window,
+  widget_factory: State<'_, Arc<WidgetFactory>>,
+) -> anyhow::Result<(), String> {
+  widget_factory
+    .check_command_permission(
+      window.label(),
+      CommandPermission::Screenshot,
+    )
+    .await
+    .map_err(|err| err.to_string())?;
+
+  // Capturing other widgets would expose their contents.
+  if widget_id.as_ref().is_some_and(|id| id != window.label()) {
+    widget_factory
+      .check_command_permission(
+        window.label(),
+        CommandPermission::ManageWidgets,
+      )
+      .await
+      .map_err(|err| err.to_string())?;
+  }
+
+  let label = widget_id.unwrap_or(window.label().to_string());
+
+  let target_window = window
+    .get_webview_window(&label)
+    .ok_or_else(|| format!("No widget found with ID '{}'.", label))?;
+
+  let output_path = match output_path {
+    Some(output_path) => widget_factory
+      .resolve_output_path(window.label(), &output_path)
+      .await
+      .map_err(|err| err.to_string())?,
+    None => {
+      let dialog = window
+        .dialog()
+        .file()
+        .set_title(t("tray.captureWidget"))
+        .set_file_name(format!("{}.png", label))
+        .add_filter("PNG", &["png"]);
+
+      let output_path =
+        task::spawn_blocking(move || dialog.blocking_save_file())
+          .await
+          .map_err(|err| err.to_string())?;
+
+      // No-op if the dialog was cancelled.
+      let Some(output_path) = output_path else {
+        return Ok(());
+      };
+
+      output_path.into_path().map_err(|err| err.to_string())?
+    }
+  };
+
+  capture_widget_to_file(&target_window, &output_path)
+    .await
+    .map_err(|err| err.to_string())
+}
+
+/// Shows a native context menu at the given position (in logical pixels
+/// relative to the window), or at the cursor if no position is given.
+///
+/// Returns the ID of the selected item, or `None` if the menu was closed
+/// without a selection.
+#[tauri::command]
+pub async fn show_context_menu(
+  items: Vec<ContextMenuItem>,
+  x: Option<f64>,
+  y: Option<f64>,
+  window: Window,
+  native_popups: State<'_, Arc<NativePopups>>,
+) -> anyhow::Result<Option<String>, String> {
+  let position = x.zip(y).map(|(x, y)| LogicalPosition::new(x, y));
+
+  native_popups
+    .show_context_menu(&window, &items, position)
+    .await
+    .map_err(|err| err.to_string())
+}
+
+/// Shows a native tooltip at the given position (in logical pixels
+/// relative to the window). Replaces any open tooltip of the widget.
+#[tauri::command]
+pub fn show_tooltip(
+  text: String,
+  x: f64,
+  y: f64,
+  window: Window,
+) -> anyhow::Result<(), String> {
+  native_popup::show_tooltip(&window, text, LogicalPosition::new(x, y))
+    .map_err(|err| err.to_string())
+}
+
+/// Hides the open tooltip of the widget (if any).
+#[tauri::command]
+pub fn hide_tooltip(window: Window) -> anyhow::Result<(), String> {
+  native_popup::hide_tooltip(&window).map_err(|err| err.to_string())
+}
+
+/// Gets the accessibility preferences from the OS settings.
+#[tauri::command]
+pub async fn accessibility_prefs(
+  accessibility_state: State<'_, Arc<AccessibilityState>>,
+) -> anyhow::Result<AccessibilityPrefs, String> {
+  Ok(accessibility_state.prefs().await)
+}
+
+/// Gets whether the display is on and the session is in use.
+#[tauri::command]
+pub async fn screen_presence(
+  screen_presence_state: State<'_, Arc<ScreenPresenceState>>,
+) -> anyhow::Result<ScreenPresence, String> {
+  Ok(screen_presence_state.presence().await)
+}
+
+/// Gets the git status of the config directory. `None` if git sync is
+/// disabled or the config directory isn't in a git repository.
+#[tauri::command]
+pub async fn git_sync_status(
+  git_sync: State<'_, Arc<GitSync>>,
+) -> anyhow::Result<Option<GitSyncStatus>, String> {
+  Ok(git_sync.status().await)
+}
+
+/// Pulls upstream changes to the config directory and reloads configs.
+#[tauri::command]
+pub async fn git_sync_pull(
+  window: Window,
+  git_sync: State<'_, Arc<GitSync>>,
+  widget_factory: State<'_, Arc<WidgetFactory>>,
+) -> anyhow::Result<(), String> {
+  widget_factory
+    .check_command_permission(window.label(), CommandPermission::GitSync)
+    .await
+    .map_err(|err| err.to_string())?;
+
+  git_sync.pull().await.map_err(|err| err.to_string())
+}
+
+/// Commits changes within the config directory and pushes them.
+#[tauri::command]
+pub async fn git_sync_commit_and_push(
+  window: Window,
+  git_sync: State<'_, Arc<GitSync>>,
+  widget_factory: State<'_, Arc<WidgetFactory>>,
+) -> anyhow::Result<(), String> {
+  widget_factory
+    .check_command_permission(window.label(), CommandPermission::GitSync)
+    .await
+    .map_err(|err| err.to_string())?;
+
+  git_sync
+    .commit_and_push()
+    .await
+    .map_err(|err| err.to_string())
+}
+
+/// Records a heartbeat from the calling widget. Sent periodically by
+/// the widget's initialization script, along with its JS heap size where
+/// the webview exposes it.
+#[tauri::command]
+pub async fn widget_heartbeat(
+  memory_usage: Option<u64>,
+  window: Window,
+  heartbeats: State<'_, Arc<WidgetHeartbeats>>,
+) -> anyhow::Result<(), String> {
+  heartbeats.beat(window.label(), memory_usage).await;
+  Ok(())
+}
+
 /// Tauri's implementation of `always_on_top` places the window above
 /// all normal windows (but not the MacOS menu bar). The following instead
 /// sets the z-order of the window to be above the menu bar.
@@ -0,0 +1,109 @@
+use std::fmt;
+
+use anyhow::Context;
+use regex::Regex;
+use schemars::{
+  gen::SchemaGenerator,
+  schema::{InstanceType, Schema, SchemaObject, SubschemaValidation},
+};
+use serde::{de, Deserializer};
+
+/// Pattern of a duration string, e.g. `500ms`, `5s`, `2m`, or `1h`.
+/// Strings without a unit are in milliseconds.
+const DURATION_PATTERN: &str = r"^\s*(\d+(?:\.\d+)?)\s*(ms|s|m|h)?\s*$";
+
+/// Parses a duration string (e.g. `5s`) into milliseconds.
+///
+/// Example:
+/// ```
+/// parse_duration_ms("1.5m") // 90000
+/// ```
+pub fn parse_duration_ms(unparsed: &str) -> anyhow::Result<u64> {
+  let err_msg = format!(
+    "Not a valid duration '{}'. Must be a number of milliseconds or of format '500ms', '5s', '2m' or '1h'.",
+    unparsed
+  );
+
+  let captures = Regex::new(DURATION_PATTERN)?
+    .captures(unparsed)
+    .context(err_msg.clone())?;
+
+  let amount = captures[1].parse::<f64>().context(err_msg)?;
+
+  let multiplier = match captures.get(2).map(|unit| unit.as_str()) {
+    Some("h") => 60. * 60. * 1000.,
+    Some("m") => 60. * 1000.,
+    Some("s") => 1000.,
+    _ => 1.,
+  };
+
+  Ok((amount * multiplier).round() as u64)
+}
+
+/// Deserializes a duration in milliseconds from either a number (e.g.
+/// `5000`) or a duration string (e.g. `"5s"`).
+///
+/// For use with `#[serde(deserialize_with = "deserialize_duration_ms")]`.
+pub fn deserialize_duration_ms<'de, D>(
+  deserializer: D,
+) -> Result<u64, D::Error>
+where
+  D: Deserializer<'de>,
+{
+  struct DurationVisitor;
+
+  impl<'de> de::Visitor<'de> for DurationVisitor {
+    type Value = u64;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+      write!(f, "a number of milliseconds or a string like '5s'")
+    }
+
+    fn visit_u64<E: de::Error>(self, value: u64) -> Result<u64, E> {
+      Ok(value)
+    }
+
+    fn visit_i64<E: de::Error>(self, value: i64) -> Result<u64, E> {
+      u64::try_from(value).map_err(|_| {
+        E::invalid_value(de::Unexpected::Signed(value), &self)
+      })
+    }
+
+    fn visit_f64<E: de::Error>(self, value: f64) -> Result<u64, E> {
+      match value >= 0. {
+        true => Ok(value.round() as u64),
+        false => {
+          Err(E::invalid_value(de::Unexpected::Float(value), &self))
+        }
+      }
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<u64, E> {
+      parse_duration_ms(value).map_err(E::custom)
+    }
+  }
+
+  deserializer.deserialize_any(DurationVisitor)
+}
+
+/// JSON schema of a duration that's deserialized with
+/// `deserialize_duration_ms`.
+///
+/// For use with `#[schemars(schema_with = "duration_schema")]`.
+pub fn duration_schema(gen: &mut SchemaGenerator) -> Schema {
+  let mut string_schema = SchemaObject {
+    instance_type: Some(InstanceType::String.into()),
+    ..Default::default()
+  };
+
+  string_schema.string().pattern = Some(DURATION_PATTERN.to_string());
+
+  SchemaObject {
+    subschemas: Some(Box::new(SubschemaValidation {
+      any_of: Some(vec![gen.subschema_for::<u64>(), string_schema.into()]),
+      ..Default::default()
+    })),
+    ..Default::default()
+  }
+  .into()
+}
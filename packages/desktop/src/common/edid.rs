@@ -0,0 +1,94 @@
+/// Fixed 8-byte header at the start of every EDID block.
+const EDID_HEADER: [u8; 8] =
+  [0x00, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x00];
+
+/// Tag of the display descriptor containing the serial number string.
+const SERIAL_DESCRIPTOR_TAG: u8 = 0xFF;
+
+/// Tag of the display descriptor containing the monitor name.
+const NAME_DESCRIPTOR_TAG: u8 = 0xFC;
+
+/// Identifying fields of a monitor's EDID (Extended Display
+/// Identification Data).
+#[derive(Clone, Debug, PartialEq)]
+pub struct Edid {
+  /// 3-letter PNP manufacturer ID (e.g. `DEL`).
+  pub manufacturer: String,
+
+  /// Manufacturer-assigned product code.
+  pub product_code: u16,
+
+  /// Serial number, preferring the serial number string descriptor over
+  /// the numeric serial (which many monitors leave as 0).
+  pub serial: Option<String>,
+
+  /// Monitor name from the name descriptor (e.g. `DELL U2415`).
+  pub name: Option<String>,
+}
+
+impl Edid {
+  /// Parses the base block of an EDID.
+  ///
+  /// Returns `None` if the data isn't a valid EDID.
+  pub fn parse(bytes: &[u8]) -> Option<Self> {
+    if bytes.len() < 128 || bytes[..8] != EDID_HEADER {
+      return None;
+    }
+
+    // Manufacturer ID is packed as three 5-bit letters (big-endian).
+    let packed = u16::from_be_bytes([bytes[8], bytes[9]]);
+    let manufacturer = [10, 5, 0]
+      .iter()
+      .map(|shift| (((packed >> shift) & 0x1F) as u8 + b'A' - 1) as char)
+      .collect::<String>();
+
+    let product_code = u16::from_le_bytes([bytes[10], bytes[11]]);
+    let serial_number =
+      u32::from_le_bytes([bytes[12], bytes[13], bytes[14], bytes[15]]);
+
+    let descriptor_text = |tag: u8| {
+      bytes[54..126]
+        .chunks_exact(18)
+        .find(|descriptor| {
+          descriptor[..3] == [0, 0, 0] && descriptor[3] == tag
+        })
+        .map(|descriptor| {
+          // Text is terminated by a newline and padded with spaces.
+          String::from_utf8_lossy(&descriptor[5..])
+            .split('\n')
+            .next()
+            .unwrap_or_default()
+            .trim()
+            .to_string()
+        })
+        .filter(|text| !text.is_empty())
+    };
+
+    let serial = descriptor_text(SERIAL_DESCRIPTOR_TAG)
+      .or((serial_number != 0).then(|| format!("{:08X}", serial_number)));
+
+    Some(Self {
+      manufacturer,
+      product_code,
+      serial,
+      name: descriptor_text(NAME_DESCRIPTOR_TAG),
+    })
+  }
+
+  /// Model ID in the same format as Windows' monitor hardware IDs (e.g.
+  /// `DEL40F0`).
+  pub fn model_id(&self) -> String {
+    format!("{}{:04X}", self.manufacturer, self.product_code)
+  }
+
+  /// Hardware ID that stays the same across reboots and driver updates.
+  ///
+  /// Consists of the model ID followed by the serial number if available
+  /// (e.g. `DEL40F0-7MT0166B1RJL`).
+  pub fn hardware_id(&self) -> String {
+    match &self.serial {
+      Some(serial) => format!("{}-{}", self.model_id(), serial),
+      None => self.model_id(),
+    }
+  }
+}
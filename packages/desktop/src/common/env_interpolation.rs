@@ -0,0 +1,43 @@
+use regex::{Captures, Regex};
+use tracing::warn;
+
+/// Pattern of an environment variable reference, e.g. `${USERNAME}` or
+/// `${DISK:-C:\}` with a fallback value.
+const ENV_VAR_PATTERN: &str =
+  r"\$\$|\$\{([A-Za-z_][A-Za-z0-9_]*)(?::-([^}]*))?\}";
+
+/// Replaces references to OS environment variables in a string.
+///
+/// References are of the form `${NAME}`, or `${NAME:-fallback}` to use
+/// `fallback` if the variable isn't set. Variables that aren't set and
+/// have no fallback are replaced with an empty string. A literal `$` can
+/// be escaped as `$$`.
+///
+/// Example:
+/// ```
+/// interpolate_env_vars("Hi ${USERNAME:-there}") // "Hi there"
+/// ```
+pub fn interpolate_env_vars(value: &str) -> String {
+  let regex = Regex::new(ENV_VAR_PATTERN).unwrap();
+
+  regex
+    .replace_all(value, |captures: &Captures| {
+      let Some(name) = captures.get(1) else {
+        return "$".to_string();
+      };
+
+      match (std::env::var(name.as_str()), captures.get(2)) {
+        (Ok(var), _) => var,
+        (Err(_), Some(fallback)) => fallback.as_str().to_string(),
+        (Err(_), None) => {
+          warn!(
+            "Environment variable '{}' is not set. Using an empty string.",
+            name.as_str()
+          );
+
+          String::new()
+        }
+      }
+    })
+    .into_owned()
+}
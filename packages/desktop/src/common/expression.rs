@@ -0,0 +1,572 @@
+use std::collections::HashMap;
+
+use anyhow::{bail, Context};
+use serde_json::{Number, Value};
+
+/// Parsed expression over provider outputs, as used by derived providers
+/// and alert conditions.
+///
+/// Supports:
+/// - Literals: numbers, `'strings'` or `"strings"`, `true`, `false`, and
+///   `null`.
+/// - Paths into source outputs (e.g. `network.interfaces[0].name`). `[]`
+///   selects every item of an array (e.g.
+///   `network.interfaces[].receivedRate`).
+/// - Arithmetic (`+ - * / %`), where `+` joins strings.
+/// - Comparisons (`== != < <= > >=`), logic (`&& || !`), and ternaries
+///   (`cond ? a : b`).
+/// - Functions: `sum`, `avg`, `min`, `max`, `count`, `round`, `floor`,
+///   `ceil`, and `abs`.
+///
+/// Arithmetic with `null` (e.g. a missing path) results in `null`.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Expression {
+  Literal(Value),
+  Path(String, Vec<PathSegment>),
+  Unary(UnaryOp, Box<Expression>),
+  Binary(BinaryOp, Box<Expression>, Box<Expression>),
+  Ternary(Box<Expression>, Box<Expression>, Box<Expression>),
+  Call(String, Vec<Expression>),
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum PathSegment {
+  Field(String),
+  Index(usize),
+
+  /// Every item of an array (`[]`).
+  All,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum UnaryOp {
+  Not,
+  Negate,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum BinaryOp {
+  Add,
+  Subtract,
+  Multiply,
+  Divide,
+  Remainder,
+  Equal,
+  NotEqual,
+  Less,
+  LessEqual,
+  Greater,
+  GreaterEqual,
+  And,
+  Or,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum Token {
+  Number(f64),
+  String(String),
+  Ident(String),
+  Symbol(&'static str),
+}
+
+/// Symbols ordered so that longer symbols are matched first.
+const SYMBOLS: [&str; 22] = [
+  "==", "!=", "<=", ">=", "&&", "||", "+", "-", "*", "/", "%", "<", ">",
+  "!", "?", ":", "(", ")", "[", "]", ".", ",",
+];
+
+impl Expression {
+  pub fn parse(source: &str) -> anyhow::Result<Expression> {
+    let tokens = tokenize(source)?;
+    let mut parser = Parser { tokens, pos: 0 };
+    let expression = parser.ternary()?;
+
+    if let Some(token) = parser.peek() {
+      bail!("Unexpected {:?} in expression '{}'.", token, source);
+    }
+
+    Ok(expression)
+  }
+
+  /// Names of the sources referenced by the expression.
+  pub fn sources(&self) -> Vec<&str> {
+    match self {
+      Expression::Literal(_) => Vec::new(),
+      Expression::Path(source, _) => vec![source.as_str()],
+      Expression::Unary(_, operand) => operand.sources(),
+      Expression::Binary(_, lhs, rhs) => {
+        [lhs.sources(), rhs.sources()].concat()
+      }
+      Expression::Ternary(cond, then, otherwise) => {
+        [cond.sources(), then.sources(), otherwise.sources()].concat()
+      }
+      Expression::Call(_, args) => {
+        args.iter().flat_map(|arg| arg.sources()).collect()
+      }
+    }
+  }
+
+  /// Evaluates the expression against the outputs of its sources.
+  pub fn evaluate(
+    &self,
+    outputs: &HashMap<String, Value>,
+  ) -> anyhow::Result<Value> {
+    match self {
+      Expression::Literal(value) => Ok(value.clone()),
+      Expression::Path(source, segments) => Ok(
+        outputs
+          .get(source)
+          .map(|output| resolve_path(output, segments))
+          .unwrap_or(Value::Null),
+      ),
+      Expression::Unary(op, operand) => {
+        let value = operand.evaluate(outputs)?;
+
+        match op {
+          UnaryOp::Not => Ok(Value::Bool(!is_truthy(&value))),
+          UnaryOp::Negate => match value {
+            Value::Null => Ok(Value::Null),
+            value => Ok(number(-as_number(&value)?)),
+          },
+        }
+      }
+      // Logical operators short-circuit.
+      Expression::Binary(BinaryOp::And, lhs, rhs) => Ok(Value::Bool(
+        is_truthy(&lhs.evaluate(outputs)?)
+          && is_truthy(&rhs.evaluate(outputs)?),
+      )),
+      Expression::Binary(BinaryOp::Or, lhs, rhs) => Ok(Value::Bool(
+        is_truthy(&lhs.evaluate(outputs)?)
+          || is_truthy(&rhs.evaluate(outputs)?),
+      )),
+      Expression::Binary(op, lhs, rhs) => {
+        binary(*op, lhs.evaluate(outputs)?, rhs.evaluate(outputs)?)
+      }
+      Expression::Ternary(cond, then, otherwise) => {
+        match is_truthy(&cond.evaluate(outputs)?) {
+          true => then.evaluate(outputs),
+          false => otherwise.evaluate(outputs),
+        }
+      }
+      Expression::Call(name, args) => {
+        let args = args
+          .iter()
+          .map(|arg| arg.evaluate(outputs))
+          .collect::<anyhow::Result<Vec<_>>>()?;
+
+        call(name, args)
+      }
+    }
+  }
+}
+
+fn tokenize(source: &str) -> anyhow::Result<Vec<Token>> {
+  let mut tokens = Vec::new();
+  let mut rest = source;
+
+  while let Some(char) = rest.chars().next() {
+    if char.is_whitespace() {
+      rest = &rest[char.len_utf8()..];
+    } else if char.is_ascii_digit() {
+      let end = rest
+        .find(|c: char| !c.is_ascii_digit() && c != '.')
+        .unwrap_or(rest.len());
+
+      let number = rest[..end]
+        .parse()
+        .with_context(|| format!("Invalid number '{}'.", &rest[..end]))?;
+
+      tokens.push(Token::Number(number));
+      rest = &rest[end..];
+    } else if char.is_alphabetic() || char == '_' {
+      let end = rest
+        .find(|c: char| !c.is_alphanumeric() && c != '_')
+        .unwrap_or(rest.len());
+
+      tokens.push(Token::Ident(rest[..end].to_string()));
+      rest = &rest[end..];
+    } else if char == '\'' || char == '"' {
+      let end = rest[1..].find(char).with_context(|| {
+        format!("Unterminated string in '{}'.", source)
+      })?;
+
+      tokens.push(Token::String(rest[1..end + 1].to_string()));
+      rest = &rest[end + 2..];
+    } else {
+      let symbol = SYMBOLS
+        .iter()
+        .find(|symbol| rest.starts_with(*symbol))
+        .with_context(|| {
+          format!("Unexpected '{}' in expression '{}'.", char, source)
+        })?;
+
+      tokens.push(Token::Symbol(*symbol));
+      rest = &rest[symbol.len()..];
+    }
+  }
+
+  Ok(tokens)
+}
+
+/// Recursive descent parser, with one method per precedence level.
+struct Parser {
+  tokens: Vec<Token>,
+  pos: usize,
+}
+
+impl Parser {
+  fn peek(&self) -> Option<&Token> {
+    self.tokens.get(self.pos)
+  }
+
+  fn advance(&mut self) -> Option<Token> {
+    let token = self.tokens.get(self.pos).cloned();
+    self.pos += 1;
+    token
+  }
+
+  /// Consumes the given symbol if it's next.
+  fn eat(&mut self, symbol: &str) -> bool {
+    let is_next =
+      matches!(self.peek(), Some(Token::Symbol(next)) if *next == symbol);
+
+    if is_next {
+      self.pos += 1;
+    }
+
+    is_next
+  }
+
+  fn expect(&mut self, symbol: &str) -> anyhow::Result<()> {
+    if !self.eat(symbol) {
+      bail!("Expected '{}' but found {:?}.", symbol, self.peek());
+    }
+
+    Ok(())
+  }
+
+  fn ternary(&mut self) -> anyhow::Result<Expression> {
+    let cond = self.binary(0)?;
+
+    if !self.eat("?") {
+      return Ok(cond);
+    }
+
+    let then = self.ternary()?;
+    self.expect(":")?;
+    let otherwise = self.ternary()?;
+
+    Ok(Expression::Ternary(
+      Box::new(cond),
+      Box::new(then),
+      Box::new(otherwise),
+    ))
+  }
+
+  /// Parses left-associative binary operators, starting at the given
+  /// precedence level.
+  fn binary(&mut self, level: usize) -> anyhow::Result<Expression> {
+    const LEVELS: [&[(&str, BinaryOp)]; 6] = [
+      &[("||", BinaryOp::Or)],
+      &[("&&", BinaryOp::And)],
+      &[("==", BinaryOp::Equal), ("!=", BinaryOp::NotEqual)],
+      &[
+        ("<=", BinaryOp::LessEqual),
+        (">=", BinaryOp::GreaterEqual),
+        ("<", BinaryOp::Less),
+        (">", BinaryOp::Greater),
+      ],
+      &[("+", BinaryOp::Add), ("-", BinaryOp::Subtract)],
+      &[
+        ("*", BinaryOp::Multiply),
+        ("/", BinaryOp::Divide),
+        ("%", BinaryOp::Remainder),
+      ],
+    ];
+
+    let Some(ops) = LEVELS.get(level) else {
+      return self.unary();
+    };
+
+    let mut lhs = self.binary(level + 1)?;
+
+    'outer: loop {
+      for (symbol, op) in ops.iter() {
+        if self.eat(symbol) {
+          let rhs = self.binary(level + 1)?;
+          lhs = Expression::Binary(*op, Box::new(lhs), Box::new(rhs));
+          continue 'outer;
+        }
+      }
+
+      return Ok(lhs);
+    }
+  }
+
+  fn unary(&mut self) -> anyhow::Result<Expression> {
+    if self.eat("!") {
+      return Ok(Expression::Unary(UnaryOp::Not, Box::new(self.unary()?)));
+    }
+
+    if self.eat("-") {
+      return Ok(Expression::Unary(
+        UnaryOp::Negate,
+        Box::new(self.unary()?),
+      ));
+    }
+
+    self.primary()
+  }
+
+  fn primary(&mut self) -> anyhow::Result<Expression> {
+    match self.advance() {
+      Some(Token::Number(number)) => Ok(Expression::Literal(
+        Number::from_f64(number).map_or(Value::Null, Value::Number),
+      )),
+      Some(Token::String(string)) => {
+        Ok(Expression::Literal(Value::String(string)))
+      }
+      Some(Token::Symbol("(")) => {
+        let expression = self.ternary()?;
+        self.expect(")")?;
+        Ok(expression)
+      }
+      Some(Token::Ident(ident)) => match ident.as_str() {
+        "true" => Ok(Expression::Literal(Value::Bool(true))),
+        "false" => Ok(Expression::Literal(Value::Bool(false))),
+        "null" => Ok(Expression::Literal(Value::Null)),
+        _ if self.eat("(") => self.call(ident),
+        _ => self.path(ident),
+      },
+      token => bail!("Unexpected {:?} in expression.", token),
+    }
+  }
+
+  fn call(&mut self, name: String) -> anyhow::Result<Expression> {
+    let mut args = Vec::new();
+
+    if !self.eat(")") {
+      loop {
+        args.push(self.ternary()?);
+
+        if self.eat(")") {
+          break;
+        }
+
+        self.expect(",")?;
+      }
+    }
+
+    Ok(Expression::Call(name, args))
+  }
+
+  fn path(&mut self, source: String) -> anyhow::Result<Expression> {
+    let mut segments = Vec::new();
+
+    loop {
+      if self.eat(".") {
+        match self.advance() {
+          Some(Token::Ident(field)) => {
+            segments.push(PathSegment::Field(field))
+          }
+          token => bail!("Expected a field name but found {:?}.", token),
+        }
+      } else if self.eat("[") {
+        if self.eat("]") {
+          segments.push(PathSegment::All);
+          continue;
+        }
+
+        match self.advance() {
+          Some(Token::Number(index)) if index.fract() == 0.0 => {
+            segments.push(PathSegment::Index(index as usize))
+          }
+          token => bail!("Expected an index but found {:?}.", token),
+        }
+
+        self.expect("]")?;
+      } else {
+        return Ok(Expression::Path(source, segments));
+      }
+    }
+  }
+}
+
+/// Resolves a path within a value. Missing fields resolve to `null`.
+fn resolve_path(value: &Value, segments: &[PathSegment]) -> Value {
+  let Some((segment, rest)) = segments.split_first() else {
+    return value.clone();
+  };
+
+  match segment {
+    PathSegment::Field(field) => value
+      .get(field)
+      .map_or(Value::Null, |value| resolve_path(value, rest)),
+    PathSegment::Index(index) => value
+      .get(index)
+      .map_or(Value::Null, |value| resolve_path(value, rest)),
+    PathSegment::All => {
+      let Value::Array(items) = value else {
+        return Value::Null;
+      };
+
+      // Nested `[]` selections are flattened into a single array.
+      let is_nested = rest.contains(&PathSegment::All);
+
+      let items = items.iter().map(|item| resolve_path(item, rest));
+
+      Value::Array(match is_nested {
+        true => items
+          .flat_map(|item| match item {
+            Value::Array(items) => items,
+            item => vec![item],
+          })
+          .collect(),
+        false => items.collect(),
+      })
+    }
+  }
+}
+
+fn binary(op: BinaryOp, lhs: Value, rhs: Value) -> anyhow::Result<Value> {
+  match op {
+    BinaryOp::Equal => Ok(Value::Bool(is_equal(&lhs, &rhs))),
+    BinaryOp::NotEqual => Ok(Value::Bool(!is_equal(&lhs, &rhs))),
+    // Comparisons with `null` are always false.
+    BinaryOp::Less
+    | BinaryOp::LessEqual
+    | BinaryOp::Greater
+    | BinaryOp::GreaterEqual => {
+      let ordering = match (&lhs, &rhs) {
+        (Value::Null, _) | (_, Value::Null) => {
+          return Ok(Value::Bool(false))
+        }
+        (Value::String(lhs), Value::String(rhs)) => lhs.partial_cmp(rhs),
+        _ => as_number(&lhs)?.partial_cmp(&as_number(&rhs)?),
+      };
+
+      Ok(Value::Bool(ordering.is_some_and(|ordering| match op {
+        BinaryOp::Less => ordering.is_lt(),
+        BinaryOp::LessEqual => ordering.is_le(),
+        BinaryOp::Greater => ordering.is_gt(),
+        _ => ordering.is_ge(),
+      })))
+    }
+    _ if lhs.is_null() || rhs.is_null() => Ok(Value::Null),
+    BinaryOp::Add if lhs.is_string() || rhs.is_string() => {
+      Ok(Value::String(to_string(&lhs) + &to_string(&rhs)))
+    }
+    _ => {
+      let (lhs, rhs) = (as_number(&lhs)?, as_number(&rhs)?);
+
+      Ok(number(match op {
+        BinaryOp::Add => lhs + rhs,
+        BinaryOp::Subtract => lhs - rhs,
+        BinaryOp::Multiply => lhs * rhs,
+        BinaryOp::Divide => lhs / rhs,
+        _ => lhs % rhs,
+      }))
+    }
+  }
+}
+
+fn call(name: &str, args: Vec<Value>) -> anyhow::Result<Value> {
+  match name {
+    "sum" | "avg" | "min" | "max" | "count" => {
+      // Aggregates take either a single array or multiple arguments.
+      let values = match <[Value; 1]>::try_from(args) {
+        Ok([Value::Array(values)]) => values,
+        Ok([value]) => vec![value],
+        Err(args) => args,
+      };
+
+      if name == "count" {
+        return Ok(number(values.len() as f64));
+      }
+
+      // Nulls (e.g. missing fields) are skipped.
+      let numbers = values
+        .iter()
+        .filter(|value| !value.is_null())
+        .map(as_number)
+        .collect::<anyhow::Result<Vec<_>>>()?;
+
+      let result = match name {
+        "sum" => Some(numbers.iter().sum()),
+        "avg" => (!numbers.is_empty())
+          .then(|| numbers.iter().sum::<f64>() / numbers.len() as f64),
+        "min" => numbers.iter().copied().reduce(f64::min),
+        _ => numbers.iter().copied().reduce(f64::max),
+      };
+
+      Ok(result.map_or(Value::Null, number))
+    }
+    "round" | "floor" | "ceil" | "abs" => {
+      let (value, decimals) = match args.as_slice() {
+        [value] => (value, 0),
+        [value, decimals] if name == "round" => {
+          (value, as_number(decimals)? as i32)
+        }
+        _ => bail!("Wrong number of arguments to `{}`.", name),
+      };
+
+      if value.is_null() {
+        return Ok(Value::Null);
+      }
+
+      let value = as_number(value)?;
+      let factor = 10f64.powi(decimals);
+
+      Ok(number(match name {
+        "round" => (value * factor).round() / factor,
+        "floor" => value.floor(),
+        "ceil" => value.ceil(),
+        _ => value.abs(),
+      }))
+    }
+    _ => bail!("Unknown function `{}`.", name),
+  }
+}
+
+fn number(value: f64) -> Value {
+  // Non-finite numbers (e.g. from dividing by zero) can't be
+  // represented in JSON.
+  Number::from_f64(value).map_or(Value::Null, Value::Number)
+}
+
+fn as_number(value: &Value) -> anyhow::Result<f64> {
+  match value {
+    Value::Number(number) => Ok(number.as_f64().unwrap_or_default()),
+    Value::Bool(bool) => Ok(*bool as u8 as f64),
+    value => bail!("Expected a number but got {}.", value),
+  }
+}
+
+fn is_equal(lhs: &Value, rhs: &Value) -> bool {
+  match (lhs, rhs) {
+    (Value::Number(lhs), Value::Number(rhs)) => {
+      lhs.as_f64() == rhs.as_f64()
+    }
+    _ => lhs == rhs,
+  }
+}
+
+/// Whether a value counts as true in conditions. `null`, `false`, `0`,
+/// and empty strings and arrays are falsy.
+pub fn is_truthy(value: &Value) -> bool {
+  match value {
+    Value::Null => false,
+    Value::Bool(bool) => *bool,
+    Value::Number(number) => number.as_f64() != Some(0.0),
+    Value::String(string) => !string.is_empty(),
+    Value::Array(items) => !items.is_empty(),
+    Value::Object(_) => true,
+  }
+}
+
+fn to_string(value: &Value) -> String {
+  match value {
+    Value::String(string) => string.clone(),
+    value => value.to_string(),
+  }
+}
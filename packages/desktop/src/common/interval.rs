@@ -1,18 +1,88 @@
-use std::time::{Duration, Instant};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+use rand::Rng;
+use schemars::JsonSchema;
+use serde::Deserialize;
+use tokio::sync::broadcast;
+
+use super::{is_asleep, on_wake, wait_for_network};
+
+/// Options for when the ticks of an interval occur.
+#[derive(Clone, Debug, Default, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct IntervalOptions {
+  /// Whether to tick on multiples of the interval since the Unix epoch
+  /// (e.g. exactly on the minute for an interval of `1m`). The first
+  /// tick still occurs immediately.
+  #[serde(default)]
+  pub align: bool,
+
+  /// Max random delay in milliseconds to add to each tick. Avoids
+  /// providers hitting an external API at the same time (e.g. on
+  /// startup).
+  #[serde(default, deserialize_with = "deserialize_optional_duration_ms")]
+  #[schemars(schema_with = "super::duration_schema")]
+  pub jitter: Option<u64>,
+}
+
+impl IntervalOptions {
+  /// Gets a random delay within the configured jitter.
+  fn jitter_delay(&self) -> Duration {
+    match self.jitter {
+      Some(jitter) if jitter > 0 => {
+        Duration::from_millis(rand::thread_rng().gen_range(0..=jitter))
+      }
+      _ => Duration::ZERO,
+    }
+  }
+}
+
+fn deserialize_optional_duration_ms<'de, D>(
+  deserializer: D,
+) -> Result<Option<u64>, D::Error>
+where
+  D: serde::Deserializer<'de>,
+{
+  super::deserialize_duration_ms(deserializer).map(Some)
+}
+
+/// Gets the time until the next multiple of the interval since the Unix
+/// epoch.
+fn delay_until_aligned(interval: Duration) -> Duration {
+  let interval_ms = interval.as_millis().max(1);
+
+  let since_epoch_ms = SystemTime::now()
+    .duration_since(UNIX_EPOCH)
+    .unwrap_or_default()
+    .as_millis();
+
+  Duration::from_millis(
+    (interval_ms - since_epoch_ms % interval_ms) as u64,
+  )
+}
 
 /// An interval timer for synchronous contexts using crossbeam.
 ///
 /// For use with crossbeam's `select!` macro.
 pub struct SyncInterval {
   interval: Duration,
+  options: IntervalOptions,
   next_tick: Instant,
   is_first: bool,
 }
 
 impl SyncInterval {
   pub fn new(interval_ms: u64) -> Self {
+    Self::with_options(interval_ms, &IntervalOptions::default())
+  }
+
+  pub fn with_options(
+    interval_ms: u64,
+    options: &IntervalOptions,
+  ) -> Self {
     Self {
       interval: Duration::from_millis(interval_ms),
+      options: options.clone(),
       next_tick: Instant::now(),
       is_first: true,
     }
@@ -20,24 +90,31 @@ impl SyncInterval {
 
   /// Returns a receiver that will get a message at the next tick time.
   pub fn tick(&mut self) -> crossbeam::channel::Receiver<Instant> {
+    let delay = self.next_delay() + self.options.jitter_delay();
+    crossbeam::channel::after(delay)
+  }
+
+  /// Gets the time until the next tick, excluding jitter.
+  fn next_delay(&mut self) -> Duration {
     if self.is_first {
       // Emit immediately on the first tick.
       self.is_first = false;
-      crossbeam::channel::after(Duration::from_secs(0))
+      Duration::ZERO
+    } else if self.options.align {
+      delay_until_aligned(self.interval)
     } else if let Some(wait_duration) =
       self.next_tick.checked_duration_since(Instant::now())
     {
       // Wait normally until the next tick.
-      let timer = crossbeam::channel::after(wait_duration);
       self.next_tick += self.interval;
-      timer
+      wait_duration
     } else {
       // We're behind - skip missed ticks to catch up.
       while self.next_tick <= Instant::now() {
         self.next_tick += self.interval;
       }
 
-      crossbeam::channel::after(self.next_tick - Instant::now())
+      self.next_tick - Instant::now()
     }
   }
 }
@@ -45,10 +122,32 @@ impl SyncInterval {
 /// An interval timer for asynchronous contexts using tokio.
 pub struct AsyncInterval {
   interval: tokio::time::Interval,
+  options: IntervalOptions,
+  is_first: bool,
+
+  /// Receiver for wake events if the interval refreshes on wake.
+  wake_rx: Option<broadcast::Receiver<()>>,
+
+  /// Whether a wake event was received, and the tick is waiting for a
+  /// network connection.
+  is_waking: bool,
+
+  /// When the pending aligned tick is due, if it's being waited for.
+  aligned_deadline: Option<tokio::time::Instant>,
+
+  /// When the jitter delay after a tick ends, if it's being waited for.
+  jitter_deadline: Option<tokio::time::Instant>,
 }
 
 impl AsyncInterval {
   pub fn new(interval_ms: u64) -> Self {
+    Self::with_options(interval_ms, &IntervalOptions::default())
+  }
+
+  pub fn with_options(
+    interval_ms: u64,
+    options: &IntervalOptions,
+  ) -> Self {
     let mut interval =
       tokio::time::interval(Duration::from_millis(interval_ms));
 
@@ -57,11 +156,108 @@ impl AsyncInterval {
     interval
       .set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
 
-    Self { interval }
+    Self {
+      interval,
+      options: options.clone(),
+      is_first: true,
+      wake_rx: None,
+      is_waking: false,
+      aligned_deadline: None,
+      jitter_deadline: None,
+    }
+  }
+
+  /// Ticks as soon as a network is connected after the system wakes from
+  /// sleep, instead of showing stale data until the next tick. Ticks
+  /// that were due while asleep are skipped.
+  ///
+  /// For providers that fetch remote data (e.g. weather).
+  pub fn refresh_on_wake(mut self) -> Self {
+    self.wake_rx = Some(on_wake());
+    self
   }
 
   /// Returns a future that will complete at the next tick time.
+  ///
+  /// Cancel-safe, so it can be used in a `select!`. Deadlines of the
+  /// aligned tick and the jitter delay are kept, so that a cancelled call
+  /// is resumed by the next one rather than restarting the wait.
   pub async fn tick(&mut self) {
-    self.interval.tick().await;
+    // Skip to the jitter delay if the previous call was cancelled
+    // during it, since the tick itself already occurred.
+    if self.jitter_deadline.is_none() {
+      self.wait_for_tick().await;
+
+      let jitter_delay = self.options.jitter_delay();
+
+      if !jitter_delay.is_zero() {
+        self.jitter_deadline =
+          Some(tokio::time::Instant::now() + jitter_delay);
+      }
+    }
+
+    if let Some(deadline) = self.jitter_deadline {
+      tokio::time::sleep_until(deadline).await;
+      self.jitter_deadline = None;
+    }
+  }
+
+  async fn wait_for_tick(&mut self) {
+    // Waiting for the network is resumed if the previous call was
+    // cancelled (e.g. in a `select!`).
+    while !self.is_waking {
+      let next_tick = Self::next_tick(
+        &mut self.interval,
+        &mut self.aligned_deadline,
+        &self.options,
+        self.is_first,
+      );
+
+      let Some(wake_rx) = &mut self.wake_rx else {
+        next_tick.await;
+        break;
+      };
+
+      self.is_waking = tokio::select! {
+        _ = next_tick => false,
+        _ = wake_rx.recv() => true,
+      };
+
+      if self.is_waking {
+        self.interval.reset();
+        self.aligned_deadline = None;
+      } else if !is_asleep() {
+        break;
+      }
+
+      // Otherwise, the tick is skipped since the system is going to
+      // sleep, and the refresh on wake replaces it.
+    }
+
+    if self.is_waking {
+      wait_for_network().await;
+      self.is_waking = false;
+    }
+
+    self.is_first = false;
+  }
+
+  async fn next_tick(
+    interval: &mut tokio::time::Interval,
+    aligned_deadline: &mut Option<tokio::time::Instant>,
+    options: &IntervalOptions,
+    is_first: bool,
+  ) {
+    if options.align && !is_first {
+      let deadline = *aligned_deadline.get_or_insert_with(|| {
+        tokio::time::Instant::now()
+          + delay_until_aligned(interval.period())
+      });
+
+      tokio::time::sleep_until(deadline).await;
+      *aligned_deadline = None;
+    } else {
+      interval.tick().await;
+    }
   }
 }
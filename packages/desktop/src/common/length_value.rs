@@ -30,7 +30,9 @@ impl LengthValue {
   pub fn to_px_scaled(&self, total_px: i32, scale_factor: f32) -> i32 {
     match self.unit {
       LengthUnit::Percentage => self.to_px(total_px),
-      LengthUnit::Pixel => (scale_factor * self.amount) as i32,
+      // Round rather than truncate, since fractional scale factors (e.g.
+      // 1.25) otherwise shrink sizes and offsets by a pixel.
+      LengthUnit::Pixel => (scale_factor * self.amount).round() as i32,
     }
   }
 }
@@ -105,3 +107,36 @@ impl Default for LengthValue {
     }
   }
 }
+
+#[cfg(test)]
+mod tests {
+  use std::str::FromStr;
+
+  use super::LengthValue;
+
+  #[test]
+  fn to_px_scaled_rounds_pixel_values() {
+    let length = LengthValue::from_str("30px").unwrap();
+    assert_eq!(length.to_px_scaled(1000, 1.25), 38);
+
+    let length = LengthValue::from_str("33px").unwrap();
+    assert_eq!(length.to_px_scaled(1000, 1.5), 50);
+    assert_eq!(length.to_px_scaled(1000, 1.), 33);
+  }
+
+  #[test]
+  fn to_px_scaled_rounds_negative_pixel_values() {
+    let length = LengthValue::from_str("-33px").unwrap();
+    assert_eq!(length.to_px_scaled(1000, 1.5), -50);
+
+    let length = LengthValue::from_str("-10px").unwrap();
+    assert_eq!(length.to_px_scaled(1000, 1.25), -13);
+  }
+
+  #[test]
+  fn to_px_scaled_ignores_scale_factor_for_percentages() {
+    let length = LengthValue::from_str("50%").unwrap();
+    assert_eq!(length.to_px_scaled(1920, 1.5), 960);
+    assert_eq!(length.to_px_scaled(3840, 2.), 1920);
+  }
+}
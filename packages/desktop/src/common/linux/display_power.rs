@@ -0,0 +1,25 @@
+use std::fs;
+
+/// Directory of DRM connectors (i.e. display outputs) in sysfs.
+const DRM_DIR: &str = "/sys/class/drm";
+
+/// Whether any connected display is on, based on the DPMS state of DRM
+/// connectors. Assumed to be on if the state can't be read (e.g. with
+/// proprietary drivers).
+pub fn is_display_on() -> bool {
+  let Ok(entries) = fs::read_dir(DRM_DIR) else {
+    return true;
+  };
+
+  let dpms_states = entries
+    .flatten()
+    .filter(|entry| {
+      fs::read_to_string(entry.path().join("status"))
+        .is_ok_and(|status| status.trim() == "connected")
+    })
+    .filter_map(|entry| fs::read_to_string(entry.path().join("dpms")).ok())
+    .collect::<Vec<_>>();
+
+  dpms_states.is_empty()
+    || dpms_states.iter().any(|state| state.trim() == "On")
+}
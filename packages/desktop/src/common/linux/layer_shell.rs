@@ -0,0 +1,153 @@
+use gtk::{gdk, prelude::*};
+use gtk_layer_shell::{Edge, KeyboardMode, Layer, LayerShell};
+use tauri::{PhysicalPosition, PhysicalSize, Runtime, WebviewWindow};
+
+use crate::{
+  config::{DockEdge, WidgetLayer},
+  monitor_state::Monitor,
+};
+
+/// Options for showing a widget window as a layer-shell surface.
+#[derive(Clone, Debug)]
+pub struct LayerSurfaceOptions {
+  pub layer: WidgetLayer,
+  pub monitor: Monitor,
+  pub size: PhysicalSize<i32>,
+  pub position: PhysicalPosition<i32>,
+
+  /// Edge to dock to and the amount of space to reserve for the widget
+  /// (in physical pixels). Docked surfaces get an exclusive zone, so
+  /// that the compositor doesn't place other windows underneath them.
+  pub dock: Option<(DockEdge, i32)>,
+}
+
+/// Whether the compositor supports the wlr-layer-shell protocol (e.g.
+/// Sway and Hyprland). Always false on X11.
+pub fn is_layer_shell_supported() -> bool {
+  gtk_layer_shell::is_supported()
+}
+
+/// Turns the window into a layer-shell surface, which makes Wayland
+/// compositors treat it as part of the desktop shell rather than as a
+/// normal (tiled) window.
+pub fn init_layer_surface<R: Runtime>(
+  window: &WebviewWindow<R>,
+  options: LayerSurfaceOptions,
+) -> anyhow::Result<()> {
+  let window = window.clone();
+
+  // GTK calls need to be made from the main thread.
+  window.clone().run_on_main_thread(move || {
+    let Ok(gtk_window) = window.gtk_window() else {
+      tracing::error!("Failed to get GTK window for layer surface.");
+      return;
+    };
+
+    apply_layer_surface(&gtk_window, &options);
+  })?;
+
+  Ok(())
+}
+
+fn apply_layer_surface(
+  gtk_window: &gtk::ApplicationWindow,
+  options: &LayerSurfaceOptions,
+) {
+  let scale_factor = options.monitor.scale_factor as f64;
+  let to_logical = |px: i32| (px as f64 / scale_factor).round() as i32;
+
+  // Layer-shell has to be initialized before the window is realized,
+  // but Tauri realizes windows on creation.
+  gtk_window.hide();
+  gtk_window.unrealize();
+  gtk_window.init_layer_shell();
+  gtk_window.set_namespace("zebar");
+
+  gtk_window.set_layer(match options.layer {
+    WidgetLayer::Bottom => Layer::Bottom,
+    WidgetLayer::Normal => Layer::Top,
+    WidgetLayer::Top | WidgetLayer::Overlay => Layer::Overlay,
+  });
+
+  // Only take keyboard focus when clicked, like a normal window.
+  gtk_window.set_keyboard_mode(KeyboardMode::OnDemand);
+
+  if let Some(gdk_monitor) = find_gdk_monitor(&options.monitor) {
+    gtk_window.set_monitor(&gdk_monitor);
+  }
+
+  let monitor = &options.monitor;
+  let width = to_logical(options.size.width);
+  let height = to_logical(options.size.height);
+  let top = to_logical(options.position.y - monitor.y);
+  let left = to_logical(options.position.x - monitor.x);
+
+  gtk_window.set_size_request(width, height);
+
+  // Distance from each monitor edge to the window. The bottom and right
+  // margins are derived from the rounded logical values, so that the
+  // margins and size add up to the monitor size at fractional scales.
+  let margins = [
+    (Edge::Top, top),
+    (Edge::Left, left),
+    (
+      Edge::Bottom,
+      to_logical(monitor.height as i32) - top - height,
+    ),
+    (Edge::Right, to_logical(monitor.width as i32) - left - width),
+  ];
+
+  // Exclusive zones only apply when anchored to a single edge or to an
+  // edge and both of its perpendicular edges.
+  let anchors: &[Edge] = match options.dock {
+    Some((DockEdge::Top, _)) => &[Edge::Top, Edge::Left, Edge::Right],
+    Some((DockEdge::Bottom, _)) => {
+      &[Edge::Bottom, Edge::Left, Edge::Right]
+    }
+    Some((DockEdge::Left, _)) => &[Edge::Left, Edge::Top, Edge::Bottom],
+    Some((DockEdge::Right, _)) => &[Edge::Right, Edge::Top, Edge::Bottom],
+    None => &[Edge::Top, Edge::Left],
+  };
+
+  for (edge, margin) in margins {
+    if anchors.contains(&edge) {
+      gtk_window.set_anchor(edge, true);
+      gtk_window.set_layer_shell_margin(edge, margin);
+    }
+  }
+
+  if let Some((_, reserved_length)) = options.dock {
+    gtk_window.set_exclusive_zone(to_logical(reserved_length));
+  }
+
+  gtk_window.show();
+}
+
+/// Finds the GDK monitor matching the given monitor. Matches by name
+/// (i.e. the GDK monitor model) if available, otherwise by its logical
+/// position.
+fn find_gdk_monitor(monitor: &Monitor) -> Option<gdk::Monitor> {
+  let display = gdk::Display::default()?;
+  let scale_factor = monitor.scale_factor as f64;
+
+  let gdk_monitors = (0..display.n_monitors())
+    .filter_map(|index| display.monitor(index))
+    .collect::<Vec<_>>();
+
+  let by_name = monitor.name.as_ref().and_then(|name| {
+    gdk_monitors.iter().find(|gdk_monitor| {
+      gdk_monitor
+        .model()
+        .is_some_and(|model| model.as_str() == name)
+    })
+  });
+
+  by_name.cloned().or_else(|| {
+    gdk_monitors.into_iter().find(|gdk_monitor| {
+      let geometry = gdk_monitor.geometry();
+
+      geometry.x() == (monitor.x as f64 / scale_factor).round() as i32
+        && geometry.y() == (monitor.y as f64 / scale_factor).round() as i32
+    })
+  })
+}
@@ -0,0 +1,11 @@
+mod display_power;
+#[cfg(feature = "layer-shell")]
+mod layer_shell;
+mod tooltip;
+mod x11;
+
+pub use display_power::*;
+#[cfg(feature = "layer-shell")]
+pub use layer_shell::*;
+pub use tooltip::*;
+pub use x11::*;
@@ -0,0 +1,69 @@
+use std::{cell::RefCell, collections::HashMap};
+
+use gtk::{gdk::WindowTypeHint, prelude::*};
+use tauri::{LogicalPosition, Runtime, Window};
+
+thread_local! {
+  /// Open tooltips by the label of the window they belong to. GTK
+  /// windows can only be accessed from the main thread.
+  static TOOLTIPS: RefCell<HashMap<String, gtk::Window>> =
+    RefCell::new(HashMap::new());
+}
+
+/// Shows a tooltip at the given position (in logical pixels relative to
+/// the screen), replacing any open tooltip of the window.
+pub fn show_tooltip<R: Runtime>(
+  window: &Window<R>,
+  text: String,
+  position: LogicalPosition<i32>,
+) -> anyhow::Result<()> {
+  let label = window.label().to_string();
+  let owner = window.clone();
+
+  // GTK calls need to be made from the main thread.
+  window.run_on_main_thread(move || {
+    close_tooltip(&label);
+
+    let tooltip = gtk::Window::new(gtk::WindowType::Popup);
+
+    // Close the tooltip together with its widget.
+    if let Ok(owner) = owner.gtk_window() {
+      tooltip.set_transient_for(Some(&owner));
+      tooltip.set_destroy_with_parent(true);
+    }
+
+    tooltip.set_type_hint(WindowTypeHint::Tooltip);
+    tooltip.set_widget_name("tooltip");
+    tooltip.style_context().add_class("tooltip");
+
+    let text_label = gtk::Label::new(Some(&text));
+    text_label.set_line_wrap(true);
+    text_label.set_max_width_chars(60);
+
+    tooltip.set_border_width(6);
+    tooltip.add(&text_label);
+    tooltip.move_(position.x, position.y);
+    tooltip.show_all();
+
+    TOOLTIPS.with_borrow_mut(|tooltips| {
+      tooltips.insert(label, tooltip);
+    });
+  })?;
+
+  Ok(())
+}
+
+/// Hides the open tooltip of the window (if any).
+pub fn hide_tooltip<R: Runtime>(window: &Window<R>) -> anyhow::Result<()> {
+  let label = window.label().to_string();
+  window.run_on_main_thread(move || close_tooltip(&label))?;
+  Ok(())
+}
+
+fn close_tooltip(label: &str) {
+  if let Some(tooltip) =
+    TOOLTIPS.with_borrow_mut(|tooltips| tooltips.remove(label))
+  {
+    tooltip.close();
+  }
+}
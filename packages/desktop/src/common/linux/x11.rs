@@ -0,0 +1,57 @@
+use gtk::{gdk::WindowTypeHint, prelude::*};
+use tauri::{Runtime, WebviewWindow};
+
+use crate::config::{WidgetLayer, X11WindowType};
+
+/// Sets the `_NET_WM_WINDOW_TYPE` hint and related hints of the window,
+/// so that X11 window managers can treat it accordingly (e.g. keep
+/// docks out of alt-tab and above other windows).
+///
+/// Does nothing when not running under X11.
+pub fn set_x11_window_type<R: Runtime>(
+  window: &WebviewWindow<R>,
+  window_type: X11WindowType,
+  layer: WidgetLayer,
+) -> anyhow::Result<()> {
+  let window = window.clone();
+
+  // GTK calls need to be made from the main thread.
+  window.clone().run_on_main_thread(move || {
+    let Ok(gtk_window) = window.gtk_window() else {
+      tracing::error!("Failed to get GTK window for setting type hint.");
+      return;
+    };
+
+    if gtk_window.display().type_().name() != "GdkX11Display" {
+      return;
+    }
+
+    // Window managers only read the type hint when the window is
+    // mapped, so the window is re-mapped after changing it.
+    gtk_window.hide();
+    gtk_window.unrealize();
+
+    gtk_window.set_type_hint(match window_type {
+      X11WindowType::Dock => WindowTypeHint::Dock,
+      X11WindowType::Desktop => WindowTypeHint::Desktop,
+      X11WindowType::Toolbar => WindowTypeHint::Toolbar,
+      X11WindowType::Utility => WindowTypeHint::Utility,
+      X11WindowType::Notification => WindowTypeHint::Notification,
+    });
+
+    gtk_window.set_skip_taskbar_hint(true);
+    gtk_window.set_skip_pager_hint(true);
+
+    match layer {
+      WidgetLayer::Top | WidgetLayer::Overlay => {
+        gtk_window.set_keep_above(true)
+      }
+      WidgetLayer::Bottom => gtk_window.set_keep_below(true),
+      WidgetLayer::Normal => {}
+    }
+
+    gtk_window.show();
+  })?;
+
+  Ok(())
+}
@@ -7,16 +7,24 @@ use tauri::{Runtime, Window};
 
 pub trait WindowExtMacOs {
   fn set_above_menu_bar(&self) -> anyhow::Result<()>;
+
+  /// Sets the window level to the given number of levels above the menu
+  /// bar. Windows on higher levels are always shown above lower ones.
+  fn set_level_above_menu_bar(&self, offset: i64) -> anyhow::Result<()>;
 }
 
 impl<R: Runtime> WindowExtMacOs for Window<R> {
   fn set_above_menu_bar(&self) -> anyhow::Result<()> {
+    self.set_level_above_menu_bar(1)
+  }
+
+  fn set_level_above_menu_bar(&self, offset: i64) -> anyhow::Result<()> {
     let ns_win =
       self.ns_window().context("Failed to get window handle.")? as id;
 
     unsafe {
       ns_win.setLevel_(
-        ((NSMainMenuWindowLevel + 1) as u64)
+        ((NSMainMenuWindowLevel as i64 + offset) as u64)
           .try_into()
           .context("Failed to cast `NSMainMenuWindowLevel`.")?,
       );
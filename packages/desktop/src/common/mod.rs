@@ -1,15 +1,31 @@
+mod duration;
+mod edid;
+mod env_interpolation;
+mod expression;
 mod format_bytes;
 mod fs_util;
 mod interval;
 mod length_value;
+#[cfg(target_os = "linux")]
+pub mod linux;
 #[cfg(target_os = "macos")]
 pub mod macos;
+mod no_window_command;
+mod open_url;
 mod path_ext;
+mod system_wake;
 #[cfg(target_os = "windows")]
 pub mod windows;
 
+pub use duration::*;
+pub use edid::*;
+pub use env_interpolation::*;
+pub use expression::*;
 pub use format_bytes::*;
 pub use fs_util::*;
 pub use interval::*;
 pub use length_value::*;
+pub use no_window_command::*;
+pub use open_url::*;
 pub use path_ext::*;
+pub use system_wake::*;
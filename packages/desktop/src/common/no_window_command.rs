@@ -0,0 +1,21 @@
+use std::{ffi::OsStr, process::Command};
+
+/// Process creation flag that prevents a console window from opening.
+#[cfg(windows)]
+const CREATE_NO_WINDOW: u32 = 0x08000000;
+
+/// Creates a command that doesn't open a console window on Windows.
+///
+/// Can be converted to a `tokio::process::Command` via `From`.
+pub fn no_window_command(program: impl AsRef<OsStr>) -> Command {
+  #[allow(unused_mut)]
+  let mut command = Command::new(program);
+
+  #[cfg(windows)]
+  {
+    use std::os::windows::process::CommandExt;
+    command.creation_flags(CREATE_NO_WINDOW);
+  }
+
+  command
+}
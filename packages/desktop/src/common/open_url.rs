@@ -0,0 +1,37 @@
+#[cfg(not(windows))]
+use std::process::Command;
+
+use anyhow::Context;
+
+#[cfg(windows)]
+use super::no_window_command;
+
+/// Opens a URL in the default browser.
+pub fn open_url(url: &str) -> anyhow::Result<()> {
+  #[cfg(windows)]
+  let mut command = {
+    let mut command = no_window_command("rundll32");
+    command.args(["url.dll,FileProtocolHandler", url]);
+    command
+  };
+
+  #[cfg(target_os = "macos")]
+  let mut command = {
+    let mut command = Command::new("open");
+    command.arg(url);
+    command
+  };
+
+  #[cfg(target_os = "linux")]
+  let mut command = {
+    let mut command = Command::new("xdg-open");
+    command.arg(url);
+    command
+  };
+
+  command
+    .spawn()
+    .with_context(|| format!("Failed to open URL '{}'.", url))?;
+
+  Ok(())
+}
@@ -0,0 +1,119 @@
+use std::{
+  net::UdpSocket,
+  sync::{
+    atomic::{AtomicBool, Ordering},
+    LazyLock, Once,
+  },
+  time::Duration,
+};
+
+use tokio::{
+  sync::broadcast,
+  time::{self, Instant},
+};
+
+/// How long to wait for a network connection after waking from sleep.
+const NETWORK_WAIT_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// How often to check for a network connection after waking from sleep.
+const NETWORK_POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+/// How often the wall clock is checked for jumps, on platforms without
+/// resume notifications.
+#[cfg(not(target_os = "windows"))]
+const CLOCK_CHECK_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Min jump of the wall clock (beyond the check interval) that's
+/// treated as the system having been asleep.
+#[cfg(not(target_os = "windows"))]
+const SLEEP_THRESHOLD: Duration = Duration::from_secs(30);
+
+static WAKE_TX: LazyLock<broadcast::Sender<()>> =
+  LazyLock::new(|| broadcast::channel(1).0);
+
+/// Whether the system is going to sleep. Only reported on Windows.
+static IS_ASLEEP: AtomicBool = AtomicBool::new(false);
+
+static LISTENER: Once = Once::new();
+
+/// Returns a receiver that gets a message whenever the system wakes
+/// from sleep. Starts listening for wake events on first call.
+pub fn on_wake() -> broadcast::Receiver<()> {
+  LISTENER.call_once(|| {
+    #[cfg(target_os = "windows")]
+    super::windows::listen_power_events();
+
+    #[cfg(not(target_os = "windows"))]
+    spawn_clock_watcher();
+  });
+
+  WAKE_TX.subscribe()
+}
+
+/// Whether the system is going to sleep (or hasn't finished waking).
+pub fn is_asleep() -> bool {
+  IS_ASLEEP.load(Ordering::Relaxed)
+}
+
+/// Marks the system as going to sleep.
+#[cfg(target_os = "windows")]
+pub fn notify_sleep() {
+  tracing::info!("System is going to sleep.");
+  IS_ASLEEP.store(true, Ordering::Relaxed);
+}
+
+/// Notifies all receivers that the system woke from sleep.
+pub fn notify_wake() {
+  tracing::info!("System woke from sleep.");
+  IS_ASLEEP.store(false, Ordering::Relaxed);
+  let _ = WAKE_TX.send(());
+}
+
+/// Waits until a network is connected, or until the timeout passes.
+///
+/// Network adapters typically take a few seconds to reconnect after
+/// waking from sleep, and requests in the meantime would fail.
+pub async fn wait_for_network() {
+  let deadline = Instant::now() + NETWORK_WAIT_TIMEOUT;
+
+  while !has_network_route() && Instant::now() < deadline {
+    time::sleep(NETWORK_POLL_INTERVAL).await;
+  }
+}
+
+/// Whether there's a route to the internet over IPv4 or IPv6.
+///
+/// Connecting a UDP socket only looks up the route, and doesn't send
+/// any packets.
+fn has_network_route() -> bool {
+  let has_route = |local_addr: &str, remote_addr: &str| {
+    UdpSocket::bind(local_addr)
+      .and_then(|socket| socket.connect(remote_addr))
+      .is_ok()
+  };
+
+  has_route("0.0.0.0:0", "1.1.1.1:53")
+    || has_route("[::]:0", "[2606:4700:4700::1111]:53")
+}
+
+/// Detects waking from sleep via jumps in the wall clock, since threads
+/// don't run while the system is asleep.
+#[cfg(not(target_os = "windows"))]
+fn spawn_clock_watcher() {
+  std::thread::spawn(|| {
+    let mut last_check = std::time::SystemTime::now();
+
+    loop {
+      std::thread::sleep(CLOCK_CHECK_INTERVAL);
+
+      let now = std::time::SystemTime::now();
+      let elapsed = now.duration_since(last_check).unwrap_or_default();
+
+      if elapsed > CLOCK_CHECK_INTERVAL + SLEEP_THRESHOLD {
+        notify_wake();
+      }
+
+      last_check = now;
+    }
+  });
+}
@@ -0,0 +1,139 @@
+use windows::{
+  core::{GUID, PCWSTR},
+  Win32::{
+    Devices::{
+      DeviceAndDriverInstallation::{
+        SetupDiDestroyDeviceInfoList, SetupDiEnumDeviceInfo,
+        SetupDiGetClassDevsW, SetupDiGetDevicePropertyW, HDEVINFO,
+        SETUP_DI_GET_CLASS_DEVS_FLAGS, SP_DEVINFO_DATA,
+      },
+      Properties::{DEVPROPKEY, DEVPROPTYPE},
+    },
+    Foundation::HWND,
+  },
+};
+
+/// Set of devices from the SetupAPI that is destroyed on drop.
+pub struct DeviceInfoSet(pub HDEVINFO);
+
+impl DeviceInfoSet {
+  /// Gets the devices matching the given setup class or interface class,
+  /// and/or enumerator (e.g. `BTHLE`).
+  pub fn new(
+    class_guid: Option<&GUID>,
+    enumerator: PCWSTR,
+    flags: SETUP_DI_GET_CLASS_DEVS_FLAGS,
+  ) -> anyhow::Result<Self> {
+    let handle = unsafe {
+      SetupDiGetClassDevsW(
+        class_guid.map(|guid| guid as *const _),
+        enumerator,
+        HWND::default(),
+        flags,
+      )
+    }?;
+
+    Ok(Self(handle))
+  }
+
+  /// Returns all devices in the set.
+  pub fn devices(&self) -> Vec<SP_DEVINFO_DATA> {
+    let mut devices = Vec::new();
+
+    for index in 0.. {
+      let mut device = SP_DEVINFO_DATA {
+        cbSize: std::mem::size_of::<SP_DEVINFO_DATA>() as u32,
+        ..Default::default()
+      };
+
+      // Fails once there are no more devices.
+      if unsafe { SetupDiEnumDeviceInfo(self.0, index, &mut device) }
+        .is_err()
+      {
+        break;
+      }
+
+      devices.push(device);
+    }
+
+    devices
+  }
+
+  /// Reads the raw value of a device property.
+  pub fn property(
+    &self,
+    device: &SP_DEVINFO_DATA,
+    key: &DEVPROPKEY,
+  ) -> Option<Vec<u8>> {
+    let mut property_type = DEVPROPTYPE::default();
+    let mut size = 0;
+
+    // Get the required buffer size first. This call is expected to fail
+    // with `ERROR_INSUFFICIENT_BUFFER`.
+    let _ = unsafe {
+      SetupDiGetDevicePropertyW(
+        self.0,
+        device,
+        key,
+        &mut property_type,
+        None,
+        Some(&mut size),
+        0,
+      )
+    };
+
+    if size == 0 {
+      return None;
+    }
+
+    let mut buffer = vec![0u8; size as usize];
+
+    unsafe {
+      SetupDiGetDevicePropertyW(
+        self.0,
+        device,
+        key,
+        &mut property_type,
+        Some(&mut buffer),
+        None,
+        0,
+      )
+    }
+    .ok()?;
+
+    Some(buffer)
+  }
+
+  /// Reads a string device property (e.g. the friendly name).
+  pub fn string_property(
+    &self,
+    device: &SP_DEVINFO_DATA,
+    key: &DEVPROPKEY,
+  ) -> Option<String> {
+    let bytes = self.property(device, key)?;
+
+    let wide = bytes
+      .chunks_exact(2)
+      .map(|pair| u16::from_le_bytes([pair[0], pair[1]]))
+      .take_while(|char| *char != 0)
+      .collect::<Vec<_>>();
+
+    Some(String::from_utf16_lossy(&wide)).filter(|value| !value.is_empty())
+  }
+
+  /// Reads a `u32` device property.
+  pub fn u32_property(
+    &self,
+    device: &SP_DEVINFO_DATA,
+    key: &DEVPROPKEY,
+  ) -> Option<u32> {
+    let bytes = self.property(device, key)?;
+    Some(u32::from_le_bytes(bytes.get(..4)?.try_into().ok()?))
+  }
+}
+
+impl Drop for DeviceInfoSet {
+  fn drop(&mut self) {
+    let _ = unsafe { SetupDiDestroyDeviceInfoList(self.0) };
+  }
+}
@@ -0,0 +1,138 @@
+use std::{
+  sync::{
+    atomic::{AtomicBool, Ordering},
+    Once,
+  },
+  thread,
+};
+
+use windows::{
+  core::{w, PCWSTR},
+  Win32::{
+    Foundation::{HANDLE, HWND, LPARAM, LRESULT, WPARAM},
+    System::{
+      Power::{
+        RegisterPowerSettingNotification,
+        RegisterSuspendResumeNotification, POWERBROADCAST_SETTING,
+      },
+      SystemServices::GUID_CONSOLE_DISPLAY_STATE,
+    },
+    UI::WindowsAndMessaging::{
+      CreateWindowExW, DefWindowProcW, DispatchMessageW, GetMessageW,
+      RegisterClassW, DEVICE_NOTIFY_WINDOW_HANDLE, HWND_MESSAGE, MSG,
+      PBT_APMRESUMEAUTOMATIC, PBT_APMSUSPEND, PBT_POWERSETTINGCHANGE,
+      WINDOW_EX_STYLE, WINDOW_STYLE, WM_POWERBROADCAST, WNDCLASSW,
+    },
+  },
+};
+
+use crate::common::{notify_sleep, notify_wake};
+
+/// Whether the display is on, as last reported by the OS.
+static IS_DISPLAY_ON: AtomicBool = AtomicBool::new(true);
+
+static LISTENER: Once = Once::new();
+
+/// Whether the display is on (or dimmed). Starts listening for power
+/// events on first call.
+pub fn is_display_on() -> bool {
+  listen_power_events();
+  IS_DISPLAY_ON.load(Ordering::Relaxed)
+}
+
+/// Starts listening for display power changes and for the system waking
+/// from sleep. No-op if already listening.
+pub fn listen_power_events() {
+  LISTENER.call_once(spawn_listener);
+}
+
+/// Listens for power events on a new thread with a message loop, via a
+/// message-only window.
+fn spawn_listener() {
+  thread::spawn(|| unsafe {
+    let class_name = w!("ZebarPowerEvents");
+
+    let class = WNDCLASSW {
+      lpfnWndProc: Some(window_proc),
+      lpszClassName: class_name,
+      ..Default::default()
+    };
+
+    RegisterClassW(&class);
+
+    let Ok(handle) = CreateWindowExW(
+      WINDOW_EX_STYLE::default(),
+      class_name,
+      PCWSTR::null(),
+      WINDOW_STYLE::default(),
+      0,
+      0,
+      0,
+      0,
+      HWND_MESSAGE,
+      None,
+      None,
+      None,
+    ) else {
+      tracing::warn!("Failed to create window for power events.");
+      return;
+    };
+
+    if let Err(err) = RegisterSuspendResumeNotification(
+      HANDLE(handle.0),
+      DEVICE_NOTIFY_WINDOW_HANDLE,
+    ) {
+      tracing::warn!("Failed to register for resume events: {:?}", err);
+    }
+
+    // The current state is sent right after registering.
+    if let Err(err) = RegisterPowerSettingNotification(
+      HANDLE(handle.0),
+      &GUID_CONSOLE_DISPLAY_STATE,
+      DEVICE_NOTIFY_WINDOW_HANDLE,
+    ) {
+      tracing::warn!(
+        "Failed to register for display power events: {:?}",
+        err
+      );
+      return;
+    }
+
+    let mut msg = MSG::default();
+    while GetMessageW(&mut msg, HWND::default(), 0, 0).as_bool() {
+      DispatchMessageW(&msg);
+    }
+  });
+}
+
+unsafe extern "system" fn window_proc(
+  handle: HWND,
+  msg: u32,
+  wparam: WPARAM,
+  lparam: LPARAM,
+) -> LRESULT {
+  if msg == WM_POWERBROADCAST && wparam.0 as u32 == PBT_APMSUSPEND {
+    notify_sleep();
+    return LRESULT(1);
+  }
+
+  if msg == WM_POWERBROADCAST && wparam.0 as u32 == PBT_APMRESUMEAUTOMATIC
+  {
+    notify_wake();
+    return LRESULT(1);
+  }
+
+  if msg == WM_POWERBROADCAST && wparam.0 as u32 == PBT_POWERSETTINGCHANGE
+  {
+    let setting = &*(lparam.0 as *const POWERBROADCAST_SETTING);
+
+    // Display state is 0 when off, 1 when on, and 2 when dimmed.
+    if setting.PowerSetting == GUID_CONSOLE_DISPLAY_STATE {
+      IS_DISPLAY_ON.store(setting.Data[0] != 0, Ordering::Relaxed);
+    }
+
+    return LRESULT(1);
+  }
+
+  DefWindowProcW(handle, msg, wparam, lparam)
+}
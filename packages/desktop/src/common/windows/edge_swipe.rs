@@ -0,0 +1,189 @@
+use std::{cell::RefCell, sync::mpsc, thread};
+
+use anyhow::Context;
+use tauri::PhysicalPosition;
+use windows::Win32::{
+  Foundation::{HINSTANCE, HWND, LPARAM, LRESULT, POINT, RECT, WPARAM},
+  Graphics::Gdi::{
+    GetMonitorInfoW, MonitorFromPoint, MONITORINFO, MONITOR_DEFAULTTONULL,
+  },
+  UI::WindowsAndMessaging::{
+    CallNextHookEx, DispatchMessageW, GetMessageW, SetWindowsHookExW, MSG,
+    MSLLHOOKSTRUCT, WH_MOUSE_LL, WM_LBUTTONDOWN, WM_LBUTTONUP,
+    WM_MOUSEMOVE,
+  },
+};
+
+use crate::config::DockEdge;
+
+/// Signature in the extra info of mouse events that were promoted from
+/// touch or pen input.
+const TOUCH_SIGNATURE: usize = 0xFF515700;
+const TOUCH_SIGNATURE_MASK: usize = 0xFFFFFF00;
+
+/// Max distance from a monitor edge (in physical pixels) for a touch to
+/// start an edge swipe.
+const EDGE_THRESHOLD: i32 = 24;
+
+/// Distance (in physical pixels) a touch needs to move away from the
+/// edge for it to count as a swipe.
+const SWIPE_DISTANCE: i32 = 60;
+
+/// Callback for detected swipes, with the swiped edge and where the
+/// swipe started (in physical pixels).
+type SwipeCallback = Box<dyn Fn(DockEdge, PhysicalPosition<i32>) + Send>;
+
+/// Touch that started at a monitor edge and hasn't been released yet.
+struct PendingSwipe {
+  edge: DockEdge,
+  start: POINT,
+}
+
+thread_local! {
+  /// Callback for detected swipes. Only set on the hook thread.
+  static ON_SWIPE: RefCell<Option<SwipeCallback>> =
+    const { RefCell::new(None) };
+
+  static PENDING_SWIPE: RefCell<Option<PendingSwipe>> =
+    const { RefCell::new(None) };
+}
+
+/// Listens for touch swipes in from the edge of any monitor.
+///
+/// Installs a low-level mouse hook on a new thread, which runs for the
+/// rest of the app's lifetime. Only touch and pen input is inspected.
+pub fn listen_edge_swipes(
+  on_swipe: impl Fn(DockEdge, PhysicalPosition<i32>) + Send + 'static,
+) -> anyhow::Result<()> {
+  let (init_tx, init_rx) = mpsc::channel();
+
+  thread::spawn(move || {
+    ON_SWIPE.with_borrow_mut(|callback| {
+      *callback = Some(Box::new(on_swipe));
+    });
+
+    let hook = unsafe {
+      SetWindowsHookExW(
+        WH_MOUSE_LL,
+        Some(mouse_proc),
+        HINSTANCE::default(),
+        0,
+      )
+    };
+
+    if let Err(err) = hook {
+      let _ = init_tx.send(Err(err));
+      return;
+    }
+
+    let _ = init_tx.send(Ok(()));
+
+    let mut msg = MSG::default();
+    while unsafe { GetMessageW(&mut msg, HWND::default(), 0, 0) }.as_bool()
+    {
+      unsafe { DispatchMessageW(&msg) };
+    }
+  });
+
+  init_rx.recv()?.context("Failed to install touch hook.")
+}
+
+unsafe extern "system" fn mouse_proc(
+  code: i32,
+  wparam: WPARAM,
+  lparam: LPARAM,
+) -> LRESULT {
+  if code >= 0 {
+    let event = &*(lparam.0 as *const MSLLHOOKSTRUCT);
+    let is_touch =
+      event.dwExtraInfo & TOUCH_SIGNATURE_MASK == TOUCH_SIGNATURE;
+
+    if is_touch {
+      handle_touch(wparam.0 as u32, event.pt);
+    }
+  }
+
+  CallNextHookEx(None, code, wparam, lparam)
+}
+
+fn handle_touch(msg: u32, point: POINT) {
+  match msg {
+    WM_LBUTTONDOWN => {
+      let pending_swipe = nearest_edge(point)
+        .map(|edge| PendingSwipe { edge, start: point });
+
+      PENDING_SWIPE.with_borrow_mut(|pending| *pending = pending_swipe);
+    }
+    WM_MOUSEMOVE => {
+      let swipe = PENDING_SWIPE.with_borrow_mut(|pending| {
+        let distance = pending.as_ref().map(|swipe| {
+          let (dx, dy) =
+            (point.x - swipe.start.x, point.y - swipe.start.y);
+
+          match swipe.edge {
+            DockEdge::Top => dy,
+            DockEdge::Bottom => -dy,
+            DockEdge::Left => dx,
+            DockEdge::Right => -dx,
+          }
+        })?;
+
+        (distance >= SWIPE_DISTANCE)
+          .then(|| pending.take())
+          .flatten()
+      });
+
+      if let Some(swipe) = swipe {
+        ON_SWIPE.with_borrow(|callback| {
+          if let Some(callback) = callback {
+            callback(
+              swipe.edge,
+              PhysicalPosition::new(swipe.start.x, swipe.start.y),
+            );
+          }
+        });
+      }
+    }
+    WM_LBUTTONUP => {
+      PENDING_SWIPE.with_borrow_mut(|pending| *pending = None);
+    }
+    _ => {}
+  }
+}
+
+/// Gets the edge of the monitor that the point is within
+/// `EDGE_THRESHOLD` of.
+fn nearest_edge(point: POINT) -> Option<DockEdge> {
+  let monitor = unsafe { MonitorFromPoint(point, MONITOR_DEFAULTTONULL) };
+
+  if monitor.is_invalid() {
+    return None;
+  }
+
+  let mut monitor_info = MONITORINFO {
+    cbSize: std::mem::size_of::<MONITORINFO>() as u32,
+    ..Default::default()
+  };
+
+  if !unsafe { GetMonitorInfoW(monitor, &mut monitor_info) }.as_bool() {
+    return None;
+  }
+
+  let RECT {
+    left,
+    top,
+    right,
+    bottom,
+  } = monitor_info.rcMonitor;
+
+  [
+    (DockEdge::Top, point.y - top),
+    (DockEdge::Bottom, bottom - 1 - point.y),
+    (DockEdge::Left, point.x - left),
+    (DockEdge::Right, right - 1 - point.x),
+  ]
+  .into_iter()
+  .filter(|(_, distance)| *distance <= EDGE_THRESHOLD)
+  .min_by_key(|(_, distance)| *distance)
+  .map(|(edge, _)| edge)
+}
@@ -0,0 +1,160 @@
+use std::io::Cursor;
+
+use anyhow::{bail, Context};
+use windows::{
+  core::{HSTRING, PWSTR},
+  Win32::{
+    Foundation::{CloseHandle, HWND},
+    Graphics::Gdi::{
+      DeleteObject, GetDC, GetDIBits, GetObjectW, ReleaseDC, BITMAP,
+      BITMAPINFO, BITMAPINFOHEADER, BI_RGB, DIB_RGB_COLORS,
+    },
+    System::Threading::{
+      OpenProcess, QueryFullProcessImageNameW, PROCESS_NAME_WIN32,
+      PROCESS_QUERY_LIMITED_INFORMATION,
+    },
+    UI::{
+      Shell::ExtractIconExW,
+      WindowsAndMessaging::{DestroyIcon, GetIconInfo, HICON, ICONINFO},
+    },
+  },
+};
+
+/// Gets the executable path of a process (e.g.
+/// `C:\Program Files\Mozilla Firefox\firefox.exe`).
+pub fn process_exe_path(process_id: u32) -> anyhow::Result<String> {
+  let handle = unsafe {
+    OpenProcess(PROCESS_QUERY_LIMITED_INFORMATION, false, process_id)
+  }
+  .context("Failed to open process.")?;
+
+  let mut buffer = [0u16; 1024];
+  let mut length = buffer.len() as u32;
+
+  let res = unsafe {
+    QueryFullProcessImageNameW(
+      handle,
+      PROCESS_NAME_WIN32,
+      PWSTR(buffer.as_mut_ptr()),
+      &mut length,
+    )
+  };
+
+  let _ = unsafe { CloseHandle(handle) };
+  res.context("Failed to get process executable path.")?;
+
+  Ok(String::from_utf16_lossy(&buffer[..length as usize]))
+}
+
+/// Extracts the large icon of an executable and encodes it as a PNG.
+pub fn exe_icon_png(exe_path: &str) -> anyhow::Result<Vec<u8>> {
+  let mut icon = HICON::default();
+
+  let icon_count = unsafe {
+    ExtractIconExW(&HSTRING::from(exe_path), 0, Some(&mut icon), None, 1)
+  };
+
+  if icon_count == 0 || icon.is_invalid() {
+    bail!("Executable has no icon.");
+  }
+
+  let res = icon_to_png(icon);
+  let _ = unsafe { DestroyIcon(icon) };
+  res
+}
+
+fn icon_to_png(icon: HICON) -> anyhow::Result<Vec<u8>> {
+  let mut icon_info = ICONINFO::default();
+  unsafe { GetIconInfo(icon, &mut icon_info) }
+    .context("Failed to get icon info.")?;
+
+  let res = bitmap_to_png(&icon_info);
+
+  unsafe {
+    let _ = DeleteObject(icon_info.hbmColor);
+    let _ = DeleteObject(icon_info.hbmMask);
+  }
+
+  res
+}
+
+fn bitmap_to_png(icon_info: &ICONINFO) -> anyhow::Result<Vec<u8>> {
+  if icon_info.hbmColor.is_invalid() {
+    bail!("Monochrome icons are not supported.");
+  }
+
+  let mut bitmap = BITMAP::default();
+
+  let res = unsafe {
+    GetObjectW(
+      icon_info.hbmColor,
+      std::mem::size_of::<BITMAP>() as i32,
+      Some(&mut bitmap as *mut _ as _),
+    )
+  };
+
+  if res == 0 {
+    bail!("Failed to get icon bitmap.");
+  }
+
+  let width = bitmap.bmWidth as u32;
+  let height = bitmap.bmHeight as u32;
+
+  let mut bitmap_info = BITMAPINFO {
+    bmiHeader: BITMAPINFOHEADER {
+      biSize: std::mem::size_of::<BITMAPINFOHEADER>() as u32,
+      biWidth: width as i32,
+      // Negative height gives a top-down bitmap.
+      biHeight: -(height as i32),
+      biPlanes: 1,
+      biBitCount: 32,
+      biCompression: BI_RGB.0,
+      ..Default::default()
+    },
+    ..Default::default()
+  };
+
+  let mut pixels = vec![0u8; (width * height * 4) as usize];
+
+  let hdc = unsafe { GetDC(HWND::default()) };
+
+  let lines = unsafe {
+    GetDIBits(
+      hdc,
+      icon_info.hbmColor,
+      0,
+      height,
+      Some(pixels.as_mut_ptr() as _),
+      &mut bitmap_info,
+      DIB_RGB_COLORS,
+    )
+  };
+
+  unsafe { ReleaseDC(HWND::default(), hdc) };
+
+  if lines == 0 {
+    bail!("Failed to read icon pixels.");
+  }
+
+  // Convert from BGRA to RGBA. Icons without an alpha channel have all
+  // alpha values set to 0, in which case they're made opaque.
+  let has_alpha = pixels.chunks_exact(4).any(|pixel| pixel[3] != 0);
+
+  for pixel in pixels.chunks_exact_mut(4) {
+    pixel.swap(0, 2);
+
+    if !has_alpha {
+      pixel[3] = 255;
+    }
+  }
+
+  let image = image::RgbaImage::from_raw(width, height, pixels)
+    .context("Invalid icon dimensions.")?;
+
+  let mut png = Vec::new();
+  image
+    .write_to(&mut Cursor::new(&mut png), image::ImageFormat::Png)
+    .context("Failed to encode icon as PNG.")?;
+
+  Ok(png)
+}
@@ -0,0 +1,68 @@
+use windows::Win32::{
+  Foundation::RECT,
+  Graphics::Gdi::{
+    GetMonitorInfoW, MonitorFromWindow, MONITORINFO,
+    MONITOR_DEFAULTTONEAREST,
+  },
+  UI::WindowsAndMessaging::{
+    GetForegroundWindow, GetShellWindow, GetWindowRect,
+    GetWindowThreadProcessId,
+  },
+};
+
+/// The window that currently has focus.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ForegroundWindow {
+  /// Handle of the window.
+  pub handle: isize,
+
+  /// ID of the process that owns the window.
+  pub process_id: u32,
+
+  /// Whether the window covers its entire monitor.
+  pub is_fullscreen: bool,
+}
+
+/// Gets the foreground window.
+///
+/// Windows belonging to Zebar itself and the desktop are ignored.
+pub fn foreground_window() -> Option<ForegroundWindow> {
+  unsafe {
+    let window = GetForegroundWindow();
+
+    if window.is_invalid() || window == GetShellWindow() {
+      return None;
+    }
+
+    let mut process_id = 0;
+    GetWindowThreadProcessId(window, Some(&mut process_id));
+
+    if process_id == std::process::id() {
+      return None;
+    }
+
+    let mut window_rect = RECT::default();
+    GetWindowRect(window, &mut window_rect).ok()?;
+
+    let monitor = MonitorFromWindow(window, MONITOR_DEFAULTTONEAREST);
+    let mut monitor_info = MONITORINFO {
+      cbSize: std::mem::size_of::<MONITORINFO>() as u32,
+      ..Default::default()
+    };
+
+    let monitor_rect =
+      match GetMonitorInfoW(monitor, &mut monitor_info).as_bool() {
+        true => monitor_info.rcMonitor,
+        false => return None,
+      };
+
+    Some(ForegroundWindow {
+      handle: window.0 as isize,
+      process_id,
+      is_fullscreen: window_rect.left <= monitor_rect.left
+        && window_rect.top <= monitor_rect.top
+        && window_rect.right >= monitor_rect.right
+        && window_rect.bottom >= monitor_rect.bottom,
+    })
+  }
+}
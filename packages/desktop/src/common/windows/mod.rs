@@ -1,7 +1,21 @@
 mod app_bar;
 mod com;
+mod device_info_set;
+mod display_power;
+mod edge_swipe;
+mod exe_icon;
+mod foreground_window;
+mod reg_key;
+mod tooltip;
 mod window_ext_windows;
 
 pub use app_bar::*;
 pub use com::*;
+pub use device_info_set::*;
+pub use display_power::*;
+pub use edge_swipe::*;
+pub use exe_icon::*;
+pub use foreground_window::*;
+pub use reg_key::*;
+pub use tooltip::*;
 pub use window_ext_windows::*;
@@ -0,0 +1,125 @@
+use windows::{
+  core::{HSTRING, PWSTR},
+  Win32::{
+    Foundation::ERROR_SUCCESS,
+    System::Registry::{
+      RegCloseKey, RegEnumKeyExW, RegOpenKeyExW, RegQueryValueExW, HKEY,
+      KEY_READ,
+    },
+  },
+};
+
+/// Registry key that is closed on drop.
+pub struct RegKey(pub HKEY);
+
+impl RegKey {
+  pub fn open(parent: HKEY, path: &str) -> Option<Self> {
+    let mut key = HKEY::default();
+
+    let res = unsafe {
+      RegOpenKeyExW(parent, &HSTRING::from(path), 0, KEY_READ, &mut key)
+    };
+
+    (res == ERROR_SUCCESS).then_some(Self(key))
+  }
+
+  /// Returns the names of all subkeys.
+  pub fn subkeys(&self) -> Vec<String> {
+    let mut names = Vec::new();
+
+    for index in 0.. {
+      let mut buffer = [0u16; 512];
+      let mut length = buffer.len() as u32;
+
+      let res = unsafe {
+        RegEnumKeyExW(
+          self.0,
+          index,
+          PWSTR(buffer.as_mut_ptr()),
+          &mut length,
+          None,
+          PWSTR::null(),
+          None,
+          None,
+        )
+      };
+
+      if res != ERROR_SUCCESS {
+        break;
+      }
+
+      names.push(String::from_utf16_lossy(&buffer[..length as usize]));
+    }
+
+    names
+  }
+
+  /// Reads a `REG_QWORD` value.
+  pub fn qword_value(&self, name: &str) -> Option<u64> {
+    let mut value = 0u64;
+    let mut size = std::mem::size_of::<u64>() as u32;
+
+    let res = unsafe {
+      RegQueryValueExW(
+        self.0,
+        &HSTRING::from(name),
+        None,
+        None,
+        Some(&mut value as *mut u64 as *mut u8),
+        Some(&mut size),
+      )
+    };
+
+    (res == ERROR_SUCCESS).then_some(value)
+  }
+
+  /// Reads a value as raw bytes (e.g. a `REG_BINARY` value).
+  pub fn binary_value(&self, name: &str) -> Option<Vec<u8>> {
+    let name = HSTRING::from(name);
+    let mut size = 0u32;
+
+    // Get the size of the value before reading it.
+    let res = unsafe {
+      RegQueryValueExW(self.0, &name, None, None, None, Some(&mut size))
+    };
+
+    if res != ERROR_SUCCESS {
+      return None;
+    }
+
+    let mut buffer = vec![0u8; size as usize];
+
+    let res = unsafe {
+      RegQueryValueExW(
+        self.0,
+        &name,
+        None,
+        None,
+        Some(buffer.as_mut_ptr()),
+        Some(&mut size),
+      )
+    };
+
+    buffer.truncate(size as usize);
+    (res == ERROR_SUCCESS).then_some(buffer)
+  }
+
+  /// Reads a `REG_SZ` value.
+  pub fn string_value(&self, name: &str) -> Option<String> {
+    let bytes = self.binary_value(name)?;
+
+    let wide = bytes
+      .chunks_exact(2)
+      .map(|chunk| u16::from_le_bytes([chunk[0], chunk[1]]))
+      .take_while(|&char| char != 0)
+      .collect::<Vec<_>>();
+
+    Some(String::from_utf16_lossy(&wide))
+  }
+}
+
+impl Drop for RegKey {
+  fn drop(&mut self) {
+    let _ = unsafe { RegCloseKey(self.0) };
+  }
+}
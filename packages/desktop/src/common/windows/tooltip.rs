@@ -0,0 +1,127 @@
+use std::{cell::RefCell, collections::HashMap};
+
+use tauri::{PhysicalPosition, Runtime, Window};
+use windows::{
+  core::{HSTRING, PCWSTR, PWSTR},
+  Win32::{
+    Foundation::{HWND, LPARAM, WPARAM},
+    UI::{
+      Controls::{
+        TOOLTIPS_CLASSW, TTF_ABSOLUTE, TTF_TRACK, TTM_ADDTOOLW,
+        TTM_SETMAXTIPWIDTH, TTM_TRACKACTIVATE, TTM_TRACKPOSITION,
+        TTS_ALWAYSTIP, TTS_NOPREFIX, TTTOOLINFOW,
+      },
+      WindowsAndMessaging::{
+        CreateWindowExW, DestroyWindow, SendMessageW, CW_USEDEFAULT,
+        WINDOW_STYLE, WS_EX_TOPMOST, WS_POPUP,
+      },
+    },
+  },
+};
+
+/// Max width of a tooltip in pixels before its text wraps.
+const MAX_TOOLTIP_WIDTH: isize = 400;
+
+thread_local! {
+  /// Handles of open tooltips by the label of the window they belong
+  /// to. Tooltips are only accessed from the main thread.
+  static TOOLTIPS: RefCell<HashMap<String, isize>> =
+    RefCell::new(HashMap::new());
+}
+
+/// Shows a tooltip at the given position (in physical pixels relative to
+/// the screen), replacing any open tooltip of the window.
+pub fn show_tooltip<R: Runtime>(
+  window: &Window<R>,
+  text: String,
+  position: PhysicalPosition<i32>,
+) -> anyhow::Result<()> {
+  let owner = window.hwnd()?.0 as isize;
+  let label = window.label().to_string();
+
+  // Win32 windows need to be created from the main thread.
+  window.run_on_main_thread(move || {
+    close_tooltip(&label);
+
+    match create_tooltip(owner, &text, position) {
+      Ok(handle) => TOOLTIPS.with_borrow_mut(|tooltips| {
+        tooltips.insert(label, handle);
+      }),
+      Err(err) => tracing::warn!("Failed to create tooltip: {:?}", err),
+    }
+  })?;
+
+  Ok(())
+}
+
+/// Hides the open tooltip of the window (if any).
+pub fn hide_tooltip<R: Runtime>(window: &Window<R>) -> anyhow::Result<()> {
+  let label = window.label().to_string();
+  window.run_on_main_thread(move || close_tooltip(&label))?;
+  Ok(())
+}
+
+fn close_tooltip(label: &str) {
+  if let Some(handle) =
+    TOOLTIPS.with_borrow_mut(|tooltips| tooltips.remove(label))
+  {
+    let _ = unsafe { DestroyWindow(HWND(handle as _)) };
+  }
+}
+
+/// Creates a tracking tooltip, which is shown at a fixed position
+/// rather than following the cursor.
+fn create_tooltip(
+  owner: isize,
+  text: &str,
+  position: PhysicalPosition<i32>,
+) -> anyhow::Result<isize> {
+  let handle = unsafe {
+    CreateWindowExW(
+      WS_EX_TOPMOST,
+      TOOLTIPS_CLASSW,
+      PCWSTR::null(),
+      WS_POPUP | WINDOW_STYLE(TTS_NOPREFIX | TTS_ALWAYSTIP),
+      CW_USEDEFAULT,
+      CW_USEDEFAULT,
+      CW_USEDEFAULT,
+      CW_USEDEFAULT,
+      HWND(owner as _),
+      None,
+      None,
+      None,
+    )?
+  };
+
+  // The text is copied by the tooltip on `TTM_ADDTOOLW`.
+  let text = HSTRING::from(text);
+
+  let tool_info = TTTOOLINFOW {
+    cbSize: std::mem::size_of::<TTTOOLINFOW>() as u32,
+    uFlags: TTF_TRACK | TTF_ABSOLUTE,
+    hwnd: HWND(owner as _),
+    lpszText: PWSTR(text.as_ptr() as _),
+    ..Default::default()
+  };
+
+  let tool_info_ptr = LPARAM(&tool_info as *const _ as isize);
+
+  // Position is packed into an `LPARAM` as two 16-bit coordinates.
+  let packed_position = LPARAM(
+    ((position.y as u16 as isize) << 16) | position.x as u16 as isize,
+  );
+
+  unsafe {
+    SendMessageW(handle, TTM_ADDTOOLW, WPARAM(0), tool_info_ptr);
+    SendMessageW(
+      handle,
+      TTM_SETMAXTIPWIDTH,
+      WPARAM(0),
+      LPARAM(MAX_TOOLTIP_WIDTH),
+    );
+    SendMessageW(handle, TTM_TRACKPOSITION, WPARAM(0), packed_position);
+    SendMessageW(handle, TTM_TRACKACTIVATE, WPARAM(1), tool_info_ptr);
+  }
+
+  Ok(handle.0 as isize)
+}
@@ -1,11 +1,13 @@
 use anyhow::Context;
 use tauri::{PhysicalPosition, PhysicalSize, Runtime, Window};
 use windows::Win32::UI::WindowsAndMessaging::{
-  SetWindowLongPtrW, GWL_EXSTYLE, WS_EX_APPWINDOW, WS_EX_TOOLWINDOW,
+  SetWindowLongPtrW, SetWindowPos, GWL_EXSTYLE, HWND_BOTTOM, HWND_TOPMOST,
+  SWP_NOACTIVATE, SWP_NOMOVE, SWP_NOSIZE, WS_EX_APPWINDOW,
+  WS_EX_TOOLWINDOW,
 };
 
 use super::app_bar;
-use crate::config::DockEdge;
+use crate::config::{DockEdge, WidgetLayer};
 
 pub trait WindowExtWindows {
   fn set_tool_window(&self, enable: bool) -> anyhow::Result<()>;
@@ -18,6 +20,10 @@ pub trait WindowExtWindows {
   ) -> anyhow::Result<(PhysicalSize<i32>, PhysicalPosition<i32>)>;
 
   fn deallocate_app_bar(&self) -> anyhow::Result<()>;
+
+  /// Moves the window to the top of its layer (or the very bottom for
+  /// `WidgetLayer::Bottom`), without activating it.
+  fn raise_in_layer(&self, layer: WidgetLayer) -> anyhow::Result<()>;
 }
 
 impl<R: Runtime> WindowExtWindows for Window<R> {
@@ -60,4 +66,28 @@ impl<R: Runtime> WindowExtWindows for Window<R> {
     let handle = self.hwnd().context("Failed to get window handle.")?;
     app_bar::remove_app_bar(handle.0 as _)
   }
+
+  fn raise_in_layer(&self, layer: WidgetLayer) -> anyhow::Result<()> {
+    let handle = self.hwnd().context("Failed to get window handle.")?;
+
+    let insert_after = match layer {
+      WidgetLayer::Bottom => HWND_BOTTOM,
+      WidgetLayer::Top | WidgetLayer::Overlay => HWND_TOPMOST,
+      WidgetLayer::Normal => return Ok(()),
+    };
+
+    unsafe {
+      SetWindowPos(
+        handle,
+        insert_after,
+        0,
+        0,
+        0,
+        0,
+        SWP_NOACTIVATE | SWP_NOMOVE | SWP_NOSIZE,
+      )
+    }?;
+
+    Ok(())
+  }
 }
@@ -1,19 +1,28 @@
 use std::{
   collections::HashMap,
+  fmt,
   fs::{self},
   path::PathBuf,
   sync::Arc,
 };
 
-use anyhow::Context;
+use anyhow::{bail, Context};
+use chrono::{Datelike, Local, NaiveTime, Weekday};
 use clap::ValueEnum;
+use regex::Regex;
 use serde::{Deserialize, Deserializer, Serialize};
 use tauri::{path::BaseDirectory, AppHandle, Manager};
 use tokio::sync::{broadcast, Mutex};
-use tracing::{error, info};
-
-use crate::common::{
-  copy_dir_all, has_extension, read_and_parse_json, LengthValue, PathExt,
+use tracing::{error, info, level_filters::LevelFilter, warn};
+
+use crate::{
+  common::{copy_dir_all, has_extension, LengthValue, PathExt},
+  config_encryption::{
+    read_and_parse_encrypted_json, write_encrypted_json,
+  },
+  config_extends::ConfigResolver,
+  portable,
+  secrets::SecretAccess,
 };
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
@@ -24,6 +33,381 @@ pub struct SettingsConfig {
   schema: Option<String>,
 
   /// Widget configs to be launched on startup.
+  ///
+  /// Used when no profile is active.
+  pub startup_configs: Vec<StartupConfig>,
+
+  /// Named sets of widget configs to launch (e.g. `work` or `gaming`).
+  #[serde(default)]
+  pub profiles: Vec<Profile>,
+
+  /// Name of the active profile. `startup_configs` is used if `None`.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub active_profile: Option<String>,
+
+  /// Saved monitor arrangements that can be re-applied from the tray.
+  #[serde(default)]
+  pub display_profiles: Vec<DisplayProfile>,
+
+  /// Locale to use for backend-generated text (e.g. `de` or `zh-CN`).
+  /// Defaults to the system locale.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub locale: Option<String>,
+
+  /// Path to a custom system tray icon (PNG or ICO). Relative paths are
+  /// resolved from the config directory.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub tray_icon_path: Option<PathBuf>,
+
+  /// Whether to show a red dot on the system tray icon when there are
+  /// recent errors.
+  #[serde(default)]
+  pub show_error_badge: bool,
+
+  /// Whether to run without a system tray icon. Zebar is then only
+  /// controllable via the CLI.
+  #[serde(default)]
+  pub hide_tray_icon: bool,
+
+  /// Whether to hold back provider updates to widgets while the display
+  /// is off or the session is locked or disconnected. The latest update
+  /// of each provider is sent once the screen is in use again.
+  #[serde(default)]
+  pub pause_when_unattended: bool,
+
+  /// Proxy for HTTP requests made by providers (e.g. weather and IP).
+  /// Falls back to the `HTTP_PROXY` and `HTTPS_PROXY` environment
+  /// variables if not set.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub proxy: Option<ProxyConfig>,
+
+  /// Search launcher that's toggled via a global hotkey.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub launcher: Option<LauncherConfig>,
+
+  /// Rules that trigger actions based on provider outputs. Evaluated
+  /// regardless of which widgets are open.
+  #[serde(default, skip_serializing_if = "Vec::is_empty")]
+  pub alerts: Vec<AlertRule>,
+
+  /// Detection of unresponsive widgets (e.g. hung JS or a crashed
+  /// renderer).
+  #[serde(default)]
+  pub heartbeat: HeartbeatConfig,
+
+  /// Recording of provider metrics to a local database, so that widgets
+  /// can query them via `query_history`. Disabled if not set.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub history: Option<HistoryConfig>,
+
+  /// Periodic backups of the config directory, which can be restored
+  /// via `zebar restore`.
+  #[serde(default)]
+  pub backup: BackupConfig,
+
+  /// Whether to show actions for pulling and pushing the config
+  /// directory in the system tray, when it's within a git repository.
+  #[serde(default = "default_git_sync")]
+  pub git_sync: bool,
+
+  /// Binds provider outputs to the system tray tooltip and icon, e.g.
+  /// for showing CPU usage while all widgets are hidden. Disabled if
+  /// not set.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub tray_binding: Option<TrayBindingConfig>,
+
+  /// Delays opening widgets on startup until a window manager or process
+  /// is available. Widgets are opened right away if not set.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub wait_for: Option<WaitForConfig>,
+
+  /// Log level overrides by provider type (e.g. `weather`). Applies to
+  /// logs from within the provider, regardless of the `LOG_LEVEL`
+  /// environment variable.
+  #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+  pub provider_log_levels: HashMap<String, LogLevel>,
+}
+
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LogLevel {
+  Off,
+  Error,
+  Warn,
+  Info,
+  Debug,
+  Trace,
+}
+
+impl From<LogLevel> for LevelFilter {
+  fn from(level: LogLevel) -> Self {
+    match level {
+      LogLevel::Off => LevelFilter::OFF,
+      LogLevel::Error => LevelFilter::ERROR,
+      LogLevel::Warn => LevelFilter::WARN,
+      LogLevel::Info => LevelFilter::INFO,
+      LogLevel::Debug => LevelFilter::DEBUG,
+      LogLevel::Trace => LevelFilter::TRACE,
+    }
+  }
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WaitForConfig {
+  /// What to wait for before opening widgets.
+  pub target: WaitForTarget,
+
+  /// How long in milliseconds to wait before opening widgets anyway.
+  #[serde(default = "default_wait_for_timeout")]
+  pub timeout: u64,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(tag = "type", content = "match", rename_all = "snake_case")]
+pub enum WaitForTarget {
+  /// GlazeWM's IPC server.
+  Glazewm,
+
+  /// Komorebi's named pipe.
+  Komorebi,
+
+  /// A running process with the given name (e.g. `glazewm.exe`).
+  Process(String),
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HeartbeatConfig {
+  /// How long in milliseconds a widget can go without a heartbeat
+  /// before it's considered unresponsive.
+  #[serde(default = "default_heartbeat_timeout")]
+  pub timeout: u64,
+
+  /// Whether to relaunch unresponsive widgets.
+  #[serde(default)]
+  pub auto_reload: bool,
+}
+
+impl Default for HeartbeatConfig {
+  fn default() -> Self {
+    Self {
+      timeout: default_heartbeat_timeout(),
+      auto_reload: false,
+    }
+  }
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BackupConfig {
+  /// Whether to periodically back up the config directory.
+  #[serde(default = "default_backup_enabled")]
+  pub enabled: bool,
+
+  /// How often in milliseconds to create a backup.
+  #[serde(default = "default_backup_interval")]
+  pub interval: u64,
+
+  /// Number of backups to keep. Older backups are deleted.
+  #[serde(default = "default_backup_retention")]
+  pub retention: usize,
+}
+
+impl Default for BackupConfig {
+  fn default() -> Self {
+    Self {
+      enabled: default_backup_enabled(),
+      interval: default_backup_interval(),
+      retention: default_backup_retention(),
+    }
+  }
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HistoryConfig {
+  /// Provider configs referenced by the metrics, keyed by name.
+  pub providers: HashMap<String, serde_json::Value>,
+
+  /// Expressions to record, keyed by metric name (e.g.
+  /// `{ "cpu": "cpu.usage" }`). Non-numeric values are skipped.
+  pub metrics: HashMap<String, String>,
+
+  /// How often in milliseconds to record a sample of each metric.
+  #[serde(default = "default_history_interval")]
+  pub interval: u64,
+
+  /// Number of days to keep samples for.
+  #[serde(default = "default_history_retention")]
+  pub retention_days: u64,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TrayBindingConfig {
+  /// Provider configs referenced by the tooltip and icon, keyed by name.
+  pub providers: HashMap<String, serde_json::Value>,
+
+  /// Tooltip with placeholders of the form `{name.path}`, same as a
+  /// widget's `window_title` template (e.g. `CPU {cpu.usage:0}%`).
+  /// Defaults to the Zebar version.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub tooltip: Option<String>,
+
+  /// Expression for a number to show in place of the tray icon (e.g.
+  /// `battery.chargePercent`). Rounded and capped at 999. The regular
+  /// icon is shown if not set or if the value isn't numeric.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub icon_value: Option<String>,
+
+  /// Hex background color of the numeric icon (e.g. `#0078d4`).
+  /// Defaults to red.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub icon_color: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AlertRule {
+  /// Unique name of the rule.
+  pub name: String,
+
+  /// Provider configs referenced by the condition, keyed by name.
+  pub providers: HashMap<String, serde_json::Value>,
+
+  /// Expression over the provider outputs (e.g. `cpu.usage > 90`). The
+  /// rule fires when this becomes truthy.
+  pub condition: String,
+
+  /// How long in milliseconds the condition must hold before the rule
+  /// fires.
+  #[serde(default)]
+  pub duration: u64,
+
+  /// Actions to run when the rule fires.
+  pub actions: Vec<AlertAction>,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum AlertAction {
+  /// Shows a native notification. The title and body can contain
+  /// `{name.path}` placeholders like window title templates.
+  Notification {
+    title: Option<String>,
+    body: Option<String>,
+  },
+
+  /// Runs a program with the given arguments.
+  RunCommand {
+    program: String,
+    #[serde(default)]
+    args: Vec<String>,
+  },
+
+  /// Emits an `alert` event to all widgets.
+  EmitEvent,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LauncherConfig {
+  /// Global hotkey that toggles the launcher (e.g. `Alt+Space`).
+  pub hotkey: String,
+
+  /// Path to the launcher's widget config. Relative paths are resolved
+  /// from the config directory.
+  pub path: PathBuf,
+
+  /// Name of the preset to open.
+  pub preset: String,
+
+  /// Commands that can be searched and run from the launcher.
+  #[serde(default)]
+  pub commands: Vec<LauncherCommand>,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LauncherCommand {
+  /// Name shown in search results.
+  pub name: String,
+
+  /// Program to run.
+  pub program: String,
+
+  #[serde(default)]
+  pub args: Vec<String>,
+
+  /// Additional terms to match when searching.
+  #[serde(default)]
+  pub keywords: Vec<String>,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProxyConfig {
+  /// Proxy URL (e.g. `http://proxy.corp:8080` or
+  /// `socks5://127.0.0.1:1080`).
+  pub url: String,
+
+  /// Username for proxy authentication.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub username: Option<String>,
+
+  /// Password for proxy authentication. Can be encrypted.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub password: Option<String>,
+
+  /// Comma-separated hosts that bypass the proxy (e.g.
+  /// `localhost,.corp.internal`).
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub no_proxy: Option<String>,
+}
+
+impl SettingsConfig {
+  /// Startup configs of the active profile, or the top-level startup
+  /// configs if no profile is active.
+  pub fn active_startup_configs(&self) -> &Vec<StartupConfig> {
+    self
+      .active_profile
+      .as_ref()
+      .and_then(|name| {
+        self.profiles.iter().find(|profile| &profile.name == name)
+      })
+      .map(|profile| &profile.startup_configs)
+      .unwrap_or(&self.startup_configs)
+  }
+
+  /// Mutable variant of `active_startup_configs`.
+  pub fn active_startup_configs_mut(&mut self) -> &mut Vec<StartupConfig> {
+    let profile = self.active_profile.as_ref().and_then(|name| {
+      self
+        .profiles
+        .iter_mut()
+        .find(|profile| &profile.name == name)
+    });
+
+    match profile {
+      Some(profile) => &mut profile.startup_configs,
+      None => &mut self.startup_configs,
+    }
+  }
+}
+
+/// Name used to refer to the top-level startup configs (i.e. no active
+/// profile) from the CLI and system tray.
+pub const DEFAULT_PROFILE_NAME: &str = "default";
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Profile {
+  /// Unique name of the profile.
+  pub name: String,
+
+  /// Widget configs to launch when the profile is active.
+  #[serde(default)]
   pub startup_configs: Vec<StartupConfig>,
 }
 
@@ -65,6 +449,38 @@ impl<'de> Deserialize<'de> for StartupConfig {
   }
 }
 
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DisplayProfile {
+  /// Unique name of the profile.
+  pub name: String,
+
+  /// Arrangement of each monitor in the profile.
+  pub monitors: Vec<DisplayProfileMonitor>,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DisplayProfileMonitor {
+  /// Device name of the monitor (e.g. `\\.\DISPLAY1` or `HDMI-1`).
+  pub name: String,
+
+  /// Whether the monitor should be set as the primary monitor.
+  pub is_primary: bool,
+
+  /// X-coordinate of the monitor in physical pixels.
+  pub x: i32,
+
+  /// Y-coordinate of the monitor in physical pixels.
+  pub y: i32,
+
+  /// Horizontal resolution of the monitor.
+  pub width: u32,
+
+  /// Vertical resolution of the monitor.
+  pub height: u32,
+}
+
 #[derive(Clone, Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct WidgetConfig {
@@ -72,12 +488,30 @@ pub struct WidgetConfig {
   #[serde(rename = "$schema")]
   schema: Option<String>,
 
-  /// Relative path to entry point HTML file.
+  /// Relative path to entry point HTML file. Can include a query
+  /// string (e.g. `bar.html?section=left`).
   pub html_path: PathBuf,
 
+  /// Paths of base configs that this config extends. Kept so that the
+  /// reference is preserved when the config is saved.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  extends: Option<serde_json::Value>,
+
+  /// Human-readable name of the widget (e.g. `Status bar`). Used as the
+  /// window title, which is what screen readers announce. Defaults to
+  /// the widget's config path.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub name: Option<String>,
+
   /// Whether to show the Tauri window above/below all others.
   pub z_order: ZOrder,
 
+  /// Layer of the window relative to other Zebar windows (e.g. so that
+  /// flyouts appear above bars). Defaults to the layer matching
+  /// `z_order`.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub layer: Option<WidgetLayer>,
+
   /// Whether the Tauri window should be shown in the taskbar.
   pub shown_in_taskbar: bool,
 
@@ -90,6 +524,16 @@ pub struct WidgetConfig {
   /// Whether the Tauri window frame should be transparent.
   pub transparent: bool,
 
+  /// Window type to advertise to the window manager on X11 (e.g. `dock`
+  /// for bars, which removes them from alt-tab). Ignored on other
+  /// platforms.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub x11_window_type: Option<X11WindowType>,
+
+  /// Touch-friendly behavior for tablets and touchscreen laptops.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub touch: Option<WidgetTouchOptions>,
+
   /// How network requests should be cached.
   #[serde(default)]
   pub caching: WidgetCaching,
@@ -98,6 +542,371 @@ pub struct WidgetConfig {
   /// compatibility with v2.3.0 and earlier.
   #[serde(alias = "defaultPlacements")]
   pub presets: Vec<WidgetPreset>,
+
+  /// Widget configs to open together with this widget (e.g. a flyout
+  /// panel for a bar). Paths are relative to this config file.
+  ///
+  /// Companions are opened with the preset of the same name if one
+  /// exists, otherwise their first preset. They're closed once all
+  /// windows of this widget are closed.
+  #[serde(default, skip_serializing_if = "Vec::is_empty")]
+  pub opens_with: Vec<PathBuf>,
+
+  /// Segments of a bar to render from this config (e.g. workspaces on
+  /// the left and a clock on the right). Each segment is opened as its
+  /// own window within the preset's placement, unless `single_webview`
+  /// is enabled.
+  #[serde(default, skip_serializing_if = "Vec::is_empty")]
+  pub segments: Vec<WidgetSegment>,
+
+  /// Whether to render all segments in one window spanning the preset's
+  /// placement, rather than a window per segment. Saves memory, since
+  /// every window has its own webview.
+  #[serde(default)]
+  pub single_webview: bool,
+
+  /// Whether to reload the widget when a file in its HTML file's
+  /// directory changes. Meant for developing widgets without a dev
+  /// server.
+  #[serde(default)]
+  pub dev_mode: bool,
+
+  /// Options for how the widget is opened on startup.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub launch_options: Option<WidgetLaunchOptions>,
+
+  /// Providers and privileged commands the widget is allowed to use.
+  ///
+  /// Widgets that don't declare any permissions can still use providers
+  /// (except ones that must be listed by name), for compatibility with
+  /// v2.x widgets, but can't invoke privileged commands.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub permissions: Option<WidgetPermissions>,
+
+  /// Remote content and network access policy for the widget. Enforced
+  /// via a Content-Security-Policy. No policy is applied if `None`.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub security: Option<WidgetSecurity>,
+
+  /// Window title bound to provider outputs (e.g. to show CPU usage in
+  /// the taskbar or alt-tab). Defaults to the widget's config path.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub window_title: Option<WindowTitleConfig>,
+
+  /// Variables to pass to the widget, so that the same HTML can be
+  /// parameterized per config (e.g. labels, colors, or which disk to
+  /// show). Values can reference OS environment variables as `${NAME}`
+  /// or `${NAME:-fallback}`.
+  #[serde(
+    default,
+    alias = "vars",
+    skip_serializing_if = "HashMap::is_empty"
+  )]
+  pub env: HashMap<String, String>,
+}
+
+impl WidgetConfig {
+  /// Layer of the widget's window, falling back to the layer matching
+  /// `z_order`.
+  pub fn layer(&self) -> WidgetLayer {
+    self.layer.unwrap_or_else(|| (&self.z_order).into())
+  }
+
+  /// Secrets that the widget's provider configs can reference.
+  pub fn secret_access(&self) -> SecretAccess {
+    SecretAccess::Only(
+      self
+        .permissions
+        .as_ref()
+        .map(|permissions| permissions.secrets.clone())
+        .unwrap_or_default(),
+    )
+  }
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WidgetLaunchOptions {
+  /// Whether to defer creating the widget's windows until it's first
+  /// shown (e.g. toggled via the system tray), rather than on startup.
+  /// Saves startup time and memory for rarely used widgets.
+  #[serde(default)]
+  pub defer_until_shown: bool,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WidgetTouchOptions {
+  /// Whether swiping in from the screen edge nearest to the widget shows
+  /// it while it's hidden (e.g. via `hideOnFullscreen`). Only supported
+  /// on Windows.
+  #[serde(default)]
+  pub edge_swipe_reveal: bool,
+
+  /// How long to show the widget for after an edge swipe (in
+  /// milliseconds).
+  #[serde(default = "default_reveal_duration")]
+  pub reveal_duration: u64,
+
+  /// Whether pressing and holding with touch or a pen is translated to a
+  /// right-click (i.e. opens the context menu).
+  #[serde(default)]
+  pub hold_to_right_click: bool,
+
+  /// How long to press for it to count as a hold (in milliseconds).
+  #[serde(default = "default_hold_duration")]
+  pub hold_duration: u64,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WidgetSegment {
+  /// Unique name of the segment within the config.
+  pub name: String,
+
+  /// Which side of the bar the segment is aligned to. Segments in the
+  /// same region are laid out in the order they're defined.
+  pub region: SegmentRegion,
+
+  /// Width of the segment. Percentages are relative to the width of the
+  /// preset's placement.
+  pub width: LengthValue,
+}
+
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SegmentRegion {
+  Left,
+  Center,
+  Right,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WindowTitleConfig {
+  /// Title with placeholders of the form `{name.path}`, where `name` is
+  /// a key in `providers` and `path` is a dot-separated path into its
+  /// output (e.g. `CPU {cpu.usage:0}%`). An optional `:N` suffix rounds
+  /// numbers to `N` decimal places.
+  pub template: String,
+
+  /// Provider configs referenced by the template, keyed by name.
+  #[serde(default)]
+  pub providers: HashMap<String, serde_json::Value>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WidgetSecurity {
+  /// Whether the widget can load scripts, styles, fonts and images from
+  /// remote hosts (e.g. a CDN).
+  #[serde(default)]
+  pub allow_remote_content: bool,
+
+  /// Origins the widget is allowed to make network requests to via
+  /// `fetch`, `XMLHttpRequest` or websockets (e.g.
+  /// `https://api.github.com` or `https://*.example.com`).
+  ///
+  /// Schemes (e.g. `wss:`) are also allowed. Anything else is rejected,
+  /// since the hosts are added to the widget's Content-Security-Policy
+  /// as-is.
+  #[serde(default, deserialize_with = "deserialize_connect_hosts")]
+  pub connect_hosts: Vec<String>,
+}
+
+/// Deserializes `WidgetSecurity::connect_hosts`, checking that each
+/// entry is a CSP host-source without a path (i.e. an origin, where the
+/// scheme, subdomain and port can be wildcards) or a scheme-source.
+fn deserialize_connect_hosts<'de, D>(
+  deserializer: D,
+) -> Result<Vec<String>, D::Error>
+where
+  D: Deserializer<'de>,
+{
+  let hosts = Vec::<String>::deserialize(deserializer)?;
+
+  let scheme_source = Regex::new(r"^[a-zA-Z][a-zA-Z0-9+.-]*:$")
+    .map_err(serde::de::Error::custom)?;
+  let host_source = Regex::new(
+    r"^([a-zA-Z][a-zA-Z0-9+.-]*://)?(\*|(\*\.)?[a-zA-Z0-9-]+(\.[a-zA-Z0-9-]+)*|\[[0-9a-fA-F:.]+\])(:([0-9]{1,5}|\*))?$",
+  )
+  .map_err(serde::de::Error::custom)?;
+
+  for host in &hosts {
+    if !scheme_source.is_match(host) && !host_source.is_match(host) {
+      return Err(serde::de::Error::custom(format!(
+        "Invalid connect host '{}'. Expected an origin (e.g. \
+         `https://api.github.com`) or a scheme (e.g. `wss:`).",
+        host
+      )));
+    }
+  }
+
+  Ok(hosts)
+}
+
+impl WidgetSecurity {
+  /// Builds the Content-Security-Policy to serve the widget's assets
+  /// with.
+  pub fn content_security_policy(&self) -> String {
+    let remote = match self.allow_remote_content {
+      true => " https:",
+      false => "",
+    };
+
+    // IPC and the GlazeWM websocket are always allowed, since they're
+    // needed by the client API.
+    let mut connect_src =
+      "'self' ipc: http://ipc.localhost ws://localhost:6123".to_string();
+
+    for host in &self.connect_hosts {
+      connect_src.push(' ');
+      connect_src.push_str(host);
+    }
+
+    [
+      "default-src 'self'".to_string(),
+      format!("script-src 'self' 'unsafe-inline' 'unsafe-eval'{remote}"),
+      format!("style-src 'self' 'unsafe-inline'{remote}"),
+      format!("font-src 'self' data:{remote}"),
+      format!("img-src 'self' data: blob:{remote}"),
+      format!("connect-src {connect_src}"),
+      "object-src 'none'".to_string(),
+    ]
+    .join("; ")
+  }
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WidgetPermissions {
+  /// Provider types the widget is allowed to listen to and call
+  /// functions on (e.g. `cpu`). `*` allows all providers, except ones
+  /// that must be listed by name (e.g. `input_stats`).
+  #[serde(default)]
+  pub providers: Vec<String>,
+
+  /// Privileged commands the widget is allowed to invoke.
+  #[serde(default)]
+  pub commands: Vec<CommandPermission>,
+
+  /// Programs the widget is allowed to run via `run_command`. Not
+  /// needed if `shell_exec` is in `commands`.
+  #[serde(default)]
+  pub shell_commands: Vec<ShellCommandRule>,
+
+  /// Names of secrets that the widget's provider configs are allowed to
+  /// reference (e.g. `owm` for `secret:owm`).
+  #[serde(default)]
+  pub secrets: Vec<String>,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ShellCommandRule {
+  /// Program name or path (e.g. `powershell` or `/usr/bin/playerctl`).
+  pub program: String,
+
+  /// Exact arguments the program can be run with. Any arguments are
+  /// allowed if `None`.
+  pub args: Option<Vec<String>>,
+}
+
+/// Provider types that must be explicitly allowed, even by widgets that
+/// don't declare any permissions. Not covered by `*`.
+const OPT_IN_PROVIDERS: &[&str] = &["input_stats"];
+
+/// Whether the provider type must be explicitly allowed by name.
+pub fn provider_requires_opt_in(provider_type: &str) -> bool {
+  OPT_IN_PROVIDERS.contains(&provider_type)
+}
+
+impl WidgetPermissions {
+  /// Whether the given provider type is allowed.
+  pub fn allows_provider(&self, provider_type: &str) -> bool {
+    self.providers.iter().any(|allowed| {
+      allowed == provider_type
+        || (allowed == "*" && !provider_requires_opt_in(provider_type))
+    })
+  }
+
+  /// Whether the given privileged command is allowed.
+  pub fn allows_command(&self, command: CommandPermission) -> bool {
+    self.commands.contains(&command)
+  }
+
+  /// Whether the given program and arguments are allowed to be run.
+  pub fn allows_shell_command(
+    &self,
+    program: &str,
+    args: &[String],
+  ) -> bool {
+    self.allows_command(CommandPermission::ShellExec)
+      || self.shell_commands.iter().any(|rule| {
+        rule.program == program
+          && rule
+            .args
+            .as_ref()
+            .map_or(true, |rule_args| rule_args == args)
+      })
+  }
+}
+
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CommandPermission {
+  /// Running any program via `run_command`, regardless of
+  /// `shell_commands`.
+  ShellExec,
+
+  /// Capturing screenshots.
+  Screenshot,
+
+  /// Starting, stopping, and editing widgets.
+  ManageWidgets,
+
+  /// Saving and applying display profiles.
+  DisplayProfiles,
+
+  /// Storing and deleting secrets in the OS credential store.
+  Secrets,
+
+  /// Locking, logging out, sleeping, hibernating, restarting, and
+  /// shutting down the system, as well as keeping it awake.
+  Power,
+
+  /// Searching and launching apps, windows, and commands via the
+  /// launcher.
+  Launcher,
+
+  /// Pulling, committing, and pushing changes to the config directory
+  /// via git sync.
+  GitSync,
+
+  /// Setting a badge on the system tray icon.
+  TrayBadge,
+
+  /// Querying and exporting the recorded history of metrics.
+  History,
+}
+
+impl fmt::Display for CommandPermission {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    let name = match self {
+      CommandPermission::ShellExec => "shell_exec",
+      CommandPermission::Screenshot => "screenshot",
+      CommandPermission::ManageWidgets => "manage_widgets",
+      CommandPermission::DisplayProfiles => "display_profiles",
+      CommandPermission::Secrets => "secrets",
+      CommandPermission::Power => "power",
+      CommandPermission::Launcher => "launcher",
+      CommandPermission::GitSync => "git_sync",
+      CommandPermission::TrayBadge => "tray_badge",
+      CommandPermission::History => "history",
+    };
+
+    write!(f, "{}", name)
+  }
 }
 
 #[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
@@ -108,6 +917,56 @@ pub enum ZOrder {
   TopMost,
 }
 
+/// Layer of a widget's window. Windows on higher layers are kept above
+/// those on lower layers, and windows within a layer are kept in the
+/// order they were opened.
+#[derive(
+  Clone,
+  Copy,
+  Debug,
+  Deserialize,
+  Eq,
+  Ord,
+  PartialEq,
+  PartialOrd,
+  Serialize,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum WidgetLayer {
+  /// Below all other windows (e.g. desktop widgets).
+  Bottom,
+
+  /// Same as other app windows.
+  Normal,
+
+  /// Above other app windows (e.g. bars).
+  Top,
+
+  /// Above `top` windows (e.g. flyouts and popups).
+  Overlay,
+}
+
+impl From<&ZOrder> for WidgetLayer {
+  fn from(z_order: &ZOrder) -> Self {
+    match z_order {
+      ZOrder::BottomMost => WidgetLayer::Bottom,
+      ZOrder::Normal => WidgetLayer::Normal,
+      ZOrder::TopMost => WidgetLayer::Top,
+    }
+  }
+}
+
+/// Value of the `_NET_WM_WINDOW_TYPE` hint on X11.
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum X11WindowType {
+  Dock,
+  Desktop,
+  Toolbar,
+  Utility,
+  Notification,
+}
+
 #[derive(Clone, Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct WidgetCaching {
@@ -145,6 +1004,105 @@ pub struct WidgetPreset {
 
   #[serde(flatten)]
   pub placement: WidgetPlacement,
+
+  /// When the widget should be shown. Only applies when launched on
+  /// startup.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub schedule: Option<WidgetSchedule>,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WidgetSchedule {
+  /// Time ranges (in local time) when the widget is open. The widget is
+  /// always open if empty.
+  #[serde(default)]
+  pub active_hours: Vec<ActiveHours>,
+
+  /// Whether to hide the widget while a fullscreen window is focused.
+  #[serde(default)]
+  pub hide_on_fullscreen: bool,
+}
+
+impl WidgetSchedule {
+  /// Whether the current local time is within the active hours.
+  pub fn is_active_now(&self) -> bool {
+    let now = Local::now();
+
+    self.active_hours.is_empty()
+      || self
+        .active_hours
+        .iter()
+        .any(|hours| hours.contains(now.weekday(), now.time()))
+  }
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ActiveHours {
+  /// Start time in `HH:MM` format (e.g. `09:00`).
+  pub start: String,
+
+  /// End time in `HH:MM` format (e.g. `17:30`). Can be earlier than the
+  /// start time for ranges that span midnight.
+  pub end: String,
+
+  /// Days of the week the range applies to. Applies to all days if
+  /// empty.
+  #[serde(default)]
+  pub days: Vec<ScheduleWeekday>,
+}
+
+impl ActiveHours {
+  /// Whether the given day and time are within the range.
+  fn contains(&self, weekday: Weekday, time: NaiveTime) -> bool {
+    let (Ok(start), Ok(end)) = (
+      NaiveTime::parse_from_str(&self.start, "%H:%M"),
+      NaiveTime::parse_from_str(&self.end, "%H:%M"),
+    ) else {
+      warn!(
+        "Invalid active hours '{}' - '{}'. Expected `HH:MM` format.",
+        self.start, self.end
+      );
+      return false;
+    };
+
+    let is_day_match = self.days.is_empty()
+      || self.days.iter().any(|day| Weekday::from(*day) == weekday);
+
+    let is_time_match = match start <= end {
+      true => time >= start && time < end,
+      false => time >= start || time < end,
+    };
+
+    is_day_match && is_time_match
+  }
+}
+
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ScheduleWeekday {
+  Mon,
+  Tue,
+  Wed,
+  Thu,
+  Fri,
+  Sat,
+  Sun,
+}
+
+impl From<ScheduleWeekday> for Weekday {
+  fn from(day: ScheduleWeekday) -> Self {
+    match day {
+      ScheduleWeekday::Mon => Weekday::Mon,
+      ScheduleWeekday::Tue => Weekday::Tue,
+      ScheduleWeekday::Wed => Weekday::Wed,
+      ScheduleWeekday::Thu => Weekday::Thu,
+      ScheduleWeekday::Fri => Weekday::Fri,
+      ScheduleWeekday::Sat => Weekday::Sat,
+      ScheduleWeekday::Sun => Weekday::Sun,
+    }
+  }
 }
 
 #[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
@@ -171,6 +1129,12 @@ pub struct WidgetPlacement {
   /// How to reserve space for the widget.
   #[serde(default)]
   pub dock_to_edge: DockConfig,
+
+  /// Query parameters to open the HTML file with. Overrides parameters
+  /// of the same name in the config's `html_path` (e.g. so that one
+  /// HTML file can render different sections of a bar).
+  #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+  pub query: HashMap<String, String>,
 }
 
 #[derive(
@@ -198,6 +1162,7 @@ pub enum MonitorSelection {
   Secondary,
   Index(usize),
   Name(String),
+  HardwareId(String),
 }
 
 #[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Default)]
@@ -265,13 +1230,8 @@ impl Config {
     app_handle: &AppHandle,
     config_dir_override: Option<PathBuf>,
   ) -> anyhow::Result<Self> {
-    let config_dir = match config_dir_override {
-      Some(dir) => dir,
-      None => app_handle
-        .path()
-        .resolve(".glzr/zebar", BaseDirectory::Home)
-        .context("Unable to get home directory.")?,
-    };
+    let config_dir =
+      Self::resolve_config_dir(app_handle, config_dir_override)?;
 
     let settings = Self::read_settings_or_init(app_handle, &config_dir)?;
     let widget_configs = Self::read_widget_configs(&config_dir)?;
@@ -292,6 +1252,22 @@ impl Config {
     })
   }
 
+  /// Gets the config directory, which is either the given override,
+  /// `config/` in portable mode, or `~/.glzr/zebar`.
+  pub fn resolve_config_dir(
+    app_handle: &AppHandle,
+    config_dir_override: Option<PathBuf>,
+  ) -> anyhow::Result<PathBuf> {
+    match (config_dir_override, portable::config_dir()) {
+      (Some(dir), _) => Self::resolve_config_dir_override(app_handle, dir),
+      (None, Some(dir)) => Ok(dir),
+      (None, None) => app_handle
+        .path()
+        .resolve(".glzr/zebar", BaseDirectory::Home)
+        .context("Unable to get home directory."),
+    }
+  }
+
   /// Re-evaluates config files within the config directory.
   pub async fn reload(&self) -> anyhow::Result<()> {
     let new_settings =
@@ -314,6 +1290,30 @@ impl Config {
     Ok(())
   }
 
+  /// Resolves a custom config directory to an absolute path, creating
+  /// it if it doesn't exist.
+  ///
+  /// A leading `~` is expanded to the home directory.
+  fn resolve_config_dir_override(
+    app_handle: &AppHandle,
+    dir: PathBuf,
+  ) -> anyhow::Result<PathBuf> {
+    let dir = match dir.strip_prefix("~") {
+      Ok(rest) => app_handle
+        .path()
+        .home_dir()
+        .context("Unable to get home directory.")?
+        .join(rest),
+      Err(_) => dir,
+    };
+
+    fs::create_dir_all(&dir).with_context(|| {
+      format!("Unable to create config directory: {}", dir.display())
+    })?;
+
+    dir.to_absolute()
+  }
+
   /// Reads the global settings file or initializes it with the starter.
   ///
   /// Returns the parsed `SettingsConfig`.
@@ -337,14 +1337,14 @@ impl Config {
   /// Reads the global settings file.
   ///
   /// Returns the parsed `SettingsConfig` if found.
-  fn read_settings(
+  pub fn read_settings(
     dir: &PathBuf,
   ) -> anyhow::Result<Option<SettingsConfig>> {
     let settings_path = dir.join("settings.json");
 
     match settings_path.exists() {
       false => Ok(None),
-      true => read_and_parse_json(&settings_path),
+      true => read_and_parse_encrypted_json(&settings_path),
     }
   }
 
@@ -354,11 +1354,7 @@ impl Config {
     new_settings: SettingsConfig,
   ) -> anyhow::Result<()> {
     let settings_path = self.config_dir.join("settings.json");
-
-    fs::write(
-      &settings_path,
-      serde_json::to_string_pretty(&new_settings)? + "\n",
-    )?;
+    write_encrypted_json(&settings_path, &new_settings)?;
 
     let mut settings = self.settings.lock().await;
     *settings = new_settings.clone();
@@ -375,6 +1371,32 @@ impl Config {
   fn read_widget_configs(
     dir: &PathBuf,
   ) -> anyhow::Result<HashMap<PathBuf, WidgetConfig>> {
+    let mut configs = HashMap::new();
+
+    for (config_path, res) in Self::parse_widget_configs(dir)? {
+      match res {
+        Ok(config) => {
+          info!("Found valid widget config at: {}", config_path.display());
+          configs.insert(config_path, config);
+        }
+        Err(err) => {
+          error!("{:?}", err);
+        }
+      }
+    }
+
+    Ok(configs)
+  }
+
+  /// Parses all widget configs at the 2nd-level of the given directory,
+  /// including invalid ones.
+  ///
+  /// Returns the config paths (absolute if valid) with their parse
+  /// results. Base configs that are extended by other configs are
+  /// skipped if they aren't complete widget configs.
+  pub fn parse_widget_configs(
+    dir: &PathBuf,
+  ) -> anyhow::Result<Vec<(PathBuf, anyhow::Result<WidgetConfig>)>> {
     let dir_paths = fs::read_dir(dir)
       .with_context(|| {
         format!("Failed to read directory: {}", dir.display())
@@ -393,33 +1415,56 @@ impl Config {
       .filter(|path| path.is_file() && has_extension(&path, ".zebar.json"))
       .collect::<Vec<PathBuf>>();
 
-    let mut configs = HashMap::new();
+    let mut resolver = ConfigResolver::default();
 
     // Parse the found config files.
-    for path in config_paths {
-      match Self::parse_widget_config(&path) {
-        Ok((config_path, config)) => {
-          info!("Found valid widget config at: {}", config_path.display());
-          configs.insert(config_path, config);
-        }
-        Err(err) => {
-          error!("{:?}", err);
+    let results = config_paths
+      .into_iter()
+      .map(|path| {
+        (
+          path.clone(),
+          Self::parse_widget_config(&path, &mut resolver),
+        )
+      })
+      .collect::<Vec<_>>();
+
+    let results = results
+      .into_iter()
+      .filter_map(|(path, res)| match res {
+        Ok((config_path, config)) => Some((config_path, Ok(config))),
+        // Base configs that are extended by other configs don't need to
+        // be complete widget configs.
+        Err(_)
+          if path
+            .to_absolute()
+            .is_ok_and(|path| resolver.base_paths.contains(&path)) =>
+        {
+          info!("Found base widget config at: {}", path.display());
+          None
         }
-      }
-    }
+        Err(err) => Some((path, Err(err))),
+      })
+      .collect();
 
-    Ok(configs)
+    Ok(results)
   }
 
+  /// Parses a widget config, resolving its `extends` and `$include`
+  /// references.
   fn parse_widget_config(
     config_path: &PathBuf,
+    resolver: &mut ConfigResolver,
   ) -> anyhow::Result<(PathBuf, WidgetConfig)> {
     let abs_path = config_path.to_absolute().with_context(|| {
       format!("Invalid widget config path '{}'.", config_path.display())
     })?;
 
-    let config =
-      read_and_parse_json::<WidgetConfig>(&abs_path).map_err(|err| {
+    let config = resolver
+      .resolve(&abs_path)
+      .and_then(|value| {
+        serde_json::from_value::<WidgetConfig>(value).map_err(Into::into)
+      })
+      .map_err(|err| {
         anyhow::anyhow!(
           "Failed to parse widget config at '{}': {:?}",
           abs_path.display(),
@@ -461,6 +1506,24 @@ impl Config {
         },
         preset: "default".into(),
       }],
+      profiles: Vec::new(),
+      active_profile: None,
+      display_profiles: Vec::new(),
+      locale: None,
+      tray_icon_path: None,
+      show_error_badge: false,
+      hide_tray_icon: false,
+      pause_when_unattended: false,
+      proxy: None,
+      launcher: None,
+      alerts: Vec::new(),
+      heartbeat: HeartbeatConfig::default(),
+      history: None,
+      backup: BackupConfig::default(),
+      git_sync: default_git_sync(),
+      tray_binding: None,
+      wait_for: None,
+      provider_log_levels: HashMap::new(),
     };
 
     let settings_path = config_dir.join("settings.json");
@@ -472,13 +1535,18 @@ impl Config {
     Ok(())
   }
 
+  /// Returns the path to `settings.json`.
+  pub fn settings_path(&self) -> PathBuf {
+    self.config_dir.join("settings.json")
+  }
+
   pub async fn widget_configs(&self) -> HashMap<PathBuf, WidgetConfig> {
     self.widget_configs.lock().await.clone()
   }
 
   /// Returns the widget configs to open on startup.
   pub async fn startup_configs(&self) -> Vec<StartupConfig> {
-    self.settings.lock().await.startup_configs.clone()
+    self.settings.lock().await.active_startup_configs().clone()
   }
 
   /// Returns the widget configs to open on startup.
@@ -524,10 +1592,7 @@ impl Config {
       .send(HashMap::from([(config_path.clone(), new_config.clone())]))?;
 
     // Write the updated config to file.
-    fs::write(
-      &config_path,
-      serde_json::to_string_pretty(&new_config)? + "\n",
-    )?;
+    write_encrypted_json(config_path, &new_config)?;
 
     Ok(())
   }
@@ -547,11 +1612,13 @@ impl Config {
       preset: preset_name.to_string(),
     };
 
-    if new_settings.startup_configs.contains(&startup_config) {
+    let startup_configs = new_settings.active_startup_configs_mut();
+
+    if startup_configs.contains(&startup_config) {
       return Ok(());
     }
 
-    new_settings.startup_configs.push(startup_config);
+    startup_configs.push(startup_config);
     self.write_settings(new_settings).await
   }
 
@@ -566,13 +1633,99 @@ impl Config {
     let mut new_settings = { self.settings.lock().await.clone() };
     let rel_path = self.to_relative_path(config_path);
 
-    new_settings.startup_configs.retain(|config| {
+    new_settings.active_startup_configs_mut().retain(|config| {
       config.path != rel_path || config.preset != preset_name
     });
 
     self.write_settings(new_settings).await
   }
 
+  /// Returns the configured profiles.
+  pub async fn profiles(&self) -> Vec<Profile> {
+    self.settings.lock().await.profiles.clone()
+  }
+
+  /// Returns the name of the active profile, if any.
+  pub async fn active_profile(&self) -> Option<String> {
+    self.settings.lock().await.active_profile.clone()
+  }
+
+  /// Sets and persists the active profile. Passing
+  /// `DEFAULT_PROFILE_NAME` deactivates the current profile.
+  pub async fn set_active_profile(
+    &self,
+    name: &str,
+  ) -> anyhow::Result<()> {
+    let mut new_settings = { self.settings.lock().await.clone() };
+
+    new_settings.active_profile = match name {
+      DEFAULT_PROFILE_NAME => None,
+      _ => {
+        if !new_settings
+          .profiles
+          .iter()
+          .any(|profile| profile.name == name)
+        {
+          bail!("No profile named '{}'.", name);
+        }
+
+        Some(name.to_string())
+      }
+    };
+
+    self.write_settings(new_settings).await
+  }
+
+  /// Returns the saved display profiles.
+  pub async fn display_profiles(&self) -> Vec<DisplayProfile> {
+    self.settings.lock().await.display_profiles.clone()
+  }
+
+  /// Returns the display profile with the given name.
+  pub async fn display_profile_by_name(
+    &self,
+    name: &str,
+  ) -> Option<DisplayProfile> {
+    self
+      .display_profiles()
+      .await
+      .into_iter()
+      .find(|profile| profile.name == name)
+  }
+
+  /// Saves the given display profile. Replaces any existing profile with
+  /// the same name.
+  pub async fn save_display_profile(
+    &self,
+    profile: DisplayProfile,
+  ) -> anyhow::Result<()> {
+    let mut new_settings = { self.settings.lock().await.clone() };
+
+    match new_settings
+      .display_profiles
+      .iter_mut()
+      .find(|existing| existing.name == profile.name)
+    {
+      Some(existing) => *existing = profile,
+      None => new_settings.display_profiles.push(profile),
+    }
+
+    self.write_settings(new_settings).await
+  }
+
+  /// Removes the display profile with the given name.
+  pub async fn remove_display_profile(
+    &self,
+    name: &str,
+  ) -> anyhow::Result<()> {
+    let mut new_settings = { self.settings.lock().await.clone() };
+    new_settings
+      .display_profiles
+      .retain(|profile| profile.name != name);
+
+    self.write_settings(new_settings).await
+  }
+
   /// Joins the given path with the config directory path.
   ///
   /// Returns an absolute path.
@@ -597,6 +1750,37 @@ impl Config {
       .into()
   }
 
+  /// Resolves the path of an asset that a widget can access outside of
+  /// its own directory. Relative paths are resolved from `base_dir`.
+  ///
+  /// Assets must be within the config directory. Config files are
+  /// excluded, since they can contain secrets.
+  pub fn resolve_asset_path(
+    &self,
+    base_dir: &PathBuf,
+    asset_path: &PathBuf,
+  ) -> anyhow::Result<PathBuf> {
+    let abs_path =
+      base_dir.join(asset_path).to_absolute().with_context(|| {
+        format!("Asset not found: {}", asset_path.display())
+      })?;
+
+    let config_dir = self.config_dir.to_absolute()?;
+
+    if !abs_path.starts_with(&config_dir) {
+      bail!("Assets must be within the config directory.");
+    }
+
+    if !abs_path.is_file()
+      || has_extension(&abs_path, ".zebar.json")
+      || abs_path == config_dir.join("settings.json")
+    {
+      bail!("Not a valid asset: {}", asset_path.display());
+    }
+
+    Ok(abs_path)
+  }
+
   /// Formats a widget's config path for display.
   ///
   /// Returns relative path without the `.zebar.json` suffix (e.g.
@@ -687,3 +1871,53 @@ const fn default_bool<const V: bool>() -> bool {
 fn default_preset_name() -> String {
   "default".into()
 }
+
+/// Helper function for setting the default edge swipe reveal duration.
+fn default_reveal_duration() -> u64 {
+  3 * 1000
+}
+
+/// Helper function for setting the default press-and-hold duration.
+fn default_hold_duration() -> u64 {
+  500
+}
+
+/// Helper function for setting the default heartbeat timeout.
+fn default_heartbeat_timeout() -> u64 {
+  30 * 1000
+}
+
+/// Helper function for setting the default wait-for timeout.
+fn default_wait_for_timeout() -> u64 {
+  60 * 1000
+}
+
+/// Helper function for setting the default history sample interval.
+fn default_history_interval() -> u64 {
+  60 * 1000
+}
+
+/// Helper function for setting the default history retention.
+fn default_history_retention() -> u64 {
+  30
+}
+
+/// Helper function for enabling git sync by default.
+fn default_git_sync() -> bool {
+  true
+}
+
+/// Helper function for enabling backups by default.
+fn default_backup_enabled() -> bool {
+  true
+}
+
+/// Helper function for setting the default backup interval.
+fn default_backup_interval() -> u64 {
+  24 * 60 * 60 * 1000
+}
+
+/// Helper function for setting the default number of backups to keep.
+fn default_backup_retention() -> usize {
+  7
+}
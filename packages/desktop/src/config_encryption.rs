@@ -0,0 +1,271 @@
+use std::{fs, path::PathBuf};
+
+use anyhow::{bail, Context};
+use base64::prelude::*;
+use chacha20poly1305::{
+  aead::{Aead, AeadCore, KeyInit, OsRng},
+  Key, XChaCha20Poly1305, XNonce,
+};
+use keyring::Entry;
+use serde::{de::DeserializeOwned, Serialize};
+use serde_json::Value;
+
+/// Prefix for encrypted string values in config files.
+const ENCRYPTED_PREFIX: &str = "enc:v1:";
+
+/// Service name that the encryption key is stored under in the OS
+/// credential store. Separate from the service of other secrets, so
+/// that the key can't be resolved via a `secret:` reference or be
+/// changed by widgets.
+const KEY_SERVICE_NAME: &str = "zebar-config-encryption";
+
+/// Name of the credential that holds the encryption key.
+const KEY_ENTRY_NAME: &str = "key";
+
+/// Length of the nonce prepended to the ciphertext.
+const NONCE_LENGTH: usize = 24;
+
+/// Reads a JSON config file and decrypts any encrypted string values
+/// before deserializing it.
+pub fn read_and_parse_encrypted_json<T: DeserializeOwned>(
+  path: &PathBuf,
+) -> anyhow::Result<T> {
+  let value = read_encrypted_json_value(path)?;
+
+  serde_json::from_value(value).with_context(|| {
+    format!("Failed to parse JSON from file: {}", path.display())
+  })
+}
+
+/// Reads a JSON file as an untyped value, decrypting any encrypted
+/// values.
+fn read_encrypted_json_value(path: &PathBuf) -> anyhow::Result<Value> {
+  let content = fs::read_to_string(path)
+    .with_context(|| format!("Failed to read file: {}", path.display()))?;
+
+  let mut value: Value =
+    serde_json::from_str(&content).with_context(|| {
+      format!("Failed to parse JSON from file: {}", path.display())
+    })?;
+
+  decrypt_fields(&mut value).with_context(|| {
+    format!("Failed to decrypt values in file: {}", path.display())
+  })?;
+
+  Ok(value)
+}
+
+/// Writes a config to a JSON file.
+///
+/// Values that were encrypted in the existing file are re-encrypted, so
+/// that plaintext secrets aren't written back to disk.
+pub fn write_encrypted_json<T: Serialize>(
+  path: &PathBuf,
+  config: &T,
+) -> anyhow::Result<()> {
+  let mut value = serde_json::to_value(config)?;
+
+  let previous_value = fs::read_to_string(path)
+    .ok()
+    .and_then(|content| serde_json::from_str::<Value>(&content).ok());
+
+  if let Some(previous_value) = previous_value {
+    reencrypt_fields(&mut value, &previous_value)?;
+  }
+
+  fs::write(path, serde_json::to_string_pretty(&value)? + "\n")?;
+
+  Ok(())
+}
+
+/// Encrypts a value for use in config files.
+///
+/// Creates the encryption key if it doesn't exist yet.
+pub fn encrypt_value(plaintext: &str) -> anyhow::Result<String> {
+  let key = match stored_encryption_key()? {
+    Some(key) => key,
+    None => {
+      let key = XChaCha20Poly1305::generate_key(&mut OsRng);
+      set_encryption_key(&BASE64_STANDARD.encode(key))?;
+      key
+    }
+  };
+
+  encrypt_with_key(&key, plaintext)
+}
+
+/// Returns the encryption key as a base64 string, e.g. for importing it
+/// on another machine.
+pub fn export_encryption_key() -> anyhow::Result<String> {
+  Ok(BASE64_STANDARD.encode(encryption_key()?))
+}
+
+/// Stores the given base64 encryption key in the OS credential store.
+pub fn set_encryption_key(key: &str) -> anyhow::Result<()> {
+  let decoded = BASE64_STANDARD
+    .decode(key.trim())
+    .context("Encryption key is not valid base64.")?;
+
+  if decoded.len() != 32 {
+    bail!("Encryption key must be 32 bytes.");
+  }
+
+  key_entry()?
+    .set_password(key.trim())
+    .context("Failed to store config encryption key.")
+}
+
+/// Retrieves the encryption key from the OS credential store.
+fn encryption_key() -> anyhow::Result<Key> {
+  stored_encryption_key()?.context("No config encryption key found.")
+}
+
+/// Retrieves the encryption key from the OS credential store, or `None`
+/// if it hasn't been created yet.
+fn stored_encryption_key() -> anyhow::Result<Option<Key>> {
+  let encoded = match key_entry()?.get_password() {
+    Ok(encoded) => encoded,
+    Err(keyring::Error::NoEntry) => return Ok(None),
+    Err(err) => {
+      return Err(err).context("Failed to read config encryption key.")
+    }
+  };
+
+  let decoded = BASE64_STANDARD
+    .decode(encoded)
+    .context("Stored encryption key is not valid base64.")?;
+
+  if decoded.len() != 32 {
+    bail!("Stored encryption key must be 32 bytes.");
+  }
+
+  Ok(Some(*Key::from_slice(&decoded)))
+}
+
+fn key_entry() -> anyhow::Result<Entry> {
+  Entry::new(KEY_SERVICE_NAME, KEY_ENTRY_NAME)
+    .context("Failed to access config encryption key.")
+}
+
+fn encrypt_with_key(key: &Key, plaintext: &str) -> anyhow::Result<String> {
+  let cipher = XChaCha20Poly1305::new(key);
+  let nonce = XChaCha20Poly1305::generate_nonce(&mut OsRng);
+
+  let ciphertext = cipher
+    .encrypt(&nonce, plaintext.as_bytes())
+    .map_err(|_| anyhow::anyhow!("Failed to encrypt value."))?;
+
+  let mut payload = nonce.to_vec();
+  payload.extend(ciphertext);
+
+  Ok(format!(
+    "{}{}",
+    ENCRYPTED_PREFIX,
+    BASE64_STANDARD.encode(payload)
+  ))
+}
+
+fn decrypt_with_key(key: &Key, encrypted: &str) -> anyhow::Result<String> {
+  let payload = BASE64_STANDARD
+    .decode(encrypted)
+    .context("Encrypted value is not valid base64.")?;
+
+  if payload.len() < NONCE_LENGTH {
+    bail!("Encrypted value is too short.");
+  }
+
+  let (nonce, ciphertext) = payload.split_at(NONCE_LENGTH);
+
+  let plaintext = XChaCha20Poly1305::new(key)
+    .decrypt(XNonce::from_slice(nonce), ciphertext)
+    .map_err(|_| {
+      anyhow::anyhow!(
+        "Failed to decrypt value. The encryption key might not match."
+      )
+    })?;
+
+  Ok(String::from_utf8(plaintext)?)
+}
+
+/// Decrypts a single string value if it's encrypted, otherwise returns
+/// it unchanged.
+pub fn decrypt_string(string: &str) -> anyhow::Result<String> {
+  match string.strip_prefix(ENCRYPTED_PREFIX) {
+    Some(encrypted) => decrypt_with_key(&encryption_key()?, encrypted),
+    None => Ok(string.to_string()),
+  }
+}
+
+/// Decrypts all encrypted string values within the given JSON value.
+///
+/// Returns whether any values were decrypted. The encryption key is
+/// only retrieved if there are encrypted values.
+pub fn decrypt_fields(value: &mut Value) -> anyhow::Result<bool> {
+  let mut key = None;
+  decrypt_fields_with(value, &mut key)?;
+
+  Ok(key.is_some())
+}
+
+fn decrypt_fields_with(
+  value: &mut Value,
+  key: &mut Option<Key>,
+) -> anyhow::Result<()> {
+  match value {
+    Value::String(string) => {
+      if let Some(encrypted) = string.strip_prefix(ENCRYPTED_PREFIX) {
+        if key.is_none() {
+          *key = Some(encryption_key()?);
+        }
+
+        *string = decrypt_with_key(key.as_ref().unwrap(), encrypted)?;
+      }
+    }
+    Value::Array(values) => {
+      for value in values {
+        decrypt_fields_with(value, key)?;
+      }
+    }
+    Value::Object(map) => {
+      for value in map.values_mut() {
+        decrypt_fields_with(value, key)?;
+      }
+    }
+    _ => {}
+  }
+
+  Ok(())
+}
+
+/// Encrypts string values in `value` that are encrypted at the same
+/// location in `previous_value`.
+fn reencrypt_fields(
+  value: &mut Value,
+  previous_value: &Value,
+) -> anyhow::Result<()> {
+  match (value, previous_value) {
+    (Value::String(string), Value::String(previous)) => {
+      if previous.starts_with(ENCRYPTED_PREFIX)
+        && !string.starts_with(ENCRYPTED_PREFIX)
+      {
+        *string = encrypt_with_key(&encryption_key()?, string)?;
+      }
+    }
+    (Value::Array(values), Value::Array(previous_values)) => {
+      for (value, previous_value) in values.iter_mut().zip(previous_values)
+      {
+        reencrypt_fields(value, previous_value)?;
+      }
+    }
+    (Value::Object(map), Value::Object(previous_map)) => {
+      for (key, value) in map.iter_mut() {
+        if let Some(previous_value) = previous_map.get(key) {
+          reencrypt_fields(value, previous_value)?;
+        }
+      }
+    }
+    _ => {}
+  }
+
+  Ok(())
+}
@@ -0,0 +1,157 @@
+use std::{collections::HashSet, fs, path::PathBuf};
+
+use anyhow::{bail, Context};
+use serde_json::Value;
+
+use crate::common::PathExt;
+
+/// Key for inheriting from one or more base configs (e.g.
+/// `"extends": "./base.zebar.json"`).
+const EXTENDS_KEY: &str = "extends";
+
+/// Key for replacing an object with the contents of a JSON fragment (e.g.
+/// `"providers": { "$include": "../shared/providers.json" }`).
+const INCLUDE_KEY: &str = "$include";
+
+/// Resolves `extends` and `$include` references in config files.
+///
+/// Base configs are deep-merged in order, with the extending config's
+/// values taking precedence. Objects are merged key-by-key, whereas
+/// arrays and other values are replaced. Paths are relative to the file
+/// they're referenced from.
+#[derive(Debug, Default)]
+pub struct ConfigResolver {
+  /// Files currently being resolved, for detecting circular references.
+  stack: Vec<PathBuf>,
+
+  /// All files that have been referenced via `extends`.
+  pub base_paths: HashSet<PathBuf>,
+}
+
+impl ConfigResolver {
+  /// Reads the config at the given path and resolves its references.
+  pub fn resolve(&mut self, path: &PathBuf) -> anyhow::Result<Value> {
+    let path = path.to_absolute().with_context(|| {
+      format!("Config file not found at '{}'.", path.display())
+    })?;
+
+    if self.stack.contains(&path) {
+      bail!("Circular reference to config file '{}'.", path.display());
+    }
+
+    self.stack.push(path.clone());
+    let res = self.resolve_file(&path);
+    self.stack.pop();
+
+    res
+  }
+
+  fn resolve_file(&mut self, path: &PathBuf) -> anyhow::Result<Value> {
+    let dir = path
+      .parent()
+      .context("Config file has no parent directory.")?
+      .to_path_buf();
+
+    // Encrypted values are kept as-is, and are only decrypted once
+    // they're used (e.g. when creating a provider).
+    let content = fs::read_to_string(path).with_context(|| {
+      format!("Failed to read file: {}", path.display())
+    })?;
+
+    let mut value: Value =
+      serde_json::from_str(&content).with_context(|| {
+        format!("Failed to parse JSON from file: {}", path.display())
+      })?;
+
+    self.resolve_includes(&mut value, &dir)?;
+
+    let base_paths = match value.get(EXTENDS_KEY) {
+      None => vec![],
+      Some(Value::String(base_path)) => vec![base_path.clone()],
+      Some(Value::Array(base_paths)) => base_paths
+        .iter()
+        .map(|base_path| base_path.as_str().map(String::from))
+        .collect::<Option<Vec<_>>>()
+        .context("`extends` must be a path or an array of paths.")?,
+      Some(_) => bail!("`extends` must be a path or an array of paths."),
+    };
+
+    let mut merged = Value::Object(Default::default());
+
+    for base_path in base_paths {
+      let base_path = dir.join(base_path);
+      let mut base = self.resolve(&base_path)?;
+      self.base_paths.insert(base_path.to_absolute()?);
+
+      // The `extends` of the base config has already been resolved.
+      if let Value::Object(base) = &mut base {
+        base.remove(EXTENDS_KEY);
+      }
+
+      deep_merge(&mut merged, base);
+    }
+
+    deep_merge(&mut merged, value);
+
+    Ok(merged)
+  }
+
+  /// Replaces objects that have an `$include` key with the contents of
+  /// the referenced file. Other keys of the object are merged on top.
+  fn resolve_includes(
+    &mut self,
+    value: &mut Value,
+    dir: &PathBuf,
+  ) -> anyhow::Result<()> {
+    match value {
+      Value::Object(map) => {
+        for child in map.values_mut() {
+          self.resolve_includes(child, dir)?;
+        }
+
+        let Some(include_path) = map.remove(INCLUDE_KEY) else {
+          return Ok(());
+        };
+
+        let include_path = include_path
+          .as_str()
+          .context("`$include` must be a path.")?;
+
+        let overrides = std::mem::take(map);
+        let mut included = self.resolve(&dir.join(include_path))?;
+
+        if !overrides.is_empty() {
+          deep_merge(&mut included, Value::Object(overrides));
+        }
+
+        *value = included;
+      }
+      Value::Array(items) => {
+        for item in items {
+          self.resolve_includes(item, dir)?;
+        }
+      }
+      _ => {}
+    }
+
+    Ok(())
+  }
+}
+
+/// Recursively merges `overrides` into `base`. Objects are merged
+/// key-by-key, whereas other values are replaced.
+fn deep_merge(base: &mut Value, overrides: Value) {
+  match (base, overrides) {
+    (Value::Object(base), Value::Object(overrides)) => {
+      for (key, value) in overrides {
+        match base.get_mut(&key) {
+          Some(base_value) => deep_merge(base_value, value),
+          None => {
+            base.insert(key, value);
+          }
+        }
+      }
+    }
+    (base, overrides) => *base = overrides,
+  }
+}
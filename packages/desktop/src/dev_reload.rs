@@ -0,0 +1,180 @@
+use std::{
+  collections::{hash_map::DefaultHasher, HashMap},
+  fs,
+  hash::{Hash, Hasher},
+  path::{Path, PathBuf},
+  sync::Arc,
+  time::Duration,
+};
+
+use tauri::{AppHandle, Manager};
+use tokio::{sync::Mutex, task, time};
+use tracing::{info, warn};
+
+use crate::widget_factory::WidgetFactory;
+
+/// How often to check widget directories for changes.
+const POLL_INTERVAL: Duration = Duration::from_millis(1000);
+
+/// Max number of files to check per widget directory. Avoids slow scans
+/// of directories that weren't meant to be watched (e.g. a home dir).
+const MAX_FILES: usize = 5000;
+
+/// Directories that are skipped when checking for changes.
+const IGNORED_DIRS: [&str; 3] = ["node_modules", ".git", "target"];
+
+/// Reloads the webviews of widgets with `dev_mode` enabled when a file
+/// in their HTML file's directory changes.
+///
+/// Gives a live-reload loop for plain HTML widgets without needing a
+/// dev server.
+pub struct DevReload {
+  app_handle: AppHandle,
+
+  /// Reference to `WidgetFactory`.
+  widget_factory: Arc<WidgetFactory>,
+
+  /// Last seen signature of each watched directory.
+  signatures: Mutex<HashMap<PathBuf, u64>>,
+}
+
+impl DevReload {
+  pub fn new(
+    app_handle: &AppHandle,
+    widget_factory: Arc<WidgetFactory>,
+  ) -> Arc<Self> {
+    let dev_reload = Arc::new(Self {
+      app_handle: app_handle.clone(),
+      widget_factory,
+      signatures: Mutex::new(HashMap::new()),
+    });
+
+    dev_reload.clone().watch();
+    dev_reload
+  }
+
+  /// Periodically checks watched directories for changes.
+  fn watch(self: Arc<Self>) {
+    task::spawn(async move {
+      let mut interval = time::interval(POLL_INTERVAL);
+      interval.set_missed_tick_behavior(time::MissedTickBehavior::Skip);
+
+      loop {
+        interval.tick().await;
+        self.check().await;
+      }
+    });
+  }
+
+  async fn check(&self) {
+    // Widget ID's of widgets in dev mode, grouped by their HTML file's
+    // directory.
+    let widget_ids_by_dir = self
+      .widget_factory
+      .states()
+      .await
+      .into_values()
+      .filter(|state| state.config.dev_mode)
+      .filter_map(|state| {
+        let dir = state.html_path.parent()?.to_path_buf();
+        Some((dir, state.id))
+      })
+      .fold(
+        HashMap::<PathBuf, Vec<String>>::new(),
+        |mut acc, (dir, id)| {
+          acc.entry(dir).or_default().push(id);
+          acc
+        },
+      );
+
+    let mut signatures = self.signatures.lock().await;
+
+    // Stop watching directories without any open widgets.
+    signatures.retain(|dir, _| widget_ids_by_dir.contains_key(dir));
+
+    for (dir, widget_ids) in widget_ids_by_dir {
+      let signature = {
+        let dir = dir.clone();
+        task::spawn_blocking(move || dir_signature(&dir)).await
+      };
+
+      let Ok(signature) = signature else {
+        continue;
+      };
+
+      let prev_signature = signatures.insert(dir.clone(), signature);
+
+      // Widgets are only reloaded on changes after they were first
+      // seen.
+      match prev_signature {
+        Some(prev_signature) if prev_signature != signature => {}
+        _ => continue,
+      }
+
+      info!("Files changed in {}. Reloading widgets.", dir.display());
+
+      for widget_id in widget_ids {
+        let Some(window) = self.app_handle.get_webview_window(&widget_id)
+        else {
+          continue;
+        };
+
+        self.widget_factory.stop_providers(&widget_id).await;
+
+        if let Err(err) = window.eval("location.reload();") {
+          warn!("Failed to reload widget #{}: {:?}", widget_id, err);
+        }
+      }
+    }
+  }
+}
+
+/// Gets a hash of the paths, sizes, and modification times of files in
+/// a directory (recursively).
+fn dir_signature(dir: &Path) -> u64 {
+  let mut hasher = DefaultHasher::new();
+  let mut pending_dirs = vec![dir.to_path_buf()];
+  let mut file_count = 0;
+
+  while let Some(dir) = pending_dirs.pop() {
+    let Ok(entries) = fs::read_dir(&dir) else {
+      continue;
+    };
+
+    // Sort entries, since `read_dir` doesn't guarantee an order.
+    let mut entries = entries.flatten().collect::<Vec<_>>();
+    entries.sort_by_key(|entry| entry.path());
+
+    for entry in entries {
+      let Ok(metadata) = entry.metadata() else {
+        continue;
+      };
+
+      let path = entry.path();
+
+      if metadata.is_dir() {
+        let is_ignored = IGNORED_DIRS
+          .iter()
+          .any(|ignored| entry.file_name() == *ignored);
+
+        if !is_ignored {
+          pending_dirs.push(path);
+        }
+
+        continue;
+      }
+
+      path.hash(&mut hasher);
+      metadata.len().hash(&mut hasher);
+      metadata.modified().ok().hash(&mut hasher);
+
+      file_count += 1;
+
+      if file_count >= MAX_FILES {
+        return hasher.finish();
+      }
+    }
+  }
+
+  hasher.finish()
+}
@@ -0,0 +1,164 @@
+use anyhow::bail;
+use tracing::info;
+
+use crate::{
+  config::{DisplayProfile, DisplayProfileMonitor},
+  monitor_state::Monitor,
+};
+
+/// Captures the given monitor arrangement into a named profile.
+///
+/// Monitors without a device name are skipped, since they cannot be
+/// matched when re-applying the profile.
+pub fn capture_display_profile(
+  name: &str,
+  monitors: &[Monitor],
+) -> DisplayProfile {
+  DisplayProfile {
+    name: name.to_string(),
+    monitors: monitors
+      .iter()
+      .filter_map(|monitor| {
+        Some(DisplayProfileMonitor {
+          name: monitor.name.clone()?,
+          is_primary: monitor.is_primary,
+          x: monitor.x,
+          y: monitor.y,
+          width: monitor.width,
+          height: monitor.height,
+        })
+      })
+      .collect(),
+  }
+}
+
+/// Applies the monitor arrangement of the given profile.
+///
+/// Widgets are relaunched afterwards by `MonitorState` detecting the
+/// change in monitors.
+pub fn apply_display_profile(
+  profile: &DisplayProfile,
+) -> anyhow::Result<()> {
+  info!("Applying display profile '{}'.", profile.name);
+
+  if profile.monitors.is_empty() {
+    bail!("Display profile '{}' has no monitors.", profile.name);
+  }
+
+  #[cfg(target_os = "windows")]
+  {
+    apply_windows(profile)
+  }
+
+  #[cfg(target_os = "linux")]
+  {
+    apply_xrandr(profile)
+  }
+
+  #[cfg(target_os = "macos")]
+  {
+    bail!("Display profiles are not yet supported on MacOS.")
+  }
+}
+
+/// Applies the profile via `ChangeDisplaySettingsExW`. Changes are first
+/// staged for each monitor and then applied together.
+#[cfg(target_os = "windows")]
+fn apply_windows(profile: &DisplayProfile) -> anyhow::Result<()> {
+  use windows::{
+    core::HSTRING,
+    Win32::Graphics::Gdi::{
+      ChangeDisplaySettingsExW, EnumDisplaySettingsW, CDS_NORESET,
+      CDS_SET_PRIMARY, CDS_TYPE, CDS_UPDATEREGISTRY, DEVMODEW,
+      DISP_CHANGE_SUCCESSFUL, DM_PELSHEIGHT, DM_PELSWIDTH, DM_POSITION,
+      ENUM_CURRENT_SETTINGS,
+    },
+  };
+
+  for monitor in &profile.monitors {
+    let device_name = HSTRING::from(monitor.name.as_str());
+
+    let mut dev_mode = DEVMODEW {
+      dmSize: std::mem::size_of::<DEVMODEW>() as u16,
+      ..Default::default()
+    };
+
+    if !unsafe {
+      EnumDisplaySettingsW(
+        &device_name,
+        ENUM_CURRENT_SETTINGS,
+        &mut dev_mode,
+      )
+    }
+    .as_bool()
+    {
+      bail!("Monitor '{}' is not connected.", monitor.name);
+    }
+
+    dev_mode.dmFields = DM_POSITION | DM_PELSWIDTH | DM_PELSHEIGHT;
+    dev_mode.dmPelsWidth = monitor.width;
+    dev_mode.dmPelsHeight = monitor.height;
+    dev_mode.Anonymous1.Anonymous2.dmPosition.x = monitor.x;
+    dev_mode.Anonymous1.Anonymous2.dmPosition.y = monitor.y;
+
+    let mut flags = CDS_UPDATEREGISTRY | CDS_NORESET;
+    if monitor.is_primary {
+      flags |= CDS_SET_PRIMARY;
+    }
+
+    let res = unsafe {
+      ChangeDisplaySettingsExW(
+        &device_name,
+        Some(&dev_mode),
+        None,
+        flags,
+        None,
+      )
+    };
+
+    if res != DISP_CHANGE_SUCCESSFUL {
+      bail!("Failed to stage display settings for '{}'.", monitor.name);
+    }
+  }
+
+  // Apply all staged changes at once.
+  let res = unsafe {
+    ChangeDisplaySettingsExW(None, None, None, CDS_TYPE(0), None)
+  };
+
+  if res != DISP_CHANGE_SUCCESSFUL {
+    bail!("Failed to apply display settings.");
+  }
+
+  Ok(())
+}
+
+/// Applies the profile via the `xrandr` CLI.
+#[cfg(target_os = "linux")]
+fn apply_xrandr(profile: &DisplayProfile) -> anyhow::Result<()> {
+  use anyhow::Context;
+
+  let mut command = std::process::Command::new("xrandr");
+
+  for monitor in &profile.monitors {
+    command
+      .arg("--output")
+      .arg(&monitor.name)
+      .arg("--mode")
+      .arg(format!("{}x{}", monitor.width, monitor.height))
+      .arg("--pos")
+      .arg(format!("{}x{}", monitor.x, monitor.y));
+
+    if monitor.is_primary {
+      command.arg("--primary");
+    }
+  }
+
+  let status = command.status().context("Unable to run `xrandr`.")?;
+
+  if !status.success() {
+    bail!("`xrandr` exited with {}.", status);
+  }
+
+  Ok(())
+}
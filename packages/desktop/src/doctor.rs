@@ -0,0 +1,285 @@
+use std::{collections::HashMap, fmt, fs, path::PathBuf};
+
+use serde_json::Value;
+use tauri::AppHandle;
+
+use crate::{
+  config::{Config, SettingsConfig},
+  providers::{check_provider_endpoints, parse_provider_config},
+  secrets::SecretAccess,
+  webview_runtime::check_webview_runtime,
+};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum CheckStatus {
+  Pass,
+  Warn,
+  Fail,
+}
+
+/// Result of a single diagnostic check.
+#[derive(Debug)]
+struct Check {
+  status: CheckStatus,
+  name: String,
+  detail: String,
+}
+
+/// Pass/fail report of the checks run by `zebar doctor`.
+#[derive(Debug, Default)]
+pub struct DoctorReport {
+  checks: Vec<Check>,
+}
+
+impl DoctorReport {
+  /// Whether any check failed.
+  pub fn has_failures(&self) -> bool {
+    self.count(CheckStatus::Fail) > 0
+  }
+
+  fn count(&self, status: CheckStatus) -> usize {
+    self
+      .checks
+      .iter()
+      .filter(|check| check.status == status)
+      .count()
+  }
+
+  fn push(
+    &mut self,
+    status: CheckStatus,
+    name: impl Into<String>,
+    detail: impl Into<String>,
+  ) {
+    self.checks.push(Check {
+      status,
+      name: name.into(),
+      detail: detail.into(),
+    });
+  }
+}
+
+impl fmt::Display for DoctorReport {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    for check in &self.checks {
+      let label = match check.status {
+        CheckStatus::Pass => "PASS",
+        CheckStatus::Warn => "WARN",
+        CheckStatus::Fail => "FAIL",
+      };
+
+      writeln!(f, "[{}] {}: {}", label, check.name, check.detail)?;
+    }
+
+    writeln!(
+      f,
+      "\n{} passed, {} warnings, {} failed.",
+      self.count(CheckStatus::Pass),
+      self.count(CheckStatus::Warn),
+      self.count(CheckStatus::Fail)
+    )
+  }
+}
+
+/// Checks for common problems (e.g. a missing webview runtime or invalid
+/// configs).
+///
+/// Doesn't require Zebar to be running, and doesn't modify the config
+/// directory.
+pub async fn run_doctor(
+  app_handle: &AppHandle,
+  config_dir_override: Option<PathBuf>,
+) -> DoctorReport {
+  let mut report = DoctorReport::default();
+
+  match check_webview_runtime() {
+    Ok(Some(version)) => {
+      report.push(CheckStatus::Pass, "Webview runtime", version)
+    }
+    Ok(None) => {
+      report.push(CheckStatus::Pass, "Webview runtime", "Provided by OS.")
+    }
+    Err(err) => report.push(
+      CheckStatus::Fail,
+      "Webview runtime",
+      format!("{:#}", err),
+    ),
+  }
+
+  let settings =
+    check_configs(app_handle, config_dir_override, &mut report);
+
+  check_window_managers(&mut report).await;
+
+  let proxy_config = settings.and_then(|settings| settings.proxy);
+
+  for (provider_type, url, res) in
+    check_provider_endpoints(proxy_config).await
+  {
+    let name = format!("Endpoint of '{}' provider", provider_type);
+
+    match res {
+      Ok(()) => report.push(CheckStatus::Pass, name, url),
+      Err(err) => report.push(
+        CheckStatus::Fail,
+        name,
+        format!("Unable to reach {}: {:#}", url, err),
+      ),
+    }
+  }
+
+  report
+}
+
+/// Checks that the config directory is readable, and that the settings
+/// and widget configs are valid.
+///
+/// Returns the settings if they're valid.
+fn check_configs(
+  app_handle: &AppHandle,
+  config_dir_override: Option<PathBuf>,
+  report: &mut DoctorReport,
+) -> Option<SettingsConfig> {
+  let config_dir =
+    match Config::resolve_config_dir(app_handle, config_dir_override) {
+      Ok(config_dir) => config_dir,
+      Err(err) => {
+        report.push(
+          CheckStatus::Fail,
+          "Config directory",
+          format!("{:#}", err),
+        );
+        return None;
+      }
+    };
+
+  if let Err(err) = fs::read_dir(&config_dir) {
+    report.push(
+      CheckStatus::Fail,
+      "Config directory",
+      format!("Unable to read {}: {}", config_dir.display(), err),
+    );
+    return None;
+  }
+
+  report.push(
+    CheckStatus::Pass,
+    "Config directory",
+    config_dir.display().to_string(),
+  );
+
+  let settings = match Config::read_settings(&config_dir) {
+    Ok(Some(settings)) => {
+      report.push(CheckStatus::Pass, "Settings", "Valid.");
+      Some(settings)
+    }
+    Ok(None) => {
+      report.push(
+        CheckStatus::Warn,
+        "Settings",
+        "No settings.json found. It's created on first launch.",
+      );
+      None
+    }
+    Err(err) => {
+      report.push(CheckStatus::Fail, "Settings", format!("{:#}", err));
+      None
+    }
+  };
+
+  if let Some(settings) = &settings {
+    for alert in &settings.alerts {
+      check_provider_configs(
+        &format!("Alert '{}'", alert.name),
+        &alert.providers,
+        &SecretAccess::Any,
+        report,
+      );
+    }
+
+    if let Some(history) = &settings.history {
+      check_provider_configs(
+        "History",
+        &history.providers,
+        &SecretAccess::Any,
+        report,
+      );
+    }
+  }
+
+  match Config::parse_widget_configs(&config_dir) {
+    Ok(results) => {
+      for (config_path, res) in results {
+        let name = format!("Widget config {}", config_path.display());
+
+        match res {
+          Ok(config) => {
+            report.push(CheckStatus::Pass, &name, "Valid.");
+
+            if let Some(window_title) = &config.window_title {
+              check_provider_configs(
+                &name,
+                &window_title.providers,
+                &config.secret_access(),
+                report,
+              );
+            }
+          }
+          Err(err) => {
+            report.push(CheckStatus::Fail, name, format!("{:#}", err))
+          }
+        }
+      }
+    }
+    Err(err) => report.push(
+      CheckStatus::Fail,
+      "Widget configs",
+      format!("{:#}", err),
+    ),
+  }
+
+  settings
+}
+
+/// Adds a failed check for each invalid provider config. Valid configs
+/// aren't listed to keep the report short.
+fn check_provider_configs(
+  source: &str,
+  providers: &HashMap<String, Value>,
+  access: &SecretAccess,
+  report: &mut DoctorReport,
+) {
+  for (name, config) in providers {
+    if let Err(err) = parse_provider_config(config.clone(), access) {
+      report.push(
+        CheckStatus::Fail,
+        format!("{} provider '{}'", source, name),
+        format!("{:#}", err),
+      );
+    }
+  }
+}
+
+/// Checks whether the IPC of supported window managers is reachable.
+/// Unreachable ones are only warnings, since either might not be used.
+#[cfg(target_os = "windows")]
+async fn check_window_managers(report: &mut DoctorReport) {
+  use crate::{config::WaitForTarget, startup_wait::is_available};
+
+  for (name, target) in [
+    ("GlazeWM IPC", WaitForTarget::Glazewm),
+    ("Komorebi socket", WaitForTarget::Komorebi),
+  ] {
+    match is_available(&target).await {
+      true => report.push(CheckStatus::Pass, name, "Reachable."),
+      false => report.push(
+        CheckStatus::Warn,
+        name,
+        "Not reachable. Ignore if it's not installed.",
+      ),
+    }
+  }
+}
+
+#[cfg(not(target_os = "windows"))]
+async fn check_window_managers(_report: &mut DoctorReport) {}
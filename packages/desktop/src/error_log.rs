@@ -0,0 +1,187 @@
+use std::{
+  collections::VecDeque,
+  fmt::Debug,
+  fs::File,
+  path::PathBuf,
+  sync::{Arc, Mutex},
+};
+
+use tokio::sync::broadcast;
+use tracing::{
+  field::{Field, Visit},
+  Event, Level, Subscriber,
+};
+use tracing_subscriber::{layer::Context, Layer};
+
+/// Max number of recent errors to keep in memory.
+const MAX_ENTRIES: usize = 10;
+
+/// A recently logged error.
+#[derive(Clone, Debug)]
+pub struct ErrorEntry {
+  /// Module path of where the error was logged.
+  pub target: String,
+
+  /// Formatted error message.
+  pub message: String,
+
+  /// Number of consecutive times the same error was logged.
+  pub count: usize,
+}
+
+/// In-memory buffer of recently logged errors, along with the path to
+/// the log file.
+pub struct ErrorLog {
+  /// Recent errors, ordered from oldest to newest.
+  entries: Mutex<VecDeque<ErrorEntry>>,
+
+  /// Path to the log file for the current session.
+  log_path: PathBuf,
+
+  _change_rx: broadcast::Receiver<()>,
+
+  /// Sender channel for when a new error is recorded or the errors are
+  /// cleared.
+  pub change_tx: broadcast::Sender<()>,
+}
+
+impl ErrorLog {
+  pub fn new(log_path: PathBuf) -> Self {
+    let (change_tx, _change_rx) = broadcast::channel(16);
+
+    Self {
+      entries: Mutex::new(VecDeque::with_capacity(MAX_ENTRIES)),
+      log_path,
+      _change_rx,
+      change_tx,
+    }
+  }
+
+  /// Creates the log file, truncating any log from a previous session.
+  pub fn create_log_file(&self) -> anyhow::Result<File> {
+    if let Some(parent) = self.log_path.parent() {
+      std::fs::create_dir_all(parent)?;
+    }
+
+    Ok(File::create(&self.log_path)?)
+  }
+
+  /// Returns recent errors, ordered from newest to oldest.
+  pub fn entries(&self) -> Vec<ErrorEntry> {
+    let entries = self.entries.lock().unwrap();
+    entries.iter().rev().cloned().collect()
+  }
+
+  /// Whether there are any recorded errors.
+  pub fn is_empty(&self) -> bool {
+    self.entries.lock().unwrap().is_empty()
+  }
+
+  /// Records an error.
+  ///
+  /// Consecutive duplicates are collapsed into a single entry, and only
+  /// new entries notify subscribers. This avoids feedback loops where
+  /// handling the change itself logs the same error.
+  pub fn push(&self, target: String, message: String) {
+    let mut entries = self.entries.lock().unwrap();
+
+    if let Some(last) = entries.back_mut() {
+      if last.target == target && last.message == message {
+        last.count += 1;
+        return;
+      }
+    }
+
+    if entries.len() == MAX_ENTRIES {
+      entries.pop_front();
+    }
+
+    entries.push_back(ErrorEntry {
+      target,
+      message,
+      count: 1,
+    });
+
+    drop(entries);
+    let _ = self.change_tx.send(());
+  }
+
+  /// Removes all recorded errors.
+  pub fn clear(&self) {
+    self.entries.lock().unwrap().clear();
+    let _ = self.change_tx.send(());
+  }
+
+  /// Opens the log file with the default application for the OS.
+  pub fn open_log_file(&self) -> anyhow::Result<()> {
+    #[cfg(target_os = "windows")]
+    {
+      std::process::Command::new("explorer")
+        .arg(self.log_path.clone())
+        .spawn()?;
+    }
+
+    #[cfg(target_os = "macos")]
+    {
+      std::process::Command::new("open")
+        .arg(self.log_path.clone())
+        .spawn()?;
+    }
+
+    #[cfg(target_os = "linux")]
+    {
+      std::process::Command::new("xdg-open")
+        .arg(self.log_path.clone())
+        .spawn()?;
+    }
+
+    Ok(())
+  }
+
+  /// Returns a `tracing` layer that records error events to this log.
+  pub fn layer(self: &Arc<Self>) -> ErrorLogLayer {
+    ErrorLogLayer {
+      error_log: self.clone(),
+    }
+  }
+}
+
+/// `tracing` layer that forwards error events to an `ErrorLog`.
+pub struct ErrorLogLayer {
+  error_log: Arc<ErrorLog>,
+}
+
+impl<S: Subscriber> Layer<S> for ErrorLogLayer {
+  fn on_event(&self, event: &Event<'_>, _: Context<'_, S>) {
+    if *event.metadata().level() != Level::ERROR {
+      return;
+    }
+
+    let mut visitor = MessageVisitor::default();
+    event.record(&mut visitor);
+
+    self
+      .error_log
+      .push(event.metadata().target().to_string(), visitor.message);
+  }
+}
+
+/// Extracts the `message` field from a `tracing` event.
+#[derive(Default)]
+struct MessageVisitor {
+  message: String,
+}
+
+impl Visit for MessageVisitor {
+  fn record_debug(&mut self, field: &Field, value: &dyn Debug) {
+    if field.name() == "message" {
+      self.message = format!("{:?}", value);
+    }
+  }
+
+  fn record_str(&mut self, field: &Field, value: &str) {
+    if field.name() == "message" {
+      self.message = value.to_string();
+    }
+  }
+}
@@ -0,0 +1,302 @@
+use std::{path::Path, process::Stdio, sync::Arc, time::Duration};
+
+use anyhow::{bail, Context};
+use serde::Serialize;
+use sysinfo::System;
+use tokio::{
+  process::Command,
+  sync::{broadcast, Mutex},
+  task, time,
+};
+use tracing::info;
+
+use crate::{common::no_window_command, config::Config};
+
+/// How often the status of the repository is refreshed.
+const STATUS_INTERVAL: Duration = Duration::from_secs(30);
+
+/// How long a git command can run before it's killed (e.g. if the
+/// remote is unreachable).
+const GIT_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// Max number of changed files to list in generated commit messages.
+const MAX_LISTED_FILES: usize = 10;
+
+/// Status of the git repository that the config directory is in.
+///
+/// Only changes within the config directory are counted, so that the
+/// config can live within a larger repository (e.g. of dotfiles).
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GitSyncStatus {
+  /// Name of the checked out branch. `None` if no branch is checked out
+  /// (i.e. a detached `HEAD`).
+  pub branch: Option<String>,
+
+  /// Paths of files with uncommitted changes, including untracked files.
+  pub changed_files: Vec<String>,
+
+  /// Whether the branch has an upstream branch to pull from and push to.
+  pub has_upstream: bool,
+
+  /// Number of local commits that haven't been pushed.
+  pub ahead: u32,
+
+  /// Number of upstream commits that haven't been pulled, as of the last
+  /// fetch.
+  pub behind: u32,
+}
+
+impl GitSyncStatus {
+  /// Whether there are uncommitted changes.
+  pub fn is_dirty(&self) -> bool {
+    !self.changed_files.is_empty()
+  }
+}
+
+/// Pulls and pushes the config directory via git, for syncing widget
+/// setups across machines.
+///
+/// Only active if `gitSync` is enabled in the settings and the config
+/// directory is within a git repository.
+pub struct GitSync {
+  config: Arc<Config>,
+
+  _change_rx: broadcast::Receiver<Option<GitSyncStatus>>,
+
+  /// Sender for changes to the repository status (e.g. when files are
+  /// edited or after a pull).
+  pub change_tx: broadcast::Sender<Option<GitSyncStatus>>,
+
+  /// Latest status. `None` if git sync is unavailable.
+  status: Mutex<Option<GitSyncStatus>>,
+
+  /// Held while running a pull or push, so that they can't overlap.
+  operation_lock: Mutex<()>,
+}
+
+impl GitSync {
+  /// Creates a new `GitSync` instance and starts refreshing the
+  /// repository status in a background task.
+  pub fn new(config: Arc<Config>) -> Arc<Self> {
+    let (change_tx, _change_rx) = broadcast::channel(16);
+
+    let git_sync = Arc::new(Self {
+      config,
+      _change_rx,
+      change_tx,
+      status: Mutex::new(None),
+      operation_lock: Mutex::new(()),
+    });
+
+    task::spawn({
+      let git_sync = git_sync.clone();
+
+      async move {
+        let mut interval = time::interval(STATUS_INTERVAL);
+
+        loop {
+          interval.tick().await;
+          git_sync.refresh().await;
+        }
+      }
+    });
+
+    git_sync
+  }
+
+  /// Gets the latest repository status. `None` if git sync is
+  /// unavailable.
+  pub async fn status(&self) -> Option<GitSyncStatus> {
+    self.status.lock().await.clone()
+  }
+
+  /// Re-reads the repository status, and broadcasts it if it changed.
+  pub async fn refresh(&self) {
+    let new_status = match self.config.settings.lock().await.git_sync {
+      true => read_status(&self.config.config_dir).await,
+      false => None,
+    };
+
+    let mut status = self.status.lock().await;
+
+    if *status != new_status {
+      *status = new_status.clone();
+      let _ = self.change_tx.send(new_status);
+    }
+  }
+
+  /// Pulls upstream changes and reloads the configs. Only
+  /// fast-forwards, so that pulling never leaves the config directory
+  /// in a conflicted state.
+  pub async fn pull(&self) -> anyhow::Result<()> {
+    self.ensure_available().await?;
+
+    {
+      let _lock = self.operation_lock.lock().await;
+      run_git(&self.config.config_dir, &["pull", "--ff-only"]).await?;
+    }
+
+    info!("Pulled config changes.");
+    self.config.reload().await?;
+    self.refresh().await;
+
+    Ok(())
+  }
+
+  /// Commits all changes within the config directory with a generated
+  /// message, then pushes to the upstream branch.
+  pub async fn commit_and_push(&self) -> anyhow::Result<()> {
+    let status = self.ensure_available().await?;
+
+    {
+      let _lock = self.operation_lock.lock().await;
+      let config_dir = &self.config.config_dir;
+
+      if status.is_dirty() {
+        let message = commit_message(&status.changed_files);
+        run_git(config_dir, &["add", "--all", "--", "."]).await?;
+        run_git(config_dir, &["commit", "-m", &message, "--", "."])
+          .await?;
+      }
+
+      match status.has_upstream {
+        true => run_git(config_dir, &["push"]).await?,
+        false => {
+          run_git(
+            config_dir,
+            &["push", "--set-upstream", "origin", "HEAD"],
+          )
+          .await?
+        }
+      };
+    }
+
+    info!("Pushed config changes.");
+    self.refresh().await;
+
+    Ok(())
+  }
+
+  /// Gets the current status, or errors if git sync is unavailable.
+  async fn ensure_available(&self) -> anyhow::Result<GitSyncStatus> {
+    self.refresh().await;
+
+    match self.status().await {
+      Some(status) => Ok(status),
+      None => bail!(
+        "Git sync is unavailable. Enable `gitSync` in settings and make \
+        sure the config directory is in a git repository."
+      ),
+    }
+  }
+}
+
+/// Reads the status of the repository that the directory is in. Returns
+/// `None` if it isn't in a repository or git isn't installed.
+async fn read_status(dir: &Path) -> Option<GitSyncStatus> {
+  let output =
+    run_git(dir, &["status", "--porcelain=v1", "--branch", "--", "."])
+      .await
+      .ok()?;
+
+  let mut lines = output.lines();
+
+  // First line is the branch header, e.g. `## main...origin/main
+  // [ahead 1, behind 2]`.
+  let header = lines.next()?.strip_prefix("## ")?;
+
+  let (branch_part, tracking) = match header.split_once(" [") {
+    Some((branch_part, tracking)) => {
+      (branch_part, tracking.trim_end_matches(']'))
+    }
+    None => (header, ""),
+  };
+
+  let (branch, has_upstream) = match branch_part.split_once("...") {
+    Some((branch, _)) => (Some(branch), true),
+    None => (
+      Some(
+        branch_part
+          .strip_prefix("No commits yet on ")
+          .unwrap_or(branch_part),
+      )
+      .filter(|branch| !branch.starts_with("HEAD ")),
+      false,
+    ),
+  };
+
+  let tracking_count = |key: &str| {
+    tracking
+      .split(", ")
+      .find_map(|part| part.strip_prefix(key)?.parse::<u32>().ok())
+      .unwrap_or(0)
+  };
+
+  // Remaining lines are changed files, e.g. ` M settings.json`.
+  let changed_files = lines
+    .filter_map(|line| line.get(3..))
+    .map(String::from)
+    .collect();
+
+  Some(GitSyncStatus {
+    branch: branch.map(String::from),
+    changed_files,
+    has_upstream,
+    ahead: tracking_count("ahead "),
+    behind: tracking_count("behind "),
+  })
+}
+
+/// Generates a commit message listing the changed files.
+fn commit_message(changed_files: &[String]) -> String {
+  let host = System::host_name().unwrap_or_else(|| "unknown host".into());
+
+  let mut message = format!(
+    "Update Zebar config from {} ({} {})\n\n",
+    host,
+    changed_files.len(),
+    if changed_files.len() == 1 {
+      "file"
+    } else {
+      "files"
+    }
+  );
+
+  for path in changed_files.iter().take(MAX_LISTED_FILES) {
+    message += &format!("- {}\n", path);
+  }
+
+  if changed_files.len() > MAX_LISTED_FILES {
+    message +=
+      &format!("- and {} more\n", changed_files.len() - MAX_LISTED_FILES);
+  }
+
+  message
+}
+
+/// Runs git within the given directory and returns its stdout.
+async fn run_git(dir: &Path, args: &[&str]) -> anyhow::Result<String> {
+  let mut command = Command::from(no_window_command("git"));
+  command
+    .arg("-C")
+    .arg(dir)
+    .args(args)
+    // Fail instead of waiting on a credential prompt that can't be
+    // answered.
+    .env("GIT_TERMINAL_PROMPT", "0")
+    .stdin(Stdio::null())
+    .kill_on_drop(true);
+
+  let output = time::timeout(GIT_TIMEOUT, command.output())
+    .await
+    .with_context(|| format!("`git {}` timed out.", args.join(" ")))?
+    .context("Failed to run git. Is it installed?")?;
+
+  if !output.status.success() {
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    bail!("`git {}` failed: {}", args[0], stderr.trim());
+  }
+
+  Ok(String::from_utf8_lossy(&output.stdout).to_string())
+}
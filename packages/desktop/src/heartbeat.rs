@@ -0,0 +1,171 @@
+use std::{collections::HashMap, sync::Arc, time::Duration};
+
+use serde::Serialize;
+use tauri::{AppHandle, Emitter};
+use tokio::{
+  sync::Mutex,
+  task,
+  time::{self, Instant},
+};
+use tracing::{error, info, warn};
+
+use crate::{config::Config, widget_factory::WidgetFactory};
+
+/// How often widgets send a heartbeat (in milliseconds). Passed to the
+/// widget's initialization script.
+pub const HEARTBEAT_INTERVAL_MS: u64 = 5000;
+
+/// Payload of the `widget-unresponsive` event.
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WidgetUnresponsiveEvent {
+  pub id: String,
+
+  /// Time in milliseconds since the last heartbeat.
+  pub elapsed: u64,
+
+  /// Whether the widget is being relaunched.
+  pub is_reloading: bool,
+}
+
+/// Detects widgets whose webview stopped responding (e.g. hung JS or a
+/// crashed renderer) via periodic heartbeats.
+///
+/// Widgets are only tracked after their first heartbeat, so that pages
+/// without IPC access aren't reported as unresponsive.
+pub struct WidgetHeartbeats {
+  app_handle: AppHandle,
+
+  /// Reference to `Config`.
+  config: Arc<Config>,
+
+  /// Reference to `WidgetFactory`.
+  widget_factory: Arc<WidgetFactory>,
+
+  /// Last heartbeat of each widget, and whether it's been reported as
+  /// unresponsive since.
+  heartbeats: Mutex<HashMap<String, (Instant, bool)>>,
+
+  /// Latest JS heap size in bytes reported by each widget. Only
+  /// available in Chromium-based webviews (i.e. WebView2).
+  memory_usages: Mutex<HashMap<String, u64>>,
+}
+
+impl WidgetHeartbeats {
+  pub fn new(
+    app_handle: &AppHandle,
+    config: Arc<Config>,
+    widget_factory: Arc<WidgetFactory>,
+  ) -> Arc<Self> {
+    let heartbeats = Arc::new(Self {
+      app_handle: app_handle.clone(),
+      config,
+      widget_factory,
+      heartbeats: Mutex::new(HashMap::new()),
+      memory_usages: Mutex::new(HashMap::new()),
+    });
+
+    heartbeats.clone().watch();
+    heartbeats
+  }
+
+  /// Records a heartbeat from the given widget, along with its JS heap
+  /// size if the webview exposes it.
+  pub async fn beat(&self, widget_id: &str, memory_usage: Option<u64>) {
+    if let Some(memory_usage) = memory_usage {
+      self
+        .memory_usages
+        .lock()
+        .await
+        .insert(widget_id.to_string(), memory_usage);
+    }
+
+    let prev = self
+      .heartbeats
+      .lock()
+      .await
+      .insert(widget_id.to_string(), (Instant::now(), false));
+
+    if let Some((_, true)) = prev {
+      info!("Widget #{} is responsive again.", widget_id);
+    }
+  }
+
+  /// Gets the latest reported JS heap size of each widget.
+  pub async fn memory_usages(&self) -> HashMap<String, u64> {
+    self.memory_usages.lock().await.clone()
+  }
+
+  /// Periodically checks for widgets that have exceeded the timeout.
+  fn watch(self: Arc<Self>) {
+    task::spawn(async move {
+      let mut interval =
+        time::interval(Duration::from_millis(HEARTBEAT_INTERVAL_MS));
+
+      interval.set_missed_tick_behavior(time::MissedTickBehavior::Skip);
+
+      loop {
+        interval.tick().await;
+        self.check().await;
+      }
+    });
+  }
+
+  async fn check(&self) {
+    let heartbeat_config =
+      self.config.settings.lock().await.heartbeat.clone();
+    let timeout = Duration::from_millis(heartbeat_config.timeout);
+    let widget_states = self.widget_factory.states().await;
+
+    let unresponsive_ids = {
+      let mut heartbeats = self.heartbeats.lock().await;
+
+      // Stop tracking widgets that have been closed.
+      heartbeats.retain(|id, _| widget_states.contains_key(id));
+
+      self
+        .memory_usages
+        .lock()
+        .await
+        .retain(|id, _| widget_states.contains_key(id));
+
+      heartbeats
+        .iter_mut()
+        .filter(|(_, (last_beat, is_reported))| {
+          !*is_reported && last_beat.elapsed() > timeout
+        })
+        .map(|(id, (last_beat, is_reported))| {
+          *is_reported = true;
+          (id.clone(), last_beat.elapsed())
+        })
+        .collect::<Vec<_>>()
+    };
+
+    for (widget_id, elapsed) in unresponsive_ids {
+      warn!(
+        "Widget #{} has not responded for {}s.",
+        widget_id,
+        elapsed.as_secs()
+      );
+
+      let _ = self.app_handle.emit(
+        "widget-unresponsive",
+        WidgetUnresponsiveEvent {
+          id: widget_id.clone(),
+          elapsed: elapsed.as_millis() as u64,
+          is_reloading: heartbeat_config.auto_reload,
+        },
+      );
+
+      if heartbeat_config.auto_reload {
+        self.heartbeats.lock().await.remove(&widget_id);
+
+        if let Err(err) =
+          self.widget_factory.relaunch_by_ids(&vec![widget_id]).await
+        {
+          error!("Failed to relaunch unresponsive widget: {:?}", err);
+        }
+      }
+    }
+  }
+}
@@ -0,0 +1,495 @@
+use std::{
+  collections::HashMap,
+  path::{Path, PathBuf},
+  sync::{Arc, Mutex as StdMutex},
+  time::Duration,
+};
+
+use anyhow::{bail, Context};
+use clap::ValueEnum;
+use rusqlite::{params, Connection, OpenFlags};
+use serde::{Deserialize, Serialize};
+use tauri::{AppHandle, Manager};
+use tokio::{
+  sync::Mutex,
+  task::{self, JoinHandle},
+};
+use tracing::{info, warn};
+
+use crate::{
+  common::{AsyncInterval, Expression},
+  config::{Config, HistoryConfig},
+  portable,
+  providers::{parse_provider_configs, ProviderEmission, ProviderManager},
+  secrets::SecretAccess,
+};
+
+/// A recorded value, or the aggregate of the values within a bucket
+/// when queried with a resolution.
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HistoryPoint {
+  /// Unix timestamp in milliseconds. Start of the bucket when queried
+  /// with a resolution.
+  pub timestamp: i64,
+
+  /// Average of the values within the bucket.
+  pub value: f64,
+  pub min: f64,
+  pub max: f64,
+}
+
+/// Records provider metrics to a local SQLite database at a fixed
+/// interval, and serves queries over the recorded samples.
+///
+/// The database is only created once history is enabled in the
+/// settings.
+pub struct HistoryRecorder {
+  app_handle: AppHandle,
+
+  /// Reference to `Config`.
+  config: Arc<Config>,
+
+  /// Reference to `ProviderManager`.
+  manager: Arc<ProviderManager>,
+
+  /// Path to the database file.
+  db_path: Option<PathBuf>,
+
+  /// Database connection. Opened on first use.
+  db: Arc<StdMutex<Option<Connection>>>,
+
+  /// Config that's currently being recorded.
+  history_config: Mutex<Option<HistoryConfig>>,
+
+  /// Active recording state, if history is enabled.
+  state: Mutex<Option<RecordingState>>,
+}
+
+struct RecordingState {
+  /// Parsed expression of each metric, keyed by metric name.
+  metrics: Vec<(String, Expression)>,
+
+  /// Names of the providers, keyed by config hash.
+  provider_names: HashMap<String, String>,
+
+  /// Latest output of each provider, keyed by name.
+  outputs: HashMap<String, serde_json::Value>,
+
+  /// Task that records a sample on each interval.
+  sample_task: JoinHandle<()>,
+}
+
+impl HistoryRecorder {
+  pub fn new(
+    app_handle: &AppHandle,
+    config: Arc<Config>,
+    manager: Arc<ProviderManager>,
+    db_path: Option<PathBuf>,
+  ) -> Arc<Self> {
+    Arc::new(Self {
+      app_handle: app_handle.clone(),
+      config,
+      manager,
+      db_path,
+      db: Arc::new(StdMutex::new(None)),
+      history_config: Mutex::new(None),
+      state: Mutex::new(None),
+    })
+  }
+
+  /// Restarts recording if the history settings changed.
+  pub async fn reload(self: &Arc<Self>) -> anyhow::Result<()> {
+    let new_config = self.config.settings.lock().await.history.clone();
+    let mut history_config = self.history_config.lock().await;
+
+    if *history_config == new_config {
+      return Ok(());
+    }
+
+    self.stop().await;
+    *history_config = new_config.clone();
+
+    if let Some(new_config) = new_config {
+      self.start(new_config).await?;
+    }
+
+    Ok(())
+  }
+
+  async fn start(
+    self: &Arc<Self>,
+    history_config: HistoryConfig,
+  ) -> anyhow::Result<()> {
+    if history_config.interval == 0 {
+      bail!("History interval must be greater than 0.");
+    }
+
+    let metrics = history_config
+      .metrics
+      .iter()
+      .map(|(name, source)| {
+        let expression = Expression::parse(source).with_context(|| {
+          format!("Invalid expression for '{}'.", name)
+        })?;
+
+        for source_name in expression.sources() {
+          if !history_config.providers.contains_key(source_name) {
+            bail!(
+              "Unknown provider '{}' in expression for '{}'.",
+              source_name,
+              name
+            );
+          }
+        }
+
+        anyhow::Ok((name.clone(), expression))
+      })
+      .try_collect::<Vec<_>>()?;
+
+    let provider_configs = parse_provider_configs(
+      &self.app_handle,
+      history_config.providers.clone(),
+      &self.config.settings_path(),
+      SecretAccess::Any,
+    )
+    .await
+    .context("Invalid history providers.")?;
+
+    // Open the database upfront, so that an unwritable path is reported
+    // on start rather than on each sample.
+    let db = self.db.clone();
+    let db_path = self.db_path.clone();
+    task::spawn_blocking(move || {
+      with_db(&db, db_path.as_ref(), |_| Ok(()))
+    })
+    .await??;
+
+    let recorder = self.clone();
+    let interval_ms = history_config.interval;
+    let retention_days = history_config.retention_days;
+
+    let sample_task = task::spawn(async move {
+      let mut interval = AsyncInterval::new(interval_ms);
+
+      // Skip the first tick, since providers won't have emitted yet.
+      interval.tick().await;
+
+      loop {
+        interval.tick().await;
+
+        if let Err(err) = recorder.record_sample(retention_days).await {
+          warn!("Failed to record history sample: {:?}", err);
+        }
+      }
+    });
+
+    let provider_configs = provider_configs
+      .into_iter()
+      .map(|(name, config)| (format!("history:{}", name), name, config))
+      .collect::<Vec<_>>();
+
+    *self.state.lock().await = Some(RecordingState {
+      metrics,
+      provider_names: provider_configs
+        .iter()
+        .map(|(config_hash, name, _)| (config_hash.clone(), name.clone()))
+        .collect(),
+      outputs: HashMap::new(),
+      sample_task,
+    });
+
+    for (config_hash, name, config) in provider_configs {
+      let subscriber = format!("history:{}", name);
+      self
+        .manager
+        .create(config_hash, config, &subscriber)
+        .await?;
+    }
+
+    info!("Started recording history.");
+    Ok(())
+  }
+
+  /// Stops recording and the providers used for it.
+  async fn stop(&self) {
+    let Some(state) = self.state.lock().await.take() else {
+      return;
+    };
+
+    state.sample_task.abort();
+
+    for (config_hash, name) in state.provider_names {
+      let subscriber = format!("history:{}", name);
+
+      if let Err(err) = self.manager.stop(config_hash, &subscriber).await {
+        warn!("Failed to stop history provider: {:?}", err);
+      }
+    }
+  }
+
+  /// Stores the latest output of the emitting provider (if it's used
+  /// for history).
+  pub async fn handle_emission(&self, emission: &ProviderEmission) {
+    let Ok(output) = &emission.result else {
+      return;
+    };
+
+    let mut state = self.state.lock().await;
+
+    let Some(state) = state.as_mut() else {
+      return;
+    };
+
+    if let Some(name) = state.provider_names.get(&emission.config_hash) {
+      let output = serde_json::to_value(output).unwrap_or_default();
+      state.outputs.insert(name.clone(), output);
+    }
+  }
+
+  /// Evaluates each metric against the latest provider outputs and
+  /// writes the numeric values to the database. Samples older than the
+  /// retention period are deleted.
+  async fn record_sample(
+    &self,
+    retention_days: u64,
+  ) -> anyhow::Result<()> {
+    let timestamp = chrono::Utc::now().timestamp_millis();
+
+    let values = {
+      let state = self.state.lock().await;
+
+      let Some(state) = state.as_ref() else {
+        return Ok(());
+      };
+
+      state
+        .metrics
+        .iter()
+        .filter_map(|(name, expression)| {
+          let value = expression.evaluate(&state.outputs).ok()?;
+          Some((name.clone(), value.as_f64()?))
+        })
+        .collect::<Vec<_>>()
+    };
+
+    let cutoff = timestamp
+      - Duration::from_secs(retention_days * 86400).as_millis() as i64;
+
+    let db = self.db.clone();
+    let db_path = self.db_path.clone();
+
+    task::spawn_blocking(move || {
+      with_db(&db, db_path.as_ref(), |conn| {
+        let tx = conn.transaction()?;
+
+        {
+          let mut insert = tx.prepare_cached(
+            "INSERT INTO samples (metric, timestamp, value) VALUES (?1, ?2, ?3)",
+          )?;
+
+          for (metric, value) in &values {
+            insert.execute(params![metric, timestamp, value])?;
+          }
+        }
+
+        tx.execute("DELETE FROM samples WHERE timestamp < ?1", [cutoff])?;
+        tx.commit()?;
+
+        Ok(())
+      })
+    })
+    .await?
+  }
+
+  /// Gets the recorded values of a metric between two Unix timestamps
+  /// in milliseconds.
+  ///
+  /// If a resolution (in milliseconds) is given, values are grouped into
+  /// buckets of that size. Otherwise, the raw samples are returned.
+  pub async fn query(
+    &self,
+    metric: String,
+    start: i64,
+    end: Option<i64>,
+    resolution: Option<u64>,
+  ) -> anyhow::Result<Vec<HistoryPoint>> {
+    if self.history_config.lock().await.is_none() {
+      bail!("History is not enabled in settings.");
+    }
+
+    let end = end.unwrap_or_else(|| chrono::Utc::now().timestamp_millis());
+    let resolution = resolution.unwrap_or(1).max(1) as i64;
+
+    let db = self.db.clone();
+    let db_path = self.db_path.clone();
+
+    task::spawn_blocking(move || {
+      with_db(&db, db_path.as_ref(), |conn| {
+        query_samples(conn, &metric, start, end, resolution)
+      })
+    })
+    .await?
+  }
+
+  /// Gets the recorded values of a metric formatted as CSV or JSON.
+  pub async fn export(
+    &self,
+    metric: String,
+    start: i64,
+    end: Option<i64>,
+    resolution: Option<u64>,
+    format: ExportFormat,
+  ) -> anyhow::Result<String> {
+    let points = self.query(metric, start, end, resolution).await?;
+    format_points(&points, format)
+  }
+}
+
+/// Output format of exported history.
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, ValueEnum)]
+#[serde(rename_all = "snake_case")]
+#[clap(rename_all = "snake_case")]
+pub enum ExportFormat {
+  Csv,
+  Json,
+}
+
+/// Path to the history database. Stored next to the webview data in
+/// portable mode.
+pub fn history_db_path(app_handle: &AppHandle) -> Option<PathBuf> {
+  portable::data_dir()
+    .or_else(|| app_handle.path().app_local_data_dir().ok())
+    .map(|dir| dir.join("history.db"))
+}
+
+/// Exports the recorded values of a metric directly from the database
+/// file. Used by the CLI, which doesn't require a running instance.
+pub fn export_from_file(
+  db_path: &Path,
+  metric: &str,
+  start: i64,
+  end: Option<i64>,
+  resolution: Option<u64>,
+  format: ExportFormat,
+) -> anyhow::Result<String> {
+  if !db_path.exists() {
+    bail!(
+      "No history recorded at {}. Enable `history` in settings first.",
+      db_path.display()
+    );
+  }
+
+  let conn =
+    Connection::open_with_flags(db_path, OpenFlags::SQLITE_OPEN_READ_ONLY)
+      .with_context(|| {
+        format!(
+          "Failed to open history database at {}.",
+          db_path.display()
+        )
+      })?;
+
+  let end = end.unwrap_or_else(|| chrono::Utc::now().timestamp_millis());
+  let resolution = resolution.unwrap_or(1).max(1) as i64;
+
+  let points = query_samples(&conn, metric, start, end, resolution)?;
+  format_points(&points, format)
+}
+
+/// Gets the samples of a metric within `[start, end)`, averaged into
+/// buckets of `resolution` milliseconds.
+fn query_samples(
+  conn: &Connection,
+  metric: &str,
+  start: i64,
+  end: i64,
+  resolution: i64,
+) -> anyhow::Result<Vec<HistoryPoint>> {
+  let mut query = conn.prepare_cached(
+    "SELECT (timestamp / ?1) * ?1 AS bucket, AVG(value), MIN(value), MAX(value)
+     FROM samples
+     WHERE metric = ?2 AND timestamp >= ?3 AND timestamp < ?4
+     GROUP BY bucket
+     ORDER BY bucket",
+  )?;
+
+  let points = query
+    .query_map(params![resolution, metric, start, end], |row| {
+      Ok(HistoryPoint {
+        timestamp: row.get(0)?,
+        value: row.get(1)?,
+        min: row.get(2)?,
+        max: row.get(3)?,
+      })
+    })?
+    .collect::<Result<Vec<_>, _>>()?;
+
+  Ok(points)
+}
+
+/// Formats points as CSV (with RFC 3339 timestamps, for spreadsheets)
+/// or as a JSON array matching the `query_history` response.
+fn format_points(
+  points: &[HistoryPoint],
+  format: ExportFormat,
+) -> anyhow::Result<String> {
+  match format {
+    ExportFormat::Csv => {
+      let mut csv = String::from("time,value,min,max\n");
+
+      for point in points {
+        let time =
+          chrono::DateTime::from_timestamp_millis(point.timestamp)
+            .context("Invalid sample timestamp.")?;
+
+        csv += &format!(
+          "{},{},{},{}\n",
+          time.to_rfc3339_opts(chrono::SecondsFormat::Millis, true),
+          point.value,
+          point.min,
+          point.max
+        );
+      }
+
+      Ok(csv)
+    }
+    ExportFormat::Json => Ok(serde_json::to_string_pretty(points)? + "\n"),
+  }
+}
+
+/// Runs a closure with the database connection, opening it and creating
+/// the schema if needed.
+fn with_db<T>(
+  db: &StdMutex<Option<Connection>>,
+  db_path: Option<&PathBuf>,
+  f: impl FnOnce(&mut Connection) -> anyhow::Result<T>,
+) -> anyhow::Result<T> {
+  let mut db = db.lock().unwrap();
+
+  if db.is_none() {
+    let db_path =
+      db_path.context("No directory available for history.")?;
+
+    if let Some(parent) = db_path.parent() {
+      std::fs::create_dir_all(parent)?;
+    }
+
+    let conn = Connection::open(db_path).with_context(|| {
+      format!("Failed to open history database at {}.", db_path.display())
+    })?;
+
+    conn.execute_batch(
+      "CREATE TABLE IF NOT EXISTS samples (
+         metric TEXT NOT NULL,
+         timestamp INTEGER NOT NULL,
+         value REAL NOT NULL
+       );
+       CREATE INDEX IF NOT EXISTS samples_metric_timestamp
+         ON samples (metric, timestamp);",
+    )?;
+
+    *db = Some(conn);
+  }
+
+  f(db.as_mut().unwrap())
+}
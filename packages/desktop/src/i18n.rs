@@ -0,0 +1,188 @@
+use std::{collections::HashMap, path::Path, sync::OnceLock};
+
+use tracing::{info, warn};
+
+use crate::common::read_and_parse_json;
+
+/// Locale to fall back to for missing translations.
+const FALLBACK_LOCALE: &str = "en";
+
+/// Built-in translations keyed by locale tag.
+const BUILTIN_LOCALES: [(&str, &str); 5] = [
+  ("en", include_str!("../resources/locales/en.json")),
+  ("de", include_str!("../resources/locales/de.json")),
+  ("es", include_str!("../resources/locales/es.json")),
+  ("fr", include_str!("../resources/locales/fr.json")),
+  ("zh-CN", include_str!("../resources/locales/zh-CN.json")),
+];
+
+static I18N: OnceLock<I18n> = OnceLock::new();
+
+/// Translations for strings generated by the backend (e.g. tray menu
+/// labels).
+pub struct I18n {
+  /// Active locale tag (e.g. `en` or `zh-CN`).
+  locale: String,
+
+  /// Translations for the active locale.
+  strings: HashMap<String, String>,
+
+  /// Translations for the fallback locale.
+  fallback_strings: HashMap<String, String>,
+}
+
+impl I18n {
+  /// Creates a new `I18n` instance for the given locale, or the system
+  /// locale if `None`.
+  ///
+  /// User-defined translations in `<CONFIG_DIR>/locales/<LOCALE>.json`
+  /// take precedence over the built-in ones.
+  fn new(locale_override: Option<&str>, config_dir: &Path) -> Self {
+    let requested = locale_override
+      .map(String::from)
+      .or_else(system_locale)
+      .unwrap_or(FALLBACK_LOCALE.into());
+
+    let locale = Self::resolve_locale(&requested, config_dir);
+    info!("Using locale '{}' (requested '{}').", locale, requested);
+
+    let mut strings = Self::builtin_strings(&locale);
+    strings.extend(Self::user_strings(&locale, config_dir));
+
+    Self {
+      locale,
+      strings,
+      fallback_strings: Self::builtin_strings(FALLBACK_LOCALE),
+    }
+  }
+
+  /// Finds the closest available locale for the requested one. Tries
+  /// an exact match first (e.g. `zh-CN`), then the language only (e.g.
+  /// `zh`).
+  fn resolve_locale(requested: &str, config_dir: &Path) -> String {
+    let language = requested.split('-').next().unwrap_or(requested);
+
+    [requested, language]
+      .into_iter()
+      .find(|candidate| {
+        BUILTIN_LOCALES.iter().any(|(tag, _)| tag == candidate)
+          || Self::user_locale_path(candidate, config_dir).exists()
+      })
+      .or_else(|| {
+        // Allow e.g. `zh` to match `zh-CN`.
+        BUILTIN_LOCALES
+          .iter()
+          .map(|(tag, _)| *tag)
+          .find(|tag| tag.split('-').next() == Some(language))
+      })
+      .unwrap_or(FALLBACK_LOCALE)
+      .to_string()
+  }
+
+  fn builtin_strings(locale: &str) -> HashMap<String, String> {
+    BUILTIN_LOCALES
+      .iter()
+      .find(|(tag, _)| *tag == locale)
+      .and_then(|(_, json)| serde_json::from_str(json).ok())
+      .unwrap_or_default()
+  }
+
+  fn user_strings(
+    locale: &str,
+    config_dir: &Path,
+  ) -> HashMap<String, String> {
+    let path = Self::user_locale_path(locale, config_dir);
+
+    if !path.exists() {
+      return HashMap::new();
+    }
+
+    read_and_parse_json(&path).unwrap_or_else(|err| {
+      warn!("Failed to read locale file: {:?}", err);
+      HashMap::new()
+    })
+  }
+
+  fn user_locale_path(
+    locale: &str,
+    config_dir: &Path,
+  ) -> std::path::PathBuf {
+    config_dir.join("locales").join(format!("{}.json", locale))
+  }
+
+  /// Returns the translation for the given key.
+  ///
+  /// Falls back to the fallback locale, and then to the key itself.
+  fn translate(&self, key: &str) -> String {
+    self
+      .strings
+      .get(key)
+      .or_else(|| self.fallback_strings.get(key))
+      .cloned()
+      .unwrap_or_else(|| key.to_string())
+  }
+}
+
+/// Initializes the global translations. No-op if already initialized.
+pub fn init(locale_override: Option<&str>, config_dir: &Path) {
+  let _ = I18N.set(I18n::new(locale_override, config_dir));
+}
+
+/// Returns the active locale tag (e.g. `en` or `zh-CN`).
+pub fn locale() -> String {
+  I18N
+    .get()
+    .map(|i18n| i18n.locale.clone())
+    .unwrap_or(FALLBACK_LOCALE.into())
+}
+
+/// Returns the translation for the given key.
+pub fn t(key: &str) -> String {
+  match I18N.get() {
+    Some(i18n) => i18n.translate(key),
+    None => I18n::builtin_strings(FALLBACK_LOCALE)
+      .remove(key)
+      .unwrap_or_else(|| key.to_string()),
+  }
+}
+
+/// Returns the translation for the given key with `{name}` placeholders
+/// replaced by their values.
+pub fn t_with(key: &str, args: &[(&str, &str)]) -> String {
+  args.iter().fold(t(key), |acc, (name, value)| {
+    acc.replace(&format!("{{{}}}", name), value)
+  })
+}
+
+/// Gets the locale tag of the OS user (e.g. `en-US`).
+fn system_locale() -> Option<String> {
+  #[cfg(target_os = "windows")]
+  {
+    use windows::Win32::{
+      Globalization::GetUserDefaultLocaleName,
+      System::SystemServices::LOCALE_NAME_MAX_LENGTH,
+    };
+
+    let mut buffer = [0u16; LOCALE_NAME_MAX_LENGTH as usize];
+    let length = unsafe { GetUserDefaultLocaleName(&mut buffer) };
+
+    (length > 1)
+      .then(|| String::from_utf16_lossy(&buffer[..length as usize - 1]))
+  }
+
+  // POSIX locales are of the format `de_DE.UTF-8`.
+  #[cfg(not(target_os = "windows"))]
+  {
+    ["LC_ALL", "LC_MESSAGES", "LANG"]
+      .into_iter()
+      .filter_map(|var| std::env::var(var).ok())
+      .find(|value| !value.is_empty() && value != "C" && value != "POSIX")
+      .map(|value| {
+        value
+          .split(['.', '@'])
+          .next()
+          .unwrap_or(&value)
+          .replace('_', "-")
+      })
+  }
+}
@@ -0,0 +1,60 @@
+use std::{
+  fs::{self, File, TryLockError},
+  path::Path,
+};
+
+use anyhow::Context;
+use sha2::{Digest, Sha256};
+use tauri::{AppHandle, Manager};
+
+use crate::{common::PathExt, portable};
+
+/// Exclusive lock on a config directory, held for as long as the
+/// instance runs.
+///
+/// The single-instance plugin is keyed on the app identifier, so it
+/// can't tell apart instances started with `--new-instance`. This lock
+/// instead prevents any two instances from running with the same
+/// config directory.
+pub struct InstanceLock {
+  _file: File,
+}
+
+impl InstanceLock {
+  /// Acquires the lock for the given config directory.
+  ///
+  /// Returns an error if another instance already holds it.
+  pub fn acquire(
+    app_handle: &AppHandle,
+    config_dir: &Path,
+  ) -> anyhow::Result<Self> {
+    let config_dir = config_dir.to_absolute()?;
+
+    // Lock files are kept outside of the config directory, since it
+    // might be synced via git.
+    let lock_dir = portable::data_dir()
+      .or_else(|| app_handle.path().app_local_data_dir().ok())
+      .context("Unable to resolve data directory.")?
+      .join("instances");
+
+    fs::create_dir_all(&lock_dir)?;
+
+    let hash = Sha256::digest(config_dir.to_string_lossy().as_bytes());
+
+    let file = File::options()
+      .create(true)
+      .truncate(false)
+      .write(true)
+      .open(lock_dir.join(format!("{:x}.lock", hash)))?;
+
+    match file.try_lock() {
+      Ok(()) => Ok(Self { _file: file }),
+      Err(TryLockError::WouldBlock) => anyhow::bail!(
+        "Another instance of Zebar is already running with the config \
+         directory {}.",
+        config_dir.display()
+      ),
+      Err(TryLockError::Error(err)) => Err(err.into()),
+    }
+  }
+}
@@ -0,0 +1,141 @@
+use std::sync::{LazyLock, Mutex};
+
+use tokio::sync::watch;
+use tracing::info;
+
+/// Global keep-awake state, shared between the `set_keep_awake` command
+/// and any running `keep_awake` providers.
+pub static KEEP_AWAKE: LazyLock<KeepAwake> = LazyLock::new(KeepAwake::new);
+
+/// Prevents the system from sleeping while active.
+pub struct KeepAwake {
+  /// Handle to the active sleep inhibition, if any. Dropping the handle
+  /// releases the inhibition.
+  inhibitor: Mutex<Option<Inhibitor>>,
+
+  /// Sender for whether sleep inhibition is active.
+  state_tx: watch::Sender<bool>,
+}
+
+impl KeepAwake {
+  fn new() -> Self {
+    Self {
+      inhibitor: Mutex::new(None),
+      state_tx: watch::channel(false).0,
+    }
+  }
+
+  /// Returns a receiver that is notified whenever the state changes.
+  pub fn subscribe(&self) -> watch::Receiver<bool> {
+    self.state_tx.subscribe()
+  }
+
+  /// Starts or stops inhibiting sleep. No-op if already in the given
+  /// state.
+  pub fn set_active(&self, is_active: bool) -> anyhow::Result<()> {
+    let mut inhibitor = self.inhibitor.lock().unwrap();
+
+    if inhibitor.is_some() == is_active {
+      return Ok(());
+    }
+
+    *inhibitor = match is_active {
+      true => Some(Inhibitor::acquire()?),
+      false => None,
+    };
+
+    info!("Keep awake set to: {}", is_active);
+    self.state_tx.send_replace(is_active);
+
+    Ok(())
+  }
+}
+
+/// Holds a `SetThreadExecutionState` request on a dedicated thread, since
+/// the request is released when the calling thread exits.
+#[cfg(target_os = "windows")]
+struct Inhibitor {
+  _release_tx: std::sync::mpsc::Sender<()>,
+}
+
+#[cfg(target_os = "windows")]
+impl Inhibitor {
+  fn acquire() -> anyhow::Result<Self> {
+    use windows::Win32::System::Power::{
+      SetThreadExecutionState, ES_CONTINUOUS, ES_DISPLAY_REQUIRED,
+      ES_SYSTEM_REQUIRED,
+    };
+
+    let (release_tx, release_rx) = std::sync::mpsc::channel::<()>();
+    let (acquired_tx, acquired_rx) = std::sync::mpsc::channel();
+
+    std::thread::spawn(move || {
+      let prev_state = unsafe {
+        SetThreadExecutionState(
+          ES_CONTINUOUS | ES_SYSTEM_REQUIRED | ES_DISPLAY_REQUIRED,
+        )
+      };
+
+      let _ = acquired_tx.send(prev_state.0 != 0);
+
+      // Blocks until the sender is dropped.
+      let _ = release_rx.recv();
+      unsafe { SetThreadExecutionState(ES_CONTINUOUS) };
+    });
+
+    match acquired_rx.recv() {
+      Ok(true) => Ok(Self {
+        _release_tx: release_tx,
+      }),
+      _ => anyhow::bail!("Failed to set thread execution state."),
+    }
+  }
+}
+
+/// Child process that holds the sleep inhibition for as long as it runs.
+#[cfg(not(target_os = "windows"))]
+struct Inhibitor {
+  child: std::process::Child,
+}
+
+#[cfg(not(target_os = "windows"))]
+impl Inhibitor {
+  fn acquire() -> anyhow::Result<Self> {
+    use anyhow::Context;
+
+    let pid = std::process::id().to_string();
+
+    // The inhibiting process exits on its own if Zebar exits without
+    // releasing it.
+    #[cfg(target_os = "macos")]
+    let child = std::process::Command::new("caffeinate")
+      .args(["-d", "-i", "-w", &pid])
+      .spawn()
+      .context("Unable to run `caffeinate`.")?;
+
+    #[cfg(target_os = "linux")]
+    let child = std::process::Command::new("systemd-inhibit")
+      .args([
+        "--what=idle:sleep",
+        "--who=Zebar",
+        "--why=Keep awake enabled",
+        "--mode=block",
+        "tail",
+        &format!("--pid={}", pid),
+        "-f",
+        "/dev/null",
+      ])
+      .spawn()
+      .context("Unable to run `systemd-inhibit`. Is systemd installed?")?;
+
+    Ok(Self { child })
+  }
+}
+
+#[cfg(not(target_os = "windows"))]
+impl Drop for Inhibitor {
+  fn drop(&mut self) {
+    let _ = self.child.kill();
+    let _ = self.child.wait();
+  }
+}
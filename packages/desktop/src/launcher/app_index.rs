@@ -0,0 +1,201 @@
+use std::{
+  fs,
+  path::{Path, PathBuf},
+  process::Command,
+};
+
+use anyhow::Context;
+
+use crate::common::open_url;
+
+/// An installed app that can be launched.
+#[derive(Clone, Debug)]
+pub struct AppEntry {
+  pub name: String,
+
+  /// Path to the Start Menu shortcut on Windows, the `.desktop` file on
+  /// Linux, or the app bundle on MacOS.
+  pub path: PathBuf,
+
+  /// Command line from the `Exec` key of a `.desktop` file.
+  pub exec: Option<String>,
+}
+
+impl AppEntry {
+  pub fn launch(&self) -> anyhow::Result<()> {
+    match &self.exec {
+      Some(exec) => {
+        Command::new("sh")
+          .args(["-c", exec])
+          .spawn()
+          .with_context(|| format!("Failed to launch '{}'.", self.name))?;
+
+        Ok(())
+      }
+      // Shortcuts and app bundles are opened by the OS.
+      None => open_url(&self.path.to_string_lossy()),
+    }
+  }
+}
+
+/// Finds installed apps, sorted by name.
+pub fn installed_apps() -> Vec<AppEntry> {
+  let mut apps = app_dirs()
+    .iter()
+    .flat_map(|dir| {
+      let mut entries = Vec::new();
+      collect_apps(dir, &mut entries);
+      entries
+    })
+    .collect::<Vec<_>>();
+
+  apps.sort_by_key(|app| app.name.to_lowercase());
+  apps.dedup_by(|a, b| a.name == b.name);
+  apps
+}
+
+#[cfg(target_os = "windows")]
+fn app_dirs() -> Vec<PathBuf> {
+  ["APPDATA", "PROGRAMDATA"]
+    .iter()
+    .filter_map(|var| std::env::var(var).ok())
+    .map(|dir| {
+      PathBuf::from(dir).join(r"Microsoft\Windows\Start Menu\Programs")
+    })
+    .collect()
+}
+
+#[cfg(target_os = "linux")]
+fn app_dirs() -> Vec<PathBuf> {
+  let data_home = std::env::var("XDG_DATA_HOME")
+    .map(PathBuf::from)
+    .or_else(|_| {
+      std::env::var("HOME")
+        .map(|home| PathBuf::from(home).join(".local/share"))
+    })
+    .ok();
+
+  let data_dirs = std::env::var("XDG_DATA_DIRS")
+    .unwrap_or_else(|_| "/usr/local/share:/usr/share".to_string());
+
+  data_home
+    .into_iter()
+    .chain(data_dirs.split(':').map(PathBuf::from))
+    .map(|dir| dir.join("applications"))
+    .collect()
+}
+
+#[cfg(target_os = "macos")]
+fn app_dirs() -> Vec<PathBuf> {
+  let mut dirs = vec![
+    PathBuf::from("/Applications"),
+    PathBuf::from("/System/Applications"),
+  ];
+
+  if let Ok(home) = std::env::var("HOME") {
+    dirs.push(PathBuf::from(home).join("Applications"));
+  }
+
+  dirs
+}
+
+/// Recursively collects apps within a directory.
+fn collect_apps(dir: &Path, apps: &mut Vec<AppEntry>) {
+  let Ok(entries) = fs::read_dir(dir) else {
+    return;
+  };
+
+  for entry in entries.flatten() {
+    let path = entry.path();
+    let extension = path
+      .extension()
+      .map(|ext| ext.to_string_lossy().to_lowercase());
+
+    match extension.as_deref() {
+      // App bundles are directories, so check them before recursing.
+      Some("app") if cfg!(target_os = "macos") => {
+        apps.extend(file_stem_entry(&path));
+      }
+      _ if path.is_dir() => collect_apps(&path, apps),
+      Some("lnk" | "url") if cfg!(target_os = "windows") => {
+        apps.extend(file_stem_entry(&path));
+      }
+      Some("desktop") if cfg!(target_os = "linux") => {
+        apps.extend(parse_desktop_file(&path));
+      }
+      _ => {}
+    }
+  }
+}
+
+fn file_stem_entry(path: &Path) -> Option<AppEntry> {
+  Some(AppEntry {
+    name: path.file_stem()?.to_string_lossy().to_string(),
+    path: path.to_path_buf(),
+    exec: None,
+  })
+}
+
+/// Parses the `[Desktop Entry]` group of a `.desktop` file. Returns
+/// `None` for hidden entries and entries that aren't apps.
+fn parse_desktop_file(path: &Path) -> Option<AppEntry> {
+  let text = fs::read_to_string(path).ok()?;
+
+  let mut name = None;
+  let mut exec = None;
+  let mut is_in_entry_group = false;
+
+  for line in text.lines() {
+    let line = line.trim();
+
+    if line.starts_with('[') {
+      is_in_entry_group = line == "[Desktop Entry]";
+      continue;
+    }
+
+    if !is_in_entry_group {
+      continue;
+    }
+
+    let Some((key, value)) = line.split_once('=') else {
+      continue;
+    };
+
+    match key.trim() {
+      "Name" => name = Some(value.trim().to_string()),
+      "Exec" => exec = Some(strip_field_codes(value.trim())),
+      "Type" if value.trim() != "Application" => return None,
+      "NoDisplay" | "Hidden" if value.trim() == "true" => return None,
+      _ => {}
+    }
+  }
+
+  Some(AppEntry {
+    name: name?,
+    path: path.to_path_buf(),
+    exec: Some(exec?),
+  })
+}
+
+/// Removes field codes (e.g. `%U` or `%f`) from an `Exec` value, since
+/// apps are launched without any files or URLs.
+fn strip_field_codes(exec: &str) -> String {
+  let mut stripped = String::with_capacity(exec.len());
+  let mut chars = exec.chars().peekable();
+
+  while let Some(char) = chars.next() {
+    if char != '%' {
+      stripped.push(char);
+      continue;
+    }
+
+    // `%%` is an escaped percent sign.
+    if chars.next_if_eq(&'%').is_some() {
+      stripped.push('%');
+    } else {
+      chars.next();
+    }
+  }
+
+  stripped.trim().to_string()
+}
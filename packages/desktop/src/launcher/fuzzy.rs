@@ -0,0 +1,65 @@
+/// Scores how well `text` matches `query`, where each character of the
+/// query must appear in order (case-insensitive). Returns `None` if the
+/// text doesn't match.
+///
+/// Matches at the start of the text or of a word, and consecutive
+/// matches, score higher. Shorter texts score slightly higher.
+pub fn fuzzy_score(query: &str, text: &str) -> Option<i64> {
+  let query = query.to_lowercase().chars().collect::<Vec<_>>();
+  let text_chars = text.chars().collect::<Vec<_>>();
+  let lower_chars = text.to_lowercase().chars().collect::<Vec<_>>();
+
+  if query.is_empty() {
+    return Some(0);
+  }
+
+  // Lowercasing can change the length of some strings, in which case
+  // matches can't be mapped back to the original characters.
+  if lower_chars.len() != text_chars.len() {
+    let query = query.iter().collect::<String>();
+    return text.to_lowercase().contains(&query).then_some(1);
+  }
+
+  let mut score = 0;
+  let mut query_index = 0;
+  let mut prev_match_index = None;
+
+  for (index, char) in lower_chars.iter().enumerate() {
+    if query_index == query.len() {
+      break;
+    }
+
+    if *char != query[query_index] {
+      continue;
+    }
+
+    score += 1;
+
+    let is_word_start = index == 0
+      || !text_chars[index - 1].is_alphanumeric()
+      || (text_chars[index].is_uppercase()
+        && text_chars[index - 1].is_lowercase());
+
+    if index == 0 {
+      score += 15;
+    } else if is_word_start {
+      score += 10;
+    }
+
+    match prev_match_index {
+      Some(prev) if prev + 1 == index => score += 5,
+      // Penalize gaps between matches.
+      Some(prev) => score -= ((index - prev - 1) as i64).min(3),
+      None => {}
+    }
+
+    prev_match_index = Some(index);
+    query_index += 1;
+  }
+
+  if query_index < query.len() {
+    return None;
+  }
+
+  Some(score * 100 - lower_chars.len() as i64)
+}
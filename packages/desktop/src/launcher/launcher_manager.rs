@@ -0,0 +1,350 @@
+use std::{path::PathBuf, process::Command, sync::Arc};
+
+use anyhow::{bail, Context};
+use serde::Serialize;
+use tauri::{plugin::TauriPlugin, AppHandle, Manager, Runtime};
+use tauri_plugin_global_shortcut::{
+  GlobalShortcutExt, Shortcut, ShortcutState,
+};
+use tokio::{sync::Mutex, task};
+use tracing::{error, info};
+
+use super::{
+  app_index::{installed_apps, AppEntry},
+  fuzzy::fuzzy_score,
+  window_index::{focus_window, open_windows},
+};
+use crate::{
+  config::{Config, LauncherCommand, LauncherConfig},
+  widget_factory::{WidgetFactory, WidgetOpenOptions},
+};
+
+/// Default max number of results returned by a search.
+const DEFAULT_RESULT_LIMIT: usize = 50;
+
+/// A search result from the launcher.
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LauncherResult {
+  /// ID to pass to `launcher_activate` (e.g. `window:1234`).
+  pub id: String,
+
+  pub kind: LauncherResultKind,
+  pub title: String,
+
+  /// Secondary text (e.g. the path of an app).
+  pub subtitle: Option<String>,
+
+  /// Match score. Higher is better.
+  pub score: i64,
+}
+
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LauncherResultKind {
+  App,
+  Window,
+  Command,
+}
+
+/// Toggles the launcher widget via a global hotkey and searches the
+/// apps, windows, and commands it can launch.
+pub struct LauncherManager {
+  app_handle: AppHandle,
+
+  /// Reference to `Config`.
+  config: Arc<Config>,
+
+  /// Reference to `WidgetFactory`.
+  widget_factory: Arc<WidgetFactory>,
+
+  /// Installed apps. Re-indexed each time the launcher opens.
+  apps: Mutex<Vec<AppEntry>>,
+
+  /// Currently registered hotkey.
+  hotkey: Mutex<Option<Shortcut>>,
+}
+
+impl LauncherManager {
+  pub fn new(
+    app_handle: &AppHandle,
+    config: Arc<Config>,
+    widget_factory: Arc<WidgetFactory>,
+  ) -> Self {
+    Self {
+      app_handle: app_handle.clone(),
+      config,
+      widget_factory,
+      apps: Mutex::new(Vec::new()),
+      hotkey: Mutex::new(None),
+    }
+  }
+
+  /// Registers the hotkey from the launcher settings, replacing any
+  /// previously registered hotkey.
+  pub async fn register_hotkey(&self) -> anyhow::Result<()> {
+    let launcher_config =
+      self.config.settings.lock().await.launcher.clone();
+
+    let new_hotkey = launcher_config
+      .map(|launcher_config| {
+        launcher_config.hotkey.parse::<Shortcut>().with_context(|| {
+          format!("Invalid launcher hotkey '{}'.", launcher_config.hotkey)
+        })
+      })
+      .transpose()?;
+
+    let mut hotkey = self.hotkey.lock().await;
+
+    if *hotkey == new_hotkey {
+      return Ok(());
+    }
+
+    let global_shortcut = self.app_handle.global_shortcut();
+
+    if let Some(hotkey) = hotkey.take() {
+      global_shortcut.unregister(hotkey)?;
+    }
+
+    if let Some(new_hotkey) = new_hotkey {
+      info!("Registering launcher hotkey: {:?}", new_hotkey);
+      global_shortcut.register(new_hotkey)?;
+      *hotkey = Some(new_hotkey);
+    }
+
+    Ok(())
+  }
+
+  /// Opens the launcher widget, or closes it if already open.
+  pub async fn toggle(&self) -> anyhow::Result<()> {
+    let (config_path, preset) = self.launcher_preset().await?;
+
+    let is_open = self
+      .widget_factory
+      .states_by_path()
+      .await
+      .get(&config_path)
+      .is_some_and(|states| {
+        states.iter().any(|state| {
+          matches!(
+            &state.open_options,
+            WidgetOpenOptions::Preset(name) if *name == preset
+          )
+        })
+      });
+
+    if is_open {
+      return self.close().await;
+    }
+
+    // Re-index apps so that newly installed apps show up.
+    *self.apps.lock().await = task::spawn_blocking(installed_apps).await?;
+
+    self
+      .widget_factory
+      .start_widget(&config_path, &WidgetOpenOptions::Preset(preset))
+      .await
+  }
+
+  /// Closes the launcher widget.
+  pub async fn close(&self) -> anyhow::Result<()> {
+    let (config_path, preset) = self.launcher_preset().await?;
+
+    self
+      .widget_factory
+      .stop_by_preset(&config_path, &preset)
+      .await
+  }
+
+  /// Searches apps, open windows, and commands. Results are sorted by
+  /// score, best match first.
+  pub async fn search(
+    &self,
+    query: &str,
+    limit: Option<usize>,
+  ) -> anyhow::Result<Vec<LauncherResult>> {
+    let launcher_config = self.launcher_config().await?;
+    let query = query.trim();
+
+    let windows = task::spawn_blocking(open_windows).await?;
+
+    let window_results = windows.into_iter().filter_map(|window| {
+      Some(LauncherResult {
+        id: format!("window:{}", window.handle),
+        kind: LauncherResultKind::Window,
+        score: fuzzy_score(query, &window.title)?,
+        title: window.title,
+        subtitle: None,
+      })
+    });
+
+    let command_results =
+      launcher_config.commands.iter().filter_map(|command| {
+        // Keywords can match, but a match on the name is preferred.
+        let score = std::iter::once(&command.name)
+          .chain(&command.keywords)
+          .filter_map(|term| fuzzy_score(query, term))
+          .max()?;
+
+        Some(LauncherResult {
+          id: format!("command:{}", command.name),
+          kind: LauncherResultKind::Command,
+          title: command.name.clone(),
+          subtitle: Some(command.program.clone()),
+          score,
+        })
+      });
+
+    let apps = self.apps.lock().await;
+
+    let app_results = apps.iter().filter_map(|app| {
+      Some(LauncherResult {
+        id: format!("app:{}", app.path.display()),
+        kind: LauncherResultKind::App,
+        title: app.name.clone(),
+        subtitle: Some(app.path.display().to_string()),
+        score: fuzzy_score(query, &app.name)?,
+      })
+    });
+
+    let mut results = window_results
+      .chain(command_results)
+      .chain(app_results)
+      .collect::<Vec<_>>();
+
+    // Stable sort, so that ties keep the order of windows, commands, and
+    // then apps.
+    results.sort_by(|a, b| b.score.cmp(&a.score));
+    results.truncate(limit.unwrap_or(DEFAULT_RESULT_LIMIT));
+
+    Ok(results)
+  }
+
+  /// Launches or focuses the result with the given ID, then closes the
+  /// launcher.
+  pub async fn activate(&self, id: &str) -> anyhow::Result<()> {
+    let (kind, value) =
+      id.split_once(':').context("Invalid launcher result ID.")?;
+
+    match kind {
+      "app" => {
+        let path = PathBuf::from(value);
+        let app = self
+          .apps
+          .lock()
+          .await
+          .iter()
+          .find(|app| app.path == path)
+          .cloned()
+          .with_context(|| format!("No app found at '{}'.", value))?;
+
+        app.launch()?;
+      }
+      "window" => {
+        let handle =
+          value.parse::<isize>().context("Invalid window handle.")?;
+
+        // Close the launcher first, so that it doesn't take focus back
+        // from the window.
+        self.close().await?;
+        return focus_window(handle);
+      }
+      "command" => {
+        let launcher_config = self.launcher_config().await?;
+        let command = launcher_config
+          .commands
+          .iter()
+          .find(|command| command.name == value)
+          .with_context(|| format!("No command named '{}'.", value))?;
+
+        run_command(command)?;
+      }
+      _ => bail!("Invalid launcher result ID."),
+    }
+
+    self.close().await
+  }
+
+  /// Gets the icon of a result as a PNG. Only available for windows on
+  /// Windows.
+  pub async fn icon(&self, id: &str) -> anyhow::Result<Vec<u8>> {
+    let handle = id
+      .strip_prefix("window:")
+      .context("Icons are only available for windows.")?
+      .parse::<isize>()
+      .context("Invalid window handle.")?;
+
+    let window = task::spawn_blocking(open_windows)
+      .await?
+      .into_iter()
+      .find(|window| window.handle == handle)
+      .context("Window is no longer open.")?;
+
+    #[cfg(target_os = "windows")]
+    {
+      use crate::common::windows::{exe_icon_png, process_exe_path};
+
+      task::spawn_blocking(move || {
+        exe_icon_png(&process_exe_path(window.process_id)?)
+      })
+      .await?
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    {
+      let _ = window;
+      bail!("Icons are only supported on Windows.")
+    }
+  }
+
+  async fn launcher_config(&self) -> anyhow::Result<LauncherConfig> {
+    self
+      .config
+      .settings
+      .lock()
+      .await
+      .launcher
+      .clone()
+      .context("Launcher is not configured in settings.")
+  }
+
+  /// Absolute path to the launcher's widget config and its preset name.
+  async fn launcher_preset(&self) -> anyhow::Result<(PathBuf, String)> {
+    let launcher_config = self.launcher_config().await?;
+    let config_path =
+      self.config.to_absolute_path(&launcher_config.path)?;
+
+    Ok((config_path, launcher_config.preset))
+  }
+}
+
+/// Spawns a user-registered command without waiting for it to exit.
+fn run_command(command: &LauncherCommand) -> anyhow::Result<()> {
+  Command::new(&command.program)
+    .args(&command.args)
+    .spawn()
+    .with_context(|| format!("Failed to run '{}'.", command.name))?;
+
+  Ok(())
+}
+
+/// Global shortcut plugin that toggles the launcher when its hotkey is
+/// pressed.
+pub fn hotkey_plugin<R: Runtime>() -> TauriPlugin<R> {
+  tauri_plugin_global_shortcut::Builder::new()
+    .with_handler(|app_handle, _, event| {
+      if event.state() != ShortcutState::Pressed {
+        return;
+      }
+
+      let launcher =
+        app_handle.state::<Arc<LauncherManager>>().inner().clone();
+
+      task::spawn(async move {
+        if let Err(err) = launcher.toggle().await {
+          error!("Failed to toggle launcher: {:?}", err);
+        }
+      });
+    })
+    .build()
+}
@@ -0,0 +1,6 @@
+mod app_index;
+mod fuzzy;
+mod launcher_manager;
+mod window_index;
+
+pub use launcher_manager::*;
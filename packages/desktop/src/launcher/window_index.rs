@@ -0,0 +1,97 @@
+/// A top-level window that can be focused from the launcher.
+#[derive(Clone, Debug)]
+pub struct OpenWindow {
+  pub handle: isize,
+  pub title: String,
+
+  /// ID of the process that owns the window.
+  pub process_id: u32,
+}
+
+/// Gets visible top-level windows with a title, excluding Zebar's own
+/// windows.
+#[cfg(target_os = "windows")]
+pub fn open_windows() -> Vec<OpenWindow> {
+  use windows::Win32::{
+    Foundation::{BOOL, HWND, LPARAM},
+    UI::WindowsAndMessaging::{
+      EnumWindows, GetWindow, GetWindowLongW, GetWindowTextW,
+      GetWindowThreadProcessId, IsWindowVisible, GWL_EXSTYLE, GW_OWNER,
+      WS_EX_TOOLWINDOW,
+    },
+  };
+
+  unsafe extern "system" fn callback(hwnd: HWND, lparam: LPARAM) -> BOOL {
+    let windows = &mut *(lparam.0 as *mut Vec<OpenWindow>);
+
+    let is_tool_window =
+      GetWindowLongW(hwnd, GWL_EXSTYLE) as u32 & WS_EX_TOOLWINDOW.0 != 0;
+
+    let has_owner =
+      GetWindow(hwnd, GW_OWNER).is_ok_and(|owner| !owner.is_invalid());
+
+    if !IsWindowVisible(hwnd).as_bool() || is_tool_window || has_owner {
+      return true.into();
+    }
+
+    let mut buffer = [0u16; 512];
+    let length = GetWindowTextW(hwnd, &mut buffer);
+
+    let mut process_id = 0;
+    GetWindowThreadProcessId(hwnd, Some(&mut process_id));
+
+    if length > 0 && process_id != std::process::id() {
+      windows.push(OpenWindow {
+        handle: hwnd.0 as isize,
+        title: String::from_utf16_lossy(&buffer[..length as usize]),
+        process_id,
+      });
+    }
+
+    true.into()
+  }
+
+  let mut windows = Vec::new();
+
+  let _ = unsafe {
+    EnumWindows(Some(callback), LPARAM(&mut windows as *mut _ as isize))
+  };
+
+  windows
+}
+
+#[cfg(not(target_os = "windows"))]
+pub fn open_windows() -> Vec<OpenWindow> {
+  Vec::new()
+}
+
+/// Brings a window to the foreground, restoring it if minimized.
+#[cfg(target_os = "windows")]
+pub fn focus_window(handle: isize) -> anyhow::Result<()> {
+  use anyhow::bail;
+  use windows::Win32::{
+    Foundation::HWND,
+    UI::WindowsAndMessaging::{
+      IsIconic, SetForegroundWindow, ShowWindow, SW_RESTORE,
+    },
+  };
+
+  let hwnd = HWND(handle as _);
+
+  unsafe {
+    if IsIconic(hwnd).as_bool() {
+      let _ = ShowWindow(hwnd, SW_RESTORE);
+    }
+
+    if !SetForegroundWindow(hwnd).as_bool() {
+      bail!("Failed to focus window.");
+    }
+  }
+
+  Ok(())
+}
+
+#[cfg(not(target_os = "windows"))]
+pub fn focus_window(_handle: isize) -> anyhow::Result<()> {
+  anyhow::bail!("Focusing windows is only supported on Windows.")
+}
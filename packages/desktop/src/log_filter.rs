@@ -0,0 +1,203 @@
+use std::{
+  collections::HashMap,
+  fmt::Debug,
+  sync::{Arc, RwLock},
+};
+
+use tracing::{
+  field::{Field, Visit},
+  level_filters::LevelFilter,
+  span,
+  subscriber::Interest,
+  Event, Metadata, Subscriber,
+};
+use tracing_subscriber::{
+  layer::Context, registry::LookupSpan, EnvFilter, Layer,
+};
+
+use crate::config::LogLevel;
+
+/// Name of the span that provider loops run in.
+pub const PROVIDER_SPAN: &str = "provider";
+
+/// Creates the span for a provider's loop. Events within it are subject
+/// to the provider's log level override (if any).
+pub fn provider_span(
+  provider_type: &str,
+  config_hash: &str,
+) -> tracing::Span {
+  tracing::info_span!(
+    parent: None,
+    PROVIDER_SPAN,
+    provider_type = %provider_type,
+    config_hash = %config_hash
+  )
+}
+
+/// Log level overrides by provider type, as set in the settings.
+#[derive(Clone, Default)]
+pub struct ProviderLogLevels(Arc<RwLock<HashMap<String, LevelFilter>>>);
+
+impl ProviderLogLevels {
+  /// Replaces the current overrides.
+  pub fn set(&self, levels: &HashMap<String, LogLevel>) {
+    *self.0.write().unwrap() = levels
+      .iter()
+      .map(|(provider_type, level)| {
+        (provider_type.clone(), (*level).into())
+      })
+      .collect();
+
+    // Callsites cache whether they're enabled, which depends on whether
+    // there are any overrides.
+    tracing::callsite::rebuild_interest_cache();
+  }
+
+  fn is_empty(&self) -> bool {
+    self.0.read().unwrap().is_empty()
+  }
+}
+
+/// `tracing` filter that applies provider log level overrides on top of
+/// an `EnvFilter`.
+pub struct LogFilter {
+  env_filter: EnvFilter,
+  provider_levels: ProviderLogLevels,
+}
+
+impl LogFilter {
+  pub fn new(
+    env_filter: EnvFilter,
+    provider_levels: ProviderLogLevels,
+  ) -> Self {
+    Self {
+      env_filter,
+      provider_levels,
+    }
+  }
+
+  /// Gets the log level override of the provider that the current span
+  /// belongs to.
+  fn provider_level<S>(&self, cx: &Context<'_, S>) -> Option<LevelFilter>
+  where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+  {
+    let levels = self.provider_levels.0.read().unwrap();
+
+    if levels.is_empty() {
+      return None;
+    }
+
+    cx.lookup_current()?.scope().find_map(|span| {
+      let extensions = span.extensions();
+      let provider_type = extensions.get::<ProviderType>()?;
+      levels.get(&provider_type.0).copied()
+    })
+  }
+}
+
+/// Provider type of a provider span, stored in the span's extensions.
+struct ProviderType(String);
+
+impl<S> Layer<S> for LogFilter
+where
+  S: Subscriber + for<'a> LookupSpan<'a>,
+{
+  fn register_callsite(
+    &self,
+    metadata: &'static Metadata<'static>,
+  ) -> Interest {
+    let interest =
+      Layer::<S>::register_callsite(&self.env_filter, metadata);
+
+    // With overrides, whether an event is enabled depends on the span
+    // it's logged within.
+    match self.provider_levels.is_empty() {
+      true => interest,
+      false => Interest::sometimes(),
+    }
+  }
+
+  fn max_level_hint(&self) -> Option<LevelFilter> {
+    match self.provider_levels.is_empty() {
+      true => Layer::<S>::max_level_hint(&self.env_filter),
+      false => Some(LevelFilter::TRACE),
+    }
+  }
+
+  fn enabled(&self, metadata: &Metadata<'_>, cx: Context<'_, S>) -> bool {
+    // Provider spans are always created, so that overrides can apply.
+    if metadata.is_span() && metadata.name() == PROVIDER_SPAN {
+      return true;
+    }
+
+    if metadata.is_event() {
+      if let Some(level) = self.provider_level(&cx) {
+        return level >= *metadata.level();
+      }
+    }
+
+    self.env_filter.enabled(metadata, cx)
+  }
+
+  fn on_new_span(
+    &self,
+    attrs: &span::Attributes<'_>,
+    id: &span::Id,
+    cx: Context<'_, S>,
+  ) {
+    if attrs.metadata().name() == PROVIDER_SPAN {
+      let mut visitor = ProviderTypeVisitor::default();
+      attrs.record(&mut visitor);
+
+      if let (Some(provider_type), Some(span)) = (visitor.0, cx.span(id)) {
+        span.extensions_mut().insert(ProviderType(provider_type));
+      }
+    }
+
+    self.env_filter.on_new_span(attrs, id, cx)
+  }
+
+  fn on_record(
+    &self,
+    id: &span::Id,
+    values: &span::Record<'_>,
+    cx: Context<'_, S>,
+  ) {
+    self.env_filter.on_record(id, values, cx)
+  }
+
+  fn on_enter(&self, id: &span::Id, cx: Context<'_, S>) {
+    self.env_filter.on_enter(id, cx)
+  }
+
+  fn on_exit(&self, id: &span::Id, cx: Context<'_, S>) {
+    self.env_filter.on_exit(id, cx)
+  }
+
+  fn on_close(&self, id: span::Id, cx: Context<'_, S>) {
+    self.env_filter.on_close(id, cx)
+  }
+
+  fn event_enabled(&self, event: &Event<'_>, cx: Context<'_, S>) -> bool {
+    self.env_filter.event_enabled(event, cx)
+  }
+}
+
+/// Extracts the `provider_type` field from a provider span.
+#[derive(Default)]
+struct ProviderTypeVisitor(Option<String>);
+
+impl Visit for ProviderTypeVisitor {
+  fn record_debug(&mut self, field: &Field, value: &dyn Debug) {
+    if field.name() == "provider_type" {
+      self.0 = Some(format!("{:?}", value));
+    }
+  }
+
+  fn record_str(&mut self, field: &Field, value: &str) {
+    if field.name() == "provider_type" {
+      self.0 = Some(value.to_string());
+    }
+  }
+}
@@ -3,37 +3,107 @@
 #![feature(async_closure)]
 #![feature(iterator_try_collect)]
 
-use std::{env, sync::Arc};
+use std::{
+  collections::HashMap, env, fs, io::Write, path::PathBuf, process,
+  sync::Arc, time::Duration,
+};
 
+use anyhow::Context;
 use clap::Parser;
 use tauri::{
   async_runtime::block_on, AppHandle, Emitter, Manager, RunEvent,
 };
 use tokio::{sync::mpsc, task};
 use tracing::{error, info, level_filters::LevelFilter};
-use tracing_subscriber::EnvFilter;
+use tracing_subscriber::{
+  fmt, layer::SubscriberExt, util::SubscriberInitExt, EnvFilter,
+};
 
 #[cfg(target_os = "windows")]
 use crate::common::windows::WindowExtWindows;
 use crate::{
-  asset_server::setup_asset_server,
-  cli::{Cli, CliCommand, MonitorType, QueryArgs},
+  accessibility::AccessibilityState,
+  alerts::Alerts,
+  asset_server::{
+    cli_token_path, setup_asset_server, DEFAULT_ASSET_SERVER_PORT,
+  },
+  backup::{backups_dir, ConfigBackups},
+  cli::{
+    Cli, CliCommand, EncryptionArgs, ExportArgs, MonitorType,
+    ProviderArgs, QueryArgs, RestoreArgs, SchemaArgs, SetSecretArgs,
+    StartupArgs, StatsArgs, WatchArgs,
+  },
   config::{Config, MonitorSelection, WidgetPlacement},
+  dev_reload::DevReload,
+  doctor::run_doctor,
+  error_log::ErrorLog,
+  git_sync::GitSync,
+  heartbeat::WidgetHeartbeats,
+  history::{history_db_path, HistoryRecorder},
+  instance_lock::InstanceLock,
+  launcher::{hotkey_plugin, LauncherManager},
+  log_filter::{LogFilter, ProviderLogLevels},
   monitor_state::MonitorState,
+  native_popup::NativePopups,
   providers::{ProviderEmission, ProviderManager},
+  scheduler::Scheduler,
+  screen_presence::ScreenPresenceState,
+  secrets::SecretAccess,
+  startup_wait::wait_for_target,
   sys_tray::SysTray,
+  touch_gestures::TouchGestures,
+  tray_binding::TrayBinding,
+  usage_stats::{UsageReport, UsageStats},
+  webview_runtime::{check_webview_runtime, show_missing_runtime_dialog},
   widget_factory::{WidgetFactory, WidgetOpenOptions},
+  window_layers::WindowLayers,
+  window_title::WindowTitles,
 };
 
+mod accessibility;
+mod alerts;
 mod asset_server;
+mod backup;
+mod capabilities;
 mod cli;
 mod commands;
 mod common;
 mod config;
+mod config_encryption;
+mod config_extends;
+mod dev_reload;
+mod display_profiles;
+mod doctor;
+mod error_log;
+mod git_sync;
+mod heartbeat;
+mod history;
+mod i18n;
+mod instance_lock;
+mod keep_awake;
+mod launcher;
+mod log_filter;
 mod monitor_state;
+mod native_popup;
+mod portable;
+mod power_action;
 mod providers;
+mod scheduler;
+mod screen_presence;
+mod secrets;
+mod shell_command;
+mod startup_wait;
 mod sys_tray;
+mod taskbar_progress;
+mod touch_gestures;
+mod tray_badge;
+mod tray_binding;
+mod usage_stats;
+mod webview_runtime;
+mod widget_capture;
 mod widget_factory;
+mod window_layers;
+mod window_title;
 
 #[macro_use]
 extern crate rocket;
@@ -63,6 +133,52 @@ async fn main() -> anyhow::Result<()> {
 
           match cli.command() {
             CliCommand::Query(args) => output_query(app, args),
+            CliCommand::SetSecret(args) => {
+              cli::print_and_exit(set_secret_from_cli(args));
+              Ok(())
+            }
+            CliCommand::Encryption(args) => {
+              cli::print_and_exit(run_encryption_command(args));
+              Ok(())
+            }
+            CliCommand::Provider(args) => {
+              cli::print_and_exit(
+                run_provider_command(app, cli.config_dir.clone(), args)
+                  .await,
+              );
+              Ok(())
+            }
+            CliCommand::Schema(args) => {
+              cli::print_and_exit(output_provider_schema(args));
+              Ok(())
+            }
+            CliCommand::Watch(args) => {
+              cli::print_and_exit(watch_widget_events(app, args).await);
+              Ok(())
+            }
+            CliCommand::Stats(args) => {
+              cli::print_and_exit(output_usage_stats(app, args).await);
+              Ok(())
+            }
+            CliCommand::Export(args) => {
+              cli::print_and_exit(export_history(app, args));
+              Ok(())
+            }
+            CliCommand::Restore(args) => {
+              cli::print_and_exit(restore_from_cli(
+                app,
+                cli.config_dir.clone(),
+                args,
+              ));
+              Ok(())
+            }
+            CliCommand::Doctor => {
+              let report =
+                run_doctor(app.handle(), cli.config_dir.clone()).await;
+
+              print!("{}", report);
+              process::exit(if report.has_failures() { 1 } else { 0 });
+            }
             _ => {
               let start_res = start_app(app, cli).await;
 
@@ -88,11 +204,47 @@ async fn main() -> anyhow::Result<()> {
       commands::start_preset,
       commands::stop_preset,
       commands::update_widget_config,
+      commands::locale,
+      commands::widget_env,
+      commands::profiles,
+      commands::active_profile,
+      commands::switch_profile,
+      commands::display_profiles,
+      commands::save_display_profile,
+      commands::apply_display_profile_by_name,
       commands::listen_provider,
       commands::unlisten_provider,
       commands::call_provider_function,
+      commands::get_provider_attachment,
+      commands::resolve_asset,
+      commands::get_provider_schema,
+      commands::get_provider_schemas,
+      commands::get_capabilities,
+      commands::run_command,
+      commands::set_secret,
+      commands::delete_secret,
       commands::set_always_on_top,
-      commands::set_skip_taskbar
+      commands::set_skip_taskbar,
+      commands::set_tray_badge,
+      commands::set_taskbar_overlay,
+      commands::set_taskbar_progress,
+      commands::set_keep_awake,
+      commands::power_action,
+      commands::launcher_search,
+      commands::launcher_activate,
+      commands::launcher_icon,
+      commands::query_history,
+      commands::export_history,
+      commands::capture_widget,
+      commands::show_context_menu,
+      commands::show_tooltip,
+      commands::hide_tooltip,
+      commands::accessibility_prefs,
+      commands::screen_presence,
+      commands::git_sync_status,
+      commands::git_sync_pull,
+      commands::git_sync_commit_and_push,
+      commands::widget_heartbeat
     ])
     .build(tauri::generate_context!())?;
 
@@ -127,115 +279,715 @@ fn output_query(app: &tauri::App, args: QueryArgs) -> anyhow::Result<()> {
   }
 }
 
+/// Stores a secret via the CLI, reading the value from stdin if not
+/// provided as an argument.
+fn set_secret_from_cli(args: SetSecretArgs) -> anyhow::Result<String> {
+  let value = match args.value {
+    Some(value) => value,
+    None => read_stdin_line()?,
+  };
+
+  secrets::set_secret(&args.name, &value)?;
+  Ok(format!("Stored secret '{}'.\n", args.name))
+}
+
+/// Runs a provider command via the CLI.
+async fn run_provider_command(
+  app: &tauri::App,
+  config_dir_override: Option<PathBuf>,
+  args: ProviderArgs,
+) -> anyhow::Result<String> {
+  match args {
+    ProviderArgs::Get {
+      provider_type,
+      config,
+      timeout,
+    } => {
+      let mut config = match config {
+        Some(config) => serde_json::from_str::<serde_json::Value>(&config)
+          .context("Provider config is not valid JSON.")?,
+        None => serde_json::json!({}),
+      };
+
+      config
+        .as_object_mut()
+        .context("Provider config must be a JSON object.")?
+        .insert("type".into(), provider_type.into());
+
+      let config =
+        providers::parse_provider_config(config, &SecretAccess::Any)?;
+
+      // Use the proxy from the settings if they're readable, since the
+      // provider might otherwise be unable to reach its endpoint.
+      let proxy_config =
+        Config::resolve_config_dir(app.handle(), config_dir_override)
+          .ok()
+          .and_then(|config_dir| {
+            Config::read_settings(&config_dir).ok().flatten()
+          })
+          .and_then(|settings| settings.proxy);
+
+      let (manager, _) = ProviderManager::new(app.handle(), proxy_config);
+
+      let output = manager
+        .get_once(config, Duration::from_millis(timeout))
+        .await?;
+
+      Ok(serde_json::to_string_pretty(&output)? + "\n")
+    }
+  }
+}
+
+/// Outputs the provider JSON schema via the CLI, optionally to a file.
+fn output_provider_schema(args: SchemaArgs) -> anyhow::Result<String> {
+  let schema =
+    serde_json::to_string_pretty(&providers::provider_schema())?;
+
+  match args.output {
+    Some(path) => {
+      fs::write(&path, schema + "\n").with_context(|| {
+        format!("Failed to write schema to {}.", path.display())
+      })?;
+
+      Ok(format!("Wrote schema to {}.\n", path.display()))
+    }
+    None => Ok(schema + "\n"),
+  }
+}
+
+/// Exports recorded history via the CLI, optionally to a file.
+fn export_history(
+  app: &tauri::App,
+  args: ExportArgs,
+) -> anyhow::Result<String> {
+  let db_path = history_db_path(app.handle())
+    .context("No directory available for history.")?;
+
+  let start = chrono::Utc::now().timestamp_millis() - args.range as i64;
+
+  let export = history::export_from_file(
+    &db_path,
+    &args.metric,
+    start,
+    None,
+    args.resolution,
+    args.format,
+  )?;
+
+  match args.output {
+    Some(path) => {
+      fs::write(&path, export).with_context(|| {
+        format!("Failed to write export to {}.", path.display())
+      })?;
+
+      Ok(format!("Wrote export to {}.\n", path.display()))
+    }
+    None => Ok(export),
+  }
+}
+
+/// Restores a config backup via the CLI, or lists the available backups
+/// if no timestamp is given.
+fn restore_from_cli(
+  app: &tauri::App,
+  config_dir_override: Option<PathBuf>,
+  args: RestoreArgs,
+) -> anyhow::Result<String> {
+  let backups_dir = backups_dir(app.handle())
+    .context("No directory available for backups.")?;
+
+  let Some(timestamp) = args.timestamp else {
+    let timestamps = backup::list_backups(&backups_dir)?;
+
+    if timestamps.is_empty() {
+      return Ok(format!("No backups in {}.\n", backups_dir.display()));
+    }
+
+    return Ok(timestamps.join("\n") + "\n");
+  };
+
+  let config_dir =
+    Config::resolve_config_dir(app.handle(), config_dir_override)?;
+
+  let prev_timestamp =
+    backup::restore_backup(&config_dir, &backups_dir, &timestamp)?;
+
+  Ok(format!(
+    "Restored backup {} to {}. The previous config was backed up as {}.\n\
+    Run `zebar reload` to apply it to a running instance.\n",
+    timestamp,
+    config_dir.display(),
+    prev_timestamp,
+  ))
+}
+
+/// Runs a config encryption command via the CLI.
+fn run_encryption_command(args: EncryptionArgs) -> anyhow::Result<String> {
+  match args {
+    EncryptionArgs::Encrypt { value } => {
+      let value = match value {
+        Some(value) => value,
+        None => read_stdin_line()?,
+      };
+
+      Ok(config_encryption::encrypt_value(&value)? + "\n")
+    }
+    EncryptionArgs::ExportKey => {
+      Ok(config_encryption::export_encryption_key()? + "\n")
+    }
+    EncryptionArgs::ImportKey { key } => {
+      config_encryption::set_encryption_key(&key)?;
+      Ok("Stored encryption key.\n".to_string())
+    }
+  }
+}
+
+/// Reads a single line from stdin, without the trailing newline.
+fn read_stdin_line() -> anyhow::Result<String> {
+  let mut line = String::new();
+  std::io::stdin().read_line(&mut line)?;
+  Ok(line.trim_end_matches(['\r', '\n']).to_string())
+}
+
+/// Prints widget lifecycle events of a running instance to stdout as
+/// they're streamed from its asset server.
+async fn watch_widget_events(
+  app: &tauri::App,
+  args: WatchArgs,
+) -> anyhow::Result<String> {
+  let mut response =
+    request_running_instance(app, args.port, "/__zebar/events").await?;
+
+  let mut stdout = std::io::stdout();
+
+  while let Some(chunk) = response.chunk().await? {
+    stdout.write_all(&chunk)?;
+    stdout.flush()?;
+  }
+
+  Ok(String::new())
+}
+
+/// Sends a request to an endpoint of a running instance's asset server,
+/// authenticated with the CLI token that the instance has written to
+/// the data directory.
+async fn request_running_instance(
+  app: &tauri::App,
+  port: Option<u16>,
+  path: &str,
+) -> anyhow::Result<reqwest::Response> {
+  let port = port.unwrap_or(DEFAULT_ASSET_SERVER_PORT);
+  let not_running =
+    || anyhow::anyhow!("No running instance of Zebar found.");
+
+  let token = fs::read_to_string(cli_token_path(app.handle(), port)?)
+    .map_err(|_| not_running())?;
+
+  reqwest::Client::new()
+    .get(format!("http://127.0.0.1:{}{}", port, path))
+    .bearer_auth(token.trim())
+    .send()
+    .await
+    .and_then(|response| response.error_for_status())
+    .map_err(|_| not_running())
+}
+
+/// Outputs the usage stats of a running instance via the CLI.
+async fn output_usage_stats(
+  app: &tauri::App,
+  args: StatsArgs,
+) -> anyhow::Result<String> {
+  let report = request_running_instance(app, args.port, "/__zebar/stats")
+    .await?
+    .json::<UsageReport>()
+    .await?;
+
+  match args.json {
+    true => Ok(serde_json::to_string_pretty(&report)? + "\n"),
+    false => Ok(report.to_string()),
+  }
+}
+
 /// Starts Zebar - either with a specific widget or all widgets.
 async fn start_app(app: &mut tauri::App, cli: Cli) -> anyhow::Result<()> {
-  tracing_subscriber::fmt()
-    .with_env_filter(
+  // Initialize `ErrorLog` in Tauri state. Logs are written to stdout,
+  // the log file, and errors are additionally kept in memory for the
+  // system tray.
+  let is_new_instance = matches!(
+    cli.command(),
+    CliCommand::Startup(StartupArgs {
+      new_instance: true,
+      ..
+    })
+  );
+
+  // Running an additional instance with the same config directory would
+  // open every widget twice.
+  if is_new_instance && cli.config_dir.is_none() {
+    cli::print_and_exit(Err(anyhow::anyhow!(
+      "`--new-instance` requires `--config-dir`."
+    )));
+  }
+
+  // Additional instances write to a separate log file to avoid
+  // truncating the log of the original instance.
+  let log_file_name = match is_new_instance {
+    true => format!("zebar-{}.log", std::process::id()),
+    false => "zebar.log".to_string(),
+  };
+
+  // Logs are written next to the executable in portable mode.
+  let log_dir = match portable::log_dir() {
+    Some(dir) => dir,
+    None => app.path().app_log_dir()?,
+  };
+
+  let error_log = Arc::new(ErrorLog::new(log_dir.join(log_file_name)));
+  app.manage(error_log.clone());
+
+  let log_file_layer = match error_log.create_log_file() {
+    Ok(file) => Some(
+      fmt::layer()
+        .with_ansi(false)
+        .with_writer(std::sync::Mutex::new(file)),
+    ),
+    Err(err) => {
+      eprintln!("Failed to create log file: {:?}", err);
+      None
+    }
+  };
+
+  // Overrides are set once the settings have been read.
+  let provider_log_levels = ProviderLogLevels::default();
+
+  tracing_subscriber::registry()
+    .with(LogFilter::new(
       EnvFilter::from_env("LOG_LEVEL")
         .add_directive(LevelFilter::INFO.into()),
-    )
+      provider_log_levels.clone(),
+    ))
+    .with(fmt::layer())
+    .with(log_file_layer)
+    .with(error_log.layer())
     .init();
 
-  let config_dir_override = match cli.command() {
-    CliCommand::Startup(args) => args.config_dir,
-    _ => None,
-  };
+  if let Some(portable_dir) = portable::portable_dir() {
+    info!("Running in portable mode from {}.", portable_dir.display());
+  }
 
   // Initialize `Config` in Tauri state.
-  let config = Arc::new(Config::new(app.handle(), config_dir_override)?);
+  let config =
+    Arc::new(Config::new(app.handle(), cli.config_dir.clone())?);
   app.manage(config.clone());
 
+  provider_log_levels
+    .set(&config.settings.lock().await.provider_log_levels);
+
+  // Initialize translations for backend-generated text.
+  i18n::init(
+    config.settings.lock().await.locale.as_deref(),
+    &config.config_dir,
+  );
+
   // Initialize `MonitorState` in Tauri state.
   let monitor_state = Arc::new(MonitorState::new(app.handle()));
   app.manage(monitor_state.clone());
 
+  // Initialize `AccessibilityState` in Tauri state.
+  let accessibility_state =
+    Arc::new(AccessibilityState::new(app.handle()));
+  app.manage(accessibility_state.clone());
+
+  // Initialize `ScreenPresenceState` in Tauri state.
+  let screen_presence = Arc::new(ScreenPresenceState::new(app.handle()));
+  app.manage(screen_presence.clone());
+
   // Initialize `WidgetFactory` in Tauri state.
   let widget_factory = Arc::new(WidgetFactory::new(
     app.handle(),
     config.clone(),
     monitor_state.clone(),
+    accessibility_state,
   ));
   app.manage(widget_factory.clone());
 
+  // Detect unresponsive widgets via heartbeats.
+  let heartbeats = WidgetHeartbeats::new(
+    app.handle(),
+    config.clone(),
+    widget_factory.clone(),
+  );
+  app.manage(heartbeats.clone());
+
+  // Initialize `NativePopups` in Tauri state.
+  app.manage(Arc::new(NativePopups::new(app.handle())));
+
+  // Keep Zebar windows stacked in order of their layer.
+  WindowLayers::new(
+    app.handle(),
+    widget_factory.clone(),
+    monitor_state.clone(),
+  );
+
+  // Reload widgets in dev mode when their files change.
+  DevReload::new(app.handle(), widget_factory.clone());
+
+  // Reveal hidden widgets on touch swipes in from the screen edge.
+  TouchGestures::new(app.handle(), widget_factory.clone());
+
   // If this is not the first instance of the app, this will emit within
-  // the original instance and exit immediately. The CLI command is
-  // guaranteed to be one of the open commands here.
-  setup_single_instance(app, widget_factory.clone())?;
+  // the original instance and exit immediately. Skipped when explicitly
+  // starting an additional instance.
+  if !is_new_instance {
+    setup_single_instance(app, config.clone(), widget_factory.clone())?;
+  }
+
+  // Additional instances aren't covered by the single-instance plugin,
+  // so instances are also kept from sharing a config directory.
+  match InstanceLock::acquire(app.handle(), &config.config_dir) {
+    Ok(lock) => {
+      app.manage(lock);
+    }
+    Err(err) => cli::print_and_exit(Err(err)),
+  }
+
+  // Commands for controlling a running instance are invalid here, since
+  // this is the first instance. Otherwise, the CLI command is guaranteed
+  // to be one of the open commands.
+  if matches!(cli.command(), CliCommand::Reload | CliCommand::Exit) {
+    cli::print_and_exit(Err(anyhow::anyhow!(
+      "No running instance of Zebar found."
+    )));
+  }
 
-  setup_asset_server();
+  let show_tray =
+    !cli.no_tray && !config.settings.lock().await.hide_tray_icon;
 
   // Prevent windows from showing up in the dock on MacOS.
   #[cfg(target_os = "macos")]
   app.set_activation_policy(tauri::ActivationPolicy::Accessory);
 
-  // Allow assets to be resolved from the config directory.
-  app
-    .asset_protocol_scope()
-    .allow_directory(&config.config_dir, true)?;
-
   app.handle().plugin(tauri_plugin_shell::init())?;
   app.handle().plugin(tauri_plugin_http::init())?;
   app.handle().plugin(tauri_plugin_dialog::init())?;
+  app.handle().plugin(tauri_plugin_notification::init())?;
 
   // Initialize `ProviderManager` in Tauri state.
-  let (manager, emit_rx) = ProviderManager::new(app.handle());
+  let (manager, emit_rx) = ProviderManager::new(
+    app.handle(),
+    config.settings.lock().await.proxy.clone(),
+  );
   app.manage(manager.clone());
 
-  // Open widgets based on CLI command.
-  open_widgets_by_cli_command(cli, widget_factory.clone()).await?;
+  // Initialize `UsageStats` in Tauri state.
+  let usage_stats = UsageStats::new(
+    config.clone(),
+    manager.clone(),
+    widget_factory.clone(),
+    heartbeats,
+  );
+  app.manage(usage_stats.clone());
+
+  setup_asset_server(
+    app.handle(),
+    is_new_instance,
+    widget_factory.clone(),
+    usage_stats.clone(),
+  )
+  .await?;
 
-  // Add application icon to system tray.
-  let tray =
-    SysTray::new(app.handle(), config.clone(), widget_factory.clone())
-      .await?;
+  // Persist the profile to open widgets from.
+  if let CliCommand::Profile(args) = cli.command() {
+    config.set_active_profile(&args.name).await?;
+  }
 
-  listen_events(
+  // Widgets can't be shown without a webview runtime. Zebar then keeps
+  // running with only the tray icon, so that the runtime can be
+  // installed from the dialog.
+  match check_webview_runtime() {
+    Ok(version) => {
+      if let Some(version) = version {
+        info!("Using webview runtime {}.", version);
+      }
+
+      let wait_for = config.settings.lock().await.wait_for.clone();
+      let config = config.clone();
+      let widget_factory = widget_factory.clone();
+
+      task::spawn(async move {
+        // Wait in the background, so that the tray icon is available
+        // in the meantime.
+        if let Some(wait_for) = wait_for {
+          wait_for_target(&wait_for).await;
+        }
+
+        // Open widgets based on CLI command.
+        if let Err(err) =
+          open_widgets_by_cli_command(cli, widget_factory.clone()).await
+        {
+          error!("Failed to open widgets: {:?}", err);
+        }
+
+        // Open and close widgets based on their schedules.
+        Scheduler::start(config, widget_factory);
+      });
+    }
+    Err(err) => {
+      error!("Running without widgets: {:?}", err);
+      show_missing_runtime_dialog(app.handle());
+    }
+  }
+
+  // Initialize `LauncherManager` in Tauri state and register its hotkey.
+  let launcher = Arc::new(LauncherManager::new(
     app.handle(),
-    config,
-    monitor_state,
-    widget_factory,
-    tray,
-    manager,
+    config.clone(),
+    widget_factory.clone(),
+  ));
+  app.manage(launcher.clone());
+  app.handle().plugin(hotkey_plugin())?;
+
+  if let Err(err) = launcher.register_hotkey().await {
+    error!("Failed to register launcher hotkey: {:?}", err);
+  }
+
+  // Initialize `GitSync` in Tauri state, for pulling and pushing the
+  // config directory if it's a git repository.
+  let git_sync = GitSync::new(config.clone());
+  app.manage(git_sync.clone());
+
+  // Add application icon to system tray (unless running without one).
+  let tray = Arc::new(
+    SysTray::new(
+      app.handle(),
+      config.clone(),
+      error_log.clone(),
+      monitor_state.clone(),
+      widget_factory.clone(),
+      git_sync.clone(),
+      show_tray,
+    )
+    .await?,
+  );
+  app.manage(tray.clone());
+
+  // Bind window titles to provider outputs for widgets that use a
+  // `window_title` template.
+  let window_titles = Arc::new(WindowTitles::new(
+    app.handle(),
+    manager.clone(),
+    widget_factory.clone(),
+  ));
+
+  // Start alert rules from the settings.
+  let alerts = Alerts::new(app.handle(), config.clone(), manager.clone());
+
+  if let Err(err) = alerts.reload().await {
+    error!("Failed to start alerts: {:?}", err);
+  }
+
+  // Record provider metrics for `query_history`.
+  let history = HistoryRecorder::new(
+    app.handle(),
+    config.clone(),
+    manager.clone(),
+    history_db_path(app.handle()),
+  );
+  app.manage(history.clone());
+
+  if let Err(err) = history.reload().await {
+    error!("Failed to start history recording: {:?}", err);
+  }
+
+  // Bind the tray tooltip and icon to provider outputs.
+  let tray_binding = TrayBinding::new(
+    app.handle(),
+    config.clone(),
+    manager.clone(),
+    tray.clone(),
+  );
+
+  if let Err(err) = tray_binding.reload().await {
+    error!("Failed to bind system tray: {:?}", err);
+  }
+
+  // Periodically back up the config directory for `zebar restore`.
+  // Skipped for additional instances, which share the backups.
+  if !is_new_instance {
+    ConfigBackups::start(config.clone(), backups_dir(app.handle()));
+  }
+
+  listen_events(
+    EventContext {
+      app_handle: app.handle().clone(),
+      config,
+      error_log,
+      monitor_state,
+      widget_factory,
+      tray,
+      manager,
+      window_titles,
+      launcher,
+      alerts,
+      history,
+      tray_binding,
+      usage_stats,
+      provider_log_levels,
+      screen_presence,
+      git_sync,
+    },
     emit_rx,
   );
 
   Ok(())
 }
 
-fn listen_events(
-  app_handle: &AppHandle,
+/// State that's needed for handling app events in `listen_events`.
+struct EventContext {
+  app_handle: AppHandle,
   config: Arc<Config>,
+  error_log: Arc<ErrorLog>,
   monitor_state: Arc<MonitorState>,
   widget_factory: Arc<WidgetFactory>,
-  tray: SysTray,
+  tray: Arc<SysTray>,
   manager: Arc<ProviderManager>,
+  window_titles: Arc<WindowTitles>,
+  launcher: Arc<LauncherManager>,
+  alerts: Arc<Alerts>,
+  history: Arc<HistoryRecorder>,
+  tray_binding: Arc<TrayBinding>,
+  usage_stats: Arc<UsageStats>,
+  provider_log_levels: ProviderLogLevels,
+  screen_presence: Arc<ScreenPresenceState>,
+  git_sync: Arc<GitSync>,
+}
+
+fn listen_events(
+  context: EventContext,
   mut emit_rx: mpsc::UnboundedReceiver<ProviderEmission>,
 ) {
-  let app_handle = app_handle.clone();
+  let EventContext {
+    app_handle,
+    config,
+    error_log,
+    monitor_state,
+    widget_factory,
+    tray,
+    manager,
+    window_titles,
+    launcher,
+    alerts,
+    history,
+    tray_binding,
+    usage_stats,
+    provider_log_levels,
+    screen_presence,
+    git_sync,
+  } = context;
+
   let mut widget_open_rx = widget_factory.open_tx.subscribe();
   let mut widget_close_rx = widget_factory.close_tx.subscribe();
   let mut settings_change_rx = config.settings_change_tx.subscribe();
   let mut monitors_change_rx = monitor_state.change_tx.subscribe();
+  let mut errors_change_rx = error_log.change_tx.subscribe();
   let mut widget_configs_change_rx =
     config.widget_configs_change_tx.subscribe();
+  let mut presence_change_rx = screen_presence.change_tx.subscribe();
+  let mut git_sync_change_rx = git_sync.change_tx.subscribe();
 
   task::spawn(async move {
+    let mut pause_when_unattended =
+      config.settings.lock().await.pause_when_unattended;
+
+    let mut is_attended = screen_presence.presence().await.is_attended();
+
+    // Latest emission of each provider (by config hash) that was held
+    // back from widgets while the screen was unattended.
+    let mut held_emissions = HashMap::<String, ProviderEmission>::new();
+
+    // Widgets opened on startup were opened before subscribing.
+    for widget_state in widget_factory.states().await.values() {
+      if let Err(err) = window_titles.register(widget_state).await {
+        error!("Failed to bind window title: {:?}", err);
+      }
+    }
+
     loop {
       let res = tokio::select! {
         Ok(widget_state) = widget_open_rx.recv() => {
           info!("Widget opened.");
           let _ = tray.refresh().await;
+
+          if let Err(err) = window_titles.register(&widget_state).await {
+            error!("Failed to bind window title: {:?}", err);
+          }
+
           let _ = app_handle.emit("widget-opened", widget_state);
           Ok(())
         },
-        Ok(widget_id) = widget_close_rx.recv() => {
+        Ok(widget_state) = widget_close_rx.recv() => {
           info!("Widget closed.");
           let _ = tray.refresh().await;
-          let _ = app_handle.emit("widget-closed", widget_id);
+          window_titles.unregister(&widget_state.id).await;
+
+          // Stop providers that the widget didn't unlisten from (e.g. if
+          // its webview crashed). Windows that were reloaded in place
+          // still exist, and their providers were already stopped before
+          // the reload.
+          if app_handle.get_webview_window(&widget_state.id).is_none() {
+            manager.stop_by_subscriber(&widget_state.id).await;
+          }
+
+          let _ = app_handle.emit("widget-closed", widget_state);
           Ok(())
         },
-        Ok(_) = settings_change_rx.recv() => {
+        Ok(settings) = settings_change_rx.recv() => {
           info!("Settings changed.");
+          provider_log_levels.set(&settings.provider_log_levels);
+          pause_when_unattended = settings.pause_when_unattended;
+
+          if !pause_when_unattended {
+            for (_, provider_emission) in held_emissions.drain() {
+              let _ = app_handle.emit("provider-emit", provider_emission);
+            }
+          }
+
+          if let Err(err) = launcher.register_hotkey().await {
+            error!("Failed to register launcher hotkey: {:?}", err);
+          }
+
+          if let Err(err) = alerts.reload().await {
+            error!("Failed to reload alerts: {:?}", err);
+          }
+
+          if let Err(err) = history.reload().await {
+            error!("Failed to reload history recording: {:?}", err);
+          }
+
+          if let Err(err) = tray_binding.reload().await {
+            error!("Failed to reload tray binding: {:?}", err);
+          }
+
+          git_sync.refresh().await;
+
+          tray.refresh().await
+        },
+        Ok(presence) = presence_change_rx.recv() => {
+          is_attended = presence.is_attended();
+
+          // Send the emissions that were held back in the meantime.
+          if is_attended {
+            for (_, provider_emission) in held_emissions.drain() {
+              let _ = app_handle.emit("provider-emit", provider_emission);
+            }
+          }
+
+          Ok(())
+        },
+        Ok(_) = errors_change_rx.recv() => {
+          tray.refresh().await
+        },
+        Ok(_) = git_sync_change_rx.recv() => {
           tray.refresh().await
         },
         Ok(_) = monitors_change_rx.recv() => {
@@ -248,7 +1000,27 @@ fn listen_events(
         },
         Some(provider_emission) = emit_rx.recv() => {
           info!("Provider emission: {:?}", provider_emission);
-          app_handle.emit("provider-emit", provider_emission.clone());
+
+          if let Err(err) = &provider_emission.result {
+            error!("Provider error: {}", err);
+          }
+
+          // Only the emission to widgets is held back. Alerts, history,
+          // etc. still handle it as usual.
+          if pause_when_unattended && !is_attended {
+            held_emissions.insert(
+              provider_emission.config_hash.clone(),
+              provider_emission.clone(),
+            );
+          } else {
+            let _ = app_handle.emit("provider-emit", provider_emission.clone());
+          }
+
+          window_titles.handle_emission(&provider_emission).await;
+          alerts.handle_emission(&provider_emission).await;
+          history.handle_emission(&provider_emission).await;
+          tray_binding.handle_emission(&provider_emission).await;
+          usage_stats.handle_emission(&provider_emission).await;
           manager.update_cache(provider_emission).await;
           Ok(())
         },
@@ -264,22 +1036,33 @@ fn listen_events(
 /// Setup single instance Tauri plugin.
 fn setup_single_instance(
   app: &tauri::App,
+  config: Arc<Config>,
   widget_factory: Arc<WidgetFactory>,
 ) -> anyhow::Result<()> {
   app.handle().plugin(tauri_plugin_single_instance::init(
-    move |_, args, _| {
+    move |app_handle, args, _| {
+      let app_handle = app_handle.clone();
+      let config = config.clone();
       let widget_factory = widget_factory.clone();
 
       task::spawn(async move {
         let res = match Cli::try_parse_from(args) {
-          Ok(cli) => {
+          Ok(cli) => match cli.command() {
             // No-op if no subcommand is provided.
-            if cli.command() != CliCommand::Empty {
-              open_widgets_by_cli_command(cli, widget_factory).await
-            } else {
+            CliCommand::Empty => Ok(()),
+            CliCommand::Reload => {
+              widget_factory.clear_cache();
+              config.reload().await
+            }
+            CliCommand::Exit => {
+              app_handle.exit(0);
               Ok(())
             }
-          }
+            CliCommand::Profile(args) => {
+              widget_factory.switch_profile(&args.name).await
+            }
+            _ => open_widgets_by_cli_command(cli, widget_factory).await,
+          },
           _ => Err(anyhow::anyhow!("Failed to parse CLI arguments.")),
         };
 
@@ -315,6 +1098,7 @@ async fn open_widgets_by_cli_command(
               MonitorType::Secondary => MonitorSelection::Secondary,
             },
             dock_to_edge: Default::default(),
+            query: Default::default(),
           }),
         )
         .await
@@ -327,9 +1111,9 @@ async fn open_widgets_by_cli_command(
         )
         .await
     }
-    CliCommand::Startup(_) | CliCommand::Empty => {
-      widget_factory.startup().await
-    }
+    CliCommand::Startup(_)
+    | CliCommand::Profile(_)
+    | CliCommand::Empty => widget_factory.startup().await,
     _ => unreachable!(),
   };
 
@@ -8,7 +8,7 @@ use tokio::{
 };
 use tracing::info;
 
-use crate::config::MonitorSelection;
+use crate::{common::Edid, config::MonitorSelection};
 
 pub struct MonitorState {
   _change_rx: broadcast::Receiver<Vec<Monitor>>,
@@ -29,6 +29,10 @@ pub struct Monitor {
   pub width: u32,
   pub height: u32,
   pub scale_factor: f32,
+
+  /// Hardware ID from the monitor's EDID. Unlike the index and name, it
+  /// stays the same across reboots and driver updates.
+  pub hardware_id: Option<String>,
 }
 
 impl MonitorState {
@@ -108,6 +112,8 @@ impl MonitorState {
             width: monitor.size().width,
             height: monitor.size().height,
             scale_factor: monitor.scale_factor() as f32,
+            hardware_id: Self::monitor_edid(&monitor)
+              .map(|edid| edid.hardware_id()),
           })
           .collect()
       })
@@ -135,6 +141,12 @@ impl MonitorState {
     Ok(monitors_str)
   }
 
+  /// Returns available monitors sorted from left-to-right and
+  /// top-to-bottom.
+  pub async fn monitors(&self) -> Vec<Monitor> {
+    self.monitors.read().await.clone()
+  }
+
   pub async fn monitors_by_selection(
     &self,
     monitor_selection: &MonitorSelection,
@@ -158,6 +170,143 @@ impl MonitorState {
         .into_iter()
         .filter(|monitor| monitor.name.as_deref() == Some(name))
         .collect(),
+      MonitorSelection::HardwareId(hardware_id) => {
+        let exact_matches = monitors
+          .iter()
+          .filter(|monitor| {
+            monitor.hardware_id.as_deref() == Some(hardware_id)
+          })
+          .cloned()
+          .collect::<Vec<_>>();
+
+        if !exact_matches.is_empty() {
+          return exact_matches;
+        }
+
+        // Fall back to matching the model ID, since the serial number
+        // isn't always passed through (e.g. by some docks and KVM
+        // switches).
+        let model_id = Self::model_id(hardware_id);
+
+        monitors
+          .into_iter()
+          .filter(|monitor| {
+            monitor
+              .hardware_id
+              .as_deref()
+              .is_some_and(|id| Self::model_id(id) == model_id)
+          })
+          .collect()
+      }
     }
   }
+
+  /// Gets the model ID portion of a hardware ID (e.g. `DEL40F0` from
+  /// `DEL40F0-7MT0166B1RJL`).
+  fn model_id(hardware_id: &str) -> &str {
+    hardware_id
+      .split_once('-')
+      .map_or(hardware_id, |(model_id, _)| model_id)
+  }
+
+  /// Reads the EDID of a monitor from the registry, via the device
+  /// instance of the monitor's display device.
+  #[cfg(target_os = "windows")]
+  fn monitor_edid(monitor: &tauri::Monitor) -> Option<Edid> {
+    use windows::{
+      core::HSTRING,
+      Win32::{
+        Graphics::Gdi::{EnumDisplayDevicesW, DISPLAY_DEVICEW},
+        System::Registry::{
+          RegGetValueW, HKEY_LOCAL_MACHINE, RRF_RT_REG_BINARY,
+        },
+        UI::WindowsAndMessaging::EDD_GET_DEVICE_INTERFACE_NAME,
+      },
+    };
+
+    let mut device = DISPLAY_DEVICEW {
+      cb: std::mem::size_of::<DISPLAY_DEVICEW>() as u32,
+      ..Default::default()
+    };
+
+    unsafe {
+      EnumDisplayDevicesW(
+        &HSTRING::from(monitor.name()?.as_str()),
+        0,
+        &mut device,
+        EDD_GET_DEVICE_INTERFACE_NAME,
+      )
+    }
+    .as_bool()
+    .then_some(())?;
+
+    // Device interface name is of the format
+    // `\\?\DISPLAY#DEL40F0#5&2e2fefb3&0&UID4352#{GUID}`, and maps to
+    // the device instance `DISPLAY\DEL40F0\5&2e2fefb3&0&UID4352`.
+    let interface_name = String::from_utf16_lossy(&device.DeviceID);
+    let instance_path = interface_name
+      .trim_end_matches('\0')
+      .trim_start_matches(r"\\?\")
+      .split('#')
+      .take(3)
+      .collect::<Vec<_>>()
+      .join("\\");
+
+    let key = HSTRING::from(format!(
+      r"SYSTEM\CurrentControlSet\Enum\{}\Device Parameters",
+      instance_path
+    ));
+
+    let mut edid = vec![0u8; 256];
+    let mut edid_len = edid.len() as u32;
+
+    unsafe {
+      RegGetValueW(
+        HKEY_LOCAL_MACHINE,
+        &key,
+        &HSTRING::from("EDID"),
+        RRF_RT_REG_BINARY,
+        None,
+        Some(edid.as_mut_ptr() as _),
+        Some(&mut edid_len),
+      )
+    }
+    .ok()
+    .ok()?;
+
+    edid.truncate(edid_len as usize);
+    Edid::parse(&edid)
+  }
+
+  /// Reads the EDID of a monitor from the DRM connectors in sysfs.
+  #[cfg(target_os = "linux")]
+  fn monitor_edid(monitor: &tauri::Monitor) -> Option<Edid> {
+    let name = monitor.name()?;
+
+    std::fs::read_dir("/sys/class/drm")
+      .ok()?
+      .flatten()
+      .filter_map(|entry| {
+        // Connector directories are named e.g. `card0-DP-1`.
+        let dir_name = entry.file_name().to_string_lossy().into_owned();
+        let (_, connector) = dir_name.split_once('-')?;
+
+        let edid = std::fs::read(entry.path().join("edid")).ok()?;
+        Some((connector.to_string(), Edid::parse(&edid)?))
+      })
+      .find(|(connector, edid)| {
+        // GDK uses the connector name as the monitor name on X11 (e.g.
+        // `DP-1`, or `DP1` with some drivers), and the model name from
+        // the EDID on Wayland.
+        connector == name
+          || connector.replace('-', "") == *name
+          || edid.name.as_ref() == Some(name)
+      })
+      .map(|(_, edid)| edid)
+  }
+
+  #[cfg(target_os = "macos")]
+  fn monitor_edid(_monitor: &tauri::Monitor) -> Option<Edid> {
+    None
+  }
 }
@@ -0,0 +1,303 @@
+use std::sync::{Arc, Mutex};
+
+use serde::Deserialize;
+use tauri::{
+  menu::{
+    CheckMenuItem, IsMenuItem, Menu, MenuItem, PredefinedMenuItem, Submenu,
+  },
+  AppHandle, LogicalPosition, Runtime, Window,
+};
+use tokio::sync::oneshot;
+use uuid::Uuid;
+
+#[cfg(target_os = "linux")]
+use crate::common::linux;
+#[cfg(target_os = "windows")]
+use crate::common::windows;
+
+/// Prefix of the ID's of context menu items, followed by the menu ID and
+/// the item's own ID (e.g. `ctx-menu:<menu_id>:<item_id>`).
+const MENU_ID_PREFIX: &str = "ctx-menu:";
+
+/// How long to wait for the selection of a context menu after it's
+/// closed. The selection is dispatched via the event loop, so it can
+/// arrive slightly after the menu is closed.
+#[cfg(not(target_os = "linux"))]
+const SELECTION_TIMEOUT: std::time::Duration =
+  std::time::Duration::from_millis(500);
+
+/// Item of a context menu shown via `show_context_menu`.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ContextMenuItem {
+  /// Clickable item. Shown with a checkmark if `checked` is set.
+  #[serde(rename_all = "camelCase")]
+  Item {
+    id: String,
+    label: String,
+    #[serde(default)]
+    disabled: bool,
+    checked: Option<bool>,
+  },
+
+  /// Line between items.
+  Separator,
+
+  /// Nested menu.
+  #[serde(rename_all = "camelCase")]
+  Submenu {
+    label: String,
+    #[serde(default)]
+    disabled: bool,
+    items: Vec<ContextMenuItem>,
+  },
+}
+
+/// Native context menus for widgets.
+///
+/// These aren't clipped by the bounds of the widget's window, unlike
+/// HTML popups (e.g. for a bar that's only a few pixels tall).
+pub struct NativePopups {
+  app_handle: AppHandle,
+
+  /// ID of the currently open context menu, and the sender for its
+  /// selected item. Replaced when another menu is shown, which resolves
+  /// the previous menu without a selection.
+  pending_menu: Arc<Mutex<Option<(String, oneshot::Sender<String>)>>>,
+}
+
+impl NativePopups {
+  pub fn new(app_handle: &AppHandle) -> Self {
+    let pending_menu =
+      Arc::new(Mutex::new(None::<(String, oneshot::Sender<String>)>));
+
+    app_handle.on_menu_event({
+      let pending_menu = pending_menu.clone();
+
+      move |_, event| {
+        let Some((menu_id, item_id)) = event
+          .id()
+          .as_ref()
+          .strip_prefix(MENU_ID_PREFIX)
+          .and_then(|id| id.split_once(':'))
+        else {
+          return;
+        };
+
+        let mut pending_menu = pending_menu.lock().unwrap();
+
+        if pending_menu.as_ref().is_some_and(|(id, _)| id == menu_id) {
+          if let Some((_, selection_tx)) = pending_menu.take() {
+            let _ = selection_tx.send(item_id.to_string());
+          }
+        }
+      }
+    });
+
+    Self {
+      app_handle: app_handle.clone(),
+      pending_menu,
+    }
+  }
+
+  /// Shows a context menu at the given position (in logical pixels
+  /// relative to the window), or at the cursor if no position is given.
+  ///
+  /// Returns the ID of the selected item, or `None` if the menu was
+  /// closed without a selection.
+  pub async fn show_context_menu(
+    &self,
+    window: &Window,
+    items: &[ContextMenuItem],
+    position: Option<LogicalPosition<f64>>,
+  ) -> anyhow::Result<Option<String>> {
+    let menu_id = Uuid::new_v4().simple().to_string();
+    let menu_items = create_menu_items(&self.app_handle, &menu_id, items)?;
+
+    let menu = Menu::with_items(
+      &self.app_handle,
+      &menu_items
+        .iter()
+        .map(|item| item.as_ref())
+        .collect::<Vec<_>>(),
+    )?;
+
+    let (selection_tx, selection_rx) = oneshot::channel();
+    *self.pending_menu.lock().unwrap() = Some((menu_id, selection_tx));
+
+    // On Linux, showing the menu doesn't block until it's closed. The
+    // selection is instead awaited until another menu is shown.
+    #[cfg(target_os = "linux")]
+    {
+      popup_menu(window, &menu, position)?;
+      Ok(selection_rx.await.ok())
+    }
+
+    // On Windows and MacOS, showing the menu blocks the main thread
+    // until it's closed.
+    #[cfg(not(target_os = "linux"))]
+    {
+      let (closed_tx, closed_rx) = oneshot::channel();
+
+      window.run_on_main_thread({
+        let window = window.clone();
+
+        move || {
+          if let Err(err) = popup_menu(&window, &menu, position) {
+            tracing::warn!("Failed to show context menu: {:?}", err);
+          }
+
+          let _ = closed_tx.send(());
+        }
+      })?;
+
+      let _ = closed_rx.await;
+
+      Ok(
+        tokio::time::timeout(SELECTION_TIMEOUT, selection_rx)
+          .await
+          .ok()
+          .and_then(|selection| selection.ok()),
+      )
+    }
+  }
+}
+
+fn popup_menu<R: Runtime>(
+  window: &Window<R>,
+  menu: &Menu<R>,
+  position: Option<LogicalPosition<f64>>,
+) -> tauri::Result<()> {
+  match position {
+    Some(position) => window.popup_menu_at(menu, position),
+    None => window.popup_menu(menu),
+  }
+}
+
+/// Creates the native items of a context menu, recursively for
+/// submenus.
+fn create_menu_items(
+  app_handle: &AppHandle,
+  menu_id: &str,
+  items: &[ContextMenuItem],
+) -> anyhow::Result<Vec<Box<dyn IsMenuItem<tauri::Wry>>>> {
+  let mut menu_items = Vec::<Box<dyn IsMenuItem<tauri::Wry>>>::new();
+
+  for item in items {
+    match item {
+      ContextMenuItem::Item {
+        id,
+        label,
+        disabled,
+        checked,
+      } => {
+        let id = format!("{}{}:{}", MENU_ID_PREFIX, menu_id, id);
+
+        match checked {
+          Some(checked) => {
+            menu_items.push(Box::new(CheckMenuItem::with_id(
+              app_handle,
+              id,
+              label,
+              !disabled,
+              *checked,
+              None::<&str>,
+            )?))
+          }
+          None => menu_items.push(Box::new(MenuItem::with_id(
+            app_handle,
+            id,
+            label,
+            !disabled,
+            None::<&str>,
+          )?)),
+        }
+      }
+      ContextMenuItem::Separator => menu_items
+        .push(Box::new(PredefinedMenuItem::separator(app_handle)?)),
+      ContextMenuItem::Submenu {
+        label,
+        disabled,
+        items,
+      } => {
+        let submenu_items = create_menu_items(app_handle, menu_id, items)?;
+
+        menu_items.push(Box::new(Submenu::with_items(
+          app_handle,
+          label,
+          !disabled,
+          &submenu_items
+            .iter()
+            .map(|item| item.as_ref())
+            .collect::<Vec<_>>(),
+        )?));
+      }
+    }
+  }
+
+  Ok(menu_items)
+}
+
+/// Shows a native tooltip at the given position (in logical pixels
+/// relative to the window), replacing any open tooltip of the window.
+pub fn show_tooltip(
+  window: &Window,
+  text: String,
+  position: LogicalPosition<f64>,
+) -> anyhow::Result<()> {
+  let window_position = window.inner_position()?;
+  let scale_factor = window.scale_factor()?;
+
+  #[cfg(target_os = "windows")]
+  {
+    let offset = position.to_physical::<i32>(scale_factor);
+
+    windows::show_tooltip(
+      window,
+      text,
+      tauri::PhysicalPosition::new(
+        window_position.x + offset.x,
+        window_position.y + offset.y,
+      ),
+    )
+  }
+
+  #[cfg(target_os = "linux")]
+  {
+    let window_position = window_position.to_logical::<f64>(scale_factor);
+
+    linux::show_tooltip(
+      window,
+      text,
+      LogicalPosition::new(
+        (window_position.x + position.x) as i32,
+        (window_position.y + position.y) as i32,
+      ),
+    )
+  }
+
+  #[cfg(target_os = "macos")]
+  {
+    let _ = (text, position, window_position, scale_factor);
+    anyhow::bail!("Native tooltips are not yet supported on MacOS.")
+  }
+}
+
+/// Hides the open tooltip of the window (if any).
+pub fn hide_tooltip(window: &Window) -> anyhow::Result<()> {
+  #[cfg(target_os = "windows")]
+  {
+    windows::hide_tooltip(window)
+  }
+
+  #[cfg(target_os = "linux")]
+  {
+    linux::hide_tooltip(window)
+  }
+
+  #[cfg(target_os = "macos")]
+  {
+    let _ = window;
+    Ok(())
+  }
+}
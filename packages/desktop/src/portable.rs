@@ -0,0 +1,41 @@
+use std::{path::PathBuf, sync::OnceLock};
+
+/// Name of the marker file that enables portable mode when placed next
+/// to the executable.
+const PORTABLE_MARKER_FILE: &str = "portable";
+
+/// Directory of the executable if running in portable mode.
+static PORTABLE_DIR: OnceLock<Option<PathBuf>> = OnceLock::new();
+
+/// Returns the directory of the executable if a `portable` marker file
+/// exists next to it.
+///
+/// In portable mode, config, logs, and webview data are stored
+/// alongside the executable instead of in the user profile.
+pub fn portable_dir() -> Option<&'static PathBuf> {
+  PORTABLE_DIR
+    .get_or_init(|| {
+      let exe_dir = std::env::current_exe().ok()?.parent()?.to_path_buf();
+
+      exe_dir
+        .join(PORTABLE_MARKER_FILE)
+        .is_file()
+        .then_some(exe_dir)
+    })
+    .as_ref()
+}
+
+/// Default config directory in portable mode (`<exe_dir>/config`).
+pub fn config_dir() -> Option<PathBuf> {
+  portable_dir().map(|dir| dir.join("config"))
+}
+
+/// Log directory in portable mode (`<exe_dir>/logs`).
+pub fn log_dir() -> Option<PathBuf> {
+  portable_dir().map(|dir| dir.join("logs"))
+}
+
+/// Webview data directory in portable mode (`<exe_dir>/data`).
+pub fn data_dir() -> Option<PathBuf> {
+  portable_dir().map(|dir| dir.join("data"))
+}
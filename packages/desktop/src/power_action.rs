@@ -0,0 +1,154 @@
+use std::fmt;
+
+use anyhow::{bail, Context};
+use serde::Deserialize;
+
+use crate::{common::no_window_command, i18n::t};
+
+/// System power action that widgets can invoke via `power_action`.
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum PowerAction {
+  Lock,
+  Logout,
+  Sleep,
+  Hibernate,
+  Restart,
+  Shutdown,
+}
+
+impl PowerAction {
+  /// Question shown in the confirmation dialog.
+  pub fn confirm_message(&self) -> String {
+    t(&format!("power.confirm.{}", self))
+  }
+
+  /// Label of the confirm button in the confirmation dialog.
+  pub fn label(&self) -> String {
+    t(&format!("power.{}", self))
+  }
+
+  /// Performs the power action.
+  pub fn run(&self) -> anyhow::Result<()> {
+    #[cfg(target_os = "windows")]
+    {
+      use windows::Win32::{
+        Foundation::BOOLEAN,
+        System::{Power::SetSuspendState, Shutdown::LockWorkStation},
+      };
+
+      match self {
+        PowerAction::Lock => {
+          return unsafe { LockWorkStation() }
+            .context("Failed to lock workstation.");
+        }
+        PowerAction::Sleep | PowerAction::Hibernate => {
+          let hibernate = BOOLEAN::from(*self == PowerAction::Hibernate);
+
+          let res =
+            unsafe { SetSuspendState(hibernate, BOOLEAN(0), BOOLEAN(0)) };
+
+          if !res.as_bool() {
+            bail!("Failed to suspend the system.");
+          }
+
+          return Ok(());
+        }
+        _ => {}
+      }
+    }
+
+    let (program, args) = self.command()?;
+
+    let output = no_window_command(program)
+      .args(&args)
+      .output()
+      .with_context(|| format!("Unable to run `{}`.", program))?;
+
+    if !output.status.success() {
+      bail!(
+        "`{}` failed: {}",
+        program,
+        String::from_utf8_lossy(&output.stderr).trim()
+      );
+    }
+
+    Ok(())
+  }
+
+  /// Program and arguments that perform the power action.
+  #[cfg(target_os = "windows")]
+  fn command(&self) -> anyhow::Result<(&'static str, Vec<String>)> {
+    let args = match self {
+      PowerAction::Logout => vec!["/l"],
+      PowerAction::Restart => vec!["/r", "/t", "0"],
+      PowerAction::Shutdown => vec!["/s", "/t", "0"],
+      _ => unreachable!("Handled via the Win32 API."),
+    };
+
+    Ok(("shutdown", args.into_iter().map(String::from).collect()))
+  }
+
+  /// Program and arguments that perform the power action.
+  #[cfg(target_os = "macos")]
+  fn command(&self) -> anyhow::Result<(&'static str, Vec<String>)> {
+    let system_events = |action: &str| {
+      (
+        "osascript",
+        vec![
+          "-e".into(),
+          format!("tell application \"System Events\" to {}", action),
+        ],
+      )
+    };
+
+    Ok(match self {
+      // Turning off the display locks the session if a password is
+      // required after sleep, which is the default.
+      PowerAction::Lock => ("pmset", vec!["displaysleepnow".into()]),
+      PowerAction::Sleep => ("pmset", vec!["sleepnow".into()]),
+      PowerAction::Hibernate => {
+        bail!("Hibernate is not supported on MacOS.")
+      }
+      PowerAction::Logout => system_events("log out"),
+      PowerAction::Restart => system_events("restart"),
+      PowerAction::Shutdown => system_events("shut down"),
+    })
+  }
+
+  /// Program and arguments that perform the power action.
+  #[cfg(target_os = "linux")]
+  fn command(&self) -> anyhow::Result<(&'static str, Vec<String>)> {
+    let (program, args) = match self {
+      PowerAction::Lock => ("loginctl", vec!["lock-session".into()]),
+      PowerAction::Logout => {
+        let session_id = std::env::var("XDG_SESSION_ID").context(
+          "No login session found (`XDG_SESSION_ID` is unset).",
+        )?;
+
+        ("loginctl", vec!["terminate-session".into(), session_id])
+      }
+      PowerAction::Sleep => ("systemctl", vec!["suspend".into()]),
+      PowerAction::Hibernate => ("systemctl", vec!["hibernate".into()]),
+      PowerAction::Restart => ("systemctl", vec!["reboot".into()]),
+      PowerAction::Shutdown => ("systemctl", vec!["poweroff".into()]),
+    };
+
+    Ok((program, args))
+  }
+}
+
+impl fmt::Display for PowerAction {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    let name = match self {
+      PowerAction::Lock => "lock",
+      PowerAction::Logout => "logout",
+      PowerAction::Sleep => "sleep",
+      PowerAction::Hibernate => "hibernate",
+      PowerAction::Restart => "restart",
+      PowerAction::Shutdown => "shutdown",
+    };
+
+    write!(f, "{}", name)
+  }
+}
@@ -0,0 +1,71 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::providers::ProviderAttachment;
+
+#[derive(Deserialize, Debug, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct AudioProviderConfig {
+  /// How often to sample the input level of the default recording
+  /// device in milliseconds. Input level metering is disabled if unset.
+  /// Only supported on Windows.
+  ///
+  /// Note that metering keeps a capture stream open, so Windows will
+  /// show the microphone as being in use.
+  pub input_level_interval: Option<u64>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct AudioOutput {
+  pub playback_devices: Vec<AudioDevice>,
+  pub recording_devices: Vec<AudioDevice>,
+  pub all_devices: Vec<AudioDevice>,
+  pub default_playback_device: Option<AudioDevice>,
+  pub default_recording_device: Option<AudioDevice>,
+
+  /// Peak input level (0-100) of the default recording device. Only set
+  /// when `input_level_interval` is configured.
+  pub input_level: Option<u32>,
+
+  /// Apps playing audio on the default playback device.
+  pub sessions: Vec<AudioSession>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct AudioDevice {
+  pub name: String,
+  pub device_id: String,
+  pub device_type: DeviceType,
+  pub volume: u32,
+  pub is_muted: bool,
+  pub is_default_playback: bool,
+  pub is_default_recording: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub enum DeviceType {
+  Playback,
+  Recording,
+}
+
+/// Audio stream of an app (e.g. a browser playing a video).
+#[derive(Debug, Clone, PartialEq, Serialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct AudioSession {
+  pub session_id: String,
+
+  /// Display name of the app (e.g. `Firefox`).
+  pub name: String,
+  pub process_id: Option<u32>,
+
+  /// Icon of the app, if one could be found.
+  pub icon: Option<ProviderAttachment>,
+  pub volume: u32,
+  pub is_muted: bool,
+
+  /// Whether the app is currently playing audio.
+  pub is_active: bool,
+}
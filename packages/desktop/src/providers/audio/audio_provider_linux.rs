@@ -0,0 +1,450 @@
+use std::{
+  collections::HashMap,
+  io::{BufRead, BufReader},
+  path::PathBuf,
+  process::{Command, Stdio},
+  time::{Duration, Instant},
+};
+
+use anyhow::{bail, Context};
+use crossbeam::channel::{self, at, never};
+use serde::{de::DeserializeOwned, Deserialize};
+use serde_json::Value;
+use tracing::debug;
+
+use super::{
+  AudioDevice, AudioOutput, AudioProviderConfig, AudioSession, DeviceType,
+};
+use crate::providers::{
+  AudioFunction, CommonProviderState, Provider, ProviderFunction,
+  ProviderFunctionResponse, ProviderInputMsg, RuntimeType,
+};
+
+/// Audio events can be frequent, so emissions within this delay are
+/// batched together.
+const BATCH_DELAY: Duration = Duration::from_millis(25);
+
+/// Icon sizes to look for in the `hicolor` theme, in order of
+/// preference.
+const ICON_SIZES: &[&str] =
+  &["48x48", "64x64", "128x128", "256x256", "32x32", "scalable"];
+
+/// Sink or source as returned by `pactl -f json list sinks`.
+#[derive(Deserialize)]
+struct PactlDevice {
+  index: u32,
+  name: String,
+  description: String,
+  mute: bool,
+  volume: HashMap<String, PactlVolume>,
+  #[serde(default)]
+  monitor_of_sink: Option<String>,
+}
+
+/// Sink input (i.e. an app's playback stream) as returned by
+/// `pactl -f json list sink-inputs`.
+#[derive(Deserialize)]
+struct PactlSinkInput {
+  index: u32,
+  sink: u32,
+  mute: bool,
+  corked: bool,
+  volume: HashMap<String, PactlVolume>,
+  properties: HashMap<String, Value>,
+}
+
+impl PactlSinkInput {
+  /// Gets a string property of the stream (e.g. `application.name`).
+  fn property(&self, key: &str) -> Option<&str> {
+    self.properties.get(key).and_then(|value| value.as_str())
+  }
+}
+
+/// Volume of a single channel.
+#[derive(Deserialize)]
+struct PactlVolume {
+  /// Volume as a percentage string (e.g. `50%`).
+  value_percent: String,
+}
+
+pub struct AudioProvider {
+  common: CommonProviderState,
+
+  /// Latest output, used to look up device types in function calls.
+  prev_output: Option<AudioOutput>,
+
+  /// App icons and their MIME types by icon name.
+  icon_cache: HashMap<String, Option<(Vec<u8>, &'static str)>>,
+}
+
+impl AudioProvider {
+  pub fn new(
+    _config: AudioProviderConfig,
+    common: CommonProviderState,
+  ) -> Self {
+    Self {
+      common,
+      prev_output: None,
+      icon_cache: HashMap::new(),
+    }
+  }
+
+  /// Main entry point.
+  ///
+  /// Uses `pactl`, which works with both PipeWire (via `pipewire-pulse`)
+  /// and PulseAudio.
+  fn start(&mut self) -> anyhow::Result<()> {
+    let (event_tx, event_rx) = channel::unbounded();
+
+    let mut subscribe = Command::new("pactl")
+      .arg("subscribe")
+      .stdout(Stdio::piped())
+      .spawn()
+      .context("Unable to run `pactl`. Is PipeWire installed?")?;
+
+    let stdout = subscribe
+      .stdout
+      .take()
+      .context("Failed to read `pactl subscribe` output.")?;
+
+    // Forward relevant events (e.g. `Event 'change' on sink #52`) from
+    // `pactl subscribe`.
+    std::thread::spawn(move || {
+      for line in BufReader::new(stdout).lines().map_while(Result::ok) {
+        if line.contains(" on client ") || line.contains(" on module ") {
+          continue;
+        }
+
+        if event_tx.send(line).is_err() {
+          break;
+        }
+      }
+    });
+
+    self.emit_output();
+
+    let mut pending_emit: Option<Instant> = None;
+
+    let res = loop {
+      let batch_timer = match pending_emit {
+        Some(deadline) => at(deadline),
+        None => never(),
+      };
+
+      crossbeam::select! {
+        recv(event_rx) -> event => {
+          match event {
+            Ok(event) => {
+              debug!("Got audio event: {}", event);
+              pending_emit.get_or_insert(Instant::now() + BATCH_DELAY);
+            }
+            Err(_) => break Err(anyhow::anyhow!("`pactl subscribe` exited.")),
+          }
+        }
+        recv(self.common.input.sync_rx) -> input => {
+          match input {
+            Ok(ProviderInputMsg::Stop) => break Ok(()),
+            Ok(ProviderInputMsg::Function(
+              ProviderFunction::Audio(audio_function),
+              sender,
+            )) => {
+              let res = self.handle_function(audio_function).map_err(|err| err.to_string());
+              sender.send(res).unwrap();
+            }
+            _ => {}
+          }
+        }
+        recv(batch_timer) -> _ => {
+          pending_emit = None;
+          self.emit_output();
+        }
+      }
+    };
+
+    let _ = subscribe.kill();
+    let _ = subscribe.wait();
+
+    res
+  }
+
+  fn emit_output(&mut self) {
+    let output = self.query_output();
+
+    if let Ok(output) = &output {
+      self.prev_output = Some(output.clone());
+    }
+
+    self.common.emitter.emit_output_cached(output);
+  }
+
+  fn query_output(&mut self) -> anyhow::Result<AudioOutput> {
+    let default_sink = pactl(&["get-default-sink"])?.trim().to_string();
+    let default_source =
+      pactl(&["get-default-source"])?.trim().to_string();
+
+    let sinks = pactl_json::<Vec<PactlDevice>>(&["list", "sinks"])?;
+    let sources = pactl_json::<Vec<PactlDevice>>(&["list", "sources"])?;
+    let sink_inputs =
+      pactl_json::<Vec<PactlSinkInput>>(&["list", "sink-inputs"])?;
+
+    let to_device = |device: &PactlDevice, device_type| AudioDevice {
+      name: device.description.clone(),
+      device_id: device.name.clone(),
+      device_type,
+      volume: volume_percent(&device.volume),
+      is_muted: device.mute,
+      is_default_playback: device.name == default_sink,
+      is_default_recording: device.name == default_source,
+    };
+
+    let playback_devices = sinks
+      .iter()
+      .map(|sink| to_device(sink, DeviceType::Playback))
+      .collect::<Vec<_>>();
+
+    // Monitor sources capture the output of a sink, so they aren't
+    // recording devices.
+    let recording_devices = sources
+      .iter()
+      .filter(|source| {
+        !source.name.ends_with(".monitor")
+          && source
+            .monitor_of_sink
+            .as_ref()
+            .map_or(true, |sink| sink == "n/a")
+      })
+      .map(|source| to_device(source, DeviceType::Recording))
+      .collect::<Vec<_>>();
+
+    let default_sink_index = sinks
+      .iter()
+      .find(|sink| sink.name == default_sink)
+      .map(|sink| sink.index);
+
+    let mut sessions = sink_inputs
+      .iter()
+      .filter(|input| Some(input.sink) == default_sink_index)
+      .map(|input| self.to_session(input))
+      .collect::<Vec<_>>();
+
+    sessions.sort_by(|a, b| a.name.cmp(&b.name));
+
+    Ok(AudioOutput {
+      default_playback_device: playback_devices
+        .iter()
+        .find(|device| device.is_default_playback)
+        .cloned(),
+      default_recording_device: recording_devices
+        .iter()
+        .find(|device| device.is_default_recording)
+        .cloned(),
+      all_devices: playback_devices
+        .iter()
+        .chain(recording_devices.iter())
+        .cloned()
+        .collect(),
+      playback_devices,
+      recording_devices,
+      input_level: None,
+      sessions,
+    })
+  }
+
+  fn to_session(&mut self, input: &PactlSinkInput) -> AudioSession {
+    let name = input
+      .property("application.name")
+      .or_else(|| input.property("media.name"))
+      .unwrap_or("Unknown")
+      .to_string();
+
+    // Apps don't always set an icon name, in which case the binary name
+    // commonly matches the icon name.
+    let icon_names = [
+      input.property("application.icon_name").map(String::from),
+      input
+        .property("application.process.binary")
+        .map(String::from),
+      Some(name.to_lowercase()),
+    ];
+
+    let icon = icon_names
+      .into_iter()
+      .flatten()
+      .find_map(|icon_name| self.app_icon(&icon_name))
+      .map(|(data, mime_type)| {
+        self.common.emitter.attach(data, mime_type)
+      });
+
+    AudioSession {
+      session_id: input.index.to_string(),
+      name,
+      process_id: input
+        .property("application.process.id")
+        .and_then(|pid| pid.parse().ok()),
+      icon,
+      volume: volume_percent(&input.volume),
+      is_muted: input.mute,
+      is_active: !input.corked,
+    }
+  }
+
+  /// Gets an app icon from the `hicolor` icon theme or `pixmaps`.
+  fn app_icon(
+    &mut self,
+    icon_name: &str,
+  ) -> Option<(Vec<u8>, &'static str)> {
+    // Only look up plain icon names, and not paths.
+    if icon_name.is_empty() || icon_name.contains('/') {
+      return None;
+    }
+
+    self
+      .icon_cache
+      .entry(icon_name.to_string())
+      .or_insert_with(|| {
+        icon_dirs().iter().find_map(|dir| {
+          [("png", "image/png"), ("svg", "image/svg+xml")]
+            .iter()
+            .find_map(|(extension, mime_type)| {
+              std::fs::read(dir.join(format!("{icon_name}.{extension}")))
+                .ok()
+                .map(|data| (data, *mime_type))
+            })
+        })
+      })
+      .clone()
+  }
+
+  fn handle_function(
+    &mut self,
+    function: AudioFunction,
+  ) -> anyhow::Result<ProviderFunctionResponse> {
+    match function {
+      AudioFunction::SetVolume(args) => {
+        let volume = format!("{}%", args.volume.round() as u32);
+
+        let is_recording = args.device_id.as_ref().is_some_and(|id| {
+          self.prev_output.as_ref().is_some_and(|output| {
+            output
+              .recording_devices
+              .iter()
+              .any(|device| &device.device_id == id)
+          })
+        });
+
+        match (is_recording, &args.device_id) {
+          (true, Some(id)) => {
+            pactl(&["set-source-volume", id, &volume])?;
+          }
+          (_, id) => {
+            let id = id.as_deref().unwrap_or("@DEFAULT_SINK@");
+            pactl(&["set-sink-volume", id, &volume])?;
+          }
+        }
+      }
+      AudioFunction::ToggleMicMute(args) => {
+        let id = args.device_id.as_deref().unwrap_or("@DEFAULT_SOURCE@");
+        pactl(&["set-source-mute", id, "toggle"])?;
+      }
+      AudioFunction::SetAppVolume(args) => {
+        pactl(&[
+          "set-sink-input-volume",
+          &args.session_id,
+          &format!("{}%", args.volume.round() as u32),
+        ])?;
+      }
+      AudioFunction::SetAppMute(args) => {
+        let is_muted = if args.is_muted { "1" } else { "0" };
+        pactl(&["set-sink-input-mute", &args.session_id, is_muted])?;
+      }
+    }
+
+    Ok(ProviderFunctionResponse::Null)
+  }
+}
+
+impl Provider for AudioProvider {
+  fn runtime_type(&self) -> RuntimeType {
+    RuntimeType::Sync
+  }
+
+  fn start_sync(&mut self) {
+    if let Err(err) = self.start() {
+      tracing::error!("Error starting audio provider: {}", err);
+      self.common.emitter.emit_output::<AudioOutput>(Err(err));
+    }
+  }
+}
+
+fn pactl(args: &[&str]) -> anyhow::Result<String> {
+  let output = Command::new("pactl")
+    .args(args)
+    .output()
+    .context("Unable to run `pactl`. Is PipeWire installed?")?;
+
+  if !output.status.success() {
+    bail!(
+      "`pactl {}` failed: {}",
+      args.join(" "),
+      String::from_utf8_lossy(&output.stderr).trim()
+    );
+  }
+
+  Ok(String::from_utf8_lossy(&output.stdout).to_string())
+}
+
+fn pactl_json<T: DeserializeOwned>(args: &[&str]) -> anyhow::Result<T> {
+  let output = pactl(&[&["-f", "json"][..], args].concat())?;
+
+  serde_json::from_str(&output).with_context(|| {
+    format!("Failed to parse `pactl {}`.", args.join(" "))
+  })
+}
+
+/// Gets the volume of the loudest channel as a percentage.
+fn volume_percent(volume: &HashMap<String, PactlVolume>) -> u32 {
+  volume
+    .values()
+    .filter_map(|channel| {
+      channel
+        .value_percent
+        .trim_end_matches('%')
+        .trim()
+        .parse()
+        .ok()
+    })
+    .max()
+    .unwrap_or(0)
+}
+
+/// Directories to search for app icons, in order of preference.
+fn icon_dirs() -> Vec<PathBuf> {
+  let data_home = std::env::var("XDG_DATA_HOME")
+    .ok()
+    .map(PathBuf::from)
+    .or_else(|| {
+      std::env::var("HOME")
+        .ok()
+        .map(|home| PathBuf::from(home).join(".local/share"))
+    });
+
+  let data_dirs = std::env::var("XDG_DATA_DIRS")
+    .unwrap_or_else(|_| "/usr/local/share:/usr/share".into());
+
+  let data_dirs = data_home
+    .into_iter()
+    .chain(data_dirs.split(':').map(PathBuf::from))
+    .collect::<Vec<_>>();
+
+  let mut icon_dirs = data_dirs
+    .iter()
+    .flat_map(|data_dir| {
+      ICON_SIZES.iter().map(move |size| {
+        data_dir.join("icons/hicolor").join(size).join("apps")
+      })
+    })
+    .collect::<Vec<_>>();
+
+  icon_dirs.push(PathBuf::from("/usr/share/pixmaps"));
+  icon_dirs
+}
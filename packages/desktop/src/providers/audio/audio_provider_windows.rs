@@ -0,0 +1,1127 @@
+use std::{
+  collections::{HashMap, HashSet},
+  path::Path,
+  time::{Duration, Instant},
+};
+
+use anyhow::Context;
+use crossbeam::channel::{self, at, never, tick};
+use tracing::{debug, info, warn};
+use windows::Win32::{
+  Devices::FunctionDiscovery::PKEY_Device_FriendlyName,
+  Foundation::{BOOL, S_OK},
+  Media::Audio::{
+    eAll, eCapture, eMultimedia, eRender, AudioSessionDisconnectReason,
+    AudioSessionState, AudioSessionStateActive, AudioSessionStateExpired,
+    EDataFlow, ERole,
+    Endpoints::{
+      IAudioEndpointVolume, IAudioEndpointVolumeCallback,
+      IAudioEndpointVolumeCallback_Impl, IAudioMeterInformation,
+    },
+    IAudioClient, IAudioSessionControl, IAudioSessionControl2,
+    IAudioSessionEvents, IAudioSessionEvents_Impl, IAudioSessionManager2,
+    IAudioSessionNotification, IAudioSessionNotification_Impl, IMMDevice,
+    IMMDeviceEnumerator, IMMEndpoint, IMMNotificationClient,
+    IMMNotificationClient_Impl, ISimpleAudioVolume, MMDeviceEnumerator,
+    AUDCLNT_SHAREMODE_SHARED, AUDIO_VOLUME_NOTIFICATION_DATA,
+    DEVICE_STATE, DEVICE_STATE_ACTIVE,
+  },
+  System::Com::{CoCreateInstance, CoTaskMemFree, CLSCTX_ALL, STGM_READ},
+  UI::Shell::PropertiesSystem::{IPropertyStore, PROPERTYKEY},
+};
+use windows_core::{Interface, GUID, HSTRING, PCWSTR, PWSTR};
+
+use super::{
+  AudioDevice, AudioOutput, AudioProviderConfig, AudioSession, DeviceType,
+};
+use crate::{
+  common::windows::{exe_icon_png, process_exe_path, COM_INIT},
+  providers::{
+    AudioFunction, CommonProviderState, Provider, ProviderFunction,
+    ProviderFunctionResponse, ProviderInputMsg, RuntimeType,
+  },
+};
+
+impl From<EDataFlow> for DeviceType {
+  fn from(flow: EDataFlow) -> Self {
+    match flow {
+      flow if flow == eCapture => Self::Recording,
+      _ => Self::Playback,
+    }
+  }
+}
+
+impl From<DeviceType> for EDataFlow {
+  fn from(device_type: DeviceType) -> Self {
+    match device_type {
+      DeviceType::Playback => eRender,
+      DeviceType::Recording => eCapture,
+    }
+  }
+}
+
+/// Events that can be emitted from audio state changes.
+#[derive(Debug)]
+enum AudioEvent {
+  DeviceAdded(String),
+  DeviceRemoved(String),
+  DefaultDeviceChanged(String, DeviceType),
+  VolumeChanged(String, f32, bool),
+  SessionsChanged,
+  SessionVolumeChanged(String, f32, bool),
+}
+
+/// Holds the state of an audio device.
+#[derive(Clone)]
+struct DeviceState {
+  name: String,
+  device_id: String,
+  device_type: DeviceType,
+  volume: u32,
+  is_muted: bool,
+  com_volume: IAudioEndpointVolume,
+  com_volume_callback: IAudioEndpointVolumeCallback,
+}
+
+/// Holds the state of an audio session.
+struct SessionState {
+  name: String,
+  process_id: Option<u32>,
+  icon: Option<Vec<u8>>,
+  volume: u32,
+  is_muted: bool,
+  is_active: bool,
+  com_control: IAudioSessionControl2,
+  com_volume: ISimpleAudioVolume,
+  com_events: IAudioSessionEvents,
+}
+
+/// Capture stream on a recording device, used for metering its input
+/// level.
+///
+/// Recording devices only report peak values while a stream is open, so
+/// the stream is started but never read from.
+struct InputMeter {
+  device_id: String,
+  com_client: IAudioClient,
+  com_meter: IAudioMeterInformation,
+}
+
+impl Drop for InputMeter {
+  fn drop(&mut self) {
+    let _ = unsafe { self.com_client.Stop() };
+  }
+}
+
+pub struct AudioProvider {
+  config: AudioProviderConfig,
+  common: CommonProviderState,
+  com_enumerator: Option<IMMDeviceEnumerator>,
+  default_playback_id: Option<String>,
+  default_recording_id: Option<String>,
+  device_states: HashMap<String, DeviceState>,
+  input_meter: Option<InputMeter>,
+  input_level: Option<u32>,
+  com_session_manager: Option<IAudioSessionManager2>,
+  com_session_notification: Option<IAudioSessionNotification>,
+  session_manager_device_id: Option<String>,
+  session_states: HashMap<String, SessionState>,
+  icon_cache: HashMap<String, Option<Vec<u8>>>,
+  event_tx: channel::Sender<AudioEvent>,
+  event_rx: channel::Receiver<AudioEvent>,
+}
+
+impl AudioProvider {
+  pub fn new(
+    config: AudioProviderConfig,
+    common: CommonProviderState,
+  ) -> Self {
+    let (event_tx, event_rx) = channel::unbounded();
+
+    Self {
+      config,
+      common,
+      com_enumerator: None,
+      default_playback_id: None,
+      default_recording_id: None,
+      device_states: HashMap::new(),
+      input_meter: None,
+      input_level: None,
+      com_session_manager: None,
+      com_session_notification: None,
+      session_manager_device_id: None,
+      session_states: HashMap::new(),
+      icon_cache: HashMap::new(),
+      event_tx,
+      event_rx,
+    }
+  }
+
+  /// Main entry point.
+  fn start(&mut self) -> anyhow::Result<()> {
+    COM_INIT.with(|_| {
+      let com_enumerator: IMMDeviceEnumerator = unsafe {
+        CoCreateInstance(&MMDeviceEnumerator, None, CLSCTX_ALL)
+      }?;
+
+      // Note that this would sporadically segfault if we didn't keep a
+      // separate variable for `IMMNotificationClient` when registering the
+      // callback. Something funky with lifetimes and the COM API's.
+      let com_device_callback: IMMNotificationClient = DeviceCallback {
+        event_tx: self.event_tx.clone(),
+      }
+      .into();
+
+      // Register device add/remove callback.
+      unsafe {
+        com_enumerator
+          .RegisterEndpointNotificationCallback(&com_device_callback)
+      }?;
+
+      self.com_enumerator = Some(com_enumerator);
+
+      // Update device list and default device IDs.
+      for com_device in self.active_devices()? {
+        self.add_device(com_device)?;
+      }
+
+      self.default_playback_id =
+        self.default_device_id(&DeviceType::Playback)?;
+      self.default_recording_id =
+        self.default_device_id(&DeviceType::Recording)?;
+
+      self.update_input_meter();
+      self.update_session_manager();
+
+      // Emit initial output.
+      self.emit_output();
+
+      // Audio events (especially volume changes) can be frequent, so we
+      // batch the emissions together.
+      let mut last_emit = Instant::now();
+      let mut pending_emission = false;
+      const BATCH_DELAY: Duration = Duration::from_millis(25);
+
+      let input_level_ticker = match self.config.input_level_interval {
+        Some(interval) => tick(Duration::from_millis(interval)),
+        None => never(),
+      };
+
+      // Listen to audio-related events.
+      loop {
+        let batch_timer = match pending_emission {
+          true => at(last_emit + BATCH_DELAY),
+          false => never(),
+        };
+
+        crossbeam::select! {
+          recv(self.event_rx) -> event => {
+            if let Ok(event) = event {
+              debug!("Got audio event: {:?}", event);
+
+              if let Err(err) = self.handle_event(event) {
+                tracing::warn!("Error handling audio event: {}", err);
+              }
+
+              // Check whether we should emit immediately or mark as pending.
+              if last_emit.elapsed() >= BATCH_DELAY {
+                self.emit_output();
+                last_emit = Instant::now();
+              } else {
+                pending_emission = true;
+              }
+            }
+          }
+          recv(self.common.input.sync_rx) -> input => {
+            match input {
+              Ok(ProviderInputMsg::Stop) => {
+                break;
+              }
+              Ok(ProviderInputMsg::Function(
+                ProviderFunction::Audio(audio_function),
+                sender,
+              )) => {
+                let res = self.handle_function(audio_function).map_err(|err| err.to_string());
+                sender.send(res).unwrap();
+              }
+              _ => {}
+            }
+          }
+          recv(input_level_ticker) -> _ => {
+            if self.update_input_level() {
+              self.emit_output();
+              last_emit = Instant::now();
+              pending_emission = false;
+            }
+          }
+          recv(batch_timer) -> _ => {
+            if pending_emission {
+              self.emit_output();
+              last_emit = Instant::now();
+              pending_emission = false;
+            }
+          }
+        }
+      }
+
+      Ok(())
+    })
+  }
+
+  /// Enumerates active devices of all device types.
+  fn active_devices(&self) -> anyhow::Result<Vec<IMMDevice>> {
+    let collection = unsafe {
+      self
+        .com_enumerator
+        .as_ref()
+        .context("Device enumerator not initialized.")?
+        .EnumAudioEndpoints(eAll, DEVICE_STATE_ACTIVE)
+    }?;
+
+    let count = unsafe { collection.GetCount() }?;
+    let devices = (0..count)
+      .filter_map(|i| unsafe { collection.Item(i).ok() })
+      .collect::<Vec<_>>();
+
+    Ok(devices)
+  }
+
+  /// Gets the friendly name of a device.
+  ///
+  /// Returns a string (e.g. `Headphones (WH-1000XM3 Stereo)`).
+  fn device_name(&self, com_device: &IMMDevice) -> anyhow::Result<String> {
+    let store: IPropertyStore =
+      unsafe { com_device.OpenPropertyStore(STGM_READ) }?;
+
+    let friendly_name =
+      unsafe { store.GetValue(&PKEY_Device_FriendlyName)?.to_string() };
+
+    Ok(friendly_name)
+  }
+
+  /// Registers volume callbacks for a device.
+  fn register_volume_callback(
+    &self,
+    com_device: &IMMDevice,
+    device_id: String,
+  ) -> anyhow::Result<(IAudioEndpointVolume, IAudioEndpointVolumeCallback)>
+  {
+    let com_volume = unsafe {
+      com_device.Activate::<IAudioEndpointVolume>(CLSCTX_ALL, None)
+    }?;
+
+    let com_volume_callback: IAudioEndpointVolumeCallback =
+      VolumeCallback {
+        device_id,
+        event_tx: self.event_tx.clone(),
+      }
+      .into();
+
+    unsafe {
+      com_volume.RegisterControlChangeNotify(&com_volume_callback)
+    }?;
+
+    Ok((com_volume, com_volume_callback))
+  }
+
+  /// Emits an `AudioOutput` update through the provider's emitter.
+  fn emit_output(&mut self) {
+    let mut output = AudioOutput {
+      playback_devices: Vec::new(),
+      recording_devices: Vec::new(),
+      all_devices: Vec::new(),
+      default_playback_device: None,
+      default_recording_device: None,
+      input_level: self.input_level,
+      sessions: Vec::new(),
+    };
+
+    for (id, state) in &self.device_states {
+      let device = AudioDevice {
+        name: state.name.clone(),
+        device_id: state.device_id.clone(),
+        device_type: state.device_type.clone(),
+        volume: state.volume,
+        is_muted: state.is_muted,
+        is_default_playback: self.default_playback_id.as_ref() == Some(id),
+        is_default_recording: self.default_recording_id.as_ref()
+          == Some(id),
+      };
+
+      output.all_devices.push(device.clone());
+
+      match device.device_type {
+        DeviceType::Playback => {
+          output.playback_devices.push(device.clone());
+
+          if self.default_playback_id.as_ref() == Some(id) {
+            output.default_playback_device = Some(device.clone());
+          }
+        }
+        DeviceType::Recording => {
+          output.recording_devices.push(device.clone());
+
+          if self.default_recording_id.as_ref() == Some(id) {
+            output.default_recording_device = Some(device.clone());
+          }
+        }
+      }
+    }
+
+    for (session_id, state) in &self.session_states {
+      output.sessions.push(AudioSession {
+        session_id: session_id.clone(),
+        name: state.name.clone(),
+        process_id: state.process_id,
+        icon: state
+          .icon
+          .clone()
+          .map(|icon| self.common.emitter.attach(icon, "image/png")),
+        volume: state.volume,
+        is_muted: state.is_muted,
+        is_active: state.is_active,
+      });
+    }
+
+    output.sessions.sort_by(|a, b| a.name.cmp(&b.name));
+
+    self.common.emitter.emit_output(Ok(output));
+  }
+
+  /// Gets the default device ID for the given device type.
+  ///
+  /// Note that a device can have multiple roles (i.e. multimedia,
+  /// communications, and console). The device with the multimedia role is
+  /// typically seen as the default.
+  fn default_device_id(
+    &self,
+    device_type: &DeviceType,
+  ) -> anyhow::Result<Option<String>> {
+    let default_device = unsafe {
+      self
+        .com_enumerator
+        .as_ref()
+        .context("Device enumerator not initialized.")?
+        .GetDefaultAudioEndpoint(
+          EDataFlow::from(device_type.clone()),
+          eMultimedia,
+        )
+    }
+    .ok();
+
+    let device_id = default_device
+      .and_then(|device| unsafe { device.GetId().ok() })
+      .and_then(|id| unsafe { id.to_string().ok() });
+
+    Ok(device_id)
+  }
+
+  /// Adds a device by its ID.
+  fn add_device_by_id(&mut self, device_id: &str) -> anyhow::Result<()> {
+    let com_device = unsafe {
+      self
+        .com_enumerator
+        .as_ref()
+        .context("Device enumerator not initialized.")?
+        .GetDevice(&HSTRING::from(device_id))
+    }?;
+
+    self.add_device(com_device)
+  }
+
+  /// Adds a device by its COM object.
+  fn add_device(&mut self, com_device: IMMDevice) -> anyhow::Result<()> {
+    let device_id = unsafe { com_device.GetId()?.to_string() }?;
+    info!("Adding new audio device: {}", device_id);
+
+    let device_type = DeviceType::from(unsafe {
+      com_device.cast::<IMMEndpoint>()?.GetDataFlow()
+    }?);
+
+    let (com_volume, com_volume_callback) =
+      self.register_volume_callback(&com_device, device_id.clone())?;
+
+    let volume = unsafe { com_volume.GetMasterVolumeLevelScalar() }?;
+    let is_muted = unsafe { com_volume.GetMute() }?.as_bool();
+
+    let device_state = DeviceState {
+      name: self.device_name(&com_device)?,
+      device_id: device_id.clone(),
+      device_type: device_type.clone(),
+      volume: (volume * 100.0).round() as u32,
+      is_muted,
+      com_volume,
+      com_volume_callback,
+    };
+
+    self.device_states.insert(device_id, device_state);
+
+    Ok(())
+  }
+
+  /// Removes a device that is no longer active.
+  ///
+  /// Deregisters volume callback and removes device from state.
+  fn remove_device(&mut self, device_id: &str) -> anyhow::Result<()> {
+    if let Some(state) = self.device_states.remove(device_id) {
+      info!("Audio device removed: {}", device_id);
+
+      unsafe {
+        state.com_volume.UnregisterControlChangeNotify(
+          &IAudioEndpointVolumeCallback::from(state.com_volume_callback),
+        )
+      }?;
+    }
+
+    Ok(())
+  }
+
+  /// Opens an input meter on the default recording device if metering
+  /// is enabled and the default device has changed.
+  fn update_input_meter(&mut self) {
+    if self.config.input_level_interval.is_none()
+      || self.input_meter.as_ref().map(|meter| &meter.device_id)
+        == self.default_recording_id.as_ref()
+    {
+      return;
+    }
+
+    self.input_meter = None;
+    self.input_level = None;
+
+    if let Some(device_id) = self.default_recording_id.clone() {
+      match self.create_input_meter(&device_id) {
+        Ok(input_meter) => self.input_meter = Some(input_meter),
+        Err(err) => warn!("Failed to create input meter: {}", err),
+      }
+    }
+  }
+
+  fn create_input_meter(
+    &self,
+    device_id: &str,
+  ) -> anyhow::Result<InputMeter> {
+    let com_device = unsafe {
+      self
+        .com_enumerator
+        .as_ref()
+        .context("Device enumerator not initialized.")?
+        .GetDevice(&HSTRING::from(device_id))
+    }?;
+
+    let com_client =
+      unsafe { com_device.Activate::<IAudioClient>(CLSCTX_ALL, None) }?;
+
+    let mix_format = unsafe { com_client.GetMixFormat() }?;
+
+    // Use a 1s buffer. Overflowing it is harmless, since the captured
+    // audio is never read.
+    let res = unsafe {
+      com_client.Initialize(
+        AUDCLNT_SHAREMODE_SHARED,
+        0,
+        10_000_000,
+        0,
+        mix_format,
+        None,
+      )
+    };
+
+    unsafe { CoTaskMemFree(Some(mix_format as _)) };
+    res?;
+
+    unsafe { com_client.Start() }?;
+
+    let com_meter = unsafe {
+      com_device.Activate::<IAudioMeterInformation>(CLSCTX_ALL, None)
+    }?;
+
+    Ok(InputMeter {
+      device_id: device_id.to_string(),
+      com_client,
+      com_meter,
+    })
+  }
+
+  /// Samples the input meter. Returns whether the input level changed.
+  fn update_input_level(&mut self) -> bool {
+    let input_level = self.input_meter.as_ref().and_then(|meter| {
+      unsafe { meter.com_meter.GetPeakValue() }
+        .ok()
+        .map(|peak| (peak * 100.0).round() as u32)
+    });
+
+    let is_changed = input_level != self.input_level;
+    self.input_level = input_level;
+    is_changed
+  }
+
+  /// Switches the session manager to the default playback device if it
+  /// has changed.
+  fn update_session_manager(&mut self) {
+    if self.session_manager_device_id == self.default_playback_id {
+      return;
+    }
+
+    self.clear_sessions();
+    self.session_manager_device_id = self.default_playback_id.clone();
+
+    if let Some(device_id) = self.default_playback_id.clone() {
+      if let Err(err) = self.create_session_manager(&device_id) {
+        warn!("Failed to create audio session manager: {}", err);
+      }
+    }
+  }
+
+  fn create_session_manager(
+    &mut self,
+    device_id: &str,
+  ) -> anyhow::Result<()> {
+    let com_device = unsafe {
+      self
+        .com_enumerator
+        .as_ref()
+        .context("Device enumerator not initialized.")?
+        .GetDevice(&HSTRING::from(device_id))
+    }?;
+
+    let com_session_manager = unsafe {
+      com_device.Activate::<IAudioSessionManager2>(CLSCTX_ALL, None)
+    }?;
+
+    // Sessions created after this are only notified once the session
+    // enumerator has been retrieved.
+    unsafe { com_session_manager.GetSessionEnumerator() }?;
+
+    let com_session_notification: IAudioSessionNotification =
+      SessionNotificationCallback {
+        event_tx: self.event_tx.clone(),
+      }
+      .into();
+
+    unsafe {
+      com_session_manager
+        .RegisterSessionNotification(&com_session_notification)
+    }?;
+
+    self.com_session_manager = Some(com_session_manager);
+    self.com_session_notification = Some(com_session_notification);
+
+    self.refresh_sessions()
+  }
+
+  /// Unregisters the session manager and all session callbacks.
+  fn clear_sessions(&mut self) {
+    for (_, state) in self.session_states.drain() {
+      let _ = unsafe {
+        state
+          .com_control
+          .UnregisterAudioSessionNotification(&state.com_events)
+      };
+    }
+
+    if let (Some(manager), Some(notification)) = (
+      self.com_session_manager.take(),
+      self.com_session_notification.take(),
+    ) {
+      let _ =
+        unsafe { manager.UnregisterSessionNotification(&notification) };
+    }
+  }
+
+  /// Syncs session states with the sessions of the session manager.
+  fn refresh_sessions(&mut self) -> anyhow::Result<()> {
+    let Some(com_session_manager) = self.com_session_manager.clone()
+    else {
+      return Ok(());
+    };
+
+    let com_sessions =
+      unsafe { com_session_manager.GetSessionEnumerator() }?;
+    let count = unsafe { com_sessions.GetCount() }?;
+    let mut session_ids = HashSet::new();
+
+    for index in 0..count {
+      let com_control = unsafe { com_sessions.GetSession(index) }?;
+      let state = unsafe { com_control.GetState() }?;
+
+      if state == AudioSessionStateExpired {
+        continue;
+      }
+
+      let com_control = com_control.cast::<IAudioSessionControl2>()?;
+      let session_id = take_pwstr(unsafe {
+        com_control.GetSessionInstanceIdentifier()
+      }?)?;
+
+      session_ids.insert(session_id.clone());
+
+      match self.session_states.get_mut(&session_id) {
+        Some(session_state) => {
+          session_state.is_active = state == AudioSessionStateActive;
+        }
+        None => {
+          let session_state =
+            self.create_session(&session_id, com_control, state)?;
+
+          self.session_states.insert(session_id, session_state);
+        }
+      }
+    }
+
+    // Remove sessions that have expired.
+    let expired_ids = self
+      .session_states
+      .keys()
+      .filter(|id| !session_ids.contains(*id))
+      .cloned()
+      .collect::<Vec<_>>();
+
+    for session_id in expired_ids {
+      if let Some(state) = self.session_states.remove(&session_id) {
+        let _ = unsafe {
+          state
+            .com_control
+            .UnregisterAudioSessionNotification(&state.com_events)
+        };
+      }
+    }
+
+    Ok(())
+  }
+
+  fn create_session(
+    &mut self,
+    session_id: &str,
+    com_control: IAudioSessionControl2,
+    state: AudioSessionState,
+  ) -> anyhow::Result<SessionState> {
+    let com_volume = com_control.cast::<ISimpleAudioVolume>()?;
+    let volume = unsafe { com_volume.GetMasterVolume() }?;
+    let is_muted = unsafe { com_volume.GetMute() }?.as_bool();
+
+    let is_system_sounds =
+      unsafe { com_control.IsSystemSoundsSession() } == S_OK;
+
+    let process_id = match is_system_sounds {
+      true => None,
+      false => unsafe { com_control.GetProcessId() }.ok(),
+    };
+
+    let exe_path = process_id.and_then(|pid| process_exe_path(pid).ok());
+
+    // Display names are often unset, or are resource references (e.g.
+    // `@%SystemRoot%\...`), in which case the executable name is used.
+    let display_name =
+      take_pwstr(unsafe { com_control.GetDisplayName() }?)
+        .ok()
+        .filter(|name| !name.is_empty() && !name.starts_with('@'));
+
+    let name = match (is_system_sounds, display_name, &exe_path) {
+      (true, _, _) => "System sounds".to_string(),
+      (false, Some(display_name), _) => display_name,
+      (false, None, Some(exe_path)) => Path::new(exe_path)
+        .file_stem()
+        .map(|stem| stem.to_string_lossy().to_string())
+        .unwrap_or_default(),
+      (false, None, None) => "Unknown".to_string(),
+    };
+
+    let icon = exe_path.and_then(|exe_path| {
+      self
+        .icon_cache
+        .entry(exe_path.clone())
+        .or_insert_with(|| exe_icon_png(&exe_path).ok())
+        .clone()
+    });
+
+    let com_events: IAudioSessionEvents = SessionEventsCallback {
+      session_id: session_id.to_string(),
+      event_tx: self.event_tx.clone(),
+    }
+    .into();
+
+    unsafe { com_control.RegisterAudioSessionNotification(&com_events) }?;
+
+    Ok(SessionState {
+      name,
+      process_id,
+      icon,
+      volume: (volume * 100.0).round() as u32,
+      is_muted,
+      is_active: state == AudioSessionStateActive,
+      com_control,
+      com_volume,
+      com_events,
+    })
+  }
+
+  /// Handles an audio event.
+  fn handle_event(&mut self, event: AudioEvent) -> anyhow::Result<()> {
+    match event {
+      AudioEvent::DeviceAdded(device_id) => {
+        self.add_device_by_id(&device_id)?;
+      }
+      AudioEvent::DeviceRemoved(device_id) => {
+        if self.input_meter.as_ref().map(|meter| &meter.device_id)
+          == Some(&device_id)
+        {
+          self.input_meter = None;
+          self.input_level = None;
+        }
+
+        self.remove_device(&device_id)?;
+      }
+      AudioEvent::DefaultDeviceChanged(device_id, device_type) => {
+        match device_type {
+          DeviceType::Playback => {
+            self.default_playback_id = Some(device_id);
+            self.update_session_manager();
+          }
+          DeviceType::Recording => {
+            self.default_recording_id = Some(device_id);
+            self.update_input_meter();
+          }
+        }
+      }
+      AudioEvent::VolumeChanged(device_id, new_volume, is_muted) => {
+        if let Some(state) = self.device_states.get_mut(&device_id) {
+          state.volume = (new_volume * 100.0).round() as u32;
+          state.is_muted = is_muted;
+        }
+      }
+      AudioEvent::SessionsChanged => {
+        self.refresh_sessions()?;
+      }
+      AudioEvent::SessionVolumeChanged(
+        session_id,
+        new_volume,
+        is_muted,
+      ) => {
+        if let Some(state) = self.session_states.get_mut(&session_id) {
+          state.volume = (new_volume * 100.0).round() as u32;
+          state.is_muted = is_muted;
+        }
+      }
+    }
+
+    Ok(())
+  }
+
+  /// Handles an incoming audio provider function call.
+  fn handle_function(
+    &mut self,
+    function: AudioFunction,
+  ) -> anyhow::Result<ProviderFunctionResponse> {
+    match function {
+      AudioFunction::SetVolume(args) => {
+        // Get target device - use specified ID or default playback
+        // device.
+        let device_state = if let Some(id) = &args.device_id {
+          self
+            .device_states
+            .get(id)
+            .context("Specified device not found.")?
+        } else {
+          self
+            .default_playback_id
+            .as_ref()
+            .and_then(|id| self.device_states.get(id))
+            .context("No active playback device.")?
+        };
+
+        unsafe {
+          device_state.com_volume.SetMasterVolumeLevelScalar(
+            args.volume / 100.,
+            &GUID::zeroed(),
+          )
+        }?;
+
+        Ok(ProviderFunctionResponse::Null)
+      }
+      AudioFunction::ToggleMicMute(args) => {
+        // Get target device - use specified ID or default recording
+        // device.
+        let device_state = if let Some(id) = &args.device_id {
+          self
+            .device_states
+            .get(id)
+            .context("Specified device not found.")?
+        } else {
+          self
+            .default_recording_id
+            .as_ref()
+            .and_then(|id| self.device_states.get(id))
+            .context("No active recording device.")?
+        };
+
+        unsafe {
+          device_state
+            .com_volume
+            .SetMute(!device_state.is_muted, &GUID::zeroed())
+        }?;
+
+        Ok(ProviderFunctionResponse::Null)
+      }
+      AudioFunction::SetAppVolume(args) => {
+        let session_state = self
+          .session_states
+          .get(&args.session_id)
+          .context("Specified session not found.")?;
+
+        unsafe {
+          session_state
+            .com_volume
+            .SetMasterVolume(args.volume / 100., &GUID::zeroed())
+        }?;
+
+        Ok(ProviderFunctionResponse::Null)
+      }
+      AudioFunction::SetAppMute(args) => {
+        let session_state = self
+          .session_states
+          .get(&args.session_id)
+          .context("Specified session not found.")?;
+
+        unsafe {
+          session_state
+            .com_volume
+            .SetMute(args.is_muted, &GUID::zeroed())
+        }?;
+
+        Ok(ProviderFunctionResponse::Null)
+      }
+    }
+  }
+}
+
+impl Drop for AudioProvider {
+  fn drop(&mut self) {
+    let device_ids =
+      self.device_states.keys().cloned().collect::<Vec<_>>();
+
+    // Ensure volume callbacks are deregistered.
+    for device_id in device_ids {
+      let _ = self.remove_device(&device_id);
+    }
+
+    self.clear_sessions();
+  }
+}
+
+impl Provider for AudioProvider {
+  fn runtime_type(&self) -> RuntimeType {
+    RuntimeType::Sync
+  }
+
+  fn start_sync(&mut self) {
+    if let Err(err) = self.start() {
+      tracing::error!("Error starting audio provider: {}", err);
+      self.common.emitter.emit_output::<AudioOutput>(Err(err));
+    }
+  }
+}
+
+/// Callback handler for volume notifications.
+///
+/// Each device has a volume callback that is used to notify when the
+/// volume changes.
+#[derive(Clone)]
+#[windows::core::implement(IAudioEndpointVolumeCallback)]
+struct VolumeCallback {
+  device_id: String,
+  event_tx: channel::Sender<AudioEvent>,
+}
+
+impl IAudioEndpointVolumeCallback_Impl for VolumeCallback_Impl {
+  fn OnNotify(
+    &self,
+    data: *mut AUDIO_VOLUME_NOTIFICATION_DATA,
+  ) -> windows::core::Result<()> {
+    if let Some(data) = unsafe { data.as_ref() } {
+      let _ = self.event_tx.send(AudioEvent::VolumeChanged(
+        self.device_id.clone(),
+        data.fMasterVolume,
+        data.bMuted.as_bool(),
+      ));
+    }
+
+    Ok(())
+  }
+}
+
+/// Reads and frees a COM-allocated string.
+fn take_pwstr(pwstr: PWSTR) -> anyhow::Result<String> {
+  let string = unsafe { pwstr.to_string() };
+  unsafe { CoTaskMemFree(Some(pwstr.0 as _)) };
+  Ok(string?)
+}
+
+/// Callback handler for new audio sessions.
+#[windows::core::implement(IAudioSessionNotification)]
+struct SessionNotificationCallback {
+  event_tx: channel::Sender<AudioEvent>,
+}
+
+impl IAudioSessionNotification_Impl for SessionNotificationCallback_Impl {
+  fn OnSessionCreated(
+    &self,
+    _new_session: Option<&IAudioSessionControl>,
+  ) -> windows::core::Result<()> {
+    let _ = self.event_tx.send(AudioEvent::SessionsChanged);
+    Ok(())
+  }
+}
+
+/// Callback handler for changes to an audio session.
+///
+/// Each session has an events callback that is used to notify when its
+/// volume or state changes.
+#[windows::core::implement(IAudioSessionEvents)]
+struct SessionEventsCallback {
+  session_id: String,
+  event_tx: channel::Sender<AudioEvent>,
+}
+
+impl IAudioSessionEvents_Impl for SessionEventsCallback_Impl {
+  fn OnDisplayNameChanged(
+    &self,
+    _new_display_name: &PCWSTR,
+    _event_context: *const GUID,
+  ) -> windows::core::Result<()> {
+    Ok(())
+  }
+
+  fn OnIconPathChanged(
+    &self,
+    _new_icon_path: &PCWSTR,
+    _event_context: *const GUID,
+  ) -> windows::core::Result<()> {
+    Ok(())
+  }
+
+  fn OnSimpleVolumeChanged(
+    &self,
+    new_volume: f32,
+    new_mute: BOOL,
+    _event_context: *const GUID,
+  ) -> windows::core::Result<()> {
+    let _ = self.event_tx.send(AudioEvent::SessionVolumeChanged(
+      self.session_id.clone(),
+      new_volume,
+      new_mute.as_bool(),
+    ));
+
+    Ok(())
+  }
+
+  fn OnChannelVolumeChanged(
+    &self,
+    _channel_count: u32,
+    _new_channel_volumes: *const f32,
+    _changed_channel: u32,
+    _event_context: *const GUID,
+  ) -> windows::core::Result<()> {
+    Ok(())
+  }
+
+  fn OnGroupingParamChanged(
+    &self,
+    _new_grouping_param: *const GUID,
+    _event_context: *const GUID,
+  ) -> windows::core::Result<()> {
+    Ok(())
+  }
+
+  fn OnStateChanged(
+    &self,
+    _new_state: AudioSessionState,
+  ) -> windows::core::Result<()> {
+    let _ = self.event_tx.send(AudioEvent::SessionsChanged);
+    Ok(())
+  }
+
+  fn OnSessionDisconnected(
+    &self,
+    _disconnect_reason: AudioSessionDisconnectReason,
+  ) -> windows::core::Result<()> {
+    let _ = self.event_tx.send(AudioEvent::SessionsChanged);
+    Ok(())
+  }
+}
+
+/// Callback handler for device change notifications.
+///
+/// This is used to detect when new devices are added or removed, and when
+/// the default device changes.
+#[windows::core::implement(IMMNotificationClient)]
+struct DeviceCallback {
+  event_tx: channel::Sender<AudioEvent>,
+}
+
+impl IMMNotificationClient_Impl for DeviceCallback_Impl {
+  fn OnDeviceAdded(
+    &self,
+    device_id: &PCWSTR,
+  ) -> windows::core::Result<()> {
+    if let Ok(id) = unsafe { device_id.to_string() } {
+      let _ = self.event_tx.send(AudioEvent::DeviceAdded(id.clone()));
+    }
+
+    Ok(())
+  }
+
+  fn OnDeviceRemoved(
+    &self,
+    device_id: &PCWSTR,
+  ) -> windows::core::Result<()> {
+    if let Ok(id) = unsafe { device_id.to_string() } {
+      let _ = self.event_tx.send(AudioEvent::DeviceRemoved(id));
+    }
+
+    Ok(())
+  }
+
+  fn OnDeviceStateChanged(
+    &self,
+    device_id: &PCWSTR,
+    new_state: DEVICE_STATE,
+  ) -> windows::core::Result<()> {
+    if let Ok(id) = unsafe { device_id.to_string() } {
+      let event = match new_state {
+        DEVICE_STATE_ACTIVE => AudioEvent::DeviceAdded(id),
+        _ => AudioEvent::DeviceRemoved(id),
+      };
+
+      let _ = self.event_tx.send(event);
+    }
+
+    Ok(())
+  }
+
+  fn OnDefaultDeviceChanged(
+    &self,
+    flow: EDataFlow,
+    role: ERole,
+    default_device_id: &PCWSTR,
+  ) -> windows::core::Result<()> {
+    if role == eMultimedia {
+      if let Ok(id) = unsafe { default_device_id.to_string() } {
+        let _ = self.event_tx.send(AudioEvent::DefaultDeviceChanged(
+          id,
+          DeviceType::from(flow),
+        ));
+      }
+    }
+
+    Ok(())
+  }
+
+  fn OnPropertyValueChanged(
+    &self,
+    _device_id: &PCWSTR,
+    _key: &PROPERTYKEY,
+  ) -> windows::core::Result<()> {
+    Ok(())
+  }
+}
@@ -1,3 +1,11 @@
-mod audio_provider;
+mod audio_output;
+#[cfg(target_os = "linux")]
+mod audio_provider_linux;
+#[cfg(windows)]
+mod audio_provider_windows;
 
-pub use audio_provider::*;
+pub use audio_output::*;
+#[cfg(target_os = "linux")]
+pub use audio_provider_linux::*;
+#[cfg(windows)]
+pub use audio_provider_windows::*;
@@ -1,4 +1,5 @@
 use anyhow::Context;
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use starship_battery::{
   units::{
@@ -9,19 +10,25 @@ use starship_battery::{
 };
 
 use crate::{
-  common::SyncInterval,
+  common::{IntervalOptions, SyncInterval},
   providers::{
     CommonProviderState, Provider, ProviderInputMsg, RuntimeType,
   },
 };
 
-#[derive(Deserialize, Debug)]
+#[derive(Deserialize, Debug, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct BatteryProviderConfig {
+  #[serde(deserialize_with = "crate::common::deserialize_duration_ms")]
+  #[schemars(schema_with = "crate::common::duration_schema")]
   pub refresh_interval: u64,
+
+  /// Alignment and jitter of refreshes.
+  #[serde(default)]
+  pub refresh_options: IntervalOptions,
 }
 
-#[derive(Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct BatteryOutput {
   pub charge_percent: f32,
@@ -79,7 +86,10 @@ impl Provider for BatteryProvider {
   }
 
   fn start_sync(&mut self) {
-    let mut interval = SyncInterval::new(self.config.refresh_interval);
+    let mut interval = SyncInterval::with_options(
+      self.config.refresh_interval,
+      &self.config.refresh_options,
+    );
 
     loop {
       crossbeam::select! {
@@ -0,0 +1,74 @@
+use std::{collections::HashSet, fs, path::Path};
+
+use super::CameraDevice;
+
+/// Directory of Video4Linux devices in sysfs.
+const VIDEO4LINUX_DIR: &str = "/sys/class/video4linux";
+
+/// Gets connected cameras via Video4Linux.
+pub fn cameras() -> anyhow::Result<Vec<CameraDevice>> {
+  let Ok(entries) = fs::read_dir(VIDEO4LINUX_DIR) else {
+    return Ok(Vec::new());
+  };
+
+  let open_nodes = open_video_nodes();
+
+  let cameras = entries
+    .flatten()
+    .filter_map(|entry| {
+      let path = entry.path();
+
+      // Cameras can expose additional nodes (e.g. for metadata), which
+      // have a non-zero index.
+      if read_trimmed(&path.join("index"))
+        .is_some_and(|index| index != "0")
+      {
+        return None;
+      }
+
+      let node = format!("/dev/{}", entry.file_name().to_string_lossy());
+
+      let name =
+        read_trimmed(&path.join("name")).unwrap_or_else(|| node.clone());
+
+      // The node number can change between reconnects, whereas the
+      // device path only depends on the port.
+      let id = fs::canonicalize(path.join("device"))
+        .map(|device_path| device_path.display().to_string())
+        .unwrap_or_else(|_| node.clone());
+
+      Some(CameraDevice {
+        id,
+        name,
+        is_active: Some(open_nodes.contains(&node)),
+      })
+    })
+    .collect();
+
+  Ok(cameras)
+}
+
+/// Gets the `/dev/video*` nodes that are open by any process.
+///
+/// Only processes of the current user can be inspected, which includes
+/// apps in the user's session.
+fn open_video_nodes() -> HashSet<String> {
+  let Ok(processes) = fs::read_dir("/proc") else {
+    return HashSet::new();
+  };
+
+  processes
+    .flatten()
+    .filter_map(|process| fs::read_dir(process.path().join("fd")).ok())
+    .flat_map(|fds| fds.flatten())
+    .filter_map(|fd| fs::read_link(fd.path()).ok())
+    .map(|target| target.display().to_string())
+    .filter(|target| target.starts_with("/dev/video"))
+    .collect()
+}
+
+fn read_trimmed(path: &Path) -> Option<String> {
+  fs::read_to_string(path)
+    .ok()
+    .map(|value| value.trim().to_string())
+}
@@ -0,0 +1,39 @@
+use std::process::Command;
+
+use anyhow::Context;
+use serde_json::Value;
+
+use super::CameraDevice;
+
+/// Gets connected cameras via `system_profiler`.
+pub fn cameras() -> anyhow::Result<Vec<CameraDevice>> {
+  let output = Command::new("system_profiler")
+    .args(["SPCameraDataType", "-json"])
+    .output()
+    .context("Failed to run `system_profiler`.")?;
+
+  let res = serde_json::from_slice::<Value>(&output.stdout)
+    .context("Failed to parse `system_profiler` output.")?;
+
+  let cameras = res["SPCameraDataType"]
+    .as_array()
+    .into_iter()
+    .flatten()
+    .filter_map(|camera| {
+      let name = camera["_name"].as_str()?.to_string();
+
+      let id = camera["spcamera_unique-id"]
+        .as_str()
+        .map(String::from)
+        .unwrap_or_else(|| name.clone());
+
+      Some(CameraDevice {
+        id,
+        name,
+        is_active: None,
+      })
+    })
+    .collect();
+
+  Ok(cameras)
+}
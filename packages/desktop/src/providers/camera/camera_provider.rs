@@ -0,0 +1,103 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[cfg(target_os = "linux")]
+use super::camera_linux as backend;
+#[cfg(target_os = "macos")]
+use super::camera_macos as backend;
+#[cfg(windows)]
+use super::camera_windows as backend;
+use crate::{
+  common::{IntervalOptions, SyncInterval},
+  providers::{
+    CommonProviderState, Provider, ProviderInputMsg, RuntimeType,
+  },
+};
+
+#[derive(Deserialize, Debug, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct CameraProviderConfig {
+  #[serde(deserialize_with = "crate::common::deserialize_duration_ms")]
+  #[schemars(schema_with = "crate::common::duration_schema")]
+  pub refresh_interval: u64,
+
+  /// Alignment and jitter of refreshes.
+  #[serde(default)]
+  pub refresh_options: IntervalOptions,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct CameraOutput {
+  /// Connected video capture devices. Changes as cameras are plugged in
+  /// or removed.
+  pub devices: Vec<CameraDevice>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct CameraDevice {
+  /// Identifier of the device, which is stable across reconnects to the
+  /// same port.
+  pub id: String,
+  pub name: String,
+
+  /// Whether the camera is currently capturing. `None` if unknown.
+  ///
+  /// Windows only tracks camera usage per app rather than per device,
+  /// so this is only known when a single camera is connected. The
+  /// `meeting` provider reports usage across all cameras instead. Not
+  /// supported on MacOS.
+  pub is_active: Option<bool>,
+}
+
+pub struct CameraProvider {
+  config: CameraProviderConfig,
+  common: CommonProviderState,
+}
+
+impl CameraProvider {
+  pub fn new(
+    config: CameraProviderConfig,
+    common: CommonProviderState,
+  ) -> CameraProvider {
+    CameraProvider { config, common }
+  }
+
+  fn run_interval(&self) -> anyhow::Result<CameraOutput> {
+    let mut devices = backend::cameras()?;
+
+    // Sort for a stable order between refreshes.
+    devices.sort_by(|a, b| a.name.cmp(&b.name).then(a.id.cmp(&b.id)));
+    devices.dedup_by(|a, b| a.id == b.id);
+
+    Ok(CameraOutput { devices })
+  }
+}
+
+impl Provider for CameraProvider {
+  fn runtime_type(&self) -> RuntimeType {
+    RuntimeType::Sync
+  }
+
+  fn start_sync(&mut self) {
+    let mut interval = SyncInterval::with_options(
+      self.config.refresh_interval,
+      &self.config.refresh_options,
+    );
+
+    loop {
+      crossbeam::select! {
+        recv(interval.tick()) -> _ => {
+          let output = self.run_interval();
+          self.common.emitter.emit_output_cached(output);
+        }
+        recv(self.common.input.sync_rx) -> input => {
+          if let Ok(ProviderInputMsg::Stop) = input {
+            break;
+          }
+        }
+      }
+    }
+  }
+}
@@ -0,0 +1,62 @@
+use windows::{
+  core::{GUID, PCWSTR},
+  Win32::Devices::{
+    DeviceAndDriverInstallation::{DIGCF_DEVICEINTERFACE, DIGCF_PRESENT},
+    Properties::{
+      DEVPKEY_Device_DeviceDesc, DEVPKEY_Device_FriendlyName,
+      DEVPKEY_Device_InstanceId,
+    },
+  },
+};
+
+use super::CameraDevice;
+use crate::{
+  common::windows::DeviceInfoSet,
+  providers::meeting::{apps_using_device, CaptureDevice},
+};
+
+/// Device interface class of video capture devices (i.e.
+/// `KSCATEGORY_VIDEO_CAMERA`).
+const VIDEO_CAMERA_INTERFACE: GUID =
+  GUID::from_u128(0xe5323777_f976_4f5b_9b55_b94699c46e44);
+
+/// Gets connected cameras via the SetupAPI.
+pub fn cameras() -> anyhow::Result<Vec<CameraDevice>> {
+  let device_set = DeviceInfoSet::new(
+    Some(&VIDEO_CAMERA_INTERFACE),
+    PCWSTR::null(),
+    DIGCF_PRESENT | DIGCF_DEVICEINTERFACE,
+  )?;
+
+  let mut cameras = device_set
+    .devices()
+    .iter()
+    .map(|device| {
+      let name = device_set
+        .string_property(device, &DEVPKEY_Device_FriendlyName)
+        .or_else(|| {
+          device_set.string_property(device, &DEVPKEY_Device_DeviceDesc)
+        })
+        .unwrap_or_default();
+
+      let id = device_set
+        .string_property(device, &DEVPKEY_Device_InstanceId)
+        .unwrap_or_else(|| name.clone());
+
+      CameraDevice {
+        id,
+        name,
+        is_active: None,
+      }
+    })
+    .collect::<Vec<_>>();
+
+  // Camera usage is tracked per app, so it can only be attributed to a
+  // device when there's no other camera.
+  if let [camera] = cameras.as_mut_slice() {
+    let apps = apps_using_device(CaptureDevice::Camera)?;
+    camera.is_active = Some(!apps.is_empty());
+  }
+
+  Ok(cameras)
+}
@@ -0,0 +1,9 @@
+#[cfg(target_os = "linux")]
+mod camera_linux;
+#[cfg(target_os = "macos")]
+mod camera_macos;
+mod camera_provider;
+#[cfg(windows)]
+mod camera_windows;
+
+pub use camera_provider::*;
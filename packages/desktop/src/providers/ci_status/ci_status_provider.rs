@@ -0,0 +1,293 @@
+use anyhow::Context;
+use async_trait::async_trait;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::{github_actions, gitlab, jenkins};
+use crate::{
+  common::{open_url, AsyncInterval, IntervalOptions},
+  providers::{
+    common::TlsConfig, CiStatusFunction, CommonProviderState, Provider,
+    ProviderFunction, ProviderFunctionResponse, ProviderInputMsg,
+    RuntimeType,
+  },
+};
+
+#[derive(Deserialize, Debug, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct CiStatusProviderConfig {
+  #[serde(deserialize_with = "crate::common::deserialize_duration_ms")]
+  #[schemars(schema_with = "crate::common::duration_schema")]
+  pub refresh_interval: u64,
+
+  /// Alignment and jitter of refreshes.
+  #[serde(default)]
+  pub refresh_options: IntervalOptions,
+
+  /// CI service to poll.
+  pub backend: CiBackend,
+
+  /// Base URL of the service, for self-hosted instances. Required for
+  /// the Jenkins backend.
+  pub base_url: Option<String>,
+
+  /// Username for Jenkins. Used together with `token` as an API token.
+  pub username: Option<String>,
+
+  /// Access token. Should be a secret reference (e.g.
+  /// `secret:github_token`). Widgets can't use a secret reference
+  /// together with `base_url`.
+  pub token: Option<String>,
+
+  /// Repos (or Jenkins jobs) to get the latest pipeline of.
+  pub targets: Vec<CiTarget>,
+
+  /// TLS options for requests to the service.
+  pub tls: Option<TlsConfig>,
+}
+
+#[derive(
+  Clone, Copy, Debug, Deserialize, PartialEq, Serialize, JsonSchema,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum CiBackend {
+  GithubActions,
+  Gitlab,
+  Jenkins,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct CiTarget {
+  /// Repo (e.g. `glzr-io/zebar`), GitLab project path, or Jenkins job
+  /// path (e.g. `folder/job`).
+  pub repo: String,
+
+  /// Branch to limit pipelines to. Defaults to any branch.
+  pub branch: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct CiStatusOutput {
+  /// Worst status across all pipelines, in the order `failure`,
+  /// `running`, `pending`, and `success`. `unknown` if there are no
+  /// pipelines.
+  pub status: CiStatus,
+
+  pub failing_count: usize,
+  pub running_count: usize,
+
+  /// Latest pipeline of each target, in the order of `targets`.
+  pub pipelines: Vec<CiPipeline>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct CiPipeline {
+  pub repo: String,
+  pub branch: Option<String>,
+
+  /// Name of the workflow or build.
+  pub name: Option<String>,
+
+  pub status: CiStatus,
+
+  /// Link to the pipeline in the browser.
+  pub url: Option<String>,
+
+  pub commit_sha: Option<String>,
+
+  /// Unix timestamp in milliseconds of when the pipeline started.
+  pub started_at: Option<u64>,
+
+  /// Error from fetching the pipeline, in which case the status is
+  /// `unknown`.
+  pub error: Option<String>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum CiStatus {
+  Success,
+  Failure,
+  Running,
+  Pending,
+  Cancelled,
+  Skipped,
+  Unknown,
+}
+
+pub struct CiStatusProvider {
+  config: CiStatusProviderConfig,
+  common: CommonProviderState,
+
+  /// Pipelines from the last interval.
+  pipelines: Vec<CiPipeline>,
+}
+
+impl CiStatusProvider {
+  pub fn new(
+    config: CiStatusProviderConfig,
+    common: CommonProviderState,
+  ) -> CiStatusProvider {
+    CiStatusProvider {
+      config,
+      common,
+      pipelines: Vec::new(),
+    }
+  }
+
+  async fn run_interval(&mut self) -> anyhow::Result<CiStatusOutput> {
+    let mut pipelines = Vec::new();
+
+    // A failing target shouldn't hide the status of the others.
+    for target in &self.config.targets {
+      let pipeline =
+        self.latest_pipeline(target).await.unwrap_or_else(|err| {
+          Some(CiPipeline {
+            repo: target.repo.clone(),
+            branch: target.branch.clone(),
+            name: None,
+            status: CiStatus::Unknown,
+            url: None,
+            commit_sha: None,
+            started_at: None,
+            error: Some(err.to_string()),
+          })
+        });
+
+      pipelines.extend(pipeline);
+    }
+
+    let count = |status: CiStatus| {
+      pipelines
+        .iter()
+        .filter(|pipeline| pipeline.status == status)
+        .count()
+    };
+
+    let failing_count = count(CiStatus::Failure);
+    let running_count = count(CiStatus::Running);
+
+    let status = if failing_count > 0 {
+      CiStatus::Failure
+    } else if running_count > 0 {
+      CiStatus::Running
+    } else if count(CiStatus::Pending) > 0 {
+      CiStatus::Pending
+    } else if count(CiStatus::Success) > 0 {
+      CiStatus::Success
+    } else {
+      CiStatus::Unknown
+    };
+
+    self.pipelines = pipelines.clone();
+
+    Ok(CiStatusOutput {
+      status,
+      failing_count,
+      running_count,
+      pipelines,
+    })
+  }
+
+  async fn latest_pipeline(
+    &self,
+    target: &CiTarget,
+  ) -> anyhow::Result<Option<CiPipeline>> {
+    let http_client = &self.common.http_client;
+    let base_url = self.config.base_url.as_deref();
+    let token = self.config.token.as_deref();
+
+    match self.config.backend {
+      CiBackend::GithubActions => {
+        github_actions::latest_pipeline(
+          http_client,
+          base_url,
+          token,
+          target,
+        )
+        .await
+      }
+      CiBackend::Gitlab => {
+        gitlab::latest_pipeline(http_client, base_url, token, target).await
+      }
+      CiBackend::Jenkins => {
+        jenkins::latest_pipeline(
+          http_client,
+          base_url,
+          self.config.username.as_deref(),
+          token,
+          target,
+        )
+        .await
+      }
+    }
+  }
+
+  fn handle_function(
+    &mut self,
+    function: CiStatusFunction,
+  ) -> anyhow::Result<ProviderFunctionResponse> {
+    match function {
+      CiStatusFunction::OpenPipeline(args) => {
+        let url = self
+          .pipelines
+          .iter()
+          .find(|pipeline| {
+            pipeline.repo == args.repo
+              && (args.branch.is_none() || pipeline.branch == args.branch)
+          })
+          .and_then(|pipeline| pipeline.url.as_ref())
+          .with_context(|| {
+            format!("No pipeline found for {}.", args.repo)
+          })?;
+
+        open_url(url)?;
+      }
+    };
+
+    Ok(ProviderFunctionResponse::Null)
+  }
+}
+
+#[async_trait]
+impl Provider for CiStatusProvider {
+  fn runtime_type(&self) -> RuntimeType {
+    RuntimeType::Async
+  }
+
+  async fn start_async(&mut self) {
+    let mut interval = AsyncInterval::with_options(
+      self.config.refresh_interval,
+      &self.config.refresh_options,
+    )
+    .refresh_on_wake();
+
+    loop {
+      tokio::select! {
+        _ = interval.tick() => {
+          let output = self.run_interval().await;
+          self.common.emitter.emit_output_cached(output);
+        }
+        Some(message) = self.common.input.async_rx.recv() => {
+          match message {
+            ProviderInputMsg::Stop => break,
+            ProviderInputMsg::Function(
+              ProviderFunction::CiStatus(ci_status_function),
+              sender,
+            ) => {
+              let res = self
+                .handle_function(ci_status_function)
+                .map_err(|err| err.to_string());
+
+              sender.send(res).unwrap();
+            }
+            _ => {}
+          }
+        }
+      }
+    }
+  }
+}
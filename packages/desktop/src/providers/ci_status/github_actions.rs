@@ -0,0 +1,98 @@
+use chrono::DateTime;
+use serde::Deserialize;
+
+use super::ci_status_provider::{CiPipeline, CiStatus, CiTarget};
+use crate::providers::common::HttpClient;
+
+const DEFAULT_API_URL: &str = "https://api.github.com";
+
+#[derive(Deserialize, Debug)]
+struct RunsRes {
+  workflow_runs: Vec<RunRes>,
+}
+
+#[derive(Deserialize, Debug)]
+struct RunRes {
+  name: Option<String>,
+  head_branch: Option<String>,
+  head_sha: String,
+  status: Option<String>,
+  conclusion: Option<String>,
+  html_url: String,
+  run_started_at: Option<String>,
+}
+
+/// Gets the latest workflow run of a repo (e.g. `glzr-io/zebar`).
+pub async fn latest_pipeline(
+  http_client: &HttpClient,
+  base_url: Option<&str>,
+  token: Option<&str>,
+  target: &CiTarget,
+) -> anyhow::Result<Option<CiPipeline>> {
+  let url = format!(
+    "{}/repos/{}/actions/runs",
+    base_url.unwrap_or(DEFAULT_API_URL).trim_end_matches('/'),
+    target.repo
+  );
+
+  let mut query = vec![("per_page", "1")];
+
+  if let Some(branch) = &target.branch {
+    query.push(("branch", branch.as_str()));
+  }
+
+  // GitHub rejects requests without a user agent.
+  let mut request = http_client
+    .get(url)
+    .query(&query)
+    .header("User-Agent", "zebar")
+    .header("Accept", "application/vnd.github+json");
+
+  if let Some(token) = token {
+    request = request.bearer_auth(token);
+  }
+
+  let res = request
+    .send()
+    .await?
+    .error_for_status()?
+    .json::<RunsRes>()
+    .await?;
+
+  Ok(res.workflow_runs.into_iter().next().map(|run| {
+    CiPipeline {
+      repo: target.repo.clone(),
+      branch: run.head_branch.or(target.branch.clone()),
+      name: run.name,
+      status: to_status(run.status.as_deref(), run.conclusion.as_deref()),
+      url: Some(run.html_url),
+      commit_sha: Some(run.head_sha),
+      started_at: run
+        .run_started_at
+        .and_then(|time| DateTime::parse_from_rfc3339(&time).ok())
+        .map(|time| time.timestamp_millis() as u64),
+      error: None,
+    }
+  }))
+}
+
+fn to_status(status: Option<&str>, conclusion: Option<&str>) -> CiStatus {
+  match (status, conclusion) {
+    (Some("in_progress"), _) => CiStatus::Running,
+    (Some("completed"), Some("success")) => CiStatus::Success,
+    (
+      Some("completed"),
+      Some(
+        "failure" | "timed_out" | "startup_failure" | "action_required",
+      ),
+    ) => CiStatus::Failure,
+    (Some("completed"), Some("cancelled")) => CiStatus::Cancelled,
+    (Some("completed"), Some("skipped" | "neutral" | "stale")) => {
+      CiStatus::Skipped
+    }
+    (Some("queued" | "requested" | "waiting" | "pending"), _) => {
+      CiStatus::Pending
+    }
+    _ => CiStatus::Unknown,
+  }
+}
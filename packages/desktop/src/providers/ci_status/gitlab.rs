@@ -0,0 +1,84 @@
+use chrono::DateTime;
+use serde::Deserialize;
+
+use super::ci_status_provider::{CiPipeline, CiStatus, CiTarget};
+use crate::providers::common::HttpClient;
+
+const DEFAULT_BASE_URL: &str = "https://gitlab.com";
+
+#[derive(Deserialize, Debug)]
+struct PipelineRes {
+  status: String,
+  #[serde(rename = "ref")]
+  git_ref: Option<String>,
+  sha: String,
+  web_url: String,
+  created_at: Option<String>,
+}
+
+/// Gets the latest pipeline of a project (e.g. `gitlab-org/gitlab`).
+pub async fn latest_pipeline(
+  http_client: &HttpClient,
+  base_url: Option<&str>,
+  token: Option<&str>,
+  target: &CiTarget,
+) -> anyhow::Result<Option<CiPipeline>> {
+  // Project paths are passed as a single URL-encoded segment.
+  let url = format!(
+    "{}/api/v4/projects/{}/pipelines",
+    base_url.unwrap_or(DEFAULT_BASE_URL).trim_end_matches('/'),
+    target.repo.replace('/', "%2F")
+  );
+
+  let mut query = vec![("per_page", "1")];
+
+  if let Some(branch) = &target.branch {
+    query.push(("ref", branch.as_str()));
+  }
+
+  let mut request = http_client.get(url).query(&query);
+
+  if let Some(token) = token {
+    request = request.header("PRIVATE-TOKEN", token);
+  }
+
+  let pipelines = request
+    .send()
+    .await?
+    .error_for_status()?
+    .json::<Vec<PipelineRes>>()
+    .await?;
+
+  Ok(pipelines.into_iter().next().map(|pipeline| {
+    CiPipeline {
+      repo: target.repo.clone(),
+      branch: pipeline.git_ref.or(target.branch.clone()),
+      name: None,
+      status: to_status(&pipeline.status),
+      url: Some(pipeline.web_url),
+      commit_sha: Some(pipeline.sha),
+      started_at: pipeline
+        .created_at
+        .and_then(|time| DateTime::parse_from_rfc3339(&time).ok())
+        .map(|time| time.timestamp_millis() as u64),
+      error: None,
+    }
+  }))
+}
+
+fn to_status(status: &str) -> CiStatus {
+  match status {
+    "success" => CiStatus::Success,
+    "failed" => CiStatus::Failure,
+    "running" => CiStatus::Running,
+    "created"
+    | "waiting_for_resource"
+    | "preparing"
+    | "pending"
+    | "scheduled"
+    | "manual" => CiStatus::Pending,
+    "canceled" => CiStatus::Cancelled,
+    "skipped" => CiStatus::Skipped,
+    _ => CiStatus::Unknown,
+  }
+}
@@ -0,0 +1,82 @@
+use anyhow::Context;
+use reqwest::StatusCode;
+use serde::Deserialize;
+
+use super::ci_status_provider::{CiPipeline, CiStatus, CiTarget};
+use crate::providers::common::HttpClient;
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+struct BuildRes {
+  display_name: Option<String>,
+  building: bool,
+
+  /// `None` while the build is running.
+  result: Option<String>,
+
+  url: String,
+
+  /// Unix timestamp in milliseconds.
+  timestamp: Option<u64>,
+}
+
+/// Gets the last build of a job (e.g. `folder/job`). For multibranch
+/// pipelines, the branch is the job of the branch within the pipeline.
+pub async fn latest_pipeline(
+  http_client: &HttpClient,
+  base_url: Option<&str>,
+  username: Option<&str>,
+  token: Option<&str>,
+  target: &CiTarget,
+) -> anyhow::Result<Option<CiPipeline>> {
+  let base_url = base_url
+    .context("A `baseUrl` is required for the Jenkins backend.")?
+    .trim_end_matches('/');
+
+  let job_path = target
+    .repo
+    .split('/')
+    .chain(target.branch.as_deref())
+    .map(|job| format!("/job/{}", job))
+    .collect::<String>();
+
+  let url = format!(
+    "{}{}/lastBuild/api/json?tree=displayName,building,result,url,timestamp",
+    base_url, job_path
+  );
+
+  let mut request = http_client.get(url);
+
+  if let (Some(username), Some(token)) = (username, token) {
+    request = request.basic_auth(username, token);
+  }
+
+  let res = request.send().await?;
+
+  // Jobs that have never been built have no last build.
+  if res.status() == StatusCode::NOT_FOUND {
+    return Ok(None);
+  }
+
+  let build = res.error_for_status()?.json::<BuildRes>().await?;
+
+  let status = match (build.building, build.result.as_deref()) {
+    (true, _) => CiStatus::Running,
+    (_, Some("SUCCESS")) => CiStatus::Success,
+    (_, Some("FAILURE" | "UNSTABLE")) => CiStatus::Failure,
+    (_, Some("ABORTED")) => CiStatus::Cancelled,
+    (_, Some("NOT_BUILT")) => CiStatus::Skipped,
+    _ => CiStatus::Unknown,
+  };
+
+  Ok(Some(CiPipeline {
+    repo: target.repo.clone(),
+    branch: target.branch.clone(),
+    name: build.display_name,
+    status,
+    url: Some(build.url),
+    commit_sha: None,
+    started_at: build.timestamp,
+    error: None,
+  }))
+}
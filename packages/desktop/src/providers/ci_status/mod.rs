@@ -0,0 +1,6 @@
+mod ci_status_provider;
+mod github_actions;
+mod gitlab;
+mod jenkins;
+
+pub use ci_status_provider::*;
@@ -0,0 +1,82 @@
+use std::{
+  collections::{HashMap, VecDeque},
+  sync::Mutex,
+  time::{Duration, Instant},
+};
+
+use anyhow::bail;
+
+/// Time window that request budgets apply to.
+const BUDGET_WINDOW: Duration = Duration::from_secs(60);
+
+/// Max number of requests to a single host within `BUDGET_WINDOW`,
+/// across all providers.
+const MAX_REQUESTS_PER_WINDOW: usize = 30;
+
+/// Request budgets per host, shared between all HTTP clients.
+#[derive(Debug, Default)]
+pub struct HostBudgets {
+  hosts: Mutex<HashMap<String, HostBudget>>,
+}
+
+#[derive(Debug, Default)]
+struct HostBudget {
+  /// Times of requests within the current window, oldest first.
+  request_times: VecDeque<Instant>,
+
+  /// Time until which requests are rejected, as requested by the host
+  /// via a `Retry-After` header.
+  blocked_until: Option<Instant>,
+}
+
+impl HostBudgets {
+  /// Consumes a request from the host's budget.
+  ///
+  /// Errors if the budget is exhausted or the host has asked for
+  /// requests to be delayed.
+  pub fn acquire(&self, host: &str) -> anyhow::Result<()> {
+    let now = Instant::now();
+    let mut hosts = self.hosts.lock().unwrap();
+    let budget = hosts.entry(host.to_string()).or_default();
+
+    if let Some(blocked_until) = budget.blocked_until {
+      if blocked_until > now {
+        bail!(
+          "Rate limited by {}. Retrying in {}s.",
+          host,
+          (blocked_until - now).as_secs() + 1
+        );
+      }
+
+      budget.blocked_until = None;
+    }
+
+    while budget
+      .request_times
+      .front()
+      .is_some_and(|time| now.duration_since(*time) >= BUDGET_WINDOW)
+    {
+      budget.request_times.pop_front();
+    }
+
+    if budget.request_times.len() >= MAX_REQUESTS_PER_WINDOW {
+      bail!(
+        "Exceeded request budget for {} ({} requests per {}s).",
+        host,
+        MAX_REQUESTS_PER_WINDOW,
+        BUDGET_WINDOW.as_secs()
+      );
+    }
+
+    budget.request_times.push_back(now);
+
+    Ok(())
+  }
+
+  /// Rejects further requests to the host for the given duration.
+  pub fn block(&self, host: &str, duration: Duration) {
+    let mut hosts = self.hosts.lock().unwrap();
+    let budget = hosts.entry(host.to_string()).or_default();
+    budget.blocked_until = Some(Instant::now() + duration);
+  }
+}
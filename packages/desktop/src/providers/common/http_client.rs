@@ -0,0 +1,290 @@
+use std::{fs, path::PathBuf, sync::Arc, time::Duration};
+
+use anyhow::{bail, Context};
+use chrono::{DateTime, Utc};
+use reqwest::{
+  header::RETRY_AFTER, Certificate, Client, ClientBuilder, IntoUrl,
+  Method, NoProxy, Proxy, RequestBuilder, Response, StatusCode,
+};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use tracing::{error, warn};
+
+use super::HostBudgets;
+use crate::config::ProxyConfig;
+
+/// Delay before retrying when a host responds with 429 or 503 without a
+/// valid `Retry-After` header.
+const DEFAULT_RETRY_AFTER: Duration = Duration::from_secs(60);
+
+/// TLS options for HTTP providers, e.g. for use behind TLS-inspecting
+/// corporate proxies.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct TlsConfig {
+  /// Absolute path to a PEM or DER-encoded CA certificate to trust in
+  /// addition to the system's root certificates. PEM files can contain
+  /// multiple certificates.
+  pub ca_cert_path: Option<PathBuf>,
+
+  /// Whether to only trust the certificates in `ca_cert_path`, which
+  /// effectively pins connections to that CA.
+  #[serde(default)]
+  pub pin_ca_cert: bool,
+
+  /// Whether to skip certificate validation entirely. Dangerous, and
+  /// should only be used as a last resort.
+  #[serde(default)]
+  pub accept_invalid_certs: bool,
+}
+
+/// HTTP client for providers that enforces per-host request budgets.
+///
+/// Budgets are shared between all clients created by the same
+/// `HttpClientFactory`, so that multiple providers hitting the same API
+/// can't exceed them together.
+#[derive(Clone, Debug)]
+pub struct HttpClient {
+  client: Client,
+  budgets: Arc<HostBudgets>,
+}
+
+impl HttpClient {
+  /// Starts building a GET request.
+  pub fn get(&self, url: impl IntoUrl) -> HttpRequest {
+    self.request(Method::GET, url)
+  }
+
+  /// Starts building a request with the given method.
+  pub fn request(&self, method: Method, url: impl IntoUrl) -> HttpRequest {
+    HttpRequest {
+      client: self.client.clone(),
+      builder: self.client.request(method, url),
+      budgets: self.budgets.clone(),
+    }
+  }
+}
+
+/// Request built via an `HttpClient`.
+pub struct HttpRequest {
+  client: Client,
+  builder: RequestBuilder,
+  budgets: Arc<HostBudgets>,
+}
+
+impl HttpRequest {
+  /// Adds query parameters to the URL.
+  pub fn query<T: Serialize + ?Sized>(mut self, query: &T) -> Self {
+    self.builder = self.builder.query(query);
+    self
+  }
+
+  /// Adds a header.
+  pub fn header(mut self, name: &str, value: &str) -> Self {
+    self.builder = self.builder.header(name, value);
+    self
+  }
+
+  /// Adds a basic auth `Authorization` header.
+  pub fn basic_auth(mut self, username: &str, password: &str) -> Self {
+    self.builder = self.builder.basic_auth(username, Some(password));
+    self
+  }
+
+  /// Adds a bearer token `Authorization` header.
+  pub fn bearer_auth(mut self, token: &str) -> Self {
+    self.builder = self.builder.bearer_auth(token);
+    self
+  }
+
+  /// Sets a URL-encoded form body.
+  pub fn form<T: Serialize + ?Sized>(mut self, form: &T) -> Self {
+    self.builder = self.builder.form(form);
+    self
+  }
+
+  /// Sets a JSON body.
+  pub fn json<T: Serialize + ?Sized>(mut self, json: &T) -> Self {
+    self.builder = self.builder.json(json);
+    self
+  }
+
+  /// Sends the request if the host's budget allows it.
+  ///
+  /// If the host responds with 429 or 503, further requests to it are
+  /// rejected until the `Retry-After` delay has passed.
+  pub async fn send(self) -> anyhow::Result<Response> {
+    let request = self.builder.build()?;
+    let host = request.url().host_str().unwrap_or_default().to_string();
+
+    self.budgets.acquire(&host)?;
+
+    let response = self.client.execute(request).await?;
+
+    if matches!(
+      response.status(),
+      StatusCode::TOO_MANY_REQUESTS | StatusCode::SERVICE_UNAVAILABLE
+    ) {
+      let retry_after = parse_retry_after(&response);
+      self.budgets.block(&host, retry_after);
+
+      bail!(
+        "{} responded with {}. Retrying in {}s.",
+        host,
+        response.status(),
+        retry_after.as_secs()
+      );
+    }
+
+    Ok(response)
+  }
+}
+
+/// Creates HTTP clients for providers with the user's proxy settings
+/// applied.
+#[derive(Clone, Debug)]
+pub struct HttpClientFactory {
+  proxy_config: Option<ProxyConfig>,
+
+  /// Client without any provider-specific TLS options. Shared between
+  /// providers to reuse connections.
+  default_client: Client,
+
+  /// Request budgets per host.
+  budgets: Arc<HostBudgets>,
+}
+
+impl HttpClientFactory {
+  pub fn new(proxy_config: Option<ProxyConfig>) -> Self {
+    // Fall back to a client without the proxy settings so that an
+    // invalid proxy doesn't prevent Zebar from starting.
+    let default_client = Self::builder(proxy_config.as_ref())
+      .and_then(|builder| {
+        builder.build().context("Failed to create HTTP client.")
+      })
+      .unwrap_or_else(|err| {
+        error!("Failed to apply proxy settings: {:?}", err);
+        Client::new()
+      });
+
+    Self {
+      proxy_config,
+      default_client,
+      budgets: Arc::new(HostBudgets::default()),
+    }
+  }
+
+  /// Returns an HTTP client with the given TLS options.
+  ///
+  /// If no TLS options are given, the shared default client is returned.
+  pub fn client(
+    &self,
+    tls_config: Option<&TlsConfig>,
+  ) -> anyhow::Result<HttpClient> {
+    let client = match tls_config {
+      Some(tls_config) => self.create_tls_client(tls_config)?,
+      None => self.default_client.clone(),
+    };
+
+    Ok(HttpClient {
+      client,
+      budgets: self.budgets.clone(),
+    })
+  }
+
+  /// Creates a client with the proxy settings and the given TLS options
+  /// applied.
+  fn create_tls_client(
+    &self,
+    tls_config: &TlsConfig,
+  ) -> anyhow::Result<Client> {
+    let mut builder = Self::builder(self.proxy_config.as_ref())?;
+
+    if let Some(ca_cert_path) = &tls_config.ca_cert_path {
+      for cert in read_certificates(ca_cert_path)? {
+        builder = builder.add_root_certificate(cert);
+      }
+
+      if tls_config.pin_ca_cert {
+        builder = builder.tls_built_in_root_certs(false);
+      }
+    }
+
+    if tls_config.accept_invalid_certs {
+      warn!("Certificate validation is disabled for an HTTP provider.");
+      builder = builder.danger_accept_invalid_certs(true);
+    }
+
+    builder.build().context("Failed to create HTTP client.")
+  }
+
+  /// Creates a client builder with the proxy settings applied.
+  ///
+  /// If no proxy is configured, the `HTTP_PROXY` and `HTTPS_PROXY`
+  /// environment variables are used instead.
+  fn builder(
+    proxy_config: Option<&ProxyConfig>,
+  ) -> anyhow::Result<ClientBuilder> {
+    let mut builder = Client::builder();
+
+    if let Some(proxy_config) = proxy_config {
+      let mut proxy = Proxy::all(&proxy_config.url)
+        .with_context(|| {
+          format!("Invalid proxy URL '{}'.", proxy_config.url)
+        })?
+        .no_proxy(
+          proxy_config
+            .no_proxy
+            .as_ref()
+            .and_then(|no_proxy| NoProxy::from_string(no_proxy)),
+        );
+
+      if let Some(username) = &proxy_config.username {
+        proxy = proxy.basic_auth(
+          username,
+          proxy_config.password.as_deref().unwrap_or_default(),
+        );
+      }
+
+      builder = builder.proxy(proxy);
+    }
+
+    Ok(builder)
+  }
+}
+
+/// Reads one or more certificates from a PEM or DER-encoded file.
+fn read_certificates(path: &PathBuf) -> anyhow::Result<Vec<Certificate>> {
+  let bytes = fs::read(path).with_context(|| {
+    format!("Failed to read CA certificate: {}", path.display())
+  })?;
+
+  let certs = match bytes.starts_with(b"-----BEGIN") {
+    true => Certificate::from_pem_bundle(&bytes),
+    false => Certificate::from_der(&bytes).map(|cert| vec![cert]),
+  };
+
+  certs
+    .with_context(|| format!("Invalid CA certificate: {}", path.display()))
+}
+
+/// Parses the `Retry-After` header of a response, which is either a
+/// number of seconds or an HTTP date.
+fn parse_retry_after(response: &Response) -> Duration {
+  let Some(value) = response
+    .headers()
+    .get(RETRY_AFTER)
+    .and_then(|value| value.to_str().ok())
+  else {
+    return DEFAULT_RETRY_AFTER;
+  };
+
+  if let Ok(seconds) = value.trim().parse::<u64>() {
+    return Duration::from_secs(seconds);
+  }
+
+  DateTime::parse_from_rfc2822(value)
+    .ok()
+    .and_then(|date| (date.with_timezone(&Utc) - Utc::now()).to_std().ok())
+    .unwrap_or(DEFAULT_RETRY_AFTER)
+}
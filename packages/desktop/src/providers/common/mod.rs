@@ -0,0 +1,7 @@
+mod host_budgets;
+mod http_client;
+mod oauth_client;
+
+pub use host_budgets::*;
+pub use http_client::*;
+pub use oauth_client::*;
@@ -0,0 +1,284 @@
+use std::time::{Duration, Instant};
+
+use anyhow::{bail, Context};
+use base64::prelude::*;
+use reqwest::{Method, Url};
+use serde::Deserialize;
+use sha2::{Digest, Sha256};
+use tokio::{
+  io::{AsyncReadExt, AsyncWriteExt},
+  net::TcpListener,
+  sync::Mutex,
+  task,
+};
+use tracing::info;
+
+use super::HttpClient;
+use crate::{
+  common::open_url,
+  secrets::{delete_secret, get_secret, set_secret},
+};
+
+/// How long to wait for the user to approve access in the browser.
+const AUTHORIZE_TIMEOUT: Duration = Duration::from_secs(5 * 60);
+
+/// Endpoints and settings of an OAuth service.
+pub struct OAuthConfig {
+  /// Display name of the service (e.g. `Spotify`).
+  pub service_name: &'static str,
+
+  pub authorize_url: &'static str,
+  pub token_url: &'static str,
+
+  /// Space-separated permissions requested from the user.
+  pub scopes: &'static str,
+
+  /// Name of the secret that the refresh token is stored under.
+  pub refresh_token_secret: &'static str,
+
+  pub client_id: String,
+
+  /// Port of the local redirect URI, which must be registered with the
+  /// service as `http://127.0.0.1:<PORT>/callback`.
+  pub redirect_port: u16,
+}
+
+#[derive(Deserialize, Debug)]
+struct TokenRes {
+  access_token: String,
+  expires_in: u64,
+
+  /// Services may rotate the refresh token when refreshing.
+  refresh_token: Option<String>,
+}
+
+#[derive(Debug)]
+struct AccessToken {
+  token: String,
+  expires_at: Instant,
+}
+
+/// Authorization via the OAuth authorization code flow with PKCE, for
+/// services that support public clients (i.e. without a client secret).
+///
+/// The refresh token is persisted in the OS credential store, so that
+/// users only need to authorize once.
+pub struct OAuthClient {
+  config: OAuthConfig,
+  http_client: HttpClient,
+  access_token: Mutex<Option<AccessToken>>,
+}
+
+impl OAuthClient {
+  pub fn new(config: OAuthConfig, http_client: HttpClient) -> Self {
+    Self {
+      config,
+      http_client,
+      access_token: Mutex::new(None),
+    }
+  }
+
+  fn redirect_uri(&self) -> String {
+    format!("http://127.0.0.1:{}/callback", self.config.redirect_port)
+  }
+
+  /// Gets a valid access token, refreshing it if needed. Returns `None`
+  /// if the user hasn't authorized yet.
+  pub async fn access_token(&self) -> anyhow::Result<Option<String>> {
+    let mut access_token = self.access_token.lock().await;
+
+    if let Some(token) = access_token
+      .as_ref()
+      .filter(|token| token.expires_at > Instant::now())
+    {
+      return Ok(Some(token.token.clone()));
+    }
+
+    // Credential store lookups can block (e.g. Secret Service on Linux).
+    let secret_name = self.config.refresh_token_secret;
+    let Ok(refresh_token) =
+      task::spawn_blocking(move || get_secret(secret_name)).await?
+    else {
+      return Ok(None);
+    };
+
+    let res = self
+      .request_token(&[
+        ("grant_type", "refresh_token"),
+        ("refresh_token", &refresh_token),
+        ("client_id", &self.config.client_id),
+      ])
+      .await
+      .with_context(|| {
+        format!(
+          "Failed to refresh {} access token.",
+          self.config.service_name
+        )
+      })?;
+
+    *access_token = Some(res);
+    Ok(access_token.as_ref().map(|token| token.token.clone()))
+  }
+
+  /// Opens the authorization page in the browser and waits for the user
+  /// to approve access.
+  pub async fn authorize(&self) -> anyhow::Result<()> {
+    let service_name = self.config.service_name;
+
+    let verifier = format!(
+      "{}{}",
+      uuid::Uuid::new_v4().simple(),
+      uuid::Uuid::new_v4().simple()
+    );
+
+    let challenge =
+      BASE64_URL_SAFE_NO_PAD.encode(Sha256::digest(verifier.as_bytes()));
+
+    let state = uuid::Uuid::new_v4().simple().to_string();
+
+    let listener =
+      TcpListener::bind(("127.0.0.1", self.config.redirect_port))
+        .await
+        .with_context(|| {
+          format!("Port {} is already in use.", self.config.redirect_port)
+        })?;
+
+    let url = Url::parse_with_params(
+      self.config.authorize_url,
+      &[
+        ("client_id", self.config.client_id.as_str()),
+        ("response_type", "code"),
+        ("redirect_uri", &self.redirect_uri()),
+        ("code_challenge_method", "S256"),
+        ("code_challenge", &challenge),
+        ("scope", self.config.scopes),
+        ("state", &state),
+      ],
+    )?;
+
+    open_url(url.as_str())?;
+
+    let code = tokio::time::timeout(
+      AUTHORIZE_TIMEOUT,
+      wait_for_callback(&listener, &state, service_name),
+    )
+    .await
+    .with_context(|| {
+      format!("Timed out waiting for {} authorization.", service_name)
+    })??;
+
+    let token = self
+      .request_token(&[
+        ("grant_type", "authorization_code"),
+        ("code", &code),
+        ("redirect_uri", &self.redirect_uri()),
+        ("client_id", &self.config.client_id),
+        ("code_verifier", &verifier),
+      ])
+      .await
+      .with_context(|| {
+        format!("Failed to get {} access token.", service_name)
+      })?;
+
+    *self.access_token.lock().await = Some(token);
+    info!("Authorized with {}.", service_name);
+
+    Ok(())
+  }
+
+  /// Forgets the stored tokens.
+  pub async fn logout(&self) -> anyhow::Result<()> {
+    *self.access_token.lock().await = None;
+
+    let secret_name = self.config.refresh_token_secret;
+    task::spawn_blocking(move || delete_secret(secret_name)).await?
+  }
+
+  /// Requests an access token and stores the refresh token if one is
+  /// returned.
+  async fn request_token(
+    &self,
+    form: &[(&str, &str)],
+  ) -> anyhow::Result<AccessToken> {
+    let res = self
+      .http_client
+      .request(Method::POST, self.config.token_url)
+      .form(form)
+      .send()
+      .await?
+      .error_for_status()?
+      .json::<TokenRes>()
+      .await?;
+
+    if let Some(refresh_token) = res.refresh_token {
+      let secret_name = self.config.refresh_token_secret;
+
+      task::spawn_blocking(move || {
+        set_secret(secret_name, &refresh_token)
+      })
+      .await??;
+    }
+
+    Ok(AccessToken {
+      token: res.access_token,
+      // Refresh slightly before the token expires.
+      expires_at: Instant::now()
+        + Duration::from_secs(res.expires_in.saturating_sub(60)),
+    })
+  }
+}
+
+/// Accepts connections to the redirect URI until one has an
+/// authorization code. Returns the code.
+async fn wait_for_callback(
+  listener: &TcpListener,
+  state: &str,
+  service_name: &str,
+) -> anyhow::Result<String> {
+  loop {
+    let (mut stream, _) = listener.accept().await?;
+
+    let mut buffer = vec![0; 8192];
+    let len = stream.read(&mut buffer).await?;
+    let request = String::from_utf8_lossy(&buffer[..len]);
+
+    // Request line is of the form `GET /callback?code=... HTTP/1.1`.
+    let Some(path) = request
+      .lines()
+      .next()
+      .and_then(|line| line.split_whitespace().nth(1))
+      .filter(|path| path.starts_with("/callback"))
+    else {
+      // Ignore other requests (e.g. for the favicon).
+      continue;
+    };
+
+    let url = Url::parse(&format!("http://127.0.0.1{}", path))?;
+    let param = |name: &str| {
+      url
+        .query_pairs()
+        .find(|(key, _)| key == name)
+        .map(|(_, value)| value.into_owned())
+    };
+
+    let response = format!(
+      "HTTP/1.1 200 OK\r\n\
+      Content-Type: text/html; charset=utf-8\r\n\
+      Connection: close\r\n\r\n\
+      <p>Zebar is connected to {}. You can close this window.</p>",
+      service_name
+    );
+
+    let _ = stream.write_all(response.as_bytes()).await;
+
+    if let Some(err) = param("error") {
+      bail!("{} authorization failed: {}", service_name, err);
+    }
+
+    if param("state").as_deref() != Some(state) {
+      bail!("{} authorization failed: state mismatch.", service_name);
+    }
+
+    return param("code").context("No authorization code in callback.");
+  }
+}
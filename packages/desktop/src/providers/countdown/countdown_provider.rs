@@ -0,0 +1,183 @@
+use anyhow::Context;
+use chrono::{Local, NaiveDateTime, TimeZone};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::date_spec::DateSpec;
+use crate::{
+  common::{IntervalOptions, SyncInterval},
+  providers::{
+    CommonProviderState, Provider, ProviderInputMsg, RuntimeType,
+  },
+};
+
+#[derive(Deserialize, Debug, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct CountdownProviderConfig {
+  #[serde(deserialize_with = "crate::common::deserialize_duration_ms")]
+  #[schemars(schema_with = "crate::common::duration_schema")]
+  pub refresh_interval: u64,
+
+  /// Alignment and jitter of refreshes.
+  #[serde(default)]
+  pub refresh_options: IntervalOptions,
+
+  /// Events to count down to.
+  pub events: Vec<CountdownEventConfig>,
+}
+
+#[derive(Deserialize, Debug, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct CountdownEventConfig {
+  pub name: String,
+
+  /// Target date in local time. Supports full dates (`2026-12-25
+  /// 18:30`, RFC 3339), relative days (`tomorrow 9am`), month and day
+  /// (`Dec 25`), weekdays (`friday 17:00`), and times (`17:30`).
+  pub date: String,
+
+  /// How often the event repeats. Defaults to the recurrence implied by
+  /// the date (e.g. `Dec 25` repeats yearly, `friday` weekly, and
+  /// `17:30` daily).
+  pub recurrence: Option<Recurrence>,
+}
+
+#[derive(
+  Clone, Copy, Debug, Deserialize, PartialEq, Serialize, JsonSchema,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum Recurrence {
+  None,
+  Daily,
+  Weekly,
+  Monthly,
+  Yearly,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct CountdownOutput {
+  /// Countdowns in the same order as the configured events.
+  pub events: Vec<Countdown>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct Countdown {
+  pub name: String,
+
+  /// Next occurrence of the event as a Unix timestamp in milliseconds.
+  pub target_time: i64,
+
+  /// Whether the event has passed. Only possible for events that don't
+  /// repeat.
+  pub is_past: bool,
+
+  /// Remaining time in milliseconds. 0 if the event has passed.
+  pub remaining: u64,
+
+  /// Remaining whole days.
+  pub days: u64,
+
+  /// Remaining hours after whole days (0-23).
+  pub hours: u64,
+
+  /// Remaining minutes after whole hours (0-59).
+  pub minutes: u64,
+
+  /// Remaining seconds after whole minutes (0-59).
+  pub seconds: u64,
+}
+
+pub struct CountdownProvider {
+  config: CountdownProviderConfig,
+  common: CommonProviderState,
+}
+
+impl CountdownProvider {
+  pub fn new(
+    config: CountdownProviderConfig,
+    common: CommonProviderState,
+  ) -> CountdownProvider {
+    CountdownProvider { config, common }
+  }
+
+  fn run_interval(&self) -> anyhow::Result<CountdownOutput> {
+    let now = Local::now();
+
+    let events = self
+      .config
+      .events
+      .iter()
+      .map(|event| {
+        let date_spec = DateSpec::parse(&event.date, now.naive_local())
+          .with_context(|| {
+            format!("Invalid date for event '{}'.", event.name)
+          })?;
+
+        let recurrence =
+          event.recurrence.unwrap_or(date_spec.implied_recurrence);
+
+        let target_time = to_timestamp(
+          date_spec.next_occurrence(recurrence, now.naive_local()),
+        );
+
+        let remaining =
+          (target_time - now.timestamp_millis()).max(0) as u64;
+
+        let remaining_secs = remaining / 1000;
+
+        Ok(Countdown {
+          name: event.name.clone(),
+          target_time,
+          is_past: remaining == 0,
+          remaining,
+          days: remaining_secs / 86400,
+          hours: remaining_secs / 3600 % 24,
+          minutes: remaining_secs / 60 % 60,
+          seconds: remaining_secs % 60,
+        })
+      })
+      .collect::<anyhow::Result<Vec<_>>>()?;
+
+    Ok(CountdownOutput { events })
+  }
+}
+
+/// Converts a local date and time to a Unix timestamp in milliseconds.
+///
+/// Times that are skipped by a DST transition are treated as UTC.
+fn to_timestamp(date_time: NaiveDateTime) -> i64 {
+  Local
+    .from_local_datetime(&date_time)
+    .earliest()
+    .map(|date_time| date_time.timestamp_millis())
+    .unwrap_or_else(|| date_time.and_utc().timestamp_millis())
+}
+
+impl Provider for CountdownProvider {
+  fn runtime_type(&self) -> RuntimeType {
+    RuntimeType::Sync
+  }
+
+  fn start_sync(&mut self) {
+    let mut interval = SyncInterval::with_options(
+      self.config.refresh_interval,
+      &self.config.refresh_options,
+    );
+
+    loop {
+      crossbeam::select! {
+        recv(interval.tick()) -> _ => {
+          let output = self.run_interval();
+          self.common.emitter.emit_output(output);
+        }
+        recv(self.common.input.sync_rx) -> input => {
+          if let Ok(ProviderInputMsg::Stop) = input {
+            break;
+          }
+        }
+      }
+    }
+  }
+}
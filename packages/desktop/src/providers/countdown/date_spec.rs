@@ -0,0 +1,189 @@
+use std::str::FromStr;
+
+use anyhow::{bail, Context};
+use chrono::{
+  DateTime, Datelike, Duration, Months, NaiveDate, NaiveDateTime,
+  NaiveTime, Weekday,
+};
+
+use super::Recurrence;
+
+/// Month name prefixes, as accepted in e.g. `Dec 25` or `december 25`.
+const MONTHS: [&str; 12] = [
+  "jan", "feb", "mar", "apr", "may", "jun", "jul", "aug", "sep", "oct",
+  "nov", "dec",
+];
+
+/// Formats accepted for the time of day.
+const TIME_FORMATS: [&str; 4] = ["%H:%M", "%H:%M:%S", "%I:%M%p", "%I%p"];
+
+/// Parsed target date of a countdown event.
+#[derive(Debug)]
+pub struct DateSpec {
+  /// First occurrence of the event in local time.
+  start: NaiveDateTime,
+
+  /// Recurrence implied by the date format (e.g. `Dec 25` repeats
+  /// yearly).
+  pub implied_recurrence: Recurrence,
+}
+
+impl DateSpec {
+  /// Parses a date relative to `now`. Supported formats:
+  ///  * Date and time: `2026-12-25`, `2026-12-25 18:30`, or RFC 3339.
+  ///  * Relative day: `today 17:00` or `tomorrow 9am`.
+  ///  * Month and day (repeats yearly): `12-25` or `Dec 25 18:00`.
+  ///  * Weekday (repeats weekly): `friday 17:00` or `mon`.
+  ///  * Time only (repeats daily): `17:30`.
+  pub fn parse(input: &str, now: NaiveDateTime) -> anyhow::Result<Self> {
+    let input = input.trim();
+
+    if let Ok(date_time) = DateTime::parse_from_rfc3339(input) {
+      return Ok(Self::once(
+        date_time.with_timezone(&chrono::Local).naive_local(),
+      ));
+    }
+
+    for format in ["%Y-%m-%dT%H:%M:%S", "%Y-%m-%dT%H:%M"] {
+      if let Ok(date_time) = NaiveDateTime::parse_from_str(input, format) {
+        return Ok(Self::once(date_time));
+      }
+    }
+
+    let lowercase = input.to_lowercase();
+    let mut tokens = lowercase.split_whitespace().collect::<Vec<_>>();
+
+    let time = match tokens.last().and_then(|token| parse_time(token)) {
+      Some(time) => {
+        tokens.pop();
+        time
+      }
+      None if tokens.is_empty() => bail!("Date is empty."),
+      None => NaiveTime::MIN,
+    };
+
+    let today = now.date();
+
+    let (date, recurrence) = match tokens.as_slice() {
+      [] => (today, Recurrence::Daily),
+      ["today"] => (today, Recurrence::None),
+      ["tomorrow"] => (today + Duration::days(1), Recurrence::None),
+      [token] => {
+        if let Ok(date) = NaiveDate::parse_from_str(token, "%Y-%m-%d") {
+          (date, Recurrence::None)
+        } else if let Ok(weekday) = Weekday::from_str(token) {
+          let days_ahead = (weekday.num_days_from_monday() + 7
+            - today.weekday().num_days_from_monday())
+            % 7;
+
+          (
+            today + Duration::days(days_ahead as i64),
+            Recurrence::Weekly,
+          )
+        } else if let Some((month, day)) = token.split_once('-') {
+          (month_day(today, month, day)?, Recurrence::Yearly)
+        } else {
+          bail!("Unrecognized date '{}'.", input);
+        }
+      }
+      [month, day] if is_month_name(month) => {
+        (month_day(today, month, day)?, Recurrence::Yearly)
+      }
+      [day, month] if is_month_name(month) => {
+        (month_day(today, month, day)?, Recurrence::Yearly)
+      }
+      _ => bail!("Unrecognized date '{}'.", input),
+    };
+
+    Ok(Self {
+      start: date.and_time(time),
+      implied_recurrence: recurrence,
+    })
+  }
+
+  fn once(start: NaiveDateTime) -> Self {
+    Self {
+      start,
+      implied_recurrence: Recurrence::None,
+    }
+  }
+
+  /// Gets the first occurrence at or after `now`. Events that don't
+  /// repeat return their start, even if it's in the past.
+  pub fn next_occurrence(
+    &self,
+    recurrence: Recurrence,
+    now: NaiveDateTime,
+  ) -> NaiveDateTime {
+    if self.start >= now {
+      return self.start;
+    }
+
+    match recurrence {
+      Recurrence::None => self.start,
+      Recurrence::Daily | Recurrence::Weekly => {
+        let period_ms = match recurrence {
+          Recurrence::Daily => Duration::days(1),
+          _ => Duration::weeks(1),
+        }
+        .num_milliseconds();
+
+        let elapsed_ms = (now - self.start).num_milliseconds();
+        let periods = (elapsed_ms + period_ms - 1) / period_ms;
+
+        self.start + Duration::milliseconds(periods * period_ms)
+      }
+      Recurrence::Monthly | Recurrence::Yearly => {
+        let step = match recurrence {
+          Recurrence::Monthly => 1,
+          _ => 12,
+        };
+
+        // Months are added to the start each time (rather than to the
+        // previous occurrence), so that e.g. the 31st isn't clamped to
+        // the 28th permanently after February.
+        (1..)
+          .map_while(|index| {
+            self.start.checked_add_months(Months::new(index * step))
+          })
+          .find(|occurrence| *occurrence >= now)
+          .unwrap_or(self.start)
+      }
+    }
+  }
+}
+
+fn parse_time(token: &str) -> Option<NaiveTime> {
+  TIME_FORMATS
+    .iter()
+    .find_map(|format| NaiveTime::parse_from_str(token, format).ok())
+}
+
+/// Parses a month as a number (`12`) or name (`dec` or `december`).
+fn parse_month(token: &str) -> Option<u32> {
+  token.parse::<u32>().ok().or_else(|| {
+    MONTHS
+      .iter()
+      .position(|name| token.starts_with(name))
+      .map(|index| index as u32 + 1)
+  })
+}
+
+fn is_month_name(token: &str) -> bool {
+  MONTHS.iter().any(|name| token.starts_with(name))
+}
+
+/// Gets the date with the given month and day in the current year, or
+/// the next year it exists in (e.g. for February 29th).
+fn month_day(
+  today: NaiveDate,
+  month: &str,
+  day: &str,
+) -> anyhow::Result<NaiveDate> {
+  let month = parse_month(month).context("Invalid month.")?;
+  let day = day.parse::<u32>().context("Invalid day.")?;
+
+  (today.year()..today.year() + 8)
+    .find_map(|year| NaiveDate::from_ymd_opt(year, month, day))
+    .with_context(|| format!("Invalid date {}-{}.", month, day))
+}
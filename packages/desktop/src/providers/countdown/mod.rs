@@ -0,0 +1,4 @@
+mod countdown_provider;
+mod date_spec;
+
+pub use countdown_provider::*;
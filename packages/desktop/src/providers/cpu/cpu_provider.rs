@@ -1,19 +1,26 @@
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 use crate::{
-  common::SyncInterval,
+  common::{IntervalOptions, SyncInterval},
   providers::{
     CommonProviderState, Provider, ProviderInputMsg, RuntimeType,
   },
 };
 
-#[derive(Deserialize, Debug)]
+#[derive(Deserialize, Debug, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct CpuProviderConfig {
+  #[serde(deserialize_with = "crate::common::deserialize_duration_ms")]
+  #[schemars(schema_with = "crate::common::duration_schema")]
   pub refresh_interval: u64,
+
+  /// Alignment and jitter of refreshes.
+  #[serde(default)]
+  pub refresh_options: IntervalOptions,
 }
 
-#[derive(Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct CpuOutput {
   pub frequency: u64,
@@ -40,14 +47,29 @@ impl CpuProvider {
     let mut sysinfo = self.common.sysinfo.blocking_lock();
     sysinfo.refresh_cpu();
 
+    let global_cpu = sysinfo.global_cpu_info();
+
+    // The global frequency and vendor ID aren't available on all
+    // platforms (e.g. ARM64), so fall back to the per-core frequencies
+    // and the brand name.
+    let frequency = match global_cpu.frequency() {
+      0 => sysinfo.cpus().iter().map(|cpu| cpu.frequency()).max(),
+      frequency => Some(frequency),
+    };
+
+    let vendor = match global_cpu.vendor_id() {
+      "" => sysinfo.cpus().first().map(|cpu| cpu.brand()),
+      vendor => Some(vendor),
+    };
+
     Ok(CpuOutput {
-      usage: sysinfo.global_cpu_info().cpu_usage(),
-      frequency: sysinfo.global_cpu_info().frequency(),
+      usage: global_cpu.cpu_usage(),
+      frequency: frequency.unwrap_or(0),
       logical_core_count: sysinfo.cpus().len(),
       physical_core_count: sysinfo
         .physical_core_count()
         .unwrap_or(sysinfo.cpus().len()),
-      vendor: sysinfo.global_cpu_info().vendor_id().into(),
+      vendor: vendor.unwrap_or_default().trim().into(),
     })
   }
 }
@@ -58,7 +80,10 @@ impl Provider for CpuProvider {
   }
 
   fn start_sync(&mut self) {
-    let mut interval = SyncInterval::new(self.config.refresh_interval);
+    let mut interval = SyncInterval::with_options(
+      self.config.refresh_interval,
+      &self.config.refresh_options,
+    );
 
     loop {
       crossbeam::select! {
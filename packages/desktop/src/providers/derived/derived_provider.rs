@@ -0,0 +1,209 @@
+use std::collections::{BTreeMap, HashMap};
+
+use anyhow::{bail, Context};
+use async_trait::async_trait;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use tokio::sync::mpsc;
+use tracing::warn;
+
+use crate::{
+  common::Expression,
+  providers::{
+    CommonProviderState, Provider, ProviderConfig, ProviderEmission,
+    ProviderInputMsg, ProviderRef, RuntimeType,
+  },
+};
+
+#[derive(Deserialize, Debug, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct DerivedProviderConfig {
+  /// Providers to derive values from, keyed by the name used to
+  /// reference their output in expressions.
+  pub providers: HashMap<String, ProviderConfig>,
+
+  /// Expressions to evaluate, keyed by the name of the output field.
+  /// For example, `sum(network.interfaces[].receivedRate)` or
+  /// `cpu.usage > 80 ? 'high' : 'normal'`.
+  pub values: HashMap<String, String>,
+}
+
+/// Evaluated values, keyed by their name in the config.
+#[derive(Debug, Clone, PartialEq, Serialize, JsonSchema)]
+#[serde(transparent)]
+pub struct DerivedOutput(pub BTreeMap<String, serde_json::Value>);
+
+/// Source providers of a derived provider, started by the
+/// `ProviderManager`.
+pub struct DerivedSources {
+  /// Receiver channel for emissions from the sources.
+  emit_rx: mpsc::UnboundedReceiver<ProviderEmission>,
+
+  /// Source names by config hash.
+  names: HashMap<String, String>,
+
+  /// Refs to the running sources.
+  refs: Vec<ProviderRef>,
+}
+
+impl DerivedSources {
+  pub fn new(emit_rx: mpsc::UnboundedReceiver<ProviderEmission>) -> Self {
+    Self {
+      emit_rx,
+      names: HashMap::new(),
+      refs: Vec::new(),
+    }
+  }
+
+  pub fn insert(
+    &mut self,
+    name: String,
+    config_hash: String,
+    provider_ref: ProviderRef,
+  ) {
+    self.names.insert(config_hash, name);
+    self.refs.push(provider_ref);
+  }
+
+  /// Stops all sources and waits for them to exit.
+  pub async fn stop(&mut self) {
+    for provider_ref in self.refs.drain(..) {
+      if let Err(err) = provider_ref.stop().await {
+        warn!("Failed to stop source provider: {:?}", err);
+      }
+    }
+  }
+}
+
+pub struct DerivedProvider {
+  common: CommonProviderState,
+  sources: DerivedSources,
+
+  /// Parsed expressions by value name, or the first parse error.
+  expressions: Result<Vec<(String, Expression)>, String>,
+
+  /// Latest output of each source, keyed by name.
+  outputs: HashMap<String, Result<serde_json::Value, String>>,
+}
+
+impl DerivedProvider {
+  pub fn new(
+    config: DerivedProviderConfig,
+    sources: DerivedSources,
+    common: CommonProviderState,
+  ) -> DerivedProvider {
+    let expressions = parse_expressions(&config.values, &sources.names)
+      .map_err(|err| format!("{:#}", err));
+
+    DerivedProvider {
+      common,
+      sources,
+      expressions,
+      outputs: HashMap::new(),
+    }
+  }
+
+  /// Evaluates all expressions against the latest source outputs.
+  /// Sources that haven't emitted yet resolve to `null`.
+  fn evaluate(&self) -> anyhow::Result<DerivedOutput> {
+    let expressions = self
+      .expressions
+      .as_ref()
+      .map_err(|err| anyhow::anyhow!(err.clone()))?;
+
+    let mut outputs = HashMap::new();
+
+    for (name, output) in &self.outputs {
+      match output {
+        Ok(output) => outputs.insert(name.clone(), output.clone()),
+        Err(err) => bail!("Source '{}' failed: {}", name, err),
+      };
+    }
+
+    let values = expressions
+      .iter()
+      .map(|(name, expression)| {
+        let value = expression
+          .evaluate(&outputs)
+          .with_context(|| format!("Failed to evaluate '{}'.", name))?;
+
+        anyhow::Ok((name.clone(), value))
+      })
+      .try_collect::<BTreeMap<_, _>>()?;
+
+    Ok(DerivedOutput(values))
+  }
+
+  fn handle_emission(&mut self, emission: ProviderEmission) {
+    let Some(name) = self.sources.names.get(&emission.config_hash) else {
+      return;
+    };
+
+    let output = emission.result.and_then(|output| {
+      serde_json::to_value(output).map_err(|err| err.to_string())
+    });
+
+    self.outputs.insert(name.clone(), output);
+
+    let output = self.evaluate();
+    self.common.emitter.emit_output_cached(output);
+  }
+}
+
+/// Parses the expression of each value, and checks that they only
+/// reference known sources.
+fn parse_expressions(
+  values: &HashMap<String, String>,
+  source_names: &HashMap<String, String>,
+) -> anyhow::Result<Vec<(String, Expression)>> {
+  values
+    .iter()
+    .map(|(name, source)| {
+      let expression = Expression::parse(source)
+        .with_context(|| format!("Invalid expression for '{}'.", name))?;
+
+      for source_name in expression.sources() {
+        if !source_names.values().any(|name| name == source_name) {
+          bail!(
+            "Unknown provider '{}' in expression for '{}'.",
+            source_name,
+            name
+          );
+        }
+      }
+
+      Ok((name.clone(), expression))
+    })
+    .collect()
+}
+
+#[async_trait]
+impl Provider for DerivedProvider {
+  fn runtime_type(&self) -> RuntimeType {
+    RuntimeType::Async
+  }
+
+  async fn start_async(&mut self) {
+    // Report invalid expressions without waiting for the sources.
+    if let Err(err) = &self.expressions {
+      self.common.emitter.emit_output_cached::<DerivedOutput>(Err(
+        anyhow::anyhow!(err.clone()),
+      ));
+    }
+
+    loop {
+      tokio::select! {
+        Some(emission) = self.sources.emit_rx.recv() => {
+          self.handle_emission(emission);
+        }
+        Some(message) = self.common.input.async_rx.recv() => {
+          if let ProviderInputMsg::Stop = message {
+            break;
+          }
+        }
+      }
+    }
+
+    self.sources.stop().await;
+  }
+}
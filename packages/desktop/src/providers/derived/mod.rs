@@ -0,0 +1,3 @@
+mod derived_provider;
+
+pub use derived_provider::*;
@@ -0,0 +1,168 @@
+use std::time::{Duration, Instant};
+
+use anyhow::Context;
+use reqwest::Method;
+use serde::Deserialize;
+use serde_json::json;
+use tokio::{sync::Mutex, task};
+use tracing::info;
+
+use super::{discord_ipc::DiscordIpc, discord_res::AuthorizeRes};
+use crate::{
+  providers::common::HttpClient,
+  secrets::{delete_secret, get_secret, set_secret},
+};
+
+const TOKEN_URL: &str = "https://discord.com/api/oauth2/token";
+
+/// Permissions requested from the user.
+const SCOPES: [&str; 4] =
+  ["identify", "rpc", "rpc.voice.read", "rpc.voice.write"];
+
+/// Name of the secret that the refresh token is stored under.
+const REFRESH_TOKEN_SECRET: &str = "discord_refresh_token";
+
+/// How long to wait for the user to approve access in Discord.
+const AUTHORIZE_TIMEOUT: Duration = Duration::from_secs(5 * 60);
+
+#[derive(Deserialize, Debug)]
+struct TokenRes {
+  access_token: String,
+  expires_in: u64,
+  refresh_token: String,
+}
+
+#[derive(Debug)]
+struct AccessToken {
+  token: String,
+  expires_at: Instant,
+}
+
+/// Authorization with the Discord client's RPC socket.
+///
+/// Access is approved via a prompt in the Discord client. The refresh
+/// token is persisted in the OS credential store, so that users only
+/// need to authorize once.
+pub struct DiscordAuth {
+  client_id: String,
+  client_secret: String,
+  http_client: HttpClient,
+  access_token: Mutex<Option<AccessToken>>,
+}
+
+impl DiscordAuth {
+  pub fn new(
+    client_id: String,
+    client_secret: String,
+    http_client: HttpClient,
+  ) -> Self {
+    Self {
+      client_id,
+      client_secret,
+      http_client,
+      access_token: Mutex::new(None),
+    }
+  }
+
+  /// Gets a valid access token, refreshing it if needed. Returns `None`
+  /// if the user hasn't authorized yet.
+  pub async fn access_token(&self) -> anyhow::Result<Option<String>> {
+    let mut access_token = self.access_token.lock().await;
+
+    if let Some(token) = access_token
+      .as_ref()
+      .filter(|token| token.expires_at > Instant::now())
+    {
+      return Ok(Some(token.token.clone()));
+    }
+
+    // Credential store lookups can block (e.g. Secret Service on Linux).
+    let Ok(refresh_token) =
+      task::spawn_blocking(|| get_secret(REFRESH_TOKEN_SECRET)).await?
+    else {
+      return Ok(None);
+    };
+
+    let res = self
+      .request_token(&[
+        ("grant_type", "refresh_token"),
+        ("refresh_token", &refresh_token),
+      ])
+      .await
+      .context("Failed to refresh Discord access token.")?;
+
+    *access_token = Some(res);
+    Ok(access_token.as_ref().map(|token| token.token.clone()))
+  }
+
+  /// Shows the authorization prompt in the Discord client and waits for
+  /// the user to approve access.
+  ///
+  /// Uses its own connection, since the prompt blocks the connection
+  /// until it's answered.
+  pub async fn authorize(&self) -> anyhow::Result<()> {
+    let mut ipc = DiscordIpc::connect(&self.client_id).await?;
+
+    let res = tokio::time::timeout(
+      AUTHORIZE_TIMEOUT,
+      ipc.command::<AuthorizeRes>(
+        "AUTHORIZE",
+        json!({ "client_id": self.client_id, "scopes": SCOPES }),
+      ),
+    )
+    .await
+    .context("Timed out waiting for Discord authorization.")??;
+
+    let token = self
+      .request_token(&[
+        ("grant_type", "authorization_code"),
+        ("code", &res.code),
+      ])
+      .await
+      .context("Failed to get Discord access token.")?;
+
+    *self.access_token.lock().await = Some(token);
+    info!("Authorized with Discord.");
+
+    Ok(())
+  }
+
+  /// Forgets the stored tokens.
+  pub async fn logout(&self) -> anyhow::Result<()> {
+    *self.access_token.lock().await = None;
+    task::spawn_blocking(|| delete_secret(REFRESH_TOKEN_SECRET)).await?
+  }
+
+  /// Requests an access token and stores the returned refresh token.
+  async fn request_token(
+    &self,
+    form: &[(&str, &str)],
+  ) -> anyhow::Result<AccessToken> {
+    let mut form = form.to_vec();
+    form.push(("client_id", &self.client_id));
+    form.push(("client_secret", &self.client_secret));
+
+    let res = self
+      .http_client
+      .request(Method::POST, TOKEN_URL)
+      .form(&form)
+      .send()
+      .await?
+      .error_for_status()?
+      .json::<TokenRes>()
+      .await?;
+
+    let refresh_token = res.refresh_token;
+    task::spawn_blocking(move || {
+      set_secret(REFRESH_TOKEN_SECRET, &refresh_token)
+    })
+    .await??;
+
+    Ok(AccessToken {
+      token: res.access_token,
+      // Refresh slightly before the token expires.
+      expires_at: Instant::now()
+        + Duration::from_secs(res.expires_in.saturating_sub(60)),
+    })
+  }
+}
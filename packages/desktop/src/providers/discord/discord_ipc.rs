@@ -0,0 +1,169 @@
+use anyhow::{bail, Context};
+use serde::de::DeserializeOwned;
+use serde_json::json;
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt};
+
+/// Discord listens on the first free socket from `discord-ipc-0` to
+/// `discord-ipc-9`.
+const MAX_SOCKET_INDEX: u32 = 10;
+
+const OP_HANDSHAKE: u32 = 0;
+const OP_FRAME: u32 = 1;
+const OP_CLOSE: u32 = 2;
+const OP_PING: u32 = 3;
+const OP_PONG: u32 = 4;
+
+trait IpcStream: AsyncRead + AsyncWrite + Unpin + Send {}
+
+impl<T: AsyncRead + AsyncWrite + Unpin + Send> IpcStream for T {}
+
+/// Connection to the local RPC socket of the Discord client.
+pub struct DiscordIpc {
+  stream: Box<dyn IpcStream>,
+}
+
+impl DiscordIpc {
+  /// Connects to the running Discord client and performs the handshake.
+  pub async fn connect(client_id: &str) -> anyhow::Result<Self> {
+    let mut ipc = Self {
+      stream: Self::open_socket().await?,
+    };
+
+    ipc
+      .send(OP_HANDSHAKE, &json!({ "v": 1, "client_id": client_id }))
+      .await?;
+
+    // Discord replies with a `READY` event on success.
+    ipc.read().await?;
+
+    Ok(ipc)
+  }
+
+  #[cfg(windows)]
+  async fn open_socket() -> anyhow::Result<Box<dyn IpcStream>> {
+    use tokio::net::windows::named_pipe::ClientOptions;
+
+    for index in 0..MAX_SOCKET_INDEX {
+      let path = format!(r"\\.\pipe\discord-ipc-{}", index);
+
+      if let Ok(pipe) = ClientOptions::new().open(path) {
+        return Ok(Box::new(pipe));
+      }
+    }
+
+    bail!("Discord isn't running.")
+  }
+
+  #[cfg(unix)]
+  async fn open_socket() -> anyhow::Result<Box<dyn IpcStream>> {
+    use std::{env, path::PathBuf};
+
+    use tokio::net::UnixStream;
+
+    let base_dirs = ["XDG_RUNTIME_DIR", "TMPDIR", "TMP", "TEMP"]
+      .into_iter()
+      .filter_map(|var| env::var(var).ok())
+      .chain(["/tmp".to_string()])
+      .map(PathBuf::from);
+
+    for base_dir in base_dirs {
+      // Flatpak and Snap installs put the socket in a subdirectory.
+      for sub_dir in ["", "app/com.discordapp.Discord", "snap.discord"] {
+        for index in 0..MAX_SOCKET_INDEX {
+          let path = base_dir
+            .join(sub_dir)
+            .join(format!("discord-ipc-{}", index));
+
+          if let Ok(stream) = UnixStream::connect(path).await {
+            return Ok(Box::new(stream));
+          }
+        }
+      }
+    }
+
+    bail!("Discord isn't running.")
+  }
+
+  /// Sends an RPC command and parses the `data` of its response.
+  pub async fn command<T: DeserializeOwned>(
+    &mut self,
+    command: &str,
+    args: serde_json::Value,
+  ) -> anyhow::Result<T> {
+    let nonce = uuid::Uuid::new_v4().to_string();
+
+    self
+      .send(
+        OP_FRAME,
+        &json!({ "cmd": command, "args": args, "nonce": nonce }),
+      )
+      .await?;
+
+    // Skip any events that arrive before the response.
+    loop {
+      let payload = self.read().await?;
+
+      if payload["nonce"].as_str() != Some(&nonce) {
+        continue;
+      }
+
+      if payload["evt"].as_str() == Some("ERROR") {
+        bail!(
+          "Discord RPC command {} failed: {}",
+          command,
+          payload["data"]["message"]
+            .as_str()
+            .unwrap_or("Unknown error.")
+        );
+      }
+
+      return serde_json::from_value(payload["data"].clone())
+        .with_context(|| {
+          format!("Invalid response to Discord RPC command {}.", command)
+        });
+    }
+  }
+
+  async fn send(
+    &mut self,
+    opcode: u32,
+    payload: &serde_json::Value,
+  ) -> anyhow::Result<()> {
+    let payload = serde_json::to_vec(payload)?;
+
+    let mut message = Vec::with_capacity(8 + payload.len());
+    message.extend_from_slice(&opcode.to_le_bytes());
+    message.extend_from_slice(&(payload.len() as u32).to_le_bytes());
+    message.extend_from_slice(&payload);
+
+    self.stream.write_all(&message).await?;
+    Ok(())
+  }
+
+  /// Reads the next frame, answering pings along the way.
+  async fn read(&mut self) -> anyhow::Result<serde_json::Value> {
+    loop {
+      let mut header = [0; 8];
+      self.stream.read_exact(&mut header).await?;
+
+      let opcode = u32::from_le_bytes(header[0..4].try_into()?);
+      let length = u32::from_le_bytes(header[4..8].try_into()?) as usize;
+
+      let mut payload = vec![0; length];
+      self.stream.read_exact(&mut payload).await?;
+
+      let payload = serde_json::from_slice::<serde_json::Value>(&payload)
+        .context("Invalid message from Discord RPC socket.")?;
+
+      match opcode {
+        OP_FRAME => return Ok(payload),
+        OP_PING => self.send(OP_PONG, &payload).await?,
+        OP_CLOSE => bail!(
+          "Discord closed the RPC connection: {}",
+          payload["message"].as_str().unwrap_or("Unknown reason.")
+        ),
+        _ => {}
+      }
+    }
+  }
+}
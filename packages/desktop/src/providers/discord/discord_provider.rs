@@ -0,0 +1,409 @@
+use std::sync::Arc;
+
+use anyhow::{bail, Context};
+use async_trait::async_trait;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+use super::{
+  discord_auth::DiscordAuth,
+  discord_ipc::DiscordIpc,
+  discord_res::{
+    AuthenticateRes, GuildRes, UserRes, VoiceChannelRes, VoiceSettingsRes,
+  },
+};
+use crate::{
+  common::{AsyncInterval, IntervalOptions},
+  providers::{
+    common::TlsConfig, CommonProviderState, DiscordFunction, Provider,
+    ProviderFunction, ProviderFunctionResponse, ProviderInputMsg,
+    RuntimeType,
+  },
+};
+
+#[derive(Deserialize, Debug, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct DiscordProviderConfig {
+  #[serde(deserialize_with = "crate::common::deserialize_duration_ms")]
+  #[schemars(schema_with = "crate::common::duration_schema")]
+  pub refresh_interval: u64,
+
+  /// Alignment and jitter of refreshes.
+  #[serde(default)]
+  pub refresh_options: IntervalOptions,
+
+  /// Client ID of an app created in the Discord developer portal.
+  pub client_id: String,
+
+  /// Client secret of the app. Should be a secret reference (e.g.
+  /// `secret:discord_client_secret`).
+  pub client_secret: String,
+
+  /// TLS options for requests to Discord.
+  pub tls: Option<TlsConfig>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct DiscordOutput {
+  /// Whether the Discord client is running.
+  pub is_running: bool,
+
+  /// Whether the user has authorized access to the Discord client.
+  /// Call the `authorize` function if `false`.
+  pub is_authorized: bool,
+
+  /// Logged in user. Discord's RPC doesn't expose the user's online
+  /// status, so only their profile is available.
+  pub user: Option<DiscordUser>,
+
+  pub is_muted: bool,
+  pub is_deafened: bool,
+
+  /// Voice channel the user is connected to.
+  pub voice_channel: Option<DiscordVoiceChannel>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct DiscordUser {
+  pub id: String,
+  pub username: String,
+
+  /// Server nickname or global display name, falling back to the
+  /// username.
+  pub display_name: String,
+
+  pub avatar_url: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct DiscordVoiceChannel {
+  pub id: String,
+  pub name: String,
+
+  /// Server the channel belongs to. `None` for calls in DMs.
+  pub guild_id: Option<String>,
+  pub guild_name: Option<String>,
+
+  pub members: Vec<DiscordVoiceMember>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct DiscordVoiceMember {
+  pub user: DiscordUser,
+  pub is_muted: bool,
+  pub is_deafened: bool,
+}
+
+pub struct DiscordProvider {
+  config: DiscordProviderConfig,
+  common: CommonProviderState,
+  auth: Arc<DiscordAuth>,
+
+  /// Connection to the Discord client. Reconnected on the next interval
+  /// if Discord is closed.
+  ipc: Option<DiscordIpc>,
+
+  /// User that the connection is authenticated as.
+  user: Option<UserRes>,
+
+  /// Name of the current voice channel's server, cached by server ID.
+  guild: Option<(String, String)>,
+}
+
+impl DiscordProvider {
+  pub fn new(
+    config: DiscordProviderConfig,
+    common: CommonProviderState,
+  ) -> DiscordProvider {
+    let auth = DiscordAuth::new(
+      config.client_id.clone(),
+      config.client_secret.clone(),
+      common.http_client.clone(),
+    );
+
+    DiscordProvider {
+      config,
+      common,
+      auth: Arc::new(auth),
+      ipc: None,
+      user: None,
+      guild: None,
+    }
+  }
+
+  async fn run_interval(&mut self) -> anyhow::Result<DiscordOutput> {
+    if self.ipc.is_none() {
+      match DiscordIpc::connect(&self.config.client_id).await {
+        Ok(ipc) => self.ipc = Some(ipc),
+        Err(_) => return Ok(DiscordOutput::default()),
+      }
+    }
+
+    let output = self.query().await;
+
+    // Drop the connection so that it's re-established on the next
+    // interval (e.g. after Discord restarts).
+    if output.is_err() {
+      self.ipc = None;
+      self.user = None;
+    }
+
+    output
+  }
+
+  async fn query(&mut self) -> anyhow::Result<DiscordOutput> {
+    if !self.authenticate().await? {
+      return Ok(DiscordOutput {
+        is_running: true,
+        ..Default::default()
+      });
+    }
+
+    let ipc = self.ipc()?;
+
+    let settings = ipc
+      .command::<VoiceSettingsRes>("GET_VOICE_SETTINGS", json!({}))
+      .await?;
+
+    let channel = ipc
+      .command::<Option<VoiceChannelRes>>(
+        "GET_SELECTED_VOICE_CHANNEL",
+        json!({}),
+      )
+      .await?;
+
+    let voice_channel = match channel {
+      Some(channel) => Some(self.to_voice_channel(channel).await?),
+      None => None,
+    };
+
+    Ok(DiscordOutput {
+      is_running: true,
+      is_authorized: true,
+      user: self.user.as_ref().map(|user| to_user(user, None)),
+      is_muted: settings.mute,
+      is_deafened: settings.deaf,
+      voice_channel,
+    })
+  }
+
+  /// Authenticates the connection if the user has authorized access.
+  /// Returns whether the connection is authenticated.
+  async fn authenticate(&mut self) -> anyhow::Result<bool> {
+    if self.user.is_some() {
+      return Ok(true);
+    }
+
+    let Some(access_token) = self.auth.access_token().await? else {
+      return Ok(false);
+    };
+
+    let res = self
+      .ipc()?
+      .command::<AuthenticateRes>(
+        "AUTHENTICATE",
+        json!({ "access_token": access_token }),
+      )
+      .await?;
+
+    self.user = Some(res.user);
+    Ok(true)
+  }
+
+  async fn to_voice_channel(
+    &mut self,
+    channel: VoiceChannelRes,
+  ) -> anyhow::Result<DiscordVoiceChannel> {
+    let guild_name = match &channel.guild_id {
+      Some(guild_id) => Some(self.guild_name(guild_id).await?),
+      None => None,
+    };
+
+    Ok(DiscordVoiceChannel {
+      id: channel.id,
+      name: channel.name,
+      guild_id: channel.guild_id,
+      guild_name,
+      members: channel
+        .voice_states
+        .into_iter()
+        .map(|state| DiscordVoiceMember {
+          user: to_user(&state.user, state.nick),
+          is_muted: state.voice_state.self_mute,
+          is_deafened: state.voice_state.self_deaf,
+        })
+        .collect(),
+    })
+  }
+
+  /// Gets the name of a server. Only fetched when the server changes.
+  async fn guild_name(
+    &mut self,
+    guild_id: &str,
+  ) -> anyhow::Result<String> {
+    if let Some((id, name)) = &self.guild {
+      if id == guild_id {
+        return Ok(name.clone());
+      }
+    }
+
+    let guild = self
+      .ipc()?
+      .command::<GuildRes>("GET_GUILD", json!({ "guild_id": guild_id }))
+      .await?;
+
+    self.guild = Some((guild.id, guild.name.clone()));
+    Ok(guild.name)
+  }
+
+  async fn handle_function(
+    &mut self,
+    function: DiscordFunction,
+  ) -> anyhow::Result<ProviderFunctionResponse> {
+    match function {
+      DiscordFunction::Authorize => unreachable!(),
+      DiscordFunction::Logout => {
+        self.auth.logout().await?;
+
+        // Connections can't be deauthenticated, so reconnect instead.
+        self.ipc = None;
+        self.user = None;
+      }
+      DiscordFunction::ToggleMute => {
+        let settings = self.voice_settings().await?;
+        self
+          .set_voice_settings(json!({ "mute": !settings.mute }))
+          .await?;
+      }
+      DiscordFunction::ToggleDeafen => {
+        let settings = self.voice_settings().await?;
+        self
+          .set_voice_settings(json!({ "deaf": !settings.deaf }))
+          .await?;
+      }
+      DiscordFunction::SetMute(args) => {
+        self
+          .set_voice_settings(json!({ "mute": args.is_muted }))
+          .await?;
+      }
+      DiscordFunction::SetDeafen(args) => {
+        self
+          .set_voice_settings(json!({ "deaf": args.is_deafened }))
+          .await?;
+      }
+    };
+
+    Ok(ProviderFunctionResponse::Null)
+  }
+
+  async fn voice_settings(&mut self) -> anyhow::Result<VoiceSettingsRes> {
+    self
+      .authenticated_ipc()?
+      .command("GET_VOICE_SETTINGS", json!({}))
+      .await
+  }
+
+  async fn set_voice_settings(
+    &mut self,
+    settings: serde_json::Value,
+  ) -> anyhow::Result<()> {
+    self
+      .authenticated_ipc()?
+      .command::<serde_json::Value>("SET_VOICE_SETTINGS", settings)
+      .await?;
+
+    Ok(())
+  }
+
+  fn ipc(&mut self) -> anyhow::Result<&mut DiscordIpc> {
+    self.ipc.as_mut().context("Discord isn't running.")
+  }
+
+  fn authenticated_ipc(&mut self) -> anyhow::Result<&mut DiscordIpc> {
+    if self.user.is_none() {
+      bail!("Not authorized with Discord. Call `authorize` first.");
+    }
+
+    self.ipc()
+  }
+}
+
+fn to_user(user: &UserRes, nick: Option<String>) -> DiscordUser {
+  DiscordUser {
+    id: user.id.clone(),
+    username: user.username.clone(),
+    display_name: nick
+      .or_else(|| user.global_name.clone())
+      .unwrap_or_else(|| user.username.clone()),
+    avatar_url: user.avatar.as_ref().map(|avatar| {
+      format!(
+        "https://cdn.discordapp.com/avatars/{}/{}.png",
+        user.id, avatar
+      )
+    }),
+  }
+}
+
+#[async_trait]
+impl Provider for DiscordProvider {
+  fn runtime_type(&self) -> RuntimeType {
+    RuntimeType::Async
+  }
+
+  async fn start_async(&mut self) {
+    let mut interval = AsyncInterval::with_options(
+      self.config.refresh_interval,
+      &self.config.refresh_options,
+    )
+    .refresh_on_wake();
+
+    loop {
+      tokio::select! {
+        _ = interval.tick() => {
+          let output = self.run_interval().await;
+          self.common.emitter.emit_output_cached(output);
+        }
+        Some(message) = self.common.input.async_rx.recv() => {
+          match message {
+            ProviderInputMsg::Stop => break,
+            ProviderInputMsg::Function(
+              ProviderFunction::Discord(DiscordFunction::Authorize),
+              sender,
+            ) => {
+              // Authorizing waits for the user to approve access in
+              // Discord, so it's done without blocking the provider.
+              let auth = self.auth.clone();
+
+              tokio::spawn(async move {
+                let res = auth
+                  .authorize()
+                  .await
+                  .map(|_| ProviderFunctionResponse::Null)
+                  .map_err(|err| err.to_string());
+
+                let _ = sender.send(res);
+              });
+            }
+            ProviderInputMsg::Function(
+              ProviderFunction::Discord(discord_function),
+              sender,
+            ) => {
+              let res = self
+                .handle_function(discord_function)
+                .await
+                .map_err(|err| err.to_string());
+
+              sender.send(res).unwrap();
+            }
+            _ => {}
+          }
+        }
+      }
+    }
+  }
+}
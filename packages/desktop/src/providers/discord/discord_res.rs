@@ -0,0 +1,54 @@
+use serde::Deserialize;
+
+#[derive(Deserialize, Debug)]
+pub struct AuthorizeRes {
+  pub code: String,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct AuthenticateRes {
+  pub user: UserRes,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct UserRes {
+  pub id: String,
+  pub username: String,
+  pub global_name: Option<String>,
+  pub avatar: Option<String>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct VoiceSettingsRes {
+  pub mute: bool,
+  pub deaf: bool,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct VoiceChannelRes {
+  pub id: String,
+  pub name: String,
+  pub guild_id: Option<String>,
+  #[serde(default)]
+  pub voice_states: Vec<VoiceStateRes>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct VoiceStateRes {
+  /// Server nickname of the member.
+  pub nick: Option<String>,
+  pub user: UserRes,
+  pub voice_state: VoiceStateFlagsRes,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct VoiceStateFlagsRes {
+  pub self_mute: bool,
+  pub self_deaf: bool,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct GuildRes {
+  pub id: String,
+  pub name: String,
+}
@@ -0,0 +1,6 @@
+mod discord_auth;
+mod discord_ipc;
+mod discord_provider;
+mod discord_res;
+
+pub use discord_provider::*;
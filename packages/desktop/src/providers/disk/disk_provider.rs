@@ -1,26 +1,73 @@
+use anyhow::Context;
+use glob::Pattern;
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use sysinfo::Disks;
 
+use super::smartctl_res::{SmartctlDeviceRes, SmartctlScanRes};
 use crate::{
-  common::{to_iec_bytes, to_si_bytes, SyncInterval},
+  common::{
+    no_window_command, to_iec_bytes, to_si_bytes, IntervalOptions,
+    SyncInterval,
+  },
   providers::{
     CommonProviderState, Provider, ProviderInputMsg, RuntimeType,
   },
 };
 
-#[derive(Deserialize, Debug)]
+#[derive(Deserialize, Debug, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct DiskProviderConfig {
+  #[serde(deserialize_with = "crate::common::deserialize_duration_ms")]
+  #[schemars(schema_with = "crate::common::duration_schema")]
   pub refresh_interval: u64,
+
+  /// Alignment and jitter of refreshes.
+  #[serde(default)]
+  pub refresh_options: IntervalOptions,
+
+  /// Glob patterns of mount points to include. All mount points are
+  /// included if empty.
+  #[serde(default, deserialize_with = "deserialize_patterns")]
+  pub include_mount_points: Vec<String>,
+
+  /// Glob patterns of mount points to exclude.
+  #[serde(default, deserialize_with = "deserialize_patterns")]
+  pub exclude_mount_points: Vec<String>,
+
+  /// Glob patterns of file system types to include (e.g. `NTFS`,
+  /// `ext4`). All file systems are included if empty.
+  #[serde(default, deserialize_with = "deserialize_patterns")]
+  pub include_file_systems: Vec<String>,
+
+  /// Glob patterns of file system types to exclude (e.g. `overlay`,
+  /// `9p`).
+  #[serde(default, deserialize_with = "deserialize_patterns")]
+  pub exclude_file_systems: Vec<String>,
+
+  /// Whether to exclude removable disks.
+  #[serde(default)]
+  pub exclude_removable: bool,
+
+  /// Whether to include S.M.A.R.T. health data for physical disks.
+  ///
+  /// Requires `smartctl` (smartmontools) to be installed, and usually
+  /// needs elevated permissions.
+  #[serde(default)]
+  pub include_smart: bool,
 }
 
-#[derive(Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct DiskOutput {
   pub disks: Vec<Disk>,
+
+  /// S.M.A.R.T. health data per physical disk. `None` if
+  /// `include_smart` is disabled.
+  pub physical_disks: Option<Vec<PhysicalDiskHealth>>,
 }
 
-#[derive(Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct Disk {
   pub name: Option<String>,
@@ -32,13 +79,115 @@ pub struct Disk {
   pub drive_type: String,
 }
 
+#[derive(Debug, Clone, PartialEq, Serialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct PhysicalDiskHealth {
+  pub device: String,
+  pub model: Option<String>,
+  pub serial_number: Option<String>,
+  pub is_healthy: Option<bool>,
+  pub celsius_temp: Option<f32>,
+  pub power_on_hours: Option<u64>,
+}
+
+/// Deserializes a list of glob patterns, rejecting the config if any
+/// pattern is invalid.
+///
+/// Otherwise, an include list where every pattern is invalid would be
+/// empty and match every disk.
+fn deserialize_patterns<'de, D>(
+  deserializer: D,
+) -> Result<Vec<String>, D::Error>
+where
+  D: serde::Deserializer<'de>,
+{
+  let patterns = Vec::<String>::deserialize(deserializer)?;
+
+  for pattern in &patterns {
+    Pattern::new(pattern).map_err(|err| {
+      serde::de::Error::custom(format!(
+        "Invalid glob pattern '{}': {}",
+        pattern, err
+      ))
+    })?;
+  }
+
+  Ok(patterns)
+}
+
 pub struct DiskProvider {
   config: DiskProviderConfig,
   common: CommonProviderState,
   disks: Disks,
+  filter: DiskFilter,
+}
+
+/// Compiled glob patterns for filtering disks.
+struct DiskFilter {
+  include_mount_points: Vec<Pattern>,
+  exclude_mount_points: Vec<Pattern>,
+  include_file_systems: Vec<Pattern>,
+  exclude_file_systems: Vec<Pattern>,
+  exclude_removable: bool,
+}
+
+impl DiskFilter {
+  fn new(config: &DiskProviderConfig) -> Self {
+    Self {
+      include_mount_points: Self::compile(&config.include_mount_points),
+      exclude_mount_points: Self::compile(&config.exclude_mount_points),
+      include_file_systems: Self::compile(&config.include_file_systems),
+      exclude_file_systems: Self::compile(&config.exclude_file_systems),
+      exclude_removable: config.exclude_removable,
+    }
+  }
+
+  /// Compiles the given glob patterns, which are already validated by
+  /// `deserialize_patterns`.
+  fn compile(patterns: &[String]) -> Vec<Pattern> {
+    patterns
+      .iter()
+      .filter_map(|pattern| Pattern::new(pattern).ok())
+      .collect()
+  }
+
+  /// Whether a disk with the given properties passes the filter.
+  fn matches(
+    &self,
+    mount_point: &str,
+    file_system: &str,
+    is_removable: bool,
+  ) -> bool {
+    if self.exclude_removable && is_removable {
+      return false;
+    }
+
+    Self::matches_patterns(
+      &self.include_mount_points,
+      &self.exclude_mount_points,
+      mount_point,
+    ) && Self::matches_patterns(
+      &self.include_file_systems,
+      &self.exclude_file_systems,
+      file_system,
+    )
+  }
+
+  /// Whether the value is included (or the include list is empty) and
+  /// not excluded.
+  fn matches_patterns(
+    include: &[Pattern],
+    exclude: &[Pattern],
+    value: &str,
+  ) -> bool {
+    let is_included = include.is_empty()
+      || include.iter().any(|pattern| pattern.matches(value));
+
+    is_included && !exclude.iter().any(|pattern| pattern.matches(value))
+  }
 }
 
-#[derive(Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct DiskSizeMeasure {
   pub bytes: u64,
@@ -54,6 +203,7 @@ impl DiskProvider {
     common: CommonProviderState,
   ) -> DiskProvider {
     DiskProvider {
+      filter: DiskFilter::new(&config),
       config,
       common,
       disks: Disks::new_with_refreshed_list(),
@@ -66,6 +216,13 @@ impl DiskProvider {
     let disks = self
       .disks
       .iter()
+      .filter(|disk| {
+        self.filter.matches(
+          &disk.mount_point().to_string_lossy(),
+          &disk.file_system().to_string_lossy(),
+          disk.is_removable(),
+        )
+      })
       .map(|disk| -> anyhow::Result<Disk> {
         let name = disk.name().to_string_lossy().to_string();
 
@@ -83,7 +240,77 @@ impl DiskProvider {
       })
       .collect::<anyhow::Result<Vec<Disk>>>()?;
 
-    Ok(DiskOutput { disks })
+    let physical_disks = match self.config.include_smart {
+      true => Some(Self::query_smart_health()?),
+      false => None,
+    };
+
+    Ok(DiskOutput {
+      disks,
+      physical_disks,
+    })
+  }
+
+  /// Queries S.M.A.R.T. health data for all physical disks via
+  /// `smartctl`.
+  fn query_smart_health() -> anyhow::Result<Vec<PhysicalDiskHealth>> {
+    let scan_res = Self::run_smartctl::<SmartctlScanRes>(&["--scan"])
+      .context("Failed to scan for physical disks with `smartctl`.")?;
+
+    let physical_disks = scan_res
+      .devices
+      .into_iter()
+      .map(|device| {
+        // Individual devices can fail (e.g. USB bridges without SMART
+        // passthrough), in which case only the device name is returned.
+        let res = Self::run_smartctl::<SmartctlDeviceRes>(&[
+          "--health",
+          "--info",
+          "--attributes",
+          &device.name,
+        ])
+        .ok();
+
+        PhysicalDiskHealth {
+          model: res.as_ref().and_then(|res| res.model_name.clone()),
+          serial_number: res
+            .as_ref()
+            .and_then(|res| res.serial_number.clone()),
+          is_healthy: res
+            .as_ref()
+            .and_then(|res| res.smart_status.as_ref())
+            .map(|status| status.passed),
+          celsius_temp: res
+            .as_ref()
+            .and_then(|res| res.temperature.as_ref())
+            .and_then(|temp| temp.current),
+          power_on_hours: res
+            .as_ref()
+            .and_then(|res| res.power_on_time.as_ref())
+            .and_then(|time| time.hours),
+          device: device.name,
+        }
+      })
+      .collect();
+
+    Ok(physical_disks)
+  }
+
+  /// Runs `smartctl` with JSON output and the given arguments.
+  ///
+  /// Note that `smartctl` uses a non-zero exit code as a bitmask for disk
+  /// warnings, so the exit code is not checked.
+  fn run_smartctl<T: serde::de::DeserializeOwned>(
+    args: &[&str],
+  ) -> anyhow::Result<T> {
+    let output = no_window_command("smartctl")
+      .arg("--json")
+      .args(args)
+      .output()
+      .context("Unable to run `smartctl`. Is smartmontools installed?")?;
+
+    serde_json::from_slice(&output.stdout)
+      .context("Failed to parse `smartctl` output.")
   }
 
   fn to_disk_size_measure(bytes: u64) -> anyhow::Result<DiskSizeMeasure> {
@@ -106,7 +333,10 @@ impl Provider for DiskProvider {
   }
 
   fn start_sync(&mut self) {
-    let mut interval = SyncInterval::new(self.config.refresh_interval);
+    let mut interval = SyncInterval::with_options(
+      self.config.refresh_interval,
+      &self.config.refresh_options,
+    );
 
     loop {
       crossbeam::select! {
@@ -1,3 +1,4 @@
 mod disk_provider;
+mod smartctl_res;
 
 pub use disk_provider::*;
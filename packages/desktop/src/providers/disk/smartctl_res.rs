@@ -0,0 +1,38 @@
+use serde::Deserialize;
+
+/// Output of `smartctl --scan --json`.
+#[derive(Deserialize, Debug)]
+pub struct SmartctlScanRes {
+  #[serde(default)]
+  pub devices: Vec<SmartctlScanDevice>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct SmartctlScanDevice {
+  pub name: String,
+}
+
+/// Output of `smartctl --json --health --info --attributes <device>`.
+#[derive(Deserialize, Debug)]
+pub struct SmartctlDeviceRes {
+  pub model_name: Option<String>,
+  pub serial_number: Option<String>,
+  pub smart_status: Option<SmartctlStatus>,
+  pub temperature: Option<SmartctlTemperature>,
+  pub power_on_time: Option<SmartctlPowerOnTime>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct SmartctlStatus {
+  pub passed: bool,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct SmartctlTemperature {
+  pub current: Option<f32>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct SmartctlPowerOnTime {
+  pub hours: Option<u64>,
+}
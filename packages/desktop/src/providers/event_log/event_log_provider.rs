@@ -0,0 +1,209 @@
+use std::collections::VecDeque;
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[cfg(windows)]
+use super::event_log_windows::EventReader;
+#[cfg(target_os = "linux")]
+use super::journal_linux::EventReader;
+use crate::{
+  common::{IntervalOptions, SyncInterval},
+  providers::{
+    CommonProviderState, Provider, ProviderInputMsg, RuntimeType,
+  },
+};
+
+/// Max number of entries to keep in memory for counting.
+const MAX_STORED_ENTRIES: usize = 1000;
+
+#[derive(Deserialize, Debug, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct EventLogProviderConfig {
+  /// How often to check for new entries.
+  #[serde(deserialize_with = "crate::common::deserialize_duration_ms")]
+  #[schemars(schema_with = "crate::common::duration_schema")]
+  pub refresh_interval: u64,
+
+  /// Alignment and jitter of refreshes.
+  #[serde(default)]
+  pub refresh_options: IntervalOptions,
+
+  /// Windows Event Log channels to watch (e.g. `System`). Ignored on
+  /// Linux, where the systemd journal is watched.
+  #[serde(default)]
+  pub channels: Vec<String>,
+
+  /// Least severe level of entries to include. All levels are included
+  /// if `None`.
+  pub min_level: Option<EventLevel>,
+
+  /// Additional platform-specific filter. On Windows, an XPath query
+  /// (e.g. `*[System[Provider[@Name='disk']]]`), which replaces the
+  /// default query for `minLevel` and `maxAge`. On Linux, `journalctl`
+  /// matches (e.g. `_SYSTEMD_UNIT=sshd.service`).
+  pub filter: Option<String>,
+
+  /// How far back to include entries, in milliseconds.
+  pub max_age: u64,
+
+  /// Max number of entries to output.
+  pub max_entries: usize,
+}
+
+#[derive(
+  Clone,
+  Copy,
+  Debug,
+  Deserialize,
+  Eq,
+  Ord,
+  PartialEq,
+  PartialOrd,
+  Serialize,
+  JsonSchema,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum EventLevel {
+  Critical,
+  Error,
+  Warning,
+  Info,
+  Debug,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct EventLogOutput {
+  /// Matching entries within `maxAge`, newest first.
+  pub entries: Vec<EventLogEntry>,
+
+  /// Number of critical and error entries within `maxAge`.
+  pub error_count: usize,
+
+  /// Number of warning entries within `maxAge`.
+  pub warning_count: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct EventLogEntry {
+  /// Event provider on Windows (e.g. `Service Control Manager`), or
+  /// syslog identifier on Linux (e.g. `sshd`).
+  pub source: String,
+
+  /// Channel on Windows (e.g. `System`), or systemd unit on Linux.
+  pub channel: Option<String>,
+
+  /// Event ID. Only available on Windows.
+  pub event_id: Option<u32>,
+
+  pub level: EventLevel,
+  pub message: String,
+
+  /// Time of the entry as a Unix timestamp in milliseconds.
+  pub timestamp: u64,
+}
+
+pub struct EventLogProvider {
+  config: EventLogProviderConfig,
+  common: CommonProviderState,
+
+  /// Entries within `max_age`, oldest first.
+  entries: VecDeque<EventLogEntry>,
+}
+
+impl EventLogProvider {
+  pub fn new(
+    config: EventLogProviderConfig,
+    common: CommonProviderState,
+  ) -> EventLogProvider {
+    EventLogProvider {
+      config,
+      common,
+      entries: VecDeque::new(),
+    }
+  }
+
+  fn run_interval(
+    &mut self,
+    reader: &mut EventReader,
+  ) -> anyhow::Result<EventLogOutput> {
+    let mut new_entries = reader.read_new()?;
+    new_entries.retain(|entry| {
+      self
+        .config
+        .min_level
+        .map_or(true, |min_level| entry.level <= min_level)
+    });
+
+    new_entries.sort_by_key(|entry| entry.timestamp);
+    self.entries.extend(new_entries);
+
+    let cutoff = (chrono::Utc::now().timestamp_millis() as u64)
+      .saturating_sub(self.config.max_age);
+
+    while self.entries.front().is_some_and(|entry| {
+      entry.timestamp < cutoff || self.entries.len() > MAX_STORED_ENTRIES
+    }) {
+      self.entries.pop_front();
+    }
+
+    let count = |predicate: fn(EventLevel) -> bool| {
+      self
+        .entries
+        .iter()
+        .filter(|entry| predicate(entry.level))
+        .count()
+    };
+
+    Ok(EventLogOutput {
+      entries: self
+        .entries
+        .iter()
+        .rev()
+        .take(self.config.max_entries)
+        .cloned()
+        .collect(),
+      error_count: count(|level| level <= EventLevel::Error),
+      warning_count: count(|level| level == EventLevel::Warning),
+    })
+  }
+
+  fn start(&mut self) -> anyhow::Result<()> {
+    let mut reader = EventReader::new(&self.config)?;
+    let mut interval = SyncInterval::with_options(
+      self.config.refresh_interval,
+      &self.config.refresh_options,
+    );
+
+    loop {
+      crossbeam::select! {
+        recv(interval.tick()) -> _ => {
+          let output = self.run_interval(&mut reader);
+          self.common.emitter.emit_output_cached(output);
+        }
+        recv(self.common.input.sync_rx) -> input => {
+          if let Ok(ProviderInputMsg::Stop) = input {
+            break;
+          }
+        }
+      }
+    }
+
+    Ok(())
+  }
+}
+
+impl Provider for EventLogProvider {
+  fn runtime_type(&self) -> RuntimeType {
+    RuntimeType::Sync
+  }
+
+  fn start_sync(&mut self) {
+    if let Err(err) = self.start() {
+      tracing::error!("Error starting event log provider: {}", err);
+      self.common.emitter.emit_output::<EventLogOutput>(Err(err));
+    }
+  }
+}
@@ -0,0 +1,316 @@
+use std::{collections::HashMap, sync::LazyLock};
+
+use anyhow::Context;
+use chrono::DateTime;
+use regex::Regex;
+use windows::{
+  core::{HSTRING, PCWSTR},
+  Win32::{
+    Foundation::{CloseHandle, HANDLE},
+    System::{
+      EventLog::{
+        EvtClose, EvtFormatMessage, EvtFormatMessageEvent, EvtNext,
+        EvtOpenPublisherMetadata, EvtRender, EvtRenderEventXml,
+        EvtSubscribe, EvtSubscribeStartAtOldestRecord, EVT_HANDLE,
+      },
+      Threading::CreateEventW,
+    },
+  },
+};
+
+use super::{EventLevel, EventLogEntry, EventLogProviderConfig};
+
+/// Max number of events to fetch per call to `EvtNext`.
+const BATCH_SIZE: usize = 16;
+
+static PROVIDER_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+  Regex::new(r#"<Provider Name=['"]([^'"]*)['"]"#).unwrap()
+});
+
+static EVENT_ID_REGEX: LazyLock<Regex> =
+  LazyLock::new(|| Regex::new(r"<EventID[^>]*>(\d+)</EventID>").unwrap());
+
+static LEVEL_REGEX: LazyLock<Regex> =
+  LazyLock::new(|| Regex::new(r"<Level>(\d+)</Level>").unwrap());
+
+static TIME_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+  Regex::new(r#"<TimeCreated SystemTime=['"]([^'"]*)['"]"#).unwrap()
+});
+
+static CHANNEL_REGEX: LazyLock<Regex> =
+  LazyLock::new(|| Regex::new(r"<Channel>([^<]*)</Channel>").unwrap());
+
+/// Reads new events from a Windows Event Log subscription.
+pub struct EventReader {
+  subscription: EVT_HANDLE,
+
+  /// Event that's signaled by the subscription when new events are
+  /// available. Events are pulled on each read regardless.
+  signal: HANDLE,
+
+  /// Cached metadata handles for formatting messages, keyed by
+  /// provider name.
+  publishers: HashMap<String, Option<EVT_HANDLE>>,
+}
+
+impl EventReader {
+  pub fn new(config: &EventLogProviderConfig) -> anyhow::Result<Self> {
+    let signal = unsafe { CreateEventW(None, true, true, None) }
+      .context("Failed to create subscription signal.")?;
+
+    let subscription = unsafe {
+      EvtSubscribe(
+        EVT_HANDLE::default(),
+        signal,
+        PCWSTR::null(),
+        &HSTRING::from(query_xml(config)),
+        EVT_HANDLE::default(),
+        None,
+        None,
+        EvtSubscribeStartAtOldestRecord.0 as u32,
+      )
+    };
+
+    let subscription = match subscription {
+      Ok(subscription) => subscription,
+      Err(err) => {
+        let _ = unsafe { CloseHandle(signal) };
+        return Err(err).context("Failed to subscribe to event log.");
+      }
+    };
+
+    Ok(Self {
+      subscription,
+      signal,
+      publishers: HashMap::new(),
+    })
+  }
+
+  /// Reads events added since the last read.
+  pub fn read_new(&mut self) -> anyhow::Result<Vec<EventLogEntry>> {
+    let mut entries = Vec::new();
+
+    loop {
+      let mut handles = [0isize; BATCH_SIZE];
+      let mut returned = 0;
+
+      // Errors with `ERROR_NO_MORE_ITEMS` once all events are read.
+      let res = unsafe {
+        EvtNext(self.subscription, &mut handles, 0, 0, &mut returned)
+      };
+
+      if res.is_err() || returned == 0 {
+        break;
+      }
+
+      for handle in &handles[..returned as usize] {
+        let event = EVT_HANDLE(*handle);
+
+        if let Some(entry) = self.read_event(event) {
+          entries.push(entry);
+        }
+
+        let _ = unsafe { EvtClose(event) };
+      }
+    }
+
+    Ok(entries)
+  }
+
+  fn read_event(&mut self, event: EVT_HANDLE) -> Option<EventLogEntry> {
+    let xml = render_xml(event).ok()?;
+    let capture = |regex: &Regex| {
+      regex
+        .captures(&xml)
+        .map(|captures| unescape_xml(&captures[1]))
+    };
+
+    let source = capture(&PROVIDER_REGEX).unwrap_or_default();
+    let message = self.format_message(&source, event).unwrap_or_default();
+
+    Some(EventLogEntry {
+      message,
+      source,
+      channel: capture(&CHANNEL_REGEX),
+      event_id: capture(&EVENT_ID_REGEX).and_then(|id| id.parse().ok()),
+      level: capture(&LEVEL_REGEX)
+        .and_then(|level| level.parse().ok())
+        .map_or(EventLevel::Info, to_level),
+      timestamp: capture(&TIME_REGEX)
+        .and_then(|time| DateTime::parse_from_rfc3339(&time).ok())
+        .map_or(0, |time| time.timestamp_millis() as u64),
+    })
+  }
+
+  /// Formats an event's message using its provider's message table.
+  fn format_message(
+    &mut self,
+    source: &str,
+    event: EVT_HANDLE,
+  ) -> Option<String> {
+    // Metadata isn't available for some providers (e.g. ones that have
+    // been uninstalled), which is also cached.
+    let publisher = *self
+      .publishers
+      .entry(source.to_string())
+      .or_insert_with(|| {
+        unsafe {
+          EvtOpenPublisherMetadata(
+            EVT_HANDLE::default(),
+            &HSTRING::from(source),
+            PCWSTR::null(),
+            0,
+            0,
+          )
+        }
+        .ok()
+      });
+
+    let publisher = publisher?;
+    let mut used = 0;
+
+    // First call gets the required buffer size.
+    let _ = unsafe {
+      EvtFormatMessage(
+        publisher,
+        event,
+        0,
+        None,
+        EvtFormatMessageEvent.0 as u32,
+        None,
+        &mut used,
+      )
+    };
+
+    let mut buffer = vec![0u16; used as usize];
+
+    unsafe {
+      EvtFormatMessage(
+        publisher,
+        event,
+        0,
+        None,
+        EvtFormatMessageEvent.0 as u32,
+        Some(&mut buffer),
+        &mut used,
+      )
+    }
+    .ok()?;
+
+    Some(from_wide(&buffer).trim().to_string())
+  }
+}
+
+impl Drop for EventReader {
+  fn drop(&mut self) {
+    for publisher in self.publishers.values().flatten() {
+      let _ = unsafe { EvtClose(*publisher) };
+    }
+
+    let _ = unsafe { EvtClose(self.subscription) };
+    let _ = unsafe { CloseHandle(self.signal) };
+  }
+}
+
+/// Builds a structured XML query that selects events from each channel.
+fn query_xml(config: &EventLogProviderConfig) -> String {
+  let xpath = config.filter.clone().unwrap_or_else(|| {
+    let levels = match config.min_level {
+      Some(EventLevel::Critical) => "(Level=1) and ",
+      Some(EventLevel::Error) => "(Level=1 or Level=2) and ",
+      Some(EventLevel::Warning) => "(Level=1 or Level=2 or Level=3) and ",
+      Some(EventLevel::Info) => "(Level!=5) and ",
+      Some(EventLevel::Debug) | None => "",
+    };
+
+    format!(
+      "*[System[{}TimeCreated[timediff(@SystemTime) <= {}]]]",
+      levels, config.max_age
+    )
+  });
+
+  let selects = config
+    .channels
+    .iter()
+    .map(|channel| {
+      format!(
+        "<Select Path=\"{}\">{}</Select>",
+        escape_xml(channel),
+        escape_xml(&xpath)
+      )
+    })
+    .collect::<String>();
+
+  format!("<QueryList><Query Id=\"0\">{}</Query></QueryList>", selects)
+}
+
+/// Renders an event as XML.
+fn render_xml(event: EVT_HANDLE) -> anyhow::Result<String> {
+  let mut used = 0;
+  let mut property_count = 0;
+
+  // First call gets the required buffer size in bytes.
+  let _ = unsafe {
+    EvtRender(
+      EVT_HANDLE::default(),
+      event,
+      EvtRenderEventXml.0 as u32,
+      0,
+      None,
+      &mut used,
+      &mut property_count,
+    )
+  };
+
+  let mut buffer = vec![0u16; used as usize / 2 + 1];
+
+  unsafe {
+    EvtRender(
+      EVT_HANDLE::default(),
+      event,
+      EvtRenderEventXml.0 as u32,
+      (buffer.len() * 2) as u32,
+      Some(buffer.as_mut_ptr() as _),
+      &mut used,
+      &mut property_count,
+    )
+  }
+  .context("Failed to render event.")?;
+
+  Ok(from_wide(&buffer))
+}
+
+/// Converts an event level (0-5) to a level. Events with level 0 are
+/// informational (e.g. audit events).
+fn to_level(level: u8) -> EventLevel {
+  match level {
+    1 => EventLevel::Critical,
+    2 => EventLevel::Error,
+    3 => EventLevel::Warning,
+    5 => EventLevel::Debug,
+    _ => EventLevel::Info,
+  }
+}
+
+fn escape_xml(value: &str) -> String {
+  value
+    .replace('&', "&amp;")
+    .replace('<', "&lt;")
+    .replace('>', "&gt;")
+    .replace('"', "&quot;")
+}
+
+fn unescape_xml(value: &str) -> String {
+  value
+    .replace("&lt;", "<")
+    .replace("&gt;", ">")
+    .replace("&quot;", "\"")
+    .replace("&apos;", "'")
+    .replace("&amp;", "&")
+}
+
+/// Converts a null-terminated UTF-16 buffer to a string.
+fn from_wide(buffer: &[u16]) -> String {
+  let len = buffer.iter().position(|&c| c == 0).unwrap_or(buffer.len());
+  String::from_utf16_lossy(&buffer[..len])
+}
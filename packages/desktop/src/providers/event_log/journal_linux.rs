@@ -0,0 +1,130 @@
+use std::process::Command;
+
+use anyhow::{bail, Context};
+use serde_json::Value;
+
+use super::{EventLevel, EventLogEntry, EventLogProviderConfig};
+
+/// Reads new entries from the systemd journal via `journalctl`.
+pub struct EventReader {
+  /// Arguments for filtering entries (e.g. by priority).
+  filter_args: Vec<String>,
+
+  /// How far back to read entries on the first read, in seconds.
+  max_age_secs: u64,
+
+  /// Journal cursor of the last read entry.
+  cursor: Option<String>,
+}
+
+impl EventReader {
+  pub fn new(config: &EventLogProviderConfig) -> anyhow::Result<Self> {
+    let mut filter_args = Vec::new();
+
+    if let Some(min_level) = config.min_level {
+      filter_args.push(format!("--priority={}", max_priority(min_level)));
+    }
+
+    if let Some(filter) = &config.filter {
+      filter_args.extend(filter.split_whitespace().map(String::from));
+    }
+
+    Ok(Self {
+      filter_args,
+      max_age_secs: config.max_age.div_ceil(1000),
+      cursor: None,
+    })
+  }
+
+  /// Reads entries added since the last read.
+  pub fn read_new(&mut self) -> anyhow::Result<Vec<EventLogEntry>> {
+    let mut command = Command::new("journalctl");
+    command
+      .args(["--output=json", "--no-pager", "--quiet"])
+      .args(&self.filter_args);
+
+    match &self.cursor {
+      Some(cursor) => command.arg(format!("--after-cursor={}", cursor)),
+      None => command.arg(format!("--since=-{}s", self.max_age_secs)),
+    };
+
+    let output = command
+      .output()
+      .context("Unable to run `journalctl`. Is systemd installed?")?;
+
+    if !output.status.success() {
+      bail!(
+        "`journalctl` failed: {}",
+        String::from_utf8_lossy(&output.stderr).trim()
+      );
+    }
+
+    let mut entries = Vec::new();
+
+    for line in String::from_utf8_lossy(&output.stdout).lines() {
+      let Ok(fields) = serde_json::from_str::<Value>(line) else {
+        continue;
+      };
+
+      if let Some(cursor) = fields["__CURSOR"].as_str() {
+        self.cursor = Some(cursor.to_string());
+      }
+
+      entries.push(EventLogEntry {
+        source: field_str(&fields, "SYSLOG_IDENTIFIER")
+          .or_else(|| field_str(&fields, "_COMM"))
+          .unwrap_or_default(),
+        channel: field_str(&fields, "_SYSTEMD_UNIT"),
+        event_id: None,
+        level: field_str(&fields, "PRIORITY")
+          .and_then(|priority| priority.parse().ok())
+          .map_or(EventLevel::Info, to_level),
+        message: field_str(&fields, "MESSAGE").unwrap_or_default(),
+        timestamp: field_str(&fields, "__REALTIME_TIMESTAMP")
+          .and_then(|micros| micros.parse::<u64>().ok())
+          .map_or(0, |micros| micros / 1000),
+      });
+    }
+
+    Ok(entries)
+  }
+}
+
+/// Gets a journal field as a string. Fields with binary data are output
+/// as byte arrays by `journalctl`.
+fn field_str(fields: &Value, name: &str) -> Option<String> {
+  match &fields[name] {
+    Value::String(value) => Some(value.clone()),
+    Value::Array(bytes) => {
+      let bytes = bytes
+        .iter()
+        .filter_map(|byte| byte.as_u64().map(|byte| byte as u8))
+        .collect::<Vec<_>>();
+
+      Some(String::from_utf8_lossy(&bytes).into_owned())
+    }
+    _ => None,
+  }
+}
+
+/// Converts a syslog priority (0-7) to a level.
+fn to_level(priority: u8) -> EventLevel {
+  match priority {
+    0..=2 => EventLevel::Critical,
+    3 => EventLevel::Error,
+    4 => EventLevel::Warning,
+    5 | 6 => EventLevel::Info,
+    _ => EventLevel::Debug,
+  }
+}
+
+/// Gets the least severe syslog priority for the given level.
+fn max_priority(level: EventLevel) -> u8 {
+  match level {
+    EventLevel::Critical => 2,
+    EventLevel::Error => 3,
+    EventLevel::Warning => 4,
+    EventLevel::Info => 6,
+    EventLevel::Debug => 7,
+  }
+}
@@ -0,0 +1,7 @@
+mod event_log_provider;
+#[cfg(windows)]
+mod event_log_windows;
+#[cfg(target_os = "linux")]
+mod journal_linux;
+
+pub use event_log_provider::*;
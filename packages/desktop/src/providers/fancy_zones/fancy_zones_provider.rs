@@ -0,0 +1,288 @@
+use std::{
+  fs,
+  path::{Path, PathBuf},
+};
+
+use anyhow::Context;
+use schemars::JsonSchema;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use windows::Win32::{
+  Foundation::HWND, UI::WindowsAndMessaging::IsWindowArranged,
+};
+
+use super::fancy_zones_res::{
+  AppZoneHistoryRes, AppliedLayoutsRes, CustomLayoutsRes, DeviceRes,
+};
+use crate::{
+  common::{
+    windows::{foreground_window, process_exe_path},
+    IntervalOptions, SyncInterval,
+  },
+  providers::{
+    CommonProviderState, Provider, ProviderInputMsg, RuntimeType,
+  },
+};
+
+/// Directory of the FancyZones state files, relative to
+/// `%LOCALAPPDATA%`.
+const FANCY_ZONES_DIR: &str = "Microsoft\\PowerToys\\FancyZones";
+
+#[derive(Deserialize, Debug, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct FancyZonesProviderConfig {
+  #[serde(deserialize_with = "crate::common::deserialize_duration_ms")]
+  #[schemars(schema_with = "crate::common::duration_schema")]
+  pub refresh_interval: u64,
+
+  /// Alignment and jitter of refreshes.
+  #[serde(default)]
+  pub refresh_options: IntervalOptions,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct FancyZonesOutput {
+  /// Whether the FancyZones state of PowerToys was found. Layouts and
+  /// zones are empty otherwise.
+  pub is_available: bool,
+
+  /// Layouts applied to each monitor and virtual desktop.
+  pub layouts: Vec<FancyZonesLayout>,
+
+  /// The window that currently has focus, excluding Zebar's own windows
+  /// and the desktop.
+  pub focused_window: Option<ZonedWindow>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct FancyZonesLayout {
+  /// GUID of the layout.
+  pub id: String,
+
+  /// Name of the layout. For built-in layouts, this is its type (e.g.
+  /// `priority-grid`).
+  pub name: String,
+
+  /// One of `blank`, `focus`, `columns`, `rows`, `grid`,
+  /// `priority-grid`, or `custom`.
+  pub layout_type: String,
+  pub zone_count: u32,
+
+  /// Hardware ID of the monitor the layout is applied to (e.g.
+  /// `DELA0F2`).
+  pub monitor: String,
+
+  /// 1-based number of the monitor, as shown in the FancyZones editor.
+  pub monitor_number: Option<u32>,
+
+  /// GUID of the virtual desktop the layout is applied to.
+  pub virtual_desktop_id: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ZonedWindow {
+  /// Executable name of the window's process (e.g. `firefox.exe`).
+  pub process_name: String,
+
+  /// Whether the window is snapped via Windows' own snapping (e.g. snap
+  /// layouts on Windows 11).
+  pub is_snapped: bool,
+
+  /// Indices of the FancyZones zones that the app was last snapped to,
+  /// within `layout`. Empty if it hasn't been snapped with the current
+  /// layout.
+  pub zones: Vec<u32>,
+
+  /// FancyZones layout that `zones` belong to.
+  pub layout: Option<FancyZonesLayout>,
+}
+
+pub struct FancyZonesProvider {
+  config: FancyZonesProviderConfig,
+  common: CommonProviderState,
+}
+
+impl FancyZonesProvider {
+  pub fn new(
+    config: FancyZonesProviderConfig,
+    common: CommonProviderState,
+  ) -> FancyZonesProvider {
+    FancyZonesProvider { config, common }
+  }
+
+  fn run_interval(&mut self) -> anyhow::Result<FancyZonesOutput> {
+    let state_dir = Self::state_dir()?;
+
+    // FancyZones writes its state files on first use, so missing files
+    // mean it isn't installed or hasn't been used yet.
+    let Some(applied_layouts) = Self::read_state::<AppliedLayoutsRes>(
+      &state_dir,
+      "applied-layouts",
+    )?
+    else {
+      return Ok(FancyZonesOutput {
+        is_available: false,
+        layouts: Vec::new(),
+        focused_window: Self::focused_window(&state_dir, &[])?,
+      });
+    };
+
+    let custom_layouts =
+      Self::read_state::<CustomLayoutsRes>(&state_dir, "custom-layouts")?
+        .map(|res| res.custom_layouts)
+        .unwrap_or_default();
+
+    let layouts = applied_layouts
+      .applied_layouts
+      .into_iter()
+      .map(|applied| {
+        let layout = applied.applied_layout;
+
+        // Only custom layouts have a name.
+        let name = custom_layouts
+          .iter()
+          .find(|custom| custom.uuid == layout.uuid)
+          .map(|custom| custom.name.clone())
+          .unwrap_or_else(|| layout.layout_type.clone());
+
+        (
+          applied.device.clone(),
+          FancyZonesLayout {
+            id: layout.uuid,
+            name,
+            layout_type: layout.layout_type,
+            zone_count: layout.zone_count,
+            monitor: applied.device.monitor,
+            monitor_number: applied.device.monitor_number,
+            virtual_desktop_id: applied.device.virtual_desktop,
+          },
+        )
+      })
+      .collect::<Vec<_>>();
+
+    let focused_window = Self::focused_window(&state_dir, &layouts)?;
+
+    Ok(FancyZonesOutput {
+      is_available: true,
+      layouts: layouts.into_iter().map(|(_, layout)| layout).collect(),
+      focused_window,
+    })
+  }
+
+  /// Gets the focused window and the zones it was last snapped to.
+  fn focused_window(
+    state_dir: &Path,
+    layouts: &[(DeviceRes, FancyZonesLayout)],
+  ) -> anyhow::Result<Option<ZonedWindow>> {
+    let Some(window) = foreground_window() else {
+      return Ok(None);
+    };
+
+    // Elevated processes can't be queried without running Zebar as
+    // admin.
+    let Ok(exe_path) = process_exe_path(window.process_id) else {
+      return Ok(None);
+    };
+
+    let process_name = Path::new(&exe_path)
+      .file_name()
+      .map(|name| name.to_string_lossy().to_string())
+      .unwrap_or_else(|| exe_path.clone());
+
+    let is_snapped =
+      unsafe { IsWindowArranged(HWND(window.handle as _)) }.as_bool();
+
+    // FancyZones keeps track of zones per app rather than per window.
+    // Only history entries for a currently applied layout are relevant.
+    let zone_history = match layouts.is_empty() {
+      true => None,
+      false => Self::read_state::<AppZoneHistoryRes>(
+        state_dir,
+        "app-zone-history",
+      )?,
+    };
+
+    let zoned = zone_history
+      .into_iter()
+      .flat_map(|res| res.app_zone_history)
+      .filter(|app| app.app_path.eq_ignore_ascii_case(&exe_path))
+      .flat_map(|app| app.history)
+      .find_map(|entry| {
+        layouts
+          .iter()
+          .find(|(device, layout)| {
+            *device == entry.device && layout.id == entry.zoneset_uuid
+          })
+          .map(|(_, layout)| (entry.zone_index_set, layout.clone()))
+      });
+
+    let (zones, layout) = match zoned {
+      Some((zones, layout)) => (zones, Some(layout)),
+      None => (Vec::new(), None),
+    };
+
+    Ok(Some(ZonedWindow {
+      process_name,
+      is_snapped,
+      zones,
+      layout,
+    }))
+  }
+
+  fn state_dir() -> anyhow::Result<PathBuf> {
+    let local_app_data = std::env::var("LOCALAPPDATA")
+      .context("Unable to get local app data directory.")?;
+
+    Ok(PathBuf::from(local_app_data).join(FANCY_ZONES_DIR))
+  }
+
+  /// Reads and parses a FancyZones state file (e.g. `applied-layouts`).
+  /// Returns `None` if the file doesn't exist.
+  fn read_state<T: DeserializeOwned>(
+    state_dir: &Path,
+    name: &str,
+  ) -> anyhow::Result<Option<T>> {
+    let path = state_dir.join(format!("{name}.json"));
+
+    if !path.exists() {
+      return Ok(None);
+    }
+
+    let contents = fs::read_to_string(&path)
+      .with_context(|| format!("Failed to read {}.", path.display()))?;
+
+    let res = serde_json::from_str(&contents)
+      .with_context(|| format!("Failed to parse {}.", path.display()))?;
+
+    Ok(Some(res))
+  }
+}
+
+impl Provider for FancyZonesProvider {
+  fn runtime_type(&self) -> RuntimeType {
+    RuntimeType::Sync
+  }
+
+  fn start_sync(&mut self) {
+    let mut interval = SyncInterval::with_options(
+      self.config.refresh_interval,
+      &self.config.refresh_options,
+    );
+
+    loop {
+      crossbeam::select! {
+        recv(interval.tick()) -> _ => {
+          let output = self.run_interval();
+          self.common.emitter.emit_output_cached(output);
+        }
+        recv(self.common.input.sync_rx) -> input => {
+          if let Ok(ProviderInputMsg::Stop) = input {
+            break;
+          }
+        }
+      }
+    }
+  }
+}
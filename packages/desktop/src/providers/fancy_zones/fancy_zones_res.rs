@@ -0,0 +1,84 @@
+use serde::Deserialize;
+
+/// Contents of `applied-layouts.json`.
+#[derive(Deserialize, Debug)]
+pub struct AppliedLayoutsRes {
+  #[serde(rename = "applied-layouts", default)]
+  pub applied_layouts: Vec<AppliedLayoutRes>,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "kebab-case")]
+pub struct AppliedLayoutRes {
+  pub device: DeviceRes,
+  pub applied_layout: LayoutRes,
+}
+
+/// Monitor and virtual desktop that a layout is applied to.
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "kebab-case")]
+pub struct DeviceRes {
+  /// Hardware ID of the monitor (e.g. `DELA0F2`).
+  pub monitor: String,
+
+  /// 1-based number of the monitor, as shown in the FancyZones editor.
+  pub monitor_number: Option<u32>,
+
+  /// GUID of the virtual desktop.
+  pub virtual_desktop: Option<String>,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "kebab-case")]
+pub struct LayoutRes {
+  pub uuid: String,
+
+  /// One of `blank`, `focus`, `columns`, `rows`, `grid`,
+  /// `priority-grid`, or `custom`.
+  #[serde(rename = "type")]
+  pub layout_type: String,
+
+  #[serde(default)]
+  pub zone_count: u32,
+}
+
+/// Contents of `custom-layouts.json`.
+#[derive(Deserialize, Debug)]
+pub struct CustomLayoutsRes {
+  #[serde(rename = "custom-layouts", default)]
+  pub custom_layouts: Vec<CustomLayoutRes>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct CustomLayoutRes {
+  pub uuid: String,
+  pub name: String,
+}
+
+/// Contents of `app-zone-history.json`.
+#[derive(Deserialize, Debug)]
+pub struct AppZoneHistoryRes {
+  #[serde(rename = "app-zone-history", default)]
+  pub app_zone_history: Vec<AppHistoryRes>,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "kebab-case")]
+pub struct AppHistoryRes {
+  /// Executable path of the app.
+  pub app_path: String,
+
+  #[serde(default)]
+  pub history: Vec<ZoneHistoryRes>,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "kebab-case")]
+pub struct ZoneHistoryRes {
+  /// Indices of the zones that the app was last snapped to. Multiple
+  /// zones are merged into one by holding `Ctrl` while snapping.
+  #[serde(default)]
+  pub zone_index_set: Vec<u32>,
+  pub device: DeviceRes,
+  pub zoneset_uuid: String,
+}
@@ -0,0 +1,4 @@
+mod fancy_zones_provider;
+mod fancy_zones_res;
+
+pub use fancy_zones_provider::*;
@@ -0,0 +1,193 @@
+use std::{
+  collections::VecDeque,
+  time::{Duration, Instant},
+};
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::present_mon::PresentMon;
+use crate::{
+  common::{windows::foreground_window, IntervalOptions, SyncInterval},
+  providers::{
+    CommonProviderState, Provider, ProviderInputMsg, RuntimeType,
+  },
+};
+
+#[derive(Deserialize, Debug, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct FpsProviderConfig {
+  #[serde(deserialize_with = "crate::common::deserialize_duration_ms")]
+  #[schemars(schema_with = "crate::common::duration_schema")]
+  pub refresh_interval: u64,
+
+  /// Alignment and jitter of refreshes.
+  #[serde(default)]
+  pub refresh_options: IntervalOptions,
+
+  /// Path to the PresentMon executable (e.g.
+  /// `C:\Tools\PresentMon-2.3.0-x64.exe`).
+  pub present_mon_path: String,
+
+  /// Whether to only measure the focused app if it's fullscreen.
+  pub fullscreen_only: bool,
+
+  /// Duration in milliseconds of recent frames to calculate stats over.
+  pub sample_window: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct FpsOutput {
+  /// ID of the process being measured. `None` if no app is focused (or
+  /// the focused app isn't fullscreen with `fullscreenOnly`).
+  pub process_id: Option<u32>,
+
+  /// Name of the process being measured (e.g. `game.exe`). `None` until
+  /// the process presents a frame.
+  pub process_name: Option<String>,
+
+  /// Average frames per second.
+  pub fps: Option<f64>,
+
+  /// Average frame time in milliseconds.
+  pub frame_time: Option<f64>,
+
+  /// 99th percentile frame time in milliseconds.
+  pub frame_time_p99: Option<f64>,
+
+  /// Frames per second of the slowest 1% of frames.
+  pub low_1_percent_fps: Option<f64>,
+}
+
+pub struct FpsProvider {
+  config: FpsProviderConfig,
+  common: CommonProviderState,
+
+  /// ID of the process being measured.
+  process_id: Option<u32>,
+
+  process_name: Option<String>,
+
+  /// Frame times in milliseconds of the process being measured, along
+  /// with when they were received. Oldest first.
+  frames: VecDeque<(Instant, f64)>,
+}
+
+impl FpsProvider {
+  pub fn new(
+    config: FpsProviderConfig,
+    common: CommonProviderState,
+  ) -> FpsProvider {
+    FpsProvider {
+      config,
+      common,
+      process_id: None,
+      process_name: None,
+      frames: VecDeque::new(),
+    }
+  }
+
+  fn run_interval(&mut self) -> FpsOutput {
+    let process_id = foreground_window()
+      .filter(|window| {
+        window.is_fullscreen || !self.config.fullscreen_only
+      })
+      .map(|window| window.process_id);
+
+    if process_id != self.process_id {
+      self.process_id = process_id;
+      self.process_name = None;
+      self.frames.clear();
+    }
+
+    let sample_window = Duration::from_millis(self.config.sample_window);
+
+    while self
+      .frames
+      .front()
+      .is_some_and(|(time, _)| time.elapsed() > sample_window)
+    {
+      self.frames.pop_front();
+    }
+
+    let mut frame_times = self
+      .frames
+      .iter()
+      .map(|(_, time)| *time)
+      .collect::<Vec<_>>();
+
+    frame_times.sort_by(|a, b| a.total_cmp(b));
+
+    let frame_time = (!frame_times.is_empty())
+      .then(|| frame_times.iter().sum::<f64>() / frame_times.len() as f64);
+
+    let frame_time_p99 = (!frame_times.is_empty()).then(|| {
+      let index = (frame_times.len() as f64 * 0.99).ceil() as usize;
+      frame_times[index.clamp(1, frame_times.len()) - 1]
+    });
+
+    FpsOutput {
+      process_id: self.process_id,
+      process_name: self.process_name.clone(),
+      fps: frame_time
+        .filter(|time| *time > 0.)
+        .map(|time| 1000. / time),
+      frame_time,
+      frame_time_p99,
+      low_1_percent_fps: frame_time_p99
+        .filter(|time| *time > 0.)
+        .map(|time| 1000. / time),
+    }
+  }
+
+  fn start(&mut self) -> anyhow::Result<()> {
+    let (frame_tx, frame_rx) = crossbeam::channel::unbounded();
+    let _present_mon =
+      PresentMon::spawn(&self.config.present_mon_path, frame_tx)?;
+
+    let mut interval = SyncInterval::with_options(
+      self.config.refresh_interval,
+      &self.config.refresh_options,
+    );
+
+    loop {
+      crossbeam::select! {
+        recv(frame_rx) -> frame => {
+          match frame {
+            Ok(Ok(frame)) if Some(frame.process_id) == self.process_id => {
+              self.process_name.get_or_insert(frame.process_name);
+              self.frames.push_back((Instant::now(), frame.frame_time));
+            }
+            Ok(Err(err)) => return Err(err),
+            _ => {}
+          }
+        }
+        recv(interval.tick()) -> _ => {
+          let output = self.run_interval();
+          self.common.emitter.emit_output_cached(Ok(output));
+        }
+        recv(self.common.input.sync_rx) -> input => {
+          if let Ok(ProviderInputMsg::Stop) = input {
+            break;
+          }
+        }
+      }
+    }
+
+    Ok(())
+  }
+}
+
+impl Provider for FpsProvider {
+  fn runtime_type(&self) -> RuntimeType {
+    RuntimeType::Sync
+  }
+
+  fn start_sync(&mut self) {
+    if let Err(err) = self.start() {
+      tracing::error!("Error starting FPS provider: {}", err);
+      self.common.emitter.emit_output::<FpsOutput>(Err(err));
+    }
+  }
+}
@@ -0,0 +1,4 @@
+mod fps_provider;
+mod present_mon;
+
+pub use fps_provider::*;
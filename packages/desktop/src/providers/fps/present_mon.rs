@@ -0,0 +1,128 @@
+use std::{
+  io::{BufRead, BufReader},
+  process::{Child, Stdio},
+  thread,
+};
+
+use anyhow::{bail, Context};
+use crossbeam::channel::Sender;
+
+use crate::common::no_window_command;
+
+/// Name of the ETW session used by PresentMon. Reusing the same name
+/// stops sessions left behind by a previous run.
+const SESSION_NAME: &str = "ZebarPresentMon";
+
+/// A frame presented by a process.
+#[derive(Debug, Clone)]
+pub struct Frame {
+  pub process_id: u32,
+  pub process_name: String,
+
+  /// Time since the previous frame in milliseconds.
+  pub frame_time: f64,
+}
+
+/// A running PresentMon process that reports frames of all processes.
+pub struct PresentMon {
+  child: Child,
+}
+
+impl PresentMon {
+  /// Spawns PresentMon and sends each frame it reports to `frame_tx`.
+  /// An error is sent if PresentMon exits.
+  pub fn spawn(
+    path: &str,
+    frame_tx: Sender<anyhow::Result<Frame>>,
+  ) -> anyhow::Result<Self> {
+    let mut child = no_window_command(path)
+      .args([
+        "--output_stdout",
+        "--no_console_stats",
+        "--stop_existing_session",
+        "--session_name",
+        SESSION_NAME,
+      ])
+      .stdout(Stdio::piped())
+      .stderr(Stdio::null())
+      .spawn()
+      .with_context(|| {
+        format!(
+          "Unable to run PresentMon at '{}'. Set `presentMonPath` to \
+          the PresentMon executable.",
+          path
+        )
+      })?;
+
+    let stdout = child.stdout.take().context("No PresentMon stdout.")?;
+
+    thread::spawn(move || {
+      let res = read_frames(BufReader::new(stdout), &frame_tx);
+
+      let err = res.err().unwrap_or_else(|| {
+        anyhow::anyhow!(
+          "PresentMon exited. It requires administrator rights or \
+          membership in the Performance Log Users group."
+        )
+      });
+
+      let _ = frame_tx.send(Err(err));
+    });
+
+    Ok(Self { child })
+  }
+}
+
+impl Drop for PresentMon {
+  fn drop(&mut self) {
+    let _ = self.child.kill();
+    let _ = self.child.wait();
+  }
+}
+
+/// Parses frames from PresentMon's CSV output until it exits or the
+/// receiver is dropped.
+fn read_frames(
+  reader: impl BufRead,
+  frame_tx: &Sender<anyhow::Result<Frame>>,
+) -> anyhow::Result<()> {
+  let mut lines = reader.lines();
+
+  let header = lines.next().context("No output from PresentMon.")??;
+  let columns = header.split(',').collect::<Vec<_>>();
+  let column = |names: &[&str]| {
+    columns.iter().position(|column| names.contains(column))
+  };
+
+  // Frame times are `MsBetweenPresents` in PresentMon 1.x, and
+  // `FrameTime` in 2.x.
+  let (Some(name_index), Some(id_index), Some(frame_time_index)) = (
+    column(&["Application"]),
+    column(&["ProcessID"]),
+    column(&["FrameTime", "MsBetweenPresents"]),
+  ) else {
+    bail!("Unrecognized PresentMon output format.");
+  };
+
+  for line in lines {
+    let line = line?;
+    let values = line.split(',').collect::<Vec<_>>();
+
+    // Values are `NA` for e.g. the first frame of a swap chain.
+    let frame = (|| {
+      Some(Frame {
+        process_name: values.get(name_index)?.to_string(),
+        process_id: values.get(id_index)?.parse().ok()?,
+        frame_time: values.get(frame_time_index)?.parse().ok()?,
+      })
+    })();
+
+    if let Some(frame) = frame {
+      if frame_tx.send(Ok(frame)).is_err() {
+        return Ok(());
+      }
+    }
+  }
+
+  Ok(())
+}
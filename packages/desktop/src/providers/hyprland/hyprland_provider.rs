@@ -0,0 +1,318 @@
+use std::{
+  env,
+  io::{BufRead, BufReader, Read, Write},
+  os::unix::net::UnixStream,
+  path::PathBuf,
+  sync::Mutex,
+};
+
+use anyhow::{bail, Context};
+use async_trait::async_trait;
+use schemars::JsonSchema;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use tracing::debug;
+
+use super::hyprland_res::{
+  HyprlandMonitorRes, HyprlandWindowRes, HyprlandWorkspaceRes,
+};
+use crate::providers::{
+  CommonProviderState, HyprlandFunction, Provider, ProviderEmitter,
+  ProviderFunction, ProviderFunctionResponse, ProviderInputMsg,
+  RuntimeType,
+};
+
+/// Events from Hyprland's event socket that result in a state update.
+const STATE_EVENTS: &[&str] = &[
+  "workspace",
+  "workspacev2",
+  "focusedmon",
+  "activewindow",
+  "activewindowv2",
+  "createworkspace",
+  "destroyworkspace",
+  "moveworkspace",
+  "renameworkspace",
+  "monitoradded",
+  "monitorremoved",
+  "openwindow",
+  "closewindow",
+  "movewindow",
+  "fullscreen",
+  "changefloatingmode",
+];
+
+#[derive(Deserialize, Debug, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct HyprlandProviderConfig {}
+
+#[derive(Debug, Clone, PartialEq, Serialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct HyprlandOutput {
+  pub monitors: Vec<HyprlandMonitor>,
+  pub workspaces: Vec<HyprlandWorkspace>,
+  pub focused_monitor: Option<HyprlandMonitor>,
+  pub focused_workspace: Option<HyprlandWorkspace>,
+  pub active_window: Option<HyprlandWindow>,
+
+  /// Name of the active submap (keybind mode). `None` when in the
+  /// default submap.
+  pub submap: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct HyprlandMonitor {
+  pub id: i64,
+  pub name: String,
+  pub description: String,
+  pub width: u32,
+  pub height: u32,
+  pub x: i32,
+  pub y: i32,
+  pub scale: f32,
+  pub active_workspace_id: i64,
+  pub is_focused: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct HyprlandWorkspace {
+  pub id: i64,
+  pub name: String,
+  pub monitor: String,
+  pub window_count: u32,
+  pub has_fullscreen: bool,
+
+  /// Whether the workspace is shown on its monitor.
+  pub is_displayed: bool,
+
+  /// Whether the workspace is shown on the focused monitor.
+  pub is_focused: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct HyprlandWindow {
+  pub address: String,
+  pub title: String,
+  pub class: String,
+  pub workspace_id: Option<i64>,
+  pub is_floating: bool,
+}
+
+pub struct HyprlandProvider {
+  common: CommonProviderState,
+}
+
+impl HyprlandProvider {
+  pub fn new(
+    _config: HyprlandProviderConfig,
+    common: CommonProviderState,
+  ) -> HyprlandProvider {
+    HyprlandProvider { common }
+  }
+
+  /// Gets the directory containing Hyprland's IPC sockets.
+  ///
+  /// Hyprland v0.40+ places them in `$XDG_RUNTIME_DIR/hypr`, and older
+  /// versions in `/tmp/hypr`.
+  fn socket_dir() -> anyhow::Result<PathBuf> {
+    let signature = env::var("HYPRLAND_INSTANCE_SIGNATURE")
+      .context("Hyprland is not running.")?;
+
+    let runtime_dir = env::var("XDG_RUNTIME_DIR")
+      .map(|dir| PathBuf::from(dir).join("hypr").join(&signature))
+      .ok()
+      .filter(|dir| dir.exists());
+
+    Ok(
+      runtime_dir
+        .unwrap_or_else(|| PathBuf::from("/tmp/hypr").join(&signature)),
+    )
+  }
+
+  /// Sends a request to Hyprland's request socket and returns the raw
+  /// response.
+  fn request(message: &str) -> anyhow::Result<String> {
+    let mut stream =
+      UnixStream::connect(Self::socket_dir()?.join(".socket.sock"))
+        .context("Failed to connect to Hyprland socket.")?;
+
+    stream.write_all(message.as_bytes())?;
+
+    let mut response = String::new();
+    stream.read_to_string(&mut response)?;
+
+    Ok(response)
+  }
+
+  /// Sends a request for JSON output (e.g. `monitors`).
+  fn query<T: DeserializeOwned>(command: &str) -> anyhow::Result<T> {
+    let response = Self::request(&format!("j/{}", command))?;
+
+    serde_json::from_str(&response).with_context(|| {
+      format!("Failed to parse Hyprland `{}` response.", command)
+    })
+  }
+
+  /// Listens on Hyprland's event socket and emits the state on each
+  /// relevant event.
+  fn listen_events(
+    emitter: &ProviderEmitter,
+    submap: &Mutex<Option<String>>,
+  ) -> anyhow::Result<()> {
+    let stream =
+      UnixStream::connect(Self::socket_dir()?.join(".socket2.sock"))
+        .context("Failed to connect to Hyprland event socket.")?;
+
+    debug!("Connected to Hyprland event socket.");
+
+    emitter.emit_output(Self::query_state(submap));
+
+    for line in BufReader::new(stream).lines() {
+      let line = line.context("Failed to read Hyprland event.")?;
+
+      let Some((event, data)) = line.split_once(">>") else {
+        continue;
+      };
+
+      if event == "submap" {
+        *submap.lock().unwrap() =
+          Some(data.to_string()).filter(|name| !name.is_empty());
+      } else if !STATE_EVENTS.contains(&event) {
+        continue;
+      }
+
+      emitter.emit_output(Self::query_state(submap));
+    }
+
+    bail!("Hyprland event socket was closed.")
+  }
+
+  fn query_state(
+    submap: &Mutex<Option<String>>,
+  ) -> anyhow::Result<HyprlandOutput> {
+    let monitors = Self::query::<Vec<HyprlandMonitorRes>>("monitors")?
+      .into_iter()
+      .map(|monitor| HyprlandMonitor {
+        id: monitor.id,
+        name: monitor.name,
+        description: monitor.description,
+        width: monitor.width,
+        height: monitor.height,
+        x: monitor.x,
+        y: monitor.y,
+        scale: monitor.scale,
+        active_workspace_id: monitor.active_workspace.id,
+        is_focused: monitor.focused,
+      })
+      .collect::<Vec<_>>();
+
+    let focused_monitor =
+      monitors.iter().find(|monitor| monitor.is_focused).cloned();
+
+    let mut workspaces =
+      Self::query::<Vec<HyprlandWorkspaceRes>>("workspaces")?
+        .into_iter()
+        .map(|workspace| HyprlandWorkspace {
+          is_displayed: monitors
+            .iter()
+            .any(|monitor| monitor.active_workspace_id == workspace.id),
+          is_focused: focused_monitor.as_ref().is_some_and(|monitor| {
+            monitor.active_workspace_id == workspace.id
+          }),
+          id: workspace.id,
+          name: workspace.name,
+          monitor: workspace.monitor,
+          window_count: workspace.windows,
+          has_fullscreen: workspace.has_fullscreen,
+        })
+        .collect::<Vec<_>>();
+
+    workspaces.sort_by_key(|workspace| workspace.id);
+
+    let focused_workspace = workspaces
+      .iter()
+      .find(|workspace| workspace.is_focused)
+      .cloned();
+
+    let window = Self::query::<HyprlandWindowRes>("activewindow")?;
+
+    let active_window = window.address.map(|address| HyprlandWindow {
+      address,
+      title: window.title.unwrap_or_default(),
+      class: window.class.unwrap_or_default(),
+      workspace_id: window.workspace.map(|workspace| workspace.id),
+      is_floating: window.floating.unwrap_or(false),
+    });
+
+    Ok(HyprlandOutput {
+      monitors,
+      workspaces,
+      focused_monitor,
+      focused_workspace,
+      active_window,
+      submap: submap.lock().unwrap().clone(),
+    })
+  }
+
+  fn handle_function(
+    function: HyprlandFunction,
+  ) -> anyhow::Result<ProviderFunctionResponse> {
+    match function {
+      HyprlandFunction::Dispatch(args) => {
+        let message = match args.args {
+          Some(dispatch_args) => {
+            format!("dispatch {} {}", args.dispatcher, dispatch_args)
+          }
+          None => format!("dispatch {}", args.dispatcher),
+        };
+
+        let response = Self::request(&message)?;
+
+        if response.trim() != "ok" {
+          bail!("Hyprland dispatch failed: {}", response.trim());
+        }
+
+        Ok(ProviderFunctionResponse::Null)
+      }
+    }
+  }
+}
+
+#[async_trait]
+impl Provider for HyprlandProvider {
+  fn runtime_type(&self) -> RuntimeType {
+    RuntimeType::Sync
+  }
+
+  fn start_sync(&mut self) {
+    let emitter = self.common.emitter.clone();
+
+    // Reading from the event socket blocks indefinitely, so it's done
+    // on a separate thread to be able to handle function calls.
+    std::thread::spawn(move || {
+      let submap = Mutex::new(None);
+
+      if let Err(err) = Self::listen_events(&emitter, &submap) {
+        emitter.emit_output::<HyprlandOutput>(Err(err));
+      }
+    });
+
+    while let Ok(input) = self.common.input.sync_rx.recv() {
+      match input {
+        ProviderInputMsg::Stop => break,
+        ProviderInputMsg::Function(
+          ProviderFunction::Hyprland(hyprland_function),
+          sender,
+        ) => {
+          let res = Self::handle_function(hyprland_function)
+            .map_err(|err| err.to_string());
+
+          sender.send(res).unwrap();
+        }
+        _ => {}
+      }
+    }
+  }
+}
@@ -0,0 +1,43 @@
+use serde::Deserialize;
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct HyprlandMonitorRes {
+  pub id: i64,
+  pub name: String,
+  pub description: String,
+  pub width: u32,
+  pub height: u32,
+  pub x: i32,
+  pub y: i32,
+  pub scale: f32,
+  pub active_workspace: HyprlandWorkspaceRefRes,
+  pub focused: bool,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct HyprlandWorkspaceRefRes {
+  pub id: i64,
+  pub name: String,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct HyprlandWorkspaceRes {
+  pub id: i64,
+  pub name: String,
+  pub monitor: String,
+  pub windows: u32,
+  #[serde(rename = "hasfullscreen")]
+  pub has_fullscreen: bool,
+}
+
+/// Active window. All fields are missing if no window is focused, in
+/// which case Hyprland responds with `{}`.
+#[derive(Deserialize, Debug)]
+pub struct HyprlandWindowRes {
+  pub address: Option<String>,
+  pub title: Option<String>,
+  pub class: Option<String>,
+  pub workspace: Option<HyprlandWorkspaceRefRes>,
+  pub floating: Option<bool>,
+}
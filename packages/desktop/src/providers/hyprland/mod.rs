@@ -0,0 +1,4 @@
+mod hyprland_provider;
+mod hyprland_res;
+
+pub use hyprland_provider::*;
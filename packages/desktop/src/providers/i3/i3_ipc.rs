@@ -0,0 +1,114 @@
+use std::{
+  env,
+  io::{Read, Write},
+  os::unix::net::UnixStream,
+  process::Command,
+};
+
+use anyhow::{bail, Context};
+use serde::de::DeserializeOwned;
+
+/// Magic string that prefixes every i3 IPC message.
+const MAGIC: &[u8] = b"i3-ipc";
+
+/// Bit that's set on the message type of events.
+pub const EVENT_BIT: u32 = 1 << 31;
+
+pub const RUN_COMMAND: u32 = 0;
+pub const GET_WORKSPACES: u32 = 1;
+pub const SUBSCRIBE: u32 = 2;
+pub const GET_TREE: u32 = 4;
+pub const GET_BINDING_STATE: u32 = 12;
+
+/// Event type for binding mode changes.
+pub const MODE_EVENT: u32 = EVENT_BIT | 2;
+
+/// Connection to the IPC socket of Sway or i3.
+pub struct I3Ipc {
+  stream: UnixStream,
+}
+
+impl I3Ipc {
+  /// Connects to the IPC socket, preferring Sway if both are running.
+  pub fn connect() -> anyhow::Result<Self> {
+    let stream = UnixStream::connect(Self::socket_path()?)
+      .context("Failed to connect to Sway/i3 socket.")?;
+
+    Ok(Self { stream })
+  }
+
+  fn socket_path() -> anyhow::Result<String> {
+    if let Ok(path) = env::var("SWAYSOCK").or_else(|_| env::var("I3SOCK"))
+    {
+      return Ok(path);
+    }
+
+    // Fall back to asking the WM, e.g. when Zebar isn't started by it.
+    for program in ["sway", "i3"] {
+      if let Ok(output) =
+        Command::new(program).arg("--get-socketpath").output()
+      {
+        let path =
+          String::from_utf8_lossy(&output.stdout).trim().to_string();
+
+        if output.status.success() && !path.is_empty() {
+          return Ok(path);
+        }
+      }
+    }
+
+    bail!("Neither Sway nor i3 is running.")
+  }
+
+  /// Sends a message with the given type and payload.
+  pub fn send(
+    &mut self,
+    message_type: u32,
+    payload: &str,
+  ) -> anyhow::Result<()> {
+    let mut message = Vec::with_capacity(MAGIC.len() + 8 + payload.len());
+    message.extend_from_slice(MAGIC);
+    message.extend_from_slice(&(payload.len() as u32).to_ne_bytes());
+    message.extend_from_slice(&message_type.to_ne_bytes());
+    message.extend_from_slice(payload.as_bytes());
+
+    self.stream.write_all(&message)?;
+    Ok(())
+  }
+
+  /// Reads the next message, which is either a reply or an event.
+  ///
+  /// Returns the message type and payload.
+  pub fn read(&mut self) -> anyhow::Result<(u32, Vec<u8>)> {
+    let mut header = [0; 14];
+    self.stream.read_exact(&mut header)?;
+
+    if &header[..MAGIC.len()] != MAGIC {
+      bail!("Invalid message from Sway/i3 socket.");
+    }
+
+    let length = u32::from_ne_bytes(header[6..10].try_into()?) as usize;
+    let message_type = u32::from_ne_bytes(header[10..14].try_into()?);
+
+    let mut payload = vec![0; length];
+    self.stream.read_exact(&mut payload)?;
+
+    Ok((message_type, payload))
+  }
+
+  /// Sends a message and parses the reply.
+  ///
+  /// Should only be used on connections without subscriptions, since
+  /// events could otherwise be read in place of the reply.
+  pub fn request<T: DeserializeOwned>(
+    &mut self,
+    message_type: u32,
+    payload: &str,
+  ) -> anyhow::Result<T> {
+    self.send(message_type, payload)?;
+    let (_, reply) = self.read()?;
+
+    serde_json::from_slice(&reply)
+      .context("Failed to parse reply from Sway/i3 socket.")
+  }
+}
@@ -0,0 +1,239 @@
+use anyhow::bail;
+use async_trait::async_trait;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use tracing::debug;
+
+use super::i3_ipc::{
+  I3Ipc, EVENT_BIT, GET_BINDING_STATE, GET_TREE, GET_WORKSPACES,
+  MODE_EVENT, RUN_COMMAND, SUBSCRIBE,
+};
+use crate::providers::{
+  CommonProviderState, I3Function, Provider, ProviderEmitter,
+  ProviderFunction, ProviderFunctionResponse, ProviderInputMsg,
+  RuntimeType,
+};
+
+#[derive(Deserialize, Debug, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct I3ProviderConfig {}
+
+#[derive(Debug, Clone, PartialEq, Serialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct I3Output {
+  pub workspaces: Vec<I3Workspace>,
+  pub focused_workspace: Option<I3Workspace>,
+  pub focused_window: Option<I3Window>,
+
+  /// Name of the active binding mode (e.g. `resize`). `default` when
+  /// no mode is active.
+  pub binding_mode: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct I3Workspace {
+  /// Workspace number, or -1 for workspaces without a number.
+  pub num: i32,
+  pub name: String,
+
+  /// Name of the output (monitor) the workspace is on.
+  pub output: String,
+
+  #[serde(alias = "visible")]
+  pub is_visible: bool,
+  #[serde(alias = "focused")]
+  pub is_focused: bool,
+  #[serde(alias = "urgent")]
+  pub is_urgent: bool,
+
+  /// Area of the workspace in layout coordinates.
+  pub rect: I3Rect,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct I3Rect {
+  pub x: i32,
+  pub y: i32,
+  pub width: i32,
+  pub height: i32,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct I3Window {
+  pub id: i64,
+  pub title: Option<String>,
+
+  /// App ID of Wayland-native windows. Only set on Sway.
+  pub app_id: Option<String>,
+
+  /// `WM_CLASS` of X11 windows.
+  pub class: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct BindingStateRes {
+  name: String,
+}
+
+#[derive(Deserialize)]
+struct CommandRes {
+  success: bool,
+  error: Option<String>,
+}
+
+pub struct I3Provider {
+  common: CommonProviderState,
+}
+
+impl I3Provider {
+  pub fn new(
+    _config: I3ProviderConfig,
+    common: CommonProviderState,
+  ) -> I3Provider {
+    I3Provider { common }
+  }
+
+  /// Subscribes to events and emits the state on each event.
+  fn listen_events(emitter: &ProviderEmitter) -> anyhow::Result<()> {
+    // Queries are made on a separate connection, since replies on the
+    // subscribed connection can be interleaved with events.
+    let mut query_ipc = I3Ipc::connect()?;
+    let mut event_ipc = I3Ipc::connect()?;
+
+    let mut binding_mode = query_ipc
+      .request::<BindingStateRes>(GET_BINDING_STATE, "")
+      .map(|res| res.name)
+      .unwrap_or_else(|_| "default".into());
+
+    event_ipc.send(SUBSCRIBE, r#"["workspace","window","mode"]"#)?;
+    debug!("Subscribed to Sway/i3 events.");
+
+    emitter.emit_output(Self::query_state(&mut query_ipc, &binding_mode));
+
+    loop {
+      let (message_type, payload) = event_ipc.read()?;
+
+      if message_type == MODE_EVENT {
+        if let Ok(event) =
+          serde_json::from_slice::<serde_json::Value>(&payload)
+        {
+          if let Some(mode) = event["change"].as_str() {
+            binding_mode = mode.to_string();
+          }
+        }
+      }
+
+      // The reply to the subscribe message is skipped, since events
+      // always have the highest bit set.
+      if message_type & EVENT_BIT == 0 {
+        continue;
+      }
+
+      emitter
+        .emit_output(Self::query_state(&mut query_ipc, &binding_mode));
+    }
+  }
+
+  fn query_state(
+    ipc: &mut I3Ipc,
+    binding_mode: &str,
+  ) -> anyhow::Result<I3Output> {
+    let workspaces =
+      ipc.request::<Vec<I3Workspace>>(GET_WORKSPACES, "")?;
+    let tree = ipc.request::<serde_json::Value>(GET_TREE, "")?;
+
+    Ok(I3Output {
+      focused_workspace: workspaces
+        .iter()
+        .find(|workspace| workspace.is_focused)
+        .cloned(),
+      focused_window: Self::find_focused_window(&tree),
+      workspaces,
+      binding_mode: binding_mode.to_string(),
+    })
+  }
+
+  /// Recursively finds the focused window in the layout tree.
+  fn find_focused_window(node: &serde_json::Value) -> Option<I3Window> {
+    let is_window =
+      matches!(node["type"].as_str(), Some("con") | Some("floating_con"))
+        && node["nodes"]
+          .as_array()
+          .map_or(true, |nodes| nodes.is_empty());
+
+    if is_window && node["focused"].as_bool() == Some(true) {
+      return Some(I3Window {
+        id: node["id"].as_i64().unwrap_or_default(),
+        title: node["name"].as_str().map(String::from),
+        app_id: node["app_id"].as_str().map(String::from),
+        class: node["window_properties"]["class"]
+          .as_str()
+          .map(String::from),
+      });
+    }
+
+    ["nodes", "floating_nodes"]
+      .iter()
+      .filter_map(|key| node[key].as_array())
+      .flatten()
+      .find_map(Self::find_focused_window)
+  }
+
+  fn handle_function(
+    function: I3Function,
+  ) -> anyhow::Result<ProviderFunctionResponse> {
+    match function {
+      I3Function::Command(args) => {
+        let results = I3Ipc::connect()?
+          .request::<Vec<CommandRes>>(RUN_COMMAND, &args.command)?;
+
+        if let Some(failed) = results.iter().find(|res| !res.success) {
+          bail!(
+            "Sway/i3 command failed: {}",
+            failed.error.as_deref().unwrap_or("Unknown error.")
+          );
+        }
+
+        Ok(ProviderFunctionResponse::Null)
+      }
+    }
+  }
+}
+
+#[async_trait]
+impl Provider for I3Provider {
+  fn runtime_type(&self) -> RuntimeType {
+    RuntimeType::Sync
+  }
+
+  fn start_sync(&mut self) {
+    let emitter = self.common.emitter.clone();
+
+    // Reading events blocks indefinitely, so it's done on a separate
+    // thread to be able to handle function calls.
+    std::thread::spawn(move || {
+      if let Err(err) = Self::listen_events(&emitter) {
+        emitter.emit_output::<I3Output>(Err(err));
+      }
+    });
+
+    while let Ok(input) = self.common.input.sync_rx.recv() {
+      match input {
+        ProviderInputMsg::Stop => break,
+        ProviderInputMsg::Function(
+          ProviderFunction::I3(i3_function),
+          sender,
+        ) => {
+          let res = Self::handle_function(i3_function)
+            .map_err(|err| err.to_string());
+
+          sender.send(res).unwrap();
+        }
+        _ => {}
+      }
+    }
+  }
+}
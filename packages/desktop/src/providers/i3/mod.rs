@@ -0,0 +1,4 @@
+mod i3_ipc;
+mod i3_provider;
+
+pub use i3_provider::*;
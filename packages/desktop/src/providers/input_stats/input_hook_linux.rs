@@ -0,0 +1,106 @@
+use std::{
+  fs::{self, File},
+  io::Read,
+  sync::{
+    atomic::{AtomicU64, Ordering},
+    OnceLock,
+  },
+  thread,
+};
+
+use anyhow::bail;
+use tracing::info;
+
+/// Event type for key and button presses.
+const EV_KEY: u16 = 0x01;
+
+/// Key codes below this are keyboard keys.
+const BTN_MISC: u16 = 0x100;
+
+/// Range of mouse button codes (`BTN_LEFT` to `BTN_TASK`).
+const MOUSE_BUTTONS: std::ops::RangeInclusive<u16> = 0x110..=0x117;
+
+/// Size of a `struct input_event`, which starts with a `timeval`.
+const EVENT_SIZE: usize = 2 * size_of::<isize>() + 8;
+
+static KEYSTROKES: AtomicU64 = AtomicU64::new(0);
+static CLICKS: AtomicU64 = AtomicU64::new(0);
+
+/// Result of starting the device readers. Readers are started once and
+/// keep running for the lifetime of the app.
+static READERS: OnceLock<Result<(), String>> = OnceLock::new();
+
+/// Counts key presses and clicks by reading evdev devices in
+/// `/dev/input`. Requires the user to be in the `input` group. Only
+/// presses are counted; which keys are pressed is never recorded.
+pub struct InputHook;
+
+impl InputHook {
+  pub fn install() -> anyhow::Result<Self> {
+    match READERS
+      .get_or_init(|| start_readers().map_err(|e| e.to_string()))
+    {
+      Ok(()) => Ok(Self),
+      Err(err) => bail!("{}", err),
+    }
+  }
+
+  /// Gets the total number of keystrokes and clicks since the readers
+  /// were started.
+  pub fn totals(&self) -> (u64, u64) {
+    (
+      KEYSTROKES.load(Ordering::Relaxed),
+      CLICKS.load(Ordering::Relaxed),
+    )
+  }
+}
+
+/// Spawns a reader thread for each readable input device.
+fn start_readers() -> anyhow::Result<()> {
+  let devices = fs::read_dir("/dev/input")?
+    .filter_map(|entry| entry.ok())
+    .filter(|entry| {
+      entry.file_name().to_string_lossy().starts_with("event")
+    })
+    .filter_map(|entry| File::open(entry.path()).ok())
+    .collect::<Vec<_>>();
+
+  if devices.is_empty() {
+    bail!(
+      "No readable input devices in /dev/input. Add the user to the \
+      `input` group to use the input stats provider."
+    );
+  }
+
+  info!("Reading input stats from {} devices.", devices.len());
+
+  for mut device in devices {
+    thread::spawn(move || {
+      let mut buffer = [0u8; EVENT_SIZE];
+
+      while device.read_exact(&mut buffer).is_ok() {
+        let offset = EVENT_SIZE - 8;
+        let event_type =
+          u16::from_ne_bytes([buffer[offset], buffer[offset + 1]]);
+        let code =
+          u16::from_ne_bytes([buffer[offset + 2], buffer[offset + 3]]);
+        let value = i32::from_ne_bytes(
+          buffer[offset + 4..offset + 8].try_into().unwrap(),
+        );
+
+        // Values are 0 for release, 1 for press, and 2 for auto-repeat.
+        if event_type != EV_KEY || value != 1 {
+          continue;
+        }
+
+        if code < BTN_MISC {
+          KEYSTROKES.fetch_add(1, Ordering::Relaxed);
+        } else if MOUSE_BUTTONS.contains(&code) {
+          CLICKS.fetch_add(1, Ordering::Relaxed);
+        }
+      }
+    });
+  }
+
+  Ok(())
+}
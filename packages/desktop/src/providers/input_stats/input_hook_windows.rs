@@ -0,0 +1,180 @@
+use std::{
+  cell::RefCell,
+  sync::{
+    atomic::{AtomicU64, Ordering},
+    mpsc, Mutex,
+  },
+  thread,
+};
+
+use anyhow::Context;
+use windows::Win32::{
+  Foundation::{HINSTANCE, HWND, LPARAM, LRESULT, WPARAM},
+  System::Threading::GetCurrentThreadId,
+  UI::WindowsAndMessaging::{
+    CallNextHookEx, DispatchMessageW, GetMessageW, PostThreadMessageW,
+    SetWindowsHookExW, UnhookWindowsHookEx, HHOOK, KBDLLHOOKSTRUCT, MSG,
+    WH_KEYBOARD_LL, WH_MOUSE_LL, WM_KEYDOWN, WM_KEYUP, WM_LBUTTONDOWN,
+    WM_MBUTTONDOWN, WM_QUIT, WM_RBUTTONDOWN, WM_SYSKEYDOWN, WM_SYSKEYUP,
+    WM_XBUTTONDOWN,
+  },
+};
+
+static KEYSTROKES: AtomicU64 = AtomicU64::new(0);
+static CLICKS: AtomicU64 = AtomicU64::new(0);
+
+/// ID of the thread running the hooks, along with the number of active
+/// `InputHook` instances.
+static HOOK_THREAD: Mutex<Option<(u32, usize)>> = Mutex::new(None);
+
+thread_local! {
+  /// Keys that are currently held down. Used to ignore auto-repeat.
+  static PRESSED_KEYS: RefCell<[bool; 256]> = const { RefCell::new([false; 256]) };
+}
+
+/// Guard that keeps low-level keyboard and mouse hooks installed.
+///
+/// The hooks are shared by all instances, and are removed once the
+/// last instance is dropped. Only key presses and clicks are counted;
+/// which keys are pressed is never recorded.
+pub struct InputHook;
+
+impl InputHook {
+  pub fn install() -> anyhow::Result<Self> {
+    let mut hook_thread = HOOK_THREAD.lock().unwrap();
+
+    *hook_thread = match *hook_thread {
+      Some((thread_id, count)) => Some((thread_id, count + 1)),
+      None => Some((spawn_hook_thread()?, 1)),
+    };
+
+    Ok(Self)
+  }
+
+  /// Gets the total number of keystrokes and clicks since the hooks
+  /// were first installed.
+  pub fn totals(&self) -> (u64, u64) {
+    (
+      KEYSTROKES.load(Ordering::Relaxed),
+      CLICKS.load(Ordering::Relaxed),
+    )
+  }
+}
+
+impl Drop for InputHook {
+  fn drop(&mut self) {
+    let mut hook_thread = HOOK_THREAD.lock().unwrap();
+
+    if let Some((thread_id, count)) = *hook_thread {
+      if count > 1 {
+        *hook_thread = Some((thread_id, count - 1));
+        return;
+      }
+
+      // Exits the message loop, which removes the hooks.
+      let _ = unsafe {
+        PostThreadMessageW(thread_id, WM_QUIT, WPARAM(0), LPARAM(0))
+      };
+
+      *hook_thread = None;
+    }
+  }
+}
+
+/// Installs the hooks on a new thread with a message loop, which is
+/// required for low-level hooks to be called.
+///
+/// Returns the ID of the thread.
+fn spawn_hook_thread() -> anyhow::Result<u32> {
+  let (init_tx, init_rx) = mpsc::channel();
+
+  thread::spawn(move || {
+    let hooks = unsafe {
+      SetWindowsHookExW(
+        WH_KEYBOARD_LL,
+        Some(keyboard_proc),
+        HINSTANCE::default(),
+        0,
+      )
+      .and_then(|keyboard_hook| {
+        SetWindowsHookExW(
+          WH_MOUSE_LL,
+          Some(mouse_proc),
+          HINSTANCE::default(),
+          0,
+        )
+        .inspect_err(|_| {
+          let _ = UnhookWindowsHookEx(keyboard_hook);
+        })
+        .map(|mouse_hook| (keyboard_hook, mouse_hook))
+      })
+    };
+
+    let (keyboard_hook, mouse_hook) = match hooks {
+      Ok(hooks) => hooks,
+      Err(err) => {
+        let _ = init_tx.send(Err(err));
+        return;
+      }
+    };
+
+    let _ = init_tx.send(Ok(unsafe { GetCurrentThreadId() }));
+
+    let mut msg = MSG::default();
+    while unsafe { GetMessageW(&mut msg, HWND::default(), 0, 0) }.as_bool()
+    {
+      unsafe { DispatchMessageW(&msg) };
+    }
+
+    unsafe {
+      let _ = UnhookWindowsHookEx(keyboard_hook);
+      let _ = UnhookWindowsHookEx(mouse_hook);
+    }
+  });
+
+  init_rx.recv()?.context("Failed to install input hooks.")
+}
+
+unsafe extern "system" fn keyboard_proc(
+  code: i32,
+  wparam: WPARAM,
+  lparam: LPARAM,
+) -> LRESULT {
+  if code >= 0 {
+    let vk_code = (*(lparam.0 as *const KBDLLHOOKSTRUCT)).vkCode as usize;
+
+    PRESSED_KEYS.with_borrow_mut(|pressed_keys| {
+      let Some(is_pressed) = pressed_keys.get_mut(vk_code) else {
+        return;
+      };
+
+      match wparam.0 as u32 {
+        WM_KEYDOWN | WM_SYSKEYDOWN if !*is_pressed => {
+          *is_pressed = true;
+          KEYSTROKES.fetch_add(1, Ordering::Relaxed);
+        }
+        WM_KEYUP | WM_SYSKEYUP => *is_pressed = false,
+        _ => {}
+      }
+    });
+  }
+
+  CallNextHookEx(HHOOK::default(), code, wparam, lparam)
+}
+
+unsafe extern "system" fn mouse_proc(
+  code: i32,
+  wparam: WPARAM,
+  lparam: LPARAM,
+) -> LRESULT {
+  if code >= 0
+    && matches!(
+      wparam.0 as u32,
+      WM_LBUTTONDOWN | WM_RBUTTONDOWN | WM_MBUTTONDOWN | WM_XBUTTONDOWN
+    )
+  {
+    CLICKS.fetch_add(1, Ordering::Relaxed);
+  }
+
+  CallNextHookEx(HHOOK::default(), code, wparam, lparam)
+}
@@ -0,0 +1,157 @@
+use std::{
+  collections::VecDeque,
+  time::{Duration, Instant},
+};
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[cfg(target_os = "linux")]
+use super::input_hook_linux::InputHook;
+#[cfg(windows)]
+use super::input_hook_windows::InputHook;
+use crate::{
+  common::{IntervalOptions, SyncInterval},
+  providers::{
+    CommonProviderState, Provider, ProviderInputMsg, RuntimeType,
+  },
+};
+
+#[derive(Deserialize, Debug, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct InputStatsProviderConfig {
+  #[serde(deserialize_with = "crate::common::deserialize_duration_ms")]
+  #[schemars(schema_with = "crate::common::duration_schema")]
+  pub refresh_interval: u64,
+
+  /// Alignment and jitter of refreshes.
+  #[serde(default)]
+  pub refresh_options: IntervalOptions,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct InputStatsOutput {
+  /// Keystrokes since the last refresh. Held keys are counted once.
+  pub keystrokes: u64,
+
+  /// Mouse clicks since the last refresh.
+  pub clicks: u64,
+
+  /// Keystrokes within the last minute.
+  pub keystrokes_per_minute: u64,
+
+  /// Mouse clicks within the last minute.
+  pub clicks_per_minute: u64,
+
+  /// Keystrokes since the provider started.
+  pub total_keystrokes: u64,
+
+  /// Mouse clicks since the provider started.
+  pub total_clicks: u64,
+}
+
+pub struct InputStatsProvider {
+  config: InputStatsProviderConfig,
+  common: CommonProviderState,
+
+  /// Totals from the hook when the provider started.
+  start_totals: (u64, u64),
+
+  /// Totals from the hook at each refresh within the last minute,
+  /// oldest first.
+  samples: VecDeque<(Instant, (u64, u64))>,
+}
+
+impl InputStatsProvider {
+  pub fn new(
+    config: InputStatsProviderConfig,
+    common: CommonProviderState,
+  ) -> InputStatsProvider {
+    InputStatsProvider {
+      config,
+      common,
+      start_totals: (0, 0),
+      samples: VecDeque::new(),
+    }
+  }
+
+  fn run_interval(&mut self, hook: &InputHook) -> InputStatsOutput {
+    let now = Instant::now();
+    let totals = hook.totals();
+
+    let prev_totals = self
+      .samples
+      .back()
+      .map_or(self.start_totals, |(_, totals)| *totals);
+
+    // Keep the newest sample that's at least a minute old as the
+    // baseline for per-minute counts.
+    while self.samples.get(1).is_some_and(|(time, _)| {
+      now.duration_since(*time) >= Duration::from_secs(60)
+    }) {
+      self.samples.pop_front();
+    }
+
+    let minute_totals = self
+      .samples
+      .front()
+      .filter(|(time, _)| {
+        now.duration_since(*time) >= Duration::from_secs(60)
+      })
+      .map_or(self.start_totals, |(_, totals)| *totals);
+
+    self.samples.push_back((now, totals));
+
+    InputStatsOutput {
+      keystrokes: totals.0 - prev_totals.0,
+      clicks: totals.1 - prev_totals.1,
+      keystrokes_per_minute: totals.0 - minute_totals.0,
+      clicks_per_minute: totals.1 - minute_totals.1,
+      total_keystrokes: totals.0 - self.start_totals.0,
+      total_clicks: totals.1 - self.start_totals.1,
+    }
+  }
+
+  fn start(&mut self) -> anyhow::Result<()> {
+    let hook = InputHook::install()?;
+    self.start_totals = hook.totals();
+
+    let mut interval = SyncInterval::with_options(
+      self.config.refresh_interval,
+      &self.config.refresh_options,
+    );
+
+    loop {
+      crossbeam::select! {
+        recv(interval.tick()) -> _ => {
+          let output = self.run_interval(&hook);
+          self.common.emitter.emit_output_cached(Ok(output));
+        }
+        recv(self.common.input.sync_rx) -> input => {
+          if let Ok(ProviderInputMsg::Stop) = input {
+            break;
+          }
+        }
+      }
+    }
+
+    Ok(())
+  }
+}
+
+impl Provider for InputStatsProvider {
+  fn runtime_type(&self) -> RuntimeType {
+    RuntimeType::Sync
+  }
+
+  fn start_sync(&mut self) {
+    if let Err(err) = self.start() {
+      tracing::error!("Error starting input stats provider: {}", err);
+      self
+        .common
+        .emitter
+        .emit_output::<InputStatsOutput>(Err(err));
+    }
+  }
+}
@@ -0,0 +1,7 @@
+#[cfg(target_os = "linux")]
+mod input_hook_linux;
+#[cfg(windows)]
+mod input_hook_windows;
+mod input_stats_provider;
+
+pub use input_stats_provider::*;
@@ -1,23 +1,33 @@
 use anyhow::Context;
 use async_trait::async_trait;
-use reqwest::Client;
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 use super::ipinfo_res::IpinfoRes;
 use crate::{
-  common::AsyncInterval,
+  common::{AsyncInterval, IntervalOptions},
   providers::{
+    common::{HttpClient, TlsConfig},
     CommonProviderState, Provider, ProviderInputMsg, RuntimeType,
   },
 };
 
-#[derive(Deserialize, Debug)]
+#[derive(Deserialize, Debug, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct IpProviderConfig {
+  #[serde(deserialize_with = "crate::common::deserialize_duration_ms")]
+  #[schemars(schema_with = "crate::common::duration_schema")]
   pub refresh_interval: u64,
+
+  /// Alignment and jitter of refreshes.
+  #[serde(default)]
+  pub refresh_options: IntervalOptions,
+
+  /// TLS options for requests to IPinfo.
+  pub tls: Option<TlsConfig>,
 }
 
-#[derive(Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct IpOutput {
   pub address: String,
@@ -30,7 +40,7 @@ pub struct IpOutput {
 pub struct IpProvider {
   config: IpProviderConfig,
   common: CommonProviderState,
-  http_client: Client,
+  http_client: HttpClient,
 }
 
 impl IpProvider {
@@ -40,8 +50,8 @@ impl IpProvider {
   ) -> IpProvider {
     IpProvider {
       config,
+      http_client: common.http_client.clone(),
       common,
-      http_client: Client::new(),
     }
   }
 
@@ -49,7 +59,9 @@ impl IpProvider {
     Self::query_ip(&self.http_client).await
   }
 
-  pub async fn query_ip(http_client: &Client) -> anyhow::Result<IpOutput> {
+  pub async fn query_ip(
+    http_client: &HttpClient,
+  ) -> anyhow::Result<IpOutput> {
     let res = http_client
       .get("https://ipinfo.io/json")
       .send()
@@ -82,7 +94,11 @@ impl Provider for IpProvider {
   }
 
   async fn start_async(&mut self) {
-    let mut interval = AsyncInterval::new(self.config.refresh_interval);
+    let mut interval = AsyncInterval::with_options(
+      self.config.refresh_interval,
+      &self.config.refresh_options,
+    )
+    .refresh_on_wake();
 
     loop {
       tokio::select! {
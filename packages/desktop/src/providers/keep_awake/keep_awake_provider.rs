@@ -0,0 +1,66 @@
+use async_trait::async_trait;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+  keep_awake::KEEP_AWAKE,
+  providers::{
+    CommonProviderState, Provider, ProviderInputMsg, RuntimeType,
+  },
+};
+
+#[derive(Deserialize, Debug, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct KeepAwakeProviderConfig {}
+
+#[derive(Debug, Clone, PartialEq, Serialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct KeepAwakeOutput {
+  /// Whether Zebar is currently preventing the system from sleeping.
+  pub is_active: bool,
+}
+
+pub struct KeepAwakeProvider {
+  _config: KeepAwakeProviderConfig,
+  common: CommonProviderState,
+}
+
+impl KeepAwakeProvider {
+  pub fn new(
+    config: KeepAwakeProviderConfig,
+    common: CommonProviderState,
+  ) -> KeepAwakeProvider {
+    KeepAwakeProvider {
+      _config: config,
+      common,
+    }
+  }
+}
+
+#[async_trait]
+impl Provider for KeepAwakeProvider {
+  fn runtime_type(&self) -> RuntimeType {
+    RuntimeType::Async
+  }
+
+  async fn start_async(&mut self) {
+    let mut state_rx = KEEP_AWAKE.subscribe();
+
+    loop {
+      let is_active = *state_rx.borrow_and_update();
+      self
+        .common
+        .emitter
+        .emit_output(Ok(KeepAwakeOutput { is_active }));
+
+      tokio::select! {
+        Ok(_) = state_rx.changed() => {}
+        Some(message) = self.common.input.async_rx.recv() => {
+          if let ProviderInputMsg::Stop = message {
+            break;
+          }
+        }
+      }
+    }
+  }
+}
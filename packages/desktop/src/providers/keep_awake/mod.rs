@@ -0,0 +1,3 @@
+mod keep_awake_provider;
+
+pub use keep_awake_provider::*;
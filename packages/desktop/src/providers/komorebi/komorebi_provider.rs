@@ -8,6 +8,7 @@ use async_trait::async_trait;
 use komorebi_client::{
   Container, Monitor, SocketMessage, Window, Workspace,
 };
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use tracing::debug;
 
@@ -15,15 +16,19 @@ use super::{
   KomorebiContainer, KomorebiLayout, KomorebiLayoutFlip, KomorebiMonitor,
   KomorebiWindow, KomorebiWorkspace,
 };
-use crate::providers::{CommonProviderState, Provider, RuntimeType};
+use crate::providers::{
+  CommonProviderState, KomorebiFunction, Provider, ProviderEmitter,
+  ProviderFunction, ProviderFunctionResponse, ProviderInputMsg,
+  RuntimeType,
+};
 
 const SOCKET_NAME: &str = "zebar.sock";
 
-#[derive(Deserialize, Debug)]
+#[derive(Deserialize, Debug, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct KomorebiProviderConfig {}
 
-#[derive(Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct KomorebiOutput {
   pub all_monitors: Vec<KomorebiMonitor>,
@@ -42,7 +47,7 @@ impl KomorebiProvider {
     KomorebiProvider { common }
   }
 
-  fn create_socket(&mut self) -> anyhow::Result<()> {
+  fn create_socket(emitter: &ProviderEmitter) -> anyhow::Result<()> {
     let socket = komorebi_client::subscribe(SOCKET_NAME)
       .context("Failed to initialize Komorebi socket.")?;
 
@@ -77,12 +82,12 @@ impl KomorebiProvider {
               &String::from_utf8(buffer).unwrap(),
             )
           {
-            self.common.emitter.emit_output(Ok(Self::transform_response(
+            emitter.emit_output(Ok(Self::transform_response(
               notification.state,
             )));
           }
         }
-        Err(_) => self.common.emitter.emit_output::<KomorebiOutput>(Err(
+        Err(_) => emitter.emit_output::<KomorebiOutput>(Err(
           anyhow::anyhow!("Failed to read Komorebi stream."),
         )),
       }
@@ -91,6 +96,29 @@ impl KomorebiProvider {
     Ok(())
   }
 
+  fn handle_function(
+    &mut self,
+    function: KomorebiFunction,
+  ) -> anyhow::Result<ProviderFunctionResponse> {
+    let message = match function {
+      KomorebiFunction::FocusWorkspace(args) => match args.monitor_index {
+        Some(monitor_index) => SocketMessage::FocusMonitorWorkspaceNumber(
+          monitor_index,
+          args.workspace_index,
+        ),
+        None => SocketMessage::FocusWorkspaceNumber(args.workspace_index),
+      },
+      KomorebiFunction::FocusMonitor(args) => {
+        SocketMessage::FocusMonitorNumber(args.monitor_index)
+      }
+    };
+
+    komorebi_client::send_message(&message)
+      .context("Failed to send message to Komorebi.")?;
+
+    Ok(ProviderFunctionResponse::Null)
+  }
+
   fn transform_response(state: komorebi_client::State) -> KomorebiOutput {
     let all_monitors = state
       .monitors
@@ -179,8 +207,31 @@ impl Provider for KomorebiProvider {
   }
 
   fn start_sync(&mut self) {
-    if let Err(err) = self.create_socket() {
-      self.common.emitter.emit_output::<KomorebiOutput>(Err(err));
+    let emitter = self.common.emitter.clone();
+
+    // Listening on the socket blocks indefinitely, so it's done on a
+    // separate thread to be able to handle function calls.
+    std::thread::spawn(move || {
+      if let Err(err) = Self::create_socket(&emitter) {
+        emitter.emit_output::<KomorebiOutput>(Err(err));
+      }
+    });
+
+    while let Ok(input) = self.common.input.sync_rx.recv() {
+      match input {
+        ProviderInputMsg::Stop => break,
+        ProviderInputMsg::Function(
+          ProviderFunction::Komorebi(komorebi_function),
+          sender,
+        ) => {
+          let res = self
+            .handle_function(komorebi_function)
+            .map_err(|err| err.to_string());
+
+          sender.send(res).unwrap();
+        }
+        _ => {}
+      }
     }
   }
 }
@@ -1,25 +1,40 @@
 use komorebi_client::{Axis, DefaultLayout, Layout, Rect};
+use schemars::JsonSchema;
 use serde::Serialize;
 
-#[derive(Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct KomorebiMonitor {
   pub id: isize,
   pub device_id: String,
   pub focused_workspace_index: usize,
   pub name: String,
+  #[schemars(with = "KomorebiRect")]
   pub size: Rect,
+  #[schemars(with = "Option<KomorebiRect>")]
   pub work_area_offset: Option<Rect>,
+  #[schemars(with = "KomorebiRect")]
   pub work_area_size: Rect,
   pub workspaces: Vec<KomorebiWorkspace>,
 }
 
-#[derive(Debug, Clone, PartialEq, Serialize)]
+/// Schema of komorebi's `Rect`, which doesn't implement `JsonSchema`.
+#[derive(JsonSchema)]
+#[allow(dead_code)]
+struct KomorebiRect {
+  left: i32,
+  top: i32,
+  right: i32,
+  bottom: i32,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct KomorebiWorkspace {
   pub container_padding: Option<i32>,
   pub floating_windows: Vec<KomorebiWindow>,
   pub focused_container_index: usize,
+  #[schemars(with = "Vec<KomorebiRect>")]
   pub latest_layout: Vec<Rect>,
   pub layout: KomorebiLayout,
   pub layout_flip: Option<KomorebiLayoutFlip>,
@@ -30,14 +45,14 @@ pub struct KomorebiWorkspace {
   pub workspace_padding: Option<i32>,
 }
 
-#[derive(Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct KomorebiContainer {
   pub id: String,
   pub windows: Vec<KomorebiWindow>,
 }
 
-#[derive(Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct KomorebiWindow {
   pub class: Option<String>,
@@ -46,7 +61,7 @@ pub struct KomorebiWindow {
   pub title: Option<String>,
 }
 
-#[derive(Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum KomorebiLayout {
   Bsp,
@@ -81,7 +96,7 @@ impl From<Layout> for KomorebiLayout {
   }
 }
 
-#[derive(Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum KomorebiLayoutFlip {
   Horizontal,
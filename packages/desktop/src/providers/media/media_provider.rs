@@ -5,6 +5,7 @@ use std::{
 
 use anyhow::Context;
 use crossbeam::channel::{unbounded, Receiver, Sender};
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use tracing::{debug, warn};
 use windows::{
@@ -14,25 +15,27 @@ use windows::{
     GlobalSystemMediaTransportControlsSessionManager as GsmtcManager,
     GlobalSystemMediaTransportControlsSessionPlaybackStatus as GsmtcPlaybackStatus,
   },
+  Storage::Streams::{DataReader, IRandomAccessStreamReference},
 };
 
 use crate::providers::{
-  CommonProviderState, MediaFunction, Provider, ProviderFunction,
-  ProviderFunctionResponse, ProviderInputMsg, RuntimeType,
+  CommonProviderState, MediaFunction, Provider, ProviderAttachment,
+  ProviderEmitter, ProviderFunction, ProviderFunctionResponse,
+  ProviderInputMsg, RuntimeType,
 };
 
-#[derive(Deserialize, Debug)]
+#[derive(Deserialize, Debug, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct MediaProviderConfig {}
 
-#[derive(Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct MediaOutput {
   pub current_session: Option<MediaSession>,
   pub all_sessions: Vec<MediaSession>,
 }
 
-#[derive(Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct MediaSession {
   pub session_id: String,
@@ -41,6 +44,9 @@ pub struct MediaSession {
   pub album_title: Option<String>,
   pub album_artist: Option<String>,
   pub track_number: u32,
+
+  /// Album art of the current track.
+  pub thumbnail: Option<ProviderAttachment>,
   pub start_time: u64,
   pub end_time: u64,
   pub position: u64,
@@ -57,6 +63,7 @@ impl Default for MediaSession {
       album_title: None,
       album_artist: None,
       track_number: 0,
+      thumbnail: None,
       start_time: 0,
       end_time: 0,
       position: 0,
@@ -214,6 +221,7 @@ impl MediaProvider {
           Self::update_media_properties(
             &mut session_state.output,
             &session_state.session,
+            &self.common.emitter,
           )?;
         }
       }
@@ -333,7 +341,11 @@ impl MediaProvider {
         let session_state = SessionState {
           tokens: self
             .register_session_callbacks(&session, &session_id)?,
-          output: Self::to_media_session_output(&session, &session_id)?,
+          output: Self::to_media_session_output(
+            &session,
+            &session_id,
+            &self.common.emitter,
+          )?,
           session,
         };
 
@@ -474,11 +486,12 @@ impl MediaProvider {
   fn to_media_session_output(
     session: &GsmtcSession,
     session_id: &str,
+    emitter: &ProviderEmitter,
   ) -> anyhow::Result<MediaSession> {
     let mut session_output = MediaSession::default();
 
     session_output.session_id = session_id.to_string();
-    Self::update_media_properties(&mut session_output, &session)?;
+    Self::update_media_properties(&mut session_output, &session, emitter)?;
     Self::update_timeline_properties(&mut session_output, &session)?;
     Self::update_playback_info(&mut session_output, &session)?;
 
@@ -489,6 +502,7 @@ impl MediaProvider {
   fn update_media_properties(
     session_output: &mut MediaSession,
     session: &GsmtcSession,
+    emitter: &ProviderEmitter,
   ) -> anyhow::Result<()> {
     let properties = session.TryGetMediaPropertiesAsync()?.get()?;
 
@@ -505,9 +519,38 @@ impl MediaProvider {
       (!album_artist.is_empty()).then_some(album_artist);
     session_output.track_number = properties.TrackNumber()? as u32;
 
+    // Thumbnails are optional, so failing to read one shouldn't prevent
+    // the rest of the session from being emitted.
+    session_output.thumbnail = match properties.Thumbnail() {
+      Ok(thumbnail) => Self::read_thumbnail(&thumbnail, emitter)
+        .inspect_err(|err| {
+          warn!("Failed to read media thumbnail: {}", err)
+        })
+        .ok(),
+      Err(_) => None,
+    };
+
     Ok(())
   }
 
+  /// Reads a thumbnail stream and stores it as a provider attachment.
+  fn read_thumbnail(
+    thumbnail: &IRandomAccessStreamReference,
+    emitter: &ProviderEmitter,
+  ) -> anyhow::Result<ProviderAttachment> {
+    let stream = thumbnail.OpenReadAsync()?.get()?;
+    let size = stream.Size()? as u32;
+    let mime_type = stream.ContentType()?.to_string();
+
+    let reader = DataReader::CreateDataReader(&stream)?;
+    reader.LoadAsync(size)?.get()?;
+
+    let mut data = vec![0u8; size as usize];
+    reader.ReadBytes(&mut data)?;
+
+    Ok(emitter.attach(data, &mime_type))
+  }
+
   /// Updates timeline properties (position/duration) in a `MediaSession`.
   fn update_timeline_properties(
     session_output: &mut MediaSession,
@@ -0,0 +1,60 @@
+use windows::Win32::System::Registry::HKEY_CURRENT_USER;
+
+use super::CaptureDevice;
+use crate::common::windows::RegKey;
+
+/// Registry path where Windows tracks capability usage per app.
+const CONSENT_STORE_PATH: &str = "Software\\Microsoft\\Windows\\CurrentVersion\\CapabilityAccessManager\\ConsentStore";
+
+/// Whether the app key is currently using the capability. Windows sets
+/// `LastUsedTimeStop` to 0 while the capability is in use.
+fn is_in_use(key: &RegKey) -> bool {
+  key.qword_value("LastUsedTimeStart").unwrap_or(0) != 0
+    && key.qword_value("LastUsedTimeStop") == Some(0)
+}
+
+/// Gets the apps currently using the given capture device via the
+/// capability access manager's consent store.
+///
+/// Packaged apps are listed by their package name, and non-packaged apps
+/// by their executable path.
+pub fn apps_using_device(
+  device: CaptureDevice,
+) -> anyhow::Result<Vec<String>> {
+  let capability = match device {
+    CaptureDevice::Camera => "webcam",
+    CaptureDevice::Microphone => "microphone",
+  };
+
+  let Some(capability_key) = RegKey::open(
+    HKEY_CURRENT_USER,
+    &format!("{}\\{}", CONSENT_STORE_PATH, capability),
+  ) else {
+    return Ok(Vec::new());
+  };
+
+  let mut apps = Vec::new();
+
+  for subkey_name in capability_key.subkeys() {
+    let Some(app_key) = RegKey::open(capability_key.0, &subkey_name)
+    else {
+      continue;
+    };
+
+    // Non-packaged (i.e. Win32) apps are nested one level deeper, with
+    // `#` in place of path separators.
+    if subkey_name == "NonPackaged" {
+      for exe_name in app_key.subkeys() {
+        if RegKey::open(app_key.0, &exe_name)
+          .is_some_and(|exe_key| is_in_use(&exe_key))
+        {
+          apps.push(exe_name.replace('#', "\\"));
+        }
+      }
+    } else if is_in_use(&app_key) {
+      apps.push(subkey_name);
+    }
+  }
+
+  Ok(apps)
+}
@@ -0,0 +1,102 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[cfg(target_os = "windows")]
+use super::consent_store::apps_using_device;
+#[cfg(target_os = "linux")]
+use super::pipewire::apps_using_device;
+use crate::{
+  common::{IntervalOptions, SyncInterval},
+  providers::{
+    CommonProviderState, Provider, ProviderInputMsg, RuntimeType,
+  },
+};
+
+#[derive(Deserialize, Debug, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct MeetingProviderConfig {
+  #[serde(deserialize_with = "crate::common::deserialize_duration_ms")]
+  #[schemars(schema_with = "crate::common::duration_schema")]
+  pub refresh_interval: u64,
+
+  /// Alignment and jitter of refreshes.
+  #[serde(default)]
+  pub refresh_options: IntervalOptions,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct MeetingOutput {
+  pub is_camera_in_use: bool,
+  pub is_microphone_in_use: bool,
+  pub camera_apps: Vec<String>,
+  pub microphone_apps: Vec<String>,
+}
+
+/// Capture device to check usage of.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CaptureDevice {
+  Camera,
+  Microphone,
+}
+
+pub struct MeetingProvider {
+  config: MeetingProviderConfig,
+  common: CommonProviderState,
+}
+
+impl MeetingProvider {
+  pub fn new(
+    config: MeetingProviderConfig,
+    common: CommonProviderState,
+  ) -> MeetingProvider {
+    MeetingProvider { config, common }
+  }
+
+  fn run_interval(&self) -> anyhow::Result<MeetingOutput> {
+    #[cfg(target_os = "macos")]
+    {
+      anyhow::bail!("Meeting provider is not yet supported on MacOS.")
+    }
+
+    #[cfg(not(target_os = "macos"))]
+    {
+      let camera_apps = apps_using_device(CaptureDevice::Camera)?;
+      let microphone_apps = apps_using_device(CaptureDevice::Microphone)?;
+
+      Ok(MeetingOutput {
+        is_camera_in_use: !camera_apps.is_empty(),
+        is_microphone_in_use: !microphone_apps.is_empty(),
+        camera_apps,
+        microphone_apps,
+      })
+    }
+  }
+}
+
+impl Provider for MeetingProvider {
+  fn runtime_type(&self) -> RuntimeType {
+    RuntimeType::Sync
+  }
+
+  fn start_sync(&mut self) {
+    let mut interval = SyncInterval::with_options(
+      self.config.refresh_interval,
+      &self.config.refresh_options,
+    );
+
+    loop {
+      crossbeam::select! {
+        recv(interval.tick()) -> _ => {
+          let output = self.run_interval();
+          self.common.emitter.emit_output_cached(output);
+        }
+        recv(self.common.input.sync_rx) -> input => {
+          if let Ok(ProviderInputMsg::Stop) = input {
+            break;
+          }
+        }
+      }
+    }
+  }
+}
@@ -0,0 +1,9 @@
+#[cfg(target_os = "windows")]
+mod consent_store;
+mod meeting_provider;
+#[cfg(target_os = "linux")]
+mod pipewire;
+
+#[cfg(target_os = "windows")]
+pub use consent_store::apps_using_device;
+pub use meeting_provider::*;
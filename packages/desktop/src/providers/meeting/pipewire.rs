@@ -0,0 +1,49 @@
+use std::process::Command;
+
+use anyhow::Context;
+use serde_json::Value;
+
+use super::CaptureDevice;
+
+/// Gets the apps currently using the given capture device via
+/// `pw-dump`.
+///
+/// Running input streams are PipeWire nodes with a media class of
+/// `Stream/Input/Audio` or `Stream/Input/Video`.
+pub fn apps_using_device(
+  device: CaptureDevice,
+) -> anyhow::Result<Vec<String>> {
+  let media_class = match device {
+    CaptureDevice::Camera => "Stream/Input/Video",
+    CaptureDevice::Microphone => "Stream/Input/Audio",
+  };
+
+  let output = Command::new("pw-dump")
+    .output()
+    .context("Unable to run `pw-dump`. Is PipeWire installed?")?;
+
+  let objects = serde_json::from_slice::<Vec<Value>>(&output.stdout)
+    .context("Failed to parse `pw-dump` output.")?;
+
+  let mut apps = objects
+    .iter()
+    .filter(|object| object["info"]["state"] == "running")
+    .filter_map(|object| {
+      let props = &object["info"]["props"];
+
+      if props["media.class"] != media_class {
+        return None;
+      }
+
+      props["application.name"]
+        .as_str()
+        .or_else(|| props["node.name"].as_str())
+        .map(String::from)
+    })
+    .collect::<Vec<_>>();
+
+  apps.sort();
+  apps.dedup();
+
+  Ok(apps)
+}
@@ -1,27 +1,73 @@
-#[cfg(windows)]
+#[cfg(any(windows, target_os = "linux"))]
 mod audio;
 mod battery;
+mod camera;
+mod ci_status;
+mod common;
+mod countdown;
 mod cpu;
+mod derived;
+mod discord;
 mod disk;
+#[cfg(any(windows, target_os = "linux"))]
+mod event_log;
+#[cfg(windows)]
+mod fancy_zones;
+#[cfg(windows)]
+mod fps;
 mod host;
+#[cfg(target_os = "linux")]
+mod hyprland;
+#[cfg(target_os = "linux")]
+mod i3;
+#[cfg(any(windows, target_os = "linux"))]
+mod input_stats;
 mod ip;
+mod keep_awake;
 #[cfg(windows)]
 mod keyboard;
 #[cfg(windows)]
 mod komorebi;
 #[cfg(windows)]
 mod media;
+mod meeting;
 mod memory;
 mod network;
+#[cfg(any(windows, target_os = "linux"))]
+mod perf_counters;
+mod peripherals;
+mod printer;
 mod provider;
+mod provider_attachment;
 mod provider_config;
+mod provider_config_error;
+mod provider_endpoints;
 mod provider_function;
 mod provider_manager;
 mod provider_output;
+mod provider_schema;
+mod provider_supervisor;
+#[cfg(any(windows, target_os = "linux"))]
+mod session;
+mod spotify;
+mod ssh_gpg;
+mod steam;
+mod sync_provider_threads;
+mod tasks;
+mod timer;
+#[cfg(windows)]
+mod virtual_desktop;
+mod vpn;
 mod weather;
 
 pub use provider::*;
+pub use provider_attachment::*;
 pub use provider_config::*;
+pub use provider_config_error::*;
+pub use provider_endpoints::*;
 pub use provider_function::*;
 pub use provider_manager::*;
 pub use provider_output::*;
+pub use provider_schema::*;
+#[cfg(any(windows, target_os = "linux"))]
+pub use session::current_user_session;
@@ -1,7 +1,8 @@
 use netdev::interface::InterfaceType as NdInterfaceType;
+use schemars::JsonSchema;
 use serde::Serialize;
 
-#[derive(Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct NetworkTraffic {
   pub received: NetworkTrafficMeasure,
@@ -10,7 +11,7 @@ pub struct NetworkTraffic {
   pub total_transmitted: NetworkTrafficMeasure,
 }
 
-#[derive(Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct NetworkTrafficMeasure {
   pub bytes: u64,
@@ -20,7 +21,7 @@ pub struct NetworkTrafficMeasure {
   pub iec_unit: String,
 }
 
-#[derive(Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct NetworkInterface {
   pub name: String,
@@ -37,7 +38,7 @@ pub struct NetworkInterface {
   pub is_default: bool,
 }
 
-#[derive(Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct NetworkGateway {
   pub mac_address: String,
@@ -47,7 +48,7 @@ pub struct NetworkGateway {
   pub signal_strength: Option<u32>,
 }
 
-#[derive(Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum InterfaceType {
   Unknown,
@@ -1,3 +1,4 @@
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use sysinfo::Networks;
 
@@ -7,19 +8,25 @@ use super::{
   NetworkTrafficMeasure,
 };
 use crate::{
-  common::{to_iec_bytes, to_si_bytes, SyncInterval},
+  common::{to_iec_bytes, to_si_bytes, IntervalOptions, SyncInterval},
   providers::{
     CommonProviderState, Provider, ProviderInputMsg, RuntimeType,
   },
 };
 
-#[derive(Deserialize, Debug)]
+#[derive(Deserialize, Debug, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct NetworkProviderConfig {
+  #[serde(deserialize_with = "crate::common::deserialize_duration_ms")]
+  #[schemars(schema_with = "crate::common::duration_schema")]
   pub refresh_interval: u64,
+
+  /// Alignment and jitter of refreshes.
+  #[serde(default)]
+  pub refresh_options: IntervalOptions,
 }
 
-#[derive(Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct NetworkOutput {
   pub default_interface: Option<NetworkInterface>,
@@ -66,10 +73,13 @@ impl NetworkProvider {
         .map(Self::transform_interface),
       default_gateway: default_interface
         .and_then(|interface| interface.gateway)
-        .and_then(|gateway| {
-          default_gateway_wifi()
-            .map(|wifi| Self::transform_gateway(&gateway, wifi))
-            .ok()
+        .map(|gateway| {
+          // Wifi info is optional, so that wired gateways are still
+          // reported if it can't be retrieved.
+          let wifi =
+            default_gateway_wifi().unwrap_or_else(|_| WifiHotstop::none());
+
+          Self::transform_gateway(&gateway, wifi)
         }),
       interfaces: interfaces
         .iter()
@@ -201,7 +211,10 @@ impl Provider for NetworkProvider {
   }
 
   fn start_sync(&mut self) {
-    let mut interval = SyncInterval::new(self.config.refresh_interval);
+    let mut interval = SyncInterval::with_options(
+      self.config.refresh_interval,
+      &self.config.refresh_options,
+    );
 
     loop {
       crossbeam::select! {
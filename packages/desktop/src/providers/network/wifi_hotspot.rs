@@ -3,7 +3,9 @@ use std::ffi::c_void;
 use anyhow::Context;
 #[cfg(target_os = "windows")]
 use windows::Win32::{
-  Foundation::{HANDLE, INVALID_HANDLE_VALUE, WIN32_ERROR},
+  Foundation::{
+    ERROR_SERVICE_NOT_ACTIVE, HANDLE, INVALID_HANDLE_VALUE, WIN32_ERROR,
+  },
   NetworkManagement::WiFi::{
     wlan_intf_opcode_current_connection, WlanCloseHandle,
     WlanEnumInterfaces, WlanFreeMemory, WlanOpenHandle,
@@ -30,30 +32,43 @@ impl Drop for WlanHandle {
   }
 }
 
+impl WifiHotstop {
+  pub(crate) fn none() -> Self {
+    Self {
+      ssid: None,
+      signal_strength: None,
+    }
+  }
+}
+
 /// Gets wifi ssid and signal strength using winapi
+///
+/// Returns no wifi info if the device has no wireless interfaces or the
+/// WLAN service isn't running (e.g. on Windows Server or ARM dev kits).
 pub fn default_gateway_wifi() -> anyhow::Result<WifiHotstop> {
   #[cfg(not(target_os = "windows"))]
   {
-    Ok(WifiHotstop {
-      ssid: None,
-      signal_strength: None,
-    })
+    Ok(WifiHotstop::none())
   }
   #[cfg(target_os = "windows")]
   {
     let mut pdw_negotiated_version = 0;
     let mut wlan_handle = WlanHandle(INVALID_HANDLE_VALUE);
 
-    WIN32_ERROR(unsafe {
+    let res = WIN32_ERROR(unsafe {
       WlanOpenHandle(
         2,
         None,
         &mut pdw_negotiated_version,
         &mut wlan_handle.0,
       )
-    })
-    .ok()
-    .context("Failed to open Wlan handle")?;
+    });
+
+    if res == ERROR_SERVICE_NOT_ACTIVE {
+      return Ok(WifiHotstop::none());
+    }
+
+    res.ok().context("Failed to open Wlan handle")?;
 
     let mut wlan_interface_info_list = std::ptr::null_mut();
     WIN32_ERROR(unsafe {
@@ -66,10 +81,18 @@ pub fn default_gateway_wifi() -> anyhow::Result<WifiHotstop> {
     .ok()
     .context("Failed to get Wlan interfaces")?;
 
-    let guid = (unsafe { *wlan_interface_info_list }).InterfaceInfo[0]
-      .InterfaceGuid;
+    let interface_info_list = unsafe { &*wlan_interface_info_list };
+
+    // Reading the first interface is only valid if there is one.
+    let guid = (interface_info_list.dwNumberOfItems > 0)
+      .then(|| interface_info_list.InterfaceInfo[0].InterfaceGuid);
+
     unsafe { WlanFreeMemory(wlan_interface_info_list as *mut c_void) };
 
+    let Some(guid) = guid else {
+      return Ok(WifiHotstop::none());
+    };
+
     let mut data_size = 0;
     let mut pdata = std::ptr::null_mut();
 
@@ -94,16 +117,11 @@ pub fn default_gateway_wifi() -> anyhow::Result<WifiHotstop> {
 
     unsafe { WlanFreeMemory(pdata) };
 
-    // needed to remove leading zeros in array
-    let ssid_arr = atributes.dot11Ssid.ucSSID;
-    let mut ssid_vec = ssid_arr
-      .into_iter()
-      .rev()
-      .skip_while(|&byte| byte == 0)
-      .collect::<Vec<_>>();
-    ssid_vec.reverse();
+    let ssid_len = (atributes.dot11Ssid.uSSIDLength as usize)
+      .min(atributes.dot11Ssid.ucSSID.len());
     let ssid =
-      String::from_utf8(ssid_vec).context("Incorrectly formatted ssid")?;
+      String::from_utf8(atributes.dot11Ssid.ucSSID[..ssid_len].to_vec())
+        .context("Incorrectly formatted ssid")?;
 
     Ok(WifiHotstop {
       ssid: Some(ssid),
@@ -0,0 +1,7 @@
+#[cfg(target_os = "linux")]
+mod perf_counters_linux;
+mod perf_counters_provider;
+#[cfg(windows)]
+mod perf_counters_windows;
+
+pub use perf_counters_provider::*;
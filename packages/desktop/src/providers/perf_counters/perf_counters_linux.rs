@@ -0,0 +1,78 @@
+use std::{
+  fs,
+  path::{Path, PathBuf},
+};
+
+use anyhow::bail;
+
+/// Reads counters from `/proc` and `/sys` files.
+pub struct CounterReader {
+  counters: Vec<FileCounter>,
+}
+
+/// A number within a file, identified by the key at the start of its
+/// line (e.g. `ctxt` in `/proc/stat`).
+struct FileCounter {
+  file: PathBuf,
+
+  /// Key of the line to read. The whole file is parsed as a number if
+  /// `None`.
+  key: Option<String>,
+}
+
+impl CounterReader {
+  /// Creates a reader for paths of the form `<FILE>` or `<FILE>:<KEY>`.
+  pub fn new(paths: &[&str]) -> anyhow::Result<Self> {
+    let counters = paths
+      .iter()
+      .map(|path| {
+        let (file, key) = match path.rsplit_once(':') {
+          Some((file, key)) => (file, Some(key.to_string())),
+          None => (*path, None),
+        };
+
+        if !file.starts_with("/proc/") && !file.starts_with("/sys/") {
+          bail!("Counter path '{}' must be within /proc or /sys.", path);
+        }
+
+        if !Path::new(file).exists() {
+          bail!("Counter file '{}' doesn't exist.", file);
+        }
+
+        Ok(FileCounter {
+          file: PathBuf::from(file),
+          key,
+        })
+      })
+      .collect::<anyhow::Result<_>>()?;
+
+    Ok(Self { counters })
+  }
+
+  /// Reads the current value of each counter. Counters that couldn't
+  /// be read are `None`.
+  pub fn read(&mut self) -> anyhow::Result<Vec<Option<f64>>> {
+    Ok(
+      self
+        .counters
+        .iter()
+        .map(|counter| {
+          let contents = fs::read_to_string(&counter.file).ok()?;
+
+          match &counter.key {
+            None => contents.trim().parse().ok(),
+            Some(key) => contents.lines().find_map(|line| {
+              let mut tokens = line.split_whitespace();
+
+              // Keys are followed by a colon in some files (e.g.
+              // `/proc/meminfo`).
+              (tokens.next()?.trim_end_matches(':') == key)
+                .then(|| tokens.next()?.parse().ok())
+                .flatten()
+            }),
+          }
+        })
+        .collect(),
+    )
+  }
+}
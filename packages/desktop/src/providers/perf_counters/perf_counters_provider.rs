@@ -0,0 +1,161 @@
+use std::{collections::HashMap, time::Instant};
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[cfg(target_os = "linux")]
+use super::perf_counters_linux::CounterReader;
+#[cfg(windows)]
+use super::perf_counters_windows::CounterReader;
+use crate::{
+  common::{IntervalOptions, SyncInterval},
+  providers::{
+    CommonProviderState, Provider, ProviderInputMsg, RuntimeType,
+  },
+};
+
+#[derive(Deserialize, Debug, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct PerfCountersProviderConfig {
+  #[serde(deserialize_with = "crate::common::deserialize_duration_ms")]
+  #[schemars(schema_with = "crate::common::duration_schema")]
+  pub refresh_interval: u64,
+
+  /// Alignment and jitter of refreshes.
+  #[serde(default)]
+  pub refresh_options: IntervalOptions,
+
+  /// Counters to sample.
+  pub counters: Vec<PerfCounterConfig>,
+}
+
+#[derive(Deserialize, Debug, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct PerfCounterConfig {
+  /// Name to output the counter's value under.
+  pub name: String,
+
+  /// On Windows, an English PDH counter path (e.g.
+  /// `\Processor(_Total)\% Interrupt Time`).
+  ///
+  /// On Linux, a file and the key of a line within it (e.g.
+  /// `/proc/stat:ctxt` or `/proc/meminfo:Dirty`), or a file that
+  /// contains a single number (e.g.
+  /// `/sys/class/thermal/thermal_zone0/temp`).
+  pub path: String,
+
+  /// Whether to output the per-second rate of change instead of the
+  /// value itself. Useful for cumulative counters (e.g. context
+  /// switches in `/proc/stat:ctxt`).
+  #[serde(default)]
+  pub rate: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct PerfCountersOutput {
+  /// Latest value of each counter, keyed by name. `null` if the counter
+  /// couldn't be read, or on the first sample of a rate.
+  pub values: HashMap<String, Option<f64>>,
+}
+
+pub struct PerfCountersProvider {
+  config: PerfCountersProviderConfig,
+  common: CommonProviderState,
+
+  /// Raw values from the previous sample, used for calculating rates.
+  prev_sample: Option<(Instant, Vec<Option<f64>>)>,
+}
+
+impl PerfCountersProvider {
+  pub fn new(
+    config: PerfCountersProviderConfig,
+    common: CommonProviderState,
+  ) -> PerfCountersProvider {
+    PerfCountersProvider {
+      config,
+      common,
+      prev_sample: None,
+    }
+  }
+
+  fn run_interval(
+    &mut self,
+    reader: &mut CounterReader,
+  ) -> anyhow::Result<PerfCountersOutput> {
+    let now = Instant::now();
+    let sample = reader.read()?;
+
+    let values = self
+      .config
+      .counters
+      .iter()
+      .enumerate()
+      .map(|(index, counter)| {
+        let value = match counter.rate {
+          false => sample[index],
+          true => self.prev_sample.as_ref().and_then(
+            |(prev_time, prev_values)| {
+              let elapsed = (now - *prev_time).as_secs_f64();
+              let delta = sample[index]? - prev_values[index]?;
+              (elapsed > 0.).then(|| delta / elapsed)
+            },
+          ),
+        };
+
+        (counter.name.clone(), value)
+      })
+      .collect();
+
+    self.prev_sample = Some((now, sample));
+
+    Ok(PerfCountersOutput { values })
+  }
+
+  fn start(&mut self) -> anyhow::Result<()> {
+    let paths = self
+      .config
+      .counters
+      .iter()
+      .map(|counter| counter.path.as_str())
+      .collect::<Vec<_>>();
+
+    let mut reader = CounterReader::new(&paths)?;
+    let mut interval = SyncInterval::with_options(
+      self.config.refresh_interval,
+      &self.config.refresh_options,
+    );
+
+    loop {
+      crossbeam::select! {
+        recv(interval.tick()) -> _ => {
+          let output = self.run_interval(&mut reader);
+          self.common.emitter.emit_output_cached(output);
+        }
+        recv(self.common.input.sync_rx) -> input => {
+          if let Ok(ProviderInputMsg::Stop) = input {
+            break;
+          }
+        }
+      }
+    }
+
+    Ok(())
+  }
+}
+
+impl Provider for PerfCountersProvider {
+  fn runtime_type(&self) -> RuntimeType {
+    RuntimeType::Sync
+  }
+
+  fn start_sync(&mut self) {
+    if let Err(err) = self.start() {
+      tracing::error!("Error starting perf counters provider: {}", err);
+      self
+        .common
+        .emitter
+        .emit_output::<PerfCountersOutput>(Err(err));
+    }
+  }
+}
@@ -0,0 +1,94 @@
+use anyhow::bail;
+use windows::{
+  core::HSTRING,
+  Win32::System::Performance::{
+    PdhAddEnglishCounterW, PdhCloseQuery, PdhCollectQueryData,
+    PdhGetFormattedCounterValue, PdhOpenQueryW, PDH_FMT_COUNTERVALUE,
+    PDH_FMT_DOUBLE, PDH_FMT_NOCAP100, PDH_HCOUNTER, PDH_HQUERY,
+  },
+};
+
+/// Reads counters via the Performance Data Helper (PDH) API.
+pub struct CounterReader {
+  query: PDH_HQUERY,
+  counters: Vec<PDH_HCOUNTER>,
+}
+
+impl CounterReader {
+  /// Creates a reader for the given English counter paths.
+  pub fn new(paths: &[&str]) -> anyhow::Result<Self> {
+    let mut query = PDH_HQUERY::default();
+
+    let status = unsafe { PdhOpenQueryW(None, 0, &mut query) };
+    if status != 0 {
+      bail!("Failed to open PDH query (error {:#x}).", status);
+    }
+
+    // Closes the query on early return.
+    let mut reader = Self {
+      query,
+      counters: Vec::new(),
+    };
+
+    for path in paths {
+      let mut counter = PDH_HCOUNTER::default();
+
+      let status = unsafe {
+        PdhAddEnglishCounterW(
+          reader.query,
+          &HSTRING::from(*path),
+          0,
+          &mut counter,
+        )
+      };
+
+      if status != 0 {
+        bail!("Invalid counter path '{}' (error {:#x}).", path, status);
+      }
+
+      reader.counters.push(counter);
+    }
+
+    // Rate counters (e.g. `% Processor Time`) need two samples, so an
+    // initial sample is collected upfront.
+    unsafe { PdhCollectQueryData(reader.query) };
+
+    Ok(reader)
+  }
+
+  /// Reads the current value of each counter. Counters that couldn't
+  /// be read are `None`.
+  pub fn read(&mut self) -> anyhow::Result<Vec<Option<f64>>> {
+    let status = unsafe { PdhCollectQueryData(self.query) };
+    if status != 0 {
+      bail!("Failed to collect counter data (error {:#x}).", status);
+    }
+
+    Ok(
+      self
+        .counters
+        .iter()
+        .map(|counter| {
+          let mut value = PDH_FMT_COUNTERVALUE::default();
+
+          let status = unsafe {
+            PdhGetFormattedCounterValue(
+              *counter,
+              PDH_FMT_DOUBLE | PDH_FMT_NOCAP100,
+              None,
+              &mut value,
+            )
+          };
+
+          (status == 0).then(|| unsafe { value.Anonymous.doubleValue })
+        })
+        .collect(),
+    )
+  }
+}
+
+impl Drop for CounterReader {
+  fn drop(&mut self) {
+    unsafe { PdhCloseQuery(self.query) };
+  }
+}
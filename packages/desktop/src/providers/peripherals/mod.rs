@@ -0,0 +1,9 @@
+#[cfg(target_os = "linux")]
+mod peripherals_linux;
+#[cfg(target_os = "macos")]
+mod peripherals_macos;
+mod peripherals_provider;
+#[cfg(windows)]
+mod peripherals_windows;
+
+pub use peripherals_provider::*;
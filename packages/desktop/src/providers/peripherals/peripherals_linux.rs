@@ -0,0 +1,94 @@
+use std::{fs, path::Path};
+
+use super::{Peripheral, PeripheralKind};
+
+/// Directory of power supplies in sysfs, which includes the batteries
+/// of peripherals.
+const POWER_SUPPLY_DIR: &str = "/sys/class/power_supply";
+
+/// Name prefixes of power supplies from game controller drivers.
+const CONTROLLER_PREFIXES: [&str; 4] = [
+  "ps-controller",
+  "sony_controller",
+  "nintendo_switch_controller",
+  "xpadneo",
+];
+
+/// Gets peripherals that have a battery, via their power supplies in
+/// sysfs. Devices without a battery aren't listed.
+pub fn peripherals() -> anyhow::Result<Vec<Peripheral>> {
+  let Ok(entries) = fs::read_dir(POWER_SUPPLY_DIR) else {
+    return Ok(Vec::new());
+  };
+
+  Ok(
+    entries
+      .flatten()
+      .filter_map(|entry| {
+        let path = entry.path();
+        let id = entry.file_name().to_string_lossy().to_string();
+
+        // Batteries of the system itself (e.g. laptop batteries) have a
+        // scope of `System` or none at all.
+        if read_attr(&path, "scope").as_deref() != Some("Device") {
+          return None;
+        }
+
+        let name = read_attr(&path, "model_name")
+          .filter(|name| !name.is_empty())
+          .unwrap_or_else(|| id.clone());
+
+        let kind = if id.starts_with("wacom") {
+          PeripheralKind::Stylus
+        } else if CONTROLLER_PREFIXES
+          .iter()
+          .any(|prefix| id.starts_with(prefix))
+        {
+          PeripheralKind::GameController
+        } else {
+          PeripheralKind::from_name(&name)
+        };
+
+        // Some drivers only report a coarse level rather than a
+        // percentage.
+        let battery_percent = read_attr(&path, "capacity")
+          .and_then(|capacity| capacity.parse::<u8>().ok())
+          .or_else(|| {
+            read_attr(&path, "capacity_level").and_then(
+              |level| match level.as_str() {
+                "Critical" => Some(5),
+                "Low" => Some(25),
+                "Normal" => Some(60),
+                "High" => Some(80),
+                "Full" => Some(100),
+                _ => None,
+              },
+            )
+          });
+
+        let is_charging = read_attr(&path, "status").and_then(|status| {
+          match status.as_str() {
+            "Charging" => Some(true),
+            "Discharging" | "Not charging" | "Full" => Some(false),
+            _ => None,
+          }
+        });
+
+        Some(Peripheral {
+          id,
+          name,
+          kind,
+          battery_percent,
+          is_charging,
+        })
+      })
+      .collect(),
+  )
+}
+
+/// Reads an attribute of a power supply (e.g. `capacity`).
+fn read_attr(path: &Path, attr: &str) -> Option<String> {
+  fs::read_to_string(path.join(attr))
+    .ok()
+    .map(|value| value.trim().to_string())
+}
@@ -0,0 +1,48 @@
+use std::process::Command;
+
+use anyhow::Context;
+
+use super::{Peripheral, PeripheralKind};
+
+/// Gets Bluetooth peripherals that report a battery level (e.g. Magic
+/// Mouse or AirPods), via the I/O Registry.
+pub fn peripherals() -> anyhow::Result<Vec<Peripheral>> {
+  let output = Command::new("ioreg")
+    .args(["-r", "-l", "-k", "BatteryPercent"])
+    .output()
+    .context("Failed to run `ioreg`.")?;
+
+  let stdout = String::from_utf8_lossy(&output.stdout);
+
+  // Each matching registry entry starts with a line containing `+-o`,
+  // followed by its properties as `"Key" = Value` lines.
+  let peripherals = stdout
+    .split("+-o")
+    .skip(1)
+    .filter_map(|entry| {
+      let property = |key: &str| {
+        entry.lines().find_map(|line| {
+          let (line_key, value) = line.split_once(" = ")?;
+          (line_key.trim().trim_matches(['|', ' ', '"']) == key)
+            .then(|| value.trim().trim_matches('"').to_string())
+        })
+      };
+
+      let name = property("Product")?;
+      let id = property("DeviceAddress")
+        .or_else(|| property("SerialNumber"))
+        .unwrap_or_else(|| name.clone());
+
+      Some(Peripheral {
+        id,
+        kind: PeripheralKind::from_name(&name),
+        battery_percent: property("BatteryPercent")
+          .and_then(|percent| percent.parse::<u8>().ok()),
+        is_charging: None,
+        name,
+      })
+    })
+    .collect();
+
+  Ok(peripherals)
+}
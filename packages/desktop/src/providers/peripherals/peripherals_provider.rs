@@ -0,0 +1,177 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[cfg(target_os = "linux")]
+use super::peripherals_linux as backend;
+#[cfg(target_os = "macos")]
+use super::peripherals_macos as backend;
+#[cfg(windows)]
+use super::peripherals_windows as backend;
+use crate::{
+  common::{IntervalOptions, SyncInterval},
+  providers::{
+    CommonProviderState, Provider, ProviderInputMsg, RuntimeType,
+  },
+};
+
+#[derive(Deserialize, Debug, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct PeripheralsProviderConfig {
+  #[serde(deserialize_with = "crate::common::deserialize_duration_ms")]
+  #[schemars(schema_with = "crate::common::duration_schema")]
+  pub refresh_interval: u64,
+
+  /// Alignment and jitter of refreshes.
+  #[serde(default)]
+  pub refresh_options: IntervalOptions,
+
+  /// Kinds of devices to include. Includes all kinds if empty.
+  #[serde(default)]
+  pub kinds: Vec<PeripheralKind>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct PeripheralsOutput {
+  pub devices: Vec<Peripheral>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct Peripheral {
+  /// Identifier of the device, which is stable across reconnects.
+  pub id: String,
+  pub name: String,
+  pub kind: PeripheralKind,
+
+  /// Battery charge as a percentage. `None` if the device doesn't
+  /// report its battery level.
+  ///
+  /// Game controllers on Windows only report a coarse level (empty,
+  /// low, medium, or full), which is mapped to 0, 25, 60, and 100.
+  pub battery_percent: Option<u8>,
+
+  /// Whether the device is charging. `None` if unknown.
+  pub is_charging: Option<bool>,
+}
+
+#[derive(
+  Debug, Clone, Copy, Deserialize, PartialEq, Serialize, JsonSchema,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum PeripheralKind {
+  Stylus,
+  GameController,
+  Mouse,
+  Keyboard,
+  Headset,
+  Other,
+}
+
+impl PeripheralKind {
+  /// Guesses the kind of a device from its name (e.g. `MX Master 3`
+  /// or `Xbox Wireless Controller`).
+  pub fn from_name(name: &str) -> Self {
+    let name = name.to_lowercase();
+    let words = name
+      .split(|char: char| !char.is_alphanumeric())
+      .collect::<Vec<_>>();
+
+    // Terms match the start of a word (e.g. `buds` matches `Buds2`),
+    // or anywhere for terms with separators (e.g. `joy-con`).
+    let contains_any = |terms: &[&str]| {
+      terms.iter().any(|term| match term.contains([' ', '-']) {
+        true => name.contains(term),
+        false => words.iter().any(|word| word.starts_with(term)),
+      })
+    };
+
+    if contains_any(&["pen", "stylus", "wacom"]) {
+      Self::Stylus
+    } else if contains_any(&[
+      "controller",
+      "gamepad",
+      "joystick",
+      "joy-con",
+      "dualsense",
+      "dualshock",
+    ]) {
+      Self::GameController
+    } else if contains_any(&[
+      "mouse",
+      "trackpad",
+      "trackball",
+      "mx master",
+    ]) {
+      Self::Mouse
+    } else if contains_any(&["keyboard", "keys"]) {
+      Self::Keyboard
+    } else if contains_any(&[
+      "headset",
+      "headphones",
+      "earbuds",
+      "airpods",
+      "buds",
+    ]) {
+      Self::Headset
+    } else {
+      Self::Other
+    }
+  }
+}
+
+pub struct PeripheralsProvider {
+  config: PeripheralsProviderConfig,
+  common: CommonProviderState,
+}
+
+impl PeripheralsProvider {
+  pub fn new(
+    config: PeripheralsProviderConfig,
+    common: CommonProviderState,
+  ) -> PeripheralsProvider {
+    PeripheralsProvider { config, common }
+  }
+
+  fn run_interval(&self) -> anyhow::Result<PeripheralsOutput> {
+    let mut devices = backend::peripherals()?
+      .into_iter()
+      .filter(|device| {
+        self.config.kinds.is_empty()
+          || self.config.kinds.contains(&device.kind)
+      })
+      .collect::<Vec<_>>();
+
+    // Sort for a stable order between refreshes.
+    devices.sort_by(|a, b| a.name.cmp(&b.name).then(a.id.cmp(&b.id)));
+
+    Ok(PeripheralsOutput { devices })
+  }
+}
+
+impl Provider for PeripheralsProvider {
+  fn runtime_type(&self) -> RuntimeType {
+    RuntimeType::Sync
+  }
+
+  fn start_sync(&mut self) {
+    let mut interval = SyncInterval::with_options(
+      self.config.refresh_interval,
+      &self.config.refresh_options,
+    );
+
+    loop {
+      crossbeam::select! {
+        recv(interval.tick()) -> _ => {
+          let output = self.run_interval();
+          self.common.emitter.emit_output_cached(output);
+        }
+        recv(self.common.input.sync_rx) -> input => {
+          if let Ok(ProviderInputMsg::Stop) = input {
+            break;
+          }
+        }
+      }
+    }
+  }
+}
@@ -0,0 +1,170 @@
+use windows::{
+  core::{w, GUID, PCWSTR},
+  Win32::{
+    Devices::{
+      DeviceAndDriverInstallation::{DIGCF_ALLCLASSES, DIGCF_PRESENT},
+      Properties::{
+        DEVPKEY_Device_DeviceDesc, DEVPKEY_Device_FriendlyName,
+        DEVPKEY_Device_InstanceId, DEVPROPKEY,
+      },
+    },
+    UI::Input::XboxController::{
+      XInputGetBatteryInformation, XInputGetCapabilities,
+      BATTERY_DEVTYPE_GAMEPAD, BATTERY_LEVEL_EMPTY, BATTERY_LEVEL_FULL,
+      BATTERY_LEVEL_LOW, BATTERY_LEVEL_MEDIUM, BATTERY_TYPE_DISCONNECTED,
+      BATTERY_TYPE_WIRED, XINPUT_BATTERY_INFORMATION, XINPUT_CAPABILITIES,
+      XINPUT_FLAG,
+    },
+  },
+};
+
+use super::{Peripheral, PeripheralKind};
+use crate::common::windows::DeviceInfoSet;
+
+/// Battery level of a Bluetooth device, as shown in the Settings app.
+/// Stored as a byte.
+const DEVPKEY_BLUETOOTH_BATTERY: DEVPROPKEY = DEVPROPKEY {
+  fmtid: GUID::from_u128(0x104ea319_6ee2_4701_bd47_8ddbf425bbe5),
+  pid: 2,
+};
+
+/// Class of device of a Bluetooth Classic device. Stored as a `u32`.
+const DEVPKEY_BLUETOOTH_CLASS_OF_DEVICE: DEVPROPKEY = DEVPROPKEY {
+  fmtid: GUID::from_u128(0x2bd67d8b_8beb_48d5_87e0_6cda3428040a),
+  pid: 10,
+};
+
+/// Max number of XInput controllers.
+const XINPUT_MAX_CONTROLLERS: u32 = 4;
+
+/// Gets Bluetooth devices that report a battery level, along with
+/// connected XInput (i.e. Xbox) controllers.
+pub fn peripherals() -> anyhow::Result<Vec<Peripheral>> {
+  let mut peripherals = Vec::new();
+
+  // Bluetooth LE and Bluetooth Classic devices are enumerated
+  // separately.
+  for enumerator in [w!("BTHLE"), w!("BTHENUM")] {
+    peripherals.extend(bluetooth_peripherals(enumerator)?);
+  }
+
+  peripherals.extend(xinput_controllers());
+
+  Ok(peripherals)
+}
+
+fn bluetooth_peripherals(
+  enumerator: PCWSTR,
+) -> anyhow::Result<Vec<Peripheral>> {
+  let device_set = DeviceInfoSet::new(
+    None,
+    enumerator,
+    DIGCF_PRESENT | DIGCF_ALLCLASSES,
+  )?;
+
+  let peripherals = device_set
+    .devices()
+    .iter()
+    .filter_map(|device| {
+      // Only devices that report a battery are of interest. Other
+      // Bluetooth device nodes (e.g. services) share the same
+      // enumerator.
+      let battery_percent = *device_set
+        .property(device, &DEVPKEY_BLUETOOTH_BATTERY)?
+        .first()?;
+
+      let name = device_set
+        .string_property(device, &DEVPKEY_Device_FriendlyName)
+        .or_else(|| {
+          device_set.string_property(device, &DEVPKEY_Device_DeviceDesc)
+        })
+        .unwrap_or_default();
+
+      let id = device_set
+        .string_property(device, &DEVPKEY_Device_InstanceId)
+        .unwrap_or_else(|| name.clone());
+
+      let kind = device_set
+        .u32_property(device, &DEVPKEY_BLUETOOTH_CLASS_OF_DEVICE)
+        .and_then(kind_from_class_of_device)
+        .unwrap_or_else(|| PeripheralKind::from_name(&name));
+
+      Some(Peripheral {
+        id,
+        name,
+        kind,
+        battery_percent: Some(battery_percent.min(100)),
+        is_charging: None,
+      })
+    })
+    .collect();
+
+  Ok(peripherals)
+}
+
+/// Gets the kind of a device from its Bluetooth class of device.
+fn kind_from_class_of_device(class: u32) -> Option<PeripheralKind> {
+  let major_class = (class >> 8) & 0x1f;
+  let minor_class = (class >> 2) & 0x3f;
+
+  match major_class {
+    // Audio/video devices.
+    0x04 => Some(PeripheralKind::Headset),
+    // Peripherals, where the upper bits of the minor class indicate a
+    // keyboard and/or pointing device, and the lower bits the type.
+    0x05 => match (minor_class >> 4, minor_class & 0x0f) {
+      (_, 0x01 | 0x02) => Some(PeripheralKind::GameController),
+      (_, 0x05 | 0x07) => Some(PeripheralKind::Stylus),
+      (0x01 | 0x03, _) => Some(PeripheralKind::Keyboard),
+      (0x02, _) => Some(PeripheralKind::Mouse),
+      _ => None,
+    },
+    _ => None,
+  }
+}
+
+/// Gets connected XInput controllers and their battery levels.
+fn xinput_controllers() -> Vec<Peripheral> {
+  (0..XINPUT_MAX_CONTROLLERS)
+    .filter_map(|index| {
+      let mut capabilities = XINPUT_CAPABILITIES::default();
+
+      let is_connected = unsafe {
+        XInputGetCapabilities(index, XINPUT_FLAG(0), &mut capabilities)
+      } == 0;
+
+      if !is_connected {
+        return None;
+      }
+
+      let mut battery = XINPUT_BATTERY_INFORMATION::default();
+      let _ = unsafe {
+        XInputGetBatteryInformation(
+          index,
+          BATTERY_DEVTYPE_GAMEPAD,
+          &mut battery,
+        )
+      };
+
+      // XInput only reports a coarse battery level.
+      let battery_percent = match battery.BatteryType {
+        BATTERY_TYPE_WIRED | BATTERY_TYPE_DISCONNECTED => None,
+        _ => match battery.BatteryLevel {
+          BATTERY_LEVEL_EMPTY => Some(0),
+          BATTERY_LEVEL_LOW => Some(25),
+          BATTERY_LEVEL_MEDIUM => Some(60),
+          BATTERY_LEVEL_FULL => Some(100),
+          _ => None,
+        },
+      };
+
+      Some(Peripheral {
+        id: format!("xinput-{}", index),
+        name: format!("Xbox Controller {}", index + 1),
+        kind: PeripheralKind::GameController,
+        battery_percent,
+        is_charging: None,
+      })
+    })
+    .collect()
+}
@@ -0,0 +1,101 @@
+use std::process::Command;
+
+use anyhow::{bail, Context};
+
+use super::{PrintJob, Printer, PrinterStatus};
+
+/// Gets all configured printers via the CUPS `lpstat` CLI.
+pub fn printers() -> anyhow::Result<Vec<Printer>> {
+  let default_name = lpstat(&["-d"])
+    .ok()
+    .and_then(|output| parse_default_printer(&output));
+
+  // `lpstat -o` errors if there are no printers, so default to no jobs.
+  let jobs_output = lpstat(&["-o"]).unwrap_or_default();
+
+  let printers = lpstat(&["-p"])?
+    .lines()
+    .filter_map(|line| line.strip_prefix("printer "))
+    .filter_map(|line| {
+      let (name, rest) = line.split_once(' ')?;
+      let jobs = parse_jobs(&jobs_output, name);
+
+      Some(Printer {
+        name: name.to_string(),
+        status: parse_status(rest),
+        is_default: default_name.as_deref() == Some(name),
+        job_count: jobs.len() as u32,
+        jobs,
+      })
+    })
+    .collect();
+
+  Ok(printers)
+}
+
+/// Cancels a print job via the CUPS `cancel` CLI.
+pub fn cancel_job(printer_name: &str, job_id: u32) -> anyhow::Result<()> {
+  let status = Command::new("cancel")
+    .arg(format!("{}-{}", printer_name, job_id))
+    .status()
+    .context("Unable to run `cancel`.")?;
+
+  if !status.success() {
+    bail!("Failed to cancel job {} on '{}'.", job_id, printer_name);
+  }
+
+  Ok(())
+}
+
+fn lpstat(args: &[&str]) -> anyhow::Result<String> {
+  let output = Command::new("lpstat")
+    .args(args)
+    .output()
+    .context("Unable to run `lpstat`. Is CUPS installed?")?;
+
+  if !output.status.success() {
+    bail!("`lpstat` exited with {}.", output.status);
+  }
+
+  Ok(String::from_utf8_lossy(&output.stdout).to_string())
+}
+
+/// Parses output of `lpstat -d` (e.g. `system default destination:
+/// Office`).
+fn parse_default_printer(output: &str) -> Option<String> {
+  output
+    .split_once(':')
+    .map(|(_, name)| name.trim().to_string())
+    .filter(|name| !name.is_empty())
+}
+
+/// Parses the remainder of a `lpstat -p` line (e.g. `is idle.  enabled
+/// since ...` or `disabled since ...`).
+fn parse_status(line: &str) -> PrinterStatus {
+  if line.starts_with("disabled") {
+    PrinterStatus::Paused
+  } else if line.starts_with("now printing") {
+    PrinterStatus::Printing
+  } else if line.contains("offline") || line.contains("not responding") {
+    PrinterStatus::Offline
+  } else {
+    PrinterStatus::Idle
+  }
+}
+
+/// Parses jobs for the given printer from `lpstat -o` output. Job ID's
+/// are of the format `<PRINTER>-<ID>`.
+fn parse_jobs(output: &str, printer_name: &str) -> Vec<PrintJob> {
+  output
+    .lines()
+    .filter_map(|line| line.split_whitespace().next())
+    .filter_map(|job_id| {
+      job_id
+        .strip_prefix(printer_name)?
+        .strip_prefix('-')?
+        .parse::<u32>()
+        .ok()
+    })
+    .map(|id| PrintJob { id, document: None })
+    .collect()
+}
@@ -0,0 +1,7 @@
+#[cfg(unix)]
+mod cups;
+mod printer_provider;
+#[cfg(windows)]
+mod spooler;
+
+pub use printer_provider::*;
@@ -0,0 +1,141 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[cfg(unix)]
+use super::cups as backend;
+#[cfg(windows)]
+use super::spooler as backend;
+use crate::{
+  common::{IntervalOptions, SyncInterval},
+  providers::{
+    CommonProviderState, PrinterFunction, Provider, ProviderFunction,
+    ProviderFunctionResponse, ProviderInputMsg, RuntimeType,
+  },
+};
+
+#[derive(Deserialize, Debug, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct PrinterProviderConfig {
+  #[serde(deserialize_with = "crate::common::deserialize_duration_ms")]
+  #[schemars(schema_with = "crate::common::duration_schema")]
+  pub refresh_interval: u64,
+
+  /// Alignment and jitter of refreshes.
+  #[serde(default)]
+  pub refresh_options: IntervalOptions,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct PrinterOutput {
+  pub printers: Vec<Printer>,
+  pub default_printer: Option<Printer>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct Printer {
+  pub name: String,
+  pub status: PrinterStatus,
+  pub is_default: bool,
+  pub job_count: u32,
+  pub jobs: Vec<PrintJob>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct PrintJob {
+  pub id: u32,
+  pub document: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum PrinterStatus {
+  Idle,
+  Printing,
+  Paused,
+  Offline,
+  Error,
+}
+
+pub struct PrinterProvider {
+  config: PrinterProviderConfig,
+  common: CommonProviderState,
+}
+
+impl PrinterProvider {
+  pub fn new(
+    config: PrinterProviderConfig,
+    common: CommonProviderState,
+  ) -> PrinterProvider {
+    PrinterProvider { config, common }
+  }
+
+  fn run_interval(&self) -> anyhow::Result<PrinterOutput> {
+    let printers = backend::printers()?;
+
+    Ok(PrinterOutput {
+      default_printer: printers
+        .iter()
+        .find(|printer| printer.is_default)
+        .cloned(),
+      printers,
+    })
+  }
+
+  /// Handles an incoming printer provider function call.
+  fn handle_function(
+    &mut self,
+    function: PrinterFunction,
+  ) -> anyhow::Result<ProviderFunctionResponse> {
+    match function {
+      PrinterFunction::CancelJob(args) => {
+        backend::cancel_job(&args.printer_name, args.job_id)?;
+
+        // Emit immediately so that the cancelled job is removed.
+        let output = self.run_interval();
+        self.common.emitter.emit_output_cached(output);
+
+        Ok(ProviderFunctionResponse::Null)
+      }
+    }
+  }
+}
+
+impl Provider for PrinterProvider {
+  fn runtime_type(&self) -> RuntimeType {
+    RuntimeType::Sync
+  }
+
+  fn start_sync(&mut self) {
+    let mut interval = SyncInterval::with_options(
+      self.config.refresh_interval,
+      &self.config.refresh_options,
+    );
+
+    loop {
+      crossbeam::select! {
+        recv(interval.tick()) -> _ => {
+          let output = self.run_interval();
+          self.common.emitter.emit_output_cached(output);
+        }
+        recv(self.common.input.sync_rx) -> input => {
+          match input {
+            Ok(ProviderInputMsg::Stop) => {
+              break;
+            }
+            Ok(ProviderInputMsg::Function(
+              ProviderFunction::Printer(printer_function),
+              sender,
+            )) => {
+              let res = self.handle_function(printer_function).map_err(|err| err.to_string());
+              sender.send(res).unwrap();
+            }
+            _ => {}
+          }
+        }
+      }
+    }
+  }
+}
@@ -0,0 +1,216 @@
+use anyhow::Context;
+use windows::{
+  core::{HSTRING, PWSTR},
+  Win32::Graphics::Printing::{
+    ClosePrinter, EnumJobsW, EnumPrintersW, GetDefaultPrinterW,
+    OpenPrinterW, SetJobW, JOB_CONTROL_DELETE, JOB_INFO_1W,
+    PRINTER_ENUM_CONNECTIONS, PRINTER_ENUM_LOCAL, PRINTER_HANDLE,
+    PRINTER_INFO_2W, PRINTER_STATUS_ERROR, PRINTER_STATUS_NOT_AVAILABLE,
+    PRINTER_STATUS_OFFLINE, PRINTER_STATUS_PAPER_JAM,
+    PRINTER_STATUS_PAPER_OUT, PRINTER_STATUS_PAUSED,
+    PRINTER_STATUS_PRINTING,
+  },
+};
+
+use super::{PrintJob, Printer, PrinterStatus};
+
+/// Handle to an open printer that is closed on drop.
+struct PrinterHandle(PRINTER_HANDLE);
+
+impl PrinterHandle {
+  fn open(printer_name: &str) -> anyhow::Result<Self> {
+    let mut handle = PRINTER_HANDLE::default();
+
+    unsafe {
+      OpenPrinterW(&HSTRING::from(printer_name), &mut handle, None)
+    }
+    .with_context(|| {
+      format!("Failed to open printer '{}'.", printer_name)
+    })?;
+
+    Ok(Self(handle))
+  }
+}
+
+impl Drop for PrinterHandle {
+  fn drop(&mut self) {
+    let _ = unsafe { ClosePrinter(self.0) };
+  }
+}
+
+/// Gets all local and connected printers via the print spooler API.
+pub fn printers() -> anyhow::Result<Vec<Printer>> {
+  let flags = PRINTER_ENUM_LOCAL | PRINTER_ENUM_CONNECTIONS;
+  let mut bytes_needed = 0;
+  let mut count = 0;
+
+  // First call gets the required buffer size.
+  let _ = unsafe {
+    EnumPrintersW(flags, None, 2, None, &mut bytes_needed, &mut count)
+  };
+
+  if bytes_needed == 0 {
+    return Ok(Vec::new());
+  }
+
+  let mut buffer = aligned_buffer(bytes_needed);
+
+  unsafe {
+    EnumPrintersW(
+      flags,
+      None,
+      2,
+      Some(as_bytes_mut(&mut buffer)),
+      &mut bytes_needed,
+      &mut count,
+    )
+  }
+  .context("Failed to enumerate printers.")?;
+
+  let printer_infos = unsafe {
+    std::slice::from_raw_parts(
+      buffer.as_ptr().cast::<PRINTER_INFO_2W>(),
+      count as usize,
+    )
+  };
+
+  let default_name = default_printer_name();
+
+  let printers = printer_infos
+    .iter()
+    .map(|info| {
+      let name =
+        unsafe { info.pPrinterName.to_string() }.unwrap_or_default();
+
+      Printer {
+        is_default: default_name.as_deref() == Some(name.as_str()),
+        status: to_printer_status(info.Status),
+        job_count: info.cJobs,
+        jobs: jobs(&name).unwrap_or_default(),
+        name,
+      }
+    })
+    .collect();
+
+  Ok(printers)
+}
+
+/// Cancels a print job via the print spooler API.
+pub fn cancel_job(printer_name: &str, job_id: u32) -> anyhow::Result<()> {
+  let handle = PrinterHandle::open(printer_name)?;
+
+  unsafe { SetJobW(handle.0, job_id, 0, None, JOB_CONTROL_DELETE) }
+    .ok()
+    .with_context(|| {
+      format!("Failed to cancel job {} on '{}'.", job_id, printer_name)
+    })
+}
+
+/// Gets pending jobs for the given printer.
+fn jobs(printer_name: &str) -> anyhow::Result<Vec<PrintJob>> {
+  let handle = PrinterHandle::open(printer_name)?;
+  let mut bytes_needed = 0;
+  let mut count = 0;
+
+  // First call gets the required buffer size.
+  let _ = unsafe {
+    EnumJobsW(
+      handle.0,
+      0,
+      u32::MAX,
+      1,
+      None,
+      &mut bytes_needed,
+      &mut count,
+    )
+  };
+
+  if bytes_needed == 0 {
+    return Ok(Vec::new());
+  }
+
+  let mut buffer = aligned_buffer(bytes_needed);
+
+  unsafe {
+    EnumJobsW(
+      handle.0,
+      0,
+      u32::MAX,
+      1,
+      Some(as_bytes_mut(&mut buffer)),
+      &mut bytes_needed,
+      &mut count,
+    )
+  }
+  .context("Failed to enumerate print jobs.")?;
+
+  let job_infos = unsafe {
+    std::slice::from_raw_parts(
+      buffer.as_ptr().cast::<JOB_INFO_1W>(),
+      count as usize,
+    )
+  };
+
+  Ok(
+    job_infos
+      .iter()
+      .map(|info| PrintJob {
+        id: info.JobId,
+        document: unsafe { info.pDocument.to_string() }.ok(),
+      })
+      .collect(),
+  )
+}
+
+/// Allocates a zeroed buffer of at least the given size in bytes.
+///
+/// Backed by `u64`s, so that the structs that the spooler API writes to
+/// it (which contain pointers) are properly aligned when read back.
+fn aligned_buffer(byte_len: u32) -> Vec<u64> {
+  vec![0; (byte_len as usize).div_ceil(size_of::<u64>())]
+}
+
+/// Views an aligned buffer as bytes, for passing to the spooler API.
+fn as_bytes_mut(buffer: &mut [u64]) -> &mut [u8] {
+  unsafe {
+    std::slice::from_raw_parts_mut(
+      buffer.as_mut_ptr().cast::<u8>(),
+      size_of_val(buffer),
+    )
+  }
+}
+
+fn default_printer_name() -> Option<String> {
+  let mut length = 0;
+  let _ = unsafe { GetDefaultPrinterW(PWSTR::null(), &mut length) };
+
+  let mut buffer = vec![0u16; length as usize];
+
+  unsafe { GetDefaultPrinterW(PWSTR(buffer.as_mut_ptr()), &mut length) }
+    .as_bool()
+    .then(|| {
+      String::from_utf16_lossy(
+        &buffer[..length.saturating_sub(1) as usize],
+      )
+    })
+}
+
+fn to_printer_status(status: u32) -> PrinterStatus {
+  if status & (PRINTER_STATUS_OFFLINE | PRINTER_STATUS_NOT_AVAILABLE) != 0
+  {
+    PrinterStatus::Offline
+  } else if status
+    & (PRINTER_STATUS_ERROR
+      | PRINTER_STATUS_PAPER_JAM
+      | PRINTER_STATUS_PAPER_OUT)
+    != 0
+  {
+    PrinterStatus::Error
+  } else if status & PRINTER_STATUS_PAUSED != 0 {
+    PrinterStatus::Paused
+  } else if status & PRINTER_STATUS_PRINTING != 0 {
+    PrinterStatus::Printing
+  } else {
+    PrinterStatus::Idle
+  }
+}
@@ -0,0 +1,108 @@
+use std::{
+  collections::{hash_map::DefaultHasher, HashMap, VecDeque},
+  hash::{Hash, Hasher},
+  sync::{Arc, Mutex},
+};
+
+use schemars::JsonSchema;
+use serde::Serialize;
+
+/// Max number of attachments kept per provider. Older attachments are
+/// evicted first.
+const MAX_ATTACHMENTS_PER_PROVIDER: usize = 16;
+
+/// Reference to binary data emitted by a provider (e.g. album art).
+///
+/// Only the reference is included in provider outputs. The data itself
+/// is fetched as a raw binary payload via the `get_provider_attachment`
+/// command, which avoids inflating emissions with base64.
+#[derive(Debug, Clone, PartialEq, Serialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ProviderAttachment {
+  /// Unique ID of the attachment. Identical data results in the same ID.
+  pub id: String,
+
+  /// MIME type of the data (e.g. `image/png`).
+  pub mime_type: String,
+
+  /// Size of the data in bytes.
+  pub size: usize,
+}
+
+#[derive(Debug)]
+struct StoredAttachment {
+  id: String,
+  data: Arc<[u8]>,
+}
+
+/// Holds attachments emitted by providers until they're evicted or the
+/// provider is stopped.
+#[derive(Debug, Default)]
+pub struct AttachmentStore {
+  /// Attachments by provider config hash, ordered from oldest to newest.
+  attachments: Mutex<HashMap<String, VecDeque<StoredAttachment>>>,
+}
+
+impl AttachmentStore {
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Stores an attachment for the provider with the given config hash.
+  pub fn insert(
+    &self,
+    config_hash: &str,
+    data: Vec<u8>,
+    mime_type: &str,
+  ) -> ProviderAttachment {
+    let mut hasher = DefaultHasher::new();
+    config_hash.hash(&mut hasher);
+    data.hash(&mut hasher);
+
+    let attachment = ProviderAttachment {
+      id: format!("{:016x}", hasher.finish()),
+      mime_type: mime_type.to_string(),
+      size: data.len(),
+    };
+
+    let mut attachments = self.attachments.lock().unwrap();
+    let provider_attachments =
+      attachments.entry(config_hash.to_string()).or_default();
+
+    // Move an existing identical attachment to the back to avoid it
+    // being evicted.
+    let existing = provider_attachments
+      .iter()
+      .position(|stored| stored.id == attachment.id)
+      .and_then(|index| provider_attachments.remove(index));
+
+    provider_attachments.push_back(existing.unwrap_or(StoredAttachment {
+      id: attachment.id.clone(),
+      data: data.into(),
+    }));
+
+    while provider_attachments.len() > MAX_ATTACHMENTS_PER_PROVIDER {
+      provider_attachments.pop_front();
+    }
+
+    attachment
+  }
+
+  /// Gets the data of the attachment with the given ID, along with the
+  /// config hash of the provider that emitted it.
+  pub fn get(&self, id: &str) -> Option<(String, Arc<[u8]>)> {
+    self.attachments.lock().unwrap().iter().find_map(
+      |(config_hash, provider_attachments)| {
+        provider_attachments
+          .iter()
+          .find(|stored| stored.id == id)
+          .map(|stored| (config_hash.clone(), stored.data.clone()))
+      },
+    )
+  }
+
+  /// Removes all attachments of the provider with the given config hash.
+  pub fn remove_all(&self, config_hash: &str) {
+    self.attachments.lock().unwrap().remove(config_hash);
+  }
+}
@@ -1,21 +1,52 @@
+use std::{collections::HashMap, path::Path};
+
+use anyhow::bail;
+use schemars::JsonSchema;
 use serde::Deserialize;
+use tauri::AppHandle;
+use tokio::task;
 
-#[cfg(windows)]
+#[cfg(any(windows, target_os = "linux"))]
 use super::{
-  audio::AudioProviderConfig, keyboard::KeyboardProviderConfig,
-  komorebi::KomorebiProviderConfig, media::MediaProviderConfig,
+  audio::AudioProviderConfig, event_log::EventLogProviderConfig,
+  input_stats::InputStatsProviderConfig,
+  perf_counters::PerfCountersProviderConfig,
+  session::SessionProviderConfig,
 };
 use super::{
-  battery::BatteryProviderConfig, cpu::CpuProviderConfig,
-  disk::DiskProviderConfig, host::HostProviderConfig,
-  ip::IpProviderConfig, memory::MemoryProviderConfig,
-  network::NetworkProviderConfig, weather::WeatherProviderConfig,
+  battery::BatteryProviderConfig, camera::CameraProviderConfig,
+  check_provider_type, ci_status::CiStatusProviderConfig,
+  common::TlsConfig, countdown::CountdownProviderConfig,
+  cpu::CpuProviderConfig, derived::DerivedProviderConfig,
+  discord::DiscordProviderConfig, disk::DiskProviderConfig,
+  host::HostProviderConfig, ip::IpProviderConfig,
+  keep_awake::KeepAwakeProviderConfig, meeting::MeetingProviderConfig,
+  memory::MemoryProviderConfig, network::NetworkProviderConfig,
+  peripherals::PeripheralsProviderConfig, printer::PrinterProviderConfig,
+  provider_config_error::ProviderConfigError,
+  spotify::SpotifyProviderConfig, ssh_gpg::SshGpgProviderConfig,
+  steam::SteamProviderConfig, tasks::TasksProviderConfig,
+  timer::TimerProviderConfig, vpn::VpnProviderConfig,
+  weather::WeatherProviderConfig,
+};
+#[cfg(windows)]
+use super::{
+  fancy_zones::FancyZonesProviderConfig, fps::FpsProviderConfig,
+  keyboard::KeyboardProviderConfig, komorebi::KomorebiProviderConfig,
+  media::MediaProviderConfig,
+  virtual_desktop::VirtualDesktopProviderConfig,
+};
+#[cfg(target_os = "linux")]
+use super::{hyprland::HyprlandProviderConfig, i3::I3ProviderConfig};
+use crate::{
+  config_encryption::decrypt_fields,
+  secrets::{resolve_secret_refs, SecretAccess},
 };
 
-#[derive(Deserialize, Debug)]
+#[derive(Deserialize, Debug, JsonSchema)]
 #[serde(tag = "type", rename_all = "snake_case")]
 pub enum ProviderConfig {
-  #[cfg(windows)]
+  #[cfg(any(windows, target_os = "linux"))]
   Audio(AudioProviderConfig),
   Battery(BatteryProviderConfig),
   Cpu(CpuProviderConfig),
@@ -31,4 +62,260 @@ pub enum ProviderConfig {
   Weather(WeatherProviderConfig),
   #[cfg(windows)]
   Keyboard(KeyboardProviderConfig),
+  Printer(PrinterProviderConfig),
+  Meeting(MeetingProviderConfig),
+  Vpn(VpnProviderConfig),
+  #[cfg(target_os = "linux")]
+  Hyprland(HyprlandProviderConfig),
+  #[cfg(target_os = "linux")]
+  I3(I3ProviderConfig),
+  #[cfg(windows)]
+  VirtualDesktop(VirtualDesktopProviderConfig),
+  KeepAwake(KeepAwakeProviderConfig),
+  #[cfg(any(windows, target_os = "linux"))]
+  Session(SessionProviderConfig),
+  Countdown(CountdownProviderConfig),
+  Timer(TimerProviderConfig),
+  #[cfg(any(windows, target_os = "linux"))]
+  PerfCounters(PerfCountersProviderConfig),
+  #[cfg(any(windows, target_os = "linux"))]
+  EventLog(EventLogProviderConfig),
+  #[cfg(any(windows, target_os = "linux"))]
+  InputStats(InputStatsProviderConfig),
+  #[cfg(windows)]
+  Fps(FpsProviderConfig),
+  #[cfg(windows)]
+  FancyZones(FancyZonesProviderConfig),
+  Spotify(SpotifyProviderConfig),
+  Discord(DiscordProviderConfig),
+  Steam(SteamProviderConfig),
+  Tasks(TasksProviderConfig),
+  CiStatus(CiStatusProviderConfig),
+  SshGpg(SshGpgProviderConfig),
+  Peripherals(PeripheralsProviderConfig),
+  Camera(CameraProviderConfig),
+  Derived(DerivedProviderConfig),
+}
+
+impl ProviderConfig {
+  /// Name of the provider type (e.g. `cpu`), as used in the `type` field
+  /// of the config.
+  pub fn provider_type(&self) -> &'static str {
+    match self {
+      #[cfg(any(windows, target_os = "linux"))]
+      ProviderConfig::Audio(_) => "audio",
+      ProviderConfig::Battery(_) => "battery",
+      ProviderConfig::Cpu(_) => "cpu",
+      ProviderConfig::Host(_) => "host",
+      ProviderConfig::Ip(_) => "ip",
+      #[cfg(windows)]
+      ProviderConfig::Komorebi(_) => "komorebi",
+      #[cfg(windows)]
+      ProviderConfig::Media(_) => "media",
+      ProviderConfig::Memory(_) => "memory",
+      ProviderConfig::Disk(_) => "disk",
+      ProviderConfig::Network(_) => "network",
+      ProviderConfig::Weather(_) => "weather",
+      #[cfg(windows)]
+      ProviderConfig::Keyboard(_) => "keyboard",
+      ProviderConfig::Printer(_) => "printer",
+      ProviderConfig::Meeting(_) => "meeting",
+      ProviderConfig::Vpn(_) => "vpn",
+      #[cfg(target_os = "linux")]
+      ProviderConfig::Hyprland(_) => "hyprland",
+      #[cfg(target_os = "linux")]
+      ProviderConfig::I3(_) => "i3",
+      #[cfg(windows)]
+      ProviderConfig::VirtualDesktop(_) => "virtual_desktop",
+      ProviderConfig::KeepAwake(_) => "keep_awake",
+      #[cfg(any(windows, target_os = "linux"))]
+      ProviderConfig::Session(_) => "session",
+      ProviderConfig::Countdown(_) => "countdown",
+      ProviderConfig::Timer(_) => "timer",
+      #[cfg(any(windows, target_os = "linux"))]
+      ProviderConfig::PerfCounters(_) => "perf_counters",
+      #[cfg(any(windows, target_os = "linux"))]
+      ProviderConfig::EventLog(_) => "event_log",
+      #[cfg(any(windows, target_os = "linux"))]
+      ProviderConfig::InputStats(_) => "input_stats",
+      #[cfg(windows)]
+      ProviderConfig::Fps(_) => "fps",
+      #[cfg(windows)]
+      ProviderConfig::FancyZones(_) => "fancy_zones",
+      ProviderConfig::Spotify(_) => "spotify",
+      ProviderConfig::Discord(_) => "discord",
+      ProviderConfig::Steam(_) => "steam",
+      ProviderConfig::Tasks(_) => "tasks",
+      ProviderConfig::CiStatus(_) => "ci_status",
+      ProviderConfig::SshGpg(_) => "ssh_gpg",
+      ProviderConfig::Peripherals(_) => "peripherals",
+      ProviderConfig::Camera(_) => "camera",
+      ProviderConfig::Derived(_) => "derived",
+    }
+  }
+
+  /// Types of all providers started by the config, including the
+  /// sources of a derived provider.
+  pub fn provider_types(&self) -> Vec<&'static str> {
+    let mut types = vec![self.provider_type()];
+
+    if let ProviderConfig::Derived(config) = self {
+      types
+        .extend(config.providers.values().flat_map(Self::provider_types));
+    }
+
+    types
+  }
+
+  /// TLS options of the provider, for providers that make HTTP requests.
+  pub fn tls_config(&self) -> Option<&TlsConfig> {
+    match self {
+      ProviderConfig::Ip(config) => config.tls.as_ref(),
+      ProviderConfig::Weather(config) => config.tls.as_ref(),
+      ProviderConfig::Spotify(config) => config.tls.as_ref(),
+      ProviderConfig::Discord(config) => config.tls.as_ref(),
+      ProviderConfig::Steam(config) => config.tls.as_ref(),
+      ProviderConfig::Tasks(config) => config.tls.as_ref(),
+      ProviderConfig::CiStatus(config) => config.tls.as_ref(),
+      _ => None,
+    }
+  }
+
+  /// Whether the config points the provider at a custom URL or host,
+  /// including in the sources of a derived provider.
+  ///
+  /// Intentionally has no catch-all arm, so that new provider types
+  /// with a configurable URL or host are added here.
+  pub fn has_custom_url(&self) -> bool {
+    match self {
+      ProviderConfig::CiStatus(config) => config.base_url.is_some(),
+      ProviderConfig::Derived(config) => {
+        config.providers.values().any(Self::has_custom_url)
+      }
+      #[cfg(any(windows, target_os = "linux"))]
+      ProviderConfig::Audio(_) => false,
+      ProviderConfig::Battery(_) => false,
+      ProviderConfig::Cpu(_) => false,
+      ProviderConfig::Host(_) => false,
+      ProviderConfig::Ip(_) => false,
+      #[cfg(windows)]
+      ProviderConfig::Komorebi(_) => false,
+      #[cfg(windows)]
+      ProviderConfig::Media(_) => false,
+      ProviderConfig::Memory(_) => false,
+      ProviderConfig::Disk(_) => false,
+      ProviderConfig::Network(_) => false,
+      ProviderConfig::Weather(_) => false,
+      #[cfg(windows)]
+      ProviderConfig::Keyboard(_) => false,
+      ProviderConfig::Printer(_) => false,
+      ProviderConfig::Meeting(_) => false,
+      ProviderConfig::Vpn(_) => false,
+      #[cfg(target_os = "linux")]
+      ProviderConfig::Hyprland(_) => false,
+      #[cfg(target_os = "linux")]
+      ProviderConfig::I3(_) => false,
+      #[cfg(windows)]
+      ProviderConfig::VirtualDesktop(_) => false,
+      ProviderConfig::KeepAwake(_) => false,
+      #[cfg(any(windows, target_os = "linux"))]
+      ProviderConfig::Session(_) => false,
+      ProviderConfig::Countdown(_) => false,
+      ProviderConfig::Timer(_) => false,
+      #[cfg(any(windows, target_os = "linux"))]
+      ProviderConfig::PerfCounters(_) => false,
+      #[cfg(any(windows, target_os = "linux"))]
+      ProviderConfig::EventLog(_) => false,
+      #[cfg(any(windows, target_os = "linux"))]
+      ProviderConfig::InputStats(_) => false,
+      #[cfg(windows)]
+      ProviderConfig::Fps(_) => false,
+      #[cfg(windows)]
+      ProviderConfig::FancyZones(_) => false,
+      ProviderConfig::Spotify(_) => false,
+      ProviderConfig::Discord(_) => false,
+      ProviderConfig::Steam(_) => false,
+      ProviderConfig::Tasks(_) => false,
+      ProviderConfig::SshGpg(_) => false,
+      ProviderConfig::Peripherals(_) => false,
+      ProviderConfig::Camera(_) => false,
+    }
+  }
+}
+
+/// Parses a raw provider config, decrypting any encrypted values and
+/// resolving any secret references that the given access allows.
+///
+/// Configs that don't match the schema of their provider type result in
+/// a `ProviderConfigError`. Blocks on credential store lookups.
+pub fn parse_provider_config(
+  mut config: serde_json::Value,
+  access: &SecretAccess,
+) -> anyhow::Result<ProviderConfig> {
+  check_provider_type(&config)?;
+
+  // Encrypted values in widget configs are only decrypted here, so that
+  // they aren't exposed to other widgets along with the widget's config.
+  let has_encrypted = decrypt_fields(&mut config)?;
+  let has_secrets =
+    resolve_secret_refs(&mut config, access)? || has_encrypted;
+
+  let config = serde_json::from_value::<ProviderConfig>(config.clone())
+    .map_err(|err| ProviderConfigError::from_serde(&config, err))?;
+
+  // Prevent widgets from sending secrets to a host of their choosing.
+  if has_secrets
+    && matches!(access, SecretAccess::Only(_))
+    && config.has_custom_url()
+  {
+    bail!("Widgets can't use secrets together with a custom `baseUrl`.");
+  }
+
+  Ok(config)
+}
+
+/// Parses raw provider configs keyed by name (e.g. from a window title
+/// template), resolving any secret references that the given access
+/// allows.
+///
+/// Validation errors include the name of the invalid provider and the
+/// config file it's defined in, and are shown in a dialog.
+pub async fn parse_provider_configs(
+  app_handle: &AppHandle,
+  providers: HashMap<String, serde_json::Value>,
+  config_path: &Path,
+  access: SecretAccess,
+) -> anyhow::Result<HashMap<String, ProviderConfig>> {
+  let config_path = config_path.to_path_buf();
+
+  // Credential store lookups can block (e.g. Secret Service on Linux).
+  let res = task::spawn_blocking(move || {
+    providers
+      .into_iter()
+      .map(|(name, config)| {
+        parse_provider_config(config, &access)
+          .map(|config| (name.clone(), config))
+          .map_err(|err| match err.downcast::<ProviderConfigError>() {
+            Ok(err) => err
+              .with_provider_name(&name)
+              .with_config_path(&config_path)
+              .into(),
+            Err(err) => {
+              err.context(format!("Invalid provider '{}'.", name))
+            }
+          })
+      })
+      .collect()
+  })
+  .await?;
+
+  if let Some(err) = res
+    .as_ref()
+    .err()
+    .and_then(|err| err.downcast_ref::<ProviderConfigError>())
+  {
+    err.show_dialog(app_handle);
+  }
+
+  res
 }
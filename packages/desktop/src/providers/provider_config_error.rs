@@ -0,0 +1,177 @@
+use std::{
+  collections::HashSet,
+  fmt,
+  path::{Path, PathBuf},
+  sync::{LazyLock, Mutex},
+};
+
+use jsonschema::{
+  error::{TypeKind, ValidationErrorKind},
+  JSONSchema,
+};
+use serde::Serialize;
+use serde_json::Value;
+use tauri::AppHandle;
+use tauri_plugin_dialog::{DialogExt, MessageDialogKind};
+
+use super::provider_schemas;
+use crate::i18n::t_with;
+
+/// Errors that a dialog has already been shown for. Reloading the same
+/// invalid config then doesn't show the dialog again.
+static SHOWN_ERRORS: LazyLock<Mutex<HashSet<String>>> =
+  LazyLock::new(|| Mutex::new(HashSet::new()));
+
+/// Error for a provider config that doesn't match the config schema of
+/// its provider type.
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProviderConfigError {
+  /// Config file that the provider is defined in, if known.
+  pub config_path: Option<PathBuf>,
+
+  /// Name of the provider in the config (e.g. a key of `providers`).
+  pub provider_name: Option<String>,
+
+  /// JSON pointer to the invalid value within the provider config (e.g.
+  /// `/refreshInterval`). Empty if the location isn't known.
+  pub pointer: String,
+
+  /// Expected type of the value (e.g. `integer`), if applicable.
+  pub expected: Option<String>,
+
+  /// Description of what's invalid.
+  pub message: String,
+}
+
+impl ProviderConfigError {
+  /// Creates an error from a failed deserialization of a provider config.
+  ///
+  /// Validates the config against the JSON schema of its provider type
+  /// to locate the invalid value, since serde errors for internally
+  /// tagged enums don't include a path.
+  pub fn from_serde(config: &Value, err: serde_json::Error) -> Self {
+    Self::from_schema(config).unwrap_or_else(|| {
+      let message = err.to_string();
+
+      // Serde errors only include the name of missing fields.
+      let pointer = message
+        .strip_prefix("missing field `")
+        .and_then(|rest| rest.split('`').next())
+        .map(|field| format!("/{}", field))
+        .unwrap_or_default();
+
+      Self {
+        config_path: None,
+        provider_name: None,
+        pointer,
+        expected: None,
+        message,
+      }
+    })
+  }
+
+  fn from_schema(config: &Value) -> Option<Self> {
+    let provider_type = config.get("type")?.as_str()?;
+
+    let schema = provider_schemas()
+      .into_iter()
+      .find(|schema| schema.name == provider_type)?;
+
+    let schema = serde_json::to_value(schema.config_schema).ok()?;
+    let compiled = JSONSchema::compile(&schema).ok()?;
+    let err = compiled.validate(config).err()?.next()?;
+
+    let mut pointer = err.instance_path.to_string();
+
+    // Values aren't included in the message, since they might be
+    // resolved secrets.
+    let (message, expected) = match &err.kind {
+      ValidationErrorKind::Required { property } => {
+        let field = property.as_str().unwrap_or_default();
+        pointer.push_str(&format!("/{}", field));
+        (format!("Missing required field `{}`.", field), None)
+      }
+      ValidationErrorKind::Type {
+        kind: TypeKind::Single(expected),
+      } => (
+        format!("Expected a value of type {}.", expected),
+        Some(expected.to_string()),
+      ),
+      ValidationErrorKind::Enum { options } => {
+        let options = options
+          .as_array()
+          .into_iter()
+          .flatten()
+          .map(|option| option.to_string())
+          .collect::<Vec<_>>()
+          .join(", ");
+
+        (format!("Expected one of {}.", options), Some(options))
+      }
+      _ => ("Invalid value.".to_string(), None),
+    };
+
+    Some(Self {
+      config_path: None,
+      provider_name: None,
+      pointer,
+      expected,
+      message,
+    })
+  }
+
+  pub fn with_config_path(mut self, config_path: &Path) -> Self {
+    self.config_path = Some(config_path.to_path_buf());
+    self
+  }
+
+  pub fn with_provider_name(mut self, provider_name: &str) -> Self {
+    self.provider_name = Some(provider_name.to_string());
+    self
+  }
+
+  /// Shows the error in a dialog, unless it has already been shown.
+  ///
+  /// Callers should also log the error, so that it's added to the tray's
+  /// error list.
+  pub fn show_dialog(&self, app_handle: &AppHandle) {
+    let message = self.to_string();
+
+    if !SHOWN_ERRORS.lock().unwrap().insert(message.clone()) {
+      return;
+    }
+
+    app_handle
+      .dialog()
+      .message(t_with(
+        "providerConfigError.message",
+        &[("error", &message)],
+      ))
+      .title("Zebar")
+      .kind(MessageDialogKind::Error)
+      .show(|_| {});
+  }
+}
+
+impl fmt::Display for ProviderConfigError {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "Invalid provider")?;
+
+    if let Some(provider_name) = &self.provider_name {
+      write!(f, " '{}'", provider_name)?;
+    }
+
+    if let Some(config_path) = &self.config_path {
+      write!(f, " in {}", config_path.display())?;
+    }
+
+    if !self.pointer.is_empty() {
+      write!(f, " at `{}`", self.pointer)?;
+    }
+
+    write!(f, ": {}", self.message)
+  }
+}
+
+impl std::error::Error for ProviderConfigError {}
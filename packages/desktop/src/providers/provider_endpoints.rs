@@ -0,0 +1,49 @@
+use std::time::Duration;
+
+use anyhow::Context;
+use tokio::time;
+
+use super::common::HttpClientFactory;
+use crate::config::ProxyConfig;
+
+/// Endpoints that network providers fetch from, keyed by provider type.
+const PROVIDER_ENDPOINTS: &[(&str, &str)] = &[
+  ("ip", "https://ipinfo.io/json"),
+  ("weather", "https://api.open-meteo.com/v1/forecast"),
+  (
+    "weather",
+    "https://air-quality-api.open-meteo.com/v1/air-quality",
+  ),
+];
+
+/// How long to wait for an endpoint to respond.
+const ENDPOINT_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Checks whether network providers can reach their endpoints, with the
+/// given proxy settings applied.
+///
+/// Any HTTP response counts as reachable, since requests are sent
+/// without the parameters that providers add.
+pub async fn check_provider_endpoints(
+  proxy_config: Option<ProxyConfig>,
+) -> Vec<(&'static str, &'static str, anyhow::Result<()>)> {
+  let client_factory = HttpClientFactory::new(proxy_config);
+  let mut results = Vec::new();
+
+  for (provider_type, url) in PROVIDER_ENDPOINTS {
+    let res = async {
+      let request = client_factory.client(None)?.get(*url);
+
+      time::timeout(ENDPOINT_TIMEOUT, request.send())
+        .await
+        .context("Timed out.")??;
+
+      anyhow::Ok(())
+    }
+    .await;
+
+    results.push((*provider_type, *url, res));
+  }
+
+  results
+}
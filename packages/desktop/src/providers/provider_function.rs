@@ -4,13 +4,46 @@ use serde::{Deserialize, Serialize};
 #[serde(tag = "type", content = "function", rename_all = "snake_case")]
 pub enum ProviderFunction {
   Audio(AudioFunction),
+  CiStatus(CiStatusFunction),
+  Discord(DiscordFunction),
+  Hyprland(HyprlandFunction),
+  I3(I3Function),
+  Komorebi(KomorebiFunction),
   Media(MediaFunction),
+  Printer(PrinterFunction),
+  Spotify(SpotifyFunction),
+  Tasks(TasksFunction),
+  Timer(TimerFunction),
+  Vpn(VpnFunction),
+}
+
+impl ProviderFunction {
+  /// Name of the provider type the function belongs to (e.g. `media`).
+  pub fn provider_type(&self) -> &'static str {
+    match self {
+      ProviderFunction::Audio(_) => "audio",
+      ProviderFunction::CiStatus(_) => "ci_status",
+      ProviderFunction::Discord(_) => "discord",
+      ProviderFunction::Hyprland(_) => "hyprland",
+      ProviderFunction::I3(_) => "i3",
+      ProviderFunction::Komorebi(_) => "komorebi",
+      ProviderFunction::Media(_) => "media",
+      ProviderFunction::Printer(_) => "printer",
+      ProviderFunction::Spotify(_) => "spotify",
+      ProviderFunction::Tasks(_) => "tasks",
+      ProviderFunction::Timer(_) => "timer",
+      ProviderFunction::Vpn(_) => "vpn",
+    }
+  }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(tag = "name", content = "args", rename_all = "snake_case")]
 pub enum AudioFunction {
   SetVolume(SetVolumeArgs),
+  ToggleMicMute(ToggleMicMuteArgs),
+  SetAppVolume(SetAppVolumeArgs),
+  SetAppMute(SetAppMuteArgs),
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -19,6 +52,115 @@ pub struct SetVolumeArgs {
   pub device_id: Option<String>,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ToggleMicMuteArgs {
+  /// Recording device to toggle. Defaults to the default recording
+  /// device.
+  pub device_id: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SetAppVolumeArgs {
+  pub session_id: String,
+  pub volume: f32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SetAppMuteArgs {
+  pub session_id: String,
+  pub is_muted: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "name", content = "args", rename_all = "snake_case")]
+pub enum CiStatusFunction {
+  OpenPipeline(OpenPipelineArgs),
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OpenPipelineArgs {
+  pub repo: String,
+  pub branch: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "name", content = "args", rename_all = "snake_case")]
+pub enum DiscordFunction {
+  Authorize,
+  Logout,
+  ToggleMute,
+  ToggleDeafen,
+  SetMute(DiscordMuteArgs),
+  SetDeafen(DiscordDeafenArgs),
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DiscordMuteArgs {
+  pub is_muted: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DiscordDeafenArgs {
+  pub is_deafened: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "name", content = "args", rename_all = "snake_case")]
+pub enum HyprlandFunction {
+  Dispatch(HyprlandDispatchArgs),
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HyprlandDispatchArgs {
+  /// Name of the dispatcher (e.g. `workspace`).
+  pub dispatcher: String,
+
+  /// Arguments to the dispatcher (e.g. `3`).
+  pub args: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "name", content = "args", rename_all = "snake_case")]
+pub enum I3Function {
+  Command(I3CommandArgs),
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct I3CommandArgs {
+  /// Command to run (e.g. `workspace 3`).
+  pub command: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "name", content = "args", rename_all = "snake_case")]
+pub enum KomorebiFunction {
+  FocusWorkspace(FocusWorkspaceArgs),
+  FocusMonitor(FocusMonitorArgs),
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FocusWorkspaceArgs {
+  pub workspace_index: usize,
+
+  /// Monitor of the workspace. Defaults to the focused monitor.
+  pub monitor_index: Option<usize>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FocusMonitorArgs {
+  pub monitor_index: usize,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(tag = "name", content = "args", rename_all = "snake_case")]
 pub enum MediaFunction {
@@ -34,6 +176,115 @@ pub struct MediaControlArgs {
   pub session_id: Option<String>,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "name", content = "args", rename_all = "snake_case")]
+pub enum PrinterFunction {
+  CancelJob(CancelJobArgs),
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CancelJobArgs {
+  pub printer_name: String,
+  pub job_id: u32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "name", content = "args", rename_all = "snake_case")]
+pub enum SpotifyFunction {
+  Authorize,
+  Logout,
+  Play,
+  Pause,
+  TogglePlayPause,
+  Next,
+  Previous,
+  Seek(SpotifySeekArgs),
+  SetVolume(SpotifyVolumeArgs),
+  TransferPlayback(SpotifyTransferArgs),
+  SetLiked(SpotifyLikeArgs),
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SpotifySeekArgs {
+  /// Position in milliseconds.
+  pub position: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SpotifyVolumeArgs {
+  /// Volume from 0 to 100.
+  pub volume: u32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SpotifyTransferArgs {
+  pub device_id: String,
+
+  /// Whether to start playing on the new device. Defaults to `false`.
+  pub play: Option<bool>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SpotifyLikeArgs {
+  pub is_liked: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "name", content = "args", rename_all = "snake_case")]
+pub enum TasksFunction {
+  Authorize,
+  Logout,
+  CompleteTask(CompleteTaskArgs),
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CompleteTaskArgs {
+  pub task_id: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "name", content = "args", rename_all = "snake_case")]
+pub enum TimerFunction {
+  CreateTimer(CreateTimerArgs),
+  Start(TimerNameArgs),
+  Pause(TimerNameArgs),
+  Lap(TimerNameArgs),
+  Reset(TimerNameArgs),
+  Cancel(TimerNameArgs),
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateTimerArgs {
+  pub name: String,
+
+  /// Duration in milliseconds to count down from. Creates a stopwatch
+  /// if not set.
+  pub duration: Option<u64>,
+
+  /// Whether to start immediately. Defaults to `true`.
+  pub start: Option<bool>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TimerNameArgs {
+  pub name: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "name", content = "args", rename_all = "snake_case")]
+pub enum VpnFunction {
+  Connect,
+  Disconnect,
+}
+
 pub type ProviderFunctionResult = Result<ProviderFunctionResponse, String>;
 
 #[derive(Debug, Clone, Serialize)]
@@ -1,26 +1,64 @@
-use std::{collections::HashMap, sync::Arc};
+use std::{
+  collections::{HashMap, HashSet},
+  sync::Arc,
+  time::Duration,
+};
 
 use anyhow::Context;
 use serde::{ser::SerializeStruct, Serialize};
-use tauri::{AppHandle, Emitter};
+use tauri::{AppHandle, Emitter, Manager};
 use tokio::{
-  sync::{mpsc, oneshot, Mutex},
-  task,
+  sync::{mpsc, oneshot, watch, Mutex},
+  task, time,
 };
-use tracing::info;
+use tracing::{info, warn, Instrument};
 
-#[cfg(windows)]
+#[cfg(any(windows, target_os = "linux"))]
 use super::{
-  audio::AudioProvider, keyboard::KeyboardProvider,
-  komorebi::KomorebiProvider, media::MediaProvider,
+  audio::AudioProvider, event_log::EventLogProvider,
+  input_stats::InputStatsProvider, perf_counters::PerfCountersProvider,
+  session::SessionProvider,
 };
 use super::{
-  battery::BatteryProvider, cpu::CpuProvider, disk::DiskProvider,
-  host::HostProvider, ip::IpProvider, memory::MemoryProvider,
-  network::NetworkProvider, weather::WeatherProvider, Provider,
-  ProviderConfig, ProviderFunction, ProviderFunctionResponse,
-  ProviderFunctionResult, ProviderOutput, RuntimeType,
+  battery::BatteryProvider,
+  camera::CameraProvider,
+  ci_status::CiStatusProvider,
+  common::{HttpClient, HttpClientFactory},
+  countdown::CountdownProvider,
+  cpu::CpuProvider,
+  derived::{DerivedProvider, DerivedSources},
+  discord::DiscordProvider,
+  disk::DiskProvider,
+  host::HostProvider,
+  ip::IpProvider,
+  keep_awake::KeepAwakeProvider,
+  meeting::MeetingProvider,
+  memory::MemoryProvider,
+  network::NetworkProvider,
+  peripherals::PeripheralsProvider,
+  printer::PrinterProvider,
+  provider_supervisor::ProviderSupervisor,
+  spotify::SpotifyProvider,
+  ssh_gpg::SshGpgProvider,
+  steam::SteamProvider,
+  sync_provider_threads::SyncProviderThreads,
+  tasks::TasksProvider,
+  timer::{TimerProvider, TimerStore},
+  vpn::VpnProvider,
+  weather::WeatherProvider,
+  AttachmentStore, Provider, ProviderAttachment, ProviderConfig,
+  ProviderFunction, ProviderFunctionResponse, ProviderFunctionResult,
+  ProviderOutput, RuntimeType,
+};
+#[cfg(windows)]
+use super::{
+  fancy_zones::FancyZonesProvider, fps::FpsProvider,
+  keyboard::KeyboardProvider, komorebi::KomorebiProvider,
+  media::MediaProvider, virtual_desktop::VirtualDesktopProvider,
 };
+#[cfg(target_os = "linux")]
+use super::{hyprland::HyprlandProvider, i3::I3Provider};
+use crate::{config::ProxyConfig, log_filter::provider_span, portable};
 
 /// Common fields for a provider.
 pub struct CommonProviderState {
@@ -33,6 +71,13 @@ pub struct CommonProviderState {
 
   /// Shared `sysinfo` instance.
   pub sysinfo: Arc<Mutex<sysinfo::System>>,
+
+  /// HTTP client with the user's proxy settings and the provider's TLS
+  /// options applied.
+  pub http_client: HttpClient,
+
+  /// Shared store of named timers and stopwatches.
+  pub timer_store: Arc<TimerStore>,
 }
 
 /// Handle for receiving provider inputs.
@@ -60,6 +105,9 @@ pub struct ProviderEmitter {
 
   /// Previous emission from the provider.
   prev_emission: Option<ProviderEmission>,
+
+  /// Store for binary attachments referenced in emissions.
+  attachments: Arc<AttachmentStore>,
 }
 
 impl ProviderEmitter {
@@ -71,6 +119,21 @@ impl ProviderEmitter {
     }
   }
 
+  /// Stores binary data (e.g. an image) to be referenced in the
+  /// provider's output.
+  ///
+  /// Attachments are cleaned up when the provider is stopped, or when
+  /// the provider has emitted too many newer attachments.
+  // Not used by any macOS providers so far.
+  #[cfg_attr(target_os = "macos", allow(dead_code))]
+  pub fn attach(
+    &self,
+    data: Vec<u8>,
+    mime_type: &str,
+  ) -> ProviderAttachment {
+    self.attachments.insert(&self.config_hash, data, mime_type)
+  }
+
   /// Emits an output from a provider.
   pub fn emit_output<T>(&self, output: anyhow::Result<T>)
   where
@@ -82,6 +145,14 @@ impl ProviderEmitter {
     });
   }
 
+  /// Emits an error from a provider.
+  pub fn emit_error(&self, err: anyhow::Error) {
+    self.emit(ProviderEmission {
+      config_hash: self.config_hash.clone(),
+      result: Err(err.to_string()),
+    });
+  }
+
   /// Emits an output from a provider and prevents duplicate emissions by
   /// caching the previous emission.
   ///
@@ -116,7 +187,10 @@ pub struct ProviderEmission {
 }
 
 /// Reference to an active provider.
-struct ProviderRef {
+pub struct ProviderRef {
+  /// Hash of the provider's config.
+  config_hash: String,
+
   /// Sender channel for sending inputs to the provider.
   async_input_tx: mpsc::Sender<ProviderInputMsg>,
 
@@ -128,6 +202,45 @@ struct ProviderRef {
 
   /// Runtime type of the provider.
   runtime_type: RuntimeType,
+
+  /// Name of the provider type (e.g. `cpu`).
+  provider_type: &'static str,
+
+  /// Store for the provider's attachments.
+  attachments: Arc<AttachmentStore>,
+
+  /// ID's of what the provider is used by (e.g. widget window labels).
+  /// The provider is stopped once it has no subscribers.
+  subscribers: HashSet<String>,
+
+  /// Sender for whether the provider has been asked to stop. Prevents
+  /// the provider from being restarted by its supervisor.
+  stop_tx: watch::Sender<bool>,
+}
+
+impl ProviderRef {
+  /// Stops the provider and waits for it to exit.
+  pub async fn stop(self) -> anyhow::Result<()> {
+    let _ = self.stop_tx.send(true);
+
+    // Send shutdown signal to the provider. This fails if the provider
+    // has already exited (e.g. after its supervisor gave up on it).
+    match self.runtime_type {
+      RuntimeType::Async => {
+        let _ = self.async_input_tx.send(ProviderInputMsg::Stop).await;
+      }
+      RuntimeType::Sync => {
+        let _ = self.sync_input_tx.send(ProviderInputMsg::Stop);
+      }
+    }
+
+    // Wait for the provider to stop.
+    self.task_handle.await?;
+
+    self.attachments.remove_all(&self.config_hash);
+
+    Ok(())
+  }
 }
 
 /// Manages the creation and cleanup of providers.
@@ -146,6 +259,18 @@ pub struct ProviderManager {
 
   /// Shared `sysinfo` instance.
   sysinfo: Arc<Mutex<sysinfo::System>>,
+
+  /// Binary attachments emitted by providers.
+  attachments: Arc<AttachmentStore>,
+
+  /// Factory for HTTP clients with the user's proxy settings applied.
+  http_client_factory: HttpClientFactory,
+
+  /// Shared store of named timers and stopwatches.
+  timer_store: Arc<TimerStore>,
+
+  /// Pool of dedicated threads for sync providers.
+  sync_threads: SyncProviderThreads,
 }
 
 impl ProviderManager {
@@ -155,30 +280,49 @@ impl ProviderManager {
   /// channel for provider emissions.
   pub fn new(
     app_handle: &AppHandle,
+    proxy_config: Option<ProxyConfig>,
   ) -> (Arc<Self>, mpsc::UnboundedReceiver<ProviderEmission>) {
     let (emit_tx, emit_rx) = mpsc::unbounded_channel::<ProviderEmission>();
 
+    // Timers are stored next to the webview data in portable mode.
+    let timers_path = portable::data_dir()
+      .or_else(|| app_handle.path().app_local_data_dir().ok())
+      .map(|dir| dir.join("timers.json"));
+
     (
       Arc::new(Self {
         app_handle: app_handle.clone(),
         provider_refs: Arc::new(Mutex::new(HashMap::new())),
         emit_cache: Arc::new(Mutex::new(HashMap::new())),
         sysinfo: Arc::new(Mutex::new(sysinfo::System::new_all())),
+        attachments: Arc::new(AttachmentStore::new()),
+        http_client_factory: HttpClientFactory::new(proxy_config),
+        timer_store: Arc::new(TimerStore::new(timers_path)),
+        sync_threads: SyncProviderThreads::default(),
         emit_tx,
       }),
       emit_rx,
     )
   }
 
-  /// Creates a provider with the given config.
+  /// Creates a provider with the given config, or adds a subscriber to
+  /// it if it already exists.
   pub async fn create(
     &self,
     config_hash: String,
     config: ProviderConfig,
+    subscriber: &str,
   ) -> anyhow::Result<()> {
-    // If a provider with the given config already exists, re-emit its
-    // latest emission and return early.
-    {
+    // Hold the lock for `provider_refs` to prevent duplicate providers
+    // from potentially being created.
+    let mut provider_refs = self.provider_refs.lock().await;
+
+    // Multiple frontend clients can call `create` for the same provider,
+    // and all will receive the same output once the provider emits.
+    if let Some(provider_ref) = provider_refs.get_mut(&config_hash) {
+      provider_ref.subscribers.insert(subscriber.to_string());
+
+      // Re-emit the latest emission if the provider has already emitted.
       if let Some(found_emit) =
         self.emit_cache.lock().await.get(&config_hash)
       {
@@ -188,24 +332,49 @@ impl ProviderManager {
         );
 
         self.app_handle.emit("provider-emit", found_emit)?;
-        return Ok(());
-      };
-    }
-
-    // Hold the lock for `provider_refs` to prevent duplicate providers
-    // from potentially being created.
-    let mut provider_refs = self.provider_refs.lock().await;
+      }
 
-    // No-op if the provider has already been created (but has not emitted
-    // yet). Multiple frontend clients can call `create` for the same
-    // provider, and all will receive the same output once the provider
-    // emits.
-    if provider_refs.contains_key(&config_hash) {
       return Ok(());
     }
 
     tracing::info!("Creating provider: {}", config_hash);
 
+    let mut provider_ref =
+      self.start(config_hash.clone(), config, self.emit_tx.clone())?;
+
+    provider_ref.subscribers.insert(subscriber.to_string());
+    provider_refs.insert(config_hash, provider_ref);
+
+    Ok(())
+  }
+
+  /// Runs a provider until its first emission, then stops it. Used for
+  /// one-shot queries via the CLI.
+  pub async fn get_once(
+    &self,
+    config: ProviderConfig,
+    timeout: Duration,
+  ) -> anyhow::Result<ProviderOutput> {
+    let (emit_tx, mut emit_rx) = mpsc::unbounded_channel();
+    let provider_ref = self.start("cli".into(), config, emit_tx)?;
+
+    let emission = time::timeout(timeout, emit_rx.recv()).await;
+    provider_ref.stop().await?;
+
+    emission
+      .context("Provider didn't emit before the timeout.")?
+      .context("Provider exited without emitting.")?
+      .result
+      .map_err(anyhow::Error::msg)
+  }
+
+  /// Starts a provider that sends its emissions to the given channel.
+  fn start(
+    &self,
+    config_hash: String,
+    config: ProviderConfig,
+    emit_tx: mpsc::UnboundedSender<ProviderEmission>,
+  ) -> anyhow::Result<ProviderRef> {
     let (async_input_tx, async_input_rx) = mpsc::channel(1);
     let (sync_input_tx, sync_input_rx) = crossbeam::channel::bounded(1);
 
@@ -215,26 +384,75 @@ impl ProviderManager {
         sync_rx: sync_input_rx,
       },
       emitter: ProviderEmitter {
-        emit_tx: self.emit_tx.clone(),
+        emit_tx,
         config_hash: config_hash.clone(),
         prev_emission: None,
+        attachments: self.attachments.clone(),
       },
       sysinfo: self.sysinfo.clone(),
+      http_client: self.http_client_factory.client(config.tls_config())?,
+      timer_store: self.timer_store.clone(),
     };
 
-    let (task_handle, runtime_type) =
-      self.create_instance(config, config_hash.clone(), common)?;
+    let provider_type = config.provider_type();
 
-    let provider_ref = ProviderRef {
+    let (stop_tx, stop_rx) = watch::channel(false);
+    let supervisor = ProviderSupervisor::new(
+      config_hash.clone(),
+      common.emitter.clone(),
+      stop_rx,
+    );
+
+    let (task_handle, runtime_type) = self.create_instance(
+      config,
+      config_hash.clone(),
+      common,
+      supervisor,
+    )?;
+
+    Ok(ProviderRef {
+      config_hash,
       async_input_tx,
       sync_input_tx,
       task_handle,
       runtime_type,
-    };
+      provider_type,
+      attachments: self.attachments.clone(),
+      subscribers: HashSet::new(),
+      stop_tx,
+    })
+  }
 
-    provider_refs.insert(config_hash, provider_ref);
+  /// Starts the sources of a derived provider. Their emissions are sent
+  /// to the derived provider instead of to the frontend.
+  fn start_sources(
+    &self,
+    config_hash: &str,
+    configs: HashMap<String, ProviderConfig>,
+  ) -> anyhow::Result<DerivedSources> {
+    let (emit_tx, emit_rx) = mpsc::unbounded_channel();
+    let mut sources = DerivedSources::new(emit_rx);
+
+    for (name, config) in configs {
+      let source_hash = format!("{}:{}", config_hash, name);
+
+      let provider_ref =
+        match self.start(source_hash.clone(), config, emit_tx.clone()) {
+          Ok(provider_ref) => provider_ref,
+          Err(err) => {
+            // Stop any sources that were already started.
+            task::spawn(async move { sources.stop().await });
+
+            return Err(
+              err.context(format!("Failed to start source '{}'.", name)),
+            );
+          }
+        };
 
-    Ok(())
+      sources.insert(name, source_hash, provider_ref);
+    }
+
+    Ok(sources)
   }
 
   /// Creates a new provider instance.
@@ -243,82 +461,226 @@ impl ProviderManager {
     config: ProviderConfig,
     config_hash: String,
     common: CommonProviderState,
+    supervisor: ProviderSupervisor,
   ) -> anyhow::Result<(task::JoinHandle<()>, RuntimeType)> {
+    let provider_type = config.provider_type();
+
     let runtime_type = match config {
-      ProviderConfig::Ip(..) | ProviderConfig::Weather(..) => {
-        RuntimeType::Async
-      }
+      ProviderConfig::Ip(..)
+      | ProviderConfig::Weather(..)
+      | ProviderConfig::Derived(..)
+      | ProviderConfig::CiStatus(..)
+      | ProviderConfig::Tasks(..)
+      | ProviderConfig::Steam(..)
+      | ProviderConfig::Discord(..)
+      | ProviderConfig::Spotify(..)
+      | ProviderConfig::Timer(..)
+      | ProviderConfig::KeepAwake(..) => RuntimeType::Async,
       _ => RuntimeType::Sync,
     };
 
+    // Sources of a derived provider are started up front, since
+    // starting them requires the manager.
+    let (config, mut derived_sources) = match config {
+      ProviderConfig::Derived(mut config) => {
+        let configs = std::mem::take(&mut config.providers);
+        let sources = self.start_sources(&config_hash, configs)?;
+        (ProviderConfig::Derived(config), Some(sources))
+      }
+      config => (config, None),
+    };
+
+    // Logs of the provider are scoped to its span, so that they can be
+    // filtered by provider type.
+    let span = provider_span(provider_type, &config_hash);
+
     // Spawn the provider's task based on its runtime type.
     let task_handle = match &runtime_type {
-      RuntimeType::Async => task::spawn(async move {
-        match config {
-          ProviderConfig::Ip(config) => {
-            let mut provider = IpProvider::new(config, common);
-            provider.start_async().await;
-          }
-          ProviderConfig::Weather(config) => {
-            let mut provider = WeatherProvider::new(config, common);
-            provider.start_async().await;
+      RuntimeType::Async => task::spawn(
+        async move {
+          match config {
+            ProviderConfig::Ip(config) => {
+              supervisor.run_async(IpProvider::new(config, common)).await;
+            }
+            ProviderConfig::Weather(config) => {
+              supervisor
+                .run_async(WeatherProvider::new(config, common))
+                .await;
+            }
+            ProviderConfig::KeepAwake(config) => {
+              supervisor
+                .run_async(KeepAwakeProvider::new(config, common))
+                .await;
+            }
+            ProviderConfig::Timer(config) => {
+              supervisor
+                .run_async(TimerProvider::new(config, common))
+                .await;
+            }
+            ProviderConfig::Spotify(config) => {
+              supervisor
+                .run_async(SpotifyProvider::new(config, common))
+                .await;
+            }
+            ProviderConfig::Discord(config) => {
+              supervisor
+                .run_async(DiscordProvider::new(config, common))
+                .await;
+            }
+            ProviderConfig::Steam(config) => {
+              supervisor
+                .run_async(SteamProvider::new(config, common))
+                .await;
+            }
+            ProviderConfig::Tasks(config) => {
+              supervisor
+                .run_async(TasksProvider::new(config, common))
+                .await;
+            }
+            ProviderConfig::CiStatus(config) => {
+              supervisor
+                .run_async(CiStatusProvider::new(config, common))
+                .await;
+            }
+            ProviderConfig::Derived(config) => {
+              let sources = derived_sources.take().unwrap();
+              supervisor
+                .run_async(DerivedProvider::new(config, sources, common))
+                .await;
+            }
+            _ => unreachable!(),
           }
-          _ => unreachable!(),
         }
-
-        info!("Provider stopped: {}", config_hash);
-      }),
-      RuntimeType::Sync => task::spawn_blocking(move || {
-        match config {
-          #[cfg(windows)]
-          ProviderConfig::Audio(config) => {
-            let mut provider = AudioProvider::new(config, common);
-            provider.start_sync();
-          }
-          ProviderConfig::Battery(config) => {
-            let mut provider = BatteryProvider::new(config, common);
-            provider.start_sync();
-          }
-          ProviderConfig::Cpu(config) => {
-            let mut provider = CpuProvider::new(config, common);
-            provider.start_sync();
-          }
-          ProviderConfig::Host(config) => {
-            let mut provider = HostProvider::new(config, common);
-            provider.start_sync();
-          }
-          #[cfg(windows)]
-          ProviderConfig::Komorebi(config) => {
-            let mut provider = KomorebiProvider::new(config, common);
-            provider.start_sync();
-          }
-          #[cfg(windows)]
-          ProviderConfig::Media(config) => {
-            let mut provider = MediaProvider::new(config, common);
-            provider.start_sync();
-          }
-          ProviderConfig::Memory(config) => {
-            let mut provider = MemoryProvider::new(config, common);
-            provider.start_sync();
-          }
-          ProviderConfig::Disk(config) => {
-            let mut provider = DiskProvider::new(config, common);
-            provider.start_sync();
+        .instrument(span),
+      ),
+      RuntimeType::Sync => self.sync_threads.spawn(
+        span,
+        supervisor,
+        common.input.sync_rx.clone(),
+        move || -> Box<dyn FnMut()> {
+          match config {
+            #[cfg(any(windows, target_os = "linux"))]
+            ProviderConfig::Audio(config) => {
+              let mut provider = AudioProvider::new(config, common);
+              Box::new(move || provider.start_sync())
+            }
+            ProviderConfig::Battery(config) => {
+              let mut provider = BatteryProvider::new(config, common);
+              Box::new(move || provider.start_sync())
+            }
+            ProviderConfig::Cpu(config) => {
+              let mut provider = CpuProvider::new(config, common);
+              Box::new(move || provider.start_sync())
+            }
+            ProviderConfig::Host(config) => {
+              let mut provider = HostProvider::new(config, common);
+              Box::new(move || provider.start_sync())
+            }
+            #[cfg(windows)]
+            ProviderConfig::Komorebi(config) => {
+              let mut provider = KomorebiProvider::new(config, common);
+              Box::new(move || provider.start_sync())
+            }
+            #[cfg(windows)]
+            ProviderConfig::Media(config) => {
+              let mut provider = MediaProvider::new(config, common);
+              Box::new(move || provider.start_sync())
+            }
+            ProviderConfig::Memory(config) => {
+              let mut provider = MemoryProvider::new(config, common);
+              Box::new(move || provider.start_sync())
+            }
+            ProviderConfig::Disk(config) => {
+              let mut provider = DiskProvider::new(config, common);
+              Box::new(move || provider.start_sync())
+            }
+            ProviderConfig::Network(config) => {
+              let mut provider = NetworkProvider::new(config, common);
+              Box::new(move || provider.start_sync())
+            }
+            #[cfg(windows)]
+            ProviderConfig::Keyboard(config) => {
+              let mut provider = KeyboardProvider::new(config, common);
+              Box::new(move || provider.start_sync())
+            }
+            ProviderConfig::Printer(config) => {
+              let mut provider = PrinterProvider::new(config, common);
+              Box::new(move || provider.start_sync())
+            }
+            ProviderConfig::Meeting(config) => {
+              let mut provider = MeetingProvider::new(config, common);
+              Box::new(move || provider.start_sync())
+            }
+            ProviderConfig::Vpn(config) => {
+              let mut provider = VpnProvider::new(config, common);
+              Box::new(move || provider.start_sync())
+            }
+            #[cfg(target_os = "linux")]
+            ProviderConfig::Hyprland(config) => {
+              let mut provider = HyprlandProvider::new(config, common);
+              Box::new(move || provider.start_sync())
+            }
+            #[cfg(target_os = "linux")]
+            ProviderConfig::I3(config) => {
+              let mut provider = I3Provider::new(config, common);
+              Box::new(move || provider.start_sync())
+            }
+            #[cfg(windows)]
+            ProviderConfig::VirtualDesktop(config) => {
+              let mut provider =
+                VirtualDesktopProvider::new(config, common);
+              Box::new(move || provider.start_sync())
+            }
+            #[cfg(any(windows, target_os = "linux"))]
+            ProviderConfig::Session(config) => {
+              let mut provider = SessionProvider::new(config, common);
+              Box::new(move || provider.start_sync())
+            }
+            ProviderConfig::Countdown(config) => {
+              let mut provider = CountdownProvider::new(config, common);
+              Box::new(move || provider.start_sync())
+            }
+            #[cfg(any(windows, target_os = "linux"))]
+            ProviderConfig::PerfCounters(config) => {
+              let mut provider = PerfCountersProvider::new(config, common);
+              Box::new(move || provider.start_sync())
+            }
+            #[cfg(any(windows, target_os = "linux"))]
+            ProviderConfig::EventLog(config) => {
+              let mut provider = EventLogProvider::new(config, common);
+              Box::new(move || provider.start_sync())
+            }
+            #[cfg(any(windows, target_os = "linux"))]
+            ProviderConfig::InputStats(config) => {
+              let mut provider = InputStatsProvider::new(config, common);
+              Box::new(move || provider.start_sync())
+            }
+            #[cfg(windows)]
+            ProviderConfig::Fps(config) => {
+              let mut provider = FpsProvider::new(config, common);
+              Box::new(move || provider.start_sync())
+            }
+            #[cfg(windows)]
+            ProviderConfig::FancyZones(config) => {
+              let mut provider = FancyZonesProvider::new(config, common);
+              Box::new(move || provider.start_sync())
+            }
+            ProviderConfig::SshGpg(config) => {
+              let mut provider = SshGpgProvider::new(config, common);
+              Box::new(move || provider.start_sync())
+            }
+            ProviderConfig::Peripherals(config) => {
+              let mut provider = PeripheralsProvider::new(config, common);
+              Box::new(move || provider.start_sync())
+            }
+            ProviderConfig::Camera(config) => {
+              let mut provider = CameraProvider::new(config, common);
+              Box::new(move || provider.start_sync())
+            }
+            _ => unreachable!(),
           }
-          ProviderConfig::Network(config) => {
-            let mut provider = NetworkProvider::new(config, common);
-            provider.start_sync();
-          }
-          #[cfg(windows)]
-          ProviderConfig::Keyboard(config) => {
-            let mut provider = KeyboardProvider::new(config, common);
-            provider.start_sync();
-          }
-          _ => unreachable!(),
-        }
-
-        info!("Provider stopped: {}", config_hash);
-      }),
+        },
+      )?,
     };
 
     Ok((task_handle, runtime_type))
@@ -363,11 +725,49 @@ impl ProviderManager {
     rx.await?.map_err(anyhow::Error::msg)
   }
 
-  /// Destroys and cleans up the provider with the given config.
-  pub async fn stop(&self, config_hash: String) -> anyhow::Result<()> {
+  /// Gets the data of a provider attachment by its ID, along with the
+  /// type of the provider that emitted it.
+  pub async fn attachment(
+    &self,
+    id: &str,
+  ) -> anyhow::Result<(Arc<[u8]>, &'static str)> {
+    let (config_hash, data) = self
+      .attachments
+      .get(id)
+      .with_context(|| format!("No attachment found with ID '{}'.", id))?;
+
+    let provider_type = self
+      .provider_refs
+      .lock()
+      .await
+      .get(&config_hash)
+      .map(|provider_ref| provider_ref.provider_type)
+      .with_context(|| format!("No attachment found with ID '{}'.", id))?;
+
+    Ok((data, provider_type))
+  }
+
+  /// Removes a subscriber from the provider with the given config.
+  /// Destroys and cleans up the provider if it has no other subscribers.
+  pub async fn stop(
+    &self,
+    config_hash: String,
+    subscriber: &str,
+  ) -> anyhow::Result<()> {
     let provider_ref = {
       let mut provider_refs = self.provider_refs.lock().await;
 
+      let subscribers = &mut provider_refs
+        .get_mut(&config_hash)
+        .context("No provider found with config.")?
+        .subscribers;
+
+      subscribers.remove(subscriber);
+
+      if !subscribers.is_empty() {
+        return Ok(());
+      }
+
       // Evict the provider's emission from cache. Hold the lock for
       // `provider_refs` to avoid a race condition with provider
       // creation.
@@ -379,27 +779,61 @@ impl ProviderManager {
         .context("No provider found with config.")?
     };
 
-    // Send shutdown signal to the provider.
-    match provider_ref.runtime_type {
-      RuntimeType::Async => {
-        provider_ref
-          .async_input_tx
-          .send(ProviderInputMsg::Stop)
-          .await
-          .context("Failed to send shutdown signal to provider.")?;
-      }
-      RuntimeType::Sync => {
-        provider_ref
-          .sync_input_tx
-          .send(ProviderInputMsg::Stop)
-          .context("Failed to send shutdown signal to provider.")?;
+    provider_ref.stop().await
+  }
+
+  /// Removes a subscriber from all providers, and stops the ones that no
+  /// longer have subscribers.
+  ///
+  /// Used when a widget closes without unlistening from its providers
+  /// (e.g. if its webview crashed or was force-closed).
+  pub async fn stop_by_subscriber(&self, subscriber: &str) {
+    let orphaned_refs = {
+      let mut provider_refs = self.provider_refs.lock().await;
+      let mut provider_cache = self.emit_cache.lock().await;
+
+      let orphaned_hashes = provider_refs
+        .iter_mut()
+        .filter(|(_, provider_ref)| {
+          provider_ref.subscribers.remove(subscriber)
+            && provider_ref.subscribers.is_empty()
+        })
+        .map(|(config_hash, _)| config_hash.clone())
+        .collect::<Vec<_>>();
+
+      orphaned_hashes
+        .into_iter()
+        .filter_map(|config_hash| {
+          let _ = provider_cache.remove(&config_hash);
+          provider_refs.remove(&config_hash)
+        })
+        .collect::<Vec<_>>()
+    };
+
+    for provider_ref in orphaned_refs {
+      info!(
+        "Stopping orphaned provider {} of {}.",
+        provider_ref.config_hash, subscriber
+      );
+
+      if let Err(err) = provider_ref.stop().await {
+        warn!("Failed to stop orphaned provider: {:?}", err);
       }
     }
+  }
 
-    // Wait for the provider to stop.
-    provider_ref.task_handle.await?;
-
-    Ok(())
+  /// Gets the provider type of the active provider with the given
+  /// config.
+  pub async fn provider_type(
+    &self,
+    config_hash: &str,
+  ) -> Option<&'static str> {
+    self
+      .provider_refs
+      .lock()
+      .await
+      .get(config_hash)
+      .map(|provider_ref| provider_ref.provider_type)
   }
 
   /// Updates the cache with the given provider emission.
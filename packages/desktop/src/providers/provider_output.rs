@@ -1,15 +1,31 @@
+use schemars::JsonSchema;
 use serde::Serialize;
 
-#[cfg(windows)]
+#[cfg(any(windows, target_os = "linux"))]
 use super::{
-  audio::AudioOutput, keyboard::KeyboardOutput, komorebi::KomorebiOutput,
-  media::MediaOutput,
+  audio::AudioOutput, event_log::EventLogOutput,
+  input_stats::InputStatsOutput, perf_counters::PerfCountersOutput,
+  session::SessionOutput,
 };
 use super::{
-  battery::BatteryOutput, cpu::CpuOutput, disk::DiskOutput,
-  host::HostOutput, ip::IpOutput, memory::MemoryOutput,
-  network::NetworkOutput, weather::WeatherOutput,
+  battery::BatteryOutput, camera::CameraOutput, ci_status::CiStatusOutput,
+  countdown::CountdownOutput, cpu::CpuOutput, derived::DerivedOutput,
+  discord::DiscordOutput, disk::DiskOutput, host::HostOutput,
+  ip::IpOutput, keep_awake::KeepAwakeOutput, meeting::MeetingOutput,
+  memory::MemoryOutput, network::NetworkOutput,
+  peripherals::PeripheralsOutput, printer::PrinterOutput,
+  spotify::SpotifyOutput, ssh_gpg::SshGpgOutput, steam::SteamOutput,
+  tasks::TasksOutput, timer::TimerOutput, vpn::VpnOutput,
+  weather::WeatherOutput,
 };
+#[cfg(windows)]
+use super::{
+  fancy_zones::FancyZonesOutput, fps::FpsOutput, keyboard::KeyboardOutput,
+  komorebi::KomorebiOutput, media::MediaOutput,
+  virtual_desktop::VirtualDesktopOutput,
+};
+#[cfg(target_os = "linux")]
+use super::{hyprland::HyprlandOutput, i3::I3Output};
 
 /// Implements `From<T>` for `ProviderOutput` for each given variant.
 macro_rules! impl_provider_output {
@@ -24,10 +40,10 @@ macro_rules! impl_provider_output {
   };
 }
 
-#[derive(Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, JsonSchema)]
 #[serde(untagged)]
 pub enum ProviderOutput {
-  #[cfg(windows)]
+  #[cfg(any(windows, target_os = "linux"))]
   Audio(AudioOutput),
   Battery(BatteryOutput),
   Cpu(CpuOutput),
@@ -43,6 +59,39 @@ pub enum ProviderOutput {
   Weather(WeatherOutput),
   #[cfg(windows)]
   Keyboard(KeyboardOutput),
+  Printer(PrinterOutput),
+  Meeting(MeetingOutput),
+  Vpn(VpnOutput),
+  #[cfg(target_os = "linux")]
+  Hyprland(HyprlandOutput),
+  #[cfg(target_os = "linux")]
+  I3(I3Output),
+  #[cfg(windows)]
+  VirtualDesktop(VirtualDesktopOutput),
+  KeepAwake(KeepAwakeOutput),
+  #[cfg(any(windows, target_os = "linux"))]
+  Session(SessionOutput),
+  Countdown(CountdownOutput),
+  Timer(TimerOutput),
+  #[cfg(any(windows, target_os = "linux"))]
+  PerfCounters(PerfCountersOutput),
+  #[cfg(any(windows, target_os = "linux"))]
+  EventLog(EventLogOutput),
+  #[cfg(any(windows, target_os = "linux"))]
+  InputStats(InputStatsOutput),
+  #[cfg(windows)]
+  Fps(FpsOutput),
+  #[cfg(windows)]
+  FancyZones(FancyZonesOutput),
+  Spotify(SpotifyOutput),
+  Discord(DiscordOutput),
+  Steam(SteamOutput),
+  Tasks(TasksOutput),
+  CiStatus(CiStatusOutput),
+  SshGpg(SshGpgOutput),
+  Peripherals(PeripheralsOutput),
+  Camera(CameraOutput),
+  Derived(DerivedOutput),
 }
 
 impl_provider_output! {
@@ -53,13 +102,45 @@ impl_provider_output! {
   Memory(MemoryOutput),
   Disk(DiskOutput),
   Network(NetworkOutput),
-  Weather(WeatherOutput)
+  Weather(WeatherOutput),
+  Printer(PrinterOutput),
+  Meeting(MeetingOutput),
+  Vpn(VpnOutput),
+  KeepAwake(KeepAwakeOutput),
+  Countdown(CountdownOutput),
+  Timer(TimerOutput),
+  Spotify(SpotifyOutput),
+  Discord(DiscordOutput),
+  Steam(SteamOutput),
+  Tasks(TasksOutput),
+  CiStatus(CiStatusOutput),
+  SshGpg(SshGpgOutput),
+  Peripherals(PeripheralsOutput),
+  Camera(CameraOutput),
+  Derived(DerivedOutput)
 }
 
-#[cfg(windows)]
+#[cfg(any(windows, target_os = "linux"))]
 impl_provider_output! {
   Audio(AudioOutput),
+  Session(SessionOutput),
+  PerfCounters(PerfCountersOutput),
+  EventLog(EventLogOutput),
+  InputStats(InputStatsOutput)
+}
+
+#[cfg(windows)]
+impl_provider_output! {
   Komorebi(KomorebiOutput),
   Media(MediaOutput),
-  Keyboard(KeyboardOutput)
+  Keyboard(KeyboardOutput),
+  VirtualDesktop(VirtualDesktopOutput),
+  Fps(FpsOutput),
+  FancyZones(FancyZonesOutput)
+}
+
+#[cfg(target_os = "linux")]
+impl_provider_output! {
+  Hyprland(HyprlandOutput),
+  I3(I3Output)
 }
@@ -0,0 +1,262 @@
+use anyhow::bail;
+use schemars::{gen::SchemaSettings, schema::RootSchema, JsonSchema};
+use serde::Serialize;
+use serde_json::json;
+
+#[cfg(any(windows, target_os = "linux"))]
+use super::{
+  audio::{AudioOutput, AudioProviderConfig},
+  event_log::{EventLogOutput, EventLogProviderConfig},
+  input_stats::{InputStatsOutput, InputStatsProviderConfig},
+  perf_counters::{PerfCountersOutput, PerfCountersProviderConfig},
+  session::{SessionOutput, SessionProviderConfig},
+};
+use super::{
+  battery::{BatteryOutput, BatteryProviderConfig},
+  camera::{CameraOutput, CameraProviderConfig},
+  ci_status::{CiStatusOutput, CiStatusProviderConfig},
+  countdown::{CountdownOutput, CountdownProviderConfig},
+  cpu::{CpuOutput, CpuProviderConfig},
+  derived::{DerivedOutput, DerivedProviderConfig},
+  discord::{DiscordOutput, DiscordProviderConfig},
+  disk::{DiskOutput, DiskProviderConfig},
+  host::{HostOutput, HostProviderConfig},
+  ip::{IpOutput, IpProviderConfig},
+  keep_awake::{KeepAwakeOutput, KeepAwakeProviderConfig},
+  meeting::{MeetingOutput, MeetingProviderConfig},
+  memory::{MemoryOutput, MemoryProviderConfig},
+  network::{NetworkOutput, NetworkProviderConfig},
+  peripherals::{PeripheralsOutput, PeripheralsProviderConfig},
+  printer::{PrinterOutput, PrinterProviderConfig},
+  spotify::{SpotifyOutput, SpotifyProviderConfig},
+  ssh_gpg::{SshGpgOutput, SshGpgProviderConfig},
+  steam::{SteamOutput, SteamProviderConfig},
+  tasks::{TasksOutput, TasksProviderConfig},
+  timer::{TimerOutput, TimerProviderConfig},
+  vpn::{VpnOutput, VpnProviderConfig},
+  weather::{WeatherOutput, WeatherProviderConfig},
+  ProviderConfig, ProviderOutput,
+};
+#[cfg(windows)]
+use super::{
+  fancy_zones::{FancyZonesOutput, FancyZonesProviderConfig},
+  fps::{FpsOutput, FpsProviderConfig},
+  keyboard::{KeyboardOutput, KeyboardProviderConfig},
+  komorebi::{KomorebiOutput, KomorebiProviderConfig},
+  media::{MediaOutput, MediaProviderConfig},
+  virtual_desktop::{VirtualDesktopOutput, VirtualDesktopProviderConfig},
+};
+#[cfg(target_os = "linux")]
+use super::{
+  hyprland::{HyprlandOutput, HyprlandProviderConfig},
+  i3::{I3Output, I3ProviderConfig},
+};
+
+const ALL_PLATFORMS: &[&str] = &["windows", "macos", "linux"];
+
+/// Platforms that each provider type is available on. Includes
+/// providers that aren't compiled in on the current platform.
+const PROVIDER_PLATFORMS: &[(&str, &[&str])] = &[
+  ("audio", &["windows", "linux"]),
+  ("battery", ALL_PLATFORMS),
+  ("camera", ALL_PLATFORMS),
+  ("ci_status", ALL_PLATFORMS),
+  ("countdown", ALL_PLATFORMS),
+  ("cpu", ALL_PLATFORMS),
+  ("derived", ALL_PLATFORMS),
+  ("discord", ALL_PLATFORMS),
+  ("disk", ALL_PLATFORMS),
+  ("event_log", &["windows", "linux"]),
+  ("fancy_zones", &["windows"]),
+  ("fps", &["windows"]),
+  ("host", ALL_PLATFORMS),
+  ("hyprland", &["linux"]),
+  ("i3", &["linux"]),
+  ("input_stats", &["windows", "linux"]),
+  ("ip", ALL_PLATFORMS),
+  ("keep_awake", ALL_PLATFORMS),
+  ("keyboard", &["windows"]),
+  ("komorebi", &["windows"]),
+  ("media", &["windows"]),
+  ("meeting", &["windows", "linux"]),
+  ("memory", ALL_PLATFORMS),
+  ("network", ALL_PLATFORMS),
+  ("perf_counters", &["windows", "linux"]),
+  ("peripherals", ALL_PLATFORMS),
+  ("printer", ALL_PLATFORMS),
+  ("session", &["windows", "linux"]),
+  ("spotify", ALL_PLATFORMS),
+  ("ssh_gpg", ALL_PLATFORMS),
+  ("steam", ALL_PLATFORMS),
+  ("tasks", ALL_PLATFORMS),
+  ("timer", ALL_PLATFORMS),
+  ("virtual_desktop", &["windows"]),
+  ("vpn", ALL_PLATFORMS),
+  ("weather", ALL_PLATFORMS),
+];
+
+/// Name, supported platforms, and config and output schemas of a
+/// provider type.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProviderSchema {
+  pub name: &'static str,
+  pub platforms: &'static [&'static str],
+  pub config_schema: RootSchema,
+  pub output_schema: RootSchema,
+}
+
+/// Returns a JSON schema of all provider configs and outputs.
+///
+/// `ProviderConfig` and `ProviderOutput` are included as top-level
+/// definitions, alongside the types they reference. Used for generating
+/// the client API's TypeScript types.
+///
+/// Only covers providers available on the current platform. The
+/// platforms of every provider type are listed under
+/// `providerPlatforms`, so that schemas from each platform can be merged
+/// and checked for completeness.
+pub fn provider_schema() -> serde_json::Value {
+  let mut generator = SchemaSettings::draft07().into_generator();
+  generator.subschema_for::<ProviderConfig>();
+  generator.subschema_for::<ProviderOutput>();
+
+  let provider_platforms = PROVIDER_PLATFORMS
+    .iter()
+    .map(|(name, platforms)| (name.to_string(), json!(platforms)))
+    .collect::<serde_json::Map<_, _>>();
+
+  json!({
+    "$schema": "http://json-schema.org/draft-07/schema#",
+    "title": "Zebar providers",
+    "definitions": generator.take_definitions(),
+    "providerPlatforms": provider_platforms,
+  })
+}
+
+/// Returns the schemas of all provider types available on the current
+/// platform.
+pub fn provider_schemas() -> Vec<ProviderSchema> {
+  vec![
+    #[cfg(any(windows, target_os = "linux"))]
+    schema_for::<AudioProviderConfig, AudioOutput>("audio"),
+    schema_for::<BatteryProviderConfig, BatteryOutput>("battery"),
+    schema_for::<CpuProviderConfig, CpuOutput>("cpu"),
+    schema_for::<DiskProviderConfig, DiskOutput>("disk"),
+    schema_for::<HostProviderConfig, HostOutput>("host"),
+    schema_for::<IpProviderConfig, IpOutput>("ip"),
+    #[cfg(windows)]
+    schema_for::<KeyboardProviderConfig, KeyboardOutput>("keyboard"),
+    #[cfg(windows)]
+    schema_for::<KomorebiProviderConfig, KomorebiOutput>("komorebi"),
+    #[cfg(windows)]
+    schema_for::<MediaProviderConfig, MediaOutput>("media"),
+    schema_for::<MeetingProviderConfig, MeetingOutput>("meeting"),
+    schema_for::<MemoryProviderConfig, MemoryOutput>("memory"),
+    schema_for::<NetworkProviderConfig, NetworkOutput>("network"),
+    schema_for::<PrinterProviderConfig, PrinterOutput>("printer"),
+    schema_for::<VpnProviderConfig, VpnOutput>("vpn"),
+    schema_for::<WeatherProviderConfig, WeatherOutput>("weather"),
+    #[cfg(target_os = "linux")]
+    schema_for::<HyprlandProviderConfig, HyprlandOutput>("hyprland"),
+    #[cfg(target_os = "linux")]
+    schema_for::<I3ProviderConfig, I3Output>("i3"),
+    #[cfg(windows)]
+    schema_for::<VirtualDesktopProviderConfig, VirtualDesktopOutput>(
+      "virtual_desktop",
+    ),
+    schema_for::<KeepAwakeProviderConfig, KeepAwakeOutput>("keep_awake"),
+    #[cfg(any(windows, target_os = "linux"))]
+    schema_for::<SessionProviderConfig, SessionOutput>("session"),
+    schema_for::<CountdownProviderConfig, CountdownOutput>("countdown"),
+    schema_for::<TimerProviderConfig, TimerOutput>("timer"),
+    #[cfg(any(windows, target_os = "linux"))]
+    schema_for::<PerfCountersProviderConfig, PerfCountersOutput>(
+      "perf_counters",
+    ),
+    #[cfg(any(windows, target_os = "linux"))]
+    schema_for::<EventLogProviderConfig, EventLogOutput>("event_log"),
+    #[cfg(any(windows, target_os = "linux"))]
+    schema_for::<InputStatsProviderConfig, InputStatsOutput>(
+      "input_stats",
+    ),
+    #[cfg(windows)]
+    schema_for::<FpsProviderConfig, FpsOutput>("fps"),
+    #[cfg(windows)]
+    schema_for::<FancyZonesProviderConfig, FancyZonesOutput>(
+      "fancy_zones",
+    ),
+    schema_for::<SpotifyProviderConfig, SpotifyOutput>("spotify"),
+    schema_for::<DiscordProviderConfig, DiscordOutput>("discord"),
+    schema_for::<SteamProviderConfig, SteamOutput>("steam"),
+    schema_for::<TasksProviderConfig, TasksOutput>("tasks"),
+    schema_for::<CiStatusProviderConfig, CiStatusOutput>("ci_status"),
+    schema_for::<SshGpgProviderConfig, SshGpgOutput>("ssh_gpg"),
+    schema_for::<PeripheralsProviderConfig, PeripheralsOutput>(
+      "peripherals",
+    ),
+    schema_for::<CameraProviderConfig, CameraOutput>("camera"),
+    schema_for::<DerivedProviderConfig, DerivedOutput>("derived"),
+  ]
+}
+
+/// Checks that the provider type in a raw provider config exists and is
+/// available on the current platform.
+///
+/// Gives a clearer error than deserializing into `ProviderConfig`,
+/// which can't tell unknown and unsupported providers apart.
+pub fn check_provider_type(
+  config: &serde_json::Value,
+) -> anyhow::Result<()> {
+  let Some(provider_type) = config.get("type").and_then(|t| t.as_str())
+  else {
+    bail!("Provider config is missing a `type` field.");
+  };
+
+  let Some((_, platforms)) = PROVIDER_PLATFORMS
+    .iter()
+    .find(|(name, _)| *name == provider_type)
+  else {
+    bail!("Unknown provider type '{}'.", provider_type);
+  };
+
+  if !platforms.contains(&std::env::consts::OS) {
+    bail!(
+      "The '{}' provider is not available on this platform (supported: {}).",
+      provider_type,
+      platforms.join(", ")
+    );
+  }
+
+  // Sources of a derived provider are checked the same way.
+  if provider_type == "derived" {
+    let sources = config
+      .get("providers")
+      .and_then(|providers| providers.as_object());
+
+    for source in sources.iter().flat_map(|sources| sources.values()) {
+      check_provider_type(source)?;
+    }
+  }
+
+  Ok(())
+}
+
+fn schema_for<C: JsonSchema, O: JsonSchema>(
+  name: &'static str,
+) -> ProviderSchema {
+  let settings = SchemaSettings::draft07();
+
+  ProviderSchema {
+    name,
+    platforms: PROVIDER_PLATFORMS
+      .iter()
+      .find(|(provider, _)| *provider == name)
+      .map_or(&[], |(_, platforms)| platforms),
+    config_schema: settings
+      .clone()
+      .into_generator()
+      .into_root_schema_for::<C>(),
+    output_schema: settings.into_generator().into_root_schema_for::<O>(),
+  }
+}
@@ -0,0 +1,190 @@
+use std::{
+  any::Any,
+  future::Future,
+  panic::{self, AssertUnwindSafe},
+  pin::Pin,
+  task::{Context, Poll},
+  time::{Duration, Instant},
+};
+
+use tokio::sync::watch;
+use tracing::{error, info, warn};
+
+use super::{Provider, ProviderEmitter};
+
+/// Max number of consecutive restarts before giving up on a provider.
+const MAX_RESTARTS: u32 = 5;
+
+/// Delay before the first restart of a provider. Doubles with each
+/// consecutive restart.
+const MIN_RESTART_DELAY: Duration = Duration::from_secs(1);
+
+/// Max delay before restarting a provider.
+const MAX_RESTART_DELAY: Duration = Duration::from_secs(60);
+
+/// How long a provider has to run for its restarts to no longer count
+/// as consecutive.
+const RESTART_RESET_AFTER: Duration = Duration::from_secs(5 * 60);
+
+/// Restarts a provider when its run loop exits without being stopped
+/// (i.e. it panicked or exited unexpectedly).
+///
+/// Gives up after `MAX_RESTARTS` consecutive restarts, in which case the
+/// reason is emitted as an error rather than leaving widgets on stale
+/// data.
+pub struct ProviderSupervisor {
+  /// Hash of the provider's config.
+  config_hash: String,
+
+  /// Emitter of the provider, used for surfacing crashes.
+  emitter: ProviderEmitter,
+
+  /// Whether the provider has been asked to stop.
+  stop_rx: watch::Receiver<bool>,
+
+  /// Number of consecutive restarts.
+  restart_count: u32,
+
+  /// Delay before the next restart.
+  restart_delay: Duration,
+}
+
+impl ProviderSupervisor {
+  pub fn new(
+    config_hash: String,
+    emitter: ProviderEmitter,
+    stop_rx: watch::Receiver<bool>,
+  ) -> Self {
+    Self {
+      config_hash,
+      emitter,
+      stop_rx,
+      restart_count: 0,
+      restart_delay: MIN_RESTART_DELAY,
+    }
+  }
+
+  pub fn config_hash(&self) -> &str {
+    &self.config_hash
+  }
+
+  /// Whether the provider has been asked to stop.
+  pub fn is_stopped(&self) -> bool {
+    *self.stop_rx.borrow()
+  }
+
+  /// Decides whether to restart the provider after its run loop exited.
+  ///
+  /// Returns the delay before restarting, or `None` if the provider
+  /// should stay stopped.
+  pub fn handle_exit(
+    &mut self,
+    ran_for: Duration,
+    panic_message: Option<String>,
+  ) -> Option<Duration> {
+    if self.is_stopped() {
+      return None;
+    }
+
+    if ran_for > RESTART_RESET_AFTER {
+      self.restart_count = 0;
+      self.restart_delay = MIN_RESTART_DELAY;
+    }
+
+    let reason = match panic_message {
+      Some(message) => format!("crashed ({})", message),
+      None => "exited unexpectedly".to_string(),
+    };
+
+    if self.restart_count >= MAX_RESTARTS {
+      error!(
+        "Provider {} {}. Giving up after {} restarts.",
+        self.config_hash, reason, MAX_RESTARTS
+      );
+
+      self.emitter.emit_error(anyhow::anyhow!(
+        "Provider {} and was stopped after {} restarts.",
+        reason,
+        MAX_RESTARTS
+      ));
+
+      return None;
+    }
+
+    let delay = self.restart_delay;
+    self.restart_count += 1;
+    self.restart_delay = (self.restart_delay * 2).min(MAX_RESTART_DELAY);
+
+    warn!(
+      "Provider {} {}. Restarting in {:?} (attempt {} of {}).",
+      self.config_hash, reason, delay, self.restart_count, MAX_RESTARTS
+    );
+
+    self.emitter.emit_error(anyhow::anyhow!(
+      "Provider {} and is restarting.",
+      reason
+    ));
+
+    Some(delay)
+  }
+
+  /// Runs an async provider until it's stopped, restarting it if its run
+  /// loop exits unexpectedly.
+  pub async fn run_async<P>(mut self, mut provider: P)
+  where
+    P: Provider + Send,
+  {
+    loop {
+      let started_at = Instant::now();
+
+      let panic_message = CatchUnwind(provider.start_async())
+        .await
+        .err()
+        .map(|payload| panic_message(&*payload));
+
+      let Some(delay) =
+        self.handle_exit(started_at.elapsed(), panic_message)
+      else {
+        break;
+      };
+
+      // Stop early if requested while waiting to restart.
+      tokio::select! {
+        _ = tokio::time::sleep(delay) => {}
+        _ = self.stop_rx.wait_for(|is_stopped| *is_stopped) => break,
+      }
+    }
+
+    info!("Provider stopped: {}", self.config_hash);
+  }
+}
+
+/// Future that catches panics of the wrapped future.
+struct CatchUnwind<'a>(Pin<Box<dyn Future<Output = ()> + Send + 'a>>);
+
+impl Future for CatchUnwind<'_> {
+  type Output = Result<(), Box<dyn Any + Send>>;
+
+  fn poll(
+    mut self: Pin<&mut Self>,
+    cx: &mut Context<'_>,
+  ) -> Poll<Self::Output> {
+    let future = &mut self.0;
+
+    match panic::catch_unwind(AssertUnwindSafe(|| {
+      future.as_mut().poll(cx)
+    })) {
+      Ok(poll) => poll.map(Ok),
+      Err(payload) => Poll::Ready(Err(payload)),
+    }
+  }
+}
+
+/// Gets the message of a caught panic.
+pub fn panic_message(payload: &(dyn Any + Send)) -> String {
+  payload
+    .downcast_ref::<&str>()
+    .map(|message| message.to_string())
+    .or_else(|| payload.downcast_ref::<String>().cloned())
+    .unwrap_or_else(|| "unknown panic".to_string())
+}
@@ -0,0 +1,7 @@
+mod session_provider;
+#[cfg(target_os = "linux")]
+mod sessions_linux;
+#[cfg(windows)]
+mod sessions_windows;
+
+pub use session_provider::*;
@@ -0,0 +1,132 @@
+use anyhow::Context;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[cfg(target_os = "linux")]
+use super::sessions_linux::user_sessions;
+#[cfg(windows)]
+use super::sessions_windows::user_sessions;
+use crate::{
+  common::{IntervalOptions, SyncInterval},
+  providers::{
+    CommonProviderState, Provider, ProviderInputMsg, RuntimeType,
+  },
+};
+
+#[derive(Deserialize, Debug, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct SessionProviderConfig {
+  /// How often to check the session state. Lock and unlock events are
+  /// emitted on the next check after they occur.
+  #[serde(deserialize_with = "crate::common::deserialize_duration_ms")]
+  #[schemars(schema_with = "crate::common::duration_schema")]
+  pub refresh_interval: u64,
+
+  /// Alignment and jitter of refreshes.
+  #[serde(default)]
+  pub refresh_options: IntervalOptions,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct SessionOutput {
+  /// Name of the user that Zebar is running as.
+  pub username: String,
+
+  /// Whether the session that Zebar is running in is locked.
+  pub is_locked: bool,
+
+  /// Whether the session that Zebar is running in is in the foreground
+  /// (i.e. not switched away from via fast user switching).
+  pub is_active: bool,
+
+  /// Login time of the current session as a Unix timestamp in
+  /// milliseconds.
+  pub login_time: Option<u64>,
+
+  /// All user sessions on the machine, including those of other users.
+  pub sessions: Vec<UserSession>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct UserSession {
+  pub id: String,
+  pub username: String,
+  pub is_locked: bool,
+  pub is_active: bool,
+
+  /// Whether this is the session that Zebar is running in.
+  pub is_current: bool,
+
+  /// Login time as a Unix timestamp in milliseconds.
+  pub login_time: Option<u64>,
+}
+
+pub struct SessionProvider {
+  config: SessionProviderConfig,
+  common: CommonProviderState,
+}
+
+impl SessionProvider {
+  pub fn new(
+    config: SessionProviderConfig,
+    common: CommonProviderState,
+  ) -> SessionProvider {
+    SessionProvider { config, common }
+  }
+
+  fn run_interval(&self) -> anyhow::Result<SessionOutput> {
+    let sessions = user_sessions()?;
+    let current = find_current_session(&sessions)?;
+
+    Ok(SessionOutput {
+      username: current.username.clone(),
+      is_locked: current.is_locked,
+      is_active: current.is_active,
+      login_time: current.login_time,
+      sessions,
+    })
+  }
+}
+
+impl Provider for SessionProvider {
+  fn runtime_type(&self) -> RuntimeType {
+    RuntimeType::Sync
+  }
+
+  fn start_sync(&mut self) {
+    let mut interval = SyncInterval::with_options(
+      self.config.refresh_interval,
+      &self.config.refresh_options,
+    );
+
+    loop {
+      crossbeam::select! {
+        recv(interval.tick()) -> _ => {
+          let output = self.run_interval();
+          self.common.emitter.emit_output_cached(output);
+        }
+        recv(self.common.input.sync_rx) -> input => {
+          if let Ok(ProviderInputMsg::Stop) = input {
+            break;
+          }
+        }
+      }
+    }
+  }
+}
+
+/// Gets the session that Zebar is running in.
+pub fn current_user_session() -> anyhow::Result<UserSession> {
+  find_current_session(&user_sessions()?).cloned()
+}
+
+fn find_current_session(
+  sessions: &[UserSession],
+) -> anyhow::Result<&UserSession> {
+  sessions
+    .iter()
+    .find(|session| session.is_current)
+    .context("Unable to find the current session.")
+}
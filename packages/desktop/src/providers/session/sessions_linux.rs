@@ -0,0 +1,96 @@
+use std::{collections::HashMap, process::Command};
+
+use anyhow::{bail, Context};
+
+use super::UserSession;
+
+/// Gets all user sessions via `loginctl`.
+pub fn user_sessions() -> anyhow::Result<Vec<UserSession>> {
+  let session_ids = loginctl(&["list-sessions", "--no-legend"])?
+    .lines()
+    .filter_map(|line| line.split_whitespace().next())
+    .map(String::from)
+    .collect::<Vec<_>>();
+
+  if session_ids.is_empty() {
+    return Ok(Vec::new());
+  }
+
+  let current_session_id = current_session_id();
+
+  let mut args = vec![
+    "show-session",
+    "--property=Id",
+    "--property=Name",
+    "--property=Class",
+    "--property=Active",
+    "--property=LockedHint",
+    "--property=Timestamp",
+    "--timestamp=unix",
+  ];
+
+  args.extend(session_ids.iter().map(String::as_str));
+
+  // Properties of each session are separated by a blank line.
+  let output = loginctl(&args)?;
+
+  Ok(
+    output
+      .split("\n\n")
+      .map(parse_properties)
+      .filter(|props| {
+        props.get("Class").is_some_and(|class| class == "user")
+      })
+      .map(|props| {
+        let id = props.get("Id").cloned().unwrap_or_default();
+
+        UserSession {
+          is_current: current_session_id.as_ref() == Some(&id),
+          id,
+          username: props.get("Name").cloned().unwrap_or_default(),
+          is_locked: props.get("LockedHint").is_some_and(|v| v == "yes"),
+          is_active: props.get("Active").is_some_and(|v| v == "yes"),
+          // Timestamps are formatted as `@<SECONDS>`.
+          login_time: props
+            .get("Timestamp")
+            .and_then(|v| v.trim_start_matches('@').parse::<u64>().ok())
+            .map(|secs| secs * 1000),
+        }
+      })
+      .collect(),
+  )
+}
+
+/// Gets the ID of the session that Zebar is running in.
+fn current_session_id() -> Option<String> {
+  std::env::var("XDG_SESSION_ID").ok().or_else(|| {
+    loginctl(&["show-session", "self", "--property=Id", "--value"])
+      .ok()
+      .map(|id| id.trim().to_string())
+  })
+}
+
+/// Parses `KEY=VALUE` lines into a map.
+fn parse_properties(block: &str) -> HashMap<String, String> {
+  block
+    .lines()
+    .filter_map(|line| line.split_once('='))
+    .map(|(key, value)| (key.to_string(), value.to_string()))
+    .collect()
+}
+
+fn loginctl(args: &[&str]) -> anyhow::Result<String> {
+  let output = Command::new("loginctl")
+    .args(args)
+    .output()
+    .context("Unable to run `loginctl`. Is systemd installed?")?;
+
+  if !output.status.success() {
+    bail!(
+      "`loginctl` failed: {}",
+      String::from_utf8_lossy(&output.stderr).trim()
+    );
+  }
+
+  Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
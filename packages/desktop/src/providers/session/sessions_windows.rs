@@ -0,0 +1,95 @@
+use anyhow::Context;
+use windows::{
+  core::PWSTR,
+  Win32::System::RemoteDesktop::{
+    ProcessIdToSessionId, WTSActive, WTSEnumerateSessionsW, WTSFreeMemory,
+    WTSQuerySessionInformationW, WTSSessionInfoEx, WTSINFOEXW,
+    WTS_CURRENT_SERVER_HANDLE, WTS_SESSIONSTATE_LOCK, WTS_SESSION_INFOW,
+  },
+};
+
+use super::UserSession;
+
+/// Difference between the Windows epoch (1601-01-01) and the Unix epoch
+/// in 100-nanosecond intervals.
+const WINDOWS_TO_UNIX_EPOCH: i64 = 116_444_736_000_000_000;
+
+/// Gets all sessions that have a user logged in via the WTS API.
+pub fn user_sessions() -> anyhow::Result<Vec<UserSession>> {
+  let mut current_session_id = 0;
+  unsafe {
+    ProcessIdToSessionId(std::process::id(), &mut current_session_id)
+  }
+  .context("Failed to get current session ID.")?;
+
+  let mut session_infos = std::ptr::null_mut::<WTS_SESSION_INFOW>();
+  let mut count = 0;
+
+  unsafe {
+    WTSEnumerateSessionsW(
+      WTS_CURRENT_SERVER_HANDLE,
+      0,
+      1,
+      &mut session_infos,
+      &mut count,
+    )
+  }
+  .context("Failed to enumerate sessions.")?;
+
+  let session_ids =
+    unsafe { std::slice::from_raw_parts(session_infos, count as usize) }
+      .iter()
+      .map(|info| info.SessionId)
+      .collect::<Vec<_>>();
+
+  unsafe { WTSFreeMemory(session_infos as _) };
+
+  // Sessions without a user (e.g. the services session) are skipped.
+  Ok(
+    session_ids
+      .into_iter()
+      .filter_map(|id| user_session(id, id == current_session_id))
+      .collect(),
+  )
+}
+
+fn user_session(session_id: u32, is_current: bool) -> Option<UserSession> {
+  let mut buffer = PWSTR::null();
+  let mut bytes = 0;
+
+  unsafe {
+    WTSQuerySessionInformationW(
+      WTS_CURRENT_SERVER_HANDLE,
+      session_id,
+      WTSSessionInfoEx,
+      &mut buffer,
+      &mut bytes,
+    )
+  }
+  .ok()?;
+
+  let info = unsafe { &*(buffer.0 as *const WTSINFOEXW) };
+  let info = unsafe { &info.Data.WTSInfoExLevel1 };
+
+  let username = from_wide(&info.UserName);
+
+  let session = (!username.is_empty()).then(|| UserSession {
+    id: session_id.to_string(),
+    username,
+    is_locked: info.SessionFlags == WTS_SESSIONSTATE_LOCK as i32,
+    is_active: info.SessionState == WTSActive,
+    is_current,
+    login_time: (info.LogonTime > 0)
+      .then(|| ((info.LogonTime - WINDOWS_TO_UNIX_EPOCH) / 10_000) as u64),
+  });
+
+  unsafe { WTSFreeMemory(buffer.0 as _) };
+
+  session
+}
+
+/// Converts a null-terminated UTF-16 buffer to a string.
+fn from_wide(buffer: &[u16]) -> String {
+  let len = buffer.iter().position(|&c| c == 0).unwrap_or(buffer.len());
+  String::from_utf16_lossy(&buffer[..len])
+}
@@ -0,0 +1,4 @@
+mod spotify_provider;
+mod spotify_res;
+
+pub use spotify_provider::*;
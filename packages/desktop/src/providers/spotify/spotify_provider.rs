@@ -0,0 +1,443 @@
+use std::sync::Arc;
+
+use anyhow::Context;
+use async_trait::async_trait;
+use reqwest::{Method, Response, StatusCode};
+use schemars::JsonSchema;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use serde_json::json;
+
+use super::spotify_res::{
+  DeviceRes, DevicesRes, PlaybackStateRes, QueueRes, TrackRes,
+};
+use crate::{
+  common::{AsyncInterval, IntervalOptions},
+  providers::{
+    common::{HttpRequest, OAuthClient, OAuthConfig, TlsConfig},
+    CommonProviderState, Provider, ProviderFunction,
+    ProviderFunctionResponse, ProviderInputMsg, RuntimeType,
+    SpotifyFunction,
+  },
+};
+
+const API_URL: &str = "https://api.spotify.com/v1";
+
+/// Permissions requested from the user.
+const SCOPES: &str =
+  "user-read-playback-state user-modify-playback-state \
+  user-read-currently-playing user-library-read user-library-modify";
+
+/// Max number of upcoming tracks to output.
+const MAX_QUEUE_LENGTH: usize = 10;
+
+#[derive(Deserialize, Debug, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct SpotifyProviderConfig {
+  #[serde(deserialize_with = "crate::common::deserialize_duration_ms")]
+  #[schemars(schema_with = "crate::common::duration_schema")]
+  pub refresh_interval: u64,
+
+  /// Alignment and jitter of refreshes.
+  #[serde(default)]
+  pub refresh_options: IntervalOptions,
+
+  /// Client ID of a Spotify app created in the Spotify developer
+  /// dashboard.
+  pub client_id: String,
+
+  /// Port of the redirect URI. The app must have
+  /// `http://127.0.0.1:<PORT>/callback` registered as a redirect URI.
+  pub redirect_port: u16,
+
+  /// TLS options for requests to Spotify.
+  pub tls: Option<TlsConfig>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct SpotifyOutput {
+  /// Whether the user has authorized access to their Spotify account.
+  /// Call the `authorize` function if `false`.
+  pub is_authorized: bool,
+
+  pub is_playing: bool,
+  pub track: Option<SpotifyTrack>,
+
+  /// Playback position in milliseconds.
+  pub position: u64,
+
+  pub is_shuffled: bool,
+
+  /// Repeat mode: `off`, `track`, or `context`.
+  pub repeat: String,
+
+  /// Device that's currently playing.
+  pub device: Option<SpotifyDevice>,
+
+  /// All available playback devices.
+  pub devices: Vec<SpotifyDevice>,
+
+  /// Upcoming tracks in the queue.
+  pub queue: Vec<SpotifyTrack>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct SpotifyTrack {
+  pub id: Option<String>,
+  pub title: String,
+  pub artists: Vec<String>,
+  pub album: Option<String>,
+  pub album_art_url: Option<String>,
+
+  /// Duration in milliseconds.
+  pub duration: u64,
+
+  /// Whether the track is in the user's liked songs. Always `false` for
+  /// tracks in the queue.
+  pub is_liked: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct SpotifyDevice {
+  pub id: Option<String>,
+  pub name: String,
+
+  /// Type of device (e.g. `Computer` or `Smartphone`).
+  pub device_type: String,
+
+  pub is_active: bool,
+
+  /// Volume from 0 to 100. `None` if the device doesn't support volume
+  /// control.
+  pub volume: Option<u32>,
+}
+
+pub struct SpotifyProvider {
+  config: SpotifyProviderConfig,
+  common: CommonProviderState,
+  auth: Arc<OAuthClient>,
+
+  /// Like status of the current track, cached by track ID.
+  liked_track: Option<(String, bool)>,
+}
+
+impl SpotifyProvider {
+  pub fn new(
+    config: SpotifyProviderConfig,
+    common: CommonProviderState,
+  ) -> SpotifyProvider {
+    let auth = OAuthClient::new(
+      OAuthConfig {
+        service_name: "Spotify",
+        authorize_url: "https://accounts.spotify.com/authorize",
+        token_url: "https://accounts.spotify.com/api/token",
+        scopes: SCOPES,
+        refresh_token_secret: "spotify_refresh_token",
+        client_id: config.client_id.clone(),
+        redirect_port: config.redirect_port,
+      },
+      common.http_client.clone(),
+    );
+
+    SpotifyProvider {
+      config,
+      common,
+      auth: Arc::new(auth),
+      liked_track: None,
+    }
+  }
+
+  async fn run_interval(&mut self) -> anyhow::Result<SpotifyOutput> {
+    if self.auth.access_token().await?.is_none() {
+      return Ok(SpotifyOutput::default());
+    }
+
+    let playback = self.get::<PlaybackStateRes>("/me/player").await?;
+    let devices = self
+      .get::<DevicesRes>("/me/player/devices")
+      .await?
+      .map_or(Vec::new(), |res| res.devices);
+
+    let Some(playback) = playback else {
+      return Ok(SpotifyOutput {
+        is_authorized: true,
+        devices: devices.into_iter().map(to_device).collect(),
+        ..Default::default()
+      });
+    };
+
+    let is_liked = match playback.item.as_ref().and_then(|t| t.id.clone())
+    {
+      Some(id) => self.is_liked(&id).await?,
+      None => false,
+    };
+
+    let queue = self
+      .get::<QueueRes>("/me/player/queue")
+      .await?
+      .map_or(Vec::new(), |res| res.queue);
+
+    Ok(SpotifyOutput {
+      is_authorized: true,
+      is_playing: playback.is_playing,
+      track: playback.item.map(|track| to_track(track, is_liked)),
+      position: playback.progress_ms.unwrap_or(0),
+      is_shuffled: playback.shuffle_state,
+      repeat: playback.repeat_state,
+      device: playback.device.map(to_device),
+      devices: devices.into_iter().map(to_device).collect(),
+      queue: queue
+        .into_iter()
+        .take(MAX_QUEUE_LENGTH)
+        .map(|track| to_track(track, false))
+        .collect(),
+    })
+  }
+
+  /// Whether the track is in the user's liked songs. Only fetched when
+  /// the track changes.
+  async fn is_liked(&mut self, track_id: &str) -> anyhow::Result<bool> {
+    if let Some((id, is_liked)) = &self.liked_track {
+      if id == track_id {
+        return Ok(*is_liked);
+      }
+    }
+
+    let is_liked = self
+      .get::<Vec<bool>>(&format!("/me/tracks/contains?ids={}", track_id))
+      .await?
+      .and_then(|res| res.first().copied())
+      .unwrap_or(false);
+
+    self.liked_track = Some((track_id.to_string(), is_liked));
+    Ok(is_liked)
+  }
+
+  async fn handle_function(
+    &mut self,
+    function: SpotifyFunction,
+  ) -> anyhow::Result<ProviderFunctionResponse> {
+    match function {
+      SpotifyFunction::Authorize => unreachable!(),
+      SpotifyFunction::Logout => {
+        self.auth.logout().await?;
+      }
+      SpotifyFunction::Play => {
+        self.send(Method::PUT, "/me/player/play").await?;
+      }
+      SpotifyFunction::Pause => {
+        self.send(Method::PUT, "/me/player/pause").await?;
+      }
+      SpotifyFunction::TogglePlayPause => {
+        let is_playing = self
+          .get::<PlaybackStateRes>("/me/player")
+          .await?
+          .is_some_and(|playback| playback.is_playing);
+
+        let path = match is_playing {
+          true => "/me/player/pause",
+          false => "/me/player/play",
+        };
+
+        self.send(Method::PUT, path).await?;
+      }
+      SpotifyFunction::Next => {
+        self.send(Method::POST, "/me/player/next").await?;
+      }
+      SpotifyFunction::Previous => {
+        self.send(Method::POST, "/me/player/previous").await?;
+      }
+      SpotifyFunction::Seek(args) => {
+        let path =
+          format!("/me/player/seek?position_ms={}", args.position);
+        self.send(Method::PUT, &path).await?;
+      }
+      SpotifyFunction::SetVolume(args) => {
+        let path = format!(
+          "/me/player/volume?volume_percent={}",
+          args.volume.min(100)
+        );
+        self.send(Method::PUT, &path).await?;
+      }
+      SpotifyFunction::TransferPlayback(args) => {
+        self
+          .request(Method::PUT, "/me/player")
+          .await?
+          .json(&json!({
+            "device_ids": [args.device_id],
+            "play": args.play.unwrap_or(false),
+          }))
+          .send()
+          .await?
+          .error_for_status()?;
+      }
+      SpotifyFunction::SetLiked(args) => {
+        let track_id = self
+          .get::<PlaybackStateRes>("/me/player")
+          .await?
+          .and_then(|playback| playback.item)
+          .and_then(|track| track.id)
+          .context("No track is playing.")?;
+
+        let method = match args.is_liked {
+          true => Method::PUT,
+          false => Method::DELETE,
+        };
+
+        self
+          .send(method, &format!("/me/tracks?ids={}", track_id))
+          .await?;
+
+        self.liked_track = Some((track_id, args.is_liked));
+      }
+    };
+
+    Ok(ProviderFunctionResponse::Null)
+  }
+
+  /// Builds an authorized request to the Web API.
+  async fn request(
+    &self,
+    method: Method,
+    path: &str,
+  ) -> anyhow::Result<HttpRequest> {
+    let token = self
+      .auth
+      .access_token()
+      .await?
+      .context("Not authorized with Spotify. Call `authorize` first.")?;
+
+    Ok(
+      self
+        .common
+        .http_client
+        .request(method, format!("{}{}", API_URL, path))
+        .bearer_auth(&token),
+    )
+  }
+
+  /// Sends a request without a response body.
+  async fn send(&self, method: Method, path: &str) -> anyhow::Result<()> {
+    // Spotify rejects `PUT` and `POST` requests without a body.
+    self
+      .request(method, path)
+      .await?
+      .json(&json!({}))
+      .send()
+      .await?
+      .error_for_status()?;
+
+    Ok(())
+  }
+
+  /// Sends a `GET` request. Returns `None` if there's no content (e.g.
+  /// when nothing is playing).
+  async fn get<T: DeserializeOwned>(
+    &self,
+    path: &str,
+  ) -> anyhow::Result<Option<T>> {
+    let res: Response = self
+      .request(Method::GET, path)
+      .await?
+      .send()
+      .await?
+      .error_for_status()?;
+
+    if res.status() == StatusCode::NO_CONTENT {
+      return Ok(None);
+    }
+
+    Ok(Some(res.json::<T>().await?))
+  }
+}
+
+fn to_track(track: TrackRes, is_liked: bool) -> SpotifyTrack {
+  SpotifyTrack {
+    id: track.id,
+    title: track.name,
+    artists: track
+      .artists
+      .into_iter()
+      .map(|artist| artist.name)
+      .collect(),
+    album_art_url: track
+      .album
+      .as_ref()
+      .and_then(|album| album.images.first())
+      .map(|image| image.url.clone()),
+    album: track.album.map(|album| album.name),
+    duration: track.duration_ms,
+    is_liked,
+  }
+}
+
+fn to_device(device: DeviceRes) -> SpotifyDevice {
+  SpotifyDevice {
+    id: device.id,
+    name: device.name,
+    device_type: device.device_type,
+    is_active: device.is_active,
+    volume: device.volume_percent,
+  }
+}
+
+#[async_trait]
+impl Provider for SpotifyProvider {
+  fn runtime_type(&self) -> RuntimeType {
+    RuntimeType::Async
+  }
+
+  async fn start_async(&mut self) {
+    let mut interval = AsyncInterval::with_options(
+      self.config.refresh_interval,
+      &self.config.refresh_options,
+    )
+    .refresh_on_wake();
+
+    loop {
+      tokio::select! {
+        _ = interval.tick() => {
+          let output = self.run_interval().await;
+          self.common.emitter.emit_output_cached(output);
+        }
+        Some(message) = self.common.input.async_rx.recv() => {
+          match message {
+            ProviderInputMsg::Stop => break,
+            ProviderInputMsg::Function(
+              ProviderFunction::Spotify(SpotifyFunction::Authorize),
+              sender,
+            ) => {
+              // Authorizing waits for the user to approve access in the
+              // browser, so it's done without blocking the provider.
+              let auth = self.auth.clone();
+
+              tokio::spawn(async move {
+                let res = auth
+                  .authorize()
+                  .await
+                  .map(|_| ProviderFunctionResponse::Null)
+                  .map_err(|err| err.to_string());
+
+                let _ = sender.send(res);
+              });
+            }
+            ProviderInputMsg::Function(
+              ProviderFunction::Spotify(spotify_function),
+              sender,
+            ) => {
+              let res = self
+                .handle_function(spotify_function)
+                .await
+                .map_err(|err| err.to_string());
+
+              sender.send(res).unwrap();
+            }
+            _ => {}
+          }
+        }
+      }
+    }
+  }
+}
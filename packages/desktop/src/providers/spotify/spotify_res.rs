@@ -0,0 +1,63 @@
+use serde::Deserialize;
+
+#[derive(Deserialize, Debug)]
+pub struct PlaybackStateRes {
+  pub device: Option<DeviceRes>,
+  pub is_playing: bool,
+  pub progress_ms: Option<u64>,
+  pub shuffle_state: bool,
+  pub repeat_state: String,
+
+  /// Currently playing track. `None` for e.g. ads.
+  pub item: Option<TrackRes>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct DevicesRes {
+  pub devices: Vec<DeviceRes>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct DeviceRes {
+  pub id: Option<String>,
+  pub name: String,
+  #[serde(rename = "type")]
+  pub device_type: String,
+  pub is_active: bool,
+  pub volume_percent: Option<u32>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct QueueRes {
+  pub queue: Vec<TrackRes>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct TrackRes {
+  pub id: Option<String>,
+  pub name: String,
+  pub duration_ms: u64,
+
+  /// Only set for tracks (not episodes).
+  #[serde(default)]
+  pub artists: Vec<ArtistRes>,
+
+  /// Only set for tracks (not episodes).
+  pub album: Option<AlbumRes>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct ArtistRes {
+  pub name: String,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct AlbumRes {
+  pub name: String,
+  pub images: Vec<ImageRes>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct ImageRes {
+  pub url: String,
+}
@@ -0,0 +1,3 @@
+mod ssh_gpg_provider;
+
+pub use ssh_gpg_provider::*;
@@ -0,0 +1,201 @@
+use std::process::Output;
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+  common::{no_window_command, IntervalOptions, SyncInterval},
+  providers::{
+    CommonProviderState, Provider, ProviderInputMsg, RuntimeType,
+  },
+};
+
+#[derive(Deserialize, Debug, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct SshGpgProviderConfig {
+  #[serde(deserialize_with = "crate::common::deserialize_duration_ms")]
+  #[schemars(schema_with = "crate::common::duration_schema")]
+  pub refresh_interval: u64,
+
+  /// Alignment and jitter of refreshes.
+  #[serde(default)]
+  pub refresh_options: IntervalOptions,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct SshGpgOutput {
+  /// Whether an SSH agent is reachable via `ssh-add`.
+  pub is_ssh_agent_running: bool,
+
+  /// Keys loaded in the SSH agent.
+  pub ssh_keys: Vec<SshKey>,
+
+  /// Whether the GPG agent is reachable via `gpg-connect-agent`.
+  pub is_gpg_agent_running: bool,
+
+  /// Number of secret keys known to the GPG agent.
+  pub gpg_key_count: usize,
+
+  /// Number of GPG keys whose passphrase is cached by the agent.
+  pub gpg_unlocked_key_count: usize,
+
+  /// Whether an OpenPGP smartcard (e.g. a YubiKey) is inserted.
+  pub is_card_present: bool,
+
+  /// Serial number of the inserted smartcard. Whether its PIN is cached
+  /// isn't reported, since checking can prompt for it.
+  pub card_serial: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct SshKey {
+  /// Key type (e.g. `ED25519` or `RSA`).
+  pub key_type: String,
+  pub bits: u32,
+  pub fingerprint: String,
+  pub comment: String,
+}
+
+pub struct SshGpgProvider {
+  config: SshGpgProviderConfig,
+  common: CommonProviderState,
+}
+
+impl SshGpgProvider {
+  pub fn new(
+    config: SshGpgProviderConfig,
+    common: CommonProviderState,
+  ) -> SshGpgProvider {
+    SshGpgProvider { config, common }
+  }
+
+  fn run_interval(&self) -> anyhow::Result<SshGpgOutput> {
+    let (is_ssh_agent_running, ssh_keys) = Self::ssh_keys();
+    let (is_gpg_agent_running, gpg_key_count, gpg_unlocked_key_count) =
+      Self::gpg_keys();
+    let card_serial = Self::card_serial();
+
+    Ok(SshGpgOutput {
+      is_ssh_agent_running,
+      ssh_keys,
+      is_gpg_agent_running,
+      gpg_key_count,
+      gpg_unlocked_key_count,
+      is_card_present: card_serial.is_some(),
+      card_serial,
+    })
+  }
+
+  /// Lists keys via `ssh-add -l`, which exits with 1 if the agent has no
+  /// keys and 2 if the agent is unreachable.
+  ///
+  /// Returns whether the agent is running and its keys.
+  fn ssh_keys() -> (bool, Vec<SshKey>) {
+    let Some(output) = run_command("ssh-add", &["-l"]) else {
+      return (false, Vec::new());
+    };
+
+    match output.status.code() {
+      Some(0) => {}
+      Some(1) => return (true, Vec::new()),
+      _ => return (false, Vec::new()),
+    }
+
+    // Lines are of the form `256 SHA256:... comment (ED25519)`.
+    let keys = String::from_utf8_lossy(&output.stdout)
+      .lines()
+      .filter_map(|line| {
+        let (bits, rest) = line.split_once(' ')?;
+        let (fingerprint, rest) = rest.split_once(' ')?;
+        let (comment, key_type) = rest.rsplit_once(' ')?;
+
+        Some(SshKey {
+          key_type: key_type
+            .trim_start_matches('(')
+            .trim_end_matches(')')
+            .to_string(),
+          bits: bits.parse().ok()?,
+          fingerprint: fingerprint.to_string(),
+          comment: comment.to_string(),
+        })
+      })
+      .collect();
+
+    (true, keys)
+  }
+
+  /// Lists secret keys via the agent's `KEYINFO --list` command.
+  ///
+  /// Returns whether the agent is running, the number of keys, and the
+  /// number of keys with a cached passphrase.
+  fn gpg_keys() -> (bool, usize, usize) {
+    let Some(output) =
+      run_command("gpg-connect-agent", &["keyinfo --list", "/bye"])
+        .filter(|output| output.status.success())
+    else {
+      return (false, 0, 0);
+    };
+
+    // Lines are of the form `S KEYINFO <KEYGRIP> D - - <CACHED> P - - -`,
+    // where the cached field is `1` if the passphrase is cached.
+    let keys = String::from_utf8_lossy(&output.stdout)
+      .lines()
+      .filter(|line| line.starts_with("S KEYINFO "))
+      .map(|line| line.split_whitespace().nth(6) == Some("1"))
+      .collect::<Vec<_>>();
+
+    let unlocked_count =
+      keys.iter().filter(|is_cached| **is_cached).count();
+
+    (true, keys.len(), unlocked_count)
+  }
+
+  /// Gets the serial number of the inserted OpenPGP card, if any.
+  fn card_serial() -> Option<String> {
+    let output =
+      run_command("gpg-connect-agent", &["scd serialno", "/bye"])?;
+
+    // Outputs `S SERIALNO <SERIAL>` if a card is inserted, and an `ERR`
+    // line otherwise.
+    String::from_utf8_lossy(&output.stdout)
+      .lines()
+      .find_map(|line| line.strip_prefix("S SERIALNO "))
+      .and_then(|rest| rest.split_whitespace().next())
+      .map(|serial| serial.to_string())
+  }
+}
+
+/// Runs a command without a console window. Returns `None` if it
+/// couldn't be started (e.g. when not installed).
+fn run_command(program: &str, args: &[&str]) -> Option<Output> {
+  no_window_command(program).args(args).output().ok()
+}
+
+impl Provider for SshGpgProvider {
+  fn runtime_type(&self) -> RuntimeType {
+    RuntimeType::Sync
+  }
+
+  fn start_sync(&mut self) {
+    let mut interval = SyncInterval::with_options(
+      self.config.refresh_interval,
+      &self.config.refresh_options,
+    );
+
+    loop {
+      crossbeam::select! {
+        recv(interval.tick()) -> _ => {
+          let output = self.run_interval();
+          self.common.emitter.emit_output_cached(output);
+        }
+        recv(self.common.input.sync_rx) -> input => {
+          if let Ok(ProviderInputMsg::Stop) = input {
+            break;
+          }
+        }
+      }
+    }
+  }
+}
@@ -0,0 +1,6 @@
+mod steam_library;
+mod steam_provider;
+mod steam_res;
+mod vdf;
+
+pub use steam_provider::*;
@@ -0,0 +1,155 @@
+use std::{
+  fs,
+  path::{Path, PathBuf},
+};
+
+use anyhow::Context;
+
+use super::vdf::parse_vdf;
+
+/// Update is running (i.e. files are being downloaded or staged).
+const STATE_UPDATE_RUNNING: u64 = 256;
+const STATE_UPDATE_PAUSED: u64 = 512;
+const STATE_UPDATE_STARTED: u64 = 1024;
+
+/// Install state of an app, read from its `appmanifest_<ID>.acf` file.
+#[derive(Debug, Clone)]
+pub struct AppManifest {
+  pub app_id: u32,
+  pub name: String,
+  pub state_flags: u64,
+  pub bytes_to_download: u64,
+  pub bytes_downloaded: u64,
+}
+
+impl AppManifest {
+  /// Whether the app has a pending, active, or paused download.
+  pub fn is_downloading(&self) -> bool {
+    self.state_flags
+      & (STATE_UPDATE_RUNNING | STATE_UPDATE_PAUSED | STATE_UPDATE_STARTED)
+      != 0
+      || self.bytes_downloaded < self.bytes_to_download
+  }
+
+  pub fn is_paused(&self) -> bool {
+    self.state_flags & STATE_UPDATE_PAUSED != 0
+  }
+}
+
+/// Finds the Steam installation directory.
+pub fn steam_dir() -> anyhow::Result<PathBuf> {
+  #[cfg(windows)]
+  {
+    use windows::Win32::System::Registry::HKEY_CURRENT_USER;
+
+    use crate::common::windows::RegKey;
+
+    RegKey::open(HKEY_CURRENT_USER, r"Software\Valve\Steam")
+      .and_then(|key| key.string_value("SteamPath"))
+      .map(PathBuf::from)
+      .context("Steam isn't installed.")
+  }
+
+  #[cfg(not(windows))]
+  {
+    let home = PathBuf::from(
+      std::env::var("HOME").context("Unable to get home directory.")?,
+    );
+
+    let candidates = if cfg!(target_os = "macos") {
+      vec![home.join("Library/Application Support/Steam")]
+    } else {
+      vec![
+        home.join(".steam/steam"),
+        home.join(".local/share/Steam"),
+        home.join(".var/app/com.valvesoftware.Steam/.local/share/Steam"),
+      ]
+    };
+
+    candidates
+      .into_iter()
+      .find(|dir| dir.join("steamapps").is_dir())
+      .context("Steam isn't installed.")
+  }
+}
+
+/// Reads the manifests of all apps across all Steam libraries.
+pub fn app_manifests(
+  steam_dir: &Path,
+) -> anyhow::Result<Vec<AppManifest>> {
+  let mut manifests = Vec::new();
+
+  for library_dir in library_dirs(steam_dir)? {
+    let Ok(entries) = fs::read_dir(library_dir.join("steamapps")) else {
+      continue;
+    };
+
+    for entry in entries.flatten() {
+      let file_name = entry.file_name().to_string_lossy().to_string();
+
+      if !file_name.starts_with("appmanifest_")
+        || !file_name.ends_with(".acf")
+      {
+        continue;
+      }
+
+      // Manifests can be mid-write while Steam is updating them.
+      if let Some(manifest) = fs::read_to_string(entry.path())
+        .ok()
+        .and_then(|text| parse_app_manifest(&text))
+      {
+        manifests.push(manifest);
+      }
+    }
+  }
+
+  Ok(manifests)
+}
+
+/// Gets the paths of all Steam libraries from `libraryfolders.vdf`.
+fn library_dirs(steam_dir: &Path) -> anyhow::Result<Vec<PathBuf>> {
+  let path = steam_dir.join("steamapps/libraryfolders.vdf");
+
+  let text = fs::read_to_string(&path)
+    .with_context(|| format!("Unable to read {}.", path.display()))?;
+
+  let vdf = parse_vdf(&text)?;
+
+  let mut dirs = vdf
+    .get("libraryfolders")
+    .and_then(|folders| folders.as_object())
+    .map(|folders| {
+      folders
+        .values()
+        .filter_map(|folder| folder["path"].as_str())
+        .map(PathBuf::from)
+        .collect::<Vec<_>>()
+    })
+    .unwrap_or_default();
+
+  if !dirs.iter().any(|dir| dir == steam_dir) {
+    dirs.push(steam_dir.to_path_buf());
+  }
+
+  Ok(dirs)
+}
+
+fn parse_app_manifest(text: &str) -> Option<AppManifest> {
+  let vdf = parse_vdf(text).ok()?;
+  let state = vdf.get("AppState")?;
+
+  let number = |key: &str| {
+    state[key]
+      .as_str()
+      .and_then(|value| value.parse::<u64>().ok())
+      .unwrap_or(0)
+  };
+
+  Some(AppManifest {
+    app_id: state["appid"].as_str()?.parse().ok()?,
+    name: state["name"].as_str().unwrap_or_default().to_string(),
+    state_flags: number("StateFlags"),
+    bytes_to_download: number("BytesToDownload"),
+    bytes_downloaded: number("BytesDownloaded"),
+  })
+}
@@ -0,0 +1,321 @@
+use std::{
+  collections::HashMap,
+  path::PathBuf,
+  time::{SystemTime, UNIX_EPOCH},
+};
+
+use async_trait::async_trait;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use tokio::task;
+
+use super::{
+  steam_library::{app_manifests, steam_dir},
+  steam_res::{FriendListRes, PlayerSummariesRes},
+};
+use crate::{
+  common::{AsyncInterval, IntervalOptions},
+  providers::{
+    common::TlsConfig, CommonProviderState, Provider, ProviderInputMsg,
+    RuntimeType,
+  },
+};
+
+/// Max number of completed downloads to output.
+const MAX_COMPLETED_DOWNLOADS: usize = 10;
+
+/// Max number of Steam IDs per player summaries request.
+const MAX_SUMMARIES_PER_REQUEST: usize = 100;
+
+#[derive(Deserialize, Debug, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct SteamProviderConfig {
+  #[serde(deserialize_with = "crate::common::deserialize_duration_ms")]
+  #[schemars(schema_with = "crate::common::duration_schema")]
+  pub refresh_interval: u64,
+
+  /// Alignment and jitter of refreshes.
+  #[serde(default)]
+  pub refresh_options: IntervalOptions,
+
+  /// Path to the Steam installation. Detected automatically if not set.
+  pub steam_path: Option<String>,
+
+  /// Steam Web API key, for getting the friends list. Should be a
+  /// secret reference (e.g. `secret:steam_api_key`).
+  pub api_key: Option<String>,
+
+  /// SteamID64 of the user, for getting the friends list.
+  pub steam_id: Option<String>,
+
+  /// TLS options for requests to the Steam Web API.
+  pub tls: Option<TlsConfig>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct SteamOutput {
+  /// Pending, active, and paused downloads.
+  pub downloads: Vec<SteamDownload>,
+
+  /// Downloads that finished while the provider was running, most
+  /// recent first.
+  pub completed_downloads: Vec<SteamCompletedDownload>,
+
+  /// Number of friends that are online. `None` if `apiKey` and
+  /// `steamId` aren't set.
+  pub online_friend_count: Option<u32>,
+
+  /// Friends that are online.
+  pub online_friends: Vec<SteamFriend>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct SteamDownload {
+  pub app_id: u32,
+  pub name: String,
+  pub bytes_downloaded: u64,
+  pub bytes_to_download: u64,
+
+  /// Download progress from 0 to 100.
+  pub progress: f32,
+
+  pub is_paused: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct SteamCompletedDownload {
+  pub app_id: u32,
+  pub name: String,
+
+  /// Unix timestamp in milliseconds of when the download finished.
+  pub completed_at: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct SteamFriend {
+  pub steam_id: String,
+  pub name: String,
+
+  /// One of `online`, `busy`, `away`, `snooze`, `looking_to_trade`, or
+  /// `looking_to_play`.
+  pub status: String,
+
+  /// Name of the game the friend is playing.
+  pub game: Option<String>,
+}
+
+pub struct SteamProvider {
+  config: SteamProviderConfig,
+  common: CommonProviderState,
+
+  /// Names of apps that were downloading at the last interval, keyed by
+  /// app ID.
+  prev_downloads: Option<HashMap<u32, String>>,
+
+  completed_downloads: Vec<SteamCompletedDownload>,
+}
+
+impl SteamProvider {
+  pub fn new(
+    config: SteamProviderConfig,
+    common: CommonProviderState,
+  ) -> SteamProvider {
+    SteamProvider {
+      config,
+      common,
+      prev_downloads: None,
+      completed_downloads: Vec::new(),
+    }
+  }
+
+  async fn run_interval(&mut self) -> anyhow::Result<SteamOutput> {
+    let downloads = self.downloads().await?;
+    self.update_completed(&downloads)?;
+
+    let online_friends =
+      match (&self.config.api_key, &self.config.steam_id) {
+        (Some(api_key), Some(steam_id)) => {
+          Some(self.online_friends(api_key, steam_id).await?)
+        }
+        _ => None,
+      };
+
+    Ok(SteamOutput {
+      downloads,
+      completed_downloads: self.completed_downloads.clone(),
+      online_friend_count: online_friends
+        .as_ref()
+        .map(|friends| friends.len() as u32),
+      online_friends: online_friends.unwrap_or_default(),
+    })
+  }
+
+  async fn downloads(&self) -> anyhow::Result<Vec<SteamDownload>> {
+    let steam_dir = match &self.config.steam_path {
+      Some(path) => PathBuf::from(path),
+      None => steam_dir()?,
+    };
+
+    let manifests =
+      task::spawn_blocking(move || app_manifests(&steam_dir)).await??;
+
+    let mut downloads = manifests
+      .into_iter()
+      .filter(|manifest| manifest.is_downloading())
+      .map(|manifest| SteamDownload {
+        app_id: manifest.app_id,
+        name: manifest.name.clone(),
+        bytes_downloaded: manifest.bytes_downloaded,
+        bytes_to_download: manifest.bytes_to_download,
+        progress: match manifest.bytes_to_download {
+          0 => 0.,
+          total => (manifest.bytes_downloaded as f32 / total as f32
+            * 100.)
+            .min(100.),
+        },
+        is_paused: manifest.is_paused(),
+      })
+      .collect::<Vec<_>>();
+
+    downloads.sort_by_key(|download| download.app_id);
+    Ok(downloads)
+  }
+
+  /// Records downloads that are no longer pending as completed.
+  fn update_completed(
+    &mut self,
+    downloads: &[SteamDownload],
+  ) -> anyhow::Result<()> {
+    let current = downloads
+      .iter()
+      .map(|download| (download.app_id, download.name.clone()))
+      .collect::<HashMap<_, _>>();
+
+    // Downloads aren't known to be completed on the first interval.
+    let Some(prev_downloads) =
+      self.prev_downloads.replace(current.clone())
+    else {
+      return Ok(());
+    };
+
+    let now = SystemTime::now().duration_since(UNIX_EPOCH)?.as_millis();
+
+    for (app_id, name) in prev_downloads {
+      if !current.contains_key(&app_id) {
+        self.completed_downloads.insert(
+          0,
+          SteamCompletedDownload {
+            app_id,
+            name,
+            completed_at: now as u64,
+          },
+        );
+      }
+    }
+
+    self.completed_downloads.truncate(MAX_COMPLETED_DOWNLOADS);
+    Ok(())
+  }
+
+  async fn online_friends(
+    &self,
+    api_key: &str,
+    steam_id: &str,
+  ) -> anyhow::Result<Vec<SteamFriend>> {
+    let friend_list = self
+      .common
+      .http_client
+      .get("https://api.steampowered.com/ISteamUser/GetFriendList/v1/")
+      .query(&[
+        ("key", api_key),
+        ("steamid", steam_id),
+        ("relationship", "friend"),
+      ])
+      .send()
+      .await?
+      .error_for_status()?
+      .json::<FriendListRes>()
+      .await?;
+
+    let steam_ids = friend_list
+      .friendslist
+      .friends
+      .into_iter()
+      .map(|friend| friend.steamid)
+      .collect::<Vec<_>>();
+
+    let mut friends = Vec::new();
+
+    for chunk in steam_ids.chunks(MAX_SUMMARIES_PER_REQUEST) {
+      let summaries = self
+        .common
+        .http_client
+        .get(
+          "https://api.steampowered.com/ISteamUser/GetPlayerSummaries/v2/",
+        )
+        .query(&[("key", api_key), ("steamids", &chunk.join(","))])
+        .send()
+        .await?
+        .error_for_status()?
+        .json::<PlayerSummariesRes>()
+        .await?;
+
+      friends.extend(summaries.response.players.into_iter().filter_map(
+        |player| {
+          let status = match player.personastate {
+            1 => "online",
+            2 => "busy",
+            3 => "away",
+            4 => "snooze",
+            5 => "looking_to_trade",
+            6 => "looking_to_play",
+            _ => return None,
+          };
+
+          Some(SteamFriend {
+            steam_id: player.steamid,
+            name: player.personaname,
+            status: status.to_string(),
+            game: player.gameextrainfo,
+          })
+        },
+      ));
+    }
+
+    friends.sort_by_key(|friend| friend.name.to_lowercase());
+    Ok(friends)
+  }
+}
+
+#[async_trait]
+impl Provider for SteamProvider {
+  fn runtime_type(&self) -> RuntimeType {
+    RuntimeType::Async
+  }
+
+  async fn start_async(&mut self) {
+    let mut interval = AsyncInterval::with_options(
+      self.config.refresh_interval,
+      &self.config.refresh_options,
+    )
+    .refresh_on_wake();
+
+    loop {
+      tokio::select! {
+        _ = interval.tick() => {
+          let output = self.run_interval().await;
+          self.common.emitter.emit_output_cached(output);
+        }
+        Some(message) = self.common.input.async_rx.recv() => {
+          if let ProviderInputMsg::Stop = message {
+            break;
+          }
+        }
+      }
+    }
+  }
+}
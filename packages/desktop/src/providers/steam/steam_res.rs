@@ -0,0 +1,39 @@
+use serde::Deserialize;
+
+#[derive(Deserialize, Debug)]
+pub struct FriendListRes {
+  pub friendslist: FriendListInnerRes,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct FriendListInnerRes {
+  pub friends: Vec<FriendRes>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct FriendRes {
+  pub steamid: String,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct PlayerSummariesRes {
+  pub response: PlayerSummariesInnerRes,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct PlayerSummariesInnerRes {
+  pub players: Vec<PlayerRes>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct PlayerRes {
+  pub steamid: String,
+  pub personaname: String,
+
+  /// 0 is offline, 1 online, 2 busy, 3 away, 4 snooze, 5 looking to
+  /// trade, and 6 looking to play.
+  pub personastate: u32,
+
+  /// Name of the game being played.
+  pub gameextrainfo: Option<String>,
+}
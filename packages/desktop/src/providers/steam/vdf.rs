@@ -0,0 +1,101 @@
+use anyhow::bail;
+
+/// Parses a Valve KeyValues (VDF) file, as used by Steam for library
+/// and app manifests, into a JSON object.
+///
+/// All values are strings or nested objects.
+pub fn parse_vdf(
+  text: &str,
+) -> anyhow::Result<serde_json::Map<String, serde_json::Value>> {
+  let mut tokens = tokenize(text)?.into_iter();
+  let object = parse_object(&mut tokens)?;
+
+  if tokens.next().is_some() {
+    bail!("Unexpected closing brace in VDF.");
+  }
+
+  Ok(object)
+}
+
+#[derive(Debug, PartialEq)]
+enum Token {
+  String(String),
+  Open,
+  Close,
+}
+
+fn parse_object(
+  tokens: &mut impl Iterator<Item = Token>,
+) -> anyhow::Result<serde_json::Map<String, serde_json::Value>> {
+  let mut object = serde_json::Map::new();
+
+  loop {
+    let key = match tokens.next() {
+      Some(Token::String(key)) => key,
+      Some(Token::Close) | None => return Ok(object),
+      Some(Token::Open) => bail!("Expected key in VDF."),
+    };
+
+    let value = match tokens.next() {
+      Some(Token::String(value)) => serde_json::Value::String(value),
+      Some(Token::Open) => {
+        serde_json::Value::Object(parse_object(tokens)?)
+      }
+      _ => bail!("Expected value for '{}' in VDF.", key),
+    };
+
+    object.insert(key, value);
+  }
+}
+
+fn tokenize(text: &str) -> anyhow::Result<Vec<Token>> {
+  let mut tokens = Vec::new();
+  let mut chars = text.chars().peekable();
+
+  while let Some(char) = chars.next() {
+    match char {
+      '{' => tokens.push(Token::Open),
+      '}' => tokens.push(Token::Close),
+      '/' if chars.peek() == Some(&'/') => {
+        chars.by_ref().take_while(|&c| c != '\n').for_each(drop);
+      }
+      '"' => {
+        let mut string = String::new();
+
+        loop {
+          match chars.next() {
+            Some('"') => break,
+            Some('\\') => match chars.next() {
+              Some('n') => string.push('\n'),
+              Some('t') => string.push('\t'),
+              Some(escaped) => string.push(escaped),
+              None => bail!("Unterminated string in VDF."),
+            },
+            Some(char) => string.push(char),
+            None => bail!("Unterminated string in VDF."),
+          }
+        }
+
+        tokens.push(Token::String(string));
+      }
+      char if char.is_whitespace() => {}
+      // Conditionals (e.g. `[$WIN32]`) aren't used in Steam's files.
+      char => {
+        let mut string = char.to_string();
+
+        while let Some(&next) = chars.peek() {
+          if next.is_whitespace() || next == '{' || next == '}' {
+            break;
+          }
+
+          string.push(next);
+          chars.next();
+        }
+
+        tokens.push(Token::String(string));
+      }
+    }
+  }
+
+  Ok(tokens)
+}
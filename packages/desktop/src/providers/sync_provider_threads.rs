@@ -0,0 +1,201 @@
+use std::{
+  panic::{self, AssertUnwindSafe},
+  sync::{Arc, Mutex},
+  thread,
+  time::Instant,
+};
+
+use anyhow::Context;
+use tokio::{sync::oneshot, task};
+use tracing::{error, info};
+
+use super::{
+  provider_supervisor::{panic_message, ProviderSupervisor},
+  ProviderInputMsg,
+};
+
+/// Max number of threads for running sync providers (of any type).
+/// Providers beyond this are queued until a thread becomes available.
+const MAX_THREADS: usize = 64;
+
+/// Provider run that is queued for a thread.
+type Job = Box<dyn FnOnce() + Send>;
+
+/// Bounded pool of dedicated threads for `RuntimeType::Sync` providers.
+///
+/// A sync provider occupies a thread for as long as it's active, rather
+/// than running on Tokio's blocking pool. This way, a blocking provider
+/// (e.g. a stuck WMI call) can't starve other blocking tasks. Threads
+/// are reused once their provider stops, and at most `MAX_THREADS` are
+/// spawned. Further providers wait in a queue until a thread is free.
+pub struct SyncProviderThreads {
+  job_tx: crossbeam::channel::Sender<Job>,
+  job_rx: crossbeam::channel::Receiver<Job>,
+  state: Arc<Mutex<PoolState>>,
+}
+
+#[derive(Default)]
+struct PoolState {
+  /// Number of threads spawned so far. Threads are kept alive for
+  /// reuse.
+  thread_count: usize,
+
+  /// Number of threads that are waiting for a job and haven't been
+  /// reserved for one yet.
+  idle_count: usize,
+}
+
+impl Default for SyncProviderThreads {
+  fn default() -> Self {
+    let (job_tx, job_rx) = crossbeam::channel::unbounded();
+
+    Self {
+      job_tx,
+      job_rx,
+      state: Arc::new(Mutex::new(PoolState::default())),
+    }
+  }
+}
+
+impl SyncProviderThreads {
+  /// Runs a provider on a thread of the pool.
+  ///
+  /// `create_run` is called on the provider's thread, and returns the
+  /// function that runs the provider until it's stopped. This function
+  /// is called again if it exits unexpectedly, as decided by the
+  /// supervisor.
+  ///
+  /// Returns a handle that completes once the provider has stopped.
+  pub fn spawn<F, R>(
+    &self,
+    span: tracing::Span,
+    supervisor: ProviderSupervisor,
+    input_rx: crossbeam::channel::Receiver<ProviderInputMsg>,
+    create_run: F,
+  ) -> anyhow::Result<task::JoinHandle<()>>
+  where
+    F: FnOnce() -> R + Send + 'static,
+    R: FnMut(),
+  {
+    let (done_tx, done_rx) = oneshot::channel::<()>();
+    let config_hash = supervisor.config_hash().to_string();
+
+    let job: Job = Box::new(move || {
+      let _span = span.entered();
+      let config_hash = supervisor.config_hash().to_string();
+
+      // Skip providers that were stopped while queued.
+      if !supervisor.is_stopped() {
+        // Panics while creating the provider aren't retried, since
+        // they'd most likely happen again.
+        if let Err(payload) =
+          panic::catch_unwind(AssertUnwindSafe(create_run))
+            .map(|run| run_supervised(supervisor, &input_rx, run))
+        {
+          error!(
+            "Provider {} panicked on creation: {}",
+            config_hash,
+            panic_message(&*payload)
+          );
+        }
+      }
+
+      info!("Provider stopped: {}", config_hash);
+      let _ = done_tx.send(());
+    });
+
+    // Jobs are queued while holding the lock, so that a thread that
+    // finishes its job either picks up queued jobs or is counted as
+    // idle.
+    let mut state = self.state.lock().unwrap();
+
+    if state.idle_count > 0 {
+      state.idle_count -= 1;
+    } else if state.thread_count < MAX_THREADS {
+      self.spawn_thread(state.thread_count)?;
+      state.thread_count += 1;
+    } else {
+      info!(
+        "Provider {} is queued, since all {} provider threads are in use.",
+        config_hash, MAX_THREADS
+      );
+    }
+
+    self.job_tx.send(job).context("Failed to queue provider.")?;
+
+    Ok(task::spawn(async move {
+      let _ = done_rx.await;
+    }))
+  }
+
+  /// Spawns a thread that runs queued jobs.
+  fn spawn_thread(&self, index: usize) -> anyhow::Result<()> {
+    let job_rx = self.job_rx.clone();
+    let state = self.state.clone();
+
+    thread::Builder::new()
+      .name(format!("sync-provider-{}", index))
+      .spawn(move || {
+        let mut job = job_rx.recv().ok();
+
+        while let Some(current_job) = job.take() {
+          current_job();
+
+          // Pick up the next queued job right away, or otherwise wait
+          // for one as an idle thread.
+          job = {
+            let mut state = state.lock().unwrap();
+
+            job_rx.try_recv().ok().or_else(|| {
+              state.idle_count += 1;
+              None
+            })
+          }
+          .or_else(|| job_rx.recv().ok());
+        }
+      })
+      .context("Failed to spawn provider thread.")?;
+
+    Ok(())
+  }
+}
+
+/// Runs a provider until it's stopped, restarting it if its run loop
+/// exits unexpectedly.
+fn run_supervised(
+  mut supervisor: ProviderSupervisor,
+  input_rx: &crossbeam::channel::Receiver<ProviderInputMsg>,
+  mut run: impl FnMut(),
+) {
+  loop {
+    let started_at = Instant::now();
+
+    let panic_message = panic::catch_unwind(AssertUnwindSafe(&mut run))
+      .err()
+      .map(|payload| panic_message(&*payload));
+
+    let Some(delay) =
+      supervisor.handle_exit(started_at.elapsed(), panic_message)
+    else {
+      return;
+    };
+
+    // Wait before restarting, while still handling inputs. The provider
+    // can't run functions until it has restarted.
+    let restart_at = crossbeam::channel::at(Instant::now() + delay);
+
+    loop {
+      crossbeam::select! {
+        recv(input_rx) -> input => match input {
+          Ok(ProviderInputMsg::Function(_, response_tx)) => {
+            let _ = response_tx.send(Err(
+              "Provider is restarting after a crash.".to_string(),
+            ));
+          }
+          Ok(ProviderInputMsg::Stop) | Err(_) => return,
+        },
+        recv(restart_at) -> _ => break,
+      }
+    }
+  }
+}
@@ -0,0 +1,133 @@
+use chrono::NaiveDate;
+use reqwest::Method;
+use serde::{de::DeserializeOwned, Deserialize};
+use serde_json::json;
+
+use super::tasks_provider::TaskItem;
+use crate::providers::common::HttpClient;
+
+const API_URL: &str = "https://graph.microsoft.com/v1.0/me/todo";
+
+#[derive(Deserialize, Debug)]
+struct PageRes<T> {
+  value: Vec<T>,
+  #[serde(rename = "@odata.nextLink")]
+  next_link: Option<String>,
+}
+
+#[derive(Deserialize, Debug)]
+struct ListRes {
+  id: String,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+struct TaskRes {
+  id: String,
+  title: String,
+  due_date_time: Option<DueRes>,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+struct DueRes {
+  /// Of the form `2025-01-31T00:00:00.0000000`. Microsoft To Do only
+  /// supports due dates, so the time is always midnight.
+  date_time: String,
+}
+
+/// Gets all open tasks, either from a single list or from all lists.
+pub async fn fetch_tasks(
+  http_client: &HttpClient,
+  access_token: &str,
+  list_id: Option<&str>,
+) -> anyhow::Result<Vec<TaskItem>> {
+  let list_ids = match list_id {
+    Some(list_id) => vec![list_id.to_string()],
+    None => fetch_pages::<ListRes>(
+      http_client,
+      access_token,
+      format!("{}/lists", API_URL),
+    )
+    .await?
+    .into_iter()
+    .map(|list| list.id)
+    .collect(),
+  };
+
+  let mut tasks = Vec::new();
+
+  for list_id in list_ids {
+    let url = format!(
+      "{}/lists/{}/tasks?$filter=status ne 'completed'&$top=100",
+      API_URL, list_id
+    );
+
+    let list_tasks =
+      fetch_pages::<TaskRes>(http_client, access_token, url).await?;
+
+    tasks.extend(list_tasks.into_iter().map(|task| {
+      let due_date = task.due_date_time.and_then(|due| {
+        due.date_time.get(..10).and_then(|date| {
+          NaiveDate::parse_from_str(date, "%Y-%m-%d").ok()
+        })
+      });
+
+      TaskItem {
+        id: task.id,
+        title: task.title,
+        list_id: Some(list_id.clone()),
+        due_date,
+        due_time: None,
+      }
+    }));
+  }
+
+  Ok(tasks)
+}
+
+pub async fn complete_task(
+  http_client: &HttpClient,
+  access_token: &str,
+  list_id: &str,
+  task_id: &str,
+) -> anyhow::Result<()> {
+  http_client
+    .request(
+      Method::PATCH,
+      format!("{}/lists/{}/tasks/{}", API_URL, list_id, task_id),
+    )
+    .bearer_auth(access_token)
+    .json(&json!({ "status": "completed" }))
+    .send()
+    .await?
+    .error_for_status()?;
+
+  Ok(())
+}
+
+/// Gets all items of a paginated collection.
+async fn fetch_pages<T: DeserializeOwned>(
+  http_client: &HttpClient,
+  access_token: &str,
+  url: String,
+) -> anyhow::Result<Vec<T>> {
+  let mut items = Vec::new();
+  let mut next_url = Some(url);
+
+  while let Some(url) = next_url {
+    let res = http_client
+      .get(url)
+      .bearer_auth(access_token)
+      .send()
+      .await?
+      .error_for_status()?
+      .json::<PageRes<T>>()
+      .await?;
+
+    items.extend(res.value);
+    next_url = res.next_link;
+  }
+
+  Ok(items)
+}
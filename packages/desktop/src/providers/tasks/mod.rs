@@ -0,0 +1,5 @@
+mod microsoft_todo;
+mod tasks_provider;
+mod todoist;
+
+pub use tasks_provider::*;
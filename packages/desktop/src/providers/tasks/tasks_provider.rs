@@ -0,0 +1,376 @@
+use std::{collections::HashMap, sync::Arc};
+
+use anyhow::{bail, Context};
+use async_trait::async_trait;
+use chrono::{DateTime, Local, NaiveDate};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::{microsoft_todo, todoist};
+use crate::{
+  common::{AsyncInterval, IntervalOptions},
+  providers::{
+    common::{OAuthClient, OAuthConfig, TlsConfig},
+    CommonProviderState, Provider, ProviderFunction,
+    ProviderFunctionResponse, ProviderInputMsg, RuntimeType,
+    TasksFunction,
+  },
+};
+
+/// Max number of tasks to output.
+const MAX_TASKS: usize = 50;
+
+#[derive(Deserialize, Debug, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct TasksProviderConfig {
+  #[serde(deserialize_with = "crate::common::deserialize_duration_ms")]
+  #[schemars(schema_with = "crate::common::duration_schema")]
+  pub refresh_interval: u64,
+
+  /// Alignment and jitter of refreshes.
+  #[serde(default)]
+  pub refresh_options: IntervalOptions,
+
+  /// Service to sync tasks from.
+  pub backend: TasksBackend,
+
+  /// Todoist API token. Required for the Todoist backend. Should be a
+  /// secret reference (e.g. `secret:todoist_token`).
+  pub api_token: Option<String>,
+
+  /// Todoist filter query (e.g. `today | overdue`) to limit tasks to.
+  pub filter: Option<String>,
+
+  /// Client ID of an app registered in Microsoft Entra. Required for
+  /// the Microsoft To Do backend.
+  pub client_id: Option<String>,
+
+  /// Port of the redirect URI for Microsoft To Do. The app must have
+  /// `http://127.0.0.1:<PORT>/callback` registered as a redirect URI.
+  pub redirect_port: u16,
+
+  /// ID of the Microsoft To Do list to limit tasks to.
+  pub list_id: Option<String>,
+
+  /// TLS options for requests to the backend.
+  pub tls: Option<TlsConfig>,
+}
+
+#[derive(
+  Clone, Copy, Debug, Deserialize, PartialEq, Serialize, JsonSchema,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum TasksBackend {
+  Todoist,
+  MicrosoftTodo,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct TasksOutput {
+  pub backend: TasksBackend,
+
+  /// Whether the user has authorized access. Always `true` for the
+  /// Todoist backend. Call the `authorize` function if `false`.
+  pub is_authorized: bool,
+
+  pub open_count: usize,
+  pub overdue_count: usize,
+  pub due_today_count: usize,
+
+  /// Open task with the earliest due date.
+  pub next_task: Option<Task>,
+
+  /// Open tasks sorted by due date, with undated tasks last.
+  pub tasks: Vec<Task>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct Task {
+  pub id: String,
+  pub title: String,
+
+  /// Due date (e.g. `2025-01-31`), or date and time in RFC 3339 format
+  /// for tasks that are due at a specific time.
+  pub due: Option<String>,
+
+  pub is_overdue: bool,
+  pub is_due_today: bool,
+}
+
+/// Task as returned by a backend.
+#[derive(Debug)]
+pub struct TaskItem {
+  pub id: String,
+  pub title: String,
+
+  /// List that the task belongs to. Needed to complete Microsoft To Do
+  /// tasks.
+  pub list_id: Option<String>,
+
+  pub due_date: Option<NaiveDate>,
+  pub due_time: Option<DateTime<Local>>,
+}
+
+pub struct TasksProvider {
+  config: TasksProviderConfig,
+  common: CommonProviderState,
+
+  /// OAuth client for the Microsoft To Do backend.
+  auth: Option<Arc<OAuthClient>>,
+
+  /// List IDs of the tasks from the last interval, keyed by task ID.
+  task_lists: HashMap<String, String>,
+}
+
+impl TasksProvider {
+  pub fn new(
+    config: TasksProviderConfig,
+    common: CommonProviderState,
+  ) -> TasksProvider {
+    let auth = match (config.backend, &config.client_id) {
+      (TasksBackend::MicrosoftTodo, Some(client_id)) => {
+        Some(Arc::new(OAuthClient::new(
+          OAuthConfig {
+            service_name: "Microsoft To Do",
+            authorize_url: "https://login.microsoftonline.com/common/oauth2/v2.0/authorize",
+            token_url:
+              "https://login.microsoftonline.com/common/oauth2/v2.0/token",
+            scopes: "Tasks.ReadWrite offline_access",
+            refresh_token_secret: "microsoft_todo_refresh_token",
+            client_id: client_id.clone(),
+            redirect_port: config.redirect_port,
+          },
+          common.http_client.clone(),
+        )))
+      }
+      _ => None,
+    };
+
+    TasksProvider {
+      config,
+      common,
+      auth,
+      task_lists: HashMap::new(),
+    }
+  }
+
+  async fn run_interval(&mut self) -> anyhow::Result<TasksOutput> {
+    let http_client = &self.common.http_client;
+
+    let items = match self.config.backend {
+      TasksBackend::Todoist => {
+        todoist::fetch_tasks(
+          http_client,
+          self.todoist_token()?,
+          self.config.filter.as_deref(),
+        )
+        .await?
+      }
+      TasksBackend::MicrosoftTodo => {
+        let Some(access_token) = self.microsoft_token().await? else {
+          return Ok(TasksOutput {
+            backend: self.config.backend,
+            is_authorized: false,
+            open_count: 0,
+            overdue_count: 0,
+            due_today_count: 0,
+            next_task: None,
+            tasks: Vec::new(),
+          });
+        };
+
+        microsoft_todo::fetch_tasks(
+          http_client,
+          &access_token,
+          self.config.list_id.as_deref(),
+        )
+        .await?
+      }
+    };
+
+    self.task_lists = items
+      .iter()
+      .filter_map(|item| Some((item.id.clone(), item.list_id.clone()?)))
+      .collect();
+
+    let mut tasks = items.into_iter().map(to_task).collect::<Vec<_>>();
+
+    // Undated tasks are sorted last, and ISO dates sort chronologically.
+    tasks.sort_by(|a, b| match (&a.due, &b.due) {
+      (Some(a), Some(b)) => a.cmp(b),
+      (Some(_), None) => std::cmp::Ordering::Less,
+      (None, Some(_)) => std::cmp::Ordering::Greater,
+      (None, None) => std::cmp::Ordering::Equal,
+    });
+
+    Ok(TasksOutput {
+      backend: self.config.backend,
+      is_authorized: true,
+      open_count: tasks.len(),
+      overdue_count: tasks.iter().filter(|task| task.is_overdue).count(),
+      due_today_count: tasks
+        .iter()
+        .filter(|task| task.is_due_today)
+        .count(),
+      next_task: tasks.iter().find(|task| task.due.is_some()).cloned(),
+      tasks: tasks.into_iter().take(MAX_TASKS).collect(),
+    })
+  }
+
+  fn todoist_token(&self) -> anyhow::Result<&str> {
+    self
+      .config
+      .api_token
+      .as_deref()
+      .context("An `apiToken` is required for the Todoist backend.")
+  }
+
+  async fn microsoft_token(&self) -> anyhow::Result<Option<String>> {
+    self
+      .auth
+      .as_ref()
+      .context(
+        "A `clientId` is required for the Microsoft To Do backend.",
+      )?
+      .access_token()
+      .await
+  }
+
+  async fn handle_function(
+    &mut self,
+    function: TasksFunction,
+  ) -> anyhow::Result<ProviderFunctionResponse> {
+    let http_client = &self.common.http_client;
+
+    match function {
+      TasksFunction::Authorize => unreachable!(),
+      TasksFunction::Logout => match &self.auth {
+        Some(auth) => auth.logout().await?,
+        None => bail!("Only the Microsoft To Do backend supports logout."),
+      },
+      TasksFunction::CompleteTask(args) => match self.config.backend {
+        TasksBackend::Todoist => {
+          todoist::complete_task(
+            http_client,
+            self.todoist_token()?,
+            &args.task_id,
+          )
+          .await?
+        }
+        TasksBackend::MicrosoftTodo => {
+          let access_token = self
+            .microsoft_token()
+            .await?
+            .context("Not authorized. Call `authorize` first.")?;
+
+          let list_id = self
+            .task_lists
+            .get(&args.task_id)
+            .context("No open task found with the given ID.")?;
+
+          microsoft_todo::complete_task(
+            http_client,
+            &access_token,
+            list_id,
+            &args.task_id,
+          )
+          .await?
+        }
+      },
+    };
+
+    Ok(ProviderFunctionResponse::Null)
+  }
+}
+
+fn to_task(item: TaskItem) -> Task {
+  let now = Local::now();
+  let today = now.date_naive();
+
+  let is_overdue = match (item.due_time, item.due_date) {
+    (Some(due_time), _) => due_time < now,
+    (None, Some(due_date)) => due_date < today,
+    (None, None) => false,
+  };
+
+  let due = match (item.due_time, item.due_date) {
+    (Some(due_time), _) => Some(due_time.to_rfc3339()),
+    (None, Some(due_date)) => Some(due_date.to_string()),
+    (None, None) => None,
+  };
+
+  Task {
+    id: item.id,
+    title: item.title,
+    due,
+    is_overdue,
+    is_due_today: item.due_date == Some(today),
+  }
+}
+
+#[async_trait]
+impl Provider for TasksProvider {
+  fn runtime_type(&self) -> RuntimeType {
+    RuntimeType::Async
+  }
+
+  async fn start_async(&mut self) {
+    let mut interval = AsyncInterval::with_options(
+      self.config.refresh_interval,
+      &self.config.refresh_options,
+    )
+    .refresh_on_wake();
+
+    loop {
+      tokio::select! {
+        _ = interval.tick() => {
+          let output = self.run_interval().await;
+          self.common.emitter.emit_output_cached(output);
+        }
+        Some(message) = self.common.input.async_rx.recv() => {
+          match message {
+            ProviderInputMsg::Stop => break,
+            ProviderInputMsg::Function(
+              ProviderFunction::Tasks(TasksFunction::Authorize),
+              sender,
+            ) => {
+              let Some(auth) = self.auth.clone() else {
+                let _ = sender.send(Err(
+                  "Only the Microsoft To Do backend supports authorize."
+                    .to_string(),
+                ));
+                continue;
+              };
+
+              // Authorizing waits for the user to approve access in the
+              // browser, so it's done without blocking the provider.
+              tokio::spawn(async move {
+                let res = auth
+                  .authorize()
+                  .await
+                  .map(|_| ProviderFunctionResponse::Null)
+                  .map_err(|err| err.to_string());
+
+                let _ = sender.send(res);
+              });
+            }
+            ProviderInputMsg::Function(
+              ProviderFunction::Tasks(tasks_function),
+              sender,
+            ) => {
+              let res = self
+                .handle_function(tasks_function)
+                .await
+                .map_err(|err| err.to_string());
+
+              sender.send(res).unwrap();
+            }
+            _ => {}
+          }
+        }
+      }
+    }
+  }
+}
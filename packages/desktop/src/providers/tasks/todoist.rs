@@ -0,0 +1,120 @@
+use chrono::{DateTime, Local, NaiveDate, NaiveDateTime, TimeZone};
+use reqwest::Method;
+use serde::Deserialize;
+
+use super::tasks_provider::TaskItem;
+use crate::providers::common::HttpClient;
+
+const API_URL: &str = "https://api.todoist.com/api/v1";
+
+#[derive(Deserialize, Debug)]
+struct TasksRes {
+  results: Vec<TaskRes>,
+  next_cursor: Option<String>,
+}
+
+#[derive(Deserialize, Debug)]
+struct TaskRes {
+  id: String,
+  content: String,
+  due: Option<DueRes>,
+}
+
+#[derive(Deserialize, Debug)]
+struct DueRes {
+  /// Either a date (`2025-01-31`), a floating date-time
+  /// (`2025-01-31T12:00:00`), or a UTC date-time
+  /// (`2025-01-31T12:00:00Z`).
+  date: String,
+}
+
+/// Gets all open tasks, optionally matching a Todoist filter query
+/// (e.g. `today | overdue`).
+pub async fn fetch_tasks(
+  http_client: &HttpClient,
+  api_token: &str,
+  filter: Option<&str>,
+) -> anyhow::Result<Vec<TaskItem>> {
+  let url = match filter {
+    Some(_) => format!("{}/tasks/filter", API_URL),
+    None => format!("{}/tasks", API_URL),
+  };
+
+  let mut tasks = Vec::new();
+  let mut cursor = None;
+
+  loop {
+    let mut query = vec![("limit", "200".to_string())];
+
+    if let Some(filter) = filter {
+      query.push(("query", filter.to_string()));
+    }
+
+    if let Some(cursor) = cursor {
+      query.push(("cursor", cursor));
+    }
+
+    let res = http_client
+      .get(&url)
+      .query(&query)
+      .bearer_auth(api_token)
+      .send()
+      .await?
+      .error_for_status()?
+      .json::<TasksRes>()
+      .await?;
+
+    tasks.extend(res.results.into_iter().map(|task| {
+      let (due_date, due_time) =
+        task.due.map(|due| parse_due(&due.date)).unwrap_or_default();
+
+      TaskItem {
+        id: task.id,
+        title: task.content,
+        list_id: None,
+        due_date,
+        due_time,
+      }
+    }));
+
+    match res.next_cursor {
+      Some(next_cursor) => cursor = Some(next_cursor),
+      None => break,
+    }
+  }
+
+  Ok(tasks)
+}
+
+pub async fn complete_task(
+  http_client: &HttpClient,
+  api_token: &str,
+  task_id: &str,
+) -> anyhow::Result<()> {
+  http_client
+    .request(Method::POST, format!("{}/tasks/{}/close", API_URL, task_id))
+    .bearer_auth(api_token)
+    .send()
+    .await?
+    .error_for_status()?;
+
+  Ok(())
+}
+
+fn parse_due(date: &str) -> (Option<NaiveDate>, Option<DateTime<Local>>) {
+  if let Ok(date) = NaiveDate::parse_from_str(date, "%Y-%m-%d") {
+    return (Some(date), None);
+  }
+
+  let time = DateTime::parse_from_rfc3339(date)
+    .map(|time| time.with_timezone(&Local))
+    .ok()
+    .or_else(|| {
+      // Floating date-times are in the user's local time.
+      NaiveDateTime::parse_from_str(date, "%Y-%m-%dT%H:%M:%S")
+        .ok()
+        .and_then(|time| Local.from_local_datetime(&time).earliest())
+    });
+
+  (time.map(|time| time.date_naive()), time)
+}
@@ -0,0 +1,5 @@
+mod timer_provider;
+mod timer_store;
+
+pub use timer_provider::*;
+pub use timer_store::*;
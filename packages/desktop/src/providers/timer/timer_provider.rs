@@ -0,0 +1,122 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::{TimerState, TimerStore};
+use crate::{
+  common::{AsyncInterval, IntervalOptions},
+  providers::{
+    CommonProviderState, Provider, ProviderFunction,
+    ProviderFunctionResponse, ProviderInputMsg, RuntimeType,
+    TimerFunction,
+  },
+};
+
+#[derive(Deserialize, Debug, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct TimerProviderConfig {
+  /// How often running timers tick in milliseconds.
+  #[serde(deserialize_with = "crate::common::deserialize_duration_ms")]
+  #[schemars(schema_with = "crate::common::duration_schema")]
+  pub refresh_interval: u64,
+
+  /// Alignment and jitter of refreshes.
+  #[serde(default)]
+  pub refresh_options: IntervalOptions,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct TimerOutput {
+  /// All timers and stopwatches in creation order.
+  pub timers: Vec<TimerState>,
+}
+
+pub struct TimerProvider {
+  config: TimerProviderConfig,
+  common: CommonProviderState,
+  store: Arc<TimerStore>,
+}
+
+impl TimerProvider {
+  pub fn new(
+    config: TimerProviderConfig,
+    common: CommonProviderState,
+  ) -> TimerProvider {
+    let store = common.timer_store.clone();
+    TimerProvider {
+      config,
+      common,
+      store,
+    }
+  }
+
+  fn emit(&mut self) {
+    let output = TimerOutput {
+      timers: self.store.states(),
+    };
+
+    self.common.emitter.emit_output_cached(Ok(output));
+  }
+
+  /// Handles an incoming timer provider function call.
+  fn handle_function(
+    &self,
+    function: TimerFunction,
+  ) -> anyhow::Result<ProviderFunctionResponse> {
+    match function {
+      TimerFunction::CreateTimer(args) => self.store.create(
+        &args.name,
+        args.duration,
+        args.start.unwrap_or(true),
+      ),
+      TimerFunction::Start(args) => self.store.start(&args.name),
+      TimerFunction::Pause(args) => self.store.pause(&args.name),
+      TimerFunction::Lap(args) => self.store.lap(&args.name),
+      TimerFunction::Reset(args) => self.store.reset(&args.name),
+      TimerFunction::Cancel(args) => self.store.cancel(&args.name),
+    }?;
+
+    Ok(ProviderFunctionResponse::Null)
+  }
+}
+
+#[async_trait]
+impl Provider for TimerProvider {
+  fn runtime_type(&self) -> RuntimeType {
+    RuntimeType::Async
+  }
+
+  async fn start_async(&mut self) {
+    let mut interval = AsyncInterval::with_options(
+      self.config.refresh_interval,
+      &self.config.refresh_options,
+    );
+    let mut change_rx = self.store.subscribe();
+
+    loop {
+      tokio::select! {
+        _ = interval.tick() => self.emit(),
+        Ok(_) = change_rx.changed() => self.emit(),
+        Some(message) = self.common.input.async_rx.recv() => {
+          match message {
+            ProviderInputMsg::Stop => break,
+            ProviderInputMsg::Function(
+              ProviderFunction::Timer(timer_function),
+              sender,
+            ) => {
+              let res = self
+                .handle_function(timer_function)
+                .map_err(|err| err.to_string());
+
+              sender.send(res).unwrap();
+            }
+            _ => {}
+          }
+        }
+      }
+    }
+  }
+}
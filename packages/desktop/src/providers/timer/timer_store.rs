@@ -0,0 +1,274 @@
+use std::{
+  fs,
+  path::PathBuf,
+  sync::Mutex,
+  time::{SystemTime, UNIX_EPOCH},
+};
+
+use anyhow::{bail, Context};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use tokio::sync::watch;
+use tracing::warn;
+
+#[derive(
+  Clone, Copy, Debug, Deserialize, PartialEq, Serialize, JsonSchema,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum TimerKind {
+  /// Counts up from zero.
+  Stopwatch,
+
+  /// Counts down from a fixed duration.
+  Timer,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct TimerState {
+  pub name: String,
+  pub kind: TimerKind,
+
+  /// Whether the timer is counting. `false` once a timer has finished.
+  pub is_running: bool,
+
+  /// Whether a timer has counted down to zero. Always `false` for
+  /// stopwatches.
+  pub is_finished: bool,
+
+  /// Elapsed time in milliseconds.
+  pub elapsed: u64,
+
+  /// Total duration in milliseconds. Only set for timers.
+  pub duration: Option<u64>,
+
+  /// Remaining time in milliseconds. Only set for timers.
+  pub remaining: Option<u64>,
+
+  /// Elapsed time in milliseconds at each recorded lap.
+  pub laps: Vec<u64>,
+}
+
+/// Persisted state of a timer or stopwatch.
+///
+/// Running time is derived from the wall clock, so that timers keep
+/// counting while Zebar isn't running.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct TimerRecord {
+  name: String,
+  kind: TimerKind,
+  duration: Option<u64>,
+
+  /// Elapsed milliseconds from previous runs (i.e. before the last
+  /// pause).
+  accumulated: u64,
+
+  /// Unix timestamp in milliseconds of when the current run started.
+  /// `None` if paused.
+  started_at: Option<u64>,
+
+  laps: Vec<u64>,
+}
+
+impl TimerRecord {
+  fn elapsed(&self, now: u64) -> u64 {
+    let elapsed = self.accumulated
+      + self
+        .started_at
+        .map_or(0, |started_at| now.saturating_sub(started_at));
+
+    match self.duration {
+      Some(duration) => elapsed.min(duration),
+      None => elapsed,
+    }
+  }
+
+  fn state(&self, now: u64) -> TimerState {
+    let elapsed = self.elapsed(now);
+
+    let is_finished =
+      self.duration.is_some_and(|duration| elapsed >= duration);
+
+    TimerState {
+      name: self.name.clone(),
+      kind: self.kind,
+      is_running: self.started_at.is_some() && !is_finished,
+      is_finished,
+      elapsed,
+      duration: self.duration,
+      remaining: self.duration.map(|duration| duration - elapsed),
+      laps: self.laps.clone(),
+    }
+  }
+}
+
+/// Named timers and stopwatches shared by all `timer` providers.
+///
+/// State is persisted to disk on every change, so that timers survive
+/// widget reloads and app restarts.
+#[derive(Debug)]
+pub struct TimerStore {
+  /// Path to the JSON file that timers are persisted to. Timers are
+  /// only kept in memory if `None`.
+  state_path: Option<PathBuf>,
+
+  records: Mutex<Vec<TimerRecord>>,
+
+  /// Sender that is notified whenever a timer changes.
+  change_tx: watch::Sender<()>,
+}
+
+impl TimerStore {
+  /// Creates a store with the timers persisted at the given path.
+  pub fn new(state_path: Option<PathBuf>) -> Self {
+    let records = state_path
+      .as_ref()
+      .filter(|path| path.exists())
+      .map(|path| {
+        fs::read_to_string(path)
+          .map_err(anyhow::Error::from)
+          .and_then(|json| {
+            Ok(serde_json::from_str::<Vec<TimerRecord>>(&json)?)
+          })
+          .unwrap_or_else(|err| {
+            warn!("Failed to read timers from {:?}: {:?}", path, err);
+            Vec::new()
+          })
+      })
+      .unwrap_or_default();
+
+    Self {
+      state_path,
+      records: Mutex::new(records),
+      change_tx: watch::channel(()).0,
+    }
+  }
+
+  /// Returns a receiver that is notified whenever a timer changes.
+  pub fn subscribe(&self) -> watch::Receiver<()> {
+    self.change_tx.subscribe()
+  }
+
+  /// Gets the current state of all timers in creation order.
+  pub fn states(&self) -> Vec<TimerState> {
+    let now = now_ms();
+
+    self
+      .records
+      .lock()
+      .unwrap()
+      .iter()
+      .map(|record| record.state(now))
+      .collect()
+  }
+
+  /// Creates a timer (if `duration` is set) or a stopwatch. Replaces any
+  /// existing timer with the same name.
+  pub fn create(
+    &self,
+    name: &str,
+    duration: Option<u64>,
+    start: bool,
+  ) -> anyhow::Result<()> {
+    let mut records = self.records.lock().unwrap();
+    records.retain(|record| record.name != name);
+
+    records.push(TimerRecord {
+      name: name.to_string(),
+      kind: match duration {
+        Some(_) => TimerKind::Timer,
+        None => TimerKind::Stopwatch,
+      },
+      duration,
+      accumulated: 0,
+      started_at: start.then(now_ms),
+      laps: Vec::new(),
+    });
+
+    self.persist(&records)
+  }
+
+  /// Starts or resumes a timer.
+  pub fn start(&self, name: &str) -> anyhow::Result<()> {
+    self.update(name, |record, now| {
+      record.started_at.get_or_insert(now);
+    })
+  }
+
+  /// Pauses a timer, keeping its elapsed time.
+  pub fn pause(&self, name: &str) -> anyhow::Result<()> {
+    self.update(name, |record, now| {
+      record.accumulated = record.elapsed(now);
+      record.started_at = None;
+    })
+  }
+
+  /// Records the current elapsed time as a lap.
+  pub fn lap(&self, name: &str) -> anyhow::Result<()> {
+    self.update(name, |record, now| {
+      let elapsed = record.elapsed(now);
+      record.laps.push(elapsed);
+    })
+  }
+
+  /// Stops a timer and clears its elapsed time and laps.
+  pub fn reset(&self, name: &str) -> anyhow::Result<()> {
+    self.update(name, |record, _| {
+      record.accumulated = 0;
+      record.started_at = None;
+      record.laps.clear();
+    })
+  }
+
+  /// Removes a timer.
+  pub fn cancel(&self, name: &str) -> anyhow::Result<()> {
+    let mut records = self.records.lock().unwrap();
+    let len = records.len();
+    records.retain(|record| record.name != name);
+
+    if records.len() == len {
+      bail!("No timer found with name '{}'.", name);
+    }
+
+    self.persist(&records)
+  }
+
+  fn update(
+    &self,
+    name: &str,
+    update_fn: impl FnOnce(&mut TimerRecord, u64),
+  ) -> anyhow::Result<()> {
+    let mut records = self.records.lock().unwrap();
+
+    let record = records
+      .iter_mut()
+      .find(|record| record.name == name)
+      .with_context(|| format!("No timer found with name '{}'.", name))?;
+
+    update_fn(record, now_ms());
+    self.persist(&records)
+  }
+
+  /// Writes the timers to disk and notifies subscribers.
+  fn persist(&self, records: &[TimerRecord]) -> anyhow::Result<()> {
+    self.change_tx.send_replace(());
+
+    let Some(state_path) = &self.state_path else {
+      return Ok(());
+    };
+
+    if let Some(parent) = state_path.parent() {
+      fs::create_dir_all(parent)?;
+    }
+
+    fs::write(state_path, serde_json::to_string_pretty(records)? + "\n")
+      .context("Failed to persist timers.")
+  }
+}
+
+fn now_ms() -> u64 {
+  SystemTime::now()
+    .duration_since(UNIX_EPOCH)
+    .map_or(0, |duration| duration.as_millis() as u64)
+}
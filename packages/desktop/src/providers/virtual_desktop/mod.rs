@@ -0,0 +1,3 @@
+mod virtual_desktop_provider;
+
+pub use virtual_desktop_provider::*;
@@ -0,0 +1,178 @@
+use anyhow::Context;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use windows::Win32::System::{
+  Registry::HKEY_CURRENT_USER, RemoteDesktop::ProcessIdToSessionId,
+};
+
+use crate::{
+  common::{windows::RegKey, IntervalOptions, SyncInterval},
+  providers::{
+    CommonProviderState, Provider, ProviderInputMsg, RuntimeType,
+  },
+};
+
+/// Registry path where Explorer stores the virtual desktop state.
+const VIRTUAL_DESKTOPS_PATH: &str =
+  "Software\\Microsoft\\Windows\\CurrentVersion\\Explorer\\VirtualDesktops";
+
+/// Registry path of per-session Explorer state. On Windows 10, the
+/// current virtual desktop is stored here instead.
+const SESSION_INFO_PATH: &str =
+  "Software\\Microsoft\\Windows\\CurrentVersion\\Explorer\\SessionInfo";
+
+/// Size of a GUID in its binary registry representation.
+const GUID_SIZE: usize = 16;
+
+#[derive(Deserialize, Debug, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct VirtualDesktopProviderConfig {
+  #[serde(deserialize_with = "crate::common::deserialize_duration_ms")]
+  #[schemars(schema_with = "crate::common::duration_schema")]
+  pub refresh_interval: u64,
+
+  /// Alignment and jitter of refreshes.
+  #[serde(default)]
+  pub refresh_options: IntervalOptions,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct VirtualDesktopOutput {
+  pub desktops: Vec<VirtualDesktop>,
+  pub current_desktop: Option<VirtualDesktop>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct VirtualDesktop {
+  /// GUID of the desktop (e.g. `{0F6B4C2A-...}`).
+  pub id: String,
+
+  /// User-assigned name of the desktop. Defaults to `Desktop <n>`, as
+  /// shown in the task view.
+  pub name: String,
+  pub index: usize,
+}
+
+pub struct VirtualDesktopProvider {
+  config: VirtualDesktopProviderConfig,
+  common: CommonProviderState,
+}
+
+impl VirtualDesktopProvider {
+  pub fn new(
+    config: VirtualDesktopProviderConfig,
+    common: CommonProviderState,
+  ) -> VirtualDesktopProvider {
+    VirtualDesktopProvider { config, common }
+  }
+
+  fn run_interval(&mut self) -> anyhow::Result<VirtualDesktopOutput> {
+    let desktops_key =
+      RegKey::open(HKEY_CURRENT_USER, VIRTUAL_DESKTOPS_PATH)
+        .context("Virtual desktops are not available.")?;
+
+    // Explorer only writes the desktop IDs once a second desktop has
+    // been created, in which case there's a single default desktop.
+    let desktop_ids = desktops_key
+      .binary_value("VirtualDesktopIDs")
+      .map(|bytes| {
+        bytes
+          .chunks_exact(GUID_SIZE)
+          .map(Self::format_guid)
+          .collect()
+      })
+      .unwrap_or_else(Vec::new);
+
+    let current_id = desktops_key
+      .binary_value("CurrentVirtualDesktop")
+      .or_else(Self::session_current_desktop)
+      .filter(|bytes| bytes.len() == GUID_SIZE)
+      .map(|bytes| Self::format_guid(&bytes));
+
+    let desktops = desktop_ids
+      .into_iter()
+      .enumerate()
+      .map(|(index, id)| {
+        let name =
+          RegKey::open(desktops_key.0, &format!("Desktops\\{id}"))
+            .and_then(|key| key.string_value("Name"))
+            .filter(|name| !name.is_empty())
+            .unwrap_or_else(|| format!("Desktop {}", index + 1));
+
+        VirtualDesktop { id, name, index }
+      })
+      .collect::<Vec<_>>();
+
+    let current_desktop = desktops
+      .iter()
+      .find(|desktop| Some(&desktop.id) == current_id.as_ref())
+      .cloned();
+
+    Ok(VirtualDesktopOutput {
+      desktops,
+      current_desktop,
+    })
+  }
+
+  /// Reads the current desktop from the session key (Windows 10).
+  fn session_current_desktop() -> Option<Vec<u8>> {
+    let mut session_id = 0;
+    unsafe { ProcessIdToSessionId(std::process::id(), &mut session_id) }
+      .ok()?;
+
+    RegKey::open(
+      HKEY_CURRENT_USER,
+      &format!("{SESSION_INFO_PATH}\\{session_id}\\VirtualDesktops"),
+    )?
+    .binary_value("CurrentVirtualDesktop")
+  }
+
+  /// Formats a GUID from its binary representation, matching the key
+  /// names under `VirtualDesktops\Desktops`.
+  fn format_guid(bytes: &[u8]) -> String {
+    let data1 =
+      u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]);
+    let data2 = u16::from_le_bytes([bytes[4], bytes[5]]);
+    let data3 = u16::from_le_bytes([bytes[6], bytes[7]]);
+
+    let data4 = bytes[8..16]
+      .iter()
+      .map(|byte| format!("{byte:02X}"))
+      .collect::<String>();
+
+    format!(
+      "{{{data1:08X}-{data2:04X}-{data3:04X}-{}-{}}}",
+      &data4[..4],
+      &data4[4..]
+    )
+  }
+}
+
+impl Provider for VirtualDesktopProvider {
+  fn runtime_type(&self) -> RuntimeType {
+    RuntimeType::Sync
+  }
+
+  fn start_sync(&mut self) {
+    let mut interval = SyncInterval::with_options(
+      self.config.refresh_interval,
+      &self.config.refresh_options,
+    );
+
+    loop {
+      crossbeam::select! {
+        recv(interval.tick()) -> _ => {
+          let output = self.run_interval();
+          self.common.emitter.emit_output_cached(output);
+        }
+        recv(self.common.input.sync_rx) -> input => {
+          if let Ok(ProviderInputMsg::Stop) = input {
+            break;
+          }
+        }
+      }
+    }
+  }
+}
@@ -0,0 +1,4 @@
+mod tailscale_res;
+mod vpn_provider;
+
+pub use vpn_provider::*;
@@ -0,0 +1,27 @@
+use std::collections::HashMap;
+
+use serde::Deserialize;
+
+/// Output of `tailscale status --json`.
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "PascalCase")]
+pub struct TailscaleStatusRes {
+  pub backend_state: String,
+  #[serde(rename = "Self")]
+  pub self_peer: Option<TailscalePeer>,
+  #[serde(default)]
+  pub peer: HashMap<String, TailscalePeer>,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "PascalCase")]
+pub struct TailscalePeer {
+  #[serde(default)]
+  pub host_name: String,
+  #[serde(default)]
+  pub online: bool,
+  #[serde(default)]
+  pub exit_node: bool,
+  #[serde(default)]
+  pub relay: String,
+}
@@ -0,0 +1,274 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use anyhow::{bail, Context};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::tailscale_res::TailscaleStatusRes;
+use crate::{
+  common::{no_window_command, IntervalOptions, SyncInterval},
+  providers::{
+    CommonProviderState, Provider, ProviderFunction,
+    ProviderFunctionResponse, ProviderInputMsg, RuntimeType, VpnFunction,
+  },
+};
+
+/// WireGuard peers are considered online if a handshake occurred within
+/// this many seconds.
+const WIREGUARD_HANDSHAKE_TIMEOUT_SECS: u64 = 180;
+
+#[derive(Deserialize, Debug, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct VpnProviderConfig {
+  #[serde(deserialize_with = "crate::common::deserialize_duration_ms")]
+  #[schemars(schema_with = "crate::common::duration_schema")]
+  pub refresh_interval: u64,
+
+  /// Alignment and jitter of refreshes.
+  #[serde(default)]
+  pub refresh_options: IntervalOptions,
+
+  /// VPN backend to query.
+  pub backend: VpnBackend,
+
+  /// Name of the WireGuard interface (e.g. `wg0`). Required for the
+  /// WireGuard backend.
+  pub interface: Option<String>,
+}
+
+#[derive(
+  Clone, Copy, Debug, Deserialize, PartialEq, Serialize, JsonSchema,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum VpnBackend {
+  Tailscale,
+  Wireguard,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct VpnOutput {
+  pub backend: VpnBackend,
+  pub is_connected: bool,
+  pub state: String,
+  pub peer_count: usize,
+  pub online_peer_count: usize,
+  pub exit_node: Option<String>,
+  pub relay: Option<String>,
+}
+
+pub struct VpnProvider {
+  config: VpnProviderConfig,
+  common: CommonProviderState,
+}
+
+impl VpnProvider {
+  pub fn new(
+    config: VpnProviderConfig,
+    common: CommonProviderState,
+  ) -> VpnProvider {
+    VpnProvider { config, common }
+  }
+
+  fn run_interval(&self) -> anyhow::Result<VpnOutput> {
+    match self.config.backend {
+      VpnBackend::Tailscale => Self::tailscale_status(),
+      VpnBackend::Wireguard => {
+        Self::wireguard_status(self.wireguard_interface()?)
+      }
+    }
+  }
+
+  fn wireguard_interface(&self) -> anyhow::Result<&str> {
+    self
+      .config
+      .interface
+      .as_deref()
+      .context("An `interface` is required for the WireGuard backend.")
+  }
+
+  fn tailscale_status() -> anyhow::Result<VpnOutput> {
+    let output = no_window_command("tailscale")
+      .args(["status", "--json"])
+      .output()
+      .context("Unable to run `tailscale`. Is Tailscale installed?")?;
+
+    let res = serde_json::from_slice::<TailscaleStatusRes>(&output.stdout)
+      .context("Failed to parse `tailscale status` output.")?;
+
+    Ok(VpnOutput {
+      backend: VpnBackend::Tailscale,
+      is_connected: res.backend_state == "Running",
+      peer_count: res.peer.len(),
+      online_peer_count: res
+        .peer
+        .values()
+        .filter(|peer| peer.online)
+        .count(),
+      exit_node: res
+        .peer
+        .values()
+        .find(|peer| peer.exit_node)
+        .map(|peer| peer.host_name.clone()),
+      relay: res
+        .self_peer
+        .map(|peer| peer.relay)
+        .filter(|relay| !relay.is_empty()),
+      state: res.backend_state,
+    })
+  }
+
+  /// Gets the status of a WireGuard interface via `wg show <IFACE>
+  /// dump`. The first line describes the interface, and each subsequent
+  /// line describes a peer.
+  fn wireguard_status(interface: &str) -> anyhow::Result<VpnOutput> {
+    let output = no_window_command("wg")
+      .args(["show", interface, "dump"])
+      .output()
+      .context("Unable to run `wg`. Is WireGuard installed?")?;
+
+    // `wg` errors if the interface is down.
+    if !output.status.success() {
+      return Ok(VpnOutput {
+        backend: VpnBackend::Wireguard,
+        is_connected: false,
+        state: "down".into(),
+        peer_count: 0,
+        online_peer_count: 0,
+        exit_node: None,
+        relay: None,
+      });
+    }
+
+    let now = SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs();
+    let stdout = String::from_utf8_lossy(&output.stdout);
+
+    // Peer fields: public key, preshared key, endpoint, allowed IPs,
+    // latest handshake, rx bytes, tx bytes, keepalive.
+    let peers = stdout
+      .lines()
+      .skip(1)
+      .map(|line| line.split('\t').collect::<Vec<_>>())
+      .filter(|fields| fields.len() >= 5)
+      .collect::<Vec<_>>();
+
+    let online_peer_count = peers
+      .iter()
+      .filter(|fields| {
+        fields[4].parse::<u64>().is_ok_and(|handshake| {
+          handshake != 0
+            && now.saturating_sub(handshake)
+              < WIREGUARD_HANDSHAKE_TIMEOUT_SECS
+        })
+      })
+      .count();
+
+    // A peer that routes all traffic acts as the exit node.
+    let exit_node = peers
+      .iter()
+      .find(|fields| {
+        fields[3]
+          .split(',')
+          .any(|ip| ip == "0.0.0.0/0" || ip == "::/0")
+      })
+      .map(|fields| fields[2].to_string());
+
+    Ok(VpnOutput {
+      backend: VpnBackend::Wireguard,
+      is_connected: true,
+      state: "up".into(),
+      peer_count: peers.len(),
+      online_peer_count,
+      exit_node,
+      relay: None,
+    })
+  }
+
+  /// Handles an incoming VPN provider function call.
+  fn handle_function(
+    &mut self,
+    function: VpnFunction,
+  ) -> anyhow::Result<ProviderFunctionResponse> {
+    let connect = matches!(function, VpnFunction::Connect);
+
+    let mut command = match self.config.backend {
+      VpnBackend::Tailscale => {
+        let mut command = no_window_command("tailscale");
+        command.arg(if connect { "up" } else { "down" });
+        command
+      }
+      VpnBackend::Wireguard => {
+        let interface = self.wireguard_interface()?;
+
+        // On Windows, tunnels are managed as services by the WireGuard
+        // app.
+        #[cfg(target_os = "windows")]
+        {
+          let mut command = no_window_command("net");
+          command
+            .arg(if connect { "start" } else { "stop" })
+            .arg(format!("WireGuardTunnel${}", interface));
+          command
+        }
+
+        #[cfg(not(target_os = "windows"))]
+        {
+          let mut command = no_window_command("wg-quick");
+          command
+            .arg(if connect { "up" } else { "down" })
+            .arg(interface);
+          command
+        }
+      }
+    };
+
+    let status = command.status().context("Unable to run VPN command.")?;
+
+    if !status.success() {
+      bail!("VPN command exited with {}.", status);
+    }
+
+    // Emit immediately so that the new state is reflected.
+    let output = self.run_interval();
+    self.common.emitter.emit_output_cached(output);
+
+    Ok(ProviderFunctionResponse::Null)
+  }
+}
+
+impl Provider for VpnProvider {
+  fn runtime_type(&self) -> RuntimeType {
+    RuntimeType::Sync
+  }
+
+  fn start_sync(&mut self) {
+    let mut interval = SyncInterval::with_options(
+      self.config.refresh_interval,
+      &self.config.refresh_options,
+    );
+
+    loop {
+      crossbeam::select! {
+        recv(interval.tick()) -> _ => {
+          let output = self.run_interval();
+          self.common.emitter.emit_output_cached(output);
+        }
+        recv(self.common.input.sync_rx) -> input => {
+          match input {
+            Ok(ProviderInputMsg::Stop) => {
+              break;
+            }
+            Ok(ProviderInputMsg::Function(
+              ProviderFunction::Vpn(vpn_function),
+              sender,
+            )) => {
+              let res = self.handle_function(vpn_function).map_err(|err| err.to_string());
+              sender.send(res).unwrap();
+            }
+            _ => {}
+          }
+        }
+      }
+    }
+  }
+}
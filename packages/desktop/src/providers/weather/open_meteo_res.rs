@@ -16,3 +16,16 @@ pub struct OpenMeteoWeather {
   pub weather_code: u32,
   pub is_day: u32,
 }
+
+#[derive(Deserialize, Debug)]
+pub struct OpenMeteoAirQualityRes {
+  pub current: OpenMeteoAirQuality,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct OpenMeteoAirQuality {
+  pub us_aqi: Option<f32>,
+  pub european_aqi: Option<f32>,
+  pub pm10: Option<f32>,
+  pub pm2_5: Option<f32>,
+}
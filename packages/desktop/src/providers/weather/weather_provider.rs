@@ -1,25 +1,39 @@
 use async_trait::async_trait;
-use reqwest::Client;
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
-use super::open_meteo_res::OpenMeteoRes;
+use super::open_meteo_res::{OpenMeteoAirQualityRes, OpenMeteoRes};
 use crate::{
-  common::AsyncInterval,
+  common::{AsyncInterval, IntervalOptions},
   providers::{
-    ip::IpProvider, CommonProviderState, Provider, ProviderInputMsg,
-    RuntimeType,
+    common::{HttpClient, TlsConfig},
+    ip::IpProvider,
+    CommonProviderState, Provider, ProviderInputMsg, RuntimeType,
   },
 };
 
-#[derive(Deserialize, Debug)]
+#[derive(Deserialize, Debug, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct WeatherProviderConfig {
+  #[serde(deserialize_with = "crate::common::deserialize_duration_ms")]
+  #[schemars(schema_with = "crate::common::duration_schema")]
   pub refresh_interval: u64,
+
+  /// Alignment and jitter of refreshes.
+  #[serde(default)]
+  pub refresh_options: IntervalOptions,
   pub latitude: Option<f32>,
   pub longitude: Option<f32>,
+
+  /// Whether to also fetch air quality data each interval.
+  #[serde(default)]
+  pub include_air_quality: bool,
+
+  /// TLS options for requests to Open-Meteo and IPinfo.
+  pub tls: Option<TlsConfig>,
 }
 
-#[derive(Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct WeatherOutput {
   pub is_daytime: bool,
@@ -27,9 +41,21 @@ pub struct WeatherOutput {
   pub celsius_temp: f32,
   pub fahrenheit_temp: f32,
   pub wind_speed: f32,
+
+  /// Air quality data. `None` if `include_air_quality` is disabled.
+  pub air_quality: Option<AirQuality>,
 }
 
-#[derive(Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct AirQuality {
+  pub us_aqi: Option<f32>,
+  pub european_aqi: Option<f32>,
+  pub pm10: Option<f32>,
+  pub pm2_5: Option<f32>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum WeatherStatus {
   ClearDay,
@@ -49,7 +75,7 @@ pub enum WeatherStatus {
 pub struct WeatherProvider {
   config: WeatherProviderConfig,
   common: CommonProviderState,
-  http_client: Client,
+  http_client: HttpClient,
 }
 
 impl WeatherProvider {
@@ -59,8 +85,8 @@ impl WeatherProvider {
   ) -> WeatherProvider {
     WeatherProvider {
       config,
+      http_client: common.http_client.clone(),
       common,
-      http_client: Client::new(),
     }
   }
 
@@ -94,6 +120,11 @@ impl WeatherProvider {
     let current_weather = res.current_weather;
     let is_daytime = current_weather.is_day == 1;
 
+    let air_quality = match self.config.include_air_quality {
+      true => Some(self.query_air_quality(latitude, longitude).await?),
+      false => None,
+    };
+
     Ok(WeatherOutput {
       is_daytime,
       status: Self::get_weather_status(
@@ -105,6 +136,34 @@ impl WeatherProvider {
         current_weather.temperature,
       ),
       wind_speed: current_weather.wind_speed,
+      air_quality,
+    })
+  }
+
+  /// Relevant documentation: https://open-meteo.com/en/docs/air-quality-api
+  async fn query_air_quality(
+    &self,
+    latitude: f32,
+    longitude: f32,
+  ) -> anyhow::Result<AirQuality> {
+    let res = self
+      .http_client
+      .get("https://air-quality-api.open-meteo.com/v1/air-quality")
+      .query(&[
+        ("latitude", &latitude.to_string()),
+        ("longitude", &longitude.to_string()),
+        ("current", &"us_aqi,european_aqi,pm10,pm2_5".to_string()),
+      ])
+      .send()
+      .await?
+      .json::<OpenMeteoAirQualityRes>()
+      .await?;
+
+    Ok(AirQuality {
+      us_aqi: res.current.us_aqi,
+      european_aqi: res.current.european_aqi,
+      pm10: res.current.pm10,
+      pm2_5: res.current.pm2_5,
     })
   }
 
@@ -158,7 +217,11 @@ impl Provider for WeatherProvider {
   }
 
   async fn start_async(&mut self) {
-    let mut interval = AsyncInterval::new(self.config.refresh_interval);
+    let mut interval = AsyncInterval::with_options(
+      self.config.refresh_interval,
+      &self.config.refresh_options,
+    )
+    .refresh_on_wake();
 
     loop {
       tokio::select! {
@@ -0,0 +1,170 @@
+use std::{
+  collections::HashMap, path::PathBuf, sync::Arc, time::Duration,
+};
+
+use tokio::{task, time};
+use tracing::{error, info};
+
+use crate::{
+  config::{Config, WidgetPreset},
+  widget_factory::{WidgetFactory, WidgetOpenOptions},
+};
+
+/// How often schedules and triggers are evaluated.
+const EVALUATE_INTERVAL_MS: u64 = 1000;
+
+/// Opens, closes, and hides widgets based on the `schedule` of their
+/// presets.
+///
+/// Only presets that are in the active startup configs are scheduled.
+pub struct Scheduler {
+  config: Arc<Config>,
+  widget_factory: Arc<WidgetFactory>,
+
+  /// Whether each scheduled preset was within its active hours at the
+  /// last evaluation. Widgets are only opened or closed when this
+  /// changes, so that manually opened or closed widgets are left as-is.
+  active_states: HashMap<(PathBuf, String), bool>,
+
+  /// Whether a fullscreen window was focused at the last evaluation.
+  is_fullscreen: bool,
+}
+
+impl Scheduler {
+  /// Starts evaluating schedules in a background task.
+  pub fn start(config: Arc<Config>, widget_factory: Arc<WidgetFactory>) {
+    let mut scheduler = Self {
+      config,
+      widget_factory,
+      active_states: HashMap::new(),
+      is_fullscreen: false,
+    };
+
+    task::spawn(async move {
+      let mut interval =
+        time::interval(Duration::from_millis(EVALUATE_INTERVAL_MS));
+
+      loop {
+        interval.tick().await;
+
+        if let Err(err) = scheduler.evaluate().await {
+          error!("Failed to evaluate widget schedules: {:?}", err);
+        }
+      }
+    });
+  }
+
+  async fn evaluate(&mut self) -> anyhow::Result<()> {
+    let scheduled_presets = self.scheduled_presets().await;
+
+    // Open or close widgets whose active hours have started or ended.
+    for (config_path, preset) in &scheduled_presets {
+      let Some(schedule) = &preset.schedule else {
+        continue;
+      };
+
+      let is_active = schedule.is_active_now();
+      let key = (config_path.clone(), preset.name.clone());
+      let prev_is_active = self.active_states.insert(key, is_active);
+
+      // Widgets are opened by `WidgetFactory::startup` on the first
+      // evaluation, so only act on changes.
+      if prev_is_active.is_none() || prev_is_active == Some(is_active) {
+        continue;
+      }
+
+      info!(
+        "Schedule for preset '{}' of {} is now {}.",
+        preset.name,
+        config_path.display(),
+        if is_active { "active" } else { "inactive" }
+      );
+
+      match is_active {
+        true => {
+          self
+            .widget_factory
+            .start_widget(
+              config_path,
+              &WidgetOpenOptions::Preset(preset.name.clone()),
+            )
+            .await?
+        }
+        false => {
+          let _ = self
+            .widget_factory
+            .stop_by_preset(config_path, &preset.name)
+            .await;
+        }
+      }
+    }
+
+    // Hide or show widgets when a fullscreen window gains or loses focus.
+    let is_fullscreen = is_fullscreen_window_focused();
+
+    if is_fullscreen != self.is_fullscreen {
+      self.is_fullscreen = is_fullscreen;
+
+      for (config_path, preset) in &scheduled_presets {
+        let hide_on_fullscreen = preset
+          .schedule
+          .as_ref()
+          .is_some_and(|schedule| schedule.hide_on_fullscreen);
+
+        if hide_on_fullscreen {
+          self
+            .widget_factory
+            .set_visible_by_preset(
+              config_path,
+              &preset.name,
+              !is_fullscreen,
+            )
+            .await?;
+        }
+      }
+    }
+
+    Ok(())
+  }
+
+  /// Returns presets with a schedule that are in the active startup
+  /// configs, along with their absolute config paths.
+  async fn scheduled_presets(&self) -> Vec<(PathBuf, WidgetPreset)> {
+    let widget_configs = self.config.widget_configs().await;
+
+    self
+      .config
+      .startup_configs()
+      .await
+      .into_iter()
+      .filter_map(|startup_config| {
+        let config_path =
+          self.config.to_absolute_path(&startup_config.path).ok()?;
+
+        let preset = widget_configs
+          .get(&config_path)?
+          .presets
+          .iter()
+          .find(|preset| preset.name == startup_config.preset)?
+          .clone();
+
+        preset.schedule.is_some().then_some((config_path, preset))
+      })
+      .collect()
+  }
+}
+
+/// Whether the foreground window covers its entire monitor.
+///
+/// Windows belonging to Zebar itself and the desktop are ignored.
+#[cfg(windows)]
+fn is_fullscreen_window_focused() -> bool {
+  crate::common::windows::foreground_window()
+    .is_some_and(|window| window.is_fullscreen)
+}
+
+/// Fullscreen detection is only supported on Windows.
+#[cfg(not(windows))]
+fn is_fullscreen_window_focused() -> bool {
+  false
+}
@@ -0,0 +1,146 @@
+use std::{sync::Arc, time::Duration};
+
+use serde::Serialize;
+use tauri::{AppHandle, Emitter};
+use tokio::{
+  sync::{broadcast, RwLock},
+  task,
+};
+use tracing::info;
+
+#[cfg(target_os = "linux")]
+use crate::common::linux;
+#[cfg(target_os = "windows")]
+use crate::common::windows;
+
+/// Name of the Tauri event emitted to all widgets when the screen
+/// presence changes.
+const SCREEN_PRESENCE_CHANGED_EVENT: &str = "screen-presence-changed";
+
+/// Whether anyone is able to see the screen.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScreenPresence {
+  /// Whether the display is on (i.e. not turned off by power saving).
+  pub is_display_on: bool,
+
+  /// Whether the session that Zebar is running in is locked.
+  pub is_session_locked: bool,
+
+  /// Whether the session that Zebar is running in is in the foreground
+  /// (i.e. not switched away from or disconnected via RDP).
+  pub is_session_active: bool,
+}
+
+impl ScreenPresence {
+  /// Whether widgets are currently visible to the user.
+  pub fn is_attended(&self) -> bool {
+    self.is_display_on && !self.is_session_locked && self.is_session_active
+  }
+}
+
+impl Default for ScreenPresence {
+  fn default() -> Self {
+    Self {
+      is_display_on: true,
+      is_session_locked: false,
+      is_session_active: true,
+    }
+  }
+}
+
+pub struct ScreenPresenceState {
+  _change_rx: broadcast::Receiver<ScreenPresence>,
+
+  pub change_tx: broadcast::Sender<ScreenPresence>,
+
+  presence: Arc<RwLock<ScreenPresence>>,
+}
+
+impl ScreenPresenceState {
+  /// Creates a new `ScreenPresenceState` instance.
+  pub fn new(app_handle: &AppHandle) -> Self {
+    let (change_tx, _change_rx) = broadcast::channel(16);
+    let presence = Arc::new(RwLock::new(ScreenPresence::default()));
+
+    Self::listen_changes(
+      app_handle.clone(),
+      presence.clone(),
+      change_tx.clone(),
+    );
+
+    Self {
+      _change_rx,
+      change_tx,
+      presence,
+    }
+  }
+
+  /// Gets the current screen presence.
+  pub async fn presence(&self) -> ScreenPresence {
+    *self.presence.read().await
+  }
+
+  /// Polls the display and session state for changes, and emits the new
+  /// presence to all widgets when it changes.
+  fn listen_changes(
+    app_handle: AppHandle,
+    presence: Arc<RwLock<ScreenPresence>>,
+    change_tx: broadcast::Sender<ScreenPresence>,
+  ) {
+    task::spawn(async move {
+      let mut interval = tokio::time::interval(Duration::from_secs(2));
+
+      interval
+        .set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
+
+      loop {
+        interval.tick().await;
+
+        let Ok(new_presence) =
+          task::spawn_blocking(Self::system_presence).await
+        else {
+          continue;
+        };
+
+        if *presence.read().await != new_presence {
+          info!("Detected change in screen presence: {:?}", new_presence);
+          *presence.write().await = new_presence;
+          let _ =
+            app_handle.emit(SCREEN_PRESENCE_CHANGED_EVENT, new_presence);
+          let _ = change_tx.send(new_presence);
+        }
+      }
+    });
+  }
+
+  /// Reads the display and session state. Falls back to the display
+  /// being on and the session being in use where these can't be read.
+  fn system_presence() -> ScreenPresence {
+    #[allow(unused_mut)]
+    let mut presence = ScreenPresence::default();
+
+    #[cfg(target_os = "windows")]
+    {
+      presence.is_display_on = windows::is_display_on();
+    }
+
+    #[cfg(target_os = "linux")]
+    {
+      presence.is_display_on = linux::is_display_on();
+    }
+
+    #[cfg(any(target_os = "windows", target_os = "linux"))]
+    match crate::providers::current_user_session() {
+      Ok(session) => {
+        presence.is_session_locked = session.is_locked;
+        presence.is_session_active = session.is_active;
+      }
+      Err(err) => {
+        tracing::debug!("Failed to get current session: {:?}", err);
+      }
+    }
+
+    presence
+  }
+}
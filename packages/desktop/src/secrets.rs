@@ -0,0 +1,97 @@
+use anyhow::{bail, Context};
+use keyring::Entry;
+
+/// Service name that secrets are stored under in the OS credential
+/// store.
+const SERVICE_NAME: &str = "zebar";
+
+/// Prefix for string values in provider configs that reference a secret
+/// (e.g. `"apiKey": "secret:owm"`).
+const SECRET_REF_PREFIX: &str = "secret:";
+
+/// Secrets that references in a provider config are allowed to resolve.
+#[derive(Clone, Debug)]
+pub enum SecretAccess {
+  /// Any secret. Used for configs from the user's settings.
+  Any,
+
+  /// Only the named secrets. Used for configs from widgets.
+  Only(Vec<String>),
+}
+
+impl SecretAccess {
+  fn allows(&self, name: &str) -> bool {
+    match self {
+      SecretAccess::Any => true,
+      SecretAccess::Only(names) => {
+        names.iter().any(|allowed| allowed == name)
+      }
+    }
+  }
+}
+
+/// Stores a secret in the OS credential store (Windows Credential
+/// Manager, macOS Keychain, or Secret Service on Linux).
+pub fn set_secret(name: &str, value: &str) -> anyhow::Result<()> {
+  entry(name)?
+    .set_password(value)
+    .with_context(|| format!("Failed to store secret '{}'.", name))
+}
+
+/// Removes a secret from the OS credential store.
+pub fn delete_secret(name: &str) -> anyhow::Result<()> {
+  entry(name)?
+    .delete_credential()
+    .with_context(|| format!("Failed to delete secret '{}'.", name))
+}
+
+/// Retrieves a secret from the OS credential store.
+pub fn get_secret(name: &str) -> anyhow::Result<String> {
+  entry(name)?
+    .get_password()
+    .with_context(|| format!("No secret found named '{}'.", name))
+}
+
+/// Replaces all string values of the form `secret:<NAME>` within the
+/// given JSON value with the corresponding secret.
+///
+/// Returns whether any secrets were resolved.
+pub fn resolve_secret_refs(
+  value: &mut serde_json::Value,
+  access: &SecretAccess,
+) -> anyhow::Result<bool> {
+  match value {
+    serde_json::Value::String(string) => {
+      let Some(name) = string.strip_prefix(SECRET_REF_PREFIX) else {
+        return Ok(false);
+      };
+
+      if !access.allows(name) {
+        bail!(
+          "Widget is not permitted to use secret '{}'. Add it to \
+          `permissions.secrets` in the widget config.",
+          name
+        );
+      }
+
+      *string = get_secret(name)?;
+      Ok(true)
+    }
+    serde_json::Value::Array(values) => {
+      values.iter_mut().try_fold(false, |resolved, value| {
+        Ok(resolve_secret_refs(value, access)? || resolved)
+      })
+    }
+    serde_json::Value::Object(map) => {
+      map.values_mut().try_fold(false, |resolved, value| {
+        Ok(resolve_secret_refs(value, access)? || resolved)
+      })
+    }
+    _ => Ok(false),
+  }
+}
+
+fn entry(name: &str) -> anyhow::Result<Entry> {
+  Entry::new(SERVICE_NAME, name)
+    .with_context(|| format!("Invalid secret name '{}'.", name))
+}
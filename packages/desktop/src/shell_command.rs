@@ -0,0 +1,118 @@
+use std::{path::PathBuf, process::Stdio, time::Duration};
+
+use anyhow::{bail, Context};
+use serde::Serialize;
+use tokio::{
+  io::{AsyncRead, AsyncReadExt},
+  process::Command,
+  time,
+};
+
+use crate::common::no_window_command;
+
+/// Default timeout for commands if none is specified.
+const DEFAULT_TIMEOUT_MS: u64 = 10_000;
+
+/// Max timeout that a widget can request.
+const MAX_TIMEOUT_MS: u64 = 60_000;
+
+/// Max number of bytes captured from each of stdout and stderr.
+const MAX_OUTPUT_BYTES: usize = 1024 * 1024;
+
+/// Output of a shell command run on behalf of a widget.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ShellCommandOutput {
+  /// Exit code of the process. `None` if it was terminated by a signal
+  /// or killed due to exceeding the output limit.
+  pub exit_code: Option<i32>,
+
+  /// Captured stdout (lossily decoded as UTF-8).
+  pub stdout: String,
+
+  /// Captured stderr (lossily decoded as UTF-8).
+  pub stderr: String,
+
+  /// Whether stdout or stderr exceeded the output limit and was cut
+  /// off.
+  pub truncated: bool,
+}
+
+/// Runs a program directly (without a shell) and captures its output.
+///
+/// The process is killed if it runs longer than the timeout or writes
+/// more than `MAX_OUTPUT_BYTES` to stdout or stderr.
+pub async fn run_shell_command(
+  program: &str,
+  args: &[String],
+  cwd: Option<PathBuf>,
+  timeout_ms: Option<u64>,
+) -> anyhow::Result<ShellCommandOutput> {
+  let timeout_ms = timeout_ms
+    .unwrap_or(DEFAULT_TIMEOUT_MS)
+    .clamp(1, MAX_TIMEOUT_MS);
+
+  let mut command = Command::from(no_window_command(program));
+  command
+    .args(args)
+    .stdin(Stdio::null())
+    .stdout(Stdio::piped())
+    .stderr(Stdio::piped())
+    .kill_on_drop(true);
+
+  if let Some(cwd) = cwd {
+    command.current_dir(cwd);
+  }
+
+  let mut child = command
+    .spawn()
+    .with_context(|| format!("Failed to run '{}'.", program))?;
+
+  let stdout = child.stdout.take().context("Missing stdout pipe.")?;
+  let stderr = child.stderr.take().context("Missing stderr pipe.")?;
+
+  let run = async {
+    let ((stdout, stdout_truncated), (stderr, stderr_truncated)) =
+      tokio::try_join!(read_limited(stdout), read_limited(stderr))?;
+
+    let truncated = stdout_truncated || stderr_truncated;
+
+    if truncated {
+      let _ = child.start_kill();
+    }
+
+    let status = child.wait().await?;
+
+    anyhow::Ok(ShellCommandOutput {
+      exit_code: status.code(),
+      stdout,
+      stderr,
+      truncated,
+    })
+  };
+
+  match time::timeout(Duration::from_millis(timeout_ms), run).await {
+    Ok(output) => output,
+    // The child is killed on drop.
+    Err(_) => bail!("'{}' timed out after {}ms.", program, timeout_ms),
+  }
+}
+
+/// Reads up to `MAX_OUTPUT_BYTES` from the reader.
+///
+/// Returns the decoded output and whether it was truncated.
+async fn read_limited(
+  reader: impl AsyncRead + Unpin,
+) -> anyhow::Result<(String, bool)> {
+  let mut buffer = Vec::new();
+
+  reader
+    .take(MAX_OUTPUT_BYTES as u64 + 1)
+    .read_to_end(&mut buffer)
+    .await?;
+
+  let truncated = buffer.len() > MAX_OUTPUT_BYTES;
+  buffer.truncate(MAX_OUTPUT_BYTES);
+
+  Ok((String::from_utf8_lossy(&buffer).into_owned(), truncated))
+}
@@ -0,0 +1,80 @@
+use std::time::Duration;
+
+use tokio::{net::TcpStream, task, time};
+use tracing::{info, warn};
+
+use crate::config::{WaitForConfig, WaitForTarget};
+
+/// How often to check whether the target is available.
+const POLL_INTERVAL_MS: u64 = 500;
+
+/// Address of GlazeWM's IPC server.
+const GLAZEWM_IPC_ADDR: &str = "127.0.0.1:6123";
+
+/// Waits until the configured target is available, or until the timeout
+/// is reached.
+///
+/// When launched at login, Zebar can otherwise start before the window
+/// manager, which leaves workspace widgets empty until they're reloaded.
+pub async fn wait_for_target(config: &WaitForConfig) {
+  info!("Waiting for {:?} before opening widgets.", config.target);
+
+  let wait = async {
+    let mut interval =
+      time::interval(Duration::from_millis(POLL_INTERVAL_MS));
+
+    loop {
+      interval.tick().await;
+
+      if is_available(&config.target).await {
+        break;
+      }
+    }
+  };
+
+  match time::timeout(Duration::from_millis(config.timeout), wait).await {
+    Ok(()) => info!("{:?} is available.", config.target),
+    Err(_) => warn!(
+      "Timed out waiting for {:?}. Opening widgets anyway.",
+      config.target
+    ),
+  }
+}
+
+/// Whether the target is currently available.
+pub async fn is_available(target: &WaitForTarget) -> bool {
+  match target {
+    WaitForTarget::Glazewm => {
+      TcpStream::connect(GLAZEWM_IPC_ADDR).await.is_ok()
+    }
+    WaitForTarget::Komorebi => task::spawn_blocking(is_komorebi_available)
+      .await
+      .unwrap_or(false),
+    WaitForTarget::Process(name) => {
+      let name = name.clone();
+
+      task::spawn_blocking(move || {
+        let mut system = sysinfo::System::new();
+        system.refresh_processes();
+
+        system
+          .processes()
+          .values()
+          .any(|process| process.name().eq_ignore_ascii_case(&name))
+      })
+      .await
+      .unwrap_or(false)
+    }
+  }
+}
+
+#[cfg(target_os = "windows")]
+fn is_komorebi_available() -> bool {
+  komorebi_client::send_query(&komorebi_client::SocketMessage::State)
+    .is_ok()
+}
+
+#[cfg(not(target_os = "windows"))]
+fn is_komorebi_available() -> bool {
+  false
+}
@@ -4,31 +4,69 @@ use anyhow::{bail, Context};
 use base64::prelude::*;
 use tauri::{
   image::Image,
-  menu::{CheckMenuItem, Menu, MenuBuilder, Submenu, SubmenuBuilder},
+  menu::{
+    CheckMenuItem, Menu, MenuBuilder, MenuItem, Submenu, SubmenuBuilder,
+  },
   tray::{
     MouseButton, MouseButtonState, TrayIcon, TrayIconBuilder,
     TrayIconEvent,
   },
   AppHandle, Manager, WebviewUrl, WebviewWindowBuilder, Wry,
 };
-use tokio::task;
-use tracing::{error, info};
+use tauri_plugin_dialog::{DialogExt, MessageDialogKind};
+use tokio::{sync::Mutex, task};
+use tracing::{error, info, warn};
 
 use crate::{
   common::PathExt,
-  config::{Config, StartupConfig, WidgetConfig, WidgetPreset},
+  config::{
+    Config, DisplayProfile, Profile, StartupConfig, WidgetConfig,
+    WidgetPreset, DEFAULT_PROFILE_NAME,
+  },
+  display_profiles::{apply_display_profile, capture_display_profile},
+  error_log::{ErrorEntry, ErrorLog},
+  git_sync::{GitSync, GitSyncStatus},
+  i18n::{t, t_with},
+  monitor_state::MonitorState,
+  portable,
+  tray_badge::{render_number_icon, TrayBadge},
+  usage_stats::UsageStats,
   widget_factory::{WidgetFactory, WidgetOpenOptions, WidgetState},
 };
 
+/// Label of the settings window.
+pub const SETTINGS_WINDOW_LABEL: &str = "settings";
+
 #[derive(Debug, Clone)]
 enum MenuEvent {
   ShowConfigFolder,
   ReloadConfigs,
   OpenSettings,
   Exit,
+  OpenLogFile,
+  ShowUsageStats,
+  ClearErrors,
+  SwitchProfile {
+    name: String,
+  },
+  SaveDisplayProfile,
+  ApplyDisplayProfile {
+    name: String,
+  },
+  GitPull,
+  GitCommitAndPush,
   EditWidget {
     path: PathBuf,
   },
+  ReloadWidget {
+    path: PathBuf,
+  },
+  OpenWidgetDevtools {
+    path: PathBuf,
+  },
+  CaptureWidget {
+    path: PathBuf,
+  },
   ToggleWidgetPreset {
     enable: bool,
     preset: String,
@@ -48,9 +86,30 @@ impl ToString for MenuEvent {
       MenuEvent::ReloadConfigs => "reload_configs".to_string(),
       MenuEvent::OpenSettings => "open_settings".to_string(),
       MenuEvent::Exit => "exit".to_string(),
+      MenuEvent::OpenLogFile => "open_log_file".to_string(),
+      MenuEvent::ShowUsageStats => "show_usage_stats".to_string(),
+      MenuEvent::ClearErrors => "clear_errors".to_string(),
+      MenuEvent::SwitchProfile { name } => {
+        format!("switch_profile_{}", name)
+      }
+      MenuEvent::SaveDisplayProfile => "save_display_profile".to_string(),
+      MenuEvent::ApplyDisplayProfile { name } => {
+        format!("apply_display_profile_{}", name)
+      }
+      MenuEvent::GitPull => "git_pull".to_string(),
+      MenuEvent::GitCommitAndPush => "git_commit_and_push".to_string(),
       MenuEvent::EditWidget { path } => {
         format!("edit_widget_{}", path.to_unicode_string())
       }
+      MenuEvent::ReloadWidget { path } => {
+        format!("reload_widget_{}", path.to_unicode_string())
+      }
+      MenuEvent::OpenWidgetDevtools { path } => {
+        format!("open_widget_devtools_{}", path.to_unicode_string())
+      }
+      MenuEvent::CaptureWidget { path } => {
+        format!("capture_widget_{}", path.to_unicode_string())
+      }
       MenuEvent::ToggleWidgetPreset {
         enable,
         preset,
@@ -90,9 +149,34 @@ impl FromStr for MenuEvent {
       ["reload", "configs"] => Ok(Self::ReloadConfigs),
       ["open", "settings"] => Ok(Self::OpenSettings),
       ["exit"] => Ok(Self::Exit),
+      ["open", "log", "file"] => Ok(Self::OpenLogFile),
+      ["show", "usage", "stats"] => Ok(Self::ShowUsageStats),
+      ["clear", "errors"] => Ok(Self::ClearErrors),
+      ["switch", "profile", name @ ..] => Ok(Self::SwitchProfile {
+        name: name.join("_"),
+      }),
+      ["save", "display", "profile"] => Ok(Self::SaveDisplayProfile),
+      ["apply", "display", "profile", name @ ..] => {
+        Ok(Self::ApplyDisplayProfile {
+          name: name.join("_"),
+        })
+      }
+      ["git", "pull"] => Ok(Self::GitPull),
+      ["git", "commit", "and", "push"] => Ok(Self::GitCommitAndPush),
       ["edit", "widget", path @ ..] => Ok(Self::EditWidget {
         path: PathBuf::from(path.join("_")),
       }),
+      ["reload", "widget", path @ ..] => Ok(Self::ReloadWidget {
+        path: PathBuf::from(path.join("_")),
+      }),
+      ["open", "widget", "devtools", path @ ..] => {
+        Ok(Self::OpenWidgetDevtools {
+          path: PathBuf::from(path.join("_")),
+        })
+      }
+      ["capture", "widget", path @ ..] => Ok(Self::CaptureWidget {
+        path: PathBuf::from(path.join("_")),
+      }),
       ["toggle", "widget", "config", enable @ ("true" | "false"), preset, path @ ..] => {
         Ok(Self::ToggleWidgetPreset {
           enable: *enable == "true",
@@ -116,42 +200,69 @@ impl FromStr for MenuEvent {
 pub struct SysTray {
   app_handle: AppHandle,
   config: Arc<Config>,
+  error_log: Arc<ErrorLog>,
+  monitor_state: Arc<MonitorState>,
   widget_factory: Arc<WidgetFactory>,
+  git_sync: Arc<GitSync>,
   tray_icon: Option<TrayIcon>,
+
+  /// Badge set by widgets via the `set_tray_badge` command.
+  badge: Mutex<Option<TrayBadge>>,
+
+  /// Number shown in place of the icon, as bound via the `trayBinding`
+  /// setting.
+  icon_value: Mutex<Option<u32>>,
 }
 
 impl SysTray {
   /// Creates a new system tray icon for Zebar.
+  ///
+  /// If `show_icon` is false, no icon is added to the system tray and
+  /// calls to `refresh` and `set_badge` are no-ops.
   pub async fn new(
     app_handle: &AppHandle,
     config: Arc<Config>,
+    error_log: Arc<ErrorLog>,
+    monitor_state: Arc<MonitorState>,
     widget_factory: Arc<WidgetFactory>,
+    git_sync: Arc<GitSync>,
+    show_icon: bool,
   ) -> anyhow::Result<SysTray> {
     let mut sys_tray = Self {
       app_handle: app_handle.clone(),
       config,
+      error_log,
+      monitor_state,
       widget_factory,
+      git_sync,
       tray_icon: None,
+      badge: Mutex::new(None),
+      icon_value: Mutex::new(None),
     };
 
-    sys_tray.tray_icon = Some(sys_tray.create_tray_icon().await?);
+    if show_icon {
+      sys_tray.tray_icon = Some(sys_tray.create_tray_icon().await?);
+    }
 
     Ok(sys_tray)
   }
 
   async fn create_tray_icon(&self) -> anyhow::Result<TrayIcon> {
-    let tooltip = format!("Zebar v{}", env!("VERSION_NUMBER"));
+    let tooltip = default_tooltip();
 
     // Linting: `mut` needed for Windows where `tray_icon` is modified with
     // additional click handler.
     #[allow(unused_mut)]
     let mut tray_icon = TrayIconBuilder::with_id("tray")
-      .icon(self.icon_image()?)
+      .icon(self.icon_image().await?)
       .menu(&self.create_tray_menu().await?)
       .tooltip(tooltip)
       .on_menu_event({
         let config = self.config.clone();
+        let error_log = self.error_log.clone();
+        let monitor_state = self.monitor_state.clone();
         let widget_factory = self.widget_factory.clone();
+        let git_sync = self.git_sync.clone();
 
         move |app_handle, event| {
           if let Ok(menu_event) = MenuEvent::from_str(event.id.as_ref()) {
@@ -159,7 +270,10 @@ impl SysTray {
               menu_event,
               app_handle.clone(),
               config.clone(),
+              error_log.clone(),
+              monitor_state.clone(),
               widget_factory.clone(),
+              git_sync.clone(),
             );
           }
         }
@@ -172,7 +286,10 @@ impl SysTray {
         tray_icon.menu_on_left_click(false).on_tray_icon_event({
           let app_handle = self.app_handle.clone();
           let config = self.config.clone();
+          let error_log = self.error_log.clone();
+          let monitor_state = self.monitor_state.clone();
           let widget_factory = self.widget_factory.clone();
+          let git_sync = self.git_sync.clone();
 
           move |_, event| {
             if let TrayIconEvent::Click {
@@ -185,7 +302,10 @@ impl SysTray {
                 MenuEvent::OpenSettings,
                 app_handle.clone(),
                 config.clone(),
+                error_log.clone(),
+                monitor_state.clone(),
                 widget_factory.clone(),
+                git_sync.clone(),
               );
             }
           }
@@ -201,18 +321,123 @@ impl SysTray {
     if let Some(tray_icon) = self.tray_icon.as_ref() {
       let tray_menu = self.create_tray_menu().await?;
       tray_icon.set_menu(Some(tray_menu))?;
+      tray_icon.set_icon(Some(self.icon_image().await?))?;
+    }
+
+    Ok(())
+  }
+
+  /// Sets or clears the badge shown on the system tray icon.
+  pub async fn set_badge(
+    &self,
+    badge: Option<TrayBadge>,
+  ) -> anyhow::Result<()> {
+    *self.badge.lock().await = badge;
+
+    if let Some(tray_icon) = self.tray_icon.as_ref() {
+      tray_icon.set_icon(Some(self.icon_image().await?))?;
+    }
+
+    Ok(())
+  }
+
+  /// Sets the tooltip of the system tray icon. Resets to the default
+  /// tooltip if `None`.
+  pub fn set_tooltip(
+    &self,
+    tooltip: Option<String>,
+  ) -> anyhow::Result<()> {
+    if let Some(tray_icon) = self.tray_icon.as_ref() {
+      tray_icon
+        .set_tooltip(Some(tooltip.unwrap_or_else(default_tooltip)))?;
+    }
+
+    Ok(())
+  }
+
+  /// Sets or clears the number shown in place of the system tray icon.
+  pub async fn set_icon_value(
+    &self,
+    value: Option<u32>,
+  ) -> anyhow::Result<()> {
+    *self.icon_value.lock().await = value;
+
+    if let Some(tray_icon) = self.tray_icon.as_ref() {
+      tray_icon.set_icon(Some(self.icon_image().await?))?;
     }
 
     Ok(())
   }
 
   /// Returns the image to use for the system tray icon.
-  fn icon_image(&self) -> anyhow::Result<Image> {
+  ///
+  /// Uses the bound number or the custom icon from settings if either is
+  /// set, and draws the error badge or widget-set badge on top.
+  async fn icon_image(&self) -> anyhow::Result<Image<'static>> {
+    let (tray_icon_path, show_error_badge, icon_color) = {
+      let settings = self.config.settings.lock().await;
+      (
+        settings.tray_icon_path.clone(),
+        settings.show_error_badge,
+        settings
+          .tray_binding
+          .as_ref()
+          .and_then(|binding| binding.icon_color.clone()),
+      )
+    };
+
+    let icon = match tray_icon_path {
+      Some(path) => self
+        .custom_icon_image(&path)
+        .map(Some)
+        .unwrap_or_else(|err| {
+          warn!("Failed to load custom tray icon: {:?}", err);
+          self.default_icon_image()
+        }),
+      None => self.default_icon_image(),
+    }
+    .context("No icon defined in Tauri config.")?;
+
+    let icon = match *self.icon_value.lock().await {
+      Some(value) => render_number_icon(
+        value,
+        icon.width().min(icon.height()),
+        icon_color.as_deref(),
+      ),
+      None => icon,
+    };
+
+    let badge = match show_error_badge && !self.error_log.is_empty() {
+      true => Some(TrayBadge::Dot { color: None }),
+      false => self.badge.lock().await.clone(),
+    };
+
+    match badge {
+      Some(badge) => badge.render(&icon),
+      None => Ok(icon),
+    }
+  }
+
+  /// Returns the application icon defined in the Tauri config.
+  fn default_icon_image(&self) -> Option<Image<'static>> {
     self
       .app_handle
       .default_window_icon()
-      .cloned()
-      .context("No icon defined in Tauri config.")
+      .map(|icon| icon.clone().to_owned())
+  }
+
+  /// Loads a custom icon from the given path.
+  fn custom_icon_image(
+    &self,
+    path: &PathBuf,
+  ) -> anyhow::Result<Image<'static>> {
+    let path = self.config.to_absolute_path(path)?;
+    let image = image::open(&path)
+      .with_context(|| format!("Unable to read {}.", path.display()))?
+      .into_rgba8();
+
+    let (width, height) = image.dimensions();
+    Ok(Image::new_owned(image.into_raw(), width, height))
   }
 
   /// Creates and returns the main system tray menu.
@@ -220,6 +445,9 @@ impl SysTray {
     let widget_configs = self.config.widget_configs().await;
     let widget_states = self.widget_factory.states_by_path().await;
     let startup_configs = self.config.startup_configs_by_path().await?;
+    let display_profiles = self.config.display_profiles().await;
+    let profiles = self.config.profiles().await;
+    let active_profile = self.config.active_profile().await;
 
     let configs_menu = self.create_configs_menu(
       &widget_configs,
@@ -227,20 +455,46 @@ impl SysTray {
       &startup_configs,
     )?;
 
+    let display_profiles_menu =
+      self.create_display_profiles_menu(&display_profiles)?;
+
+    let errors_menu =
+      self.create_errors_menu(&self.error_log.entries())?;
+
     let mut tray_menu = MenuBuilder::new(&self.app_handle)
-      .text(MenuEvent::OpenSettings, "Open settings")
-      .item(&configs_menu)
+      .text(MenuEvent::OpenSettings, t("tray.openSettings"))
+      .item(&configs_menu);
+
+    if !profiles.is_empty() {
+      let profiles_menu =
+        self.create_profiles_menu(&profiles, active_profile.as_deref())?;
+
+      tray_menu = tray_menu.item(&profiles_menu);
+    }
+
+    tray_menu = tray_menu.item(&display_profiles_menu);
+
+    if let Some(status) = self.git_sync.status().await {
+      let git_sync_menu = self.create_git_sync_menu(&status)?;
+      tray_menu = tray_menu.item(&git_sync_menu);
+    }
+
+    let mut tray_menu = tray_menu
       .text(MenuEvent::ReloadConfigs, {
         #[cfg(windows)]
         {
           // Windows needs to triple escape ampersands.
-          "Empty cache &&& reload configs"
+          t("tray.reloadConfigs").replace('&', "&&&")
         }
         #[cfg(not(windows))]
         {
-          "Empty cache & reload configs"
+          t("tray.reloadConfigs")
         }
       })
+      .separator()
+      .item(&errors_menu)
+      .text(MenuEvent::OpenLogFile, t("tray.openLogFile"))
+      .text(MenuEvent::ShowUsageStats, t("tray.usageStats"))
       .separator();
 
     // Add submenus for currently active widget.
@@ -261,7 +515,8 @@ impl SysTray {
       tray_menu = tray_menu.separator();
     }
 
-    let tray_menu = tray_menu.text(MenuEvent::Exit, "Exit").build()?;
+    let tray_menu =
+      tray_menu.text(MenuEvent::Exit, t("tray.exit")).build()?;
 
     // Set "Open settings" as the default menu item on Windows.
     #[cfg(windows)]
@@ -288,7 +543,10 @@ impl SysTray {
     event: MenuEvent,
     app_handle: AppHandle,
     config: Arc<Config>,
+    error_log: Arc<ErrorLog>,
+    monitor_state: Arc<MonitorState>,
     widget_factory: Arc<WidgetFactory>,
+    git_sync: Arc<GitSync>,
   ) {
     task::spawn(async move {
       info!("Received tray menu event: {:?}", event);
@@ -308,9 +566,69 @@ impl SysTray {
           app_handle.exit(0);
           Ok(())
         }
+        MenuEvent::OpenLogFile => error_log
+          .open_log_file()
+          .context("Failed to open log file."),
+        MenuEvent::ShowUsageStats => {
+          Self::show_usage_stats(&app_handle).await;
+          Ok(())
+        }
+        MenuEvent::ClearErrors => {
+          error_log.clear();
+          Ok(())
+        }
+        MenuEvent::SwitchProfile { name } => {
+          widget_factory.switch_profile(&name).await
+        }
+        MenuEvent::SaveDisplayProfile => {
+          let profiles = config.display_profiles().await;
+
+          // Use the first unused number, so that an existing profile
+          // isn't overwritten after another one is deleted.
+          let mut number = 1;
+
+          let name = loop {
+            let name = t_with(
+              "tray.displayProfileName",
+              &[("number", &number.to_string())],
+            );
+
+            if !profiles.iter().any(|profile| profile.name == name) {
+              break name;
+            }
+
+            number += 1;
+          };
+
+          let profile = capture_display_profile(
+            &name,
+            &monitor_state.monitors().await,
+          );
+
+          config.save_display_profile(profile).await
+        }
+        MenuEvent::ApplyDisplayProfile { name } => {
+          match config.display_profile_by_name(&name).await {
+            Some(profile) => apply_display_profile(&profile),
+            None => {
+              Err(anyhow::anyhow!("No display profile named '{}'.", name))
+            }
+          }
+        }
+        MenuEvent::GitPull => git_sync.pull().await,
+        MenuEvent::GitCommitAndPush => git_sync.commit_and_push().await,
         MenuEvent::EditWidget { path } => {
           Self::open_settings_window(&app_handle, Some(&path))
         }
+        MenuEvent::ReloadWidget { path } => {
+          widget_factory.relaunch_by_paths(&vec![path]).await
+        }
+        MenuEvent::OpenWidgetDevtools { path } => {
+          widget_factory.open_devtools_by_path(&path).await
+        }
+        MenuEvent::CaptureWidget { path } => {
+          Self::capture_widget(&app_handle, &widget_factory, &path).await
+        }
         MenuEvent::ToggleWidgetPreset {
           enable,
           path,
@@ -339,36 +657,93 @@ impl SysTray {
     });
   }
 
+  /// Shows the usage stats in a dialog.
+  async fn show_usage_stats(app_handle: &AppHandle) {
+    let report = app_handle.state::<Arc<UsageStats>>().report().await;
+
+    app_handle
+      .dialog()
+      .message(report.to_string())
+      .title(t("tray.usageStats"))
+      .kind(MessageDialogKind::Info)
+      .show(|_| {});
+  }
+
+  /// Asks where to save the capture, then renders the open widgets of
+  /// the given config to PNG.
+  async fn capture_widget(
+    app_handle: &AppHandle,
+    widget_factory: &WidgetFactory,
+    config_path: &PathBuf,
+  ) -> anyhow::Result<()> {
+    let file_name = format!(
+      "{}.png",
+      config_path
+        .file_stem()
+        .and_then(|stem| stem.to_str())
+        .map(|stem| stem.trim_end_matches(".zebar"))
+        .unwrap_or("widget")
+    );
+
+    let dialog = app_handle
+      .dialog()
+      .file()
+      .set_title(t("tray.captureWidget"))
+      .set_file_name(file_name)
+      .add_filter("PNG", &["png"]);
+
+    let output_path =
+      task::spawn_blocking(move || dialog.blocking_save_file()).await?;
+
+    // No-op if the dialog was cancelled.
+    let Some(output_path) = output_path else {
+      return Ok(());
+    };
+
+    widget_factory
+      .capture_by_path(config_path, &output_path.into_path()?)
+      .await
+  }
+
   fn open_settings_window(
     app_handle: &AppHandle,
     config_path: Option<&PathBuf>,
   ) -> anyhow::Result<()> {
     // Get existing settings window if it's already open.
-    let settings_window = app_handle.get_webview_window("settings");
+    let settings_window =
+      app_handle.get_webview_window(SETTINGS_WINDOW_LABEL);
 
     let route = match config_path {
       None => "/index.html".to_string(),
       Some(path) => {
+        // URL-safe, since standard base64 can contain `/`, which would
+        // split the route.
         format!(
           "/index.html#/widget/{}",
-          BASE64_STANDARD.encode(path.to_unicode_string())
+          BASE64_URL_SAFE_NO_PAD.encode(path.to_unicode_string())
         )
       }
     };
 
     match &settings_window {
       None => {
-        WebviewWindowBuilder::new(
+        let mut builder = WebviewWindowBuilder::new(
           app_handle,
-          "settings",
+          SETTINGS_WINDOW_LABEL,
           WebviewUrl::App(route.into()),
         )
         .title("Settings - Zebar")
         .focused(true)
         .visible(true)
-        .inner_size(900., 600.)
-        .build()
-        .context("Failed to build the settings window.")?;
+        .inner_size(900., 600.);
+
+        if let Some(data_dir) = portable::data_dir() {
+          builder = builder.data_directory(data_dir.join("settings"));
+        }
+
+        builder
+          .build()
+          .context("Failed to build the settings window.")?;
 
         Ok(())
       }
@@ -386,6 +761,204 @@ impl SysTray {
     }
   }
 
+  /// Creates and returns a submenu listing recently logged errors.
+  fn create_errors_menu(
+    &self,
+    entries: &[ErrorEntry],
+  ) -> anyhow::Result<Submenu<Wry>> {
+    let label = match entries.len() {
+      0 => t("tray.recentErrors"),
+      count => format!("{} ({})", t("tray.recentErrors"), count),
+    };
+
+    let mut errors_menu = SubmenuBuilder::new(&self.app_handle, label);
+
+    if entries.is_empty() {
+      let empty_item = MenuItem::new(
+        &self.app_handle,
+        t("tray.noRecentErrors"),
+        false,
+        None::<&str>,
+      )?;
+
+      return Ok(errors_menu.item(&empty_item).build()?);
+    }
+
+    for entry in entries {
+      let error_item = MenuItem::new(
+        &self.app_handle,
+        Self::error_label(entry),
+        false,
+        None::<&str>,
+      )?;
+
+      errors_menu = errors_menu.item(&error_item);
+    }
+
+    errors_menu = errors_menu
+      .separator()
+      .text(MenuEvent::ClearErrors, t("tray.clearErrors"));
+
+    Ok(errors_menu.build()?)
+  }
+
+  /// Formats an error entry as a single-line menu label.
+  fn error_label(entry: &ErrorEntry) -> String {
+    const MAX_LABEL_LENGTH: usize = 80;
+
+    let first_line = entry.message.lines().next().unwrap_or_default();
+
+    let mut label = match first_line.chars().count() > MAX_LABEL_LENGTH {
+      true => format!(
+        "{}…",
+        first_line
+          .chars()
+          .take(MAX_LABEL_LENGTH)
+          .collect::<String>()
+      ),
+      false => first_line.to_string(),
+    };
+
+    if entry.count > 1 {
+      label = format!("({}×) {}", entry.count, label);
+    }
+
+    // Windows needs to triple escape ampersands.
+    #[cfg(windows)]
+    {
+      label = label.replace('&', "&&&");
+    }
+
+    label
+  }
+
+  /// Creates and returns a submenu for pulling and pushing the config
+  /// directory, along with its git status.
+  fn create_git_sync_menu(
+    &self,
+    status: &GitSyncStatus,
+  ) -> anyhow::Result<Submenu<Wry>> {
+    let label = match status.changed_files.len() {
+      0 => t("tray.gitSync"),
+      count => format!("{} ({})", t("tray.gitSync"), count),
+    };
+
+    let changes_label = match status.changed_files.len() {
+      0 => t("tray.gitNoChanges"),
+      count => t_with("tray.gitChanges", &[("count", &count.to_string())]),
+    };
+
+    let changes_item =
+      MenuItem::new(&self.app_handle, changes_label, false, None::<&str>)?;
+
+    let mut git_sync_menu =
+      SubmenuBuilder::new(&self.app_handle, label).item(&changes_item);
+
+    if status.ahead > 0 || status.behind > 0 {
+      let tracking_item = MenuItem::new(
+        &self.app_handle,
+        t_with(
+          "tray.gitAheadBehind",
+          &[
+            ("ahead", &status.ahead.to_string()),
+            ("behind", &status.behind.to_string()),
+          ],
+        ),
+        false,
+        None::<&str>,
+      )?;
+
+      git_sync_menu = git_sync_menu.item(&tracking_item);
+    }
+
+    let commit_and_push_label = {
+      #[cfg(windows)]
+      {
+        // Windows needs to triple escape ampersands.
+        t("tray.gitCommitAndPush").replace('&', "&&&")
+      }
+      #[cfg(not(windows))]
+      {
+        t("tray.gitCommitAndPush")
+      }
+    };
+
+    Ok(
+      git_sync_menu
+        .separator()
+        .text(MenuEvent::GitPull, t("tray.gitPull"))
+        .text(MenuEvent::GitCommitAndPush, commit_and_push_label)
+        .build()?,
+    )
+  }
+
+  /// Creates and returns a submenu for switching between profiles.
+  fn create_profiles_menu(
+    &self,
+    profiles: &[Profile],
+    active_profile: Option<&str>,
+  ) -> anyhow::Result<Submenu<Wry>> {
+    let mut profiles_menu =
+      SubmenuBuilder::new(&self.app_handle, t("tray.profiles"));
+
+    let default_item = CheckMenuItem::with_id(
+      &self.app_handle,
+      MenuEvent::SwitchProfile {
+        name: DEFAULT_PROFILE_NAME.to_string(),
+      },
+      t("tray.defaultProfile"),
+      true,
+      active_profile.is_none(),
+      None::<&str>,
+    )?;
+
+    profiles_menu = profiles_menu.item(&default_item).separator();
+
+    for profile in profiles {
+      let profile_item = CheckMenuItem::with_id(
+        &self.app_handle,
+        MenuEvent::SwitchProfile {
+          name: profile.name.clone(),
+        },
+        &profile.name,
+        true,
+        active_profile == Some(profile.name.as_str()),
+        None::<&str>,
+      )?;
+
+      profiles_menu = profiles_menu.item(&profile_item);
+    }
+
+    Ok(profiles_menu.build()?)
+  }
+
+  /// Creates and returns a submenu for the saved display profiles.
+  fn create_display_profiles_menu(
+    &self,
+    display_profiles: &[DisplayProfile],
+  ) -> anyhow::Result<Submenu<Wry>> {
+    let mut profiles_menu =
+      SubmenuBuilder::new(&self.app_handle, t("tray.displayProfiles"));
+
+    for profile in display_profiles {
+      profiles_menu = profiles_menu.text(
+        MenuEvent::ApplyDisplayProfile {
+          name: profile.name.clone(),
+        },
+        &profile.name,
+      );
+    }
+
+    if !display_profiles.is_empty() {
+      profiles_menu = profiles_menu.separator();
+    }
+
+    profiles_menu = profiles_menu
+      .text(MenuEvent::SaveDisplayProfile, t("tray.saveDisplayProfile"));
+
+    Ok(profiles_menu.build()?)
+  }
+
   /// Creates and returns a submenu for the widget configs.
   fn create_configs_menu(
     &self,
@@ -394,7 +967,7 @@ impl SysTray {
     startup_configs: &HashMap<PathBuf, StartupConfig>,
   ) -> anyhow::Result<Submenu<Wry>> {
     let mut configs_menu =
-      SubmenuBuilder::new(&self.app_handle, "Widget configs");
+      SubmenuBuilder::new(&self.app_handle, t("tray.widgetConfigs"));
 
     // Add each widget config to the menu.
     for (config_path, widget_config) in widget_configs {
@@ -435,9 +1008,34 @@ impl SysTray {
         MenuEvent::EditWidget {
           path: config_path.clone(),
         },
-        "Edit",
+        t("tray.edit"),
       );
 
+    // Add actions that only apply to currently open widgets.
+    if widget_states.contains_key(config_path) {
+      presets_menu = presets_menu
+        .text(
+          MenuEvent::ReloadWidget {
+            path: config_path.clone(),
+          },
+          t("tray.reload"),
+        )
+        .text(
+          MenuEvent::OpenWidgetDevtools {
+            path: config_path.clone(),
+          },
+          t("tray.openDevtools"),
+        )
+        .text(
+          MenuEvent::CaptureWidget {
+            path: config_path.clone(),
+          },
+          t("tray.captureWidget"),
+        );
+    }
+
+    presets_menu = presets_menu.separator();
+
     // Add each widget config to the menu.
     for preset in &widget_config.presets {
       let preset_menu = self.create_preset_menu(
@@ -479,7 +1077,7 @@ impl SysTray {
         preset: preset.name.clone(),
         path: config_path.clone(),
       },
-      "Enabled",
+      t("tray.enabled"),
       true,
       preset_count > 0,
       None::<&str>,
@@ -492,7 +1090,7 @@ impl SysTray {
         preset: preset.name.clone(),
         path: config_path.clone(),
       },
-      "Launch on startup",
+      t("tray.launchOnStartup"),
       true,
       is_launched_on_startup,
       None::<&str>,
@@ -510,3 +1108,8 @@ impl SysTray {
     Ok(config_menu.build()?)
   }
 }
+
+/// Tooltip shown when none is bound via the `trayBinding` setting.
+fn default_tooltip() -> String {
+  format!("Zebar v{}", env!("VERSION_NUMBER"))
+}
@@ -0,0 +1,56 @@
+use serde::Deserialize;
+use tauri::window::{ProgressBarState, ProgressBarStatus};
+
+/// Progress shown on a widget's taskbar button.
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct TaskbarProgress {
+  /// Progress from 0 to 100. Ignored for indeterminate progress.
+  #[serde(default)]
+  pub progress: u64,
+
+  #[serde(default)]
+  pub status: TaskbarProgressStatus,
+}
+
+#[derive(Clone, Copy, Debug, Default, Deserialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum TaskbarProgressStatus {
+  #[default]
+  Normal,
+
+  /// Pulsing progress without a known completion.
+  Indeterminate,
+
+  /// Shown in yellow on Windows.
+  Paused,
+
+  /// Shown in red on Windows.
+  Error,
+}
+
+/// Converts to a Tauri progress bar state. `None` clears the progress.
+pub fn progress_bar_state(
+  progress: Option<TaskbarProgress>,
+) -> ProgressBarState {
+  let Some(progress) = progress else {
+    return ProgressBarState {
+      status: Some(ProgressBarStatus::None),
+      progress: None,
+    };
+  };
+
+  let status = match progress.status {
+    TaskbarProgressStatus::Normal => ProgressBarStatus::Normal,
+    TaskbarProgressStatus::Indeterminate => {
+      ProgressBarStatus::Indeterminate
+    }
+    TaskbarProgressStatus::Paused => ProgressBarStatus::Paused,
+    TaskbarProgressStatus::Error => ProgressBarStatus::Error,
+  };
+
+  ProgressBarState {
+    status: Some(status),
+    progress: Some(progress.progress.min(100)),
+  }
+}
@@ -0,0 +1,182 @@
+use std::{sync::Arc, time::Duration};
+
+use tauri::{AppHandle, Manager, PhysicalPosition, WebviewWindow};
+use tokio::{
+  sync::{broadcast, mpsc},
+  task,
+};
+use tracing::{info, warn};
+
+use crate::{
+  config::DockEdge,
+  widget_factory::{WidgetFactory, WidgetState},
+};
+
+/// Reveals hidden widgets with `touch.edge_swipe_reveal` enabled when
+/// swiping in from the screen edge nearest to them.
+///
+/// Press-and-hold is instead handled by the initialization script, since
+/// touch input goes straight to the webview.
+pub struct TouchGestures {
+  app_handle: AppHandle,
+
+  /// Reference to `WidgetFactory`.
+  widget_factory: Arc<WidgetFactory>,
+}
+
+impl TouchGestures {
+  pub fn new(
+    app_handle: &AppHandle,
+    widget_factory: Arc<WidgetFactory>,
+  ) -> Arc<Self> {
+    let touch_gestures = Arc::new(Self {
+      app_handle: app_handle.clone(),
+      widget_factory,
+    });
+
+    touch_gestures.clone().listen();
+    touch_gestures
+  }
+
+  fn listen(self: Arc<Self>) {
+    let mut open_rx = self.widget_factory.open_tx.subscribe();
+
+    task::spawn(async move {
+      // Swipes are only listened for once a widget needs them, since
+      // this requires a global input hook.
+      let mut swipe_rx = loop {
+        let widget_state = match open_rx.recv().await {
+          Ok(widget_state) => widget_state,
+          Err(broadcast::error::RecvError::Lagged(_)) => continue,
+          Err(broadcast::error::RecvError::Closed) => return,
+        };
+
+        if !is_reveal_enabled(&widget_state) {
+          continue;
+        }
+
+        match listen_edge_swipes() {
+          Ok(swipe_rx) => break swipe_rx,
+          Err(err) => {
+            warn!("Edge swipes are unavailable: {:?}", err);
+            return;
+          }
+        }
+      };
+
+      info!("Listening for edge swipes.");
+
+      while let Some((edge, position)) = swipe_rx.recv().await {
+        self.handle_swipe(edge, position).await;
+      }
+    });
+  }
+
+  /// Reveals hidden widgets that are nearest to the swiped edge of the
+  /// monitor.
+  async fn handle_swipe(
+    &self,
+    edge: DockEdge,
+    position: PhysicalPosition<i32>,
+  ) {
+    let states = self.widget_factory.states().await;
+
+    for state in states.values().filter(|state| is_reveal_enabled(state)) {
+      let Some(window) = self.app_handle.get_webview_window(&state.id)
+      else {
+        continue;
+      };
+
+      if window.is_visible().unwrap_or(true)
+        || nearest_monitor_edge(&window, position) != Some(edge)
+      {
+        continue;
+      }
+
+      let reveal_duration = state
+        .config
+        .touch
+        .as_ref()
+        .map(|touch| touch.reveal_duration)
+        .unwrap_or_default();
+
+      if let Err(err) = self
+        .widget_factory
+        .reveal(&state.id, Duration::from_millis(reveal_duration))
+        .await
+      {
+        warn!("Failed to reveal widget #{}: {:?}", state.id, err);
+      }
+    }
+  }
+}
+
+fn is_reveal_enabled(state: &WidgetState) -> bool {
+  state
+    .config
+    .touch
+    .as_ref()
+    .is_some_and(|touch| touch.edge_swipe_reveal)
+}
+
+/// Gets the edge of the window's monitor that the window is closest to.
+/// Returns `None` if the position isn't on the window's monitor.
+fn nearest_monitor_edge(
+  window: &WebviewWindow,
+  position: PhysicalPosition<i32>,
+) -> Option<DockEdge> {
+  let monitor = window.current_monitor().ok()??;
+  let window_position = window.outer_position().ok()?;
+  let window_size = window.outer_size().ok()?;
+
+  let (left, top) = (monitor.position().x, monitor.position().y);
+  let right = left + monitor.size().width as i32;
+  let bottom = top + monitor.size().height as i32;
+
+  let is_on_monitor = position.x >= left
+    && position.x < right
+    && position.y >= top
+    && position.y < bottom;
+
+  if !is_on_monitor {
+    return None;
+  }
+
+  [
+    (DockEdge::Top, window_position.y - top),
+    (
+      DockEdge::Bottom,
+      bottom - (window_position.y + window_size.height as i32),
+    ),
+    (DockEdge::Left, window_position.x - left),
+    (
+      DockEdge::Right,
+      right - (window_position.x + window_size.width as i32),
+    ),
+  ]
+  .into_iter()
+  .min_by_key(|(_, distance)| *distance)
+  .map(|(edge, _)| edge)
+}
+
+/// Starts listening for touch swipes in from monitor edges. Emits the
+/// swiped edge and where the swipe started (in physical pixels).
+fn listen_edge_swipes() -> anyhow::Result<
+  mpsc::UnboundedReceiver<(DockEdge, PhysicalPosition<i32>)>,
+> {
+  #[cfg(target_os = "windows")]
+  {
+    let (swipe_tx, swipe_rx) = mpsc::unbounded_channel();
+
+    crate::common::windows::listen_edge_swipes(move |edge, position| {
+      let _ = swipe_tx.send((edge, position));
+    })?;
+
+    Ok(swipe_rx)
+  }
+
+  #[cfg(not(target_os = "windows"))]
+  {
+    anyhow::bail!("Edge swipes are only supported on Windows.")
+  }
+}
@@ -0,0 +1,198 @@
+use anyhow::Context;
+use image::{Rgba, RgbaImage};
+use serde::Deserialize;
+use tauri::image::Image;
+
+/// Default badge color (red).
+const DEFAULT_COLOR: [u8; 3] = [0xe8, 0x11, 0x23];
+
+/// 3x5 bitmap glyphs for the characters `0-9` and `+`.
+const GLYPHS: [[u8; 5]; 11] = [
+  [0b111, 0b101, 0b101, 0b101, 0b111],
+  [0b010, 0b110, 0b010, 0b010, 0b111],
+  [0b111, 0b001, 0b111, 0b100, 0b111],
+  [0b111, 0b001, 0b111, 0b001, 0b111],
+  [0b101, 0b101, 0b111, 0b001, 0b001],
+  [0b111, 0b100, 0b111, 0b001, 0b111],
+  [0b111, 0b100, 0b111, 0b101, 0b111],
+  [0b111, 0b001, 0b001, 0b001, 0b001],
+  [0b111, 0b101, 0b111, 0b101, 0b111],
+  [0b111, 0b101, 0b111, 0b001, 0b111],
+  [0b000, 0b010, 0b111, 0b010, 0b000],
+];
+
+/// Badge overlay rendered onto the system tray icon or a widget's
+/// taskbar icon.
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum TrayBadge {
+  /// A plain dot in the bottom-right corner.
+  Dot {
+    /// Hex color of the dot (e.g. `#ff0000`). Defaults to red.
+    color: Option<String>,
+  },
+
+  /// A count in the bottom-right corner. Counts above 9 are shown as
+  /// `9+`.
+  Count {
+    count: u32,
+
+    /// Hex background color of the badge. Defaults to red.
+    color: Option<String>,
+  },
+}
+
+impl TrayBadge {
+  /// Returns a copy of the given icon with the badge drawn on top.
+  pub fn render(&self, icon: &Image) -> anyhow::Result<Image<'static>> {
+    let mut canvas = RgbaImage::from_raw(
+      icon.width(),
+      icon.height(),
+      icon.rgba().to_vec(),
+    )
+    .context("Invalid tray icon dimensions.")?;
+
+    self.draw(&mut canvas, 1.);
+
+    let (width, height) = canvas.dimensions();
+    Ok(Image::new_owned(canvas.into_raw(), width, height))
+  }
+
+  /// Renders the badge by itself on a transparent square (e.g. for a
+  /// taskbar overlay icon).
+  pub fn render_standalone(&self, size: u32) -> Image<'static> {
+    let mut canvas = RgbaImage::new(size, size);
+
+    // Scaled so that count badges fill the canvas.
+    self.draw(&mut canvas, 1. / 0.65);
+
+    Image::new_owned(canvas.into_raw(), size, size)
+  }
+
+  /// Draws the badge in the bottom-right corner of the canvas. Badge
+  /// sizes are relative to the canvas and multiplied by `scale`.
+  fn draw(&self, canvas: &mut RgbaImage, scale: f32) {
+    let max_size = canvas.width().min(canvas.height());
+    let size = max_size as f32 * scale;
+
+    match self {
+      TrayBadge::Dot { color } => {
+        let diameter = ((size * 0.45) as u32).min(max_size);
+        draw_circle(canvas, diameter, parse_color(color.as_deref()));
+      }
+      TrayBadge::Count { count, color } => {
+        let diameter = ((size * 0.65) as u32).min(max_size);
+        let (x, y) =
+          draw_circle(canvas, diameter, parse_color(color.as_deref()));
+
+        let text = match count {
+          0..=9 => count.to_string(),
+          _ => "9+".to_string(),
+        };
+
+        draw_text(canvas, &text, x, y, diameter);
+      }
+    }
+  }
+}
+
+/// Renders a number (e.g. a CPU percentage) as a standalone icon, with
+/// white digits on a colored circle. Numbers above 999 are capped.
+pub fn render_number_icon(
+  number: u32,
+  size: u32,
+  color: Option<&str>,
+) -> Image<'static> {
+  let mut canvas = RgbaImage::new(size, size);
+
+  let (x, y) = draw_circle(&mut canvas, size, parse_color(color));
+  draw_text(&mut canvas, &number.min(999).to_string(), x, y, size);
+
+  Image::new_owned(canvas.into_raw(), size, size)
+}
+
+/// Parses a hex color string (e.g. `#ff0000`), falling back to the
+/// default badge color if invalid.
+fn parse_color(color: Option<&str>) -> Rgba<u8> {
+  let [r, g, b] = color
+    .map(|color| color.trim_start_matches('#'))
+    .filter(|hex| hex.len() == 6)
+    .and_then(|hex| u32::from_str_radix(hex, 16).ok())
+    .map(|value| [(value >> 16) as u8, (value >> 8) as u8, value as u8])
+    .unwrap_or(DEFAULT_COLOR);
+
+  Rgba([r, g, b, 0xff])
+}
+
+/// Draws a filled circle in the bottom-right corner of the canvas.
+///
+/// Returns the top-left coordinates of the circle's bounding box.
+fn draw_circle(
+  canvas: &mut RgbaImage,
+  diameter: u32,
+  color: Rgba<u8>,
+) -> (u32, u32) {
+  let x = canvas.width() - diameter;
+  let y = canvas.height() - diameter;
+  let radius = diameter as f32 / 2.;
+
+  for dy in 0..diameter {
+    for dx in 0..diameter {
+      let dist_x = dx as f32 + 0.5 - radius;
+      let dist_y = dy as f32 + 0.5 - radius;
+
+      if dist_x * dist_x + dist_y * dist_y <= radius * radius {
+        canvas.put_pixel(x + dx, y + dy, color);
+      }
+    }
+  }
+
+  (x, y)
+}
+
+/// Draws white text centered within the given square area.
+fn draw_text(
+  canvas: &mut RgbaImage,
+  text: &str,
+  x: u32,
+  y: u32,
+  area_size: u32,
+) {
+  // Text width in glyph units, with a 1 unit gap between characters.
+  let units_width = text.len() as u32 * 4 - 1;
+  let scale =
+    ((area_size as f32 * 0.6) / units_width as f32).max(1.) as u32;
+
+  let text_width = units_width * scale;
+  let text_height = 5 * scale;
+  let origin_x = x + area_size.saturating_sub(text_width) / 2;
+  let origin_y = y + area_size.saturating_sub(text_height) / 2;
+
+  for (index, ch) in text.chars().enumerate() {
+    let glyph = match ch {
+      '+' => GLYPHS[10],
+      _ => GLYPHS[ch.to_digit(10).unwrap_or(0) as usize],
+    };
+
+    let char_x = origin_x + index as u32 * 4 * scale;
+
+    for (row, bits) in glyph.iter().enumerate() {
+      for col in 0..3 {
+        if bits & (0b100 >> col) == 0 {
+          continue;
+        }
+
+        for sy in 0..scale {
+          for sx in 0..scale {
+            let px = char_x + col * scale + sx;
+            let py = origin_y + row as u32 * scale + sy;
+
+            if px < canvas.width() && py < canvas.height() {
+              canvas.put_pixel(px, py, Rgba([0xff, 0xff, 0xff, 0xff]));
+            }
+          }
+        }
+      }
+    }
+  }
+}
@@ -0,0 +1,229 @@
+use std::{collections::HashMap, sync::Arc};
+
+use anyhow::{bail, Context};
+use tauri::AppHandle;
+use tokio::sync::Mutex;
+use tracing::{info, warn};
+
+use crate::{
+  common::Expression,
+  config::{Config, TrayBindingConfig},
+  providers::{parse_provider_configs, ProviderEmission, ProviderManager},
+  secrets::SecretAccess,
+  sys_tray::SysTray,
+  window_title::render_template,
+};
+
+/// Keeps the system tray tooltip and icon in sync with provider outputs,
+/// as set by the `trayBinding` setting.
+///
+/// Providers used by the binding are created under their own config
+/// hashes, so that they're independent of any open widgets.
+pub struct TrayBinding {
+  app_handle: AppHandle,
+
+  /// Reference to `Config`.
+  config: Arc<Config>,
+
+  /// Reference to `ProviderManager`.
+  manager: Arc<ProviderManager>,
+
+  /// Reference to `SysTray`.
+  tray: Arc<SysTray>,
+
+  /// Config that's currently bound.
+  binding_config: Mutex<Option<TrayBindingConfig>>,
+
+  /// Active binding state, if a binding is set.
+  state: Mutex<Option<BindingState>>,
+}
+
+struct BindingState {
+  tooltip: Option<String>,
+  icon_value: Option<Expression>,
+
+  /// Names of the providers, keyed by config hash.
+  provider_names: HashMap<String, String>,
+
+  /// Latest output of each provider, keyed by name.
+  outputs: HashMap<String, serde_json::Value>,
+
+  /// Tooltip that was last set on the tray icon.
+  rendered_tooltip: Option<String>,
+
+  /// Number that was last shown in place of the tray icon.
+  rendered_icon_value: Option<u32>,
+}
+
+impl TrayBinding {
+  pub fn new(
+    app_handle: &AppHandle,
+    config: Arc<Config>,
+    manager: Arc<ProviderManager>,
+    tray: Arc<SysTray>,
+  ) -> Arc<Self> {
+    Arc::new(Self {
+      app_handle: app_handle.clone(),
+      config,
+      manager,
+      tray,
+      binding_config: Mutex::new(None),
+      state: Mutex::new(None),
+    })
+  }
+
+  /// Restarts the binding if it changed in the settings.
+  pub async fn reload(&self) -> anyhow::Result<()> {
+    let new_config =
+      self.config.settings.lock().await.tray_binding.clone();
+
+    let mut binding_config = self.binding_config.lock().await;
+
+    if *binding_config == new_config {
+      return Ok(());
+    }
+
+    self.stop().await;
+    *binding_config = new_config.clone();
+
+    if let Some(new_config) = new_config {
+      self.start(new_config).await?;
+    }
+
+    Ok(())
+  }
+
+  async fn start(
+    &self,
+    binding_config: TrayBindingConfig,
+  ) -> anyhow::Result<()> {
+    let icon_value = binding_config
+      .icon_value
+      .as_deref()
+      .map(Expression::parse)
+      .transpose()
+      .context("Invalid expression for tray icon value.")?;
+
+    for source_name in icon_value.iter().flat_map(Expression::sources) {
+      if !binding_config.providers.contains_key(source_name) {
+        bail!(
+          "Unknown provider '{}' in expression for tray icon value.",
+          source_name
+        );
+      }
+    }
+
+    let provider_configs = parse_provider_configs(
+      &self.app_handle,
+      binding_config.providers.clone(),
+      &self.config.settings_path(),
+      SecretAccess::Any,
+    )
+    .await
+    .context("Invalid tray binding providers.")?;
+
+    let provider_configs = provider_configs
+      .into_iter()
+      .map(|(name, config)| {
+        (format!("tray-binding:{}", name), name, config)
+      })
+      .collect::<Vec<_>>();
+
+    *self.state.lock().await = Some(BindingState {
+      tooltip: binding_config.tooltip,
+      icon_value,
+      provider_names: provider_configs
+        .iter()
+        .map(|(config_hash, name, _)| (config_hash.clone(), name.clone()))
+        .collect(),
+      outputs: HashMap::new(),
+      rendered_tooltip: None,
+      rendered_icon_value: None,
+    });
+
+    for (config_hash, name, config) in provider_configs {
+      let subscriber = format!("tray-binding:{}", name);
+      self
+        .manager
+        .create(config_hash, config, &subscriber)
+        .await?;
+    }
+
+    info!("Bound system tray to provider outputs.");
+    Ok(())
+  }
+
+  /// Stops the providers used for the binding, and resets the tray icon
+  /// to its defaults.
+  async fn stop(&self) {
+    let Some(state) = self.state.lock().await.take() else {
+      return;
+    };
+
+    for (config_hash, name) in state.provider_names {
+      let subscriber = format!("tray-binding:{}", name);
+
+      if let Err(err) = self.manager.stop(config_hash, &subscriber).await {
+        warn!("Failed to stop tray binding provider: {:?}", err);
+      }
+    }
+
+    if let Err(err) = self.tray.set_tooltip(None) {
+      warn!("Failed to reset tray tooltip: {:?}", err);
+    }
+
+    if let Err(err) = self.tray.set_icon_value(None).await {
+      warn!("Failed to reset tray icon: {:?}", err);
+    }
+  }
+
+  /// Re-renders the tooltip and icon if the emitting provider is used
+  /// for the binding.
+  pub async fn handle_emission(&self, emission: &ProviderEmission) {
+    let Ok(output) = &emission.result else {
+      return;
+    };
+
+    let mut state = self.state.lock().await;
+
+    let Some(state) = state.as_mut() else {
+      return;
+    };
+
+    let Some(name) = state.provider_names.get(&emission.config_hash)
+    else {
+      return;
+    };
+
+    let output = serde_json::to_value(output).unwrap_or_default();
+    state.outputs.insert(name.clone(), output);
+
+    let tooltip = state
+      .tooltip
+      .as_ref()
+      .map(|tooltip| render_template(tooltip, &state.outputs));
+
+    if tooltip != state.rendered_tooltip {
+      if let Err(err) = self.tray.set_tooltip(tooltip.clone()) {
+        warn!("Failed to set tray tooltip: {:?}", err);
+      }
+
+      state.rendered_tooltip = tooltip;
+    }
+
+    // Non-numeric values (e.g. before a provider first emits) fall back
+    // to the regular icon.
+    let icon_value = state.icon_value.as_ref().and_then(|expression| {
+      let value = expression.evaluate(&state.outputs).ok()?.as_f64()?;
+      Some(value.round().clamp(0., 999.) as u32)
+    });
+
+    if icon_value != state.rendered_icon_value {
+      if let Err(err) = self.tray.set_icon_value(icon_value).await {
+        warn!("Failed to set tray icon: {:?}", err);
+      }
+
+      state.rendered_icon_value = icon_value;
+    }
+  }
+}
@@ -0,0 +1,201 @@
+use std::{collections::HashMap, fmt, sync::Arc, time::Instant};
+
+use serde::{Deserialize, Serialize};
+use tokio::sync::Mutex;
+
+use crate::{
+  config::Config,
+  heartbeat::WidgetHeartbeats,
+  providers::{ProviderEmission, ProviderManager},
+  widget_factory::WidgetFactory,
+};
+
+/// Local summary of Zebar's resource usage, shown via `zebar stats` and
+/// the system tray.
+///
+/// Stats are only kept in memory and are never sent anywhere.
+pub struct UsageStats {
+  /// When Zebar was started.
+  started_at: Instant,
+
+  /// Provider type and number of emissions of each provider, keyed by
+  /// config hash.
+  emit_counts: Mutex<HashMap<String, (String, u64)>>,
+
+  /// Reference to `Config`.
+  config: Arc<Config>,
+
+  /// Reference to `ProviderManager`.
+  manager: Arc<ProviderManager>,
+
+  /// Reference to `WidgetFactory`.
+  widget_factory: Arc<WidgetFactory>,
+
+  /// Reference to `WidgetHeartbeats`.
+  heartbeats: Arc<WidgetHeartbeats>,
+}
+
+/// Snapshot of `UsageStats`.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UsageReport {
+  /// Time in milliseconds since Zebar was started.
+  pub uptime: u64,
+
+  /// Providers sorted by their number of emissions (descending).
+  pub providers: Vec<ProviderUsage>,
+
+  /// Currently open widgets.
+  pub widgets: Vec<WidgetUsage>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProviderUsage {
+  pub config_hash: String,
+  pub provider_type: String,
+  pub emit_count: u64,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WidgetUsage {
+  pub id: String,
+
+  /// Config path relative to the config directory.
+  pub config_path: String,
+
+  /// JS heap size in bytes. Only available in Chromium-based webviews
+  /// (i.e. WebView2).
+  pub memory_usage: Option<u64>,
+}
+
+impl UsageStats {
+  pub fn new(
+    config: Arc<Config>,
+    manager: Arc<ProviderManager>,
+    widget_factory: Arc<WidgetFactory>,
+    heartbeats: Arc<WidgetHeartbeats>,
+  ) -> Arc<Self> {
+    Arc::new(Self {
+      started_at: Instant::now(),
+      emit_counts: Mutex::new(HashMap::new()),
+      config,
+      manager,
+      widget_factory,
+      heartbeats,
+    })
+  }
+
+  /// Counts an emission towards its provider.
+  pub async fn handle_emission(&self, emission: &ProviderEmission) {
+    let mut emit_counts = self.emit_counts.lock().await;
+
+    match emit_counts.get_mut(&emission.config_hash) {
+      Some((_, count)) => *count += 1,
+      None => {
+        let provider_type = self
+          .manager
+          .provider_type(&emission.config_hash)
+          .await
+          .unwrap_or("unknown");
+
+        emit_counts.insert(
+          emission.config_hash.clone(),
+          (provider_type.to_string(), 1),
+        );
+      }
+    }
+  }
+
+  /// Gets a snapshot of the current stats.
+  pub async fn report(&self) -> UsageReport {
+    let mut providers = self
+      .emit_counts
+      .lock()
+      .await
+      .iter()
+      .map(|(config_hash, (provider_type, emit_count))| ProviderUsage {
+        config_hash: config_hash.clone(),
+        provider_type: provider_type.clone(),
+        emit_count: *emit_count,
+      })
+      .collect::<Vec<_>>();
+
+    providers.sort_by(|a, b| b.emit_count.cmp(&a.emit_count));
+
+    let memory_usages = self.heartbeats.memory_usages().await;
+
+    let mut widgets = self
+      .widget_factory
+      .states()
+      .await
+      .into_values()
+      .map(|state| WidgetUsage {
+        memory_usage: memory_usages.get(&state.id).copied(),
+        config_path: self.config.formatted_widget_path(&state.config_path),
+        id: state.id,
+      })
+      .collect::<Vec<_>>();
+
+    widgets.sort_by(|a, b| a.config_path.cmp(&b.config_path));
+
+    UsageReport {
+      uptime: self.started_at.elapsed().as_millis() as u64,
+      providers,
+      widgets,
+    }
+  }
+}
+
+impl fmt::Display for UsageReport {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    let uptime_secs = self.uptime / 1000;
+
+    writeln!(
+      f,
+      "Uptime: {}h {}m {}s",
+      uptime_secs / 3600,
+      uptime_secs % 3600 / 60,
+      uptime_secs % 60
+    )?;
+
+    writeln!(f, "\nProvider emissions:")?;
+
+    if self.providers.is_empty() {
+      writeln!(f, "  None")?;
+    }
+
+    for provider in &self.providers {
+      writeln!(
+        f,
+        "  {} ({}): {}",
+        provider.provider_type,
+        // Config hashes are long, so only the start is shown.
+        provider.config_hash.chars().take(8).collect::<String>(),
+        provider.emit_count
+      )?;
+    }
+
+    writeln!(f, "\nWidget memory (JS heap):")?;
+
+    if self.widgets.is_empty() {
+      writeln!(f, "  None")?;
+    }
+
+    for widget in &self.widgets {
+      let memory_usage = match widget.memory_usage {
+        Some(bytes) => format!("{:.1} MB", bytes as f64 / 1_000_000.),
+        None => "Unavailable".to_string(),
+      };
+
+      writeln!(
+        f,
+        "  {} (#{}): {}",
+        widget.config_path, widget.id, memory_usage
+      )?;
+    }
+
+    Ok(())
+  }
+}
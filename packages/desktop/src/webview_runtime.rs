@@ -0,0 +1,131 @@
+use tauri::AppHandle;
+use tauri_plugin_dialog::{
+  DialogExt, MessageDialogButtons, MessageDialogKind,
+};
+use tokio::task;
+use tracing::error;
+
+use crate::i18n::t;
+
+/// Download URL of the Evergreen WebView2 bootstrapper, which installs
+/// the latest WebView2 runtime.
+#[cfg(target_os = "windows")]
+const WEBVIEW2_BOOTSTRAPPER_URL: &str =
+  "https://go.microsoft.com/fwlink/p/?LinkId=2124703";
+
+/// Checks that a webview runtime is available for rendering widgets.
+///
+/// Returns the runtime version if it can be determined. WebKit is part
+/// of the OS on MacOS, and WebKitGTK is dynamically linked on Linux, so
+/// only WebView2 on Windows can be missing at this point.
+pub fn check_webview_runtime() -> anyhow::Result<Option<String>> {
+  #[cfg(target_os = "windows")]
+  {
+    use anyhow::Context;
+    use webview2_com::{
+      take_pwstr,
+      Microsoft::Web::WebView2::Win32::GetAvailableCoreWebView2BrowserVersionString,
+    };
+    use windows::core::{PCWSTR, PWSTR};
+
+    let mut version = PWSTR::null();
+
+    unsafe {
+      GetAvailableCoreWebView2BrowserVersionString(
+        PCWSTR::null(),
+        &mut version,
+      )
+    }
+    .context("WebView2 runtime is not installed or is broken.")?;
+
+    if version.is_null() {
+      anyhow::bail!("WebView2 runtime is not installed.");
+    }
+
+    Ok(Some(take_pwstr(version)))
+  }
+
+  #[cfg(not(target_os = "windows"))]
+  Ok(None)
+}
+
+/// Shows a native dialog explaining that the webview runtime is missing,
+/// with an option to install it.
+///
+/// Zebar keeps running with only its tray icon, since widgets can't be
+/// shown until the runtime is installed.
+pub fn show_missing_runtime_dialog(app_handle: &AppHandle) {
+  let app_handle = app_handle.clone();
+
+  app_handle
+    .dialog()
+    .message(t("webviewRuntime.missing"))
+    .title("Zebar")
+    .kind(MessageDialogKind::Error)
+    .buttons(MessageDialogButtons::OkCancelCustom(
+      t("webviewRuntime.install"),
+      t("webviewRuntime.dismiss"),
+    ))
+    .show({
+      let app_handle = app_handle.clone();
+
+      move |is_confirmed| {
+        if !is_confirmed {
+          return;
+        }
+
+        task::spawn(async move {
+          match install_webview_runtime().await {
+            // Restart so that widgets are opened with the new runtime.
+            Ok(()) => app_handle.restart(),
+            Err(err) => {
+              error!("Failed to install webview runtime: {:?}", err)
+            }
+          }
+        });
+      }
+    });
+}
+
+/// Downloads and runs the WebView2 bootstrapper, waiting for the install
+/// to finish.
+#[cfg(target_os = "windows")]
+async fn install_webview_runtime() -> anyhow::Result<()> {
+  use anyhow::{bail, Context};
+  use tracing::info;
+
+  info!("Downloading WebView2 bootstrapper.");
+
+  let bootstrapper = reqwest::get(WEBVIEW2_BOOTSTRAPPER_URL)
+    .await?
+    .error_for_status()?
+    .bytes()
+    .await?;
+
+  let bootstrapper_path =
+    std::env::temp_dir().join("MicrosoftEdgeWebview2Setup.exe");
+
+  tokio::fs::write(&bootstrapper_path, bootstrapper)
+    .await
+    .context("Failed to save WebView2 bootstrapper.")?;
+
+  info!("Running WebView2 bootstrapper.");
+
+  let status = tokio::process::Command::new(&bootstrapper_path)
+    .status()
+    .await
+    .context("Failed to run WebView2 bootstrapper.")?;
+
+  let _ = tokio::fs::remove_file(&bootstrapper_path).await;
+
+  if !status.success() {
+    bail!("WebView2 bootstrapper exited with {}.", status);
+  }
+
+  Ok(())
+}
+
+#[cfg(not(target_os = "windows"))]
+async fn install_webview_runtime() -> anyhow::Result<()> {
+  anyhow::bail!("Webview runtime is provided by the OS.")
+}
@@ -0,0 +1,176 @@
+use std::path::Path;
+
+use anyhow::Context;
+use tauri::WebviewWindow;
+use tokio::sync::mpsc;
+
+/// Renders the contents of a widget's webview to a PNG.
+///
+/// Unlike a screen capture, this keeps the transparent background of
+/// the widget intact and isn't affected by windows covering it.
+pub async fn capture_widget(
+  window: &WebviewWindow,
+) -> anyhow::Result<Vec<u8>> {
+  let (capture_tx, mut capture_rx) = mpsc::unbounded_channel();
+
+  // The webview can only be accessed from the main thread, and the
+  // capture completes asynchronously on it.
+  window.with_webview(move |webview| {
+    if let Err(err) = start_capture(webview, capture_tx.clone()) {
+      let _ = capture_tx.send(Err(err));
+    }
+  })?;
+
+  capture_rx
+    .recv()
+    .await
+    .context("Webview was closed before the capture completed.")?
+}
+
+/// Renders a widget's webview to a PNG file at the given path.
+pub async fn capture_widget_to_file(
+  window: &WebviewWindow,
+  path: &Path,
+) -> anyhow::Result<()> {
+  let png = capture_widget(window).await?;
+
+  tokio::fs::write(path, png).await.with_context(|| {
+    format!("Failed to write capture to {}.", path.display())
+  })
+}
+
+#[cfg(target_os = "windows")]
+fn start_capture(
+  webview: tauri::webview::PlatformWebview,
+  capture_tx: mpsc::UnboundedSender<anyhow::Result<Vec<u8>>>,
+) -> anyhow::Result<()> {
+  use webview2_com::{
+    CapturePreviewCompletedHandler,
+    Microsoft::Web::WebView2::Win32::COREWEBVIEW2_CAPTURE_PREVIEW_IMAGE_FORMAT_PNG,
+  };
+  use windows::Win32::{
+    Foundation::HGLOBAL,
+    System::Com::{
+      IStream, StructuredStorage::CreateStreamOnHGlobal, STATFLAG_NONAME,
+      STATSTG, STREAM_SEEK_SET,
+    },
+  };
+
+  fn read_stream(stream: &IStream) -> anyhow::Result<Vec<u8>> {
+    let mut stat = STATSTG::default();
+    unsafe { stream.Stat(&mut stat, STATFLAG_NONAME) }?;
+    unsafe { stream.Seek(0, STREAM_SEEK_SET, None) }?;
+
+    let mut png = vec![0u8; stat.cbSize as usize];
+    let mut read = 0;
+
+    unsafe {
+      stream.Read(png.as_mut_ptr() as _, png.len() as u32, Some(&mut read))
+    }
+    .ok()?;
+
+    png.truncate(read as usize);
+    Ok(png)
+  }
+
+  let core_webview = unsafe { webview.controller().CoreWebView2() }?;
+  let stream = unsafe { CreateStreamOnHGlobal(HGLOBAL::default(), true) }?;
+
+  let handler_stream = stream.clone();
+  let handler = CapturePreviewCompletedHandler::create(Box::new(
+    move |res: windows::core::Result<()>| {
+      let png = res
+        .context("WebView2 failed to capture the widget.")
+        .and_then(|_| read_stream(&handler_stream));
+
+      let _ = capture_tx.send(png);
+      Ok(())
+    },
+  ));
+
+  unsafe {
+    core_webview.CapturePreview(
+      COREWEBVIEW2_CAPTURE_PREVIEW_IMAGE_FORMAT_PNG,
+      &stream,
+      &handler,
+    )
+  }?;
+
+  Ok(())
+}
+
+#[cfg(target_os = "linux")]
+fn start_capture(
+  webview: tauri::webview::PlatformWebview,
+  capture_tx: mpsc::UnboundedSender<anyhow::Result<Vec<u8>>>,
+) -> anyhow::Result<()> {
+  use gtk::cairo::ImageSurface;
+  use webkit2gtk::{SnapshotOptions, SnapshotRegion, WebViewExt};
+
+  /// Converts a cairo surface (premultiplied BGRA) to a PNG.
+  fn surface_to_png(
+    surface: gtk::cairo::Surface,
+  ) -> anyhow::Result<Vec<u8>> {
+    let mut surface = ImageSurface::try_from(surface)
+      .map_err(|_| anyhow::anyhow!("Snapshot is not an image surface."))?;
+
+    let width = surface.width() as u32;
+    let height = surface.height() as u32;
+    let stride = surface.stride() as usize;
+    let data = surface.data()?;
+
+    let mut pixels = Vec::with_capacity((width * height * 4) as usize);
+
+    for row in data.chunks_exact(stride).take(height as usize) {
+      for pixel in row[..width as usize * 4].chunks_exact(4) {
+        let alpha = pixel[3];
+
+        let unpremultiply = |channel: u8| match alpha {
+          0 => 0,
+          _ => (channel as u32 * 255 / alpha as u32) as u8,
+        };
+
+        pixels.extend([
+          unpremultiply(pixel[2]),
+          unpremultiply(pixel[1]),
+          unpremultiply(pixel[0]),
+          alpha,
+        ]);
+      }
+    }
+
+    let image = image::RgbaImage::from_raw(width, height, pixels)
+      .context("Invalid snapshot dimensions.")?;
+
+    let mut png = Vec::new();
+    image.write_to(
+      &mut std::io::Cursor::new(&mut png),
+      image::ImageFormat::Png,
+    )?;
+
+    Ok(png)
+  }
+
+  webview.inner().snapshot(
+    SnapshotRegion::Visible,
+    SnapshotOptions::TRANSPARENT_BACKGROUND,
+    None::<&gtk::gio::Cancellable>,
+    move |res| {
+      let png = res
+        .context("WebKitGTK failed to capture the widget.")
+        .and_then(surface_to_png);
+
+      let _ = capture_tx.send(png);
+    },
+  );
+
+  Ok(())
+}
+
+#[cfg(target_os = "macos")]
+fn start_capture(
+  _webview: tauri::webview::PlatformWebview,
+  _capture_tx: mpsc::UnboundedSender<anyhow::Result<Vec<u8>>>,
+) -> anyhow::Result<()> {
+  anyhow::bail!("Capturing widgets is not yet supported on MacOS.")
+}
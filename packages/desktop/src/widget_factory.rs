@@ -1,37 +1,55 @@
 use std::{
   collections::HashMap,
-  path::PathBuf,
+  path::{Path, PathBuf},
   sync::{
     atomic::{AtomicU32, Ordering},
     Arc,
   },
+  time::{Duration, Instant},
 };
 
 use anyhow::{bail, Context};
 use base64::prelude::*;
 use serde::Serialize;
 use tauri::{
-  path::BaseDirectory, AppHandle, Manager, PhysicalPosition, PhysicalSize,
-  WebviewUrl, WebviewWindowBuilder, WindowEvent,
+  path::BaseDirectory, AppHandle, Emitter, Manager, PhysicalPosition,
+  PhysicalSize, WebviewUrl, WebviewWindow, WebviewWindowBuilder,
+  WindowEvent,
 };
 use tokio::{
   sync::{broadcast, Mutex},
-  task,
+  task, time,
 };
-use tracing::{error, info};
+use tracing::{error, info, warn};
 
+#[cfg(target_os = "linux")]
+use crate::common::linux::set_x11_window_type;
+#[cfg(all(target_os = "linux", feature = "layer-shell"))]
+use crate::common::linux::{
+  init_layer_surface, is_layer_shell_supported, LayerSurfaceOptions,
+};
 #[cfg(target_os = "macos")]
 use crate::common::macos::WindowExtMacOs;
 #[cfg(target_os = "windows")]
 use crate::common::windows::{remove_app_bar, WindowExtWindows};
 use crate::{
+  accessibility::{AccessibilityPrefs, AccessibilityState},
   asset_server::create_init_url,
-  common::PathExt,
+  capabilities::API_VERSION,
+  common::{interpolate_env_vars, PathExt},
   config::{
-    AnchorPoint, Config, DockConfig, DockEdge, WidgetConfig,
-    WidgetPlacement,
+    provider_requires_opt_in, AnchorPoint, CommandPermission, Config,
+    DockConfig, DockEdge, SegmentRegion, WidgetConfig, WidgetPlacement,
+    WidgetSegment,
   },
+  config_encryption::decrypt_string,
+  heartbeat::HEARTBEAT_INTERVAL_MS,
   monitor_state::{Monitor, MonitorState},
+  portable,
+  providers::ProviderManager,
+  secrets::SecretAccess,
+  sys_tray::SETTINGS_WINDOW_LABEL,
+  widget_capture::capture_widget_to_file,
 };
 
 /// Manages the creation of Zebar widgets.
@@ -39,9 +57,9 @@ pub struct WidgetFactory {
   /// Handle to the Tauri application.
   app_handle: AppHandle,
 
-  _close_rx: broadcast::Receiver<String>,
+  _close_rx: broadcast::Receiver<WidgetState>,
 
-  pub close_tx: broadcast::Sender<String>,
+  pub close_tx: broadcast::Sender<WidgetState>,
 
   /// Reference to `Config`.
   config: Arc<Config>,
@@ -50,11 +68,21 @@ pub struct WidgetFactory {
 
   pub open_tx: broadcast::Sender<WidgetState>,
 
+  _focus_rx: broadcast::Receiver<String>,
+
+  /// Sender for the ID's of widgets whose window gained focus.
+  pub focus_tx: broadcast::Sender<String>,
+
   /// Reference to `MonitorState`.
   ///
   /// Used for widget positioning.
   monitor_state: Arc<MonitorState>,
 
+  /// Reference to `AccessibilityState`.
+  ///
+  /// Used for passing the initial preferences to widgets.
+  accessibility_state: Arc<AccessibilityState>,
+
   /// Running total of widgets created.
   ///
   /// Used to generate unique widget ID's which are used as Tauri window
@@ -63,6 +91,15 @@ pub struct WidgetFactory {
 
   /// Map of widget ID's to their states.
   widget_states: Arc<Mutex<HashMap<String, WidgetState>>>,
+
+  /// Startup presets whose windows aren't created until they're first
+  /// shown (via `launch_options.defer_until_shown`). Stored as absolute
+  /// config path and preset name.
+  deferred_presets: Mutex<Vec<(PathBuf, String)>>,
+
+  /// Widgets that are temporarily shown via `reveal`, and when to hide
+  /// them again.
+  revealed_widgets: Arc<Mutex<HashMap<String, Instant>>>,
 }
 
 #[derive(Serialize, Clone, Debug)]
@@ -89,6 +126,22 @@ pub struct WidgetState {
 
   /// How the widget was opened.
   pub open_options: WidgetOpenOptions,
+
+  /// Absolute path to the config of the widget that this widget was
+  /// opened as a companion of (via `opens_with`).
+  pub opened_by: Option<PathBuf>,
+
+  /// Name of the segment that the window renders. `None` if the config
+  /// has no segments, or if they're all rendered in the same window.
+  pub segment: Option<String>,
+
+  /// Variables from the widget config's `env`, with references to OS
+  /// environment variables resolved.
+  ///
+  /// Not serialized, since widget states are visible to other widgets.
+  /// Only the widget itself can retrieve them via `widget_env`.
+  #[serde(skip)]
+  pub env: HashMap<String, String>,
 }
 
 #[derive(Clone, Debug, PartialEq, Serialize)]
@@ -98,6 +151,35 @@ pub enum WidgetOpenOptions {
   Preset(String),
 }
 
+/// Session storage key for the state of a widget that was reloaded in
+/// place.
+const RELOAD_STATE_KEY: &str = "ZEBAR_RELOAD_STATE";
+
+/// Name of the Tauri event that window events are emitted as to the
+/// widget's own window.
+const WIDGET_WINDOW_EVENT: &str = "widget-window-event";
+
+/// Window lifecycle event forwarded to the widget that owns the window.
+#[derive(Clone, Debug, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum WidgetWindowEvent {
+  Focused,
+  Blurred,
+  Moved { x: i32, y: i32 },
+  Resized { width: u32, height: u32 },
+  MonitorChanged { monitor: Option<String> },
+  VisibilityChanged { visible: bool },
+}
+
+/// Emits a window event to the given widget window only.
+fn emit_window_event(window: &WebviewWindow, event: WidgetWindowEvent) {
+  if let Err(err) =
+    window.emit_to(window.label(), WIDGET_WINDOW_EVENT, event)
+  {
+    error!("Failed to emit window event: {:?}", err);
+  }
+}
+
 struct WidgetCoordinates {
   size: PhysicalSize<i32>,
   position: PhysicalPosition<i32>,
@@ -155,9 +237,11 @@ impl WidgetFactory {
     app_handle: &AppHandle,
     config: Arc<Config>,
     monitor_state: Arc<MonitorState>,
+    accessibility_state: Arc<AccessibilityState>,
   ) -> Self {
     let (open_tx, _open_rx) = broadcast::channel(16);
     let (close_tx, _close_rx) = broadcast::channel(16);
+    let (focus_tx, _focus_rx) = broadcast::channel(16);
 
     Self {
       app_handle: app_handle.clone(),
@@ -166,13 +250,19 @@ impl WidgetFactory {
       config,
       _open_rx,
       open_tx,
+      _focus_rx,
+      focus_tx,
       monitor_state,
+      accessibility_state,
       widget_count: Arc::new(AtomicU32::new(0)),
       widget_states: Arc::new(Mutex::new(HashMap::new())),
+      deferred_presets: Mutex::new(Vec::new()),
+      revealed_widgets: Arc::new(Mutex::new(HashMap::new())),
     }
   }
 
-  /// Opens widget from a given config path.
+  /// Opens widget from a given config path, along with any companion
+  /// widgets declared via `opens_with`.
   ///
   /// Config path must be absolute.
   pub async fn start_widget(
@@ -180,6 +270,94 @@ impl WidgetFactory {
     config_path: &PathBuf,
     open_options: &WidgetOpenOptions,
   ) -> anyhow::Result<()> {
+    let (config_path, widget_config) =
+      self.open_windows(config_path, open_options, None).await?;
+
+    if let WidgetOpenOptions::Preset(name) = open_options {
+      self.take_deferred(&config_path, name).await;
+    }
+
+    let parent_dir =
+      config_path.parent().context("No parent directory.")?;
+
+    // Failing to open a companion shouldn't affect the primary widget.
+    for companion_path in &widget_config.opens_with {
+      let res = match parent_dir.join(companion_path).to_absolute() {
+        Ok(companion_path) => {
+          self
+            .start_companion(&companion_path, open_options, &config_path)
+            .await
+        }
+        Err(err) => Err(err),
+      };
+
+      if let Err(err) = res {
+        warn!(
+          "Failed to open companion widget '{}': {:?}",
+          companion_path.display(),
+          err
+        );
+      }
+    }
+
+    Ok(())
+  }
+
+  /// Opens a companion widget of the widget at `opened_by`.
+  ///
+  /// Uses the preset with the same name as the primary widget's preset
+  /// if one exists, otherwise the companion's first preset.
+  async fn start_companion(
+    &self,
+    config_path: &PathBuf,
+    primary_open_options: &WidgetOpenOptions,
+    opened_by: &PathBuf,
+  ) -> anyhow::Result<()> {
+    let (config_path, widget_config) = self
+      .config
+      .widget_config_by_path(config_path)
+      .await
+      .with_context(|| {
+        format!("No config found at path '{}'.", config_path.display())
+      })?;
+
+    let preset_name = match primary_open_options {
+      WidgetOpenOptions::Preset(name)
+        if widget_config
+          .presets
+          .iter()
+          .any(|preset| preset.name == *name) =>
+      {
+        name.clone()
+      }
+      _ => widget_config
+        .presets
+        .first()
+        .context("Companion widget has no presets.")?
+        .name
+        .clone(),
+    };
+
+    self
+      .open_windows(
+        &config_path,
+        &WidgetOpenOptions::Preset(preset_name),
+        Some(opened_by),
+      )
+      .await?;
+
+    Ok(())
+  }
+
+  /// Creates the widget windows for a given config path.
+  ///
+  /// Returns the absolute config path and the widget config.
+  async fn open_windows(
+    &self,
+    config_path: &PathBuf,
+    open_options: &WidgetOpenOptions,
+    opened_by: Option<&PathBuf>,
+  ) -> anyhow::Result<(PathBuf, WidgetConfig)> {
     let (config_path, widget_config) = self
       .config
       .widget_config_by_path(config_path)
@@ -204,7 +382,7 @@ impl WidgetFactory {
       };
 
       if is_preset_open {
-        return Ok(());
+        return Ok((config_path, widget_config));
       }
     }
 
@@ -227,7 +405,9 @@ impl WidgetFactory {
       }
     };
 
-    for coordinates in self.widget_coordinates(placement).await {
+    for (segment, coordinates) in
+      self.window_coordinates(&widget_config, placement).await
+    {
       let new_count =
         self.widget_count.fetch_add(1, Ordering::Relaxed) + 1;
 
@@ -243,19 +423,10 @@ impl WidgetFactory {
       let parent_dir =
         config_path.parent().context("No parent directory.")?;
 
-      let html_path = parent_dir.join(&widget_config.html_path);
-
-      if !html_path.exists() {
-        bail!(
-          "HTML file not found at '{}' for config '{}'.",
-          widget_config.html_path.display(),
-          config_path.display()
-        )
-      }
+      let (html_path, init_url) =
+        Self::widget_url(&config_path, &widget_config, placement).await?;
 
-      let webview_url = WebviewUrl::External(
-        create_init_url(&parent_dir, &html_path).await?,
-      );
+      let webview_url = WebviewUrl::External(init_url.clone());
 
       let mut state = WidgetState {
         id: widget_id.clone(),
@@ -264,6 +435,9 @@ impl WidgetFactory {
         config_path: config_path.clone(),
         html_path: html_path.clone(),
         open_options: open_options.clone(),
+        opened_by: opened_by.cloned(),
+        segment: segment.clone(),
+        env: resolve_env(&widget_config)?,
       };
 
       // Widgets from the same top-level directory share their browser
@@ -271,15 +445,17 @@ impl WidgetFactory {
       let cache_id =
         BASE64_STANDARD.encode(parent_dir.to_unicode_string());
 
+      let init_script = self.initialization_script(
+        &state,
+        self.accessibility_state.prefs().await,
+      )?;
+
       let window = WebviewWindowBuilder::new(
         &self.app_handle,
         widget_id.clone(),
         webview_url,
       )
-      .title(format!(
-        "Zebar - {}",
-        self.config.formatted_widget_path(&config_path)
-      ))
+      .title(self.window_title(&config_path, &widget_config))
       .focused(widget_config.focused)
       .skip_taskbar(!widget_config.shown_in_taskbar)
       .visible_on_all_workspaces(true)
@@ -287,41 +463,57 @@ impl WidgetFactory {
       .shadow(false)
       .decorations(false)
       .resizable(widget_config.resizable)
-      .initialization_script(&self.initialization_script(&state)?)
+      .initialization_script(&init_script)
+      .on_navigation({
+        // Prevent widgets with a security policy from navigating away
+        // from the asset server (e.g. to a remote page, which would
+        // otherwise get access to IPC).
+        let restrict = widget_config.security.is_some();
+        move |url| !restrict || url.origin() == init_url.origin()
+      })
       .data_directory(
         // TODO: Add this as an ext method on the Tauri window.
-        self
-          .app_handle
-          .path()
-          .resolve(
-            format!(".glzr/zebar/tmp-{}", cache_id),
-            BaseDirectory::Home,
-          )
-          .context("Unable to get home directory.")
-          .unwrap(),
+        match portable::data_dir() {
+          Some(dir) => dir.join(format!("tmp-{}", cache_id)),
+          None => self
+            .app_handle
+            .path()
+            .resolve(
+              format!(".glzr/zebar/tmp-{}", cache_id),
+              BaseDirectory::Home,
+            )
+            .context("Unable to get home directory.")?,
+        },
       )
       .build()?;
 
-      // Widget coordinates might be modified when docked to an edge.
-      let (size, position) = match placement.dock_to_edge.enabled {
-        false => (coordinates.size, coordinates.position),
-        true => self.dock_to_edge(
-          &window,
-          &placement.dock_to_edge,
-          &coordinates,
-        )?,
-      };
-
-      info!("Positioning widget to {:?} {:?}", size, position);
-      let _ = window.set_size(size);
-      let _ = window.set_position(position);
+      // On X11, let the window manager know what kind of window this is
+      // before positioning it.
+      #[cfg(target_os = "linux")]
+      if let Some(window_type) = widget_config.x11_window_type {
+        set_x11_window_type(&window, window_type, widget_config.layer())?;
+      }
 
-      // On Windows, we need to set the position twice to account for
-      // different monitor scale factors.
-      #[cfg(target_os = "windows")]
-      {
-        let _ = window.set_size(size);
-        let _ = window.set_position(position);
+      let dock_config = self.position_window(
+        &window,
+        placement,
+        segment.as_ref(),
+        &coordinates,
+      )?;
+
+      // On Wayland compositors that support it (e.g. Sway and Hyprland),
+      // show the widget as a layer surface so that it isn't managed like
+      // a normal window.
+      #[cfg(all(target_os = "linux", feature = "layer-shell"))]
+      if is_layer_shell_supported() {
+        init_layer_surface(
+          &window,
+          self.layer_surface_options(
+            &widget_config,
+            &dock_config,
+            &coordinates,
+          ),
+        )?;
       }
 
       // On Windows, Tauri's `skip_taskbar` option isn't 100% reliable,
@@ -336,7 +528,7 @@ impl WidgetFactory {
       // to truly be always on top.
       #[cfg(target_os = "macos")]
       {
-        if widget_config.z_order == crate::config::ZOrder::TopMost {
+        if widget_config.layer() >= crate::config::WidgetLayer::Top {
           let _ = window.as_ref().window().set_above_menu_bar();
         }
       }
@@ -360,7 +552,124 @@ impl WidgetFactory {
       self.open_tx.send(state)?;
     }
 
-    Ok(())
+    Ok((config_path, widget_config))
+  }
+
+  /// Gets the absolute path to a widget's HTML file, and the URL to
+  /// open it with.
+  ///
+  /// The query string of the config's `html_path` (if any) is kept, with
+  /// the placement's query parameters merged on top.
+  async fn widget_url(
+    config_path: &PathBuf,
+    widget_config: &WidgetConfig,
+    placement: &WidgetPlacement,
+  ) -> anyhow::Result<(PathBuf, tauri::Url)> {
+    let parent_dir =
+      config_path.parent().context("No parent directory.")?;
+
+    let unparsed_path = widget_config.html_path.to_unicode_string();
+
+    let (relative_path, query) = match unparsed_path.split_once('?') {
+      Some((path, query)) => (path, query),
+      None => (unparsed_path.as_str(), ""),
+    };
+
+    let html_path = parent_dir.join(relative_path);
+
+    if !html_path.exists() {
+      bail!(
+        "HTML file not found at '{}' for config '{}'.",
+        relative_path,
+        config_path.display()
+      )
+    }
+
+    let csp = widget_config
+      .security
+      .as_ref()
+      .map(|security| security.content_security_policy());
+
+    let query = merge_query(query, &placement.query);
+
+    let init_url =
+      create_init_url(parent_dir, &html_path, query, csp).await?;
+
+    Ok((html_path, init_url))
+  }
+
+  /// Gets the title of a widget window, which is what screen readers
+  /// announce.
+  fn window_title(
+    &self,
+    config_path: &PathBuf,
+    widget_config: &WidgetConfig,
+  ) -> String {
+    widget_config.name.clone().unwrap_or_else(|| {
+      format!("Zebar - {}", self.config.formatted_widget_path(config_path))
+    })
+  }
+
+  /// Moves a widget window to the given coordinates, docking it to an
+  /// edge if enabled in the placement.
+  ///
+  /// Returns the dock config that was applied.
+  fn position_window(
+    &self,
+    window: &WebviewWindow,
+    placement: &WidgetPlacement,
+    segment: Option<&String>,
+    coordinates: &WidgetCoordinates,
+  ) -> anyhow::Result<DockConfig> {
+    // Windows of individual segments aren't docked, since reserving
+    // space for each of them would misplace the others.
+    let dock_config = match segment {
+      None => placement.dock_to_edge.clone(),
+      Some(_) => DockConfig::default(),
+    };
+
+    // Widget coordinates might be modified when docked to an edge.
+    let (size, position) = match dock_config.enabled {
+      false => (coordinates.size, coordinates.position),
+      true => self.dock_to_edge(window, &dock_config, coordinates)?,
+    };
+
+    info!("Positioning widget to {:?} {:?}", size, position);
+    Self::set_window_bounds(window, size, position, coordinates);
+
+    Ok(dock_config)
+  }
+
+  /// Sets the size and position of a widget window on the monitor it's
+  /// placed on.
+  fn set_window_bounds(
+    window: &tauri::WebviewWindow,
+    size: PhysicalSize<i32>,
+    position: PhysicalPosition<i32>,
+    #[cfg_attr(target_os = "windows", allow(unused_variables))]
+    coords: &WidgetCoordinates,
+  ) {
+    // On Windows, physical coordinates are shared across all monitors.
+    // We need to set the position twice to account for different monitor
+    // scale factors.
+    #[cfg(target_os = "windows")]
+    {
+      for _ in 0..2 {
+        let _ = window.set_size(size);
+        let _ = window.set_position(position);
+      }
+    }
+
+    // On MacOS and Linux, physical coordinates aren't shared across
+    // mixed-DPI monitors, so use logical coordinates instead.
+    #[cfg(not(target_os = "windows"))]
+    {
+      let (size, position) =
+        logical_bounds(size, position, coords.monitor.scale_factor);
+
+      let _ = window.set_size(size);
+      let _ = window.set_position(position);
+    }
   }
 
   /// Dock the widget window to a given edge. This might result in the
@@ -487,11 +796,96 @@ impl WidgetFactory {
     }
   }
 
+  /// Gets the layer surface options for a widget window. Docked widgets
+  /// reserve their size plus the window margin via an exclusive zone.
+  #[cfg(all(target_os = "linux", feature = "layer-shell"))]
+  fn layer_surface_options(
+    &self,
+    widget_config: &WidgetConfig,
+    dock_config: &DockConfig,
+    coords: &WidgetCoordinates,
+  ) -> LayerSurfaceOptions {
+    // Disallow docking with a centered anchor point, same as on Windows.
+    let dock = (dock_config.enabled
+      && coords.anchor != AnchorPoint::Center)
+      .then(|| {
+        let edge =
+          dock_config.edge.unwrap_or_else(|| coords.closest_edge());
+
+        let window_length = if edge.is_horizontal() {
+          coords.size.height
+        } else {
+          coords.size.width
+        };
+
+        let window_margin = dock_config
+          .window_margin
+          .to_px_scaled(window_length, coords.monitor.scale_factor);
+
+        (edge, (window_length + window_margin).max(0))
+      });
+
+    LayerSurfaceOptions {
+      layer: widget_config.layer(),
+      monitor: coords.monitor.clone(),
+      size: coords.size,
+      position: coords.position,
+      dock,
+    }
+  }
+
   /// Opens presets that are configured to be launched on startup.
   pub async fn startup(&self) -> anyhow::Result<()> {
     let startup_configs = self.config.startup_configs().await;
+    let widget_configs = self.config.widget_configs().await;
 
     for startup_config in startup_configs {
+      let abs_path =
+        self.config.to_absolute_path(&startup_config.path).ok();
+
+      let widget_config =
+        abs_path.as_ref().and_then(|path| widget_configs.get(path));
+
+      // Skip presets that are outside of their scheduled active hours.
+      // These get opened by the `Scheduler` once active.
+      let schedule = widget_config
+        .and_then(|config| {
+          config
+            .presets
+            .iter()
+            .find(|preset| preset.name == startup_config.preset)
+        })
+        .and_then(|preset| preset.schedule.as_ref());
+
+      if schedule.is_some_and(|schedule| !schedule.is_active_now()) {
+        info!(
+          "Skipping preset '{}' of {} outside of its active hours.",
+          startup_config.preset,
+          startup_config.path.display()
+        );
+        continue;
+      }
+
+      let is_deferred = widget_config
+        .and_then(|config| config.launch_options.as_ref())
+        .is_some_and(|launch_options| launch_options.defer_until_shown);
+
+      if let (true, Some(abs_path)) = (is_deferred, abs_path) {
+        info!(
+          "Deferring preset '{}' of {} until it's shown.",
+          startup_config.preset,
+          startup_config.path.display()
+        );
+
+        self
+          .deferred_presets
+          .lock()
+          .await
+          .push((abs_path, startup_config.preset));
+
+        continue;
+      }
+
       self
         .start_widget(
           &startup_config.path,
@@ -503,12 +897,48 @@ impl WidgetFactory {
     Ok(())
   }
 
+  /// Closes all open widgets and opens the startup configs of the given
+  /// profile, which is persisted as the active profile.
+  pub async fn switch_profile(&self, name: &str) -> anyhow::Result<()> {
+    info!("Switching to profile '{}'.", name);
+
+    self.config.set_active_profile(name).await?;
+    self.deferred_presets.lock().await.clear();
+
+    let widget_ids = {
+      self
+        .widget_states
+        .lock()
+        .await
+        .keys()
+        .cloned()
+        .collect::<Vec<_>>()
+    };
+
+    for widget_id in widget_ids {
+      let _ = self.stop_by_id(&widget_id);
+    }
+
+    self.startup().await
+  }
+
   fn initialization_script(
     &self,
     state: &WidgetState,
+    accessibility_prefs: AccessibilityPrefs,
   ) -> anyhow::Result<String> {
-    let state_script =
-      format!("window.__ZEBAR_STATE={};", serde_json::to_string(state)?);
+    // Windows that are reloaded in place get their latest state via
+    // session storage, since the initialization script can't be changed.
+    // It's removed once read, so that it doesn't override the state of
+    // later loads.
+    let state_script = format!(
+      "window.__ZEBAR_STATE=JSON.parse(sessionStorage.getItem('{0}'))??{1};sessionStorage.removeItem('{0}');window.__ZEBAR_ACCESSIBILITY={2};window.__ZEBAR_HEARTBEAT_INTERVAL={3};window.__ZEBAR_API_VERSION={4};",
+      RELOAD_STATE_KEY,
+      serde_json::to_string(state)?,
+      serde_json::to_string(&accessibility_prefs)?,
+      HEARTBEAT_INTERVAL_MS,
+      API_VERSION
+    );
 
     let sw_script = include_str!("../resources/initialization-script.js");
 
@@ -518,129 +948,229 @@ impl WidgetFactory {
   /// Registers window events for a given widget.
   fn register_window_events(
     &self,
-    window: &tauri::WebviewWindow,
+    window: &WebviewWindow,
     widget_id: String,
   ) -> anyhow::Result<()> {
     let widget_states = self.widget_states.clone();
     let close_tx = self.close_tx.clone();
+    let focus_tx = self.focus_tx.clone();
+    let app_handle = self.app_handle.clone();
+    let event_window = window.clone();
+
+    // Name of the monitor the window was last on, used to detect when
+    // the window moves to a different monitor.
+    let prev_monitor = std::sync::Mutex::new(
+      window
+        .current_monitor()
+        .ok()
+        .flatten()
+        .and_then(|monitor| monitor.name().cloned()),
+    );
 
     window.on_window_event(move |event| {
-      if let WindowEvent::Destroyed = event {
-        let widget_states = widget_states.clone();
-        let close_tx = close_tx.clone();
-        let widget_id = widget_id.clone();
-
-        task::spawn(async move {
-          let mut widget_states = widget_states.lock().await;
-
-          // Remove the widget state.
-          let state = widget_states.remove(&widget_id);
-
-          // Ensure appbar space is deallocated on close.
-          #[cfg(target_os = "windows")]
-          {
-            if let Some(window_handle) =
-              state.and_then(|state| state.window_handle)
+      match event {
+        WindowEvent::Focused(true) => {
+          emit_window_event(&event_window, WidgetWindowEvent::Focused);
+          let _ = focus_tx.send(widget_id.clone());
+        }
+        WindowEvent::Focused(false) => {
+          emit_window_event(&event_window, WidgetWindowEvent::Blurred)
+        }
+        WindowEvent::Resized(size) => emit_window_event(
+          &event_window,
+          WidgetWindowEvent::Resized {
+            width: size.width,
+            height: size.height,
+          },
+        ),
+        WindowEvent::Moved(position) => {
+          emit_window_event(
+            &event_window,
+            WidgetWindowEvent::Moved {
+              x: position.x,
+              y: position.y,
+            },
+          );
+
+          let monitor = event_window
+            .current_monitor()
+            .ok()
+            .flatten()
+            .and_then(|monitor| monitor.name().cloned());
+
+          let mut prev_monitor = prev_monitor.lock().unwrap();
+
+          if *prev_monitor != monitor {
+            *prev_monitor = monitor.clone();
+            emit_window_event(
+              &event_window,
+              WidgetWindowEvent::MonitorChanged { monitor },
+            );
+          }
+        }
+        WindowEvent::Destroyed => {
+          let widget_states = widget_states.clone();
+          let close_tx = close_tx.clone();
+          let app_handle = app_handle.clone();
+          let widget_id = widget_id.clone();
+
+          task::spawn(async move {
+            let mut widget_states = widget_states.lock().await;
+
+            // Remove the widget state.
+            let Some(state) = widget_states.remove(&widget_id) else {
+              return;
+            };
+
+            // Ensure appbar space is deallocated on close.
+            #[cfg(target_os = "windows")]
             {
-              let _ = remove_app_bar(window_handle);
+              if let Some(window_handle) = state.window_handle {
+                let _ = remove_app_bar(window_handle);
+              }
             }
-          }
 
-          // Broadcast the close event.
-          if let Err(err) = close_tx.send(widget_id) {
-            error!("Failed to send window close event: {:?}", err);
-          }
-        });
+            // Close companion widgets once the last window of the
+            // primary widget is closed.
+            let is_last_window = !widget_states
+              .values()
+              .any(|other| other.config_path == state.config_path);
+
+            if is_last_window {
+              for companion in widget_states.values().filter(|other| {
+                other.opened_by.as_ref() == Some(&state.config_path)
+              }) {
+                if let Some(window) =
+                  app_handle.get_webview_window(&companion.id)
+                {
+                  let _ = window.close();
+                }
+              }
+            }
+
+            // Broadcast the close event.
+            if let Err(err) = close_tx.send(state) {
+              error!("Failed to send window close event: {:?}", err);
+            }
+          });
+        }
+        _ => {}
       }
     });
 
     Ok(())
   }
 
+  /// Returns coordinates for each window of a widget, along with the
+  /// name of the segment that the window renders.
+  async fn window_coordinates(
+    &self,
+    widget_config: &WidgetConfig,
+    placement: &WidgetPlacement,
+  ) -> Vec<(Option<String>, WidgetCoordinates)> {
+    let coordinates = self.widget_coordinates(placement).await;
+
+    if widget_config.segments.is_empty() || widget_config.single_webview {
+      return coordinates
+        .into_iter()
+        .map(|coords| (None, coords))
+        .collect();
+    }
+
+    coordinates
+      .iter()
+      .flat_map(|coords| {
+        Self::segment_coordinates(&widget_config.segments, coords)
+      })
+      .collect()
+  }
+
+  /// Lays out segments within the given coordinates. Segments in the
+  /// same region are placed side-by-side in the order they're defined.
+  fn segment_coordinates(
+    segments: &[WidgetSegment],
+    coords: &WidgetCoordinates,
+  ) -> Vec<(Option<String>, WidgetCoordinates)> {
+    let widths = segments
+      .iter()
+      .map(|segment| {
+        segment
+          .width
+          .to_px_scaled(coords.size.width, coords.monitor.scale_factor)
+      })
+      .collect::<Vec<_>>();
+
+    let region_width = |region: SegmentRegion| {
+      segments
+        .iter()
+        .zip(&widths)
+        .filter(|(segment, _)| segment.region == region)
+        .map(|(_, width)| width)
+        .sum::<i32>()
+    };
+
+    // X-coordinate of the next segment in each region, relative to the
+    // left edge of the placement.
+    let mut left_x = 0;
+    let mut center_x =
+      (coords.size.width - region_width(SegmentRegion::Center)) / 2;
+    let mut right_x =
+      coords.size.width - region_width(SegmentRegion::Right);
+
+    segments
+      .iter()
+      .zip(widths)
+      .map(|(segment, width)| {
+        let next_x = match segment.region {
+          SegmentRegion::Left => &mut left_x,
+          SegmentRegion::Center => &mut center_x,
+          SegmentRegion::Right => &mut right_x,
+        };
+
+        let x = *next_x;
+        *next_x += width;
+
+        let segment_coords = WidgetCoordinates {
+          size: PhysicalSize::new(width, coords.size.height),
+          position: PhysicalPosition::new(
+            coords.position.x + x,
+            coords.position.y,
+          ),
+          offset: coords.offset,
+          anchor: coords.anchor,
+          monitor: coords.monitor.clone(),
+        };
+
+        (Some(segment.name.clone()), segment_coords)
+      })
+      .collect()
+  }
+
   /// Returns coordinates for window placement based on the given config.
   async fn widget_coordinates(
     &self,
     placement: &WidgetPlacement,
   ) -> Vec<WidgetCoordinates> {
-    let mut coordinates = vec![];
-
-    let monitors = self
+    self
       .monitor_state
       .monitors_by_selection(&placement.monitor_selection)
-      .await;
-
-    for monitor in monitors {
-      let monitor_width = monitor.width as i32;
-      let monitor_height = monitor.height as i32;
-
-      // Pixel values should be scaled by the monitor's scale factor,
-      // whereas percentage values are left as-is. This is because the
-      // percentage values are already relative to the monitor's size.
-      let window_width = placement
-        .width
-        .to_px_scaled(monitor_width, monitor.scale_factor);
-
-      let window_height = placement
-        .height
-        .to_px_scaled(monitor_height, monitor.scale_factor);
-
-      let window_size = PhysicalSize::new(window_width, window_height);
-
-      let (anchor_x, anchor_y) = match placement.anchor {
-        AnchorPoint::TopLeft => (monitor.x, monitor.y),
-        AnchorPoint::TopCenter => (
-          monitor.x + (monitor_width / 2) - (window_size.width / 2),
-          monitor.y,
-        ),
-        AnchorPoint::TopRight => {
-          (monitor.x + monitor_width - window_size.width, monitor.y)
-        }
-        AnchorPoint::CenterLeft => (
-          monitor.x,
-          monitor.y + (monitor_height / 2) - (window_size.height / 2),
-        ),
-        AnchorPoint::Center => (
-          monitor.x + (monitor_width / 2) - (window_size.width / 2),
-          monitor.y + (monitor_height / 2) - (window_size.height / 2),
-        ),
-        AnchorPoint::CenterRight => (
-          monitor.x + monitor_width - window_size.width,
-          monitor.y + (monitor_height / 2) - (window_size.height / 2),
-        ),
-        AnchorPoint::BottomLeft => {
-          (monitor.x, monitor.y + monitor_height - window_size.height)
-        }
-        AnchorPoint::BottomCenter => (
-          monitor.x + (monitor_width / 2) - (window_size.width / 2),
-          monitor.y + monitor_height - window_size.height,
-        ),
-        AnchorPoint::BottomRight => (
-          monitor.x + monitor_width - window_size.width,
-          monitor.y + monitor_height - window_size.height,
-        ),
-      };
-
-      let offset_x = placement
-        .offset_x
-        .to_px_scaled(monitor_width, monitor.scale_factor);
-
-      let offset_y = placement
-        .offset_y
-        .to_px_scaled(monitor_height, monitor.scale_factor);
-
-      let window_position =
-        PhysicalPosition::new(anchor_x + offset_x, anchor_y + offset_y);
+      .await
+      .iter()
+      .map(|monitor| placement_coordinates(placement, monitor))
+      .collect()
+  }
 
-      coordinates.push(WidgetCoordinates {
-        size: window_size,
-        position: window_position,
-        offset: PhysicalPosition::new(offset_x, offset_y),
-        monitor: monitor.clone(),
-        anchor: placement.anchor,
-      });
+  /// Removes a widget as a subscriber from all of its providers.
+  ///
+  /// Needs to be called before a window is reloaded in place, since its
+  /// label stays the same and the reloaded page listens to its providers
+  /// again.
+  pub async fn stop_providers(&self, widget_id: &str) {
+    if let Some(manager) =
+      self.app_handle.try_state::<Arc<ProviderManager>>()
+    {
+      manager.stop_by_subscriber(widget_id).await;
     }
-
-    coordinates
   }
 
   /// Closes a single widget by a given widget ID.
@@ -679,6 +1209,11 @@ impl WidgetFactory {
     config_path: &PathBuf,
     preset_name: &str,
   ) -> anyhow::Result<()> {
+    // A deferred preset has no windows to close.
+    if self.take_deferred(config_path, preset_name).await {
+      return Ok(());
+    }
+
     let widget_states = self.states_by_path().await;
 
     let found_widget_states = widget_states
@@ -699,32 +1234,354 @@ impl WidgetFactory {
     Ok(())
   }
 
-  /// Relaunches all currently open widgets.
-  pub async fn relaunch_all(&self) -> anyhow::Result<()> {
-    let widget_ids =
-      { self.widget_states.lock().await.keys().cloned().collect() };
-
-    self.relaunch_by_ids(&widget_ids).await
-  }
-
-  /// Relaunches widgets with the given widget ID's.
-  pub async fn relaunch_by_ids(
+  /// Shows or hides all widgets of the given preset name.
+  pub async fn set_visible_by_preset(
     &self,
-    widget_ids: &Vec<String>,
+    config_path: &PathBuf,
+    preset_name: &str,
+    visible: bool,
   ) -> anyhow::Result<()> {
-    let changed_states = {
-      let mut widget_states = self.widget_states.lock().await;
+    // Deferred presets are opened once they're first shown.
+    if visible && self.take_deferred(config_path, preset_name).await {
+      return self
+        .start_widget(
+          config_path,
+          &WidgetOpenOptions::Preset(preset_name.to_string()),
+        )
+        .await;
+    }
 
-      // Optimistically clear running widget states.
-      widget_ids
-        .iter()
-        .filter_map(|id| widget_states.remove(id))
-        .inspect(|widget_state| {
-          // Need to clean up any appbars prior to restarting.
-          #[cfg(target_os = "windows")]
-          {
-            if let Some(window_handle) = widget_state.window_handle {
-              let _ = remove_app_bar(window_handle);
+    let widget_states = self.states_by_path().await;
+
+    let found_widget_states =
+      widget_states.get(config_path).into_iter().flatten().filter(
+        |state| {
+          matches!(
+            &state.open_options,
+            WidgetOpenOptions::Preset(name) if name == preset_name
+          )
+        },
+      );
+
+    let mut revealed_widgets = self.revealed_widgets.lock().await;
+
+    for widget_state in found_widget_states {
+      // Explicit visibility changes take precedence over reveals.
+      revealed_widgets.remove(&widget_state.id);
+
+      if let Some(window) =
+        self.app_handle.get_webview_window(&widget_state.id)
+      {
+        match visible {
+          true => window.show()?,
+          false => window.hide()?,
+        }
+
+        emit_window_event(
+          &window,
+          WidgetWindowEvent::VisibilityChanged { visible },
+        );
+      }
+    }
+
+    Ok(())
+  }
+
+  /// Temporarily shows a hidden widget (e.g. on an edge swipe). It's
+  /// hidden again after the given duration, unless its visibility is
+  /// changed in the meantime.
+  pub async fn reveal(
+    &self,
+    widget_id: &str,
+    duration: Duration,
+  ) -> anyhow::Result<()> {
+    let window = self
+      .app_handle
+      .get_webview_window(widget_id)
+      .context("No window found for widget.")?;
+
+    window.show()?;
+    emit_window_event(
+      &window,
+      WidgetWindowEvent::VisibilityChanged { visible: true },
+    );
+
+    // Revealing an already revealed widget extends the duration.
+    self
+      .revealed_widgets
+      .lock()
+      .await
+      .insert(widget_id.to_string(), Instant::now() + duration);
+
+    let revealed_widgets = self.revealed_widgets.clone();
+    let widget_id = widget_id.to_string();
+
+    task::spawn(async move {
+      time::sleep(duration).await;
+
+      let mut revealed_widgets = revealed_widgets.lock().await;
+
+      let should_hide = revealed_widgets
+        .get(&widget_id)
+        .is_some_and(|hide_at| *hide_at <= Instant::now());
+
+      if should_hide {
+        revealed_widgets.remove(&widget_id);
+
+        if window.hide().is_ok() {
+          emit_window_event(
+            &window,
+            WidgetWindowEvent::VisibilityChanged { visible: false },
+          );
+        }
+      }
+    });
+
+    Ok(())
+  }
+
+  /// Removes the given preset from the deferred presets.
+  ///
+  /// Returns whether the preset was deferred.
+  async fn take_deferred(
+    &self,
+    config_path: &PathBuf,
+    preset_name: &str,
+  ) -> bool {
+    let mut deferred_presets = self.deferred_presets.lock().await;
+    let prev_len = deferred_presets.len();
+
+    deferred_presets
+      .retain(|(path, name)| path != config_path || name != preset_name);
+
+    deferred_presets.len() != prev_len
+  }
+
+  /// Relaunches all currently open widgets.
+  ///
+  /// Windows are reused where possible, which avoids flicker and
+  /// changes to their z-order.
+  pub async fn relaunch_all(&self) -> anyhow::Result<()> {
+    let widget_ids =
+      { self.widget_states.lock().await.keys().cloned().collect() };
+
+    self.recycle_by_ids(&widget_ids).await
+  }
+
+  /// Relaunches widgets with the given widget ID's, reusing their
+  /// windows where possible. Remaining widgets are closed and reopened.
+  async fn recycle_by_ids(
+    &self,
+    widget_ids: &Vec<String>,
+  ) -> anyhow::Result<()> {
+    // Windows are reused per preset (or standalone placement), since
+    // the number of windows can change with the config.
+    let mut groups = Vec::<Vec<WidgetState>>::new();
+
+    for state in self.states().await.into_values() {
+      if !widget_ids.contains(&state.id) {
+        continue;
+      }
+
+      match groups.iter_mut().find(|group| {
+        group[0].config_path == state.config_path
+          && group[0].open_options == state.open_options
+          && group[0].opened_by == state.opened_by
+      }) {
+        Some(group) => group.push(state),
+        None => groups.push(vec![state]),
+      }
+    }
+
+    let mut remaining_ids = Vec::new();
+
+    for group in groups {
+      match self.reload_in_place(&group).await {
+        Ok(true) => {}
+        Ok(false) => {
+          remaining_ids.extend(group.into_iter().map(|state| state.id))
+        }
+        Err(err) => {
+          warn!("Failed to reload widget in place: {:?}", err);
+          remaining_ids.extend(group.into_iter().map(|state| state.id));
+        }
+      }
+    }
+
+    self.relaunch_by_ids(&remaining_ids).await
+  }
+
+  /// Navigates the windows of a preset (or standalone placement) to
+  /// their latest config and moves them to their new coordinates.
+  ///
+  /// Returns `false` without changing anything if the windows can't be
+  /// reused (e.g. a window option changed, or the number of windows).
+  async fn reload_in_place(
+    &self,
+    states: &[WidgetState],
+  ) -> anyhow::Result<bool> {
+    // Layer surfaces can't be moved after they're created.
+    #[cfg(all(target_os = "linux", feature = "layer-shell"))]
+    if is_layer_shell_supported() {
+      return Ok(false);
+    }
+
+    let prev_state = &states[0];
+
+    let Some((config_path, widget_config)) = self
+      .config
+      .widget_config_by_path(&prev_state.config_path)
+      .await
+    else {
+      return Ok(false);
+    };
+
+    // Options that only apply when the window is created.
+    let prev_config = &prev_state.config;
+
+    if widget_config.z_order != prev_config.z_order
+      || widget_config.layer() != prev_config.layer()
+      || widget_config.focused != prev_config.focused
+      || widget_config.shown_in_taskbar != prev_config.shown_in_taskbar
+      || widget_config.resizable != prev_config.resizable
+      || widget_config.transparent != prev_config.transparent
+      || widget_config.x11_window_type != prev_config.x11_window_type
+      || widget_config.security != prev_config.security
+    {
+      return Ok(false);
+    }
+
+    let placement = match &prev_state.open_options {
+      WidgetOpenOptions::Standalone(placement) => placement,
+      WidgetOpenOptions::Preset(name) => {
+        match widget_config.presets.iter().find(|p| p.name == *name) {
+          Some(preset) => &preset.placement,
+          None => return Ok(false),
+        }
+      }
+    };
+
+    // Windows were created in the same order as their coordinates.
+    let mut states = states.to_vec();
+    states.sort_by_key(|state| {
+      state
+        .id
+        .trim_start_matches("widget-")
+        .parse::<u32>()
+        .unwrap_or_default()
+    });
+
+    let coordinates =
+      self.window_coordinates(&widget_config, placement).await;
+
+    if coordinates.len() != states.len()
+      || states
+        .iter()
+        .zip(&coordinates)
+        .any(|(state, (segment, _))| state.segment != *segment)
+    {
+      return Ok(false);
+    }
+
+    let (html_path, init_url) =
+      Self::widget_url(&config_path, &widget_config, placement).await?;
+
+    let mut windows = Vec::new();
+
+    for state in &states {
+      // Session storage isn't shared with other origins (e.g. if the
+      // widget navigated to a remote page).
+      let is_same_origin = self
+        .app_handle
+        .get_webview_window(&state.id)
+        .filter(|window| {
+          window
+            .url()
+            .is_ok_and(|url| url.origin() == init_url.origin())
+        });
+
+      match is_same_origin {
+        Some(window) => windows.push(window),
+        None => return Ok(false),
+      }
+    }
+
+    for ((prev_state, window), (_, coordinates)) in
+      states.into_iter().zip(windows).zip(coordinates)
+    {
+      info!(
+        "Reloading widget #{} in place from {}",
+        prev_state.id,
+        config_path.display()
+      );
+
+      let state = WidgetState {
+        config: widget_config.clone(),
+        html_path: html_path.clone(),
+        env: resolve_env(&widget_config)?,
+        ..prev_state.clone()
+      };
+
+      // Docking is redone, since the placement might have changed.
+      #[cfg(target_os = "windows")]
+      if let Some(window_handle) = state.window_handle {
+        let _ = remove_app_bar(window_handle);
+      }
+
+      self.position_window(
+        &window,
+        placement,
+        state.segment.as_ref(),
+        &coordinates,
+      )?;
+
+      window
+        .set_title(&self.window_title(&config_path, &widget_config))?;
+
+      self.stop_providers(&state.id).await;
+
+      window.eval(&format!(
+        "sessionStorage.setItem('{}',{});location.replace({});",
+        RELOAD_STATE_KEY,
+        serde_json::to_string(&serde_json::to_string(&state)?)?,
+        serde_json::to_string(init_url.as_str())?
+      ))?;
+
+      self
+        .widget_states
+        .lock()
+        .await
+        .insert(state.id.clone(), state.clone());
+
+      // Broadcast as a relaunch, same as when the window is recreated.
+      self.close_tx.send(prev_state)?;
+      self.open_tx.send(state)?;
+    }
+
+    Ok(true)
+  }
+
+  /// Relaunches widgets with the given widget ID's.
+  ///
+  /// Windows are always recreated (e.g. for widgets whose webview is
+  /// unresponsive).
+  pub async fn relaunch_by_ids(
+    &self,
+    widget_ids: &Vec<String>,
+  ) -> anyhow::Result<()> {
+    let changed_states = {
+      let mut widget_states = self.widget_states.lock().await;
+
+      // Optimistically clear running widget states.
+      widget_ids
+        .iter()
+        .filter_map(|id| widget_states.remove(id))
+        .inspect(|widget_state| {
+          // Need to clean up any appbars prior to restarting.
+          #[cfg(target_os = "windows")]
+          {
+            if let Some(window_handle) = widget_state.window_handle {
+              let _ = remove_app_bar(window_handle);
             }
           }
         })
@@ -738,6 +1595,9 @@ impl WidgetFactory {
         widget_state.config_path.display()
       );
 
+      // The window might not be destroyed yet once its close event is
+      // handled, so its providers are stopped here.
+      self.stop_providers(&widget_state.id).await;
       let _ = self.stop_by_id(&widget_state.id);
 
       self
@@ -751,7 +1611,8 @@ impl WidgetFactory {
     Ok(())
   }
 
-  /// Relaunches widgets with the given config paths.
+  /// Relaunches widgets with the given config paths, reusing their
+  /// windows where possible.
   pub async fn relaunch_by_paths(
     &self,
     config_paths: &Vec<PathBuf>,
@@ -767,7 +1628,70 @@ impl WidgetFactory {
         .collect::<Vec<_>>()
     };
 
-    self.relaunch_by_ids(&widget_ids).await
+    self.recycle_by_ids(&widget_ids).await
+  }
+
+  /// Opens the devtools for all widgets with the given config path.
+  pub async fn open_devtools_by_path(
+    &self,
+    config_path: &PathBuf,
+  ) -> anyhow::Result<()> {
+    let widget_states = self.states_by_path().await;
+
+    let found_widget_states = widget_states
+      .get(config_path)
+      .context("No widgets found with the given config path.")?;
+
+    for widget_state in found_widget_states {
+      let window = self
+        .app_handle
+        .get_webview_window(&widget_state.id)
+        .context("No Tauri window found for the given widget ID.")?;
+
+      window.open_devtools();
+    }
+
+    Ok(())
+  }
+
+  /// Renders each open widget with the given config path to a PNG.
+  ///
+  /// If multiple widgets are open, the index of each is appended to the
+  /// file name (e.g. `bar-1.png`).
+  pub async fn capture_by_path(
+    &self,
+    config_path: &PathBuf,
+    output_path: &Path,
+  ) -> anyhow::Result<()> {
+    let widget_states = self.states_by_path().await;
+
+    let found_widget_states = widget_states
+      .get(config_path)
+      .context("No widgets found with the given config path.")?;
+
+    for (index, widget_state) in found_widget_states.iter().enumerate() {
+      let window = self
+        .app_handle
+        .get_webview_window(&widget_state.id)
+        .context("No Tauri window found for the given widget ID.")?;
+
+      let output_path = match found_widget_states.len() {
+        1 => output_path.to_path_buf(),
+        _ => output_path.with_file_name(format!(
+          "{}-{}.png",
+          output_path
+            .file_stem()
+            .unwrap_or_default()
+            .to_string_lossy(),
+          index + 1
+        )),
+      };
+
+      capture_widget_to_file(&window, &output_path).await?;
+      info!("Captured widget to {}.", output_path.display());
+    }
+
+    Ok(())
   }
 
   /// Clears the cache for all open widgets.
@@ -785,11 +1709,242 @@ impl WidgetFactory {
     }
   }
 
+  /// Checks whether the widget with the given ID is allowed to use a
+  /// provider type.
+  ///
+  /// The settings window is unrestricted. Widgets that don't declare
+  /// permissions can use any provider, except for providers that require
+  /// opting in.
+  pub async fn check_provider_permission(
+    &self,
+    widget_id: &str,
+    provider_type: &str,
+  ) -> anyhow::Result<()> {
+    let widget_states = self.widget_states.lock().await;
+
+    let Some(state) = widget_states.get(widget_id) else {
+      return check_settings_window(widget_id);
+    };
+
+    let is_allowed = match &state.config.permissions {
+      Some(permissions) => permissions.allows_provider(provider_type),
+      None => !provider_requires_opt_in(provider_type),
+    };
+
+    if !is_allowed {
+      bail!(
+        "Widget is not permitted to use the '{}' provider. Add it to \
+        `permissions.providers` in the widget config.",
+        provider_type
+      );
+    }
+
+    Ok(())
+  }
+
+  /// Checks whether the widget with the given ID is allowed to invoke a
+  /// privileged command.
+  ///
+  /// The settings window is unrestricted. Widgets must explicitly allow
+  /// each privileged command, even if they don't declare any other
+  /// permissions.
+  pub async fn check_command_permission(
+    &self,
+    widget_id: &str,
+    command: CommandPermission,
+  ) -> anyhow::Result<()> {
+    let widget_states = self.widget_states.lock().await;
+
+    let Some(state) = widget_states.get(widget_id) else {
+      return check_settings_window(widget_id);
+    };
+
+    let is_allowed = state
+      .config
+      .permissions
+      .as_ref()
+      .is_some_and(|permissions| permissions.allows_command(command));
+
+    if !is_allowed {
+      bail!(
+        "Widget is not permitted to use '{}' commands. Add it to \
+        `permissions.commands` in the widget config.",
+        command
+      );
+    }
+
+    Ok(())
+  }
+
+  /// Checks whether the widget with the given ID is allowed to run a
+  /// program with the given arguments and working directory.
+  ///
+  /// The program must be listed in `shell_commands`, unless the widget
+  /// allows `shell_exec`. The working directory is resolved relative to
+  /// the widget's directory and, unless the widget allows `shell_exec`,
+  /// must be within it.
+  ///
+  /// Returns the resolved working directory.
+  pub async fn check_shell_command_permission(
+    &self,
+    widget_id: &str,
+    program: &str,
+    args: &[String],
+    cwd: Option<&str>,
+  ) -> anyhow::Result<Option<PathBuf>> {
+    let widget_states = self.widget_states.lock().await;
+
+    let Some(state) = widget_states.get(widget_id) else {
+      check_settings_window(widget_id)?;
+      return Ok(cwd.map(PathBuf::from));
+    };
+
+    let permissions = state.config.permissions.as_ref();
+
+    let is_allowed = permissions.is_some_and(|permissions| {
+      permissions.allows_shell_command(program, args)
+    });
+
+    if !is_allowed {
+      bail!(
+        "Widget is not permitted to run '{}' with the given arguments. \
+        Add it to `permissions.shellCommands` in the widget config.",
+        program
+      );
+    }
+
+    let Some(cwd) = cwd else {
+      return Ok(None);
+    };
+
+    let widget_dir = state
+      .config_path
+      .parent()
+      .context("Invalid widget config path.")?
+      .to_absolute()?;
+
+    let cwd = widget_dir
+      .join(cwd)
+      .to_absolute()
+      .with_context(|| format!("Invalid working directory '{}'.", cwd))?;
+
+    let allows_any_cwd = permissions.is_some_and(|permissions| {
+      permissions.allows_command(CommandPermission::ShellExec)
+    });
+
+    if !allows_any_cwd && !cwd.starts_with(&widget_dir) {
+      bail!(
+        "Widget is not permitted to run commands outside of its \
+        directory. Add `shell_exec` to `permissions.commands` in the \
+        widget config."
+      );
+    }
+
+    Ok(Some(cwd))
+  }
+
+  /// Resolves a path that the widget with the given ID writes a file to.
+  ///
+  /// Relative paths are resolved from the widget's directory, and the
+  /// file must be within it. Paths from the settings window are used
+  /// as-is.
+  pub async fn resolve_output_path(
+    &self,
+    widget_id: &str,
+    path: &str,
+  ) -> anyhow::Result<PathBuf> {
+    let widget_states = self.widget_states.lock().await;
+
+    let Some(state) = widget_states.get(widget_id) else {
+      check_settings_window(widget_id)?;
+      return Ok(PathBuf::from(path));
+    };
+
+    let widget_dir = state
+      .config_path
+      .parent()
+      .context("Invalid widget config path.")?
+      .to_absolute()?;
+
+    // The file might not exist yet, so only its parent is resolved.
+    let joined_path = widget_dir.join(path);
+
+    let output_path = joined_path
+      .parent()
+      .zip(joined_path.file_name())
+      .and_then(|(parent, file_name)| {
+        Some(parent.to_absolute().ok()?.join(file_name))
+      })
+      .with_context(|| format!("Invalid output path '{}'.", path))?;
+
+    if !output_path.starts_with(&widget_dir) {
+      bail!(
+        "Widget is not permitted to write files outside of its \
+        directory."
+      );
+    }
+
+    Ok(output_path)
+  }
+
+  /// Secrets that provider configs of the widget with the given ID can
+  /// reference.
+  ///
+  /// The settings window can reference any secret.
+  pub async fn secret_access(
+    &self,
+    widget_id: &str,
+  ) -> anyhow::Result<SecretAccess> {
+    match self.widget_states.lock().await.get(widget_id) {
+      Some(state) => Ok(state.config.secret_access()),
+      None => {
+        check_settings_window(widget_id)?;
+        Ok(SecretAccess::Any)
+      }
+    }
+  }
+
+  /// Checks whether the widget with the given ID is shown in the
+  /// taskbar, which is required for taskbar overlays and progress.
+  ///
+  /// The settings window is always allowed, whereas any other window
+  /// that isn't a widget is denied.
+  pub async fn check_shown_in_taskbar(
+    &self,
+    widget_id: &str,
+  ) -> anyhow::Result<()> {
+    match self.widget_states.lock().await.get(widget_id) {
+      Some(state) if !state.config.shown_in_taskbar => {
+        bail!(
+          "Widget must be shown in the taskbar. Enable `shownInTaskbar` \
+          in the widget config."
+        )
+      }
+      Some(_) => Ok(()),
+      None => check_settings_window(widget_id),
+    }
+  }
+
   /// Returns widget states by their widget ID's.
   pub async fn states(&self) -> HashMap<String, WidgetState> {
     self.widget_states.lock().await.clone()
   }
 
+  /// Returns the `env` variables of the widget with the given window
+  /// label.
+  pub async fn env(
+    &self,
+    label: &str,
+  ) -> anyhow::Result<HashMap<String, String>> {
+    self
+      .widget_states
+      .lock()
+      .await
+      .get(label)
+      .map(|state| state.env.clone())
+      .context("No widget found for the window.")
+  }
+
   /// Returns widget states grouped by their config paths.
   pub async fn states_by_path(
     &self,
@@ -807,3 +1962,328 @@ impl WidgetFactory {
     )
   }
 }
+
+/// Resolves the variables to pass to a widget from its config.
+///
+/// Encrypted values are decrypted, since they're only kept encrypted
+/// within the widget config.
+fn resolve_env(
+  widget_config: &WidgetConfig,
+) -> anyhow::Result<HashMap<String, String>> {
+  widget_config
+    .env
+    .iter()
+    .map(|(key, value)| {
+      let value = decrypt_string(value)?;
+      Ok((key.clone(), interpolate_env_vars(&value)))
+    })
+    .collect()
+}
+
+/// Merges query parameters into a query string, replacing existing
+/// parameters of the same name.
+///
+/// Returns `None` if the resulting query string is empty.
+fn merge_query(
+  query: &str,
+  overrides: &HashMap<String, String>,
+) -> Option<String> {
+  let mut url = tauri::Url::parse("http://127.0.0.1").ok()?;
+  url.set_query(Some(query));
+
+  let pairs = url
+    .query_pairs()
+    .filter(|(key, _)| !overrides.contains_key(key.as_ref()))
+    .map(|(key, value)| (key.into_owned(), value.into_owned()))
+    .collect::<Vec<_>>();
+
+  // Sort overrides so that the URL is the same across reloads.
+  let mut overrides = overrides.iter().collect::<Vec<_>>();
+  overrides.sort();
+
+  url
+    .query_pairs_mut()
+    .clear()
+    .extend_pairs(pairs)
+    .extend_pairs(overrides);
+
+  url
+    .query()
+    .filter(|query| !query.is_empty())
+    .map(|query| query.to_string())
+}
+
+/// Allows the settings window, which is the only window that isn't a
+/// widget. Any other window without a widget state is denied.
+fn check_settings_window(label: &str) -> anyhow::Result<()> {
+  if label != SETTINGS_WINDOW_LABEL {
+    bail!("Unknown widget '{}'.", label);
+  }
+
+  Ok(())
+}
+
+/// Gets the coordinates of a widget with the given placement on a
+/// single monitor.
+///
+/// Sizes and offsets are in the monitor's own physical pixels, so the
+/// result only depends on that monitor's bounds and scale factor, even
+/// when other monitors have a different DPI.
+fn placement_coordinates(
+  placement: &WidgetPlacement,
+  monitor: &Monitor,
+) -> WidgetCoordinates {
+  let monitor_width = monitor.width as i32;
+  let monitor_height = monitor.height as i32;
+
+  // Pixel values should be scaled by the monitor's scale factor,
+  // whereas percentage values are left as-is. This is because the
+  // percentage values are already relative to the monitor's size.
+  let window_width = placement
+    .width
+    .to_px_scaled(monitor_width, monitor.scale_factor);
+
+  let window_height = placement
+    .height
+    .to_px_scaled(monitor_height, monitor.scale_factor);
+
+  let window_size = PhysicalSize::new(window_width, window_height);
+
+  let (anchor_x, anchor_y) = match placement.anchor {
+    AnchorPoint::TopLeft => (monitor.x, monitor.y),
+    AnchorPoint::TopCenter => (
+      monitor.x + (monitor_width / 2) - (window_size.width / 2),
+      monitor.y,
+    ),
+    AnchorPoint::TopRight => {
+      (monitor.x + monitor_width - window_size.width, monitor.y)
+    }
+    AnchorPoint::CenterLeft => (
+      monitor.x,
+      monitor.y + (monitor_height / 2) - (window_size.height / 2),
+    ),
+    AnchorPoint::Center => (
+      monitor.x + (monitor_width / 2) - (window_size.width / 2),
+      monitor.y + (monitor_height / 2) - (window_size.height / 2),
+    ),
+    AnchorPoint::CenterRight => (
+      monitor.x + monitor_width - window_size.width,
+      monitor.y + (monitor_height / 2) - (window_size.height / 2),
+    ),
+    AnchorPoint::BottomLeft => {
+      (monitor.x, monitor.y + monitor_height - window_size.height)
+    }
+    AnchorPoint::BottomCenter => (
+      monitor.x + (monitor_width / 2) - (window_size.width / 2),
+      monitor.y + monitor_height - window_size.height,
+    ),
+    AnchorPoint::BottomRight => (
+      monitor.x + monitor_width - window_size.width,
+      monitor.y + monitor_height - window_size.height,
+    ),
+  };
+
+  let offset_x = placement
+    .offset_x
+    .to_px_scaled(monitor_width, monitor.scale_factor);
+
+  let offset_y = placement
+    .offset_y
+    .to_px_scaled(monitor_height, monitor.scale_factor);
+
+  let window_position =
+    PhysicalPosition::new(anchor_x + offset_x, anchor_y + offset_y);
+
+  WidgetCoordinates {
+    size: window_size,
+    position: window_position,
+    offset: PhysicalPosition::new(offset_x, offset_y),
+    monitor: monitor.clone(),
+    anchor: placement.anchor,
+  }
+}
+
+/// Converts physical window bounds to logical bounds on the monitor with
+/// the given scale factor.
+///
+/// On MacOS and Linux, the physical coordinates of each monitor are its
+/// logical coordinates multiplied by its own scale factor. These aren't
+/// shared across mixed-DPI monitors, so the target monitor's scale
+/// factor has to be used. Otherwise, the scale factor of the monitor the
+/// window is currently on is used.
+#[cfg(any(not(target_os = "windows"), test))]
+fn logical_bounds(
+  size: PhysicalSize<i32>,
+  position: PhysicalPosition<i32>,
+  scale_factor: f32,
+) -> (tauri::LogicalSize<f64>, tauri::LogicalPosition<f64>) {
+  let scale_factor = scale_factor as f64;
+
+  (
+    size.to_logical(scale_factor),
+    position.to_logical(scale_factor),
+  )
+}
+
+#[cfg(test)]
+mod tests {
+  use std::{collections::HashMap, str::FromStr};
+
+  use tauri::{
+    LogicalPosition, LogicalSize, PhysicalPosition, PhysicalSize,
+  };
+
+  use super::{logical_bounds, placement_coordinates};
+  use crate::{
+    common::LengthValue,
+    config::{AnchorPoint, MonitorSelection, WidgetPlacement},
+    monitor_state::Monitor,
+  };
+
+  fn monitor(
+    x: i32,
+    y: i32,
+    width: u32,
+    height: u32,
+    scale: f32,
+  ) -> Monitor {
+    Monitor {
+      name: None,
+      is_primary: false,
+      x,
+      y,
+      width,
+      height,
+      scale_factor: scale,
+      hardware_id: None,
+    }
+  }
+
+  fn placement(
+    anchor: AnchorPoint,
+    size: (&str, &str),
+    offset: (&str, &str),
+  ) -> WidgetPlacement {
+    WidgetPlacement {
+      anchor,
+      offset_x: LengthValue::from_str(offset.0).unwrap(),
+      offset_y: LengthValue::from_str(offset.1).unwrap(),
+      width: LengthValue::from_str(size.0).unwrap(),
+      height: LengthValue::from_str(size.1).unwrap(),
+      monitor_selection: MonitorSelection::All,
+      dock_to_edge: Default::default(),
+      query: HashMap::new(),
+    }
+  }
+
+  #[test]
+  fn placement_coordinates_scale_per_monitor() {
+    let placement =
+      placement(AnchorPoint::TopLeft, ("100%", "40px"), ("0px", "0px"));
+
+    let coords =
+      placement_coordinates(&placement, &monitor(0, 0, 1920, 1080, 1.));
+    assert_eq!(coords.size, PhysicalSize::new(1920, 40));
+    assert_eq!(coords.position, PhysicalPosition::new(0, 0));
+
+    let coords = placement_coordinates(
+      &placement,
+      &monitor(1920, -200, 3840, 2160, 2.),
+    );
+    assert_eq!(coords.size, PhysicalSize::new(3840, 80));
+    assert_eq!(coords.position, PhysicalPosition::new(1920, -200));
+
+    // Converting back with the target monitor's scale factor gives the
+    // same logical height on both monitors.
+    assert_eq!(
+      logical_bounds(coords.size, coords.position, 2.),
+      (
+        LogicalSize::new(1920., 40.),
+        LogicalPosition::new(960., -100.)
+      )
+    );
+  }
+
+  #[test]
+  fn placement_coordinates_offset_from_far_anchor() {
+    let placement = placement(
+      AnchorPoint::BottomRight,
+      ("200px", "50px"),
+      ("-10px", "-10px"),
+    );
+
+    let coords =
+      placement_coordinates(&placement, &monitor(0, 0, 1920, 1080, 1.));
+    assert_eq!(coords.size, PhysicalSize::new(200, 50));
+    assert_eq!(coords.position, PhysicalPosition::new(1710, 1020));
+    assert_eq!(coords.offset, PhysicalPosition::new(-10, -10));
+
+    let coords = placement_coordinates(
+      &placement,
+      &monitor(-2560, 0, 2560, 1440, 1.25),
+    );
+    assert_eq!(coords.size, PhysicalSize::new(250, 63));
+    assert_eq!(coords.position, PhysicalPosition::new(-263, 1364));
+    assert_eq!(coords.offset, PhysicalPosition::new(-13, -13));
+  }
+
+  #[test]
+  fn placement_coordinates_keep_percentages_unscaled() {
+    let placement =
+      placement(AnchorPoint::Center, ("50%", "10%"), ("5%", "0%"));
+
+    let coords =
+      placement_coordinates(&placement, &monitor(0, 0, 1920, 1080, 1.));
+    assert_eq!(coords.size, PhysicalSize::new(960, 108));
+    assert_eq!(coords.position, PhysicalPosition::new(576, 486));
+
+    let coords = placement_coordinates(
+      &placement,
+      &monitor(1920, -200, 3840, 2160, 2.),
+    );
+    assert_eq!(coords.size, PhysicalSize::new(1920, 216));
+    assert_eq!(coords.position, PhysicalPosition::new(3072, 772));
+  }
+
+  #[test]
+  fn logical_bounds_uses_target_monitor_scale_factor() {
+    let size = PhysicalSize::new(400, 60);
+    let position = PhysicalPosition::new(3840, 0);
+
+    assert_eq!(
+      logical_bounds(size, position, 2.),
+      (LogicalSize::new(200., 30.), LogicalPosition::new(1920., 0.))
+    );
+
+    assert_eq!(
+      logical_bounds(size, position, 1.),
+      (LogicalSize::new(400., 60.), LogicalPosition::new(3840., 0.))
+    );
+  }
+
+  #[test]
+  fn logical_bounds_keeps_negative_origins() {
+    let (size, position) = logical_bounds(
+      PhysicalSize::new(250, 50),
+      PhysicalPosition::new(-2560, -1440),
+      1.25,
+    );
+
+    assert_eq!(size, LogicalSize::new(200., 40.));
+    assert_eq!(position, LogicalPosition::new(-2048., -1152.));
+  }
+
+  #[test]
+  fn logical_bounds_keeps_fractional_coordinates() {
+    let (size, position) = logical_bounds(
+      PhysicalSize::new(101, 31),
+      PhysicalPosition::new(-1921, 7),
+      1.5,
+    );
+
+    assert!((size.width - 67.333).abs() < 0.001);
+    assert!((size.height - 20.667).abs() < 0.001);
+    assert!((position.x - -1280.667).abs() < 0.001);
+    assert!((position.y - 4.667).abs() < 0.001);
+  }
+}
@@ -0,0 +1,150 @@
+use std::{sync::Arc, time::Duration};
+
+use tauri::{AppHandle, Manager, WebviewWindow};
+use tokio::{task, time};
+use tracing::warn;
+
+#[cfg(target_os = "macos")]
+use crate::common::macos::WindowExtMacOs;
+#[cfg(target_os = "windows")]
+use crate::common::windows::WindowExtWindows;
+use crate::{
+  config::WidgetLayer, monitor_state::MonitorState,
+  widget_factory::WidgetFactory,
+};
+
+/// How often to re-assert the order of windows, for changes that can't
+/// be listened for (e.g. a fullscreen app being toggled).
+const REASSERT_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Keeps Zebar windows in order of their layer, so that e.g. flyouts
+/// reliably appear above bars and below popups.
+///
+/// The order is re-asserted whenever it might have been reshuffled by
+/// the OS, i.e. when a widget is opened or focused, when monitors
+/// change, and periodically.
+pub struct WindowLayers {
+  app_handle: AppHandle,
+
+  /// Reference to `WidgetFactory`.
+  widget_factory: Arc<WidgetFactory>,
+}
+
+impl WindowLayers {
+  pub fn new(
+    app_handle: &AppHandle,
+    widget_factory: Arc<WidgetFactory>,
+    monitor_state: Arc<MonitorState>,
+  ) -> Arc<Self> {
+    let window_layers = Arc::new(Self {
+      app_handle: app_handle.clone(),
+      widget_factory,
+    });
+
+    window_layers.clone().listen(monitor_state);
+    window_layers
+  }
+
+  fn listen(self: Arc<Self>, monitor_state: Arc<MonitorState>) {
+    let mut open_rx = self.widget_factory.open_tx.subscribe();
+    let mut focus_rx = self.widget_factory.focus_tx.subscribe();
+    let mut monitor_change_rx = monitor_state.change_tx.subscribe();
+
+    task::spawn(async move {
+      let mut interval = time::interval(REASSERT_INTERVAL);
+      interval.set_missed_tick_behavior(time::MissedTickBehavior::Skip);
+
+      loop {
+        tokio::select! {
+          _ = open_rx.recv() => {},
+          _ = focus_rx.recv() => {},
+          _ = monitor_change_rx.recv() => {},
+          _ = interval.tick() => {},
+        }
+
+        self.reassert().await;
+      }
+    });
+  }
+
+  /// Restacks Zebar windows by layer. Windows within a layer are kept in
+  /// the order they were opened (i.e. newer windows on top).
+  async fn reassert(&self) {
+    let mut states = self
+      .widget_factory
+      .states()
+      .await
+      .into_values()
+      .collect::<Vec<_>>();
+
+    states.sort_by_key(|state| {
+      let widget_number = state
+        .id
+        .trim_start_matches("widget-")
+        .parse::<u32>()
+        .unwrap_or_default();
+
+      (state.config.layer(), widget_number)
+    });
+
+    // Windows moved to the bottom are placed below all others, so
+    // bottom windows are restacked from newest to oldest.
+    let (mut bottom, rest): (Vec<_>, Vec<_>) = states
+      .into_iter()
+      .partition(|state| state.config.layer() == WidgetLayer::Bottom);
+
+    bottom.reverse();
+
+    for state in bottom.into_iter().chain(rest) {
+      let Some(window) = self.app_handle.get_webview_window(&state.id)
+      else {
+        continue;
+      };
+
+      if let Err(err) = raise_in_layer(&window, state.config.layer()) {
+        warn!(
+          "Failed to restack window of widget #{}: {:?}",
+          state.id, err
+        );
+      }
+    }
+  }
+}
+
+/// Moves a window to the top of its layer without activating it.
+fn raise_in_layer(
+  window: &WebviewWindow,
+  layer: WidgetLayer,
+) -> anyhow::Result<()> {
+  // Layer surfaces are stacked by the compositor.
+  #[cfg(all(target_os = "linux", feature = "layer-shell"))]
+  if crate::common::linux::is_layer_shell_supported() {
+    return Ok(());
+  }
+
+  #[cfg(target_os = "windows")]
+  window.as_ref().window().raise_in_layer(layer)?;
+
+  // On MacOS, each layer above normal windows gets its own window
+  // level.
+  #[cfg(target_os = "macos")]
+  match layer {
+    WidgetLayer::Bottom => window.set_always_on_bottom(true)?,
+    WidgetLayer::Normal => {}
+    WidgetLayer::Top => window.as_ref().window().set_above_menu_bar()?,
+    WidgetLayer::Overlay => {
+      window.as_ref().window().set_level_above_menu_bar(2)?
+    }
+  }
+
+  #[cfg(target_os = "linux")]
+  match layer {
+    WidgetLayer::Bottom => window.set_always_on_bottom(true)?,
+    WidgetLayer::Normal => {}
+    WidgetLayer::Top | WidgetLayer::Overlay => {
+      window.set_always_on_top(true)?
+    }
+  }
+
+  Ok(())
+}
@@ -0,0 +1,263 @@
+use std::{collections::HashMap, sync::Arc};
+
+use anyhow::Context;
+use tauri::{AppHandle, Manager};
+use tokio::sync::Mutex;
+use tracing::{info, warn};
+
+use crate::{
+  providers::{parse_provider_configs, ProviderEmission, ProviderManager},
+  widget_factory::{WidgetFactory, WidgetState},
+};
+
+/// Keeps widget window titles in sync with their `window_title`
+/// template.
+///
+/// Providers used by a template are created under their own config
+/// hashes, so that they're independent of the providers the widget
+/// listens to from the frontend.
+pub struct WindowTitles {
+  app_handle: AppHandle,
+
+  /// Reference to `ProviderManager`.
+  manager: Arc<ProviderManager>,
+
+  /// Reference to `WidgetFactory`.
+  widget_factory: Arc<WidgetFactory>,
+
+  /// Bound titles by widget ID.
+  titles: Mutex<HashMap<String, BoundTitle>>,
+}
+
+/// A window title template along with the latest outputs of its
+/// providers.
+struct BoundTitle {
+  template: String,
+
+  /// Names of the template's providers, keyed by config hash.
+  provider_names: HashMap<String, String>,
+
+  /// Latest output of each provider, keyed by name.
+  outputs: HashMap<String, serde_json::Value>,
+
+  /// Title that was last set on the window.
+  rendered: Option<String>,
+}
+
+impl WindowTitles {
+  pub fn new(
+    app_handle: &AppHandle,
+    manager: Arc<ProviderManager>,
+    widget_factory: Arc<WidgetFactory>,
+  ) -> Self {
+    Self {
+      app_handle: app_handle.clone(),
+      manager,
+      widget_factory,
+      titles: Mutex::new(HashMap::new()),
+    }
+  }
+
+  /// Starts the providers for a widget's title template. No-op if the
+  /// widget has no template or is already registered.
+  pub async fn register(
+    &self,
+    widget: &WidgetState,
+  ) -> anyhow::Result<()> {
+    let Some(title_config) = &widget.config.window_title else {
+      return Ok(());
+    };
+
+    if self.titles.lock().await.contains_key(&widget.id) {
+      return Ok(());
+    }
+
+    let provider_configs = parse_provider_configs(
+      &self.app_handle,
+      title_config.providers.clone(),
+      &widget.config_path,
+      widget.config.secret_access(),
+    )
+    .await
+    .context("Invalid window title providers.")?;
+
+    for config in provider_configs.values() {
+      for provider_type in config.provider_types() {
+        self
+          .widget_factory
+          .check_provider_permission(&widget.id, provider_type)
+          .await?;
+      }
+    }
+
+    let provider_configs = provider_configs
+      .into_iter()
+      .map(|(name, config)| {
+        let config_hash = format!("window-title:{}:{}", widget.id, name);
+        (config_hash, name, config)
+      })
+      .collect::<Vec<_>>();
+
+    self.titles.lock().await.insert(
+      widget.id.clone(),
+      BoundTitle {
+        template: title_config.template.clone(),
+        provider_names: provider_configs
+          .iter()
+          .map(|(config_hash, name, _)| {
+            (config_hash.clone(), name.clone())
+          })
+          .collect(),
+        outputs: HashMap::new(),
+        rendered: None,
+      },
+    );
+
+    // Placeholders are left empty until their provider first emits.
+    self.update_title(&widget.id).await;
+
+    let subscriber = format!("window-title:{}", widget.id);
+
+    for (config_hash, _, config) in provider_configs {
+      self
+        .manager
+        .create(config_hash, config, &subscriber)
+        .await?;
+    }
+
+    info!("Bound window title for widget {}.", widget.id);
+    Ok(())
+  }
+
+  /// Stops the providers for a widget's title template.
+  pub async fn unregister(&self, widget_id: &str) {
+    let Some(title) = self.titles.lock().await.remove(widget_id) else {
+      return;
+    };
+
+    let subscriber = format!("window-title:{}", widget_id);
+
+    for config_hash in title.provider_names.into_keys() {
+      if let Err(err) = self.manager.stop(config_hash, &subscriber).await {
+        warn!("Failed to stop window title provider: {:?}", err);
+      }
+    }
+  }
+
+  /// Re-renders the title bound to the emitting provider (if any).
+  pub async fn handle_emission(&self, emission: &ProviderEmission) {
+    let Ok(output) = &emission.result else {
+      return;
+    };
+
+    let widget_id = {
+      let mut titles = self.titles.lock().await;
+
+      let Some((widget_id, title)) =
+        titles.iter_mut().find(|(_, title)| {
+          title.provider_names.contains_key(&emission.config_hash)
+        })
+      else {
+        return;
+      };
+
+      let name = title.provider_names[&emission.config_hash].clone();
+      let output = serde_json::to_value(output).unwrap_or_default();
+      title.outputs.insert(name, output);
+
+      widget_id.clone()
+    };
+
+    self.update_title(&widget_id).await;
+  }
+
+  /// Renders a widget's title and sets it on the window if it changed.
+  async fn update_title(&self, widget_id: &str) {
+    let mut titles = self.titles.lock().await;
+
+    let Some(title) = titles.get_mut(widget_id) else {
+      return;
+    };
+
+    let rendered = render_template(&title.template, &title.outputs);
+
+    if title.rendered.as_ref() == Some(&rendered) {
+      return;
+    }
+
+    if let Some(window) = self.app_handle.get_webview_window(widget_id) {
+      if let Err(err) = window.set_title(&rendered) {
+        warn!("Failed to set window title: {:?}", err);
+      }
+    }
+
+    title.rendered = Some(rendered);
+  }
+}
+
+/// Replaces `{name.path}` and `{name.path:N}` placeholders with values
+/// from the provider outputs. Placeholders that can't be resolved (e.g.
+/// before the provider's first emission) are left empty. `{{` and `}}`
+/// are escapes for literal braces.
+pub fn render_template(
+  template: &str,
+  outputs: &HashMap<String, serde_json::Value>,
+) -> String {
+  let mut rendered = String::with_capacity(template.len());
+  let mut chars = template.chars().peekable();
+
+  while let Some(char) = chars.next() {
+    match char {
+      '{' if chars.peek() == Some(&'{') => {
+        chars.next();
+        rendered.push('{');
+      }
+      '}' if chars.peek() == Some(&'}') => {
+        chars.next();
+        rendered.push('}');
+      }
+      '{' => {
+        let placeholder =
+          chars.by_ref().take_while(|&c| c != '}').collect::<String>();
+
+        rendered.push_str(&render_placeholder(&placeholder, outputs));
+      }
+      _ => rendered.push(char),
+    }
+  }
+
+  rendered
+}
+
+fn render_placeholder(
+  placeholder: &str,
+  outputs: &HashMap<String, serde_json::Value>,
+) -> String {
+  let (path, precision) = match placeholder.rsplit_once(':') {
+    Some((path, precision)) => (path, precision.trim().parse().ok()),
+    None => (placeholder, None),
+  };
+
+  let mut segments = path.trim().split('.');
+
+  let value = segments.next().and_then(|name| outputs.get(name)).and_then(
+    |output| {
+      segments.try_fold(output, |value, segment| match value {
+        serde_json::Value::Array(values) => {
+          values.get(segment.parse::<usize>().ok()?)
+        }
+        _ => value.get(segment),
+      })
+    },
+  );
+
+  match (value, precision) {
+    (None | Some(serde_json::Value::Null), _) => String::new(),
+    (Some(serde_json::Value::String(string)), _) => string.clone(),
+    (Some(serde_json::Value::Number(number)), Some(precision)) => number
+      .as_f64()
+      .map(|number| format!("{:.*}", precision, number))
+      .unwrap_or_else(|| number.to_string()),
+    (Some(value), _) => value.to_string(),
+  }
+}
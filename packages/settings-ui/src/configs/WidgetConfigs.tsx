@@ -39,7 +39,7 @@ export function WidgetConfigs() {
 
   const [selectedConfigPath, setSelectedConfigPath] = createSignal<
     string | null
-  >(params.path ? atob(params.path) : null);
+  >(params.path ? decodeConfigPath(params.path) : null);
 
   const [selectedPreset, setSelectedPreset] = createSignal<string | null>(
     null,
@@ -80,7 +80,7 @@ export function WidgetConfigs() {
   listen('widget-closed', (event: Event<any>) => {
     mutateWidgetStates(states => {
       const newStates = { ...states };
-      delete newStates[event.payload];
+      delete newStates[event.payload.id];
       return newStates;
     });
   });
@@ -91,7 +91,7 @@ export function WidgetConfigs() {
     on(
       () => params.path,
       () => {
-        setSelectedConfigPath(params.path ? atob(params.path) : null);
+        setSelectedConfigPath(params.path ? decodeConfigPath(params.path) : null);
       },
     ),
   );
@@ -263,3 +263,14 @@ export function WidgetConfigs() {
 function WidgetSettingsEmptyState() {
   return <p class="p-4">No config selected.</p>;
 }
+
+/**
+ * Decodes a config path from the URL-safe base64 route param. Paths are
+ * UTF-8 encoded, which `atob` alone would garble (e.g. for non-ASCII
+ * user names).
+ */
+function decodeConfigPath(encoded: string): string {
+  const base64 = encoded.replace(/-/g, '+').replace(/_/g, '/');
+  const bytes = Uint8Array.from(atob(base64), char => char.charCodeAt(0));
+  return new TextDecoder().decode(bytes);
+}